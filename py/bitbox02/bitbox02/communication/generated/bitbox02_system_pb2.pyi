@@ -187,6 +187,27 @@ class SetDeviceNameRequest(google.protobuf.message.Message):
 
 global___SetDeviceNameRequest = SetDeviceNameRequest
 
+@typing.final
+class SetBlindSigningEnabledRequest(google.protobuf.message.Message):
+    """Enables or disables blind signing. This is a global setting shared by all apps. It allows signing
+    data that cannot be fully verified on the device: Ethereum typed messages given only their
+    precomputed domain separator and message hash (see `ETHSignTypedMessageRequest`), and Solana
+    transactions containing instructions that cannot be decoded (see `SolanaSignTransactionRequest`).
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    ENABLED_FIELD_NUMBER: builtins.int
+    enabled: builtins.bool
+    def __init__(
+        self,
+        *,
+        enabled: builtins.bool = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["enabled", b"enabled"]) -> None: ...
+
+global___SetBlindSigningEnabledRequest = SetBlindSigningEnabledRequest
+
 @typing.final
 class SetPasswordRequest(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -15,17 +15,17 @@ from . import common_pb2 as common__pb2
 from . import antiklepto_pb2 as antiklepto__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\tbtc.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\x1a\x10\x61ntiklepto.proto\"\xc6\x04\n\x0f\x42TCScriptConfig\x12G\n\x0bsimple_type\x18\x01 \x01(\x0e\x32\x30.shiftcrypto.bitbox02.BTCScriptConfig.SimpleTypeH\x00\x12\x42\n\x08multisig\x18\x02 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCScriptConfig.MultisigH\x00\x12>\n\x06policy\x18\x03 \x01(\x0b\x32,.shiftcrypto.bitbox02.BTCScriptConfig.PolicyH\x00\x1a\xd9\x01\n\x08Multisig\x12\x11\n\tthreshold\x18\x01 \x01(\r\x12)\n\x05xpubs\x18\x02 \x03(\x0b\x32\x1a.shiftcrypto.bitbox02.XPub\x12\x16\n\x0eour_xpub_index\x18\x03 \x01(\r\x12N\n\x0bscript_type\x18\x04 \x01(\x0e\x32\x39.shiftcrypto.bitbox02.BTCScriptConfig.Multisig.ScriptType\"\'\n\nScriptType\x12\t\n\x05P2WSH\x10\x00\x12\x0e\n\nP2WSH_P2SH\x10\x01\x1aK\n\x06Policy\x12\x0e\n\x06policy\x18\x01 \x01(\t\x12\x31\n\x04keys\x18\x02 \x03(\x0b\x32#.shiftcrypto.bitbox02.KeyOriginInfo\"3\n\nSimpleType\x12\x0f\n\x0bP2WPKH_P2SH\x10\x00\x12\n\n\x06P2WPKH\x10\x01\x12\x08\n\x04P2TR\x10\x02\x42\x08\n\x06\x63onfig\"\xfc\x02\n\rBTCPubRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\x41\n\txpub_type\x18\x03 \x01(\x0e\x32,.shiftcrypto.bitbox02.BTCPubRequest.XPubTypeH\x00\x12>\n\rscript_config\x18\x04 \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCScriptConfigH\x00\x12\x0f\n\x07\x64isplay\x18\x05 \x01(\x08\"\x8e\x01\n\x08XPubType\x12\x08\n\x04TPUB\x10\x00\x12\x08\n\x04XPUB\x10\x01\x12\x08\n\x04YPUB\x10\x02\x12\x08\n\x04ZPUB\x10\x03\x12\x08\n\x04VPUB\x10\x04\x12\x08\n\x04UPUB\x10\x05\x12\x10\n\x0c\x43\x41PITAL_VPUB\x10\x06\x12\x10\n\x0c\x43\x41PITAL_ZPUB\x10\x07\x12\x10\n\x0c\x43\x41PITAL_UPUB\x10\x08\x12\x10\n\x0c\x43\x41PITAL_YPUB\x10\tB\x08\n\x06output\"\xdf\x01\n\x0f\x42TCXpubsRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12\x41\n\txpub_type\x18\x02 \x01(\x0e\x32..shiftcrypto.bitbox02.BTCXpubsRequest.XPubType\x12/\n\x08keypaths\x18\x03 \x03(\x0b\x32\x1d.shiftcrypto.bitbox02.Keypath\"+\n\x08XPubType\x12\x0b\n\x07UNKNOWN\x10\x00\x12\x08\n\x04XPUB\x10\x01\x12\x08\n\x04TPUB\x10\x02\"k\n\x1a\x42TCScriptConfigWithKeypath\x12<\n\rscript_config\x18\x02 \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCScriptConfig\x12\x0f\n\x07keypath\x18\x03 \x03(\r\"\xbf\x03\n\x12\x42TCSignInitRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12H\n\x0escript_configs\x18\x02 \x03(\x0b\x32\x30.shiftcrypto.bitbox02.BTCScriptConfigWithKeypath\x12\x0f\n\x07version\x18\x04 \x01(\r\x12\x12\n\nnum_inputs\x18\x05 \x01(\r\x12\x13\n\x0bnum_outputs\x18\x06 \x01(\r\x12\x10\n\x08locktime\x18\x07 \x01(\r\x12H\n\x0b\x66ormat_unit\x18\x08 \x01(\x0e\x32\x33.shiftcrypto.bitbox02.BTCSignInitRequest.FormatUnit\x12\'\n\x1f\x63ontains_silent_payment_outputs\x18\t \x01(\x08\x12O\n\x15output_script_configs\x18\n \x03(\x0b\x32\x30.shiftcrypto.bitbox02.BTCScriptConfigWithKeypath\"\"\n\nFormatUnit\x12\x0b\n\x07\x44\x45\x46\x41ULT\x10\x00\x12\x07\n\x03SAT\x10\x01\"\xc4\x03\n\x13\x42TCSignNextResponse\x12<\n\x04type\x18\x01 \x01(\x0e\x32..shiftcrypto.bitbox02.BTCSignNextResponse.Type\x12\r\n\x05index\x18\x02 \x01(\r\x12\x15\n\rhas_signature\x18\x03 \x01(\x08\x12\x11\n\tsignature\x18\x04 \x01(\x0c\x12\x12\n\nprev_index\x18\x05 \x01(\r\x12W\n\x1d\x61nti_klepto_signer_commitment\x18\x06 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignerCommitment\x12!\n\x19generated_output_pkscript\x18\x07 \x01(\x0c\x12!\n\x19silent_payment_dleq_proof\x18\x08 \x01(\x0c\"\x82\x01\n\x04Type\x12\t\n\x05INPUT\x10\x00\x12\n\n\x06OUTPUT\x10\x01\x12\x08\n\x04\x44ONE\x10\x02\x12\x0f\n\x0bPREVTX_INIT\x10\x03\x12\x10\n\x0cPREVTX_INPUT\x10\x04\x12\x11\n\rPREVTX_OUTPUT\x10\x05\x12\x0e\n\nHOST_NONCE\x10\x06\x12\x13\n\x0fPAYMENT_REQUEST\x10\x07\"\xea\x01\n\x13\x42TCSignInputRequest\x12\x13\n\x0bprevOutHash\x18\x01 \x01(\x0c\x12\x14\n\x0cprevOutIndex\x18\x02 \x01(\r\x12\x14\n\x0cprevOutValue\x18\x03 \x01(\x04\x12\x10\n\x08sequence\x18\x04 \x01(\r\x12\x0f\n\x07keypath\x18\x06 \x03(\r\x12\x1b\n\x13script_config_index\x18\x07 \x01(\r\x12R\n\x15host_nonce_commitment\x18\x08 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\"\x9f\x03\n\x14\x42TCSignOutputRequest\x12\x0c\n\x04ours\x18\x01 \x01(\x08\x12\x31\n\x04type\x18\x02 \x01(\x0e\x32#.shiftcrypto.bitbox02.BTCOutputType\x12\r\n\x05value\x18\x03 \x01(\x04\x12\x0f\n\x07payload\x18\x04 \x01(\x0c\x12\x0f\n\x07keypath\x18\x05 \x03(\r\x12\x1b\n\x13script_config_index\x18\x06 \x01(\r\x12\"\n\x15payment_request_index\x18\x07 \x01(\rH\x00\x88\x01\x01\x12P\n\x0esilent_payment\x18\x08 \x01(\x0b\x32\x38.shiftcrypto.bitbox02.BTCSignOutputRequest.SilentPayment\x12\'\n\x1aoutput_script_config_index\x18\t \x01(\rH\x01\x88\x01\x01\x1a \n\rSilentPayment\x12\x0f\n\x07\x61\x64\x64ress\x18\x01 \x01(\tB\x18\n\x16_payment_request_indexB\x1d\n\x1b_output_script_config_index\"\x99\x01\n\x1b\x42TCScriptConfigRegistration\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12<\n\rscript_config\x18\x02 \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCScriptConfig\x12\x0f\n\x07keypath\x18\x03 \x03(\r\"\x0c\n\nBTCSuccess\"m\n\"BTCIsScriptConfigRegisteredRequest\x12G\n\x0cregistration\x18\x01 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.BTCScriptConfigRegistration\"<\n#BTCIsScriptConfigRegisteredResponse\x12\x15\n\ris_registered\x18\x01 \x01(\x08\"\xfc\x01\n\x1e\x42TCRegisterScriptConfigRequest\x12G\n\x0cregistration\x18\x01 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.BTCScriptConfigRegistration\x12\x0c\n\x04name\x18\x02 \x01(\t\x12P\n\txpub_type\x18\x03 \x01(\x0e\x32=.shiftcrypto.bitbox02.BTCRegisterScriptConfigRequest.XPubType\"1\n\x08XPubType\x12\x11\n\rAUTO_ELECTRUM\x10\x00\x12\x12\n\x0e\x41UTO_XPUB_TPUB\x10\x01\"b\n\x14\x42TCPrevTxInitRequest\x12\x0f\n\x07version\x18\x01 \x01(\r\x12\x12\n\nnum_inputs\x18\x02 \x01(\r\x12\x13\n\x0bnum_outputs\x18\x03 \x01(\r\x12\x10\n\x08locktime\x18\x04 \x01(\r\"r\n\x15\x42TCPrevTxInputRequest\x12\x15\n\rprev_out_hash\x18\x01 \x01(\x0c\x12\x16\n\x0eprev_out_index\x18\x02 \x01(\r\x12\x18\n\x10signature_script\x18\x03 \x01(\x0c\x12\x10\n\x08sequence\x18\x04 \x01(\r\">\n\x16\x42TCPrevTxOutputRequest\x12\r\n\x05value\x18\x01 \x01(\x04\x12\x15\n\rpubkey_script\x18\x02 \x01(\x0c\"\xab\x02\n\x18\x42TCPaymentRequestRequest\x12\x16\n\x0erecipient_name\x18\x01 \x01(\t\x12\x42\n\x05memos\x18\x02 \x03(\x0b\x32\x33.shiftcrypto.bitbox02.BTCPaymentRequestRequest.Memo\x12\r\n\x05nonce\x18\x03 \x01(\x0c\x12\x14\n\x0ctotal_amount\x18\x04 \x01(\x04\x12\x11\n\tsignature\x18\x05 \x01(\x0c\x1a{\n\x04Memo\x12Q\n\ttext_memo\x18\x01 \x01(\x0b\x32<.shiftcrypto.bitbox02.BTCPaymentRequestRequest.Memo.TextMemoH\x00\x1a\x18\n\x08TextMemo\x12\x0c\n\x04note\x18\x01 \x01(\tB\x06\n\x04memo\"\xee\x01\n\x15\x42TCSignMessageRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12G\n\rscript_config\x18\x02 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.BTCScriptConfigWithKeypath\x12\x0b\n\x03msg\x18\x03 \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\x04 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\"+\n\x16\x42TCSignMessageResponse\x12\x11\n\tsignature\x18\x01 \x01(\x0c\"\xb9\x05\n\nBTCRequest\x12_\n\x1bis_script_config_registered\x18\x01 \x01(\x0b\x32\x38.shiftcrypto.bitbox02.BTCIsScriptConfigRegisteredRequestH\x00\x12V\n\x16register_script_config\x18\x02 \x01(\x0b\x32\x34.shiftcrypto.bitbox02.BTCRegisterScriptConfigRequestH\x00\x12\x41\n\x0bprevtx_init\x18\x03 \x01(\x0b\x32*.shiftcrypto.bitbox02.BTCPrevTxInitRequestH\x00\x12\x43\n\x0cprevtx_input\x18\x04 \x01(\x0b\x32+.shiftcrypto.bitbox02.BTCPrevTxInputRequestH\x00\x12\x45\n\rprevtx_output\x18\x05 \x01(\x0b\x32,.shiftcrypto.bitbox02.BTCPrevTxOutputRequestH\x00\x12\x43\n\x0csign_message\x18\x06 \x01(\x0b\x32+.shiftcrypto.bitbox02.BTCSignMessageRequestH\x00\x12P\n\x14\x61ntiklepto_signature\x18\x07 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignatureRequestH\x00\x12I\n\x0fpayment_request\x18\x08 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCPaymentRequestRequestH\x00\x12\x36\n\x05xpubs\x18\t \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCXpubsRequestH\x00\x42\t\n\x07request\"\xc4\x03\n\x0b\x42TCResponse\x12\x33\n\x07success\x18\x01 \x01(\x0b\x32 .shiftcrypto.bitbox02.BTCSuccessH\x00\x12`\n\x1bis_script_config_registered\x18\x02 \x01(\x0b\x32\x39.shiftcrypto.bitbox02.BTCIsScriptConfigRegisteredResponseH\x00\x12>\n\tsign_next\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.BTCSignNextResponseH\x00\x12\x44\n\x0csign_message\x18\x04 \x01(\x0b\x32,.shiftcrypto.bitbox02.BTCSignMessageResponseH\x00\x12X\n\x1c\x61ntiklepto_signer_commitment\x18\x05 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignerCommitmentH\x00\x12\x32\n\x04pubs\x18\x06 \x01(\x0b\x32\".shiftcrypto.bitbox02.PubsResponseH\x00\x42\n\n\x08response*9\n\x07\x42TCCoin\x12\x07\n\x03\x42TC\x10\x00\x12\x08\n\x04TBTC\x10\x01\x12\x07\n\x03LTC\x10\x02\x12\x08\n\x04TLTC\x10\x03\x12\x08\n\x04RBTC\x10\x04*a\n\rBTCOutputType\x12\x0b\n\x07UNKNOWN\x10\x00\x12\t\n\x05P2PKH\x10\x01\x12\x08\n\x04P2SH\x10\x02\x12\n\n\x06P2WPKH\x10\x03\x12\t\n\x05P2WSH\x10\x04\x12\x08\n\x04P2TR\x10\x05\x12\r\n\tOP_RETURN\x10\x06\x62\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\tbtc.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\x1a\x10\x61ntiklepto.proto\"\xc6\x04\n\x0f\x42TCScriptConfig\x12G\n\x0bsimple_type\x18\x01 \x01(\x0e\x32\x30.shiftcrypto.bitbox02.BTCScriptConfig.SimpleTypeH\x00\x12\x42\n\x08multisig\x18\x02 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCScriptConfig.MultisigH\x00\x12>\n\x06policy\x18\x03 \x01(\x0b\x32,.shiftcrypto.bitbox02.BTCScriptConfig.PolicyH\x00\x1a\xd9\x01\n\x08Multisig\x12\x11\n\tthreshold\x18\x01 \x01(\r\x12)\n\x05xpubs\x18\x02 \x03(\x0b\x32\x1a.shiftcrypto.bitbox02.XPub\x12\x16\n\x0eour_xpub_index\x18\x03 \x01(\r\x12N\n\x0bscript_type\x18\x04 \x01(\x0e\x32\x39.shiftcrypto.bitbox02.BTCScriptConfig.Multisig.ScriptType\"\'\n\nScriptType\x12\t\n\x05P2WSH\x10\x00\x12\x0e\n\nP2WSH_P2SH\x10\x01\x1aK\n\x06Policy\x12\x0e\n\x06policy\x18\x01 \x01(\t\x12\x31\n\x04keys\x18\x02 \x03(\x0b\x32#.shiftcrypto.bitbox02.KeyOriginInfo\"3\n\nSimpleType\x12\x0f\n\x0bP2WPKH_P2SH\x10\x00\x12\n\n\x06P2WPKH\x10\x01\x12\x08\n\x04P2TR\x10\x02\x42\x08\n\x06\x63onfig\"\xfc\x02\n\rBTCPubRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\x41\n\txpub_type\x18\x03 \x01(\x0e\x32,.shiftcrypto.bitbox02.BTCPubRequest.XPubTypeH\x00\x12>\n\rscript_config\x18\x04 \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCScriptConfigH\x00\x12\x0f\n\x07\x64isplay\x18\x05 \x01(\x08\"\x8e\x01\n\x08XPubType\x12\x08\n\x04TPUB\x10\x00\x12\x08\n\x04XPUB\x10\x01\x12\x08\n\x04YPUB\x10\x02\x12\x08\n\x04ZPUB\x10\x03\x12\x08\n\x04VPUB\x10\x04\x12\x08\n\x04UPUB\x10\x05\x12\x10\n\x0c\x43\x41PITAL_VPUB\x10\x06\x12\x10\n\x0c\x43\x41PITAL_ZPUB\x10\x07\x12\x10\n\x0c\x43\x41PITAL_UPUB\x10\x08\x12\x10\n\x0c\x43\x41PITAL_YPUB\x10\tB\x08\n\x06output\"\xdf\x01\n\x0f\x42TCXpubsRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12\x41\n\txpub_type\x18\x02 \x01(\x0e\x32..shiftcrypto.bitbox02.BTCXpubsRequest.XPubType\x12/\n\x08keypaths\x18\x03 \x03(\x0b\x32\x1d.shiftcrypto.bitbox02.Keypath\"+\n\x08XPubType\x12\x0b\n\x07UNKNOWN\x10\x00\x12\x08\n\x04XPUB\x10\x01\x12\x08\n\x04TPUB\x10\x02\"k\n\x1a\x42TCScriptConfigWithKeypath\x12<\n\rscript_config\x18\x02 \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCScriptConfig\x12\x0f\n\x07keypath\x18\x03 \x03(\r\"\xbf\x03\n\x12\x42TCSignInitRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12H\n\x0escript_configs\x18\x02 \x03(\x0b\x32\x30.shiftcrypto.bitbox02.BTCScriptConfigWithKeypath\x12\x0f\n\x07version\x18\x04 \x01(\r\x12\x12\n\nnum_inputs\x18\x05 \x01(\r\x12\x13\n\x0bnum_outputs\x18\x06 \x01(\r\x12\x10\n\x08locktime\x18\x07 \x01(\r\x12H\n\x0b\x66ormat_unit\x18\x08 \x01(\x0e\x32\x33.shiftcrypto.bitbox02.BTCSignInitRequest.FormatUnit\x12\'\n\x1f\x63ontains_silent_payment_outputs\x18\t \x01(\x08\x12O\n\x15output_script_configs\x18\n \x03(\x0b\x32\x30.shiftcrypto.bitbox02.BTCScriptConfigWithKeypath\"\"\n\nFormatUnit\x12\x0b\n\x07\x44\x45\x46\x41ULT\x10\x00\x12\x07\n\x03SAT\x10\x01\"\xc4\x03\n\x13\x42TCSignNextResponse\x12<\n\x04type\x18\x01 \x01(\x0e\x32..shiftcrypto.bitbox02.BTCSignNextResponse.Type\x12\r\n\x05index\x18\x02 \x01(\r\x12\x15\n\rhas_signature\x18\x03 \x01(\x08\x12\x11\n\tsignature\x18\x04 \x01(\x0c\x12\x12\n\nprev_index\x18\x05 \x01(\r\x12W\n\x1d\x61nti_klepto_signer_commitment\x18\x06 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignerCommitment\x12!\n\x19generated_output_pkscript\x18\x07 \x01(\x0c\x12!\n\x19silent_payment_dleq_proof\x18\x08 \x01(\x0c\"\x82\x01\n\x04Type\x12\t\n\x05INPUT\x10\x00\x12\n\n\x06OUTPUT\x10\x01\x12\x08\n\x04\x44ONE\x10\x02\x12\x0f\n\x0bPREVTX_INIT\x10\x03\x12\x10\n\x0cPREVTX_INPUT\x10\x04\x12\x11\n\rPREVTX_OUTPUT\x10\x05\x12\x0e\n\nHOST_NONCE\x10\x06\x12\x13\n\x0fPAYMENT_REQUEST\x10\x07\"\xea\x01\n\x13\x42TCSignInputRequest\x12\x13\n\x0bprevOutHash\x18\x01 \x01(\x0c\x12\x14\n\x0cprevOutIndex\x18\x02 \x01(\r\x12\x14\n\x0cprevOutValue\x18\x03 \x01(\x04\x12\x10\n\x08sequence\x18\x04 \x01(\r\x12\x0f\n\x07keypath\x18\x06 \x03(\r\x12\x1b\n\x13script_config_index\x18\x07 \x01(\r\x12R\n\x15host_nonce_commitment\x18\x08 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\"\x9f\x03\n\x14\x42TCSignOutputRequest\x12\x0c\n\x04ours\x18\x01 \x01(\x08\x12\x31\n\x04type\x18\x02 \x01(\x0e\x32#.shiftcrypto.bitbox02.BTCOutputType\x12\r\n\x05value\x18\x03 \x01(\x04\x12\x0f\n\x07payload\x18\x04 \x01(\x0c\x12\x0f\n\x07keypath\x18\x05 \x03(\r\x12\x1b\n\x13script_config_index\x18\x06 \x01(\r\x12\"\n\x15payment_request_index\x18\x07 \x01(\rH\x00\x88\x01\x01\x12P\n\x0esilent_payment\x18\x08 \x01(\x0b\x32\x38.shiftcrypto.bitbox02.BTCSignOutputRequest.SilentPayment\x12\'\n\x1aoutput_script_config_index\x18\t \x01(\rH\x01\x88\x01\x01\x1a \n\rSilentPayment\x12\x0f\n\x07\x61\x64\x64ress\x18\x01 \x01(\tB\x18\n\x16_payment_request_indexB\x1d\n\x1b_output_script_config_index\"\x99\x01\n\x1b\x42TCScriptConfigRegistration\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12<\n\rscript_config\x18\x02 \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCScriptConfig\x12\x0f\n\x07keypath\x18\x03 \x03(\r\"\x0c\n\nBTCSuccess\"m\n\"BTCIsScriptConfigRegisteredRequest\x12G\n\x0cregistration\x18\x01 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.BTCScriptConfigRegistration\"<\n#BTCIsScriptConfigRegisteredResponse\x12\x15\n\ris_registered\x18\x01 \x01(\x08\"\xfc\x01\n\x1e\x42TCRegisterScriptConfigRequest\x12G\n\x0cregistration\x18\x01 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.BTCScriptConfigRegistration\x12\x0c\n\x04name\x18\x02 \x01(\t\x12P\n\txpub_type\x18\x03 \x01(\x0e\x32=.shiftcrypto.bitbox02.BTCRegisterScriptConfigRequest.XPubType\"1\n\x08XPubType\x12\x11\n\rAUTO_ELECTRUM\x10\x00\x12\x12\n\x0e\x41UTO_XPUB_TPUB\x10\x01\"b\n\x14\x42TCPrevTxInitRequest\x12\x0f\n\x07version\x18\x01 \x01(\r\x12\x12\n\nnum_inputs\x18\x02 \x01(\r\x12\x13\n\x0bnum_outputs\x18\x03 \x01(\r\x12\x10\n\x08locktime\x18\x04 \x01(\r\"r\n\x15\x42TCPrevTxInputRequest\x12\x15\n\rprev_out_hash\x18\x01 \x01(\x0c\x12\x16\n\x0eprev_out_index\x18\x02 \x01(\r\x12\x18\n\x10signature_script\x18\x03 \x01(\x0c\x12\x10\n\x08sequence\x18\x04 \x01(\r\">\n\x16\x42TCPrevTxOutputRequest\x12\r\n\x05value\x18\x01 \x01(\x04\x12\x15\n\rpubkey_script\x18\x02 \x01(\x0c\"\xab\x02\n\x18\x42TCPaymentRequestRequest\x12\x16\n\x0erecipient_name\x18\x01 \x01(\t\x12\x42\n\x05memos\x18\x02 \x03(\x0b\x32\x33.shiftcrypto.bitbox02.BTCPaymentRequestRequest.Memo\x12\r\n\x05nonce\x18\x03 \x01(\x0c\x12\x14\n\x0ctotal_amount\x18\x04 \x01(\x04\x12\x11\n\tsignature\x18\x05 \x01(\x0c\x1a{\n\x04Memo\x12Q\n\ttext_memo\x18\x01 \x01(\x0b\x32<.shiftcrypto.bitbox02.BTCPaymentRequestRequest.Memo.TextMemoH\x00\x1a\x18\n\x08TextMemo\x12\x0c\n\x04note\x18\x01 \x01(\tB\x06\n\x04memo\"\xee\x01\n\x15\x42TCSignMessageRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12G\n\rscript_config\x18\x02 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.BTCScriptConfigWithKeypath\x12\x0b\n\x03msg\x18\x03 \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\x04 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\"+\n\x16\x42TCSignMessageResponse\x12\x11\n\tsignature\x18\x01 \x01(\x0c\"\x1b\n\x19\x42TCLightningNodeIdRequest\"-\n\x1a\x42TCLightningNodeIdResponse\x12\x0f\n\x07node_id\x18\x01 \x01(\x0c\")\n\x17\x42TCLightningEcdhRequest\x12\x0e\n\x06pubkey\x18\x01 \x01(\x0c\"1\n\x18\x42TCLightningEcdhResponse\x12\x15\n\rshared_secret\x18\x01 \x01(\x0c\"\x9e\x02\n\x1d\x42TCLightningChannelParameters\x12\x13\n\x0bis_outbound\x18\x01 \x01(\x08\x12&\n\x1e\x63ounterparty_payment_basepoint\x18\x02 \x01(\x0c\x12)\n!counterparty_revocation_basepoint\x18\x03 \x01(\x0c\x12.\n&counterparty_delayed_payment_basepoint\x18\x04 \x01(\x0c\x12\x1c\n\x14holder_to_self_delay\x18\x05 \x01(\r\x12\"\n\x1a\x63ounterparty_to_self_delay\x18\x06 \x01(\r\x12#\n\x1b\x63ounterparty_htlc_basepoint\x18\x07 \x01(\x0c\"\x81\x01\n\x1f\x42TCLightningChannelSetupRequest\x12\x15\n\rchannel_index\x18\x01 \x01(\r\x12G\n\nparameters\x18\x02 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.BTCLightningChannelParameters\"\xae\x01\n BTCLightningChannelSetupResponse\x12\x16\n\x0e\x66unding_pubkey\x18\x01 \x01(\x0c\x12\x1c\n\x14revocation_basepoint\x18\x02 \x01(\x0c\x12\x19\n\x11payment_basepoint\x18\x03 \x01(\x0c\x12!\n\x19\x64\x65layed_payment_basepoint\x18\x04 \x01(\x0c\x12\x16\n\x0ehtlc_basepoint\x18\x05 \x01(\x0c\"Y\n%BTCLightningPerCommitmentPointRequest\x12\x15\n\rchannel_index\x18\x01 \x01(\r\x12\x19\n\x11\x63ommitment_number\x18\x02 \x01(\x04\"F\n&BTCLightningPerCommitmentPointResponse\x12\x1c\n\x14per_commitment_point\x18\x01 \x01(\x0c\"M\n\x19\x42TCLightningRevokeRequest\x12\x15\n\rchannel_index\x18\x01 \x01(\r\x12\x19\n\x11\x63ommitment_number\x18\x02 \x01(\x04\";\n\x1a\x42TCLightningRevokeResponse\x12\x1d\n\x15per_commitment_secret\x18\x01 \x01(\x0c\":\n\x12\x42TCLightningOutput\x12\r\n\x05value\x18\x01 \x01(\x04\x12\x15\n\rpubkey_script\x18\x02 \x01(\x0c\"\xfa\x01\n\x18\x42TCLightningCommitmentTx\x12\x0f\n\x07version\x18\x01 \x01(\r\x12\x10\n\x08locktime\x18\x02 \x01(\r\x12\x14\n\x0c\x66unding_txid\x18\x03 \x01(\x0c\x12\x1c\n\x14\x66unding_output_index\x18\x04 \x01(\r\x12\x15\n\rfunding_value\x18\x05 \x01(\x04\x12\x10\n\x08sequence\x18\x06 \x01(\r\x12\x39\n\x07outputs\x18\x07 \x03(\x0b\x32(.shiftcrypto.bitbox02.BTCLightningOutput\x12#\n\x1b\x63ounterparty_funding_pubkey\x18\x08 \x01(\x0c\"\x87\x01\n\x12\x42TCLightningHtlcTx\x12\x1f\n\x17\x63ommitment_output_index\x18\x01 \x01(\r\x12\x0f\n\x07offered\x18\x02 \x01(\x08\x12\x14\n\x0cpayment_hash\x18\x03 \x01(\x0c\x12\x13\n\x0b\x63ltv_expiry\x18\x04 \x01(\r\x12\x14\n\x0coutput_value\x18\x05 \x01(\x04\"\xc0\x02\n-BTCLightningSignCounterpartyCommitmentRequest\x12\x15\n\rchannel_index\x18\x01 \x01(\r\x12\x19\n\x11\x63ommitment_number\x18\x02 \x01(\x04\x12\x1c\n\x14per_commitment_point\x18\x03 \x01(\x0c\x12:\n\x02tx\x18\x04 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCLightningCommitmentTx\x12:\n\x08htlc_txs\x18\x05 \x03(\x0b\x32(.shiftcrypto.bitbox02.BTCLightningHtlcTx\x12G\n\nparameters\x18\x06 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.BTCLightningChannelParameters\"\xc0\x02\n+BTCLightningValidateHolderCommitmentRequest\x12\x15\n\rchannel_index\x18\x01 \x01(\r\x12\x19\n\x11\x63ommitment_number\x18\x02 \x01(\x04\x12:\n\x02tx\x18\x03 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCLightningCommitmentTx\x12:\n\x08htlc_txs\x18\x04 \x03(\x0b\x32(.shiftcrypto.bitbox02.BTCLightningHtlcTx\x12G\n\nparameters\x18\x05 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.BTCLightningChannelParameters\x12\x1e\n\x16\x63ounterparty_signature\x18\x06 \x01(\x0c\".\n,BTCLightningValidateHolderCommitmentResponse\"\x9c\x02\n\'BTCLightningSignHolderCommitmentRequest\x12\x15\n\rchannel_index\x18\x01 \x01(\r\x12\x19\n\x11\x63ommitment_number\x18\x02 \x01(\x04\x12:\n\x02tx\x18\x03 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCLightningCommitmentTx\x12:\n\x08htlc_txs\x18\x04 \x03(\x0b\x32(.shiftcrypto.bitbox02.BTCLightningHtlcTx\x12G\n\nparameters\x18\x05 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.BTCLightningChannelParameters\"P\n\"BTCLightningSignCommitmentResponse\x12\x11\n\tsignature\x18\x01 \x01(\x0c\x12\x17\n\x0fhtlc_signatures\x18\x02 \x03(\x0c\"@\n!BTCLightningForgetChannelsRequest\x12\x1b\n\x13\x62\x65low_channel_index\x18\x01 \x01(\r\"$\n\"BTCLightningForgetChannelsResponse\"\xcb\x06\n\x13\x42TCLightningRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.BTCCoin\x12\x42\n\x07node_id\x18\x02 \x01(\x0b\x32/.shiftcrypto.bitbox02.BTCLightningNodeIdRequestH\x00\x12=\n\x04\x65\x63\x64h\x18\x03 \x01(\x0b\x32-.shiftcrypto.bitbox02.BTCLightningEcdhRequestH\x00\x12N\n\rchannel_setup\x18\x04 \x01(\x0b\x32\x35.shiftcrypto.bitbox02.BTCLightningChannelSetupRequestH\x00\x12[\n\x14per_commitment_point\x18\x05 \x01(\x0b\x32;.shiftcrypto.bitbox02.BTCLightningPerCommitmentPointRequestH\x00\x12\x41\n\x06revoke\x18\x06 \x01(\x0b\x32/.shiftcrypto.bitbox02.BTCLightningRevokeRequestH\x00\x12k\n\x1csign_counterparty_commitment\x18\x07 \x01(\x0b\x32\x43.shiftcrypto.bitbox02.BTCLightningSignCounterpartyCommitmentRequestH\x00\x12_\n\x16sign_holder_commitment\x18\x08 \x01(\x0b\x32=.shiftcrypto.bitbox02.BTCLightningSignHolderCommitmentRequestH\x00\x12g\n\x1avalidate_holder_commitment\x18\t \x01(\x0b\x32\x41.shiftcrypto.bitbox02.BTCLightningValidateHolderCommitmentRequestH\x00\x12R\n\x0f\x66orget_channels\x18\n \x01(\x0b\x32\x37.shiftcrypto.bitbox02.BTCLightningForgetChannelsRequestH\x00\x42\t\n\x07request\"\xae\x05\n\x14\x42TCLightningResponse\x12\x43\n\x07node_id\x18\x01 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.BTCLightningNodeIdResponseH\x00\x12>\n\x04\x65\x63\x64h\x18\x02 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCLightningEcdhResponseH\x00\x12O\n\rchannel_setup\x18\x03 \x01(\x0b\x32\x36.shiftcrypto.bitbox02.BTCLightningChannelSetupResponseH\x00\x12\\\n\x14per_commitment_point\x18\x04 \x01(\x0b\x32<.shiftcrypto.bitbox02.BTCLightningPerCommitmentPointResponseH\x00\x12\x42\n\x06revoke\x18\x05 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.BTCLightningRevokeResponseH\x00\x12S\n\x0fsign_commitment\x18\x06 \x01(\x0b\x32\x38.shiftcrypto.bitbox02.BTCLightningSignCommitmentResponseH\x00\x12h\n\x1avalidate_holder_commitment\x18\x07 \x01(\x0b\x32\x42.shiftcrypto.bitbox02.BTCLightningValidateHolderCommitmentResponseH\x00\x12S\n\x0f\x66orget_channels\x18\x08 \x01(\x0b\x32\x38.shiftcrypto.bitbox02.BTCLightningForgetChannelsResponseH\x00\x42\n\n\x08response\"\xf9\x05\n\nBTCRequest\x12_\n\x1bis_script_config_registered\x18\x01 \x01(\x0b\x32\x38.shiftcrypto.bitbox02.BTCIsScriptConfigRegisteredRequestH\x00\x12V\n\x16register_script_config\x18\x02 \x01(\x0b\x32\x34.shiftcrypto.bitbox02.BTCRegisterScriptConfigRequestH\x00\x12\x41\n\x0bprevtx_init\x18\x03 \x01(\x0b\x32*.shiftcrypto.bitbox02.BTCPrevTxInitRequestH\x00\x12\x43\n\x0cprevtx_input\x18\x04 \x01(\x0b\x32+.shiftcrypto.bitbox02.BTCPrevTxInputRequestH\x00\x12\x45\n\rprevtx_output\x18\x05 \x01(\x0b\x32,.shiftcrypto.bitbox02.BTCPrevTxOutputRequestH\x00\x12\x43\n\x0csign_message\x18\x06 \x01(\x0b\x32+.shiftcrypto.bitbox02.BTCSignMessageRequestH\x00\x12P\n\x14\x61ntiklepto_signature\x18\x07 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignatureRequestH\x00\x12I\n\x0fpayment_request\x18\x08 \x01(\x0b\x32..shiftcrypto.bitbox02.BTCPaymentRequestRequestH\x00\x12\x36\n\x05xpubs\x18\t \x01(\x0b\x32%.shiftcrypto.bitbox02.BTCXpubsRequestH\x00\x12>\n\tlightning\x18\n \x01(\x0b\x32).shiftcrypto.bitbox02.BTCLightningRequestH\x00\x42\t\n\x07request\"\x85\x04\n\x0b\x42TCResponse\x12\x33\n\x07success\x18\x01 \x01(\x0b\x32 .shiftcrypto.bitbox02.BTCSuccessH\x00\x12`\n\x1bis_script_config_registered\x18\x02 \x01(\x0b\x32\x39.shiftcrypto.bitbox02.BTCIsScriptConfigRegisteredResponseH\x00\x12>\n\tsign_next\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.BTCSignNextResponseH\x00\x12\x44\n\x0csign_message\x18\x04 \x01(\x0b\x32,.shiftcrypto.bitbox02.BTCSignMessageResponseH\x00\x12X\n\x1c\x61ntiklepto_signer_commitment\x18\x05 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignerCommitmentH\x00\x12\x32\n\x04pubs\x18\x06 \x01(\x0b\x32\".shiftcrypto.bitbox02.PubsResponseH\x00\x12?\n\tlightning\x18\x07 \x01(\x0b\x32*.shiftcrypto.bitbox02.BTCLightningResponseH\x00\x42\n\n\x08response*9\n\x07\x42TCCoin\x12\x07\n\x03\x42TC\x10\x00\x12\x08\n\x04TBTC\x10\x01\x12\x07\n\x03LTC\x10\x02\x12\x08\n\x04TLTC\x10\x03\x12\x08\n\x04RBTC\x10\x04*a\n\rBTCOutputType\x12\x0b\n\x07UNKNOWN\x10\x00\x12\t\n\x05P2PKH\x10\x01\x12\x08\n\x04P2SH\x10\x02\x12\n\n\x06P2WPKH\x10\x03\x12\t\n\x05P2WSH\x10\x04\x12\x08\n\x04P2TR\x10\x05\x12\r\n\tOP_RETURN\x10\x06\x62\x06proto3')
 
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'btc_pb2', globals())
 if _descriptor._USE_C_DESCRIPTORS == False:
 
   DESCRIPTOR._options = None
-  _BTCCOIN._serialized_start=5551
-  _BTCCOIN._serialized_end=5608
-  _BTCOUTPUTTYPE._serialized_start=5610
-  _BTCOUTPUTTYPE._serialized_end=5707
+  _BTCCOIN._serialized_start=9904
+  _BTCCOIN._serialized_end=9961
+  _BTCOUTPUTTYPE._serialized_start=9963
+  _BTCOUTPUTTYPE._serialized_end=10060
   _BTCSCRIPTCONFIG._serialized_start=68
   _BTCSCRIPTCONFIG._serialized_end=650
   _BTCSCRIPTCONFIG_MULTISIG._serialized_start=293
@@ -88,8 +88,54 @@ if _descriptor._USE_C_DESCRIPTORS == False:
   _BTCSIGNMESSAGEREQUEST._serialized_end=4349
   _BTCSIGNMESSAGERESPONSE._serialized_start=4351
   _BTCSIGNMESSAGERESPONSE._serialized_end=4394
-  _BTCREQUEST._serialized_start=4397
-  _BTCREQUEST._serialized_end=5094
-  _BTCRESPONSE._serialized_start=5097
-  _BTCRESPONSE._serialized_end=5549
+  _BTCLIGHTNINGNODEIDREQUEST._serialized_start=4396
+  _BTCLIGHTNINGNODEIDREQUEST._serialized_end=4423
+  _BTCLIGHTNINGNODEIDRESPONSE._serialized_start=4425
+  _BTCLIGHTNINGNODEIDRESPONSE._serialized_end=4470
+  _BTCLIGHTNINGECDHREQUEST._serialized_start=4472
+  _BTCLIGHTNINGECDHREQUEST._serialized_end=4513
+  _BTCLIGHTNINGECDHRESPONSE._serialized_start=4515
+  _BTCLIGHTNINGECDHRESPONSE._serialized_end=4564
+  _BTCLIGHTNINGCHANNELPARAMETERS._serialized_start=4567
+  _BTCLIGHTNINGCHANNELPARAMETERS._serialized_end=4853
+  _BTCLIGHTNINGCHANNELSETUPREQUEST._serialized_start=4856
+  _BTCLIGHTNINGCHANNELSETUPREQUEST._serialized_end=4985
+  _BTCLIGHTNINGCHANNELSETUPRESPONSE._serialized_start=4988
+  _BTCLIGHTNINGCHANNELSETUPRESPONSE._serialized_end=5162
+  _BTCLIGHTNINGPERCOMMITMENTPOINTREQUEST._serialized_start=5164
+  _BTCLIGHTNINGPERCOMMITMENTPOINTREQUEST._serialized_end=5253
+  _BTCLIGHTNINGPERCOMMITMENTPOINTRESPONSE._serialized_start=5255
+  _BTCLIGHTNINGPERCOMMITMENTPOINTRESPONSE._serialized_end=5325
+  _BTCLIGHTNINGREVOKEREQUEST._serialized_start=5327
+  _BTCLIGHTNINGREVOKEREQUEST._serialized_end=5404
+  _BTCLIGHTNINGREVOKERESPONSE._serialized_start=5406
+  _BTCLIGHTNINGREVOKERESPONSE._serialized_end=5465
+  _BTCLIGHTNINGOUTPUT._serialized_start=5467
+  _BTCLIGHTNINGOUTPUT._serialized_end=5525
+  _BTCLIGHTNINGCOMMITMENTTX._serialized_start=5528
+  _BTCLIGHTNINGCOMMITMENTTX._serialized_end=5778
+  _BTCLIGHTNINGHTLCTX._serialized_start=5781
+  _BTCLIGHTNINGHTLCTX._serialized_end=5916
+  _BTCLIGHTNINGSIGNCOUNTERPARTYCOMMITMENTREQUEST._serialized_start=5919
+  _BTCLIGHTNINGSIGNCOUNTERPARTYCOMMITMENTREQUEST._serialized_end=6239
+  _BTCLIGHTNINGVALIDATEHOLDERCOMMITMENTREQUEST._serialized_start=6242
+  _BTCLIGHTNINGVALIDATEHOLDERCOMMITMENTREQUEST._serialized_end=6562
+  _BTCLIGHTNINGVALIDATEHOLDERCOMMITMENTRESPONSE._serialized_start=6564
+  _BTCLIGHTNINGVALIDATEHOLDERCOMMITMENTRESPONSE._serialized_end=6610
+  _BTCLIGHTNINGSIGNHOLDERCOMMITMENTREQUEST._serialized_start=6613
+  _BTCLIGHTNINGSIGNHOLDERCOMMITMENTREQUEST._serialized_end=6897
+  _BTCLIGHTNINGSIGNCOMMITMENTRESPONSE._serialized_start=6899
+  _BTCLIGHTNINGSIGNCOMMITMENTRESPONSE._serialized_end=6979
+  _BTCLIGHTNINGFORGETCHANNELSREQUEST._serialized_start=6981
+  _BTCLIGHTNINGFORGETCHANNELSREQUEST._serialized_end=7045
+  _BTCLIGHTNINGFORGETCHANNELSRESPONSE._serialized_start=7047
+  _BTCLIGHTNINGFORGETCHANNELSRESPONSE._serialized_end=7083
+  _BTCLIGHTNINGREQUEST._serialized_start=7086
+  _BTCLIGHTNINGREQUEST._serialized_end=7929
+  _BTCLIGHTNINGRESPONSE._serialized_start=7932
+  _BTCLIGHTNINGRESPONSE._serialized_end=8618
+  _BTCREQUEST._serialized_start=8621
+  _BTCREQUEST._serialized_end=9382
+  _BTCRESPONSE._serialized_start=9385
+  _BTCRESPONSE._serialized_end=9902
 # @@protoc_insertion_point(module_scope)
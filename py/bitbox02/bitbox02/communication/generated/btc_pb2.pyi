@@ -839,6 +839,599 @@ class BTCSignMessageResponse(google.protobuf.message.Message):
 
 global___BTCSignMessageResponse = BTCSignMessageResponse
 
+@typing.final
+class BTCLightningNodeIdRequest(google.protobuf.message.Message):
+    """Lightning channel signer. Commitment numbers count up from 0, as in BOLT #3."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    def __init__(
+        self,
+    ) -> None: ...
+
+global___BTCLightningNodeIdRequest = BTCLightningNodeIdRequest
+
+@typing.final
+class BTCLightningNodeIdResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    NODE_ID_FIELD_NUMBER: builtins.int
+    node_id: builtins.bytes
+    """33 bytes compressed public key."""
+    def __init__(
+        self,
+        *,
+        node_id: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["node_id", b"node_id"]) -> None: ...
+
+global___BTCLightningNodeIdResponse = BTCLightningNodeIdResponse
+
+@typing.final
+class BTCLightningEcdhRequest(google.protobuf.message.Message):
+    """BOLT #8 ECDH with the node key."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PUBKEY_FIELD_NUMBER: builtins.int
+    pubkey: builtins.bytes
+    """33 bytes compressed public key."""
+    def __init__(
+        self,
+        *,
+        pubkey: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["pubkey", b"pubkey"]) -> None: ...
+
+global___BTCLightningEcdhRequest = BTCLightningEcdhRequest
+
+@typing.final
+class BTCLightningEcdhResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    SHARED_SECRET_FIELD_NUMBER: builtins.int
+    shared_secret: builtins.bytes
+    """SHA256 of the compressed shared point."""
+    def __init__(
+        self,
+        *,
+        shared_secret: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["shared_secret", b"shared_secret"]) -> None: ...
+
+global___BTCLightningEcdhResponse = BTCLightningEcdhResponse
+
+@typing.final
+class BTCLightningChannelParameters(google.protobuf.message.Message):
+    """Parameters of a channel negotiated in `open_channel` and `accept_channel`, see BOLT #2."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    IS_OUTBOUND_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_PAYMENT_BASEPOINT_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_REVOCATION_BASEPOINT_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_DELAYED_PAYMENT_BASEPOINT_FIELD_NUMBER: builtins.int
+    HOLDER_TO_SELF_DELAY_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_TO_SELF_DELAY_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_HTLC_BASEPOINT_FIELD_NUMBER: builtins.int
+    is_outbound: builtins.bool
+    """True if we opened (funded) the channel."""
+    counterparty_payment_basepoint: builtins.bytes
+    """33 bytes compressed public keys of the counterparty."""
+    counterparty_revocation_basepoint: builtins.bytes
+    counterparty_delayed_payment_basepoint: builtins.bytes
+    holder_to_self_delay: builtins.int
+    """`to_self_delay` of our to_local output, requested by the counterparty."""
+    counterparty_to_self_delay: builtins.int
+    """`to_self_delay` of the counterparty's to_local output, requested by us."""
+    counterparty_htlc_basepoint: builtins.bytes
+    """33 bytes compressed public key of the counterparty."""
+    def __init__(
+        self,
+        *,
+        is_outbound: builtins.bool = ...,
+        counterparty_payment_basepoint: builtins.bytes = ...,
+        counterparty_revocation_basepoint: builtins.bytes = ...,
+        counterparty_delayed_payment_basepoint: builtins.bytes = ...,
+        holder_to_self_delay: builtins.int = ...,
+        counterparty_to_self_delay: builtins.int = ...,
+        counterparty_htlc_basepoint: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["counterparty_delayed_payment_basepoint", b"counterparty_delayed_payment_basepoint", "counterparty_htlc_basepoint", b"counterparty_htlc_basepoint", "counterparty_payment_basepoint", b"counterparty_payment_basepoint", "counterparty_revocation_basepoint", b"counterparty_revocation_basepoint", "counterparty_to_self_delay", b"counterparty_to_self_delay", "holder_to_self_delay", b"holder_to_self_delay", "is_outbound", b"is_outbound"]) -> None: ...
+
+global___BTCLightningChannelParameters = BTCLightningChannelParameters
+
+@typing.final
+class BTCLightningChannelSetupRequest(google.protobuf.message.Message):
+    """Returns the basepoints of the channel. If `parameters` is set, they are stored for the channel,
+    which is required before any of its commitments can be signed. The parameters of a channel
+    can't be changed once stored, and have to be passed again with every commitment.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHANNEL_INDEX_FIELD_NUMBER: builtins.int
+    PARAMETERS_FIELD_NUMBER: builtins.int
+    channel_index: builtins.int
+    @property
+    def parameters(self) -> global___BTCLightningChannelParameters: ...
+    def __init__(
+        self,
+        *,
+        channel_index: builtins.int = ...,
+        parameters: global___BTCLightningChannelParameters | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["parameters", b"parameters"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["channel_index", b"channel_index", "parameters", b"parameters"]) -> None: ...
+
+global___BTCLightningChannelSetupRequest = BTCLightningChannelSetupRequest
+
+@typing.final
+class BTCLightningChannelSetupResponse(google.protobuf.message.Message):
+    """All fields are 33 bytes compressed public keys."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    FUNDING_PUBKEY_FIELD_NUMBER: builtins.int
+    REVOCATION_BASEPOINT_FIELD_NUMBER: builtins.int
+    PAYMENT_BASEPOINT_FIELD_NUMBER: builtins.int
+    DELAYED_PAYMENT_BASEPOINT_FIELD_NUMBER: builtins.int
+    HTLC_BASEPOINT_FIELD_NUMBER: builtins.int
+    funding_pubkey: builtins.bytes
+    revocation_basepoint: builtins.bytes
+    payment_basepoint: builtins.bytes
+    delayed_payment_basepoint: builtins.bytes
+    htlc_basepoint: builtins.bytes
+    def __init__(
+        self,
+        *,
+        funding_pubkey: builtins.bytes = ...,
+        revocation_basepoint: builtins.bytes = ...,
+        payment_basepoint: builtins.bytes = ...,
+        delayed_payment_basepoint: builtins.bytes = ...,
+        htlc_basepoint: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["delayed_payment_basepoint", b"delayed_payment_basepoint", "funding_pubkey", b"funding_pubkey", "htlc_basepoint", b"htlc_basepoint", "payment_basepoint", b"payment_basepoint", "revocation_basepoint", b"revocation_basepoint"]) -> None: ...
+
+global___BTCLightningChannelSetupResponse = BTCLightningChannelSetupResponse
+
+@typing.final
+class BTCLightningPerCommitmentPointRequest(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHANNEL_INDEX_FIELD_NUMBER: builtins.int
+    COMMITMENT_NUMBER_FIELD_NUMBER: builtins.int
+    channel_index: builtins.int
+    commitment_number: builtins.int
+    def __init__(
+        self,
+        *,
+        channel_index: builtins.int = ...,
+        commitment_number: builtins.int = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["channel_index", b"channel_index", "commitment_number", b"commitment_number"]) -> None: ...
+
+global___BTCLightningPerCommitmentPointRequest = BTCLightningPerCommitmentPointRequest
+
+@typing.final
+class BTCLightningPerCommitmentPointResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PER_COMMITMENT_POINT_FIELD_NUMBER: builtins.int
+    per_commitment_point: builtins.bytes
+    def __init__(
+        self,
+        *,
+        per_commitment_point: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["per_commitment_point", b"per_commitment_point"]) -> None: ...
+
+global___BTCLightningPerCommitmentPointResponse = BTCLightningPerCommitmentPointResponse
+
+@typing.final
+class BTCLightningRevokeRequest(google.protobuf.message.Message):
+    """Releases the per-commitment secret of a holder commitment, revoking it. Only holder commitments
+    older than the last validated one can be revoked.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHANNEL_INDEX_FIELD_NUMBER: builtins.int
+    COMMITMENT_NUMBER_FIELD_NUMBER: builtins.int
+    channel_index: builtins.int
+    commitment_number: builtins.int
+    def __init__(
+        self,
+        *,
+        channel_index: builtins.int = ...,
+        commitment_number: builtins.int = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["channel_index", b"channel_index", "commitment_number", b"commitment_number"]) -> None: ...
+
+global___BTCLightningRevokeRequest = BTCLightningRevokeRequest
+
+@typing.final
+class BTCLightningRevokeResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PER_COMMITMENT_SECRET_FIELD_NUMBER: builtins.int
+    per_commitment_secret: builtins.bytes
+    def __init__(
+        self,
+        *,
+        per_commitment_secret: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["per_commitment_secret", b"per_commitment_secret"]) -> None: ...
+
+global___BTCLightningRevokeResponse = BTCLightningRevokeResponse
+
+@typing.final
+class BTCLightningOutput(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    VALUE_FIELD_NUMBER: builtins.int
+    PUBKEY_SCRIPT_FIELD_NUMBER: builtins.int
+    value: builtins.int
+    pubkey_script: builtins.bytes
+    def __init__(
+        self,
+        *,
+        value: builtins.int = ...,
+        pubkey_script: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["pubkey_script", b"pubkey_script", "value", b"value"]) -> None: ...
+
+global___BTCLightningOutput = BTCLightningOutput
+
+@typing.final
+class BTCLightningCommitmentTx(google.protobuf.message.Message):
+    """Unsigned commitment transaction, spending the funding output. Its outputs can only be the
+    to_local and to_remote outputs of the commitment and HTLC outputs, see BOLT #3. The transactions
+    spending all HTLC outputs have to be passed along with the commitment.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    VERSION_FIELD_NUMBER: builtins.int
+    LOCKTIME_FIELD_NUMBER: builtins.int
+    FUNDING_TXID_FIELD_NUMBER: builtins.int
+    FUNDING_OUTPUT_INDEX_FIELD_NUMBER: builtins.int
+    FUNDING_VALUE_FIELD_NUMBER: builtins.int
+    SEQUENCE_FIELD_NUMBER: builtins.int
+    OUTPUTS_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_FUNDING_PUBKEY_FIELD_NUMBER: builtins.int
+    version: builtins.int
+    locktime: builtins.int
+    funding_txid: builtins.bytes
+    """In the byte order of the serialized transaction."""
+    funding_output_index: builtins.int
+    funding_value: builtins.int
+    sequence: builtins.int
+    counterparty_funding_pubkey: builtins.bytes
+    """33 bytes compressed public key."""
+    @property
+    def outputs(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___BTCLightningOutput]: ...
+    def __init__(
+        self,
+        *,
+        version: builtins.int = ...,
+        locktime: builtins.int = ...,
+        funding_txid: builtins.bytes = ...,
+        funding_output_index: builtins.int = ...,
+        funding_value: builtins.int = ...,
+        sequence: builtins.int = ...,
+        outputs: collections.abc.Iterable[global___BTCLightningOutput] | None = ...,
+        counterparty_funding_pubkey: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["counterparty_funding_pubkey", b"counterparty_funding_pubkey", "funding_output_index", b"funding_output_index", "funding_txid", b"funding_txid", "funding_value", b"funding_value", "locktime", b"locktime", "outputs", b"outputs", "sequence", b"sequence", "version", b"version"]) -> None: ...
+
+global___BTCLightningCommitmentTx = BTCLightningCommitmentTx
+
+@typing.final
+class BTCLightningHtlcTx(google.protobuf.message.Message):
+    """Unsigned HTLC-timeout or HTLC-success transaction, spending an HTLC output of the commitment
+    transaction. The script of the HTLC output, the locktime and the output script of the HTLC
+    transaction are derived from these fields and the keys of the commitment, see BOLT #3.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    COMMITMENT_OUTPUT_INDEX_FIELD_NUMBER: builtins.int
+    OFFERED_FIELD_NUMBER: builtins.int
+    PAYMENT_HASH_FIELD_NUMBER: builtins.int
+    CLTV_EXPIRY_FIELD_NUMBER: builtins.int
+    OUTPUT_VALUE_FIELD_NUMBER: builtins.int
+    commitment_output_index: builtins.int
+    offered: builtins.bool
+    """True if the HTLC is offered by the owner of the commitment, i.e. this is its HTLC-timeout
+    transaction. Otherwise, it is the HTLC-success transaction of an HTLC received by the owner.
+    """
+    payment_hash: builtins.bytes
+    """32 bytes SHA256 hash of the payment preimage."""
+    cltv_expiry: builtins.int
+    """Block height at which the HTLC times out."""
+    output_value: builtins.int
+    """Value of the output of the HTLC transaction, i.e. the value of the HTLC output minus the fee."""
+    def __init__(
+        self,
+        *,
+        commitment_output_index: builtins.int = ...,
+        offered: builtins.bool = ...,
+        payment_hash: builtins.bytes = ...,
+        cltv_expiry: builtins.int = ...,
+        output_value: builtins.int = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["cltv_expiry", b"cltv_expiry", "commitment_output_index", b"commitment_output_index", "offered", b"offered", "output_value", b"output_value", "payment_hash", b"payment_hash"]) -> None: ...
+
+global___BTCLightningHtlcTx = BTCLightningHtlcTx
+
+@typing.final
+class BTCLightningSignCounterpartyCommitmentRequest(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHANNEL_INDEX_FIELD_NUMBER: builtins.int
+    COMMITMENT_NUMBER_FIELD_NUMBER: builtins.int
+    PER_COMMITMENT_POINT_FIELD_NUMBER: builtins.int
+    TX_FIELD_NUMBER: builtins.int
+    HTLC_TXS_FIELD_NUMBER: builtins.int
+    PARAMETERS_FIELD_NUMBER: builtins.int
+    channel_index: builtins.int
+    commitment_number: builtins.int
+    per_commitment_point: builtins.bytes
+    """Per-commitment point of the counterparty for this commitment."""
+    @property
+    def tx(self) -> global___BTCLightningCommitmentTx: ...
+    @property
+    def htlc_txs(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___BTCLightningHtlcTx]: ...
+    @property
+    def parameters(self) -> global___BTCLightningChannelParameters: ...
+    def __init__(
+        self,
+        *,
+        channel_index: builtins.int = ...,
+        commitment_number: builtins.int = ...,
+        per_commitment_point: builtins.bytes = ...,
+        tx: global___BTCLightningCommitmentTx | None = ...,
+        htlc_txs: collections.abc.Iterable[global___BTCLightningHtlcTx] | None = ...,
+        parameters: global___BTCLightningChannelParameters | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["parameters", b"parameters", "tx", b"tx"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["channel_index", b"channel_index", "commitment_number", b"commitment_number", "htlc_txs", b"htlc_txs", "parameters", b"parameters", "per_commitment_point", b"per_commitment_point", "tx", b"tx"]) -> None: ...
+
+global___BTCLightningSignCounterpartyCommitmentRequest = BTCLightningSignCounterpartyCommitmentRequest
+
+@typing.final
+class BTCLightningValidateHolderCommitmentRequest(google.protobuf.message.Message):
+    """Validates a new holder commitment received from the counterparty, including the counterparty's
+    signature. Holder commitments are validated in order.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHANNEL_INDEX_FIELD_NUMBER: builtins.int
+    COMMITMENT_NUMBER_FIELD_NUMBER: builtins.int
+    TX_FIELD_NUMBER: builtins.int
+    HTLC_TXS_FIELD_NUMBER: builtins.int
+    PARAMETERS_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_SIGNATURE_FIELD_NUMBER: builtins.int
+    channel_index: builtins.int
+    commitment_number: builtins.int
+    counterparty_signature: builtins.bytes
+    """64 bytes (32 bytes big endian R, 32 bytes big endian S) signature of the counterparty."""
+    @property
+    def tx(self) -> global___BTCLightningCommitmentTx: ...
+    @property
+    def htlc_txs(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___BTCLightningHtlcTx]: ...
+    @property
+    def parameters(self) -> global___BTCLightningChannelParameters: ...
+    def __init__(
+        self,
+        *,
+        channel_index: builtins.int = ...,
+        commitment_number: builtins.int = ...,
+        tx: global___BTCLightningCommitmentTx | None = ...,
+        htlc_txs: collections.abc.Iterable[global___BTCLightningHtlcTx] | None = ...,
+        parameters: global___BTCLightningChannelParameters | None = ...,
+        counterparty_signature: builtins.bytes = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["parameters", b"parameters", "tx", b"tx"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["channel_index", b"channel_index", "commitment_number", b"commitment_number", "counterparty_signature", b"counterparty_signature", "htlc_txs", b"htlc_txs", "parameters", b"parameters", "tx", b"tx"]) -> None: ...
+
+global___BTCLightningValidateHolderCommitmentRequest = BTCLightningValidateHolderCommitmentRequest
+
+@typing.final
+class BTCLightningValidateHolderCommitmentResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    def __init__(
+        self,
+    ) -> None: ...
+
+global___BTCLightningValidateHolderCommitmentResponse = BTCLightningValidateHolderCommitmentResponse
+
+@typing.final
+class BTCLightningSignHolderCommitmentRequest(google.protobuf.message.Message):
+    """Signs a validated holder commitment for broadcasting it, force closing the channel."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHANNEL_INDEX_FIELD_NUMBER: builtins.int
+    COMMITMENT_NUMBER_FIELD_NUMBER: builtins.int
+    TX_FIELD_NUMBER: builtins.int
+    HTLC_TXS_FIELD_NUMBER: builtins.int
+    PARAMETERS_FIELD_NUMBER: builtins.int
+    channel_index: builtins.int
+    commitment_number: builtins.int
+    @property
+    def tx(self) -> global___BTCLightningCommitmentTx: ...
+    @property
+    def htlc_txs(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___BTCLightningHtlcTx]: ...
+    @property
+    def parameters(self) -> global___BTCLightningChannelParameters: ...
+    def __init__(
+        self,
+        *,
+        channel_index: builtins.int = ...,
+        commitment_number: builtins.int = ...,
+        tx: global___BTCLightningCommitmentTx | None = ...,
+        htlc_txs: collections.abc.Iterable[global___BTCLightningHtlcTx] | None = ...,
+        parameters: global___BTCLightningChannelParameters | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["parameters", b"parameters", "tx", b"tx"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["channel_index", b"channel_index", "commitment_number", b"commitment_number", "htlc_txs", b"htlc_txs", "parameters", b"parameters", "tx", b"tx"]) -> None: ...
+
+global___BTCLightningSignHolderCommitmentRequest = BTCLightningSignHolderCommitmentRequest
+
+@typing.final
+class BTCLightningSignCommitmentResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    SIGNATURE_FIELD_NUMBER: builtins.int
+    HTLC_SIGNATURES_FIELD_NUMBER: builtins.int
+    signature: builtins.bytes
+    """64 bytes (32 bytes big endian R, 32 bytes big endian S)."""
+    @property
+    def htlc_signatures(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.bytes]:
+        """One signature per HTLC transaction, in the same order."""
+
+    def __init__(
+        self,
+        *,
+        signature: builtins.bytes = ...,
+        htlc_signatures: collections.abc.Iterable[builtins.bytes] | None = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["htlc_signatures", b"htlc_signatures", "signature", b"signature"]) -> None: ...
+
+global___BTCLightningSignCommitmentResponse = BTCLightningSignCommitmentResponse
+
+@typing.final
+class BTCLightningForgetChannelsRequest(google.protobuf.message.Message):
+    """Frees the slots of all closed channels with an index below `below_channel_index`. Their indices
+    can't be set up again. Only forget channels whose closing transaction is deeply confirmed.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    BELOW_CHANNEL_INDEX_FIELD_NUMBER: builtins.int
+    below_channel_index: builtins.int
+    def __init__(
+        self,
+        *,
+        below_channel_index: builtins.int = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["below_channel_index", b"below_channel_index"]) -> None: ...
+
+global___BTCLightningForgetChannelsRequest = BTCLightningForgetChannelsRequest
+
+@typing.final
+class BTCLightningForgetChannelsResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    def __init__(
+        self,
+    ) -> None: ...
+
+global___BTCLightningForgetChannelsResponse = BTCLightningForgetChannelsResponse
+
+@typing.final
+class BTCLightningRequest(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    COIN_FIELD_NUMBER: builtins.int
+    NODE_ID_FIELD_NUMBER: builtins.int
+    ECDH_FIELD_NUMBER: builtins.int
+    CHANNEL_SETUP_FIELD_NUMBER: builtins.int
+    PER_COMMITMENT_POINT_FIELD_NUMBER: builtins.int
+    REVOKE_FIELD_NUMBER: builtins.int
+    SIGN_COUNTERPARTY_COMMITMENT_FIELD_NUMBER: builtins.int
+    SIGN_HOLDER_COMMITMENT_FIELD_NUMBER: builtins.int
+    VALIDATE_HOLDER_COMMITMENT_FIELD_NUMBER: builtins.int
+    FORGET_CHANNELS_FIELD_NUMBER: builtins.int
+    coin: global___BTCCoin.ValueType
+    @property
+    def node_id(self) -> global___BTCLightningNodeIdRequest: ...
+    @property
+    def ecdh(self) -> global___BTCLightningEcdhRequest: ...
+    @property
+    def channel_setup(self) -> global___BTCLightningChannelSetupRequest: ...
+    @property
+    def per_commitment_point(self) -> global___BTCLightningPerCommitmentPointRequest: ...
+    @property
+    def revoke(self) -> global___BTCLightningRevokeRequest: ...
+    @property
+    def sign_counterparty_commitment(self) -> global___BTCLightningSignCounterpartyCommitmentRequest: ...
+    @property
+    def sign_holder_commitment(self) -> global___BTCLightningSignHolderCommitmentRequest: ...
+    @property
+    def validate_holder_commitment(self) -> global___BTCLightningValidateHolderCommitmentRequest: ...
+    @property
+    def forget_channels(self) -> global___BTCLightningForgetChannelsRequest: ...
+    def __init__(
+        self,
+        *,
+        coin: global___BTCCoin.ValueType = ...,
+        node_id: global___BTCLightningNodeIdRequest | None = ...,
+        ecdh: global___BTCLightningEcdhRequest | None = ...,
+        channel_setup: global___BTCLightningChannelSetupRequest | None = ...,
+        per_commitment_point: global___BTCLightningPerCommitmentPointRequest | None = ...,
+        revoke: global___BTCLightningRevokeRequest | None = ...,
+        sign_counterparty_commitment: global___BTCLightningSignCounterpartyCommitmentRequest | None = ...,
+        sign_holder_commitment: global___BTCLightningSignHolderCommitmentRequest | None = ...,
+        validate_holder_commitment: global___BTCLightningValidateHolderCommitmentRequest | None = ...,
+        forget_channels: global___BTCLightningForgetChannelsRequest | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["channel_setup", b"channel_setup", "ecdh", b"ecdh", "forget_channels", b"forget_channels", "node_id", b"node_id", "per_commitment_point", b"per_commitment_point", "request", b"request", "revoke", b"revoke", "sign_counterparty_commitment", b"sign_counterparty_commitment", "sign_holder_commitment", b"sign_holder_commitment", "validate_holder_commitment", b"validate_holder_commitment"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["channel_setup", b"channel_setup", "coin", b"coin", "ecdh", b"ecdh", "forget_channels", b"forget_channels", "node_id", b"node_id", "per_commitment_point", b"per_commitment_point", "request", b"request", "revoke", b"revoke", "sign_counterparty_commitment", b"sign_counterparty_commitment", "sign_holder_commitment", b"sign_holder_commitment", "validate_holder_commitment", b"validate_holder_commitment"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["node_id", "ecdh", "channel_setup", "per_commitment_point", "revoke", "sign_counterparty_commitment", "sign_holder_commitment", "validate_holder_commitment", "forget_channels"] | None: ...
+
+global___BTCLightningRequest = BTCLightningRequest
+
+@typing.final
+class BTCLightningResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    NODE_ID_FIELD_NUMBER: builtins.int
+    ECDH_FIELD_NUMBER: builtins.int
+    CHANNEL_SETUP_FIELD_NUMBER: builtins.int
+    PER_COMMITMENT_POINT_FIELD_NUMBER: builtins.int
+    REVOKE_FIELD_NUMBER: builtins.int
+    SIGN_COMMITMENT_FIELD_NUMBER: builtins.int
+    VALIDATE_HOLDER_COMMITMENT_FIELD_NUMBER: builtins.int
+    FORGET_CHANNELS_FIELD_NUMBER: builtins.int
+    @property
+    def node_id(self) -> global___BTCLightningNodeIdResponse: ...
+    @property
+    def ecdh(self) -> global___BTCLightningEcdhResponse: ...
+    @property
+    def channel_setup(self) -> global___BTCLightningChannelSetupResponse: ...
+    @property
+    def per_commitment_point(self) -> global___BTCLightningPerCommitmentPointResponse: ...
+    @property
+    def revoke(self) -> global___BTCLightningRevokeResponse: ...
+    @property
+    def sign_commitment(self) -> global___BTCLightningSignCommitmentResponse: ...
+    @property
+    def validate_holder_commitment(self) -> global___BTCLightningValidateHolderCommitmentResponse: ...
+    @property
+    def forget_channels(self) -> global___BTCLightningForgetChannelsResponse: ...
+    def __init__(
+        self,
+        *,
+        node_id: global___BTCLightningNodeIdResponse | None = ...,
+        ecdh: global___BTCLightningEcdhResponse | None = ...,
+        channel_setup: global___BTCLightningChannelSetupResponse | None = ...,
+        per_commitment_point: global___BTCLightningPerCommitmentPointResponse | None = ...,
+        revoke: global___BTCLightningRevokeResponse | None = ...,
+        sign_commitment: global___BTCLightningSignCommitmentResponse | None = ...,
+        validate_holder_commitment: global___BTCLightningValidateHolderCommitmentResponse | None = ...,
+        forget_channels: global___BTCLightningForgetChannelsResponse | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["channel_setup", b"channel_setup", "ecdh", b"ecdh", "forget_channels", b"forget_channels", "node_id", b"node_id", "per_commitment_point", b"per_commitment_point", "response", b"response", "revoke", b"revoke", "sign_commitment", b"sign_commitment", "validate_holder_commitment", b"validate_holder_commitment"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["channel_setup", b"channel_setup", "ecdh", b"ecdh", "forget_channels", b"forget_channels", "node_id", b"node_id", "per_commitment_point", b"per_commitment_point", "response", b"response", "revoke", b"revoke", "sign_commitment", b"sign_commitment", "validate_holder_commitment", b"validate_holder_commitment"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["node_id", "ecdh", "channel_setup", "per_commitment_point", "revoke", "sign_commitment", "validate_holder_commitment", "forget_channels"] | None: ...
+
+global___BTCLightningResponse = BTCLightningResponse
+
 @typing.final
 class BTCRequest(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -852,6 +1445,7 @@ class BTCRequest(google.protobuf.message.Message):
     ANTIKLEPTO_SIGNATURE_FIELD_NUMBER: builtins.int
     PAYMENT_REQUEST_FIELD_NUMBER: builtins.int
     XPUBS_FIELD_NUMBER: builtins.int
+    LIGHTNING_FIELD_NUMBER: builtins.int
     @property
     def is_script_config_registered(self) -> global___BTCIsScriptConfigRegisteredRequest: ...
     @property
@@ -870,6 +1464,8 @@ class BTCRequest(google.protobuf.message.Message):
     def payment_request(self) -> global___BTCPaymentRequestRequest: ...
     @property
     def xpubs(self) -> global___BTCXpubsRequest: ...
+    @property
+    def lightning(self) -> global___BTCLightningRequest: ...
     def __init__(
         self,
         *,
@@ -882,10 +1478,11 @@ class BTCRequest(google.protobuf.message.Message):
         antiklepto_signature: antiklepto_pb2.AntiKleptoSignatureRequest | None = ...,
         payment_request: global___BTCPaymentRequestRequest | None = ...,
         xpubs: global___BTCXpubsRequest | None = ...,
+        lightning: global___BTCLightningRequest | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "is_script_config_registered", b"is_script_config_registered", "payment_request", b"payment_request", "prevtx_init", b"prevtx_init", "prevtx_input", b"prevtx_input", "prevtx_output", b"prevtx_output", "register_script_config", b"register_script_config", "request", b"request", "sign_message", b"sign_message", "xpubs", b"xpubs"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "is_script_config_registered", b"is_script_config_registered", "payment_request", b"payment_request", "prevtx_init", b"prevtx_init", "prevtx_input", b"prevtx_input", "prevtx_output", b"prevtx_output", "register_script_config", b"register_script_config", "request", b"request", "sign_message", b"sign_message", "xpubs", b"xpubs"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["is_script_config_registered", "register_script_config", "prevtx_init", "prevtx_input", "prevtx_output", "sign_message", "antiklepto_signature", "payment_request", "xpubs"] | None: ...
+    def HasField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "is_script_config_registered", b"is_script_config_registered", "lightning", b"lightning", "payment_request", b"payment_request", "prevtx_init", b"prevtx_init", "prevtx_input", b"prevtx_input", "prevtx_output", b"prevtx_output", "register_script_config", b"register_script_config", "request", b"request", "sign_message", b"sign_message", "xpubs", b"xpubs"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "is_script_config_registered", b"is_script_config_registered", "lightning", b"lightning", "payment_request", b"payment_request", "prevtx_init", b"prevtx_init", "prevtx_input", b"prevtx_input", "prevtx_output", b"prevtx_output", "register_script_config", b"register_script_config", "request", b"request", "sign_message", b"sign_message", "xpubs", b"xpubs"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["is_script_config_registered", "register_script_config", "prevtx_init", "prevtx_input", "prevtx_output", "sign_message", "antiklepto_signature", "payment_request", "xpubs", "lightning"] | None: ...
 
 global___BTCRequest = BTCRequest
 
@@ -899,6 +1496,7 @@ class BTCResponse(google.protobuf.message.Message):
     SIGN_MESSAGE_FIELD_NUMBER: builtins.int
     ANTIKLEPTO_SIGNER_COMMITMENT_FIELD_NUMBER: builtins.int
     PUBS_FIELD_NUMBER: builtins.int
+    LIGHTNING_FIELD_NUMBER: builtins.int
     @property
     def success(self) -> global___BTCSuccess: ...
     @property
@@ -911,6 +1509,8 @@ class BTCResponse(google.protobuf.message.Message):
     def antiklepto_signer_commitment(self) -> antiklepto_pb2.AntiKleptoSignerCommitment: ...
     @property
     def pubs(self) -> common_pb2.PubsResponse: ...
+    @property
+    def lightning(self) -> global___BTCLightningResponse: ...
     def __init__(
         self,
         *,
@@ -920,9 +1520,10 @@ class BTCResponse(google.protobuf.message.Message):
         sign_message: global___BTCSignMessageResponse | None = ...,
         antiklepto_signer_commitment: antiklepto_pb2.AntiKleptoSignerCommitment | None = ...,
         pubs: common_pb2.PubsResponse | None = ...,
+        lightning: global___BTCLightningResponse | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "is_script_config_registered", b"is_script_config_registered", "pubs", b"pubs", "response", b"response", "sign_message", b"sign_message", "sign_next", b"sign_next", "success", b"success"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "is_script_config_registered", b"is_script_config_registered", "pubs", b"pubs", "response", b"response", "sign_message", b"sign_message", "sign_next", b"sign_next", "success", b"success"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["success", "is_script_config_registered", "sign_next", "sign_message", "antiklepto_signer_commitment", "pubs"] | None: ...
+    def HasField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "is_script_config_registered", b"is_script_config_registered", "lightning", b"lightning", "pubs", b"pubs", "response", b"response", "sign_message", b"sign_message", "sign_next", b"sign_next", "success", b"success"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "is_script_config_registered", b"is_script_config_registered", "lightning", b"lightning", "pubs", b"pubs", "response", b"response", "sign_message", b"sign_message", "sign_next", b"sign_next", "success", b"success"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["success", "is_script_config_registered", "sign_next", "sign_message", "antiklepto_signer_commitment", "pubs", "lightning"] | None: ...
 
 global___BTCResponse = BTCResponse
@@ -14,51 +14,117 @@ _sym_db = _symbol_database.Default()
 from . import common_pb2 as common__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\rcardano.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\"F\n\x13\x43\x61rdanoXpubsRequest\x12/\n\x08keypaths\x18\x01 \x03(\x0b\x32\x1d.shiftcrypto.bitbox02.Keypath\"%\n\x14\x43\x61rdanoXpubsResponse\x12\r\n\x05xpubs\x18\x01 \x03(\x0c\"\x9e\x01\n\x13\x43\x61rdanoScriptConfig\x12\x43\n\x07pkh_skh\x18\x01 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.CardanoScriptConfig.PkhSkhH\x00\x1a\x38\n\x06PkhSkh\x12\x17\n\x0fkeypath_payment\x18\x01 \x03(\r\x12\x15\n\rkeypath_stake\x18\x02 \x03(\rB\x08\n\x06\x63onfig\"\xa1\x01\n\x15\x43\x61rdanoAddressRequest\x12\x35\n\x07network\x18\x01 \x01(\x0e\x32$.shiftcrypto.bitbox02.CardanoNetwork\x12\x0f\n\x07\x64isplay\x18\x02 \x01(\x08\x12@\n\rscript_config\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoScriptConfig\"\xb0\r\n\x1d\x43\x61rdanoSignTransactionRequest\x12\x35\n\x07network\x18\x01 \x01(\x0e\x32$.shiftcrypto.bitbox02.CardanoNetwork\x12I\n\x06inputs\x18\x02 \x03(\x0b\x32\x39.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Input\x12K\n\x07outputs\x18\x03 \x03(\x0b\x32:.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Output\x12\x0b\n\x03\x66\x65\x65\x18\x04 \x01(\x04\x12\x0b\n\x03ttl\x18\x05 \x01(\x04\x12U\n\x0c\x63\x65rtificates\x18\x06 \x03(\x0b\x32?.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate\x12S\n\x0bwithdrawals\x18\x07 \x03(\x0b\x32>.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Withdrawal\x12\x1f\n\x17validity_interval_start\x18\x08 \x01(\x04\x12\x16\n\x0e\x61llow_zero_ttl\x18\t \x01(\x08\x12\x15\n\rtag_cbor_sets\x18\n \x01(\x08\x1aG\n\x05Input\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x15\n\rprev_out_hash\x18\x02 \x01(\x0c\x12\x16\n\x0eprev_out_index\x18\x03 \x01(\r\x1a\xa1\x01\n\nAssetGroup\x12\x11\n\tpolicy_id\x18\x01 \x01(\x0c\x12T\n\x06tokens\x18\x02 \x03(\x0b\x32\x44.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AssetGroup.Token\x1a*\n\x05Token\x12\x12\n\nasset_name\x18\x01 \x01(\x0c\x12\r\n\x05value\x18\x02 \x01(\x04\x1a\xc8\x01\n\x06Output\x12\x17\n\x0f\x65ncoded_address\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\x04\x12@\n\rscript_config\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoScriptConfig\x12T\n\x0c\x61sset_groups\x18\x04 \x03(\x0b\x32>.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AssetGroup\x1a\xc3\x05\n\x0b\x43\x65rtificate\x12;\n\x12stake_registration\x18\x01 \x01(\x0b\x32\x1d.shiftcrypto.bitbox02.KeypathH\x00\x12=\n\x14stake_deregistration\x18\x02 \x01(\x0b\x32\x1d.shiftcrypto.bitbox02.KeypathH\x00\x12k\n\x10stake_delegation\x18\x03 \x01(\x0b\x32O.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.StakeDelegationH\x00\x12i\n\x0fvote_delegation\x18\n \x01(\x0b\x32N.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.VoteDelegationH\x00\x1a\x38\n\x0fStakeDelegation\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x14\n\x0cpool_keyhash\x18\x02 \x01(\x0c\x1a\x9d\x02\n\x0eVoteDelegation\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12l\n\x04type\x18\x02 \x01(\x0e\x32^.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.VoteDelegation.CardanoDRepType\x12\x1a\n\rdrep_credhash\x18\x03 \x01(\x0cH\x00\x88\x01\x01\"^\n\x0f\x43\x61rdanoDRepType\x12\x0c\n\x08KEY_HASH\x10\x00\x12\x0f\n\x0bSCRIPT_HASH\x10\x01\x12\x12\n\x0e\x41LWAYS_ABSTAIN\x10\x02\x12\x18\n\x14\x41LWAYS_NO_CONFIDENCE\x10\x03\x42\x10\n\x0e_drep_credhashB\x06\n\x04\x63\x65rt\x1a,\n\nWithdrawal\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\r\n\x05value\x18\x02 \x01(\x04\"\xb9\x01\n\x1e\x43\x61rdanoSignTransactionResponse\x12^\n\x11shelley_witnesses\x18\x01 \x03(\x0b\x32\x43.shiftcrypto.bitbox02.CardanoSignTransactionResponse.ShelleyWitness\x1a\x37\n\x0eShelleyWitness\x12\x12\n\npublic_key\x18\x01 \x01(\x0c\x12\x11\n\tsignature\x18\x02 \x01(\x0c\"\xe8\x01\n\x0e\x43\x61rdanoRequest\x12:\n\x05xpubs\x18\x01 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoXpubsRequestH\x00\x12>\n\x07\x61\x64\x64ress\x18\x02 \x01(\x0b\x32+.shiftcrypto.bitbox02.CardanoAddressRequestH\x00\x12O\n\x10sign_transaction\x18\x03 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.CardanoSignTransactionRequestH\x00\x42\t\n\x07request\"\xde\x01\n\x0f\x43\x61rdanoResponse\x12;\n\x05xpubs\x18\x01 \x01(\x0b\x32*.shiftcrypto.bitbox02.CardanoXpubsResponseH\x00\x12\x30\n\x03pub\x18\x02 \x01(\x0b\x32!.shiftcrypto.bitbox02.PubResponseH\x00\x12P\n\x10sign_transaction\x18\x03 \x01(\x0b\x32\x34.shiftcrypto.bitbox02.CardanoSignTransactionResponseH\x00\x42\n\n\x08response*8\n\x0e\x43\x61rdanoNetwork\x12\x12\n\x0e\x43\x61rdanoMainnet\x10\x00\x12\x12\n\x0e\x43\x61rdanoTestnet\x10\x01\x62\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\rcardano.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\"\x8c\x01\n\x13\x43\x61rdanoXpubsRequest\x12/\n\x08keypaths\x18\x01 \x03(\x0b\x32\x1d.shiftcrypto.bitbox02.Keypath\x12\x44\n\x0f\x64\x65rivation_type\x18\x02 \x01(\x0e\x32+.shiftcrypto.bitbox02.CardanoDerivationType\"%\n\x14\x43\x61rdanoXpubsResponse\x12\r\n\x05xpubs\x18\x01 \x03(\x0c\"\xa3\x04\n\x13\x43\x61rdanoNativeScript\x12\x42\n\x06pubkey\x18\x01 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.CardanoNativeScript.PubKeyH\x00\x12@\n\x03\x61ll\x18\x02 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.CardanoNativeScript.ScriptsH\x00\x12@\n\x03\x61ny\x18\x03 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.CardanoNativeScript.ScriptsH\x00\x12@\n\x06n_of_k\x18\x04 \x01(\x0b\x32..shiftcrypto.bitbox02.CardanoNativeScript.NOfKH\x00\x12\x18\n\x0einvalid_before\x18\x05 \x01(\x04H\x00\x12\x1b\n\x11invalid_hereafter\x18\x06 \x01(\x04H\x00\x1a+\n\x06PubKey\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x10\n\x08key_hash\x18\x02 \x01(\x0c\x1a\x45\n\x07Scripts\x12:\n\x07scripts\x18\x01 \x03(\x0b\x32).shiftcrypto.bitbox02.CardanoNativeScript\x1aM\n\x04NOfK\x12\t\n\x01n\x18\x01 \x01(\r\x12:\n\x07scripts\x18\x02 \x03(\x0b\x32).shiftcrypto.bitbox02.CardanoNativeScriptB\x08\n\x06script\"\xb8\x06\n\x13\x43\x61rdanoScriptConfig\x12\x43\n\x07pkh_skh\x18\x01 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.CardanoScriptConfig.PkhSkhH\x00\x12Q\n\x0enative_scripts\x18\x02 \x01(\x0b\x32\x37.shiftcrypto.bitbox02.CardanoScriptConfig.NativeScriptsH\x00\x12<\n\x03pkh\x18\x03 \x01(\x0b\x32-.shiftcrypto.bitbox02.CardanoScriptConfig.PkhH\x00\x12<\n\x03skh\x18\x04 \x01(\x0b\x32-.shiftcrypto.bitbox02.CardanoScriptConfig.SkhH\x00\x12K\n\x0bpkh_pointer\x18\x05 \x01(\x0b\x32\x34.shiftcrypto.bitbox02.CardanoScriptConfig.PkhPointerH\x00\x1a\x38\n\x06PkhSkh\x12\x17\n\x0fkeypath_payment\x18\x01 \x03(\r\x12\x15\n\rkeypath_stake\x18\x02 \x03(\r\x1a\x1e\n\x03Pkh\x12\x17\n\x0fkeypath_payment\x18\x01 \x03(\r\x1a\x1c\n\x03Skh\x12\x15\n\rkeypath_stake\x18\x01 \x03(\r\x1a=\n\x07Pointer\x12\x0c\n\x04slot\x18\x01 \x01(\x04\x12\x10\n\x08tx_index\x18\x02 \x01(\x04\x12\x12\n\ncert_index\x18\x03 \x01(\x04\x1ai\n\nPkhPointer\x12\x17\n\x0fkeypath_payment\x18\x01 \x03(\r\x12\x42\n\x07pointer\x18\x02 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.CardanoScriptConfig.Pointer\x1a\x93\x01\n\rNativeScripts\x12\x41\n\x0epayment_script\x18\x01 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoNativeScript\x12?\n\x0cstake_script\x18\x02 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoNativeScriptB\x08\n\x06\x63onfig\"\xe0\x01\n\x1f\x43\x61rdanoScriptConfigRegistration\x12\x35\n\x07network\x18\x01 \x01(\x0e\x32$.shiftcrypto.bitbox02.CardanoNetwork\x12@\n\rscript_config\x18\x02 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoScriptConfig\x12\x44\n\x0f\x64\x65rivation_type\x18\x03 \x01(\x0e\x32+.shiftcrypto.bitbox02.CardanoDerivationType\"u\n&CardanoIsScriptConfigRegisteredRequest\x12K\n\x0cregistration\x18\x01 \x01(\x0b\x32\x35.shiftcrypto.bitbox02.CardanoScriptConfigRegistration\"@\n\'CardanoIsScriptConfigRegisteredResponse\x12\x15\n\ris_registered\x18\x01 \x01(\x08\"\x7f\n\"CardanoRegisterScriptConfigRequest\x12K\n\x0cregistration\x18\x01 \x01(\x0b\x32\x35.shiftcrypto.bitbox02.CardanoScriptConfigRegistration\x12\x0c\n\x04name\x18\x02 \x01(\t\"\x10\n\x0e\x43\x61rdanoSuccess\"\xe7\x01\n\x15\x43\x61rdanoAddressRequest\x12\x35\n\x07network\x18\x01 \x01(\x0e\x32$.shiftcrypto.bitbox02.CardanoNetwork\x12\x0f\n\x07\x64isplay\x18\x02 \x01(\x08\x12@\n\rscript_config\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoScriptConfig\x12\x44\n\x0f\x64\x65rivation_type\x18\x04 \x01(\x0e\x32+.shiftcrypto.bitbox02.CardanoDerivationType\"\xa8(\n\x1d\x43\x61rdanoSignTransactionRequest\x12\x35\n\x07network\x18\x01 \x01(\x0e\x32$.shiftcrypto.bitbox02.CardanoNetwork\x12I\n\x06inputs\x18\x02 \x03(\x0b\x32\x39.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Input\x12K\n\x07outputs\x18\x03 \x03(\x0b\x32:.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Output\x12\x0b\n\x03\x66\x65\x65\x18\x04 \x01(\x04\x12\x0b\n\x03ttl\x18\x05 \x01(\x04\x12U\n\x0c\x63\x65rtificates\x18\x06 \x03(\x0b\x32?.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate\x12S\n\x0bwithdrawals\x18\x07 \x03(\x0b\x32>.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Withdrawal\x12\x1f\n\x17validity_interval_start\x18\x08 \x01(\x04\x12\x16\n\x0e\x61llow_zero_ttl\x18\t \x01(\x08\x12\x15\n\rtag_cbor_sets\x18\n \x01(\x08\x12Y\n\x0e\x61uxiliary_data\x18\x0b \x01(\x0b\x32\x41.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AuxiliaryData\x12P\n\x04mint\x18\x0c \x03(\x0b\x32\x42.shiftcrypto.bitbox02.CardanoSignTransactionRequest.MintAssetGroup\x12\x36\n\x0fpolicy_keypaths\x18\r \x03(\x0b\x32\x1d.shiftcrypto.bitbox02.Keypath\x12\x18\n\x10script_data_hash\x18\x0e \x01(\x0c\x12T\n\x11\x63ollateral_inputs\x18\x0f \x03(\x0b\x32\x39.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Input\x12U\n\x11\x63ollateral_return\x18\x10 \x01(\x0b\x32:.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Output\x12\x1d\n\x10total_collateral\x18\x11 \x01(\x04H\x00\x88\x01\x01\x12\\\n\x10required_signers\x18\x12 \x03(\x0b\x32\x42.shiftcrypto.bitbox02.CardanoSignTransactionRequest.RequiredSigner\x12S\n\x10reference_inputs\x18\x13 \x03(\x0b\x32\x39.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Input\x12_\n\x11voting_procedures\x18\x14 \x01(\x0b\x32\x44.shiftcrypto.bitbox02.CardanoSignTransactionRequest.VotingProcedures\x12\x44\n\x0f\x64\x65rivation_type\x18\x15 \x01(\x0e\x32+.shiftcrypto.bitbox02.CardanoDerivationType\x1aG\n\x05Input\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x15\n\rprev_out_hash\x18\x02 \x01(\x0c\x12\x16\n\x0eprev_out_index\x18\x03 \x01(\r\x1a\xa1\x01\n\nAssetGroup\x12\x11\n\tpolicy_id\x18\x01 \x01(\x0c\x12T\n\x06tokens\x18\x02 \x03(\x0b\x32\x44.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AssetGroup.Token\x1a*\n\x05Token\x12\x12\n\nasset_name\x18\x01 \x01(\x0c\x12\r\n\x05value\x18\x02 \x01(\x04\x1a\xa9\x01\n\x0eMintAssetGroup\x12\x11\n\tpolicy_id\x18\x01 \x01(\x0c\x12X\n\x06tokens\x18\x02 \x03(\x0b\x32H.shiftcrypto.bitbox02.CardanoSignTransactionRequest.MintAssetGroup.Token\x1a*\n\x05Token\x12\x12\n\nasset_name\x18\x01 \x01(\x0c\x12\r\n\x05value\x18\x02 \x01(\x12\x1a\xf2\x01\n\x06Output\x12\x17\n\x0f\x65ncoded_address\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\x04\x12@\n\rscript_config\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoScriptConfig\x12T\n\x0c\x61sset_groups\x18\x04 \x03(\x0b\x32>.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AssetGroup\x12\x12\n\ndatum_hash\x18\x05 \x01(\x0c\x12\x14\n\x0cinline_datum\x18\x06 \x01(\x0c\x1a(\n\x06\x41nchor\x12\x0b\n\x03url\x18\x01 \x01(\t\x12\x11\n\tdata_hash\x18\x02 \x01(\x0c\x1a\xd0\x0f\n\x0b\x43\x65rtificate\x12;\n\x12stake_registration\x18\x01 \x01(\x0b\x32\x1d.shiftcrypto.bitbox02.KeypathH\x00\x12=\n\x14stake_deregistration\x18\x02 \x01(\x0b\x32\x1d.shiftcrypto.bitbox02.KeypathH\x00\x12k\n\x10stake_delegation\x18\x03 \x01(\x0b\x32O.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.StakeDelegationH\x00\x12i\n\x0fvote_delegation\x18\n \x01(\x0b\x32N.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.VoteDelegationH\x00\x12t\n\x1astake_registration_deposit\x18\x0b \x01(\x0b\x32N.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.KeypathDepositH\x00\x12v\n\x1cstake_deregistration_deposit\x18\x0c \x01(\x0b\x32N.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.KeypathDepositH\x00\x12t\n\x15stake_vote_delegation\x18\r \x01(\x0b\x32S.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.StakeVoteDelegationH\x00\x12m\n\x11\x64rep_registration\x18\x0e \x01(\x0b\x32P.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.DRepRegistrationH\x00\x12m\n\x13\x64rep_deregistration\x18\x0f \x01(\x0b\x32N.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.KeypathDepositH\x00\x12\x61\n\x0b\x64rep_update\x18\x10 \x01(\x0b\x32J.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.DRepUpdateH\x00\x1aZ\n\x0fStakeDelegation\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x14\n\x0cpool_keyhash\x18\x02 \x01(\x0c\x12\x14\n\x07\x64\x65posit\x18\x03 \x01(\x04H\x00\x88\x01\x01\x42\n\n\x08_deposit\x1a\xbf\x02\n\x0eVoteDelegation\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12l\n\x04type\x18\x02 \x01(\x0e\x32^.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.VoteDelegation.CardanoDRepType\x12\x1a\n\rdrep_credhash\x18\x03 \x01(\x0cH\x00\x88\x01\x01\x12\x14\n\x07\x64\x65posit\x18\x04 \x01(\x04H\x01\x88\x01\x01\"^\n\x0f\x43\x61rdanoDRepType\x12\x0c\n\x08KEY_HASH\x10\x00\x12\x0f\n\x0bSCRIPT_HASH\x10\x01\x12\x12\n\x0e\x41LWAYS_ABSTAIN\x10\x02\x12\x18\n\x14\x41LWAYS_NO_CONFIDENCE\x10\x03\x42\x10\n\x0e_drep_credhashB\n\n\x08_deposit\x1a\xff\x01\n\x13StakeVoteDelegation\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x14\n\x0cpool_keyhash\x18\x02 \x01(\x0c\x12q\n\tdrep_type\x18\x03 \x01(\x0e\x32^.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Certificate.VoteDelegation.CardanoDRepType\x12\x1a\n\rdrep_credhash\x18\x04 \x01(\x0cH\x00\x88\x01\x01\x12\x14\n\x07\x64\x65posit\x18\x05 \x01(\x04H\x01\x88\x01\x01\x42\x10\n\x0e_drep_credhashB\n\n\x08_deposit\x1a\x32\n\x0eKeypathDeposit\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x0f\n\x07\x64\x65posit\x18\x02 \x01(\x04\x1a\x80\x01\n\x10\x44RepRegistration\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x0f\n\x07\x64\x65posit\x18\x02 \x01(\x04\x12J\n\x06\x61nchor\x18\x03 \x01(\x0b\x32:.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Anchor\x1ai\n\nDRepUpdate\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12J\n\x06\x61nchor\x18\x02 \x01(\x0b\x32:.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AnchorB\x06\n\x04\x63\x65rt\x1a\x97\x03\n\x10VotingProcedures\x12\x14\n\x0c\x64rep_keypath\x18\x01 \x03(\r\x12X\n\x05votes\x18\x02 \x03(\x0b\x32I.shiftcrypto.bitbox02.CardanoSignTransactionRequest.VotingProcedures.Vote\x1a\x92\x02\n\x04Vote\x12\x1a\n\x12gov_action_tx_hash\x18\x01 \x01(\x0c\x12\x18\n\x10gov_action_index\x18\x02 \x01(\r\x12`\n\x06\x63hoice\x18\x03 \x01(\x0e\x32P.shiftcrypto.bitbox02.CardanoSignTransactionRequest.VotingProcedures.Vote.Choice\x12J\n\x06\x61nchor\x18\x04 \x01(\x0b\x32:.shiftcrypto.bitbox02.CardanoSignTransactionRequest.Anchor\"&\n\x06\x43hoice\x12\x06\n\x02NO\x10\x00\x12\x07\n\x03YES\x10\x01\x12\x0b\n\x07\x41\x42STAIN\x10\x02\x1a,\n\nWithdrawal\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\r\n\x05value\x18\x02 \x01(\x04\x1a\xdf\x04\n\rAuxiliaryData\x12\x0e\n\x04hash\x18\x01 \x01(\x0cH\x00\x12g\n\rcip20_message\x18\x02 \x01(\x0b\x32N.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AuxiliaryData.CIP20MessageH\x00\x12q\n\x12\x63ip36_registration\x18\x03 \x01(\x0b\x32S.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AuxiliaryData.CIP36RegistrationH\x00\x1a\x1d\n\x0c\x43IP20Message\x12\r\n\x05lines\x18\x01 \x03(\t\x1a\xba\x02\n\x11\x43IP36Registration\x12s\n\x0b\x64\x65legations\x18\x01 \x03(\x0b\x32^.shiftcrypto.bitbox02.CardanoSignTransactionRequest.AuxiliaryData.CIP36Registration.Delegation\x12\x15\n\rstake_keypath\x18\x02 \x03(\r\x12\x42\n\x0fpayment_address\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoScriptConfig\x12\r\n\x05nonce\x18\x04 \x01(\x04\x12\x16\n\x0evoting_purpose\x18\x05 \x01(\x04\x1a.\n\nDelegation\x12\x10\n\x08vote_key\x18\x01 \x01(\x0c\x12\x0e\n\x06weight\x18\x02 \x01(\rB\x06\n\x04\x64\x61ta\x1a\x33\n\x0eRequiredSigner\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x10\n\x08key_hash\x18\x02 \x01(\x0c\x42\x13\n\x11_total_collateral\"\xfb\x02\n\x1e\x43\x61rdanoSignTransactionResponse\x12^\n\x11shelley_witnesses\x18\x01 \x03(\x0b\x32\x43.shiftcrypto.bitbox02.CardanoSignTransactionResponse.ShelleyWitness\x12o\n\x19\x61uxiliary_data_supplement\x18\x02 \x01(\x0b\x32L.shiftcrypto.bitbox02.CardanoSignTransactionResponse.AuxiliaryDataSupplement\x1a\x37\n\x0eShelleyWitness\x12\x12\n\npublic_key\x18\x01 \x01(\x0c\x12\x11\n\tsignature\x18\x02 \x01(\x0c\x1aO\n\x17\x41uxiliaryDataSupplement\x12\x1b\n\x13\x61uxiliary_data_hash\x18\x01 \x01(\x0c\x12\x17\n\x0f\x63ip36_signature\x18\x02 \x01(\x0c\"\x82\x02\n\x19\x43\x61rdanoSignMessageRequest\x12\x35\n\x07network\x18\x01 \x01(\x0e\x32$.shiftcrypto.bitbox02.CardanoNetwork\x12@\n\rscript_config\x18\x02 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoScriptConfig\x12\x15\n\rstake_keypath\x18\x03 \x03(\r\x12\x0f\n\x07payload\x18\x04 \x01(\x0c\x12\x44\n\x0f\x64\x65rivation_type\x18\x05 \x01(\x0e\x32+.shiftcrypto.bitbox02.CardanoDerivationType\"<\n\x1a\x43\x61rdanoSignMessageResponse\x12\x11\n\tsignature\x18\x01 \x01(\x0c\x12\x0b\n\x03key\x18\x02 \x01(\x0c\"\xf2\x03\n\x0e\x43\x61rdanoRequest\x12:\n\x05xpubs\x18\x01 \x01(\x0b\x32).shiftcrypto.bitbox02.CardanoXpubsRequestH\x00\x12>\n\x07\x61\x64\x64ress\x18\x02 \x01(\x0b\x32+.shiftcrypto.bitbox02.CardanoAddressRequestH\x00\x12O\n\x10sign_transaction\x18\x03 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.CardanoSignTransactionRequestH\x00\x12\x63\n\x1bis_script_config_registered\x18\x04 \x01(\x0b\x32<.shiftcrypto.bitbox02.CardanoIsScriptConfigRegisteredRequestH\x00\x12Z\n\x16register_script_config\x18\x05 \x01(\x0b\x32\x38.shiftcrypto.bitbox02.CardanoRegisterScriptConfigRequestH\x00\x12G\n\x0csign_message\x18\x06 \x01(\x0b\x32/.shiftcrypto.bitbox02.CardanoSignMessageRequestH\x00\x42\t\n\x07request\"\xc7\x03\n\x0f\x43\x61rdanoResponse\x12;\n\x05xpubs\x18\x01 \x01(\x0b\x32*.shiftcrypto.bitbox02.CardanoXpubsResponseH\x00\x12\x30\n\x03pub\x18\x02 \x01(\x0b\x32!.shiftcrypto.bitbox02.PubResponseH\x00\x12P\n\x10sign_transaction\x18\x03 \x01(\x0b\x32\x34.shiftcrypto.bitbox02.CardanoSignTransactionResponseH\x00\x12\x64\n\x1bis_script_config_registered\x18\x04 \x01(\x0b\x32=.shiftcrypto.bitbox02.CardanoIsScriptConfigRegisteredResponseH\x00\x12\x37\n\x07success\x18\x05 \x01(\x0b\x32$.shiftcrypto.bitbox02.CardanoSuccessH\x00\x12H\n\x0csign_message\x18\x06 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.CardanoSignMessageResponseH\x00\x42\n\n\x08response*{\n\x15\x43\x61rdanoDerivationType\x12\x1d\n\x19\x43\x41RDANO_DERIVATION_LEDGER\x10\x00\x12\x1d\n\x19\x43\x41RDANO_DERIVATION_ICARUS\x10\x01\x12$\n CARDANO_DERIVATION_ICARUS_TREZOR\x10\x02*8\n\x0e\x43\x61rdanoNetwork\x12\x12\n\x0e\x43\x61rdanoMainnet\x10\x00\x12\x12\n\x0e\x43\x61rdanoTestnet\x10\x01\x62\x06proto3')
 
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'cardano_pb2', globals())
 if _descriptor._USE_C_DESCRIPTORS == False:
 
   DESCRIPTOR._options = None
-  _CARDANONETWORK._serialized_start=2852
-  _CARDANONETWORK._serialized_end=2908
-  _CARDANOXPUBSREQUEST._serialized_start=53
-  _CARDANOXPUBSREQUEST._serialized_end=123
-  _CARDANOXPUBSRESPONSE._serialized_start=125
-  _CARDANOXPUBSRESPONSE._serialized_end=162
-  _CARDANOSCRIPTCONFIG._serialized_start=165
-  _CARDANOSCRIPTCONFIG._serialized_end=323
-  _CARDANOSCRIPTCONFIG_PKHSKH._serialized_start=257
-  _CARDANOSCRIPTCONFIG_PKHSKH._serialized_end=313
-  _CARDANOADDRESSREQUEST._serialized_start=326
-  _CARDANOADDRESSREQUEST._serialized_end=487
-  _CARDANOSIGNTRANSACTIONREQUEST._serialized_start=490
-  _CARDANOSIGNTRANSACTIONREQUEST._serialized_end=2202
-  _CARDANOSIGNTRANSACTIONREQUEST_INPUT._serialized_start=1008
-  _CARDANOSIGNTRANSACTIONREQUEST_INPUT._serialized_end=1079
-  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP._serialized_start=1082
-  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP._serialized_end=1243
-  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP_TOKEN._serialized_start=1201
-  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP_TOKEN._serialized_end=1243
-  _CARDANOSIGNTRANSACTIONREQUEST_OUTPUT._serialized_start=1246
-  _CARDANOSIGNTRANSACTIONREQUEST_OUTPUT._serialized_end=1446
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE._serialized_start=1449
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE._serialized_end=2156
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_STAKEDELEGATION._serialized_start=1804
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_STAKEDELEGATION._serialized_end=1860
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION._serialized_start=1863
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION._serialized_end=2148
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION_CARDANODREPTYPE._serialized_start=2036
-  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION_CARDANODREPTYPE._serialized_end=2130
-  _CARDANOSIGNTRANSACTIONREQUEST_WITHDRAWAL._serialized_start=2158
-  _CARDANOSIGNTRANSACTIONREQUEST_WITHDRAWAL._serialized_end=2202
-  _CARDANOSIGNTRANSACTIONRESPONSE._serialized_start=2205
-  _CARDANOSIGNTRANSACTIONRESPONSE._serialized_end=2390
-  _CARDANOSIGNTRANSACTIONRESPONSE_SHELLEYWITNESS._serialized_start=2335
-  _CARDANOSIGNTRANSACTIONRESPONSE_SHELLEYWITNESS._serialized_end=2390
-  _CARDANOREQUEST._serialized_start=2393
-  _CARDANOREQUEST._serialized_end=2625
-  _CARDANORESPONSE._serialized_start=2628
-  _CARDANORESPONSE._serialized_end=2850
+  _CARDANODERIVATIONTYPE._serialized_start=9232
+  _CARDANODERIVATIONTYPE._serialized_end=9355
+  _CARDANONETWORK._serialized_start=9357
+  _CARDANONETWORK._serialized_end=9413
+  _CARDANOXPUBSREQUEST._serialized_start=54
+  _CARDANOXPUBSREQUEST._serialized_end=194
+  _CARDANOXPUBSRESPONSE._serialized_start=196
+  _CARDANOXPUBSRESPONSE._serialized_end=233
+  _CARDANONATIVESCRIPT._serialized_start=236
+  _CARDANONATIVESCRIPT._serialized_end=783
+  _CARDANONATIVESCRIPT_PUBKEY._serialized_start=580
+  _CARDANONATIVESCRIPT_PUBKEY._serialized_end=623
+  _CARDANONATIVESCRIPT_SCRIPTS._serialized_start=625
+  _CARDANONATIVESCRIPT_SCRIPTS._serialized_end=694
+  _CARDANONATIVESCRIPT_NOFK._serialized_start=696
+  _CARDANONATIVESCRIPT_NOFK._serialized_end=773
+  _CARDANOSCRIPTCONFIG._serialized_start=786
+  _CARDANOSCRIPTCONFIG._serialized_end=1610
+  _CARDANOSCRIPTCONFIG_PKHSKH._serialized_start=1162
+  _CARDANOSCRIPTCONFIG_PKHSKH._serialized_end=1218
+  _CARDANOSCRIPTCONFIG_PKH._serialized_start=1220
+  _CARDANOSCRIPTCONFIG_PKH._serialized_end=1250
+  _CARDANOSCRIPTCONFIG_SKH._serialized_start=1252
+  _CARDANOSCRIPTCONFIG_SKH._serialized_end=1280
+  _CARDANOSCRIPTCONFIG_POINTER._serialized_start=1282
+  _CARDANOSCRIPTCONFIG_POINTER._serialized_end=1343
+  _CARDANOSCRIPTCONFIG_PKHPOINTER._serialized_start=1345
+  _CARDANOSCRIPTCONFIG_PKHPOINTER._serialized_end=1450
+  _CARDANOSCRIPTCONFIG_NATIVESCRIPTS._serialized_start=1453
+  _CARDANOSCRIPTCONFIG_NATIVESCRIPTS._serialized_end=1600
+  _CARDANOSCRIPTCONFIGREGISTRATION._serialized_start=1613
+  _CARDANOSCRIPTCONFIGREGISTRATION._serialized_end=1837
+  _CARDANOISSCRIPTCONFIGREGISTEREDREQUEST._serialized_start=1839
+  _CARDANOISSCRIPTCONFIGREGISTEREDREQUEST._serialized_end=1956
+  _CARDANOISSCRIPTCONFIGREGISTEREDRESPONSE._serialized_start=1958
+  _CARDANOISSCRIPTCONFIGREGISTEREDRESPONSE._serialized_end=2022
+  _CARDANOREGISTERSCRIPTCONFIGREQUEST._serialized_start=2024
+  _CARDANOREGISTERSCRIPTCONFIGREQUEST._serialized_end=2151
+  _CARDANOSUCCESS._serialized_start=2153
+  _CARDANOSUCCESS._serialized_end=2169
+  _CARDANOADDRESSREQUEST._serialized_start=2172
+  _CARDANOADDRESSREQUEST._serialized_end=2403
+  _CARDANOSIGNTRANSACTIONREQUEST._serialized_start=2406
+  _CARDANOSIGNTRANSACTIONREQUEST._serialized_end=7566
+  _CARDANOSIGNTRANSACTIONREQUEST_INPUT._serialized_start=3729
+  _CARDANOSIGNTRANSACTIONREQUEST_INPUT._serialized_end=3800
+  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP._serialized_start=3803
+  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP._serialized_end=3964
+  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP_TOKEN._serialized_start=3922
+  _CARDANOSIGNTRANSACTIONREQUEST_ASSETGROUP_TOKEN._serialized_end=3964
+  _CARDANOSIGNTRANSACTIONREQUEST_MINTASSETGROUP._serialized_start=3967
+  _CARDANOSIGNTRANSACTIONREQUEST_MINTASSETGROUP._serialized_end=4136
+  _CARDANOSIGNTRANSACTIONREQUEST_MINTASSETGROUP_TOKEN._serialized_start=4094
+  _CARDANOSIGNTRANSACTIONREQUEST_MINTASSETGROUP_TOKEN._serialized_end=4136
+  _CARDANOSIGNTRANSACTIONREQUEST_OUTPUT._serialized_start=4139
+  _CARDANOSIGNTRANSACTIONREQUEST_OUTPUT._serialized_end=4381
+  _CARDANOSIGNTRANSACTIONREQUEST_ANCHOR._serialized_start=4383
+  _CARDANOSIGNTRANSACTIONREQUEST_ANCHOR._serialized_end=4423
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE._serialized_start=4426
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE._serialized_end=6426
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_STAKEDELEGATION._serialized_start=5458
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_STAKEDELEGATION._serialized_end=5548
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION._serialized_start=5551
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION._serialized_end=5870
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION_CARDANODREPTYPE._serialized_start=5746
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_VOTEDELEGATION_CARDANODREPTYPE._serialized_end=5840
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_STAKEVOTEDELEGATION._serialized_start=5873
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_STAKEVOTEDELEGATION._serialized_end=6128
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_KEYPATHDEPOSIT._serialized_start=6130
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_KEYPATHDEPOSIT._serialized_end=6180
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_DREPREGISTRATION._serialized_start=6183
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_DREPREGISTRATION._serialized_end=6311
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_DREPUPDATE._serialized_start=6313
+  _CARDANOSIGNTRANSACTIONREQUEST_CERTIFICATE_DREPUPDATE._serialized_end=6418
+  _CARDANOSIGNTRANSACTIONREQUEST_VOTINGPROCEDURES._serialized_start=6429
+  _CARDANOSIGNTRANSACTIONREQUEST_VOTINGPROCEDURES._serialized_end=6836
+  _CARDANOSIGNTRANSACTIONREQUEST_VOTINGPROCEDURES_VOTE._serialized_start=6562
+  _CARDANOSIGNTRANSACTIONREQUEST_VOTINGPROCEDURES_VOTE._serialized_end=6836
+  _CARDANOSIGNTRANSACTIONREQUEST_VOTINGPROCEDURES_VOTE_CHOICE._serialized_start=6798
+  _CARDANOSIGNTRANSACTIONREQUEST_VOTINGPROCEDURES_VOTE_CHOICE._serialized_end=6836
+  _CARDANOSIGNTRANSACTIONREQUEST_WITHDRAWAL._serialized_start=6838
+  _CARDANOSIGNTRANSACTIONREQUEST_WITHDRAWAL._serialized_end=6882
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA._serialized_start=6885
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA._serialized_end=7492
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA_CIP20MESSAGE._serialized_start=7138
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA_CIP20MESSAGE._serialized_end=7167
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA_CIP36REGISTRATION._serialized_start=7170
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA_CIP36REGISTRATION._serialized_end=7484
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA_CIP36REGISTRATION_DELEGATION._serialized_start=7438
+  _CARDANOSIGNTRANSACTIONREQUEST_AUXILIARYDATA_CIP36REGISTRATION_DELEGATION._serialized_end=7484
+  _CARDANOSIGNTRANSACTIONREQUEST_REQUIREDSIGNER._serialized_start=7494
+  _CARDANOSIGNTRANSACTIONREQUEST_REQUIREDSIGNER._serialized_end=7545
+  _CARDANOSIGNTRANSACTIONRESPONSE._serialized_start=7569
+  _CARDANOSIGNTRANSACTIONRESPONSE._serialized_end=7948
+  _CARDANOSIGNTRANSACTIONRESPONSE_SHELLEYWITNESS._serialized_start=7812
+  _CARDANOSIGNTRANSACTIONRESPONSE_SHELLEYWITNESS._serialized_end=7867
+  _CARDANOSIGNTRANSACTIONRESPONSE_AUXILIARYDATASUPPLEMENT._serialized_start=7869
+  _CARDANOSIGNTRANSACTIONRESPONSE_AUXILIARYDATASUPPLEMENT._serialized_end=7948
+  _CARDANOSIGNMESSAGEREQUEST._serialized_start=7951
+  _CARDANOSIGNMESSAGEREQUEST._serialized_end=8209
+  _CARDANOSIGNMESSAGERESPONSE._serialized_start=8211
+  _CARDANOSIGNMESSAGERESPONSE._serialized_end=8271
+  _CARDANOREQUEST._serialized_start=8274
+  _CARDANOREQUEST._serialized_end=8772
+  _CARDANORESPONSE._serialized_start=8775
+  _CARDANORESPONSE._serialized_end=9230
 # @@protoc_insertion_point(module_scope)
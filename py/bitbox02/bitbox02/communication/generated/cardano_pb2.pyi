@@ -20,6 +20,32 @@ else:
 
 DESCRIPTOR: google.protobuf.descriptor.FileDescriptor
 
+class _CardanoDerivationType:
+    ValueType = typing.NewType("ValueType", builtins.int)
+    V: typing_extensions.TypeAlias = ValueType
+
+class _CardanoDerivationTypeEnumTypeWrapper(google.protobuf.internal.enum_type_wrapper._EnumTypeWrapper[_CardanoDerivationType.ValueType], builtins.type):
+    DESCRIPTOR: google.protobuf.descriptor.EnumDescriptor
+    CARDANO_DERIVATION_LEDGER: _CardanoDerivationType.ValueType  # 0
+    """Compatible with Ledger."""
+    CARDANO_DERIVATION_ICARUS: _CardanoDerivationType.ValueType  # 1
+    """Compatible with most software wallets, e.g. Yoroi and Eternl."""
+    CARDANO_DERIVATION_ICARUS_TREZOR: _CardanoDerivationType.ValueType  # 2
+    """Same as Icarus, except for 24 word mnemonics. Compatible with Trezor."""
+
+class CardanoDerivationType(_CardanoDerivationType, metaclass=_CardanoDerivationTypeEnumTypeWrapper):
+    """Derivation of the root key from the BIP-39 mnemonic, see
+    https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0003/CIP-0003.md
+    """
+
+CARDANO_DERIVATION_LEDGER: CardanoDerivationType.ValueType  # 0
+"""Compatible with Ledger."""
+CARDANO_DERIVATION_ICARUS: CardanoDerivationType.ValueType  # 1
+"""Compatible with most software wallets, e.g. Yoroi and Eternl."""
+CARDANO_DERIVATION_ICARUS_TREZOR: CardanoDerivationType.ValueType  # 2
+"""Same as Icarus, except for 24 word mnemonics. Compatible with Trezor."""
+global___CardanoDerivationType = CardanoDerivationType
+
 class _CardanoNetwork:
     ValueType = typing.NewType("ValueType", builtins.int)
     V: typing_extensions.TypeAlias = ValueType
@@ -40,14 +66,17 @@ class CardanoXpubsRequest(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
 
     KEYPATHS_FIELD_NUMBER: builtins.int
+    DERIVATION_TYPE_FIELD_NUMBER: builtins.int
+    derivation_type: global___CardanoDerivationType.ValueType
     @property
     def keypaths(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[common_pb2.Keypath]: ...
     def __init__(
         self,
         *,
         keypaths: collections.abc.Iterable[common_pb2.Keypath] | None = ...,
+        derivation_type: global___CardanoDerivationType.ValueType = ...,
     ) -> None: ...
-    def ClearField(self, field_name: typing.Literal["keypaths", b"keypaths"]) -> None: ...
+    def ClearField(self, field_name: typing.Literal["derivation_type", b"derivation_type", "keypaths", b"keypaths"]) -> None: ...
 
 global___CardanoXpubsRequest = CardanoXpubsRequest
 
@@ -67,6 +96,103 @@ class CardanoXpubsResponse(google.protobuf.message.Message):
 
 global___CardanoXpubsResponse = CardanoXpubsResponse
 
+@typing.final
+class CardanoNativeScript(google.protobuf.message.Message):
+    """Native script, used for multisig wallets according to CIP-1854.
+    See https://github.com/cardano-foundation/CIPs/blob/master/CIP-1854/README.md and
+    https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/allegra/impl/cddl-files/allegra.cddl
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    @typing.final
+    class PubKey(google.protobuf.message.Message):
+        """Exactly one of the fields must be set."""
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        KEYPATH_FIELD_NUMBER: builtins.int
+        KEY_HASH_FIELD_NUMBER: builtins.int
+        key_hash: builtins.bytes
+        """Key hash of a cosigner.
+        28 bytes
+        """
+        @property
+        def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]:
+            """Key of this wallet, m/1854'/1815'/account'/role/index."""
+
+        def __init__(
+            self,
+            *,
+            keypath: collections.abc.Iterable[builtins.int] | None = ...,
+            key_hash: builtins.bytes = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["key_hash", b"key_hash", "keypath", b"keypath"]) -> None: ...
+
+    @typing.final
+    class Scripts(google.protobuf.message.Message):
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        SCRIPTS_FIELD_NUMBER: builtins.int
+        @property
+        def scripts(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoNativeScript]: ...
+        def __init__(
+            self,
+            *,
+            scripts: collections.abc.Iterable[global___CardanoNativeScript] | None = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["scripts", b"scripts"]) -> None: ...
+
+    @typing.final
+    class NOfK(google.protobuf.message.Message):
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        N_FIELD_NUMBER: builtins.int
+        SCRIPTS_FIELD_NUMBER: builtins.int
+        n: builtins.int
+        @property
+        def scripts(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoNativeScript]: ...
+        def __init__(
+            self,
+            *,
+            n: builtins.int = ...,
+            scripts: collections.abc.Iterable[global___CardanoNativeScript] | None = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["n", b"n", "scripts", b"scripts"]) -> None: ...
+
+    PUBKEY_FIELD_NUMBER: builtins.int
+    ALL_FIELD_NUMBER: builtins.int
+    ANY_FIELD_NUMBER: builtins.int
+    N_OF_K_FIELD_NUMBER: builtins.int
+    INVALID_BEFORE_FIELD_NUMBER: builtins.int
+    INVALID_HEREAFTER_FIELD_NUMBER: builtins.int
+    invalid_before: builtins.int
+    """Slot numbers."""
+    invalid_hereafter: builtins.int
+    @property
+    def pubkey(self) -> global___CardanoNativeScript.PubKey: ...
+    @property
+    def all(self) -> global___CardanoNativeScript.Scripts: ...
+    @property
+    def any(self) -> global___CardanoNativeScript.Scripts: ...
+    @property
+    def n_of_k(self) -> global___CardanoNativeScript.NOfK: ...
+    def __init__(
+        self,
+        *,
+        pubkey: global___CardanoNativeScript.PubKey | None = ...,
+        all: global___CardanoNativeScript.Scripts | None = ...,
+        any: global___CardanoNativeScript.Scripts | None = ...,
+        n_of_k: global___CardanoNativeScript.NOfK | None = ...,
+        invalid_before: builtins.int = ...,
+        invalid_hereafter: builtins.int = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["all", b"all", "any", b"any", "invalid_before", b"invalid_before", "invalid_hereafter", b"invalid_hereafter", "n_of_k", b"n_of_k", "pubkey", b"pubkey", "script", b"script"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["all", b"all", "any", b"any", "invalid_before", b"invalid_before", "invalid_hereafter", b"invalid_hereafter", "n_of_k", b"n_of_k", "pubkey", b"pubkey", "script", b"script"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["script", b"script"]) -> typing.Literal["pubkey", "all", "any", "n_of_k", "invalid_before", "invalid_hereafter"] | None: ...
+
+global___CardanoNativeScript = CardanoNativeScript
+
 @typing.final
 class CardanoScriptConfig(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -89,22 +215,227 @@ class CardanoScriptConfig(google.protobuf.message.Message):
         ) -> None: ...
         def ClearField(self, field_name: typing.Literal["keypath_payment", b"keypath_payment", "keypath_stake", b"keypath_stake"]) -> None: ...
 
+    @typing.final
+    class Pkh(google.protobuf.message.Message):
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        KEYPATH_PAYMENT_FIELD_NUMBER: builtins.int
+        @property
+        def keypath_payment(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+        def __init__(
+            self,
+            *,
+            keypath_payment: collections.abc.Iterable[builtins.int] | None = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["keypath_payment", b"keypath_payment"]) -> None: ...
+
+    @typing.final
+    class Skh(google.protobuf.message.Message):
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        KEYPATH_STAKE_FIELD_NUMBER: builtins.int
+        @property
+        def keypath_stake(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+        def __init__(
+            self,
+            *,
+            keypath_stake: collections.abc.Iterable[builtins.int] | None = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["keypath_stake", b"keypath_stake"]) -> None: ...
+
+    @typing.final
+    class Pointer(google.protobuf.message.Message):
+        """Refers to the stake registration certificate at the given position in the chain."""
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        SLOT_FIELD_NUMBER: builtins.int
+        TX_INDEX_FIELD_NUMBER: builtins.int
+        CERT_INDEX_FIELD_NUMBER: builtins.int
+        slot: builtins.int
+        tx_index: builtins.int
+        cert_index: builtins.int
+        def __init__(
+            self,
+            *,
+            slot: builtins.int = ...,
+            tx_index: builtins.int = ...,
+            cert_index: builtins.int = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["cert_index", b"cert_index", "slot", b"slot", "tx_index", b"tx_index"]) -> None: ...
+
+    @typing.final
+    class PkhPointer(google.protobuf.message.Message):
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        KEYPATH_PAYMENT_FIELD_NUMBER: builtins.int
+        POINTER_FIELD_NUMBER: builtins.int
+        @property
+        def keypath_payment(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+        @property
+        def pointer(self) -> global___CardanoScriptConfig.Pointer: ...
+        def __init__(
+            self,
+            *,
+            keypath_payment: collections.abc.Iterable[builtins.int] | None = ...,
+            pointer: global___CardanoScriptConfig.Pointer | None = ...,
+        ) -> None: ...
+        def HasField(self, field_name: typing.Literal["pointer", b"pointer"]) -> builtins.bool: ...
+        def ClearField(self, field_name: typing.Literal["keypath_payment", b"keypath_payment", "pointer", b"pointer"]) -> None: ...
+
+    @typing.final
+    class NativeScripts(google.protobuf.message.Message):
+        """Payment script using role 0 keys and stake script using role 2 keys. If there is no stake
+        script, it is an enterprise address without staking rights.
+        """
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        PAYMENT_SCRIPT_FIELD_NUMBER: builtins.int
+        STAKE_SCRIPT_FIELD_NUMBER: builtins.int
+        @property
+        def payment_script(self) -> global___CardanoNativeScript: ...
+        @property
+        def stake_script(self) -> global___CardanoNativeScript: ...
+        def __init__(
+            self,
+            *,
+            payment_script: global___CardanoNativeScript | None = ...,
+            stake_script: global___CardanoNativeScript | None = ...,
+        ) -> None: ...
+        def HasField(self, field_name: typing.Literal["payment_script", b"payment_script", "stake_script", b"stake_script"]) -> builtins.bool: ...
+        def ClearField(self, field_name: typing.Literal["payment_script", b"payment_script", "stake_script", b"stake_script"]) -> None: ...
+
     PKH_SKH_FIELD_NUMBER: builtins.int
+    NATIVE_SCRIPTS_FIELD_NUMBER: builtins.int
+    PKH_FIELD_NUMBER: builtins.int
+    SKH_FIELD_NUMBER: builtins.int
+    PKH_POINTER_FIELD_NUMBER: builtins.int
     @property
     def pkh_skh(self) -> global___CardanoScriptConfig.PkhSkh:
         """Shelley PaymentKeyHash & StakeKeyHash"""
 
+    @property
+    def native_scripts(self) -> global___CardanoScriptConfig.NativeScripts:
+        """Shelley ScriptHash & ScriptHash, or ScriptHash only. Must be registered using
+        `CardanoRegisterScriptConfigRequest`.
+        """
+
+    @property
+    def pkh(self) -> global___CardanoScriptConfig.Pkh:
+        """Shelley PaymentKeyHash only (enterprise address)"""
+
+    @property
+    def skh(self) -> global___CardanoScriptConfig.Skh:
+        """Shelley StakeKeyHash (reward address). Not a payment address, so it can't be used as a
+        transaction output.
+        """
+
+    @property
+    def pkh_pointer(self) -> global___CardanoScriptConfig.PkhPointer:
+        """Shelley PaymentKeyHash & Pointer"""
+
     def __init__(
         self,
         *,
         pkh_skh: global___CardanoScriptConfig.PkhSkh | None = ...,
+        native_scripts: global___CardanoScriptConfig.NativeScripts | None = ...,
+        pkh: global___CardanoScriptConfig.Pkh | None = ...,
+        skh: global___CardanoScriptConfig.Skh | None = ...,
+        pkh_pointer: global___CardanoScriptConfig.PkhPointer | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["config", b"config", "pkh_skh", b"pkh_skh"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["config", b"config", "pkh_skh", b"pkh_skh"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["config", b"config"]) -> typing.Literal["pkh_skh"] | None: ...
+    def HasField(self, field_name: typing.Literal["config", b"config", "native_scripts", b"native_scripts", "pkh", b"pkh", "pkh_pointer", b"pkh_pointer", "pkh_skh", b"pkh_skh", "skh", b"skh"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["config", b"config", "native_scripts", b"native_scripts", "pkh", b"pkh", "pkh_pointer", b"pkh_pointer", "pkh_skh", b"pkh_skh", "skh", b"skh"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["config", b"config"]) -> typing.Literal["pkh_skh", "native_scripts", "pkh", "skh", "pkh_pointer"] | None: ...
 
 global___CardanoScriptConfig = CardanoScriptConfig
 
+@typing.final
+class CardanoScriptConfigRegistration(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    NETWORK_FIELD_NUMBER: builtins.int
+    SCRIPT_CONFIG_FIELD_NUMBER: builtins.int
+    DERIVATION_TYPE_FIELD_NUMBER: builtins.int
+    network: global___CardanoNetwork.ValueType
+    derivation_type: global___CardanoDerivationType.ValueType
+    @property
+    def script_config(self) -> global___CardanoScriptConfig: ...
+    def __init__(
+        self,
+        *,
+        network: global___CardanoNetwork.ValueType = ...,
+        script_config: global___CardanoScriptConfig | None = ...,
+        derivation_type: global___CardanoDerivationType.ValueType = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["script_config", b"script_config"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["derivation_type", b"derivation_type", "network", b"network", "script_config", b"script_config"]) -> None: ...
+
+global___CardanoScriptConfigRegistration = CardanoScriptConfigRegistration
+
+@typing.final
+class CardanoIsScriptConfigRegisteredRequest(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    REGISTRATION_FIELD_NUMBER: builtins.int
+    @property
+    def registration(self) -> global___CardanoScriptConfigRegistration: ...
+    def __init__(
+        self,
+        *,
+        registration: global___CardanoScriptConfigRegistration | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["registration", b"registration"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["registration", b"registration"]) -> None: ...
+
+global___CardanoIsScriptConfigRegisteredRequest = CardanoIsScriptConfigRegisteredRequest
+
+@typing.final
+class CardanoIsScriptConfigRegisteredResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    IS_REGISTERED_FIELD_NUMBER: builtins.int
+    is_registered: builtins.bool
+    def __init__(
+        self,
+        *,
+        is_registered: builtins.bool = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["is_registered", b"is_registered"]) -> None: ...
+
+global___CardanoIsScriptConfigRegisteredResponse = CardanoIsScriptConfigRegisteredResponse
+
+@typing.final
+class CardanoRegisterScriptConfigRequest(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    REGISTRATION_FIELD_NUMBER: builtins.int
+    NAME_FIELD_NUMBER: builtins.int
+    name: builtins.str
+    """If empty, the name is entered on the device instead."""
+    @property
+    def registration(self) -> global___CardanoScriptConfigRegistration: ...
+    def __init__(
+        self,
+        *,
+        registration: global___CardanoScriptConfigRegistration | None = ...,
+        name: builtins.str = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["registration", b"registration"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["name", b"name", "registration", b"registration"]) -> None: ...
+
+global___CardanoRegisterScriptConfigRequest = CardanoRegisterScriptConfigRequest
+
+@typing.final
+class CardanoSuccess(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    def __init__(
+        self,
+    ) -> None: ...
+
+global___CardanoSuccess = CardanoSuccess
+
 @typing.final
 class CardanoAddressRequest(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -112,8 +443,10 @@ class CardanoAddressRequest(google.protobuf.message.Message):
     NETWORK_FIELD_NUMBER: builtins.int
     DISPLAY_FIELD_NUMBER: builtins.int
     SCRIPT_CONFIG_FIELD_NUMBER: builtins.int
+    DERIVATION_TYPE_FIELD_NUMBER: builtins.int
     network: global___CardanoNetwork.ValueType
     display: builtins.bool
+    derivation_type: global___CardanoDerivationType.ValueType
     @property
     def script_config(self) -> global___CardanoScriptConfig: ...
     def __init__(
@@ -122,9 +455,10 @@ class CardanoAddressRequest(google.protobuf.message.Message):
         network: global___CardanoNetwork.ValueType = ...,
         display: builtins.bool = ...,
         script_config: global___CardanoScriptConfig | None = ...,
+        derivation_type: global___CardanoDerivationType.ValueType = ...,
     ) -> None: ...
     def HasField(self, field_name: typing.Literal["script_config", b"script_config"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["display", b"display", "network", b"network", "script_config", b"script_config"]) -> None: ...
+    def ClearField(self, field_name: typing.Literal["derivation_type", b"derivation_type", "display", b"display", "network", b"network", "script_config", b"script_config"]) -> None: ...
 
 global___CardanoAddressRequest = CardanoAddressRequest
 
@@ -149,7 +483,11 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
         prev_out_hash: builtins.bytes
         prev_out_index: builtins.int
         @property
-        def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+        def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]:
+            """Empty for inputs not spent by this wallet, i.e. reference inputs and inputs locked by a
+            script (only allowed if `script_data_hash` is set).
+            """
+
         def __init__(
             self,
             *,
@@ -195,6 +533,44 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
         ) -> None: ...
         def ClearField(self, field_name: typing.Literal["policy_id", b"policy_id", "tokens", b"tokens"]) -> None: ...
 
+    @typing.final
+    class MintAssetGroup(google.protobuf.message.Message):
+        """Native tokens minted or burned under one policy.
+        See https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl#L501
+        """
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        @typing.final
+        class Token(google.protobuf.message.Message):
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            ASSET_NAME_FIELD_NUMBER: builtins.int
+            VALUE_FIELD_NUMBER: builtins.int
+            asset_name: builtins.bytes
+            value: builtins.int
+            """Positive to mint, negative to burn. Must not be zero."""
+            def __init__(
+                self,
+                *,
+                asset_name: builtins.bytes = ...,
+                value: builtins.int = ...,
+            ) -> None: ...
+            def ClearField(self, field_name: typing.Literal["asset_name", b"asset_name", "value", b"value"]) -> None: ...
+
+        POLICY_ID_FIELD_NUMBER: builtins.int
+        TOKENS_FIELD_NUMBER: builtins.int
+        policy_id: builtins.bytes
+        @property
+        def tokens(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.MintAssetGroup.Token]: ...
+        def __init__(
+            self,
+            *,
+            policy_id: builtins.bytes = ...,
+            tokens: collections.abc.Iterable[global___CardanoSignTransactionRequest.MintAssetGroup.Token] | None = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["policy_id", b"policy_id", "tokens", b"tokens"]) -> None: ...
+
     @typing.final
     class Output(google.protobuf.message.Message):
         DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -203,11 +579,22 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
         VALUE_FIELD_NUMBER: builtins.int
         SCRIPT_CONFIG_FIELD_NUMBER: builtins.int
         ASSET_GROUPS_FIELD_NUMBER: builtins.int
+        DATUM_HASH_FIELD_NUMBER: builtins.int
+        INLINE_DATUM_FIELD_NUMBER: builtins.int
         encoded_address: builtins.str
         value: builtins.int
+        datum_hash: builtins.bytes
+        """At most one of `datum_hash` and `inline_datum` can be set. If one is set, the output is
+        serialized in the post-Alonzo map format, otherwise in the legacy array format.
+        32 bytes
+        """
+        inline_datum: builtins.bytes
+        """CBOR encoded Plutus data."""
         @property
         def script_config(self) -> global___CardanoScriptConfig:
-            """Optional. If provided, this is validated as a change output."""
+            """Optional. If provided, this is validated as a change output. Enterprise and pointer
+            addresses are not accepted as change.
+            """
 
         @property
         def asset_groups(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.AssetGroup]: ...
@@ -218,9 +605,33 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
             value: builtins.int = ...,
             script_config: global___CardanoScriptConfig | None = ...,
             asset_groups: collections.abc.Iterable[global___CardanoSignTransactionRequest.AssetGroup] | None = ...,
+            datum_hash: builtins.bytes = ...,
+            inline_datum: builtins.bytes = ...,
         ) -> None: ...
         def HasField(self, field_name: typing.Literal["script_config", b"script_config"]) -> builtins.bool: ...
-        def ClearField(self, field_name: typing.Literal["asset_groups", b"asset_groups", "encoded_address", b"encoded_address", "script_config", b"script_config", "value", b"value"]) -> None: ...
+        def ClearField(self, field_name: typing.Literal["asset_groups", b"asset_groups", "datum_hash", b"datum_hash", "encoded_address", b"encoded_address", "inline_datum", b"inline_datum", "script_config", b"script_config", "value", b"value"]) -> None: ...
+
+    @typing.final
+    class Anchor(google.protobuf.message.Message):
+        """Governance metadata anchor.
+        See https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl
+        """
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        URL_FIELD_NUMBER: builtins.int
+        DATA_HASH_FIELD_NUMBER: builtins.int
+        url: builtins.str
+        """At most 128 bytes."""
+        data_hash: builtins.bytes
+        """32 bytes"""
+        def __init__(
+            self,
+            *,
+            url: builtins.str = ...,
+            data_hash: builtins.bytes = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["data_hash", b"data_hash", "url", b"url"]) -> None: ...
 
     @typing.final
     class Certificate(google.protobuf.message.Message):
@@ -234,7 +645,10 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
 
             KEYPATH_FIELD_NUMBER: builtins.int
             POOL_KEYHASH_FIELD_NUMBER: builtins.int
+            DEPOSIT_FIELD_NUMBER: builtins.int
             pool_keyhash: builtins.bytes
+            deposit: builtins.int
+            """If set, the stake key is registered in the same certificate (stake_reg_deleg_cert)."""
             @property
             def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
             def __init__(
@@ -242,8 +656,11 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
                 *,
                 keypath: collections.abc.Iterable[builtins.int] | None = ...,
                 pool_keyhash: builtins.bytes = ...,
+                deposit: builtins.int | None = ...,
             ) -> None: ...
-            def ClearField(self, field_name: typing.Literal["keypath", b"keypath", "pool_keyhash", b"pool_keyhash"]) -> None: ...
+            def HasField(self, field_name: typing.Literal["_deposit", b"_deposit", "deposit", b"deposit"]) -> builtins.bool: ...
+            def ClearField(self, field_name: typing.Literal["_deposit", b"_deposit", "deposit", b"deposit", "keypath", b"keypath", "pool_keyhash", b"pool_keyhash"]) -> None: ...
+            def WhichOneof(self, oneof_group: typing.Literal["_deposit", b"_deposit"]) -> typing.Literal["deposit"] | None: ...
 
         @typing.final
         class VoteDelegation(google.protobuf.message.Message):
@@ -269,8 +686,11 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
             KEYPATH_FIELD_NUMBER: builtins.int
             TYPE_FIELD_NUMBER: builtins.int
             DREP_CREDHASH_FIELD_NUMBER: builtins.int
+            DEPOSIT_FIELD_NUMBER: builtins.int
             type: global___CardanoSignTransactionRequest.Certificate.VoteDelegation.CardanoDRepType.ValueType
             drep_credhash: builtins.bytes
+            deposit: builtins.int
+            """If set, the stake key is registered in the same certificate (vote_reg_deleg_cert)."""
             @property
             def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]:
                 """keypath in this instance refers to stake credential"""
@@ -281,15 +701,121 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
                 keypath: collections.abc.Iterable[builtins.int] | None = ...,
                 type: global___CardanoSignTransactionRequest.Certificate.VoteDelegation.CardanoDRepType.ValueType = ...,
                 drep_credhash: builtins.bytes | None = ...,
+                deposit: builtins.int | None = ...,
+            ) -> None: ...
+            def HasField(self, field_name: typing.Literal["_deposit", b"_deposit", "_drep_credhash", b"_drep_credhash", "deposit", b"deposit", "drep_credhash", b"drep_credhash"]) -> builtins.bool: ...
+            def ClearField(self, field_name: typing.Literal["_deposit", b"_deposit", "_drep_credhash", b"_drep_credhash", "deposit", b"deposit", "drep_credhash", b"drep_credhash", "keypath", b"keypath", "type", b"type"]) -> None: ...
+            @typing.overload
+            def WhichOneof(self, oneof_group: typing.Literal["_deposit", b"_deposit"]) -> typing.Literal["deposit"] | None: ...
+            @typing.overload
+            def WhichOneof(self, oneof_group: typing.Literal["_drep_credhash", b"_drep_credhash"]) -> typing.Literal["drep_credhash"] | None: ...
+
+        @typing.final
+        class StakeVoteDelegation(google.protobuf.message.Message):
+            """Delegation of both staking and voting (stake_vote_deleg_cert)."""
+
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            KEYPATH_FIELD_NUMBER: builtins.int
+            POOL_KEYHASH_FIELD_NUMBER: builtins.int
+            DREP_TYPE_FIELD_NUMBER: builtins.int
+            DREP_CREDHASH_FIELD_NUMBER: builtins.int
+            DEPOSIT_FIELD_NUMBER: builtins.int
+            pool_keyhash: builtins.bytes
+            drep_type: global___CardanoSignTransactionRequest.Certificate.VoteDelegation.CardanoDRepType.ValueType
+            drep_credhash: builtins.bytes
+            deposit: builtins.int
+            """If set, the stake key is registered in the same certificate (stake_vote_reg_deleg_cert)."""
+            @property
+            def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+            def __init__(
+                self,
+                *,
+                keypath: collections.abc.Iterable[builtins.int] | None = ...,
+                pool_keyhash: builtins.bytes = ...,
+                drep_type: global___CardanoSignTransactionRequest.Certificate.VoteDelegation.CardanoDRepType.ValueType = ...,
+                drep_credhash: builtins.bytes | None = ...,
+                deposit: builtins.int | None = ...,
             ) -> None: ...
-            def HasField(self, field_name: typing.Literal["_drep_credhash", b"_drep_credhash", "drep_credhash", b"drep_credhash"]) -> builtins.bool: ...
-            def ClearField(self, field_name: typing.Literal["_drep_credhash", b"_drep_credhash", "drep_credhash", b"drep_credhash", "keypath", b"keypath", "type", b"type"]) -> None: ...
+            def HasField(self, field_name: typing.Literal["_deposit", b"_deposit", "_drep_credhash", b"_drep_credhash", "deposit", b"deposit", "drep_credhash", b"drep_credhash"]) -> builtins.bool: ...
+            def ClearField(self, field_name: typing.Literal["_deposit", b"_deposit", "_drep_credhash", b"_drep_credhash", "deposit", b"deposit", "drep_credhash", b"drep_credhash", "drep_type", b"drep_type", "keypath", b"keypath", "pool_keyhash", b"pool_keyhash"]) -> None: ...
+            @typing.overload
+            def WhichOneof(self, oneof_group: typing.Literal["_deposit", b"_deposit"]) -> typing.Literal["deposit"] | None: ...
+            @typing.overload
             def WhichOneof(self, oneof_group: typing.Literal["_drep_credhash", b"_drep_credhash"]) -> typing.Literal["drep_credhash"] | None: ...
 
+        @typing.final
+        class KeypathDeposit(google.protobuf.message.Message):
+            """Stake key (reg_cert, unreg_cert) or DRep key (unreg_drep_cert) with an explicit deposit."""
+
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            KEYPATH_FIELD_NUMBER: builtins.int
+            DEPOSIT_FIELD_NUMBER: builtins.int
+            deposit: builtins.int
+            @property
+            def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+            def __init__(
+                self,
+                *,
+                keypath: collections.abc.Iterable[builtins.int] | None = ...,
+                deposit: builtins.int = ...,
+            ) -> None: ...
+            def ClearField(self, field_name: typing.Literal["deposit", b"deposit", "keypath", b"keypath"]) -> None: ...
+
+        @typing.final
+        class DRepRegistration(google.protobuf.message.Message):
+            """keypath refers to the DRep key m/1852'/1815'/account'/3/0."""
+
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            KEYPATH_FIELD_NUMBER: builtins.int
+            DEPOSIT_FIELD_NUMBER: builtins.int
+            ANCHOR_FIELD_NUMBER: builtins.int
+            deposit: builtins.int
+            @property
+            def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+            @property
+            def anchor(self) -> global___CardanoSignTransactionRequest.Anchor: ...
+            def __init__(
+                self,
+                *,
+                keypath: collections.abc.Iterable[builtins.int] | None = ...,
+                deposit: builtins.int = ...,
+                anchor: global___CardanoSignTransactionRequest.Anchor | None = ...,
+            ) -> None: ...
+            def HasField(self, field_name: typing.Literal["anchor", b"anchor"]) -> builtins.bool: ...
+            def ClearField(self, field_name: typing.Literal["anchor", b"anchor", "deposit", b"deposit", "keypath", b"keypath"]) -> None: ...
+
+        @typing.final
+        class DRepUpdate(google.protobuf.message.Message):
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            KEYPATH_FIELD_NUMBER: builtins.int
+            ANCHOR_FIELD_NUMBER: builtins.int
+            @property
+            def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+            @property
+            def anchor(self) -> global___CardanoSignTransactionRequest.Anchor: ...
+            def __init__(
+                self,
+                *,
+                keypath: collections.abc.Iterable[builtins.int] | None = ...,
+                anchor: global___CardanoSignTransactionRequest.Anchor | None = ...,
+            ) -> None: ...
+            def HasField(self, field_name: typing.Literal["anchor", b"anchor"]) -> builtins.bool: ...
+            def ClearField(self, field_name: typing.Literal["anchor", b"anchor", "keypath", b"keypath"]) -> None: ...
+
         STAKE_REGISTRATION_FIELD_NUMBER: builtins.int
         STAKE_DEREGISTRATION_FIELD_NUMBER: builtins.int
         STAKE_DELEGATION_FIELD_NUMBER: builtins.int
         VOTE_DELEGATION_FIELD_NUMBER: builtins.int
+        STAKE_REGISTRATION_DEPOSIT_FIELD_NUMBER: builtins.int
+        STAKE_DEREGISTRATION_DEPOSIT_FIELD_NUMBER: builtins.int
+        STAKE_VOTE_DELEGATION_FIELD_NUMBER: builtins.int
+        DREP_REGISTRATION_FIELD_NUMBER: builtins.int
+        DREP_DEREGISTRATION_FIELD_NUMBER: builtins.int
+        DREP_UPDATE_FIELD_NUMBER: builtins.int
         @property
         def stake_registration(self) -> common_pb2.Keypath: ...
         @property
@@ -298,6 +824,18 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
         def stake_delegation(self) -> global___CardanoSignTransactionRequest.Certificate.StakeDelegation: ...
         @property
         def vote_delegation(self) -> global___CardanoSignTransactionRequest.Certificate.VoteDelegation: ...
+        @property
+        def stake_registration_deposit(self) -> global___CardanoSignTransactionRequest.Certificate.KeypathDeposit: ...
+        @property
+        def stake_deregistration_deposit(self) -> global___CardanoSignTransactionRequest.Certificate.KeypathDeposit: ...
+        @property
+        def stake_vote_delegation(self) -> global___CardanoSignTransactionRequest.Certificate.StakeVoteDelegation: ...
+        @property
+        def drep_registration(self) -> global___CardanoSignTransactionRequest.Certificate.DRepRegistration: ...
+        @property
+        def drep_deregistration(self) -> global___CardanoSignTransactionRequest.Certificate.KeypathDeposit: ...
+        @property
+        def drep_update(self) -> global___CardanoSignTransactionRequest.Certificate.DRepUpdate: ...
         def __init__(
             self,
             *,
@@ -305,10 +843,82 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
             stake_deregistration: common_pb2.Keypath | None = ...,
             stake_delegation: global___CardanoSignTransactionRequest.Certificate.StakeDelegation | None = ...,
             vote_delegation: global___CardanoSignTransactionRequest.Certificate.VoteDelegation | None = ...,
+            stake_registration_deposit: global___CardanoSignTransactionRequest.Certificate.KeypathDeposit | None = ...,
+            stake_deregistration_deposit: global___CardanoSignTransactionRequest.Certificate.KeypathDeposit | None = ...,
+            stake_vote_delegation: global___CardanoSignTransactionRequest.Certificate.StakeVoteDelegation | None = ...,
+            drep_registration: global___CardanoSignTransactionRequest.Certificate.DRepRegistration | None = ...,
+            drep_deregistration: global___CardanoSignTransactionRequest.Certificate.KeypathDeposit | None = ...,
+            drep_update: global___CardanoSignTransactionRequest.Certificate.DRepUpdate | None = ...,
+        ) -> None: ...
+        def HasField(self, field_name: typing.Literal["cert", b"cert", "drep_deregistration", b"drep_deregistration", "drep_registration", b"drep_registration", "drep_update", b"drep_update", "stake_delegation", b"stake_delegation", "stake_deregistration", b"stake_deregistration", "stake_deregistration_deposit", b"stake_deregistration_deposit", "stake_registration", b"stake_registration", "stake_registration_deposit", b"stake_registration_deposit", "stake_vote_delegation", b"stake_vote_delegation", "vote_delegation", b"vote_delegation"]) -> builtins.bool: ...
+        def ClearField(self, field_name: typing.Literal["cert", b"cert", "drep_deregistration", b"drep_deregistration", "drep_registration", b"drep_registration", "drep_update", b"drep_update", "stake_delegation", b"stake_delegation", "stake_deregistration", b"stake_deregistration", "stake_deregistration_deposit", b"stake_deregistration_deposit", "stake_registration", b"stake_registration", "stake_registration_deposit", b"stake_registration_deposit", "stake_vote_delegation", b"stake_vote_delegation", "vote_delegation", b"vote_delegation"]) -> None: ...
+        def WhichOneof(self, oneof_group: typing.Literal["cert", b"cert"]) -> typing.Literal["stake_registration", "stake_deregistration", "stake_delegation", "vote_delegation", "stake_registration_deposit", "stake_deregistration_deposit", "stake_vote_delegation", "drep_registration", "drep_deregistration", "drep_update"] | None: ...
+
+    @typing.final
+    class VotingProcedures(google.protobuf.message.Message):
+        """Votes on governance actions cast with the DRep key of this wallet.
+        See https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl
+        """
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        @typing.final
+        class Vote(google.protobuf.message.Message):
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            class _Choice:
+                ValueType = typing.NewType("ValueType", builtins.int)
+                V: typing_extensions.TypeAlias = ValueType
+
+            class _ChoiceEnumTypeWrapper(google.protobuf.internal.enum_type_wrapper._EnumTypeWrapper[CardanoSignTransactionRequest.VotingProcedures.Vote._Choice.ValueType], builtins.type):
+                DESCRIPTOR: google.protobuf.descriptor.EnumDescriptor
+                NO: CardanoSignTransactionRequest.VotingProcedures.Vote._Choice.ValueType  # 0
+                YES: CardanoSignTransactionRequest.VotingProcedures.Vote._Choice.ValueType  # 1
+                ABSTAIN: CardanoSignTransactionRequest.VotingProcedures.Vote._Choice.ValueType  # 2
+
+            class Choice(_Choice, metaclass=_ChoiceEnumTypeWrapper): ...
+            NO: CardanoSignTransactionRequest.VotingProcedures.Vote.Choice.ValueType  # 0
+            YES: CardanoSignTransactionRequest.VotingProcedures.Vote.Choice.ValueType  # 1
+            ABSTAIN: CardanoSignTransactionRequest.VotingProcedures.Vote.Choice.ValueType  # 2
+
+            GOV_ACTION_TX_HASH_FIELD_NUMBER: builtins.int
+            GOV_ACTION_INDEX_FIELD_NUMBER: builtins.int
+            CHOICE_FIELD_NUMBER: builtins.int
+            ANCHOR_FIELD_NUMBER: builtins.int
+            gov_action_tx_hash: builtins.bytes
+            """Governance action ID.
+            32 bytes
+            """
+            gov_action_index: builtins.int
+            choice: global___CardanoSignTransactionRequest.VotingProcedures.Vote.Choice.ValueType
+            @property
+            def anchor(self) -> global___CardanoSignTransactionRequest.Anchor: ...
+            def __init__(
+                self,
+                *,
+                gov_action_tx_hash: builtins.bytes = ...,
+                gov_action_index: builtins.int = ...,
+                choice: global___CardanoSignTransactionRequest.VotingProcedures.Vote.Choice.ValueType = ...,
+                anchor: global___CardanoSignTransactionRequest.Anchor | None = ...,
+            ) -> None: ...
+            def HasField(self, field_name: typing.Literal["anchor", b"anchor"]) -> builtins.bool: ...
+            def ClearField(self, field_name: typing.Literal["anchor", b"anchor", "choice", b"choice", "gov_action_index", b"gov_action_index", "gov_action_tx_hash", b"gov_action_tx_hash"]) -> None: ...
+
+        DREP_KEYPATH_FIELD_NUMBER: builtins.int
+        VOTES_FIELD_NUMBER: builtins.int
+        @property
+        def drep_keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]:
+            """DRep key m/1852'/1815'/account'/3/0."""
+
+        @property
+        def votes(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.VotingProcedures.Vote]: ...
+        def __init__(
+            self,
+            *,
+            drep_keypath: collections.abc.Iterable[builtins.int] | None = ...,
+            votes: collections.abc.Iterable[global___CardanoSignTransactionRequest.VotingProcedures.Vote] | None = ...,
         ) -> None: ...
-        def HasField(self, field_name: typing.Literal["cert", b"cert", "stake_delegation", b"stake_delegation", "stake_deregistration", b"stake_deregistration", "stake_registration", b"stake_registration", "vote_delegation", b"vote_delegation"]) -> builtins.bool: ...
-        def ClearField(self, field_name: typing.Literal["cert", b"cert", "stake_delegation", b"stake_delegation", "stake_deregistration", b"stake_deregistration", "stake_registration", b"stake_registration", "vote_delegation", b"vote_delegation"]) -> None: ...
-        def WhichOneof(self, oneof_group: typing.Literal["cert", b"cert"]) -> typing.Literal["stake_registration", "stake_deregistration", "stake_delegation", "vote_delegation"] | None: ...
+        def ClearField(self, field_name: typing.Literal["drep_keypath", b"drep_keypath", "votes", b"votes"]) -> None: ...
 
     @typing.final
     class Withdrawal(google.protobuf.message.Message):
@@ -327,6 +937,133 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
         ) -> None: ...
         def ClearField(self, field_name: typing.Literal["keypath", b"keypath", "value", b"value"]) -> None: ...
 
+    @typing.final
+    class AuxiliaryData(google.protobuf.message.Message):
+        """Auxiliary data (transaction metadata). The device computes the auxiliary data hash included in
+        the transaction body (map entry 7) and returns it, see `AuxiliaryDataSupplement`.
+
+        For `cip20_message` and `cip36_registration`, the host must serialize the auxiliary data
+        exactly as the device does: a metadata map (no auxiliary scripts) in canonical CBOR.
+        """
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        @typing.final
+        class CIP20Message(google.protobuf.message.Message):
+            """CIP-20 transaction message, serialized as {674: {"msg": [lines...]}}.
+            See https://github.com/cardano-foundation/CIPs/blob/master/CIP-0020/README.md
+            """
+
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            LINES_FIELD_NUMBER: builtins.int
+            @property
+            def lines(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.str]:
+                """Each line is at most 64 bytes."""
+
+            def __init__(
+                self,
+                *,
+                lines: collections.abc.Iterable[builtins.str] | None = ...,
+            ) -> None: ...
+            def ClearField(self, field_name: typing.Literal["lines", b"lines"]) -> None: ...
+
+        @typing.final
+        class CIP36Registration(google.protobuf.message.Message):
+            """CIP-36 vote key registration, serialized as {61284: registration, 61285: {1: signature}}.
+            The device signs the registration with the stake key.
+            See https://github.com/cardano-foundation/CIPs/blob/master/CIP-0036/README.md
+            """
+
+            DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+            @typing.final
+            class Delegation(google.protobuf.message.Message):
+                DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+                VOTE_KEY_FIELD_NUMBER: builtins.int
+                WEIGHT_FIELD_NUMBER: builtins.int
+                vote_key: builtins.bytes
+                """32 bytes"""
+                weight: builtins.int
+                def __init__(
+                    self,
+                    *,
+                    vote_key: builtins.bytes = ...,
+                    weight: builtins.int = ...,
+                ) -> None: ...
+                def ClearField(self, field_name: typing.Literal["vote_key", b"vote_key", "weight", b"weight"]) -> None: ...
+
+            DELEGATIONS_FIELD_NUMBER: builtins.int
+            STAKE_KEYPATH_FIELD_NUMBER: builtins.int
+            PAYMENT_ADDRESS_FIELD_NUMBER: builtins.int
+            NONCE_FIELD_NUMBER: builtins.int
+            VOTING_PURPOSE_FIELD_NUMBER: builtins.int
+            nonce: builtins.int
+            voting_purpose: builtins.int
+            """0 is Catalyst."""
+            @property
+            def delegations(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.AuxiliaryData.CIP36Registration.Delegation]: ...
+            @property
+            def stake_keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+            @property
+            def payment_address(self) -> global___CardanoScriptConfig:
+                """Address receiving the voting rewards. Must be an address of this wallet."""
+
+            def __init__(
+                self,
+                *,
+                delegations: collections.abc.Iterable[global___CardanoSignTransactionRequest.AuxiliaryData.CIP36Registration.Delegation] | None = ...,
+                stake_keypath: collections.abc.Iterable[builtins.int] | None = ...,
+                payment_address: global___CardanoScriptConfig | None = ...,
+                nonce: builtins.int = ...,
+                voting_purpose: builtins.int = ...,
+            ) -> None: ...
+            def HasField(self, field_name: typing.Literal["payment_address", b"payment_address"]) -> builtins.bool: ...
+            def ClearField(self, field_name: typing.Literal["delegations", b"delegations", "nonce", b"nonce", "payment_address", b"payment_address", "stake_keypath", b"stake_keypath", "voting_purpose", b"voting_purpose"]) -> None: ...
+
+        HASH_FIELD_NUMBER: builtins.int
+        CIP20_MESSAGE_FIELD_NUMBER: builtins.int
+        CIP36_REGISTRATION_FIELD_NUMBER: builtins.int
+        hash: builtins.bytes
+        """Hash of arbitrary auxiliary data serialized by the host. It is shown to the user as is."""
+        @property
+        def cip20_message(self) -> global___CardanoSignTransactionRequest.AuxiliaryData.CIP20Message: ...
+        @property
+        def cip36_registration(self) -> global___CardanoSignTransactionRequest.AuxiliaryData.CIP36Registration: ...
+        def __init__(
+            self,
+            *,
+            hash: builtins.bytes = ...,
+            cip20_message: global___CardanoSignTransactionRequest.AuxiliaryData.CIP20Message | None = ...,
+            cip36_registration: global___CardanoSignTransactionRequest.AuxiliaryData.CIP36Registration | None = ...,
+        ) -> None: ...
+        def HasField(self, field_name: typing.Literal["cip20_message", b"cip20_message", "cip36_registration", b"cip36_registration", "data", b"data", "hash", b"hash"]) -> builtins.bool: ...
+        def ClearField(self, field_name: typing.Literal["cip20_message", b"cip20_message", "cip36_registration", b"cip36_registration", "data", b"data", "hash", b"hash"]) -> None: ...
+        def WhichOneof(self, oneof_group: typing.Literal["data", b"data"]) -> typing.Literal["hash", "cip20_message", "cip36_registration"] | None: ...
+
+    @typing.final
+    class RequiredSigner(google.protobuf.message.Message):
+        """Signer required by a Plutus script. Exactly one of the fields must be set. If `keypath` is
+        set, the transaction is signed with the key at the keypath.
+        """
+
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        KEYPATH_FIELD_NUMBER: builtins.int
+        KEY_HASH_FIELD_NUMBER: builtins.int
+        key_hash: builtins.bytes
+        """28 bytes"""
+        @property
+        def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+        def __init__(
+            self,
+            *,
+            keypath: collections.abc.Iterable[builtins.int] | None = ...,
+            key_hash: builtins.bytes = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["key_hash", b"key_hash", "keypath", b"keypath"]) -> None: ...
+
     NETWORK_FIELD_NUMBER: builtins.int
     INPUTS_FIELD_NUMBER: builtins.int
     OUTPUTS_FIELD_NUMBER: builtins.int
@@ -337,6 +1074,17 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
     VALIDITY_INTERVAL_START_FIELD_NUMBER: builtins.int
     ALLOW_ZERO_TTL_FIELD_NUMBER: builtins.int
     TAG_CBOR_SETS_FIELD_NUMBER: builtins.int
+    AUXILIARY_DATA_FIELD_NUMBER: builtins.int
+    MINT_FIELD_NUMBER: builtins.int
+    POLICY_KEYPATHS_FIELD_NUMBER: builtins.int
+    SCRIPT_DATA_HASH_FIELD_NUMBER: builtins.int
+    COLLATERAL_INPUTS_FIELD_NUMBER: builtins.int
+    COLLATERAL_RETURN_FIELD_NUMBER: builtins.int
+    TOTAL_COLLATERAL_FIELD_NUMBER: builtins.int
+    REQUIRED_SIGNERS_FIELD_NUMBER: builtins.int
+    REFERENCE_INPUTS_FIELD_NUMBER: builtins.int
+    VOTING_PROCEDURES_FIELD_NUMBER: builtins.int
+    DERIVATION_TYPE_FIELD_NUMBER: builtins.int
     network: global___CardanoNetwork.ValueType
     fee: builtins.int
     ttl: builtins.int
@@ -347,6 +1095,13 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
     """Tag arrays in the transaction serialization with the 258 tag.
     See https://github.com/IntersectMBO/cardano-ledger/blob/6e2d37cc0f47bd02e89b4ce9f78b59c35c958e96/eras/conway/impl/cddl-files/extra.cddl#L5
     """
+    script_data_hash: builtins.bytes
+    """Fields to interact with Plutus scripts. Collateral inputs and the collateral return must
+    belong to this wallet.
+    32 bytes
+    """
+    total_collateral: builtins.int
+    derivation_type: global___CardanoDerivationType.ValueType
     @property
     def inputs(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.Input]: ...
     @property
@@ -355,6 +1110,26 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
     def certificates(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.Certificate]: ...
     @property
     def withdrawals(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.Withdrawal]: ...
+    @property
+    def auxiliary_data(self) -> global___CardanoSignTransactionRequest.AuxiliaryData: ...
+    @property
+    def mint(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.MintAssetGroup]: ...
+    @property
+    def policy_keypaths(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[common_pb2.Keypath]:
+        """Minting policy keys (m/1855'/1815'/policy') to sign the transaction with. Only allowed if
+        `mint` is not empty.
+        """
+
+    @property
+    def collateral_inputs(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.Input]: ...
+    @property
+    def collateral_return(self) -> global___CardanoSignTransactionRequest.Output: ...
+    @property
+    def required_signers(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.RequiredSigner]: ...
+    @property
+    def reference_inputs(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionRequest.Input]: ...
+    @property
+    def voting_procedures(self) -> global___CardanoSignTransactionRequest.VotingProcedures: ...
     def __init__(
         self,
         *,
@@ -368,8 +1143,21 @@ class CardanoSignTransactionRequest(google.protobuf.message.Message):
         validity_interval_start: builtins.int = ...,
         allow_zero_ttl: builtins.bool = ...,
         tag_cbor_sets: builtins.bool = ...,
+        auxiliary_data: global___CardanoSignTransactionRequest.AuxiliaryData | None = ...,
+        mint: collections.abc.Iterable[global___CardanoSignTransactionRequest.MintAssetGroup] | None = ...,
+        policy_keypaths: collections.abc.Iterable[common_pb2.Keypath] | None = ...,
+        script_data_hash: builtins.bytes = ...,
+        collateral_inputs: collections.abc.Iterable[global___CardanoSignTransactionRequest.Input] | None = ...,
+        collateral_return: global___CardanoSignTransactionRequest.Output | None = ...,
+        total_collateral: builtins.int | None = ...,
+        required_signers: collections.abc.Iterable[global___CardanoSignTransactionRequest.RequiredSigner] | None = ...,
+        reference_inputs: collections.abc.Iterable[global___CardanoSignTransactionRequest.Input] | None = ...,
+        voting_procedures: global___CardanoSignTransactionRequest.VotingProcedures | None = ...,
+        derivation_type: global___CardanoDerivationType.ValueType = ...,
     ) -> None: ...
-    def ClearField(self, field_name: typing.Literal["allow_zero_ttl", b"allow_zero_ttl", "certificates", b"certificates", "fee", b"fee", "inputs", b"inputs", "network", b"network", "outputs", b"outputs", "tag_cbor_sets", b"tag_cbor_sets", "ttl", b"ttl", "validity_interval_start", b"validity_interval_start", "withdrawals", b"withdrawals"]) -> None: ...
+    def HasField(self, field_name: typing.Literal["_total_collateral", b"_total_collateral", "auxiliary_data", b"auxiliary_data", "collateral_return", b"collateral_return", "total_collateral", b"total_collateral", "voting_procedures", b"voting_procedures"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["_total_collateral", b"_total_collateral", "allow_zero_ttl", b"allow_zero_ttl", "auxiliary_data", b"auxiliary_data", "certificates", b"certificates", "collateral_inputs", b"collateral_inputs", "collateral_return", b"collateral_return", "derivation_type", b"derivation_type", "fee", b"fee", "inputs", b"inputs", "mint", b"mint", "network", b"network", "outputs", b"outputs", "policy_keypaths", b"policy_keypaths", "reference_inputs", b"reference_inputs", "required_signers", b"required_signers", "script_data_hash", b"script_data_hash", "tag_cbor_sets", b"tag_cbor_sets", "total_collateral", b"total_collateral", "ttl", b"ttl", "validity_interval_start", b"validity_interval_start", "voting_procedures", b"voting_procedures", "withdrawals", b"withdrawals"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["_total_collateral", b"_total_collateral"]) -> typing.Literal["total_collateral"] | None: ...
 
 global___CardanoSignTransactionRequest = CardanoSignTransactionRequest
 
@@ -393,18 +1181,104 @@ class CardanoSignTransactionResponse(google.protobuf.message.Message):
         ) -> None: ...
         def ClearField(self, field_name: typing.Literal["public_key", b"public_key", "signature", b"signature"]) -> None: ...
 
+    @typing.final
+    class AuxiliaryDataSupplement(google.protobuf.message.Message):
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        AUXILIARY_DATA_HASH_FIELD_NUMBER: builtins.int
+        CIP36_SIGNATURE_FIELD_NUMBER: builtins.int
+        auxiliary_data_hash: builtins.bytes
+        """Blake2b-256 hash of the serialized auxiliary data."""
+        cip36_signature: builtins.bytes
+        """Signature of the CIP-36 registration, only present for `cip36_registration`."""
+        def __init__(
+            self,
+            *,
+            auxiliary_data_hash: builtins.bytes = ...,
+            cip36_signature: builtins.bytes = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["auxiliary_data_hash", b"auxiliary_data_hash", "cip36_signature", b"cip36_signature"]) -> None: ...
+
     SHELLEY_WITNESSES_FIELD_NUMBER: builtins.int
+    AUXILIARY_DATA_SUPPLEMENT_FIELD_NUMBER: builtins.int
     @property
     def shelley_witnesses(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___CardanoSignTransactionResponse.ShelleyWitness]: ...
+    @property
+    def auxiliary_data_supplement(self) -> global___CardanoSignTransactionResponse.AuxiliaryDataSupplement:
+        """Only present if `auxiliary_data` was provided in the request."""
+
     def __init__(
         self,
         *,
         shelley_witnesses: collections.abc.Iterable[global___CardanoSignTransactionResponse.ShelleyWitness] | None = ...,
+        auxiliary_data_supplement: global___CardanoSignTransactionResponse.AuxiliaryDataSupplement | None = ...,
     ) -> None: ...
-    def ClearField(self, field_name: typing.Literal["shelley_witnesses", b"shelley_witnesses"]) -> None: ...
+    def HasField(self, field_name: typing.Literal["auxiliary_data_supplement", b"auxiliary_data_supplement"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["auxiliary_data_supplement", b"auxiliary_data_supplement", "shelley_witnesses", b"shelley_witnesses"]) -> None: ...
 
 global___CardanoSignTransactionResponse = CardanoSignTransactionResponse
 
+@typing.final
+class CardanoSignMessageRequest(google.protobuf.message.Message):
+    """Signs a message according to CIP-8, as used by the CIP-30 `signData` dapp connector endpoint.
+    Exactly one of `script_config` and `stake_keypath` must be set.
+    See https://cips.cardano.org/cip/CIP-0008 and https://cips.cardano.org/cip/CIP-0030.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    NETWORK_FIELD_NUMBER: builtins.int
+    SCRIPT_CONFIG_FIELD_NUMBER: builtins.int
+    STAKE_KEYPATH_FIELD_NUMBER: builtins.int
+    PAYLOAD_FIELD_NUMBER: builtins.int
+    DERIVATION_TYPE_FIELD_NUMBER: builtins.int
+    network: global___CardanoNetwork.ValueType
+    payload: builtins.bytes
+    derivation_type: global___CardanoDerivationType.ValueType
+    @property
+    def script_config(self) -> global___CardanoScriptConfig:
+        """Base address (`pkh_skh` only). The message is signed with its payment key."""
+
+    @property
+    def stake_keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]:
+        """Stake key (m/1852'/1815'/account'/2/0). The message is signed with this key, and its reward
+        address is used.
+        """
+
+    def __init__(
+        self,
+        *,
+        network: global___CardanoNetwork.ValueType = ...,
+        script_config: global___CardanoScriptConfig | None = ...,
+        stake_keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        payload: builtins.bytes = ...,
+        derivation_type: global___CardanoDerivationType.ValueType = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["script_config", b"script_config"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["derivation_type", b"derivation_type", "network", b"network", "payload", b"payload", "script_config", b"script_config", "stake_keypath", b"stake_keypath"]) -> None: ...
+
+global___CardanoSignMessageRequest = CardanoSignMessageRequest
+
+@typing.final
+class CardanoSignMessageResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    SIGNATURE_FIELD_NUMBER: builtins.int
+    KEY_FIELD_NUMBER: builtins.int
+    signature: builtins.bytes
+    """CBOR encoded COSE_Sign1 structure."""
+    key: builtins.bytes
+    """CBOR encoded COSE_Key of the public key."""
+    def __init__(
+        self,
+        *,
+        signature: builtins.bytes = ...,
+        key: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["key", b"key", "signature", b"signature"]) -> None: ...
+
+global___CardanoSignMessageResponse = CardanoSignMessageResponse
+
 @typing.final
 class CardanoRequest(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -412,22 +1286,34 @@ class CardanoRequest(google.protobuf.message.Message):
     XPUBS_FIELD_NUMBER: builtins.int
     ADDRESS_FIELD_NUMBER: builtins.int
     SIGN_TRANSACTION_FIELD_NUMBER: builtins.int
+    IS_SCRIPT_CONFIG_REGISTERED_FIELD_NUMBER: builtins.int
+    REGISTER_SCRIPT_CONFIG_FIELD_NUMBER: builtins.int
+    SIGN_MESSAGE_FIELD_NUMBER: builtins.int
     @property
     def xpubs(self) -> global___CardanoXpubsRequest: ...
     @property
     def address(self) -> global___CardanoAddressRequest: ...
     @property
     def sign_transaction(self) -> global___CardanoSignTransactionRequest: ...
+    @property
+    def is_script_config_registered(self) -> global___CardanoIsScriptConfigRegisteredRequest: ...
+    @property
+    def register_script_config(self) -> global___CardanoRegisterScriptConfigRequest: ...
+    @property
+    def sign_message(self) -> global___CardanoSignMessageRequest: ...
     def __init__(
         self,
         *,
         xpubs: global___CardanoXpubsRequest | None = ...,
         address: global___CardanoAddressRequest | None = ...,
         sign_transaction: global___CardanoSignTransactionRequest | None = ...,
+        is_script_config_registered: global___CardanoIsScriptConfigRegisteredRequest | None = ...,
+        register_script_config: global___CardanoRegisterScriptConfigRequest | None = ...,
+        sign_message: global___CardanoSignMessageRequest | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["address", b"address", "request", b"request", "sign_transaction", b"sign_transaction", "xpubs", b"xpubs"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["address", b"address", "request", b"request", "sign_transaction", b"sign_transaction", "xpubs", b"xpubs"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["xpubs", "address", "sign_transaction"] | None: ...
+    def HasField(self, field_name: typing.Literal["address", b"address", "is_script_config_registered", b"is_script_config_registered", "register_script_config", b"register_script_config", "request", b"request", "sign_message", b"sign_message", "sign_transaction", b"sign_transaction", "xpubs", b"xpubs"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["address", b"address", "is_script_config_registered", b"is_script_config_registered", "register_script_config", b"register_script_config", "request", b"request", "sign_message", b"sign_message", "sign_transaction", b"sign_transaction", "xpubs", b"xpubs"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["xpubs", "address", "sign_transaction", "is_script_config_registered", "register_script_config", "sign_message"] | None: ...
 
 global___CardanoRequest = CardanoRequest
 
@@ -438,21 +1324,33 @@ class CardanoResponse(google.protobuf.message.Message):
     XPUBS_FIELD_NUMBER: builtins.int
     PUB_FIELD_NUMBER: builtins.int
     SIGN_TRANSACTION_FIELD_NUMBER: builtins.int
+    IS_SCRIPT_CONFIG_REGISTERED_FIELD_NUMBER: builtins.int
+    SUCCESS_FIELD_NUMBER: builtins.int
+    SIGN_MESSAGE_FIELD_NUMBER: builtins.int
     @property
     def xpubs(self) -> global___CardanoXpubsResponse: ...
     @property
     def pub(self) -> common_pb2.PubResponse: ...
     @property
     def sign_transaction(self) -> global___CardanoSignTransactionResponse: ...
+    @property
+    def is_script_config_registered(self) -> global___CardanoIsScriptConfigRegisteredResponse: ...
+    @property
+    def success(self) -> global___CardanoSuccess: ...
+    @property
+    def sign_message(self) -> global___CardanoSignMessageResponse: ...
     def __init__(
         self,
         *,
         xpubs: global___CardanoXpubsResponse | None = ...,
         pub: common_pb2.PubResponse | None = ...,
         sign_transaction: global___CardanoSignTransactionResponse | None = ...,
+        is_script_config_registered: global___CardanoIsScriptConfigRegisteredResponse | None = ...,
+        success: global___CardanoSuccess | None = ...,
+        sign_message: global___CardanoSignMessageResponse | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["pub", b"pub", "response", b"response", "sign_transaction", b"sign_transaction", "xpubs", b"xpubs"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["pub", b"pub", "response", b"response", "sign_transaction", b"sign_transaction", "xpubs", b"xpubs"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["xpubs", "pub", "sign_transaction"] | None: ...
+    def HasField(self, field_name: typing.Literal["is_script_config_registered", b"is_script_config_registered", "pub", b"pub", "response", b"response", "sign_message", b"sign_message", "sign_transaction", b"sign_transaction", "success", b"success", "xpubs", b"xpubs"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["is_script_config_registered", b"is_script_config_registered", "pub", b"pub", "response", b"response", "sign_message", b"sign_message", "sign_transaction", b"sign_transaction", "success", b"success", "xpubs", b"xpubs"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["xpubs", "pub", "sign_transaction", "is_script_config_registered", "success", "sign_message"] | None: ...
 
 global___CardanoResponse = CardanoResponse
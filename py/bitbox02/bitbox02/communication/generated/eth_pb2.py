@@ -15,51 +15,65 @@ from . import common_pb2 as common__pb2
 from . import antiklepto_pb2 as antiklepto__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\teth.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\x1a\x10\x61ntiklepto.proto\"\xf4\x01\n\rETHPubRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12+\n\x04\x63oin\x18\x02 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.ETHCoin\x12\x43\n\x0boutput_type\x18\x03 \x01(\x0e\x32..shiftcrypto.bitbox02.ETHPubRequest.OutputType\x12\x0f\n\x07\x64isplay\x18\x04 \x01(\x08\x12\x18\n\x10\x63ontract_address\x18\x05 \x01(\x0c\x12\x10\n\x08\x63hain_id\x18\x06 \x01(\x04\"#\n\nOutputType\x12\x0b\n\x07\x41\x44\x44RESS\x10\x00\x12\x08\n\x04XPUB\x10\x01\"\xea\x02\n\x0e\x45THSignRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.ETHCoin\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\r\n\x05nonce\x18\x03 \x01(\x0c\x12\x11\n\tgas_price\x18\x04 \x01(\x0c\x12\x11\n\tgas_limit\x18\x05 \x01(\x0c\x12\x11\n\trecipient\x18\x06 \x01(\x0c\x12\r\n\x05value\x18\x07 \x01(\x0c\x12\x0c\n\x04\x64\x61ta\x18\x08 \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\t \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12\x10\n\x08\x63hain_id\x18\n \x01(\x04\x12:\n\x0c\x61\x64\x64ress_case\x18\x0b \x01(\x0e\x32$.shiftcrypto.bitbox02.ETHAddressCase\x12\x13\n\x0b\x64\x61ta_length\x18\x0c \x01(\r\"\xec\x02\n\x15\x45THSignEIP1559Request\x12\x10\n\x08\x63hain_id\x18\x01 \x01(\x04\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\r\n\x05nonce\x18\x03 \x01(\x0c\x12 \n\x18max_priority_fee_per_gas\x18\x04 \x01(\x0c\x12\x17\n\x0fmax_fee_per_gas\x18\x05 \x01(\x0c\x12\x11\n\tgas_limit\x18\x06 \x01(\x0c\x12\x11\n\trecipient\x18\x07 \x01(\x0c\x12\r\n\x05value\x18\x08 \x01(\x0c\x12\x0c\n\x04\x64\x61ta\x18\t \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\n \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12:\n\x0c\x61\x64\x64ress_case\x18\x0b \x01(\x0e\x32$.shiftcrypto.bitbox02.ETHAddressCase\x12\x13\n\x0b\x64\x61ta_length\x18\x0c \x01(\r\"A\n\x1f\x45THSignDataRequestChunkResponse\x12\x0e\n\x06offset\x18\x01 \x01(\r\x12\x0e\n\x06length\x18\x02 \x01(\r\"0\n\x1f\x45THSignDataResponseChunkRequest\x12\r\n\x05\x63hunk\x18\x01 \x01(\x0c\"\xc8\x01\n\x15\x45THSignMessageRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.ETHCoin\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\x0b\n\x03msg\x18\x03 \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\x04 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12\x10\n\x08\x63hain_id\x18\x05 \x01(\x04\"$\n\x0f\x45THSignResponse\x12\x11\n\tsignature\x18\x01 \x01(\x0c\"\xfb\x05\n\x1a\x45THSignTypedMessageRequest\x12\x10\n\x08\x63hain_id\x18\x01 \x01(\x04\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12J\n\x05types\x18\x03 \x03(\x0b\x32;.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.StructType\x12\x14\n\x0cprimary_type\x18\x04 \x01(\t\x12R\n\x15host_nonce_commitment\x18\x05 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x1a\xc9\x01\n\nMemberType\x12G\n\x04type\x18\x01 \x01(\x0e\x32\x39.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.DataType\x12\x0c\n\x04size\x18\x02 \x01(\r\x12\x13\n\x0bstruct_name\x18\x03 \x01(\t\x12O\n\narray_type\x18\x04 \x01(\x0b\x32;.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.MemberType\x1a\x61\n\x06Member\x12\x0c\n\x04name\x18\x01 \x01(\t\x12I\n\x04type\x18\x02 \x01(\x0b\x32;.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.MemberType\x1a\x64\n\nStructType\x12\x0c\n\x04name\x18\x01 \x01(\t\x12H\n\x07members\x18\x02 \x03(\x0b\x32\x37.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.Member\"o\n\x08\x44\x61taType\x12\x0b\n\x07UNKNOWN\x10\x00\x12\t\n\x05\x42YTES\x10\x01\x12\x08\n\x04UINT\x10\x02\x12\x07\n\x03INT\x10\x03\x12\x08\n\x04\x42OOL\x10\x04\x12\x0b\n\x07\x41\x44\x44RESS\x10\x05\x12\n\n\x06STRING\x10\x06\x12\t\n\x05\x41RRAY\x10\x07\x12\n\n\x06STRUCT\x10\x08\"\xb4\x01\n\x1c\x45THTypedMessageValueResponse\x12R\n\x0broot_object\x18\x01 \x01(\x0e\x32=.shiftcrypto.bitbox02.ETHTypedMessageValueResponse.RootObject\x12\x0c\n\x04path\x18\x02 \x03(\r\"2\n\nRootObject\x12\x0b\n\x07UNKNOWN\x10\x00\x12\n\n\x06\x44OMAIN\x10\x01\x12\x0b\n\x07MESSAGE\x10\x02\",\n\x1b\x45THTypedMessageValueRequest\x12\r\n\x05value\x18\x01 \x01(\x0c\"\xc9\x04\n\nETHRequest\x12\x32\n\x03pub\x18\x01 \x01(\x0b\x32#.shiftcrypto.bitbox02.ETHPubRequestH\x00\x12\x34\n\x04sign\x18\x02 \x01(\x0b\x32$.shiftcrypto.bitbox02.ETHSignRequestH\x00\x12?\n\x08sign_msg\x18\x03 \x01(\x0b\x32+.shiftcrypto.bitbox02.ETHSignMessageRequestH\x00\x12P\n\x14\x61ntiklepto_signature\x18\x04 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignatureRequestH\x00\x12J\n\x0esign_typed_msg\x18\x05 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.ETHSignTypedMessageRequestH\x00\x12L\n\x0ftyped_msg_value\x18\x06 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.ETHTypedMessageValueRequestH\x00\x12\x43\n\x0csign_eip1559\x18\x07 \x01(\x0b\x32+.shiftcrypto.bitbox02.ETHSignEIP1559RequestH\x00\x12T\n\x13\x64\x61ta_response_chunk\x18\x08 \x01(\x0b\x32\x35.shiftcrypto.bitbox02.ETHSignDataResponseChunkRequestH\x00\x42\t\n\x07request\"\x80\x03\n\x0b\x45THResponse\x12\x30\n\x03pub\x18\x01 \x01(\x0b\x32!.shiftcrypto.bitbox02.PubResponseH\x00\x12\x35\n\x04sign\x18\x02 \x01(\x0b\x32%.shiftcrypto.bitbox02.ETHSignResponseH\x00\x12X\n\x1c\x61ntiklepto_signer_commitment\x18\x03 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignerCommitmentH\x00\x12M\n\x0ftyped_msg_value\x18\x04 \x01(\x0b\x32\x32.shiftcrypto.bitbox02.ETHTypedMessageValueResponseH\x00\x12S\n\x12\x64\x61ta_request_chunk\x18\x05 \x01(\x0b\x32\x35.shiftcrypto.bitbox02.ETHSignDataRequestChunkResponseH\x00\x42\n\n\x08response*2\n\x07\x45THCoin\x12\x07\n\x03\x45TH\x10\x00\x12\x0e\n\nRopstenETH\x10\x01\x12\x0e\n\nRinkebyETH\x10\x02*d\n\x0e\x45THAddressCase\x12\x1a\n\x16\x45TH_ADDRESS_CASE_MIXED\x10\x00\x12\x1a\n\x16\x45TH_ADDRESS_CASE_UPPER\x10\x01\x12\x1a\n\x16\x45TH_ADDRESS_CASE_LOWER\x10\x02\x62\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\teth.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\x1a\x10\x61ntiklepto.proto\"\xf4\x01\n\rETHPubRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12+\n\x04\x63oin\x18\x02 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.ETHCoin\x12\x43\n\x0boutput_type\x18\x03 \x01(\x0e\x32..shiftcrypto.bitbox02.ETHPubRequest.OutputType\x12\x0f\n\x07\x64isplay\x18\x04 \x01(\x08\x12\x18\n\x10\x63ontract_address\x18\x05 \x01(\x0c\x12\x10\n\x08\x63hain_id\x18\x06 \x01(\x04\"#\n\nOutputType\x12\x0b\n\x07\x41\x44\x44RESS\x10\x00\x12\x08\n\x04XPUB\x10\x01\"\xac\x01\n\x0e\x45THPubsRequest\x12\x44\n\x0boutput_type\x18\x01 \x01(\x0e\x32/.shiftcrypto.bitbox02.ETHPubsRequest.OutputType\x12/\n\x08keypaths\x18\x02 \x03(\x0b\x32\x1d.shiftcrypto.bitbox02.Keypath\"#\n\nOutputType\x12\x0b\n\x07\x41\x44\x44RESS\x10\x00\x12\x08\n\x04XPUB\x10\x01\"\xea\x02\n\x0e\x45THSignRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.ETHCoin\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\r\n\x05nonce\x18\x03 \x01(\x0c\x12\x11\n\tgas_price\x18\x04 \x01(\x0c\x12\x11\n\tgas_limit\x18\x05 \x01(\x0c\x12\x11\n\trecipient\x18\x06 \x01(\x0c\x12\r\n\x05value\x18\x07 \x01(\x0c\x12\x0c\n\x04\x64\x61ta\x18\x08 \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\t \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12\x10\n\x08\x63hain_id\x18\n \x01(\x04\x12:\n\x0c\x61\x64\x64ress_case\x18\x0b \x01(\x0e\x32$.shiftcrypto.bitbox02.ETHAddressCase\x12\x13\n\x0b\x64\x61ta_length\x18\x0c \x01(\r\"\xec\x02\n\x15\x45THSignEIP1559Request\x12\x10\n\x08\x63hain_id\x18\x01 \x01(\x04\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\r\n\x05nonce\x18\x03 \x01(\x0c\x12 \n\x18max_priority_fee_per_gas\x18\x04 \x01(\x0c\x12\x17\n\x0fmax_fee_per_gas\x18\x05 \x01(\x0c\x12\x11\n\tgas_limit\x18\x06 \x01(\x0c\x12\x11\n\trecipient\x18\x07 \x01(\x0c\x12\r\n\x05value\x18\x08 \x01(\x0c\x12\x0c\n\x04\x64\x61ta\x18\t \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\n \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12:\n\x0c\x61\x64\x64ress_case\x18\x0b \x01(\x0e\x32$.shiftcrypto.bitbox02.ETHAddressCase\x12\x13\n\x0b\x64\x61ta_length\x18\x0c \x01(\r\"\xa9\x03\n\x15\x45THSignEIP4844Request\x12\x10\n\x08\x63hain_id\x18\x01 \x01(\x04\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\r\n\x05nonce\x18\x03 \x01(\x0c\x12 \n\x18max_priority_fee_per_gas\x18\x04 \x01(\x0c\x12\x17\n\x0fmax_fee_per_gas\x18\x05 \x01(\x0c\x12\x11\n\tgas_limit\x18\x06 \x01(\x0c\x12\x11\n\trecipient\x18\x07 \x01(\x0c\x12\r\n\x05value\x18\x08 \x01(\x0c\x12\x0c\n\x04\x64\x61ta\x18\t \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\n \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12:\n\x0c\x61\x64\x64ress_case\x18\x0b \x01(\x0e\x32$.shiftcrypto.bitbox02.ETHAddressCase\x12\x13\n\x0b\x64\x61ta_length\x18\x0c \x01(\r\x12\x1c\n\x14max_fee_per_blob_gas\x18\r \x01(\x0c\x12\x1d\n\x15\x62lob_versioned_hashes\x18\x0e \x03(\x0c\"A\n\x1f\x45THSignDataRequestChunkResponse\x12\x0e\n\x06offset\x18\x01 \x01(\r\x12\x0e\n\x06length\x18\x02 \x01(\r\"0\n\x1f\x45THSignDataResponseChunkRequest\x12\r\n\x05\x63hunk\x18\x01 \x01(\x0c\"\xc8\x01\n\x15\x45THSignMessageRequest\x12+\n\x04\x63oin\x18\x01 \x01(\x0e\x32\x1d.shiftcrypto.bitbox02.ETHCoin\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12\x0b\n\x03msg\x18\x03 \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\x04 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12\x10\n\x08\x63hain_id\x18\x05 \x01(\x04\"$\n\x0f\x45THSignResponse\x12\x11\n\tsignature\x18\x01 \x01(\x0c\"\xab\x06\n\x1a\x45THSignTypedMessageRequest\x12\x10\n\x08\x63hain_id\x18\x01 \x01(\x04\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12J\n\x05types\x18\x03 \x03(\x0b\x32;.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.StructType\x12\x14\n\x0cprimary_type\x18\x04 \x01(\t\x12R\n\x15host_nonce_commitment\x18\x05 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\x12\x18\n\x10\x64omain_separator\x18\x06 \x01(\x0c\x12\x14\n\x0cmessage_hash\x18\x07 \x01(\x0c\x1a\xc9\x01\n\nMemberType\x12G\n\x04type\x18\x01 \x01(\x0e\x32\x39.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.DataType\x12\x0c\n\x04size\x18\x02 \x01(\r\x12\x13\n\x0bstruct_name\x18\x03 \x01(\t\x12O\n\narray_type\x18\x04 \x01(\x0b\x32;.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.MemberType\x1a\x61\n\x06Member\x12\x0c\n\x04name\x18\x01 \x01(\t\x12I\n\x04type\x18\x02 \x01(\x0b\x32;.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.MemberType\x1a\x64\n\nStructType\x12\x0c\n\x04name\x18\x01 \x01(\t\x12H\n\x07members\x18\x02 \x03(\x0b\x32\x37.shiftcrypto.bitbox02.ETHSignTypedMessageRequest.Member\"o\n\x08\x44\x61taType\x12\x0b\n\x07UNKNOWN\x10\x00\x12\t\n\x05\x42YTES\x10\x01\x12\x08\n\x04UINT\x10\x02\x12\x07\n\x03INT\x10\x03\x12\x08\n\x04\x42OOL\x10\x04\x12\x0b\n\x07\x41\x44\x44RESS\x10\x05\x12\n\n\x06STRING\x10\x06\x12\t\n\x05\x41RRAY\x10\x07\x12\n\n\x06STRUCT\x10\x08\"\xb4\x01\n\x1c\x45THTypedMessageValueResponse\x12R\n\x0broot_object\x18\x01 \x01(\x0e\x32=.shiftcrypto.bitbox02.ETHTypedMessageValueResponse.RootObject\x12\x0c\n\x04path\x18\x02 \x03(\r\"2\n\nRootObject\x12\x0b\n\x07UNKNOWN\x10\x00\x12\n\n\x06\x44OMAIN\x10\x01\x12\x0b\n\x07MESSAGE\x10\x02\",\n\x1b\x45THTypedMessageValueRequest\x12\r\n\x05value\x18\x01 \x01(\x0c\"I\n\x19\x45THRegisterNetworkRequest\x12\x10\n\x08\x63hain_id\x18\x01 \x01(\x04\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0c\n\x04unit\x18\x03 \x01(\t\"\xa6\x04\n\x1b\x45THSignUserOperationRequest\x12\x10\n\x08\x63hain_id\x18\x01 \x01(\x04\x12\x0f\n\x07keypath\x18\x02 \x03(\r\x12`\n\x13\x65ntry_point_version\x18\x03 \x01(\x0e\x32\x43.shiftcrypto.bitbox02.ETHSignUserOperationRequest.EntryPointVersion\x12\x13\n\x0b\x65ntry_point\x18\x04 \x01(\x0c\x12\x0e\n\x06sender\x18\x05 \x01(\x0c\x12\r\n\x05nonce\x18\x06 \x01(\x0c\x12\x11\n\tinit_code\x18\x07 \x01(\x0c\x12\x11\n\tcall_data\x18\x08 \x01(\x0c\x12\x16\n\x0e\x63\x61ll_gas_limit\x18\t \x01(\x0c\x12\x1e\n\x16verification_gas_limit\x18\n \x01(\x0c\x12\x1c\n\x14pre_verification_gas\x18\x0b \x01(\x0c\x12\x17\n\x0fmax_fee_per_gas\x18\x0c \x01(\x0c\x12 \n\x18max_priority_fee_per_gas\x18\r \x01(\x0c\x12\x1a\n\x12paymaster_and_data\x18\x0e \x01(\x0c\x12R\n\x15host_nonce_commitment\x18\x0f \x01(\x0b\x32\x33.shiftcrypto.bitbox02.AntiKleptoHostNonceCommitment\"\'\n\x11\x45ntryPointVersion\x12\x08\n\x04V0_6\x10\x00\x12\x08\n\x04V0_7\x10\x01\"\x0c\n\nETHSuccess\"\xe3\x06\n\nETHRequest\x12\x32\n\x03pub\x18\x01 \x01(\x0b\x32#.shiftcrypto.bitbox02.ETHPubRequestH\x00\x12\x34\n\x04sign\x18\x02 \x01(\x0b\x32$.shiftcrypto.bitbox02.ETHSignRequestH\x00\x12?\n\x08sign_msg\x18\x03 \x01(\x0b\x32+.shiftcrypto.bitbox02.ETHSignMessageRequestH\x00\x12P\n\x14\x61ntiklepto_signature\x18\x04 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignatureRequestH\x00\x12J\n\x0esign_typed_msg\x18\x05 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.ETHSignTypedMessageRequestH\x00\x12L\n\x0ftyped_msg_value\x18\x06 \x01(\x0b\x32\x31.shiftcrypto.bitbox02.ETHTypedMessageValueRequestH\x00\x12\x43\n\x0csign_eip1559\x18\x07 \x01(\x0b\x32+.shiftcrypto.bitbox02.ETHSignEIP1559RequestH\x00\x12T\n\x13\x64\x61ta_response_chunk\x18\x08 \x01(\x0b\x32\x35.shiftcrypto.bitbox02.ETHSignDataResponseChunkRequestH\x00\x12K\n\x10register_network\x18\t \x01(\x0b\x32/.shiftcrypto.bitbox02.ETHRegisterNetworkRequestH\x00\x12P\n\x13sign_user_operation\x18\n \x01(\x0b\x32\x31.shiftcrypto.bitbox02.ETHSignUserOperationRequestH\x00\x12\x43\n\x0csign_eip4844\x18\x0b \x01(\x0b\x32+.shiftcrypto.bitbox02.ETHSignEIP4844RequestH\x00\x12\x34\n\x04pubs\x18\x0c \x01(\x0b\x32$.shiftcrypto.bitbox02.ETHPubsRequestH\x00\x42\t\n\x07request\"\xe9\x03\n\x0b\x45THResponse\x12\x30\n\x03pub\x18\x01 \x01(\x0b\x32!.shiftcrypto.bitbox02.PubResponseH\x00\x12\x35\n\x04sign\x18\x02 \x01(\x0b\x32%.shiftcrypto.bitbox02.ETHSignResponseH\x00\x12X\n\x1c\x61ntiklepto_signer_commitment\x18\x03 \x01(\x0b\x32\x30.shiftcrypto.bitbox02.AntiKleptoSignerCommitmentH\x00\x12M\n\x0ftyped_msg_value\x18\x04 \x01(\x0b\x32\x32.shiftcrypto.bitbox02.ETHTypedMessageValueResponseH\x00\x12S\n\x12\x64\x61ta_request_chunk\x18\x05 \x01(\x0b\x32\x35.shiftcrypto.bitbox02.ETHSignDataRequestChunkResponseH\x00\x12\x33\n\x07success\x18\x06 \x01(\x0b\x32 .shiftcrypto.bitbox02.ETHSuccessH\x00\x12\x32\n\x04pubs\x18\x07 \x01(\x0b\x32\".shiftcrypto.bitbox02.PubsResponseH\x00\x42\n\n\x08response*2\n\x07\x45THCoin\x12\x07\n\x03\x45TH\x10\x00\x12\x0e\n\nRopstenETH\x10\x01\x12\x0e\n\nRinkebyETH\x10\x02*d\n\x0e\x45THAddressCase\x12\x1a\n\x16\x45TH_ADDRESS_CASE_MIXED\x10\x00\x12\x1a\n\x16\x45TH_ADDRESS_CASE_UPPER\x10\x01\x12\x1a\n\x16\x45TH_ADDRESS_CASE_LOWER\x10\x02\x62\x06proto3')
 
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'eth_pb2', globals())
 if _descriptor._USE_C_DESCRIPTORS == False:
 
   DESCRIPTOR._options = None
-  _ETHCOIN._serialized_start=3374
-  _ETHCOIN._serialized_end=3424
-  _ETHADDRESSCASE._serialized_start=3426
-  _ETHADDRESSCASE._serialized_end=3526
+  _ETHCOIN._serialized_start=5054
+  _ETHCOIN._serialized_end=5104
+  _ETHADDRESSCASE._serialized_start=5106
+  _ETHADDRESSCASE._serialized_end=5206
   _ETHPUBREQUEST._serialized_start=68
   _ETHPUBREQUEST._serialized_end=312
   _ETHPUBREQUEST_OUTPUTTYPE._serialized_start=277
   _ETHPUBREQUEST_OUTPUTTYPE._serialized_end=312
-  _ETHSIGNREQUEST._serialized_start=315
-  _ETHSIGNREQUEST._serialized_end=677
-  _ETHSIGNEIP1559REQUEST._serialized_start=680
-  _ETHSIGNEIP1559REQUEST._serialized_end=1044
-  _ETHSIGNDATAREQUESTCHUNKRESPONSE._serialized_start=1046
-  _ETHSIGNDATAREQUESTCHUNKRESPONSE._serialized_end=1111
-  _ETHSIGNDATARESPONSECHUNKREQUEST._serialized_start=1113
-  _ETHSIGNDATARESPONSECHUNKREQUEST._serialized_end=1161
-  _ETHSIGNMESSAGEREQUEST._serialized_start=1164
-  _ETHSIGNMESSAGEREQUEST._serialized_end=1364
-  _ETHSIGNRESPONSE._serialized_start=1366
-  _ETHSIGNRESPONSE._serialized_end=1402
-  _ETHSIGNTYPEDMESSAGEREQUEST._serialized_start=1405
-  _ETHSIGNTYPEDMESSAGEREQUEST._serialized_end=2168
-  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBERTYPE._serialized_start=1653
-  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBERTYPE._serialized_end=1854
-  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBER._serialized_start=1856
-  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBER._serialized_end=1953
-  _ETHSIGNTYPEDMESSAGEREQUEST_STRUCTTYPE._serialized_start=1955
-  _ETHSIGNTYPEDMESSAGEREQUEST_STRUCTTYPE._serialized_end=2055
-  _ETHSIGNTYPEDMESSAGEREQUEST_DATATYPE._serialized_start=2057
-  _ETHSIGNTYPEDMESSAGEREQUEST_DATATYPE._serialized_end=2168
-  _ETHTYPEDMESSAGEVALUERESPONSE._serialized_start=2171
-  _ETHTYPEDMESSAGEVALUERESPONSE._serialized_end=2351
-  _ETHTYPEDMESSAGEVALUERESPONSE_ROOTOBJECT._serialized_start=2301
-  _ETHTYPEDMESSAGEVALUERESPONSE_ROOTOBJECT._serialized_end=2351
-  _ETHTYPEDMESSAGEVALUEREQUEST._serialized_start=2353
-  _ETHTYPEDMESSAGEVALUEREQUEST._serialized_end=2397
-  _ETHREQUEST._serialized_start=2400
-  _ETHREQUEST._serialized_end=2985
-  _ETHRESPONSE._serialized_start=2988
-  _ETHRESPONSE._serialized_end=3372
+  _ETHPUBSREQUEST._serialized_start=315
+  _ETHPUBSREQUEST._serialized_end=487
+  _ETHPUBSREQUEST_OUTPUTTYPE._serialized_start=277
+  _ETHPUBSREQUEST_OUTPUTTYPE._serialized_end=312
+  _ETHSIGNREQUEST._serialized_start=490
+  _ETHSIGNREQUEST._serialized_end=852
+  _ETHSIGNEIP1559REQUEST._serialized_start=855
+  _ETHSIGNEIP1559REQUEST._serialized_end=1219
+  _ETHSIGNEIP4844REQUEST._serialized_start=1222
+  _ETHSIGNEIP4844REQUEST._serialized_end=1647
+  _ETHSIGNDATAREQUESTCHUNKRESPONSE._serialized_start=1649
+  _ETHSIGNDATAREQUESTCHUNKRESPONSE._serialized_end=1714
+  _ETHSIGNDATARESPONSECHUNKREQUEST._serialized_start=1716
+  _ETHSIGNDATARESPONSECHUNKREQUEST._serialized_end=1764
+  _ETHSIGNMESSAGEREQUEST._serialized_start=1767
+  _ETHSIGNMESSAGEREQUEST._serialized_end=1967
+  _ETHSIGNRESPONSE._serialized_start=1969
+  _ETHSIGNRESPONSE._serialized_end=2005
+  _ETHSIGNTYPEDMESSAGEREQUEST._serialized_start=2008
+  _ETHSIGNTYPEDMESSAGEREQUEST._serialized_end=2819
+  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBERTYPE._serialized_start=2304
+  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBERTYPE._serialized_end=2505
+  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBER._serialized_start=2507
+  _ETHSIGNTYPEDMESSAGEREQUEST_MEMBER._serialized_end=2604
+  _ETHSIGNTYPEDMESSAGEREQUEST_STRUCTTYPE._serialized_start=2606
+  _ETHSIGNTYPEDMESSAGEREQUEST_STRUCTTYPE._serialized_end=2706
+  _ETHSIGNTYPEDMESSAGEREQUEST_DATATYPE._serialized_start=2708
+  _ETHSIGNTYPEDMESSAGEREQUEST_DATATYPE._serialized_end=2819
+  _ETHTYPEDMESSAGEVALUERESPONSE._serialized_start=2822
+  _ETHTYPEDMESSAGEVALUERESPONSE._serialized_end=3002
+  _ETHTYPEDMESSAGEVALUERESPONSE_ROOTOBJECT._serialized_start=2952
+  _ETHTYPEDMESSAGEVALUERESPONSE_ROOTOBJECT._serialized_end=3002
+  _ETHTYPEDMESSAGEVALUEREQUEST._serialized_start=3004
+  _ETHTYPEDMESSAGEVALUEREQUEST._serialized_end=3048
+  _ETHREGISTERNETWORKREQUEST._serialized_start=3050
+  _ETHREGISTERNETWORKREQUEST._serialized_end=3123
+  _ETHSIGNUSEROPERATIONREQUEST._serialized_start=3126
+  _ETHSIGNUSEROPERATIONREQUEST._serialized_end=3676
+  _ETHSIGNUSEROPERATIONREQUEST_ENTRYPOINTVERSION._serialized_start=3637
+  _ETHSIGNUSEROPERATIONREQUEST_ENTRYPOINTVERSION._serialized_end=3676
+  _ETHSUCCESS._serialized_start=3678
+  _ETHSUCCESS._serialized_end=3690
+  _ETHREQUEST._serialized_start=3693
+  _ETHREQUEST._serialized_end=4560
+  _ETHRESPONSE._serialized_start=4563
+  _ETHRESPONSE._serialized_end=5052
 # @@protoc_insertion_point(module_scope)
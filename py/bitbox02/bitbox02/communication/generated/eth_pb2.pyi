@@ -106,6 +106,40 @@ class ETHPubRequest(google.protobuf.message.Message):
 
 global___ETHPubRequest = ETHPubRequest
 
+@typing.final
+class ETHPubsRequest(google.protobuf.message.Message):
+    """Retrieves many addresses or xpubs at once, without displaying them."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    class _OutputType:
+        ValueType = typing.NewType("ValueType", builtins.int)
+        V: typing_extensions.TypeAlias = ValueType
+
+    class _OutputTypeEnumTypeWrapper(google.protobuf.internal.enum_type_wrapper._EnumTypeWrapper[ETHPubsRequest._OutputType.ValueType], builtins.type):
+        DESCRIPTOR: google.protobuf.descriptor.EnumDescriptor
+        ADDRESS: ETHPubsRequest._OutputType.ValueType  # 0
+        XPUB: ETHPubsRequest._OutputType.ValueType  # 1
+
+    class OutputType(_OutputType, metaclass=_OutputTypeEnumTypeWrapper): ...
+    ADDRESS: ETHPubsRequest.OutputType.ValueType  # 0
+    XPUB: ETHPubsRequest.OutputType.ValueType  # 1
+
+    OUTPUT_TYPE_FIELD_NUMBER: builtins.int
+    KEYPATHS_FIELD_NUMBER: builtins.int
+    output_type: global___ETHPubsRequest.OutputType.ValueType
+    @property
+    def keypaths(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[common_pb2.Keypath]: ...
+    def __init__(
+        self,
+        *,
+        output_type: global___ETHPubsRequest.OutputType.ValueType = ...,
+        keypaths: collections.abc.Iterable[common_pb2.Keypath] | None = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["keypaths", b"keypaths", "output_type", b"output_type"]) -> None: ...
+
+global___ETHPubsRequest = ETHPubsRequest
+
 @typing.final
 class ETHSignRequest(google.protobuf.message.Message):
     """TX payload for "legacy" (EIP-155) transactions: https://eips.ethereum.org/EIPS/eip-155"""
@@ -227,6 +261,78 @@ class ETHSignEIP1559Request(google.protobuf.message.Message):
 
 global___ETHSignEIP1559Request = ETHSignEIP1559Request
 
+@typing.final
+class ETHSignEIP4844Request(google.protobuf.message.Message):
+    """TX payload for an EIP-4844 (type 3) blob transaction: https://eips.ethereum.org/EIPS/eip-4844
+    The blobs themselves are not part of the signed transaction, only their versioned hashes.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHAIN_ID_FIELD_NUMBER: builtins.int
+    KEYPATH_FIELD_NUMBER: builtins.int
+    NONCE_FIELD_NUMBER: builtins.int
+    MAX_PRIORITY_FEE_PER_GAS_FIELD_NUMBER: builtins.int
+    MAX_FEE_PER_GAS_FIELD_NUMBER: builtins.int
+    GAS_LIMIT_FIELD_NUMBER: builtins.int
+    RECIPIENT_FIELD_NUMBER: builtins.int
+    VALUE_FIELD_NUMBER: builtins.int
+    DATA_FIELD_NUMBER: builtins.int
+    HOST_NONCE_COMMITMENT_FIELD_NUMBER: builtins.int
+    ADDRESS_CASE_FIELD_NUMBER: builtins.int
+    DATA_LENGTH_FIELD_NUMBER: builtins.int
+    MAX_FEE_PER_BLOB_GAS_FIELD_NUMBER: builtins.int
+    BLOB_VERSIONED_HASHES_FIELD_NUMBER: builtins.int
+    chain_id: builtins.int
+    nonce: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    max_priority_fee_per_gas: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    max_fee_per_gas: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    gas_limit: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    recipient: builtins.bytes
+    """20 byte recipient"""
+    value: builtins.bytes
+    """smallest big endian serialization, max. 32 bytes"""
+    data: builtins.bytes
+    address_case: global___ETHAddressCase.ValueType
+    data_length: builtins.int
+    """For streaming: if non-zero, data field should be empty and data will be requested in chunks"""
+    max_fee_per_blob_gas: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    @property
+    def host_nonce_commitment(self) -> antiklepto_pb2.AntiKleptoHostNonceCommitment: ...
+    @property
+    def blob_versioned_hashes(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.bytes]:
+        """32 byte versioned hashes of the blobs, 1 to 6 entries."""
+
+    def __init__(
+        self,
+        *,
+        chain_id: builtins.int = ...,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        nonce: builtins.bytes = ...,
+        max_priority_fee_per_gas: builtins.bytes = ...,
+        max_fee_per_gas: builtins.bytes = ...,
+        gas_limit: builtins.bytes = ...,
+        recipient: builtins.bytes = ...,
+        value: builtins.bytes = ...,
+        data: builtins.bytes = ...,
+        host_nonce_commitment: antiklepto_pb2.AntiKleptoHostNonceCommitment | None = ...,
+        address_case: global___ETHAddressCase.ValueType = ...,
+        data_length: builtins.int = ...,
+        max_fee_per_blob_gas: builtins.bytes = ...,
+        blob_versioned_hashes: collections.abc.Iterable[builtins.bytes] | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["host_nonce_commitment", b"host_nonce_commitment"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["address_case", b"address_case", "blob_versioned_hashes", b"blob_versioned_hashes", "chain_id", b"chain_id", "data", b"data", "data_length", b"data_length", "gas_limit", b"gas_limit", "host_nonce_commitment", b"host_nonce_commitment", "keypath", b"keypath", "max_fee_per_blob_gas", b"max_fee_per_blob_gas", "max_fee_per_gas", b"max_fee_per_gas", "max_priority_fee_per_gas", b"max_priority_fee_per_gas", "nonce", b"nonce", "recipient", b"recipient", "value", b"value"]) -> None: ...
+
+global___ETHSignEIP4844Request = ETHSignEIP4844Request
+
 @typing.final
 class ETHSignDataRequestChunkResponse(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -406,8 +512,17 @@ class ETHSignTypedMessageRequest(google.protobuf.message.Message):
     TYPES_FIELD_NUMBER: builtins.int
     PRIMARY_TYPE_FIELD_NUMBER: builtins.int
     HOST_NONCE_COMMITMENT_FIELD_NUMBER: builtins.int
+    DOMAIN_SEPARATOR_FIELD_NUMBER: builtins.int
+    MESSAGE_HASH_FIELD_NUMBER: builtins.int
     chain_id: builtins.int
     primary_type: builtins.str
+    domain_separator: builtins.bytes
+    """Blind signing: if both are set, `types` and `primary_type` are ignored and the message is
+    signed as keccak256("\\x19\\x01" || domain_separator || message_hash) without streaming its
+    values. Both must be 32 bytes. Only allowed if blind signing was enabled on the device
+    using `SetBlindSigningEnabledRequest`.
+    """
+    message_hash: builtins.bytes
     @property
     def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
     @property
@@ -422,9 +537,11 @@ class ETHSignTypedMessageRequest(google.protobuf.message.Message):
         types: collections.abc.Iterable[global___ETHSignTypedMessageRequest.StructType] | None = ...,
         primary_type: builtins.str = ...,
         host_nonce_commitment: antiklepto_pb2.AntiKleptoHostNonceCommitment | None = ...,
+        domain_separator: builtins.bytes = ...,
+        message_hash: builtins.bytes = ...,
     ) -> None: ...
     def HasField(self, field_name: typing.Literal["host_nonce_commitment", b"host_nonce_commitment"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["chain_id", b"chain_id", "host_nonce_commitment", b"host_nonce_commitment", "keypath", b"keypath", "primary_type", b"primary_type", "types", b"types"]) -> None: ...
+    def ClearField(self, field_name: typing.Literal["chain_id", b"chain_id", "domain_separator", b"domain_separator", "host_nonce_commitment", b"host_nonce_commitment", "keypath", b"keypath", "message_hash", b"message_hash", "primary_type", b"primary_type", "types", b"types"]) -> None: ...
 
 global___ETHSignTypedMessageRequest = ETHSignTypedMessageRequest
 
@@ -477,6 +594,136 @@ class ETHTypedMessageValueRequest(google.protobuf.message.Message):
 
 global___ETHTypedMessageValueRequest = ETHTypedMessageValueRequest
 
+@typing.final
+class ETHRegisterNetworkRequest(google.protobuf.message.Message):
+    """Registers a network that is not built into the firmware. After registration, the network is
+    shown by its name, followed by its chain ID, and its unit instead of a warning about an unknown
+    chain ID.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    CHAIN_ID_FIELD_NUMBER: builtins.int
+    NAME_FIELD_NUMBER: builtins.int
+    UNIT_FIELD_NUMBER: builtins.int
+    chain_id: builtins.int
+    name: builtins.str
+    """Printable ASCII, at most 30 bytes. Must not be the name of a built-in network."""
+    unit: builtins.str
+    """Native currency unit, e.g. "ETH". Printable ASCII without spaces, at most 10 bytes."""
+    def __init__(
+        self,
+        *,
+        chain_id: builtins.int = ...,
+        name: builtins.str = ...,
+        unit: builtins.str = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["chain_id", b"chain_id", "name", b"name", "unit", b"unit"]) -> None: ...
+
+global___ETHRegisterNetworkRequest = ETHRegisterNetworkRequest
+
+@typing.final
+class ETHSignUserOperationRequest(google.protobuf.message.Message):
+    """ERC-4337 user operation of a smart account: https://eips.ethereum.org/EIPS/eip-4337
+
+    The fields are given unpacked for both EntryPoint versions. For v0.7, the device packs them into
+    a PackedUserOperation: `init_code` is `factory || factoryData` and `paymaster_and_data` is
+    `paymaster || paymasterVerificationGasLimit (16 bytes) || paymasterPostOpGasLimit (16 bytes) ||
+    paymasterData`.
+
+    The signature is over the EIP-191 message hash of the 32 byte userOpHash, as expected by
+    smart accounts that use `toEthSignedMessageHash(userOpHash)` to validate signatures.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    class _EntryPointVersion:
+        ValueType = typing.NewType("ValueType", builtins.int)
+        V: typing_extensions.TypeAlias = ValueType
+
+    class _EntryPointVersionEnumTypeWrapper(google.protobuf.internal.enum_type_wrapper._EnumTypeWrapper[ETHSignUserOperationRequest._EntryPointVersion.ValueType], builtins.type):
+        DESCRIPTOR: google.protobuf.descriptor.EnumDescriptor
+        V0_6: ETHSignUserOperationRequest._EntryPointVersion.ValueType  # 0
+        V0_7: ETHSignUserOperationRequest._EntryPointVersion.ValueType  # 1
+
+    class EntryPointVersion(_EntryPointVersion, metaclass=_EntryPointVersionEnumTypeWrapper): ...
+    V0_6: ETHSignUserOperationRequest.EntryPointVersion.ValueType  # 0
+    V0_7: ETHSignUserOperationRequest.EntryPointVersion.ValueType  # 1
+
+    CHAIN_ID_FIELD_NUMBER: builtins.int
+    KEYPATH_FIELD_NUMBER: builtins.int
+    ENTRY_POINT_VERSION_FIELD_NUMBER: builtins.int
+    ENTRY_POINT_FIELD_NUMBER: builtins.int
+    SENDER_FIELD_NUMBER: builtins.int
+    NONCE_FIELD_NUMBER: builtins.int
+    INIT_CODE_FIELD_NUMBER: builtins.int
+    CALL_DATA_FIELD_NUMBER: builtins.int
+    CALL_GAS_LIMIT_FIELD_NUMBER: builtins.int
+    VERIFICATION_GAS_LIMIT_FIELD_NUMBER: builtins.int
+    PRE_VERIFICATION_GAS_FIELD_NUMBER: builtins.int
+    MAX_FEE_PER_GAS_FIELD_NUMBER: builtins.int
+    MAX_PRIORITY_FEE_PER_GAS_FIELD_NUMBER: builtins.int
+    PAYMASTER_AND_DATA_FIELD_NUMBER: builtins.int
+    HOST_NONCE_COMMITMENT_FIELD_NUMBER: builtins.int
+    chain_id: builtins.int
+    entry_point_version: global___ETHSignUserOperationRequest.EntryPointVersion.ValueType
+    entry_point: builtins.bytes
+    """20 bytes"""
+    sender: builtins.bytes
+    """20 bytes"""
+    nonce: builtins.bytes
+    """smallest big endian serialization, max. 32 bytes"""
+    init_code: builtins.bytes
+    call_data: builtins.bytes
+    call_gas_limit: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    verification_gas_limit: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    pre_verification_gas: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    max_fee_per_gas: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    max_priority_fee_per_gas: builtins.bytes
+    """smallest big endian serialization, max. 16 bytes"""
+    paymaster_and_data: builtins.bytes
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    @property
+    def host_nonce_commitment(self) -> antiklepto_pb2.AntiKleptoHostNonceCommitment: ...
+    def __init__(
+        self,
+        *,
+        chain_id: builtins.int = ...,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        entry_point_version: global___ETHSignUserOperationRequest.EntryPointVersion.ValueType = ...,
+        entry_point: builtins.bytes = ...,
+        sender: builtins.bytes = ...,
+        nonce: builtins.bytes = ...,
+        init_code: builtins.bytes = ...,
+        call_data: builtins.bytes = ...,
+        call_gas_limit: builtins.bytes = ...,
+        verification_gas_limit: builtins.bytes = ...,
+        pre_verification_gas: builtins.bytes = ...,
+        max_fee_per_gas: builtins.bytes = ...,
+        max_priority_fee_per_gas: builtins.bytes = ...,
+        paymaster_and_data: builtins.bytes = ...,
+        host_nonce_commitment: antiklepto_pb2.AntiKleptoHostNonceCommitment | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["host_nonce_commitment", b"host_nonce_commitment"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["call_data", b"call_data", "call_gas_limit", b"call_gas_limit", "chain_id", b"chain_id", "entry_point", b"entry_point", "entry_point_version", b"entry_point_version", "host_nonce_commitment", b"host_nonce_commitment", "init_code", b"init_code", "keypath", b"keypath", "max_fee_per_gas", b"max_fee_per_gas", "max_priority_fee_per_gas", b"max_priority_fee_per_gas", "nonce", b"nonce", "paymaster_and_data", b"paymaster_and_data", "pre_verification_gas", b"pre_verification_gas", "sender", b"sender", "verification_gas_limit", b"verification_gas_limit"]) -> None: ...
+
+global___ETHSignUserOperationRequest = ETHSignUserOperationRequest
+
+@typing.final
+class ETHSuccess(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    def __init__(
+        self,
+    ) -> None: ...
+
+global___ETHSuccess = ETHSuccess
+
 @typing.final
 class ETHRequest(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -489,6 +736,10 @@ class ETHRequest(google.protobuf.message.Message):
     TYPED_MSG_VALUE_FIELD_NUMBER: builtins.int
     SIGN_EIP1559_FIELD_NUMBER: builtins.int
     DATA_RESPONSE_CHUNK_FIELD_NUMBER: builtins.int
+    REGISTER_NETWORK_FIELD_NUMBER: builtins.int
+    SIGN_USER_OPERATION_FIELD_NUMBER: builtins.int
+    SIGN_EIP4844_FIELD_NUMBER: builtins.int
+    PUBS_FIELD_NUMBER: builtins.int
     @property
     def pub(self) -> global___ETHPubRequest: ...
     @property
@@ -505,6 +756,14 @@ class ETHRequest(google.protobuf.message.Message):
     def sign_eip1559(self) -> global___ETHSignEIP1559Request: ...
     @property
     def data_response_chunk(self) -> global___ETHSignDataResponseChunkRequest: ...
+    @property
+    def register_network(self) -> global___ETHRegisterNetworkRequest: ...
+    @property
+    def sign_user_operation(self) -> global___ETHSignUserOperationRequest: ...
+    @property
+    def sign_eip4844(self) -> global___ETHSignEIP4844Request: ...
+    @property
+    def pubs(self) -> global___ETHPubsRequest: ...
     def __init__(
         self,
         *,
@@ -516,10 +775,14 @@ class ETHRequest(google.protobuf.message.Message):
         typed_msg_value: global___ETHTypedMessageValueRequest | None = ...,
         sign_eip1559: global___ETHSignEIP1559Request | None = ...,
         data_response_chunk: global___ETHSignDataResponseChunkRequest | None = ...,
+        register_network: global___ETHRegisterNetworkRequest | None = ...,
+        sign_user_operation: global___ETHSignUserOperationRequest | None = ...,
+        sign_eip4844: global___ETHSignEIP4844Request | None = ...,
+        pubs: global___ETHPubsRequest | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "data_response_chunk", b"data_response_chunk", "pub", b"pub", "request", b"request", "sign", b"sign", "sign_eip1559", b"sign_eip1559", "sign_msg", b"sign_msg", "sign_typed_msg", b"sign_typed_msg", "typed_msg_value", b"typed_msg_value"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "data_response_chunk", b"data_response_chunk", "pub", b"pub", "request", b"request", "sign", b"sign", "sign_eip1559", b"sign_eip1559", "sign_msg", b"sign_msg", "sign_typed_msg", b"sign_typed_msg", "typed_msg_value", b"typed_msg_value"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["pub", "sign", "sign_msg", "antiklepto_signature", "sign_typed_msg", "typed_msg_value", "sign_eip1559", "data_response_chunk"] | None: ...
+    def HasField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "data_response_chunk", b"data_response_chunk", "pub", b"pub", "pubs", b"pubs", "register_network", b"register_network", "request", b"request", "sign", b"sign", "sign_eip1559", b"sign_eip1559", "sign_eip4844", b"sign_eip4844", "sign_msg", b"sign_msg", "sign_typed_msg", b"sign_typed_msg", "sign_user_operation", b"sign_user_operation", "typed_msg_value", b"typed_msg_value"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["antiklepto_signature", b"antiklepto_signature", "data_response_chunk", b"data_response_chunk", "pub", b"pub", "pubs", b"pubs", "register_network", b"register_network", "request", b"request", "sign", b"sign", "sign_eip1559", b"sign_eip1559", "sign_eip4844", b"sign_eip4844", "sign_msg", b"sign_msg", "sign_typed_msg", b"sign_typed_msg", "sign_user_operation", b"sign_user_operation", "typed_msg_value", b"typed_msg_value"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["pub", "sign", "sign_msg", "antiklepto_signature", "sign_typed_msg", "typed_msg_value", "sign_eip1559", "data_response_chunk", "register_network", "sign_user_operation", "sign_eip4844", "pubs"] | None: ...
 
 global___ETHRequest = ETHRequest
 
@@ -532,6 +795,8 @@ class ETHResponse(google.protobuf.message.Message):
     ANTIKLEPTO_SIGNER_COMMITMENT_FIELD_NUMBER: builtins.int
     TYPED_MSG_VALUE_FIELD_NUMBER: builtins.int
     DATA_REQUEST_CHUNK_FIELD_NUMBER: builtins.int
+    SUCCESS_FIELD_NUMBER: builtins.int
+    PUBS_FIELD_NUMBER: builtins.int
     @property
     def pub(self) -> common_pb2.PubResponse: ...
     @property
@@ -542,6 +807,10 @@ class ETHResponse(google.protobuf.message.Message):
     def typed_msg_value(self) -> global___ETHTypedMessageValueResponse: ...
     @property
     def data_request_chunk(self) -> global___ETHSignDataRequestChunkResponse: ...
+    @property
+    def success(self) -> global___ETHSuccess: ...
+    @property
+    def pubs(self) -> common_pb2.PubsResponse: ...
     def __init__(
         self,
         *,
@@ -550,9 +819,11 @@ class ETHResponse(google.protobuf.message.Message):
         antiklepto_signer_commitment: antiklepto_pb2.AntiKleptoSignerCommitment | None = ...,
         typed_msg_value: global___ETHTypedMessageValueResponse | None = ...,
         data_request_chunk: global___ETHSignDataRequestChunkResponse | None = ...,
+        success: global___ETHSuccess | None = ...,
+        pubs: common_pb2.PubsResponse | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "data_request_chunk", b"data_request_chunk", "pub", b"pub", "response", b"response", "sign", b"sign", "typed_msg_value", b"typed_msg_value"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "data_request_chunk", b"data_request_chunk", "pub", b"pub", "response", b"response", "sign", b"sign", "typed_msg_value", b"typed_msg_value"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["pub", "sign", "antiklepto_signer_commitment", "typed_msg_value", "data_request_chunk"] | None: ...
+    def HasField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "data_request_chunk", b"data_request_chunk", "pub", b"pub", "pubs", b"pubs", "response", b"response", "sign", b"sign", "success", b"success", "typed_msg_value", b"typed_msg_value"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["antiklepto_signer_commitment", b"antiklepto_signer_commitment", "data_request_chunk", b"data_request_chunk", "pub", b"pub", "pubs", b"pubs", "response", b"response", "sign", b"sign", "success", b"success", "typed_msg_value", b"typed_msg_value"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["pub", "sign", "antiklepto_signer_commitment", "typed_msg_value", "data_request_chunk", "success", "pubs"] | None: ...
 
 global___ETHResponse = ETHResponse
@@ -14,7 +14,7 @@ _sym_db = _symbol_database.Default()
 from google.protobuf import empty_pb2 as google_dot_protobuf_dot_empty__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0ekeystore.proto\x12\x14shiftcrypto.bitbox02\x1a\x1bgoogle/protobuf/empty.proto\"/\n\x1c\x45lectrumEncryptionKeyRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\",\n\x1d\x45lectrumEncryptionKeyResponse\x12\x0b\n\x03key\x18\x01 \x01(\t\"\x97\x01\n\x0c\x42IP85Request\x12\'\n\x05\x62ip39\x18\x01 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12\x36\n\x02ln\x18\x02 \x01(\x0b\x32(.shiftcrypto.bitbox02.BIP85Request.AppLnH\x00\x1a\x1f\n\x05\x41ppLn\x12\x16\n\x0e\x61\x63\x63ount_number\x18\x01 \x01(\rB\x05\n\x03\x61pp\"M\n\rBIP85Response\x12\'\n\x05\x62ip39\x18\x01 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12\x0c\n\x02ln\x18\x02 \x01(\x0cH\x00\x42\x05\n\x03\x61ppb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0ekeystore.proto\x12\x14shiftcrypto.bitbox02\x1a\x1bgoogle/protobuf/empty.proto\"/\n\x1c\x45lectrumEncryptionKeyRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\",\n\x1d\x45lectrumEncryptionKeyResponse\x12\x0b\n\x03key\x18\x01 \x01(\t\"\xe9\x02\n\x0c\x42IP85Request\x12\'\n\x05\x62ip39\x18\x01 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12\x36\n\x02ln\x18\x02 \x01(\x0b\x32(.shiftcrypto.bitbox02.BIP85Request.AppLnH\x00\x12%\n\x03hex\x18\x03 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12,\n\npwd_base64\x18\x04 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12,\n\npwd_base85\x18\x05 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12%\n\x03wif\x18\x06 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12&\n\x04xprv\x18\x07 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x1a\x1f\n\x05\x41ppLn\x12\x16\n\x0e\x61\x63\x63ount_number\x18\x01 \x01(\rB\x05\n\x03\x61pp\"\x9f\x02\n\rBIP85Response\x12\'\n\x05\x62ip39\x18\x01 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12\x0c\n\x02ln\x18\x02 \x01(\x0cH\x00\x12%\n\x03hex\x18\x03 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12,\n\npwd_base64\x18\x04 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12,\n\npwd_base85\x18\x05 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12%\n\x03wif\x18\x06 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x12&\n\x04xprv\x18\x07 \x01(\x0b\x32\x16.google.protobuf.EmptyH\x00\x42\x05\n\x03\x61ppb\x06proto3')
 
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'keystore_pb2', globals())
@@ -26,9 +26,9 @@ if _descriptor._USE_C_DESCRIPTORS == False:
   _ELECTRUMENCRYPTIONKEYRESPONSE._serialized_start=118
   _ELECTRUMENCRYPTIONKEYRESPONSE._serialized_end=162
   _BIP85REQUEST._serialized_start=165
-  _BIP85REQUEST._serialized_end=316
-  _BIP85REQUEST_APPLN._serialized_start=278
-  _BIP85REQUEST_APPLN._serialized_end=309
-  _BIP85RESPONSE._serialized_start=318
-  _BIP85RESPONSE._serialized_end=395
+  _BIP85REQUEST._serialized_end=526
+  _BIP85REQUEST_APPLN._serialized_start=488
+  _BIP85REQUEST_APPLN._serialized_end=519
+  _BIP85RESPONSE._serialized_start=529
+  _BIP85RESPONSE._serialized_end=816
 # @@protoc_insertion_point(module_scope)
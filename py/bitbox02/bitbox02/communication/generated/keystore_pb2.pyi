@@ -65,19 +65,39 @@ class BIP85Request(google.protobuf.message.Message):
 
     BIP39_FIELD_NUMBER: builtins.int
     LN_FIELD_NUMBER: builtins.int
+    HEX_FIELD_NUMBER: builtins.int
+    PWD_BASE64_FIELD_NUMBER: builtins.int
+    PWD_BASE85_FIELD_NUMBER: builtins.int
+    WIF_FIELD_NUMBER: builtins.int
+    XPRV_FIELD_NUMBER: builtins.int
     @property
     def bip39(self) -> google.protobuf.empty_pb2.Empty: ...
     @property
     def ln(self) -> global___BIP85Request.AppLn: ...
+    @property
+    def hex(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def pwd_base64(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def pwd_base85(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def wif(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def xprv(self) -> google.protobuf.empty_pb2.Empty: ...
     def __init__(
         self,
         *,
         bip39: google.protobuf.empty_pb2.Empty | None = ...,
         ln: global___BIP85Request.AppLn | None = ...,
+        hex: google.protobuf.empty_pb2.Empty | None = ...,
+        pwd_base64: google.protobuf.empty_pb2.Empty | None = ...,
+        pwd_base85: google.protobuf.empty_pb2.Empty | None = ...,
+        wif: google.protobuf.empty_pb2.Empty | None = ...,
+        xprv: google.protobuf.empty_pb2.Empty | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "ln", b"ln"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "ln", b"ln"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["app", b"app"]) -> typing.Literal["bip39", "ln"] | None: ...
+    def HasField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "hex", b"hex", "ln", b"ln", "pwd_base64", b"pwd_base64", "pwd_base85", b"pwd_base85", "wif", b"wif", "xprv", b"xprv"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "hex", b"hex", "ln", b"ln", "pwd_base64", b"pwd_base64", "pwd_base85", b"pwd_base85", "wif", b"wif", "xprv", b"xprv"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["app", b"app"]) -> typing.Literal["bip39", "ln", "hex", "pwd_base64", "pwd_base85", "wif", "xprv"] | None: ...
 
 global___BIP85Request = BIP85Request
 
@@ -87,17 +107,37 @@ class BIP85Response(google.protobuf.message.Message):
 
     BIP39_FIELD_NUMBER: builtins.int
     LN_FIELD_NUMBER: builtins.int
+    HEX_FIELD_NUMBER: builtins.int
+    PWD_BASE64_FIELD_NUMBER: builtins.int
+    PWD_BASE85_FIELD_NUMBER: builtins.int
+    WIF_FIELD_NUMBER: builtins.int
+    XPRV_FIELD_NUMBER: builtins.int
     ln: builtins.bytes
     @property
     def bip39(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def hex(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def pwd_base64(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def pwd_base85(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def wif(self) -> google.protobuf.empty_pb2.Empty: ...
+    @property
+    def xprv(self) -> google.protobuf.empty_pb2.Empty: ...
     def __init__(
         self,
         *,
         bip39: google.protobuf.empty_pb2.Empty | None = ...,
         ln: builtins.bytes = ...,
+        hex: google.protobuf.empty_pb2.Empty | None = ...,
+        pwd_base64: google.protobuf.empty_pb2.Empty | None = ...,
+        pwd_base85: google.protobuf.empty_pb2.Empty | None = ...,
+        wif: google.protobuf.empty_pb2.Empty | None = ...,
+        xprv: google.protobuf.empty_pb2.Empty | None = ...,
     ) -> None: ...
-    def HasField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "ln", b"ln"]) -> builtins.bool: ...
-    def ClearField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "ln", b"ln"]) -> None: ...
-    def WhichOneof(self, oneof_group: typing.Literal["app", b"app"]) -> typing.Literal["bip39", "ln"] | None: ...
+    def HasField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "hex", b"hex", "ln", b"ln", "pwd_base64", b"pwd_base64", "pwd_base85", b"pwd_base85", "wif", b"wif", "xprv", b"xprv"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["app", b"app", "bip39", b"bip39", "hex", b"hex", "ln", b"ln", "pwd_base64", b"pwd_base64", "pwd_base85", b"pwd_base85", "wif", b"wif", "xprv", b"xprv"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["app", b"app"]) -> typing.Literal["bip39", "ln", "hex", "pwd_base64", "pwd_base85", "wif", "xprv"] | None: ...
 
 global___BIP85Response = BIP85Response
@@ -13,7 +13,7 @@ _sym_db = _symbol_database.Default()
 
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0emnemonic.proto\x12\x14shiftcrypto.bitbox02\"\x15\n\x13ShowMnemonicRequest\"H\n\x1aRestoreFromMnemonicRequest\x12\x11\n\ttimestamp\x18\x01 \x01(\r\x12\x17\n\x0ftimezone_offset\x18\x02 \x01(\x05\"6\n#SetMnemonicPassphraseEnabledRequest\x12\x0f\n\x07\x65nabled\x18\x01 \x01(\x08\x62\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0emnemonic.proto\x12\x14shiftcrypto.bitbox02\"\x15\n\x13ShowMnemonicRequest\"H\n\x1aRestoreFromMnemonicRequest\x12\x11\n\ttimestamp\x18\x01 \x01(\r\x12\x17\n\x0ftimezone_offset\x18\x02 \x01(\x05\"\xb0\x01\n\x17ShowSlip39SharesRequest\x12\x17\n\x0fgroup_threshold\x18\x01 \x01(\r\x12\x43\n\x06groups\x18\x02 \x03(\x0b\x32\x33.shiftcrypto.bitbox02.ShowSlip39SharesRequest.Group\x1a\x37\n\x05Group\x12\x18\n\x10member_threshold\x18\x01 \x01(\r\x12\x14\n\x0cmember_count\x18\x02 \x01(\r\"L\n\x1eRestoreFromSlip39SharesRequest\x12\x11\n\ttimestamp\x18\x01 \x01(\r\x12\x17\n\x0ftimezone_offset\x18\x02 \x01(\x05\"6\n#SetMnemonicPassphraseEnabledRequest\x12\x0f\n\x07\x65nabled\x18\x01 \x01(\x08\x62\x06proto3')
 
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'mnemonic_pb2', globals())
@@ -24,6 +24,12 @@ if _descriptor._USE_C_DESCRIPTORS == False:
   _SHOWMNEMONICREQUEST._serialized_end=61
   _RESTOREFROMMNEMONICREQUEST._serialized_start=63
   _RESTOREFROMMNEMONICREQUEST._serialized_end=135
-  _SETMNEMONICPASSPHRASEENABLEDREQUEST._serialized_start=137
-  _SETMNEMONICPASSPHRASEENABLEDREQUEST._serialized_end=191
+  _SHOWSLIP39SHARESREQUEST._serialized_start=138
+  _SHOWSLIP39SHARESREQUEST._serialized_end=314
+  _SHOWSLIP39SHARESREQUEST_GROUP._serialized_start=259
+  _SHOWSLIP39SHARESREQUEST_GROUP._serialized_end=314
+  _RESTOREFROMSLIP39SHARESREQUEST._serialized_start=316
+  _RESTOREFROMSLIP39SHARESREQUEST._serialized_end=392
+  _SETMNEMONICPASSPHRASEENABLEDREQUEST._serialized_start=394
+  _SETMNEMONICPASSPHRASEENABLEDREQUEST._serialized_end=448
 # @@protoc_insertion_point(module_scope)
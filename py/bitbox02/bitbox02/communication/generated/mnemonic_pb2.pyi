@@ -4,7 +4,9 @@ isort:skip_file
 SPDX-License-Identifier: Apache-2.0"""
 
 import builtins
+import collections.abc
 import google.protobuf.descriptor
+import google.protobuf.internal.containers
 import google.protobuf.message
 import typing
 
@@ -38,6 +40,67 @@ class RestoreFromMnemonicRequest(google.protobuf.message.Message):
 
 global___RestoreFromMnemonicRequest = RestoreFromMnemonicRequest
 
+@typing.final
+class ShowSlip39SharesRequest(google.protobuf.message.Message):
+    """Splits the seed into SLIP-39 shares and displays them on the device. A share set is recovered if
+    `group_threshold` groups are complete, i.e. have `member_threshold` shares each. The shares encode
+    the BIP-32 seed, so this fails with an invalid state error for an initialized BIP-39 wallet. A
+    newly created seed is used as the BIP-32 seed from then on.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    @typing.final
+    class Group(google.protobuf.message.Message):
+        DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+        MEMBER_THRESHOLD_FIELD_NUMBER: builtins.int
+        MEMBER_COUNT_FIELD_NUMBER: builtins.int
+        member_threshold: builtins.int
+        member_count: builtins.int
+        def __init__(
+            self,
+            *,
+            member_threshold: builtins.int = ...,
+            member_count: builtins.int = ...,
+        ) -> None: ...
+        def ClearField(self, field_name: typing.Literal["member_count", b"member_count", "member_threshold", b"member_threshold"]) -> None: ...
+
+    GROUP_THRESHOLD_FIELD_NUMBER: builtins.int
+    GROUPS_FIELD_NUMBER: builtins.int
+    group_threshold: builtins.int
+    @property
+    def groups(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___ShowSlip39SharesRequest.Group]: ...
+    def __init__(
+        self,
+        *,
+        group_threshold: builtins.int = ...,
+        groups: collections.abc.Iterable[global___ShowSlip39SharesRequest.Group] | None = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["group_threshold", b"group_threshold", "groups", b"groups"]) -> None: ...
+
+global___ShowSlip39SharesRequest = ShowSlip39SharesRequest
+
+@typing.final
+class RestoreFromSlip39SharesRequest(google.protobuf.message.Message):
+    """Restores from SLIP-39 shares. The master secret is used as the BIP-32 seed."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    TIMESTAMP_FIELD_NUMBER: builtins.int
+    TIMEZONE_OFFSET_FIELD_NUMBER: builtins.int
+    timestamp: builtins.int
+    timezone_offset: builtins.int
+    def __init__(
+        self,
+        *,
+        timestamp: builtins.int = ...,
+        timezone_offset: builtins.int = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["timestamp", b"timestamp", "timezone_offset", b"timezone_offset"]) -> None: ...
+
+global___RestoreFromSlip39SharesRequest = RestoreFromSlip39SharesRequest
+
 @typing.final
 class SetMnemonicPassphraseEnabledRequest(google.protobuf.message.Message):
     DESCRIPTOR: google.protobuf.descriptor.Descriptor
@@ -0,0 +1,46 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# source: nostr.proto
+"""Generated protocol buffer code."""
+from google.protobuf.internal import builder as _builder
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import symbol_database as _symbol_database
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import common_pb2 as common__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0bnostr.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\"3\n\x0fNostrPubRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x0f\n\x07\x64isplay\x18\x02 \x01(\x08\"\x1a\n\x08NostrTag\x12\x0e\n\x06values\x18\x01 \x03(\t\"\x89\x01\n\x15NostrSignEventRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x12\n\ncreated_at\x18\x02 \x01(\x04\x12\x0c\n\x04kind\x18\x03 \x01(\r\x12,\n\x04tags\x18\x04 \x03(\x0b\x32\x1e.shiftcrypto.bitbox02.NostrTag\x12\x0f\n\x07\x63ontent\x18\x05 \x01(\t\"7\n\x16NostrSignEventResponse\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\x11\n\tsignature\x18\x02 \x01(\x0c\"\x8c\x01\n\x13NostrEncryptRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12;\n\x06scheme\x18\x02 \x01(\x0e\x32+.shiftcrypto.bitbox02.NostrEncryptionScheme\x12\x14\n\x0c\x63ounterparty\x18\x03 \x01(\x0c\x12\x11\n\tplaintext\x18\x04 \x01(\x0c\"\'\n\x14NostrEncryptResponse\x12\x0f\n\x07payload\x18\x01 \x01(\x0c\"\x8a\x01\n\x13NostrDecryptRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12;\n\x06scheme\x18\x02 \x01(\x0e\x32+.shiftcrypto.bitbox02.NostrEncryptionScheme\x12\x14\n\x0c\x63ounterparty\x18\x03 \x01(\x0c\x12\x0f\n\x07payload\x18\x04 \x01(\x0c\")\n\x14NostrDecryptResponse\x12\x11\n\tplaintext\x18\x01 \x01(\x0c\"\x8e\x02\n\x0cNostrRequest\x12\x34\n\x03pub\x18\x01 \x01(\x0b\x32%.shiftcrypto.bitbox02.NostrPubRequestH\x00\x12\x41\n\nsign_event\x18\x02 \x01(\x0b\x32+.shiftcrypto.bitbox02.NostrSignEventRequestH\x00\x12<\n\x07\x65ncrypt\x18\x03 \x01(\x0b\x32).shiftcrypto.bitbox02.NostrEncryptRequestH\x00\x12<\n\x07\x64\x65\x63rypt\x18\x04 \x01(\x0b\x32).shiftcrypto.bitbox02.NostrDecryptRequestH\x00\x42\t\n\x07request\"\x8f\x02\n\rNostrResponse\x12\x30\n\x03pub\x18\x01 \x01(\x0b\x32!.shiftcrypto.bitbox02.PubResponseH\x00\x12\x42\n\nsign_event\x18\x02 \x01(\x0b\x32,.shiftcrypto.bitbox02.NostrSignEventResponseH\x00\x12=\n\x07\x65ncrypt\x18\x03 \x01(\x0b\x32*.shiftcrypto.bitbox02.NostrEncryptResponseH\x00\x12=\n\x07\x64\x65\x63rypt\x18\x04 \x01(\x0b\x32*.shiftcrypto.bitbox02.NostrDecryptResponseH\x00\x42\n\n\x08response*O\n\x15NostrEncryptionScheme\x12\x1a\n\x16NOSTR_ENCRYPTION_NIP44\x10\x00\x12\x1a\n\x16NOSTR_ENCRYPTION_NIP04\x10\x01\x62\x06proto3')
+
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'nostr_pb2', globals())
+if _descriptor._USE_C_DESCRIPTORS == False:
+
+  DESCRIPTOR._options = None
+  _NOSTRENCRYPTIONSCHEME._serialized_start=1244
+  _NOSTRENCRYPTIONSCHEME._serialized_end=1323
+  _NOSTRPUBREQUEST._serialized_start=51
+  _NOSTRPUBREQUEST._serialized_end=102
+  _NOSTRTAG._serialized_start=104
+  _NOSTRTAG._serialized_end=130
+  _NOSTRSIGNEVENTREQUEST._serialized_start=133
+  _NOSTRSIGNEVENTREQUEST._serialized_end=270
+  _NOSTRSIGNEVENTRESPONSE._serialized_start=272
+  _NOSTRSIGNEVENTRESPONSE._serialized_end=327
+  _NOSTRENCRYPTREQUEST._serialized_start=330
+  _NOSTRENCRYPTREQUEST._serialized_end=470
+  _NOSTRENCRYPTRESPONSE._serialized_start=472
+  _NOSTRENCRYPTRESPONSE._serialized_end=511
+  _NOSTRDECRYPTREQUEST._serialized_start=514
+  _NOSTRDECRYPTREQUEST._serialized_end=652
+  _NOSTRDECRYPTRESPONSE._serialized_start=654
+  _NOSTRDECRYPTRESPONSE._serialized_end=695
+  _NOSTRREQUEST._serialized_start=698
+  _NOSTRREQUEST._serialized_end=968
+  _NOSTRRESPONSE._serialized_start=971
+  _NOSTRRESPONSE._serialized_end=1242
+# @@protoc_insertion_point(module_scope)
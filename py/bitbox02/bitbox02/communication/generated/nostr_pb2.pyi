@@ -0,0 +1,293 @@
+"""
+@generated by mypy-protobuf.  Do not edit manually!
+isort:skip_file
+SPDX-License-Identifier: Apache-2.0"""
+
+import builtins
+import collections.abc
+from . import common_pb2
+import google.protobuf.descriptor
+import google.protobuf.internal.containers
+import google.protobuf.internal.enum_type_wrapper
+import google.protobuf.message
+import sys
+import typing
+
+if sys.version_info >= (3, 10):
+    import typing as typing_extensions
+else:
+    import typing_extensions
+
+DESCRIPTOR: google.protobuf.descriptor.FileDescriptor
+
+class _NostrEncryptionScheme:
+    ValueType = typing.NewType("ValueType", builtins.int)
+    V: typing_extensions.TypeAlias = ValueType
+
+class _NostrEncryptionSchemeEnumTypeWrapper(google.protobuf.internal.enum_type_wrapper._EnumTypeWrapper[_NostrEncryptionScheme.ValueType], builtins.type):
+    DESCRIPTOR: google.protobuf.descriptor.EnumDescriptor
+    NOSTR_ENCRYPTION_NIP44: _NostrEncryptionScheme.ValueType  # 0
+    """See https://github.com/nostr-protocol/nips/blob/master/44.md"""
+    NOSTR_ENCRYPTION_NIP04: _NostrEncryptionScheme.ValueType  # 1
+    """Deprecated, but still widely used for direct messages.
+    See https://github.com/nostr-protocol/nips/blob/master/04.md
+    """
+
+class NostrEncryptionScheme(_NostrEncryptionScheme, metaclass=_NostrEncryptionSchemeEnumTypeWrapper): ...
+
+NOSTR_ENCRYPTION_NIP44: NostrEncryptionScheme.ValueType  # 0
+"""See https://github.com/nostr-protocol/nips/blob/master/44.md"""
+NOSTR_ENCRYPTION_NIP04: NostrEncryptionScheme.ValueType  # 1
+"""Deprecated, but still widely used for direct messages.
+See https://github.com/nostr-protocol/nips/blob/master/04.md
+"""
+global___NostrEncryptionScheme = NostrEncryptionScheme
+
+@typing.final
+class NostrPubRequest(google.protobuf.message.Message):
+    """Keypath must be m/44'/1237'/account'/0/0, see NIP-06. The response contains the npub-encoded
+    public key.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    KEYPATH_FIELD_NUMBER: builtins.int
+    DISPLAY_FIELD_NUMBER: builtins.int
+    display: builtins.bool
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    def __init__(
+        self,
+        *,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        display: builtins.bool = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["display", b"display", "keypath", b"keypath"]) -> None: ...
+
+global___NostrPubRequest = NostrPubRequest
+
+@typing.final
+class NostrTag(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    VALUES_FIELD_NUMBER: builtins.int
+    @property
+    def values(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.str]: ...
+    def __init__(
+        self,
+        *,
+        values: collections.abc.Iterable[builtins.str] | None = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["values", b"values"]) -> None: ...
+
+global___NostrTag = NostrTag
+
+@typing.final
+class NostrSignEventRequest(google.protobuf.message.Message):
+    """Signs a NIP-01 event. The public key of the event is the one at the keypath. The device
+    computes the event id from the serialized event and signs it. Only common event kinds are
+    supported, and their relevant tags are shown to the user.
+    See https://github.com/nostr-protocol/nips/blob/master/01.md
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    KEYPATH_FIELD_NUMBER: builtins.int
+    CREATED_AT_FIELD_NUMBER: builtins.int
+    KIND_FIELD_NUMBER: builtins.int
+    TAGS_FIELD_NUMBER: builtins.int
+    CONTENT_FIELD_NUMBER: builtins.int
+    created_at: builtins.int
+    """Unix timestamp in seconds."""
+    kind: builtins.int
+    content: builtins.str
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    @property
+    def tags(self) -> google.protobuf.internal.containers.RepeatedCompositeFieldContainer[global___NostrTag]: ...
+    def __init__(
+        self,
+        *,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        created_at: builtins.int = ...,
+        kind: builtins.int = ...,
+        tags: collections.abc.Iterable[global___NostrTag] | None = ...,
+        content: builtins.str = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["content", b"content", "created_at", b"created_at", "keypath", b"keypath", "kind", b"kind", "tags", b"tags"]) -> None: ...
+
+global___NostrSignEventRequest = NostrSignEventRequest
+
+@typing.final
+class NostrSignEventResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    ID_FIELD_NUMBER: builtins.int
+    SIGNATURE_FIELD_NUMBER: builtins.int
+    id: builtins.bytes
+    """32 bytes event id."""
+    signature: builtins.bytes
+    """64 bytes BIP-340 Schnorr signature over the event id."""
+    def __init__(
+        self,
+        *,
+        id: builtins.bytes = ...,
+        signature: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["id", b"id", "signature", b"signature"]) -> None: ...
+
+global___NostrSignEventResponse = NostrSignEventResponse
+
+@typing.final
+class NostrEncryptRequest(google.protobuf.message.Message):
+    """Encrypts a message to the counterparty, using ECDH between the key at the keypath and the
+    counterparty's public key. The user confirms each counterparty once until the device is
+    unplugged.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    KEYPATH_FIELD_NUMBER: builtins.int
+    SCHEME_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_FIELD_NUMBER: builtins.int
+    PLAINTEXT_FIELD_NUMBER: builtins.int
+    scheme: global___NostrEncryptionScheme.ValueType
+    counterparty: builtins.bytes
+    """32 bytes x-only public key."""
+    plaintext: builtins.bytes
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    def __init__(
+        self,
+        *,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        scheme: global___NostrEncryptionScheme.ValueType = ...,
+        counterparty: builtins.bytes = ...,
+        plaintext: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["counterparty", b"counterparty", "keypath", b"keypath", "plaintext", b"plaintext", "scheme", b"scheme"]) -> None: ...
+
+global___NostrEncryptRequest = NostrEncryptRequest
+
+@typing.final
+class NostrEncryptResponse(google.protobuf.message.Message):
+    """The payload is not base64 encoded. For NIP-44, it is the version byte, nonce, ciphertext and
+    MAC. For NIP-04, it is the 16 byte IV followed by the ciphertext.
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PAYLOAD_FIELD_NUMBER: builtins.int
+    payload: builtins.bytes
+    def __init__(
+        self,
+        *,
+        payload: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["payload", b"payload"]) -> None: ...
+
+global___NostrEncryptResponse = NostrEncryptResponse
+
+@typing.final
+class NostrDecryptRequest(google.protobuf.message.Message):
+    """Decrypts a message from or to the counterparty. See `NostrEncryptRequest`."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    KEYPATH_FIELD_NUMBER: builtins.int
+    SCHEME_FIELD_NUMBER: builtins.int
+    COUNTERPARTY_FIELD_NUMBER: builtins.int
+    PAYLOAD_FIELD_NUMBER: builtins.int
+    scheme: global___NostrEncryptionScheme.ValueType
+    counterparty: builtins.bytes
+    """32 bytes x-only public key."""
+    payload: builtins.bytes
+    """See `NostrEncryptResponse`."""
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    def __init__(
+        self,
+        *,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        scheme: global___NostrEncryptionScheme.ValueType = ...,
+        counterparty: builtins.bytes = ...,
+        payload: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["counterparty", b"counterparty", "keypath", b"keypath", "payload", b"payload", "scheme", b"scheme"]) -> None: ...
+
+global___NostrDecryptRequest = NostrDecryptRequest
+
+@typing.final
+class NostrDecryptResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PLAINTEXT_FIELD_NUMBER: builtins.int
+    plaintext: builtins.bytes
+    def __init__(
+        self,
+        *,
+        plaintext: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["plaintext", b"plaintext"]) -> None: ...
+
+global___NostrDecryptResponse = NostrDecryptResponse
+
+@typing.final
+class NostrRequest(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PUB_FIELD_NUMBER: builtins.int
+    SIGN_EVENT_FIELD_NUMBER: builtins.int
+    ENCRYPT_FIELD_NUMBER: builtins.int
+    DECRYPT_FIELD_NUMBER: builtins.int
+    @property
+    def pub(self) -> global___NostrPubRequest: ...
+    @property
+    def sign_event(self) -> global___NostrSignEventRequest: ...
+    @property
+    def encrypt(self) -> global___NostrEncryptRequest: ...
+    @property
+    def decrypt(self) -> global___NostrDecryptRequest: ...
+    def __init__(
+        self,
+        *,
+        pub: global___NostrPubRequest | None = ...,
+        sign_event: global___NostrSignEventRequest | None = ...,
+        encrypt: global___NostrEncryptRequest | None = ...,
+        decrypt: global___NostrDecryptRequest | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["decrypt", b"decrypt", "encrypt", b"encrypt", "pub", b"pub", "request", b"request", "sign_event", b"sign_event"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["decrypt", b"decrypt", "encrypt", b"encrypt", "pub", b"pub", "request", b"request", "sign_event", b"sign_event"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["pub", "sign_event", "encrypt", "decrypt"] | None: ...
+
+global___NostrRequest = NostrRequest
+
+@typing.final
+class NostrResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PUB_FIELD_NUMBER: builtins.int
+    SIGN_EVENT_FIELD_NUMBER: builtins.int
+    ENCRYPT_FIELD_NUMBER: builtins.int
+    DECRYPT_FIELD_NUMBER: builtins.int
+    @property
+    def pub(self) -> common_pb2.PubResponse: ...
+    @property
+    def sign_event(self) -> global___NostrSignEventResponse: ...
+    @property
+    def encrypt(self) -> global___NostrEncryptResponse: ...
+    @property
+    def decrypt(self) -> global___NostrDecryptResponse: ...
+    def __init__(
+        self,
+        *,
+        pub: common_pb2.PubResponse | None = ...,
+        sign_event: global___NostrSignEventResponse | None = ...,
+        encrypt: global___NostrEncryptResponse | None = ...,
+        decrypt: global___NostrDecryptResponse | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["decrypt", b"decrypt", "encrypt", b"encrypt", "pub", b"pub", "response", b"response", "sign_event", b"sign_event"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["decrypt", b"decrypt", "encrypt", b"encrypt", "pub", b"pub", "response", b"response", "sign_event", b"sign_event"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["pub", "sign_event", "encrypt", "decrypt"] | None: ...
+
+global___NostrResponse = NostrResponse
@@ -0,0 +1,34 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# source: solana.proto
+"""Generated protocol buffer code."""
+from google.protobuf.internal import builder as _builder
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import symbol_database as _symbol_database
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import common_pb2 as common__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0csolana.proto\x12\x14shiftcrypto.bitbox02\x1a\x0c\x63ommon.proto\"4\n\x10SolanaPubRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x0f\n\x07\x64isplay\x18\x02 \x01(\x08\"@\n\x1cSolanaSignTransactionRequest\x12\x0f\n\x07keypath\x18\x01 \x03(\r\x12\x0f\n\x07message\x18\x02 \x01(\x0c\"2\n\x1dSolanaSignTransactionResponse\x12\x11\n\tsignature\x18\x01 \x01(\x0c\"\xa1\x01\n\rSolanaRequest\x12\x35\n\x03pub\x18\x01 \x01(\x0b\x32&.shiftcrypto.bitbox02.SolanaPubRequestH\x00\x12N\n\x10sign_transaction\x18\x02 \x01(\x0b\x32\x32.shiftcrypto.bitbox02.SolanaSignTransactionRequestH\x00\x42\t\n\x07request\"\x9f\x01\n\x0eSolanaResponse\x12\x30\n\x03pub\x18\x01 \x01(\x0b\x32!.shiftcrypto.bitbox02.PubResponseH\x00\x12O\n\x10sign_transaction\x18\x02 \x01(\x0b\x32\x33.shiftcrypto.bitbox02.SolanaSignTransactionResponseH\x00\x42\n\n\x08responseb\x06proto3')
+
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, globals())
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'solana_pb2', globals())
+if _descriptor._USE_C_DESCRIPTORS == False:
+
+  DESCRIPTOR._options = None
+  _SOLANAPUBREQUEST._serialized_start=52
+  _SOLANAPUBREQUEST._serialized_end=104
+  _SOLANASIGNTRANSACTIONREQUEST._serialized_start=106
+  _SOLANASIGNTRANSACTIONREQUEST._serialized_end=170
+  _SOLANASIGNTRANSACTIONRESPONSE._serialized_start=172
+  _SOLANASIGNTRANSACTIONRESPONSE._serialized_end=222
+  _SOLANAREQUEST._serialized_start=225
+  _SOLANAREQUEST._serialized_end=386
+  _SOLANARESPONSE._serialized_start=389
+  _SOLANARESPONSE._serialized_end=548
+# @@protoc_insertion_point(module_scope)
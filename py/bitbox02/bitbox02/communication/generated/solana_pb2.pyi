@@ -0,0 +1,118 @@
+"""
+@generated by mypy-protobuf.  Do not edit manually!
+isort:skip_file
+SPDX-License-Identifier: Apache-2.0"""
+
+import builtins
+import collections.abc
+from . import common_pb2
+import google.protobuf.descriptor
+import google.protobuf.internal.containers
+import google.protobuf.message
+import typing
+
+DESCRIPTOR: google.protobuf.descriptor.FileDescriptor
+
+@typing.final
+class SolanaPubRequest(google.protobuf.message.Message):
+    """Keypath must be m/44'/501'/account'/0'."""
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    KEYPATH_FIELD_NUMBER: builtins.int
+    DISPLAY_FIELD_NUMBER: builtins.int
+    display: builtins.bool
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    def __init__(
+        self,
+        *,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        display: builtins.bool = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["display", b"display", "keypath", b"keypath"]) -> None: ...
+
+global___SolanaPubRequest = SolanaPubRequest
+
+@typing.final
+class SolanaSignTransactionRequest(google.protobuf.message.Message):
+    """Signs a serialized Solana transaction message, legacy or versioned (v0). See
+    https://solana.com/docs/core/transactions#message
+    """
+
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    KEYPATH_FIELD_NUMBER: builtins.int
+    MESSAGE_FIELD_NUMBER: builtins.int
+    message: builtins.bytes
+    @property
+    def keypath(self) -> google.protobuf.internal.containers.RepeatedScalarFieldContainer[builtins.int]: ...
+    def __init__(
+        self,
+        *,
+        keypath: collections.abc.Iterable[builtins.int] | None = ...,
+        message: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["keypath", b"keypath", "message", b"message"]) -> None: ...
+
+global___SolanaSignTransactionRequest = SolanaSignTransactionRequest
+
+@typing.final
+class SolanaSignTransactionResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    SIGNATURE_FIELD_NUMBER: builtins.int
+    signature: builtins.bytes
+    """64 bytes ed25519 signature."""
+    def __init__(
+        self,
+        *,
+        signature: builtins.bytes = ...,
+    ) -> None: ...
+    def ClearField(self, field_name: typing.Literal["signature", b"signature"]) -> None: ...
+
+global___SolanaSignTransactionResponse = SolanaSignTransactionResponse
+
+@typing.final
+class SolanaRequest(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PUB_FIELD_NUMBER: builtins.int
+    SIGN_TRANSACTION_FIELD_NUMBER: builtins.int
+    @property
+    def pub(self) -> global___SolanaPubRequest: ...
+    @property
+    def sign_transaction(self) -> global___SolanaSignTransactionRequest: ...
+    def __init__(
+        self,
+        *,
+        pub: global___SolanaPubRequest | None = ...,
+        sign_transaction: global___SolanaSignTransactionRequest | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["pub", b"pub", "request", b"request", "sign_transaction", b"sign_transaction"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["pub", b"pub", "request", b"request", "sign_transaction", b"sign_transaction"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["request", b"request"]) -> typing.Literal["pub", "sign_transaction"] | None: ...
+
+global___SolanaRequest = SolanaRequest
+
+@typing.final
+class SolanaResponse(google.protobuf.message.Message):
+    DESCRIPTOR: google.protobuf.descriptor.Descriptor
+
+    PUB_FIELD_NUMBER: builtins.int
+    SIGN_TRANSACTION_FIELD_NUMBER: builtins.int
+    @property
+    def pub(self) -> common_pb2.PubResponse: ...
+    @property
+    def sign_transaction(self) -> global___SolanaSignTransactionResponse: ...
+    def __init__(
+        self,
+        *,
+        pub: common_pb2.PubResponse | None = ...,
+        sign_transaction: global___SolanaSignTransactionResponse | None = ...,
+    ) -> None: ...
+    def HasField(self, field_name: typing.Literal["pub", b"pub", "response", b"response", "sign_transaction", b"sign_transaction"]) -> builtins.bool: ...
+    def ClearField(self, field_name: typing.Literal["pub", b"pub", "response", b"response", "sign_transaction", b"sign_transaction"]) -> None: ...
+    def WhichOneof(self, oneof_group: typing.Literal["response", b"response"]) -> typing.Literal["pub", "sign_transaction"] | None: ...
+
+global___SolanaResponse = SolanaResponse
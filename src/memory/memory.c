@@ -33,6 +33,12 @@ static_assert(
 static_assert(
     MEMORY_MULTISIG_NAME_MAX_LEN_WITH_NULL == 31,
     "MEMORY_MULTISIG_NAME_MAX_LEN_WITH_NULL must remain 31.");
+static_assert(
+    MEMORY_ETH_NETWORK_NAME_MAX_LEN_WITH_NULL == 31,
+    "MEMORY_ETH_NETWORK_NAME_MAX_LEN_WITH_NULL must remain 31.");
+static_assert(
+    MEMORY_ETH_NETWORK_UNIT_MAX_LEN_WITH_NULL == 11,
+    "MEMORY_ETH_NETWORK_UNIT_MAX_LEN_WITH_NULL must remain 11.");
 
 // Documentation of all appData chunks and their contents.  A chunk is defined as
 // 16 pages, which is the erase granularity: changing any byte in the page
@@ -110,17 +116,74 @@ typedef struct __attribute__((__packed__)) {
     uint8_t hash[32]; // hash comitting to the multisig setup.
 } multisig_configuration_t;
 
+typedef struct __attribute__((__packed__)) {
+    // version fixed at 0xFF for now.
+    // The network entry is considered empty/unset if the chain ID is 0xFFFFFFFFFFFFFFFF.
+    uint8_t version;
+    uint64_t chain_id;
+    char name[MEMORY_ETH_NETWORK_NAME_MAX_LEN_WITH_NULL]; // user-confirmed network name.
+    char unit[MEMORY_ETH_NETWORK_UNIT_MAX_LEN_WITH_NULL]; // user-confirmed native currency unit.
+} eth_network_t;
+
+typedef struct __attribute__((__packed__)) {
+    // version fixed at 0xFF for now.
+    // The channel entry is considered empty/unset if the channel index is 0xFFFFFFFF.
+    uint8_t version;
+    // BIP-44 coin type (unhardened) of the channel keys.
+    uint32_t coin;
+    uint32_t channel_index;
+    // Hash committing to the channel parameters.
+    uint8_t parameters_hash[32];
+    // Next commitment number of the counterparty that can be signed.
+    uint64_t next_counterparty_commitment;
+    // Next holder commitment number that can be validated.
+    uint64_t next_holder_commitment;
+    // Number of holder commitments whose per-commitment secret has been released.
+    uint64_t revoked_holder_commitments;
+    // Our balance in the last signed counterparty commitment transaction.
+    uint64_t to_self_sat;
+    // Hash of the last signed counterparty commitment transaction and its HTLC transactions.
+    uint8_t counterparty_commitment_hash[32];
+    // 0xFF if the channel is open, 0x01 if a holder commitment has been signed to force close it.
+    uint8_t closed;
+} lightning_channel_t;
+
 // CHUNK_2: Various app data
 #define CHUNK_2 (2)
 typedef union {
     struct __attribute__((__packed__)) {
         multisig_configuration_t multisig_configs[MEMORY_MULTISIG_NUM_ENTRIES];
+        eth_network_t eth_networks[MEMORY_ETH_NETWORK_NUM_ENTRIES];
     } fields;
     uint8_t bytes[CHUNK_SIZE];
 } chunk_2_t;
 
 static_assert(sizeof(((chunk_2_t*)0)->fields) <= (size_t)CHUNK_SIZE, "chunk too large");
 
+// For how many coins forgotten Lightning channels can be tracked.
+#define LIGHTNING_FORGOTTEN_NUM_ENTRIES 4
+
+typedef struct __attribute__((__packed__)) {
+    // The entry is considered empty/unset if the coin is 0xFFFFFFFF.
+    uint32_t coin;
+    // Channels of this coin with a lower index have been forgotten.
+    uint32_t below_channel_index;
+} lightning_forgotten_t;
+
+// CHUNK_3: Lightning channel signer state. It is rewritten with every signed commitment, so it is
+// kept apart from the rarely written app data in CHUNK_2. It survives device resets (is not erased
+// during `memory_reset_hww()`), so that the channels of a restored seed can't be set up again.
+#define CHUNK_3 (3)
+typedef union {
+    struct __attribute__((__packed__)) {
+        lightning_channel_t channels[MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES];
+        lightning_forgotten_t forgotten[LIGHTNING_FORGOTTEN_NUM_ENTRIES];
+    } fields;
+    uint8_t bytes[CHUNK_SIZE];
+} chunk_3_t;
+
+static_assert(sizeof(((chunk_3_t*)0)->fields) <= (size_t)CHUNK_SIZE, "chunk too large");
+
 #if FLASH_APPDATA_LEN / CHUNK_SIZE != 8
     #error \
         "We expect 8 chunks in app data. This check is to ensure that chunk_7_t below is the last chunk, so it is not erased during reset."
@@ -146,6 +209,8 @@ static_assert(sizeof(((chunk_7_t*)0)->fields) <= (size_t)CHUNK_SIZE, "chunk too
 #define BITMASK_SEEDED ((uint8_t)(1u << 0u))
 #define BITMASK_INITIALIZED ((uint8_t)(1u << 1u))
 #define BITMASK_ENABLE_MNEMONIC_PASSPHRASE ((uint8_t)(1u << 2u))
+#define BITMASK_ENABLE_BLIND_SIGNING ((uint8_t)(1u << 3u))
+#define BITMASK_SLIP39_SEED ((uint8_t)(1u << 4u))
 
 static void _clean_chunk(uint8_t** chunk_bytes)
 {
@@ -364,6 +429,10 @@ bool memory_reset_hww(void)
 {
     // Erase all app data chunks expect the first and the last one, which is permanent.
     for (uint32_t chunk = CHUNK_1; chunk < (FLASH_APPDATA_LEN / CHUNK_SIZE) - 1; chunk++) {
+        // The Lightning channel signer state is kept as well.
+        if (chunk == CHUNK_3) {
+            continue;
+        }
         if (!_write_chunk(chunk, NULL)) {
             return false;
         }
@@ -427,6 +496,11 @@ bool memory_is_initialized(void)
     return _is_bitmask_flag_set(BITMASK_INITIALIZED);
 }
 
+bool memory_is_slip39_seed(void)
+{
+    return _is_bitmask_flag_set(BITMASK_SLIP39_SEED);
+}
+
 bool memory_set_initialized(void)
 {
     if (!memory_is_seeded()) {
@@ -461,6 +535,26 @@ bool memory_set_mnemonic_passphrase_enabled(bool enabled)
     return _write_chunk(CHUNK_1, chunk.bytes);
 }
 
+bool memory_is_blind_signing_enabled(void)
+{
+    return _is_bitmask_flag_set(BITMASK_ENABLE_BLIND_SIGNING);
+}
+
+bool memory_set_blind_signing_enabled(bool enabled)
+{
+    chunk_1_t chunk = {0};
+    CLEANUP_CHUNK(chunk);
+    _read_chunk(CHUNK_1, chunk_bytes);
+    uint8_t bitmask = ~chunk.fields.bitmask;
+    if (enabled) {
+        bitmask |= BITMASK_ENABLE_BLIND_SIGNING;
+    } else {
+        bitmask &= ~BITMASK_ENABLE_BLIND_SIGNING;
+    }
+    chunk.fields.bitmask = ~bitmask;
+    return _write_chunk(CHUNK_1, chunk.bytes);
+}
+
 uint8_t memory_get_failed_unlock_attempts(void)
 {
     chunk_1_t chunk = {0};
@@ -499,7 +593,8 @@ bool memory_reset_failed_unlock_attempts(void)
 bool memory_set_encrypted_seed_and_hmac(
     const uint8_t* encrypted_seed_and_hmac,
     uint8_t len,
-    memory_password_stretch_algo_t password_stretch_algo)
+    memory_password_stretch_algo_t password_stretch_algo,
+    bool slip39_seed)
 {
     chunk_1_t chunk = {0};
     CLEANUP_CHUNK(chunk);
@@ -523,9 +618,14 @@ bool memory_set_encrypted_seed_and_hmac(
     memset(
         chunk.fields.encrypted_seed_and_hmac, 0xFF, sizeof(chunk.fields.encrypted_seed_and_hmac));
     memcpy(chunk.fields.encrypted_seed_and_hmac, encrypted_seed_and_hmac, len);
-    // set seeded bit
+    // set seeded bit, and the seed type in the same write
     uint8_t bitmask = ~chunk.fields.bitmask;
     bitmask |= BITMASK_SEEDED;
+    if (slip39_seed) {
+        bitmask |= BITMASK_SLIP39_SEED;
+    } else {
+        bitmask &= ~BITMASK_SLIP39_SEED;
+    }
     chunk.fields.bitmask = ~bitmask;
     return _write_chunk(CHUNK_1, chunk.bytes);
 }
@@ -946,6 +1046,282 @@ bool memory_multisig_get_by_hash(const uint8_t* hash, char* name_out)
     return false;
 }
 
+memory_result_t memory_eth_network_set(uint64_t chain_id, const char* name, const char* unit)
+{
+    if (!strlens(name) || !strlens(unit)) {
+        return MEMORY_ERR_INVALID_INPUT;
+    }
+    if (chain_id == 0 || chain_id == UINT64_MAX) {
+        return MEMORY_ERR_INVALID_INPUT;
+    }
+
+    chunk_2_t chunk = {0};
+    CLEANUP_CHUNK(chunk);
+    _read_chunk(CHUNK_2, chunk.bytes);
+
+    // This will be true if the chain ID already exists.
+    bool found = false;
+    // This is the slot we will write to.
+    size_t write_index = 0;
+    // This will be the index of an empty slot (if empty_found is true).
+    size_t empty_index = 0;
+    bool empty_found = false;
+    // This loop looks for the already existing entry with the chain ID to overwrite, or an empty
+    // slot.
+    for (size_t i = 0; i < MEMORY_ETH_NETWORK_NUM_ENTRIES; i++) {
+        const eth_network_t* network = &chunk.fields.eth_networks[i];
+        if (!empty_found && network->chain_id == UINT64_MAX) {
+            empty_found = true;
+            empty_index = i;
+        }
+        if (network->chain_id == chain_id) {
+            if (STREQ(network->name, name) && STREQ(network->unit, unit)) {
+                // network already exists (equal), early abort, skipping another write.
+                return MEMORY_OK;
+            }
+            write_index = i;
+            found = true;
+            break;
+        }
+    }
+    if (!found && !empty_found) {
+        return MEMORY_ERR_FULL;
+    }
+    if (!found) {
+        write_index = empty_index;
+    }
+    eth_network_t* network = &chunk.fields.eth_networks[write_index];
+    network->chain_id = chain_id;
+    memset(network->name, '\0', sizeof(network->name));
+    snprintf(network->name, sizeof(network->name), "%s", name);
+    memset(network->unit, '\0', sizeof(network->unit));
+    snprintf(network->unit, sizeof(network->unit), "%s", unit);
+    if (!_write_chunk(CHUNK_2, chunk.bytes)) {
+        return MEMORY_ERR_UNKNOWN;
+    }
+    return MEMORY_OK;
+}
+
+bool memory_eth_network_get(uint64_t chain_id, char* name_out, char* unit_out)
+{
+    if (chain_id == UINT64_MAX) {
+        return false;
+    }
+
+    chunk_2_t chunk = {0};
+    CLEANUP_CHUNK(chunk);
+    _read_chunk(CHUNK_2, chunk.bytes);
+
+    for (size_t i = 0; i < MEMORY_ETH_NETWORK_NUM_ENTRIES; i++) {
+        const eth_network_t* network = &chunk.fields.eth_networks[i];
+        if (network->chain_id == chain_id) {
+            if (name_out != NULL) {
+                snprintf(name_out, sizeof(network->name), "%s", network->name);
+            }
+            if (unit_out != NULL) {
+                snprintf(unit_out, sizeof(network->unit), "%s", network->unit);
+            }
+            return true;
+        }
+    }
+    return false;
+}
+
+#define MEMORY_LIGHTNING_CHANNEL_OPEN 0xFF
+#define MEMORY_LIGHTNING_CHANNEL_CLOSED 0x01
+
+memory_result_t memory_lightning_channel_set(
+    uint32_t coin,
+    uint32_t channel_index,
+    const uint8_t* parameters_hash,
+    uint64_t next_counterparty_commitment,
+    uint64_t next_holder_commitment,
+    uint64_t revoked_holder_commitments,
+    uint64_t to_self_sat,
+    const uint8_t* counterparty_commitment_hash,
+    bool closed)
+{
+    if (channel_index == UINT32_MAX) {
+        return MEMORY_ERR_INVALID_INPUT;
+    }
+    const uint8_t closed_byte =
+        closed ? MEMORY_LIGHTNING_CHANNEL_CLOSED : MEMORY_LIGHTNING_CHANNEL_OPEN;
+
+    chunk_3_t chunk = {0};
+    CLEANUP_CHUNK(chunk);
+    _read_chunk(CHUNK_3, chunk.bytes);
+
+    // This will be true if the channel already exists.
+    bool found = false;
+    // This is the slot we will write to.
+    size_t write_index = 0;
+    // This will be the index of an empty slot (if empty_found is true).
+    size_t empty_index = 0;
+    bool empty_found = false;
+    for (size_t i = 0; i < MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES; i++) {
+        const lightning_channel_t* channel = &chunk.fields.channels[i];
+        if (!empty_found && channel->channel_index == UINT32_MAX) {
+            empty_found = true;
+            empty_index = i;
+        }
+        if (channel->coin == coin && channel->channel_index == channel_index) {
+            if (MEMEQ(channel->parameters_hash, parameters_hash, 32) &&
+                channel->next_counterparty_commitment == next_counterparty_commitment &&
+                channel->next_holder_commitment == next_holder_commitment &&
+                channel->revoked_holder_commitments == revoked_holder_commitments &&
+                channel->to_self_sat == to_self_sat &&
+                MEMEQ(channel->counterparty_commitment_hash, counterparty_commitment_hash, 32) &&
+                channel->closed == closed_byte) {
+                // state unchanged, early abort, skipping another write.
+                return MEMORY_OK;
+            }
+            write_index = i;
+            found = true;
+            break;
+        }
+    }
+    if (!found && !empty_found) {
+        return MEMORY_ERR_FULL;
+    }
+    if (!found) {
+        write_index = empty_index;
+    }
+    lightning_channel_t* channel = &chunk.fields.channels[write_index];
+    channel->coin = coin;
+    channel->channel_index = channel_index;
+    memcpy(channel->parameters_hash, parameters_hash, sizeof(channel->parameters_hash));
+    channel->next_counterparty_commitment = next_counterparty_commitment;
+    channel->next_holder_commitment = next_holder_commitment;
+    channel->revoked_holder_commitments = revoked_holder_commitments;
+    channel->to_self_sat = to_self_sat;
+    memcpy(
+        channel->counterparty_commitment_hash,
+        counterparty_commitment_hash,
+        sizeof(channel->counterparty_commitment_hash));
+    channel->closed = closed_byte;
+    if (!_write_chunk(CHUNK_3, chunk.bytes)) {
+        return MEMORY_ERR_UNKNOWN;
+    }
+    return MEMORY_OK;
+}
+
+bool memory_lightning_channel_get(
+    uint32_t coin,
+    uint32_t channel_index,
+    uint8_t* parameters_hash_out,
+    uint64_t* next_counterparty_commitment_out,
+    uint64_t* next_holder_commitment_out,
+    uint64_t* revoked_holder_commitments_out,
+    uint64_t* to_self_sat_out,
+    uint8_t* counterparty_commitment_hash_out,
+    bool* closed_out)
+{
+    if (channel_index == UINT32_MAX) {
+        return false;
+    }
+
+    chunk_3_t chunk = {0};
+    CLEANUP_CHUNK(chunk);
+    _read_chunk(CHUNK_3, chunk.bytes);
+
+    for (size_t i = 0; i < MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES; i++) {
+        const lightning_channel_t* channel = &chunk.fields.channels[i];
+        if (channel->coin == coin && channel->channel_index == channel_index) {
+            memcpy(
+                parameters_hash_out, channel->parameters_hash, sizeof(channel->parameters_hash));
+            *next_counterparty_commitment_out = channel->next_counterparty_commitment;
+            *next_holder_commitment_out = channel->next_holder_commitment;
+            *revoked_holder_commitments_out = channel->revoked_holder_commitments;
+            *to_self_sat_out = channel->to_self_sat;
+            memcpy(
+                counterparty_commitment_hash_out,
+                channel->counterparty_commitment_hash,
+                sizeof(channel->counterparty_commitment_hash));
+            *closed_out = channel->closed == MEMORY_LIGHTNING_CHANNEL_CLOSED;
+            return true;
+        }
+    }
+    return false;
+}
+
+memory_result_t memory_lightning_channels_forget(uint32_t coin, uint32_t below_channel_index)
+{
+    if (coin == UINT32_MAX || below_channel_index == UINT32_MAX) {
+        return MEMORY_ERR_INVALID_INPUT;
+    }
+
+    chunk_3_t chunk = {0};
+    CLEANUP_CHUNK(chunk);
+    _read_chunk(CHUNK_3, chunk.bytes);
+
+    lightning_forgotten_t* forgotten = NULL;
+    for (size_t i = 0; i < LIGHTNING_FORGOTTEN_NUM_ENTRIES; i++) {
+        if (chunk.fields.forgotten[i].coin == coin) {
+            forgotten = &chunk.fields.forgotten[i];
+            break;
+        }
+    }
+    if (forgotten == NULL) {
+        for (size_t i = 0; i < LIGHTNING_FORGOTTEN_NUM_ENTRIES; i++) {
+            if (chunk.fields.forgotten[i].coin == UINT32_MAX) {
+                forgotten = &chunk.fields.forgotten[i];
+                break;
+            }
+        }
+    }
+    if (forgotten == NULL) {
+        return MEMORY_ERR_FULL;
+    }
+
+    bool changed = false;
+    for (size_t i = 0; i < MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES; i++) {
+        const lightning_channel_t* channel = &chunk.fields.channels[i];
+        if (channel->coin == coin && channel->channel_index < below_channel_index) {
+            if (channel->closed != MEMORY_LIGHTNING_CHANNEL_CLOSED) {
+                return MEMORY_ERR_INVALID_INPUT;
+            }
+            changed = true;
+        }
+    }
+    for (size_t i = 0; i < MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES; i++) {
+        lightning_channel_t* channel = &chunk.fields.channels[i];
+        if (channel->coin == coin && channel->channel_index < below_channel_index) {
+            memset(channel, 0xFF, sizeof(lightning_channel_t));
+        }
+    }
+    if (forgotten->coin != coin || forgotten->below_channel_index < below_channel_index) {
+        forgotten->coin = coin;
+        forgotten->below_channel_index = below_channel_index;
+        changed = true;
+    }
+    if (!changed) {
+        // early abort, skipping another write.
+        return MEMORY_OK;
+    }
+    if (!_write_chunk(CHUNK_3, chunk.bytes)) {
+        return MEMORY_ERR_UNKNOWN;
+    }
+    return MEMORY_OK;
+}
+
+uint32_t memory_lightning_channels_forgotten_below(uint32_t coin)
+{
+    if (coin == UINT32_MAX) {
+        return 0;
+    }
+
+    chunk_3_t chunk = {0};
+    CLEANUP_CHUNK(chunk);
+    _read_chunk(CHUNK_3, chunk.bytes);
+
+    for (size_t i = 0; i < LIGHTNING_FORGOTTEN_NUM_ENTRIES; i++) {
+        if (chunk.fields.forgotten[i].coin == coin) {
+            return chunk.fields.forgotten[i].below_channel_index;
+        }
+    }
+    return 0;
+}
+
 // Default is BLE ENABLED
 #define MEMORY_BLE_ENABLED 0xFF
 #define MEMORY_BLE_DISABLED 0x01
@@ -11,9 +11,16 @@
 
 #define NOISE_PUBKEY_SIZE 32
 
-// How many multisig configurations (accounts) can be registered.
+// How many multisig configurations (accounts) can be registered. The slots are shared by Bitcoin
+// multisig accounts, Bitcoin policies and Cardano native script accounts.
 #define MEMORY_MULTISIG_NUM_ENTRIES 25
 
+// How many Ethereum networks can be registered.
+#define MEMORY_ETH_NETWORK_NUM_ENTRIES 25
+
+// For how many Lightning channels the signer state can be stored.
+#define MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES 25
+
 typedef enum {
     // Legacy/initial value, corresponds to the original Optiga factorysetup config.
     MEMORY_OPTIGA_CONFIG_V0,
@@ -54,6 +61,10 @@ typedef enum {
  * @return true on success, false on failure.
  */
 USE_RESULT bool memory_setup(const memory_interface_functions_t* ifs);
+/**
+ * Erases the app data, except for the permanent chunk and the Lightning channel signer state.
+ * @return true on success, false on failure.
+ */
 USE_RESULT bool memory_reset_hww(void);
 
 /**
@@ -104,6 +115,12 @@ USE_RESULT bool memory_is_initialized(void);
  */
 USE_RESULT bool memory_set_initialized(void);
 
+/**
+ * Returns true if the stored seed is a SLIP-39 master secret, which is used directly as the BIP-32
+ * seed, and false if it is BIP-39 entropy (see memory_set_encrypted_seed_and_hmac).
+ */
+USE_RESULT bool memory_is_slip39_seed(void);
+
 /**
  * Returns true if the bip39 passphrase feature is enabled.
  */
@@ -115,6 +132,19 @@ USE_RESULT bool memory_is_mnemonic_passphrase_enabled(void);
  */
 USE_RESULT bool memory_set_mnemonic_passphrase_enabled(bool enabled);
 
+/**
+ * Returns true if blind signing is enabled. This is a global setting used by all apps: it allows
+ * signing precomputed (hash-only) Ethereum typed messages and Solana transactions with unknown
+ * instructions.
+ */
+USE_RESULT bool memory_is_blind_signing_enabled(void);
+
+/**
+ * Activates or deactivates blind signing for all apps, see memory_is_blind_signing_enabled().
+ * @return true on success, false on failure.
+ */
+USE_RESULT bool memory_set_blind_signing_enabled(bool enabled);
+
 /**
  * @return The number of failed unlock attempts.
  */
@@ -132,10 +162,17 @@ USE_RESULT bool memory_increment_failed_unlock_attempts(void);
  */
 USE_RESULT bool memory_reset_failed_unlock_attempts(void);
 
+/**
+ * Stores the encrypted seed and hmac.
+ * @param[in] slip39_seed true if the seed is a SLIP-39 master secret, false if it is BIP-39
+ * entropy. It is stored together with the seed, see memory_is_slip39_seed().
+ * @return true on success, false on failure.
+ */
 USE_RESULT bool memory_set_encrypted_seed_and_hmac(
     const uint8_t* encrypted_seed_and_hmac,
     uint8_t len,
-    memory_password_stretch_algo_t password_stretch_algo);
+    memory_password_stretch_algo_t password_stretch_algo,
+    bool slip39_seed);
 
 /**
  * Retrieves the encrypted seed and hmac.
@@ -270,6 +307,106 @@ USE_RESULT memory_result_t memory_multisig_set_by_hash(const uint8_t* hash, cons
  */
 USE_RESULT bool memory_multisig_get_by_hash(const uint8_t* hash, char* name_out);
 
+/**
+ * Store the name and native currency unit of an Ethereum network under its chain ID. At most
+ * MEMORY_ETH_NETWORK_NUM_ENTRIES different networks can be stored.
+ * If a network is already stored with this chain ID, it will be overwritten.
+ * It's the callers responsibility to validate the name and unit (beyond that they must be
+ * non-empty).
+ * @param[in] chain_id EIP-155 chain ID. Can't be 0 or 0xfffff....
+ * @param[in] name human readable network name. Must be at most
+ * MEMORY_ETH_NETWORK_NAME_MAX_LEN_WITH_NULL bytes, including the null terminator (otherwise the
+ * name will be truncated), and non-empty.
+ * @param[in] unit native currency unit. Must be at most MEMORY_ETH_NETWORK_UNIT_MAX_LEN_WITH_NULL
+ * bytes, including the null terminator (otherwise the unit will be truncated), and non-empty.
+ * @return see memory_result_t, can return MEMORY_OK, MEMORY_ERR_INVALID_INPUT, MEMORY_ERR_FULL,
+ * MEMORY_ERR_UNKNOWN.
+ */
+USE_RESULT memory_result_t
+memory_eth_network_set(uint64_t chain_id, const char* name, const char* unit);
+
+/**
+ * Retrieves the name and unit of a previously stored Ethereum network identified by `chain_id`.
+ * @param[in] chain_id EIP-155 chain ID.
+ * @param[out] name_out will contain the name. Must have at least
+ * `MEMORY_ETH_NETWORK_NAME_MAX_LEN_WITH_NULL` bytes. Can be NULL.
+ * @param[out] unit_out will contain the unit. Must have at least
+ * `MEMORY_ETH_NETWORK_UNIT_MAX_LEN_WITH_NULL` bytes. Can be NULL.
+ * @return true if the network was found, false otherwise.
+ */
+USE_RESULT bool memory_eth_network_get(uint64_t chain_id, char* name_out, char* unit_out);
+
+/**
+ * Store the signer state of a Lightning channel. At most MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES
+ * different channels can be stored. A channel is identified by its coin and index. If the channel
+ * is already stored, its state is overwritten.
+ * @param[in] coin BIP-44 coin type (unhardened) of the channel keys.
+ * @param[in] channel_index index of the channel. Can't be 0xffffffff.
+ * @param[in] parameters_hash 32 bytes hash committing to the channel parameters.
+ * @param[in] next_counterparty_commitment next commitment number of the counterparty that can be
+ * signed.
+ * @param[in] next_holder_commitment next holder commitment number that can be validated.
+ * @param[in] revoked_holder_commitments number of holder commitments that have been revoked.
+ * @param[in] to_self_sat our balance in the last signed counterparty commitment.
+ * @param[in] counterparty_commitment_hash 32 bytes hash of the last signed counterparty commitment
+ * transaction and its HTLC transactions.
+ * @param[in] closed true if a holder commitment has been signed to force close the channel.
+ * @return see memory_result_t, can return MEMORY_OK, MEMORY_ERR_INVALID_INPUT, MEMORY_ERR_FULL,
+ * MEMORY_ERR_UNKNOWN.
+ */
+USE_RESULT memory_result_t memory_lightning_channel_set(
+    uint32_t coin,
+    uint32_t channel_index,
+    const uint8_t* parameters_hash,
+    uint64_t next_counterparty_commitment,
+    uint64_t next_holder_commitment,
+    uint64_t revoked_holder_commitments,
+    uint64_t to_self_sat,
+    const uint8_t* counterparty_commitment_hash,
+    bool closed);
+
+/**
+ * Retrieves the signer state of a Lightning channel stored with `memory_lightning_channel_set()`.
+ * @param[in] coin BIP-44 coin type (unhardened) of the channel keys.
+ * @param[in] channel_index index of the channel.
+ * @param[out] parameters_hash_out will contain the 32 bytes parameters hash. Must not be NULL.
+ * @param[out] next_counterparty_commitment_out, next_holder_commitment_out,
+ * revoked_holder_commitments_out, to_self_sat_out, closed_out will contain the stored state. Must
+ * not be NULL.
+ * @param[out] counterparty_commitment_hash_out will contain the 32 bytes hash of the last signed
+ * counterparty commitment. Must not be NULL.
+ * @return true if the channel was found, false otherwise.
+ */
+USE_RESULT bool memory_lightning_channel_get(
+    uint32_t coin,
+    uint32_t channel_index,
+    uint8_t* parameters_hash_out,
+    uint64_t* next_counterparty_commitment_out,
+    uint64_t* next_holder_commitment_out,
+    uint64_t* revoked_holder_commitments_out,
+    uint64_t* to_self_sat_out,
+    uint8_t* counterparty_commitment_hash_out,
+    bool* closed_out);
+
+/**
+ * Removes the signer state of all Lightning channels of a coin with an index below
+ * `below_channel_index`, freeing their slots. The channels must be closed. The forgotten channel
+ * indices are remembered, see `memory_lightning_channels_forgotten_below()`.
+ * @param[in] coin BIP-44 coin type (unhardened) of the channel keys. Can't be 0xffffffff.
+ * @param[in] below_channel_index channels with a lower index are forgotten. Can't be 0xffffffff.
+ * @return see memory_result_t, can return MEMORY_OK, MEMORY_ERR_INVALID_INPUT (also if one of
+ * the channels is not closed), MEMORY_ERR_FULL, MEMORY_ERR_UNKNOWN.
+ */
+USE_RESULT memory_result_t
+memory_lightning_channels_forget(uint32_t coin, uint32_t below_channel_index);
+
+/**
+ * @param[in] coin BIP-44 coin type (unhardened) of the channel keys.
+ * @return the highest `below_channel_index` passed to `memory_lightning_channels_forget()` for
+ * the coin, or 0 if no channels of the coin have been forgotten.
+ */
+USE_RESULT uint32_t memory_lightning_channels_forgotten_below(uint32_t coin);
+
 /**
  * Enable or disable BLE during boot
  */
@@ -40,7 +40,8 @@ fn pkcs7_unpad(buf: &mut Vec<u8>) -> Result<(), ()> {
     Ok(())
 }
 
-fn encrypt(iv: &[u8; 16], key: &[u8; 32], plain: &[u8]) -> Vec<u8> {
+/// Encrypts with AES-256-CBC and PKCS7 padding. The result is the IV followed by the ciphertext.
+pub fn encrypt(iv: &[u8; 16], key: &[u8; 32], plain: &[u8]) -> Vec<u8> {
     // Allocate enough space so copying plain into it and padding it does not create copies that
     // won't get zeroed.
     let padded_len = plain.len() + pad_length(plain.len());
@@ -55,7 +56,8 @@ fn encrypt(iv: &[u8; 16], key: &[u8; 32], plain: &[u8]) -> Vec<u8> {
     out
 }
 
-fn decrypt(key: &[u8; 32], cipher: &[u8]) -> Result<zeroize::Zeroizing<Vec<u8>>, ()> {
+/// Decrypts the output of `encrypt()`. The padding is checked, but there is no authentication.
+pub fn decrypt(key: &[u8; 32], cipher: &[u8]) -> Result<zeroize::Zeroizing<Vec<u8>>, ()> {
     let (iv, cipher) = cipher.split_at_checked(16).ok_or(())?;
     let mut result = zeroize::Zeroizing::new(cipher.to_vec());
     ctaes::Aes256Cbc::new(key, &iv.try_into().unwrap())
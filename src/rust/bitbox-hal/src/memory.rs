@@ -8,6 +8,12 @@ pub const DEVICE_NAME_MAX_LEN: usize = 63;
 /// Maximum multisig account name length in bytes, excluding the null terminator used in C
 /// strings.
 pub const MULTISIG_NAME_MAX_LEN: usize = 30;
+/// Maximum Ethereum network name length in bytes, excluding the null terminator used in C
+/// strings.
+pub const ETH_NETWORK_NAME_MAX_LEN: usize = 30;
+/// Maximum Ethereum network unit length in bytes, excluding the null terminator used in C
+/// strings.
+pub const ETH_NETWORK_UNIT_MAX_LEN: usize = 10;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum PasswordStretchAlgo {
@@ -15,6 +21,15 @@ pub enum PasswordStretchAlgo {
     V1,
 }
 
+/// How the BIP-32 seed is derived from the stored seed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SeedType {
+    /// The seed is BIP-39 entropy, stretched with the BIP-39 passphrase into the BIP-32 seed.
+    Bip39,
+    /// The seed is a SLIP-39 master secret, used directly as the BIP-32 seed.
+    Slip39,
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum SecurechipType {
     Atecc,
@@ -27,6 +42,25 @@ pub enum Platform {
     BitBox02Plus,
 }
 
+/// Signer state of a Lightning channel, see `memory_lightning_channel_set()` in memory.h.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct LightningChannelState {
+    /// Hash committing to the channel parameters.
+    pub parameters_hash: [u8; 32],
+    /// Next commitment number of the counterparty that can be signed.
+    pub next_counterparty_commitment: u64,
+    /// Next holder commitment number that can be validated.
+    pub next_holder_commitment: u64,
+    /// Number of holder commitments whose per-commitment secret has been released.
+    pub revoked_holder_commitments: u64,
+    /// Our balance in the last signed counterparty commitment.
+    pub to_self_sat: u64,
+    /// Hash of the last signed counterparty commitment transaction and its HTLC transactions.
+    pub counterparty_commitment_hash: [u8; 32],
+    /// True if a holder commitment has been signed to force close the channel.
+    pub closed: bool,
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Error {
     InvalidInput,
@@ -45,17 +79,24 @@ pub trait Memory {
     fn set_device_name(&mut self, name: &str) -> Result<(), Error>;
     fn is_mnemonic_passphrase_enabled(&mut self) -> bool;
     fn set_mnemonic_passphrase_enabled(&mut self, enabled: bool) -> Result<(), ()>;
+    /// Blind signing is a global setting used by all apps that can sign data that the device
+    /// cannot fully display, e.g. Ethereum typed message hashes and unknown Solana instructions.
+    fn is_blind_signing_enabled(&mut self) -> bool;
+    fn set_blind_signing_enabled(&mut self, enabled: bool) -> Result<(), ()>;
     fn set_seed_birthdate(&mut self, timestamp: u32) -> Result<(), ()>;
     fn get_seed_birthdate(&mut self) -> u32;
     fn is_seeded(&mut self) -> bool;
     fn is_initialized(&mut self) -> bool;
     fn set_initialized(&mut self) -> Result<(), ()>;
     fn get_encrypted_seed_and_hmac(&mut self) -> Result<(Vec<u8>, PasswordStretchAlgo), ()>;
+    /// The seed type is stored together with the seed, see [`Memory::get_seed_type`].
     fn set_encrypted_seed_and_hmac(
         &mut self,
         data: &[u8],
         password_stretch_algo: PasswordStretchAlgo,
+        seed_type: SeedType,
     ) -> Result<(), ()>;
+    fn get_seed_type(&mut self) -> SeedType;
     fn reset_hww(&mut self) -> Result<(), ()>;
     fn get_unlock_attempts(&mut self) -> u8;
     fn increment_unlock_attempts(&mut self);
@@ -69,6 +110,34 @@ pub trait Memory {
     ) -> Result<(), ()>;
     fn get_attestation_bootloader_hash(&mut self) -> [u8; 32];
     /// `name` must be non-empty and at most [`MULTISIG_NAME_MAX_LEN`] bytes long.
+    ///
+    /// Bitcoin multisig accounts, Bitcoin policies and Cardano native script accounts share the
+    /// same slots. The first byte hashed into `hash` identifies the kind of registration.
     fn multisig_set_by_hash(&mut self, hash: &[u8; 32], name: &str) -> Result<(), Error>;
     fn multisig_get_by_hash(&self, hash: &[u8; 32]) -> Option<String>;
+    /// `name` and `unit` must be non-empty and at most [`ETH_NETWORK_NAME_MAX_LEN`] and
+    /// [`ETH_NETWORK_UNIT_MAX_LEN`] bytes long respectively.
+    fn eth_network_set(&mut self, chain_id: u64, name: &str, unit: &str) -> Result<(), Error>;
+    /// Returns the name and unit of the network registered under `chain_id`.
+    fn eth_network_get(&self, chain_id: u64) -> Option<(String, String)>;
+    /// A channel is identified by `coin`, the BIP-44 coin type (unhardened) of its keys, and
+    /// `channel_index`, which can't be `0xFFFFFFFF`.
+    fn lightning_channel_set(
+        &mut self,
+        coin: u32,
+        channel_index: u32,
+        state: &LightningChannelState,
+    ) -> Result<(), Error>;
+    fn lightning_channel_get(&self, coin: u32, channel_index: u32)
+    -> Option<LightningChannelState>;
+    /// Removes the state of all channels of `coin` with an index below `below_channel_index`.
+    /// Fails with [`Error::InvalidInput`] if one of them is not closed.
+    fn lightning_channels_forget(
+        &mut self,
+        coin: u32,
+        below_channel_index: u32,
+    ) -> Result<(), Error>;
+    /// Returns the highest `below_channel_index` passed to
+    /// [`Memory::lightning_channels_forget`] for `coin`, or 0.
+    fn lightning_channels_forgotten_below(&self, coin: u32) -> u32;
 }
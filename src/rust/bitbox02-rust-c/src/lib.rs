@@ -66,11 +66,21 @@ pub const MEMORY_DEVICE_MAX_LEN_WITH_NULL: u8 = 64;
 // The static assert below enforces consistency with the Rust HAL constant.
 /// Maximum multisig account name length in bytes, including null terminator.
 pub const MEMORY_MULTISIG_NAME_MAX_LEN_WITH_NULL: u8 = 31;
+// Keep these as numeric literals so cbindgen reliably exports them to C.
+// The static asserts below enforce consistency with the Rust HAL constants.
+/// Maximum Ethereum network name length in bytes, including null terminator.
+pub const MEMORY_ETH_NETWORK_NAME_MAX_LEN_WITH_NULL: u8 = 31;
+/// Maximum Ethereum network unit length in bytes, including null terminator.
+pub const MEMORY_ETH_NETWORK_UNIT_MAX_LEN_WITH_NULL: u8 = 11;
 
 const _: [(); bitbox_hal::memory::DEVICE_NAME_MAX_LEN + 1] =
     [(); MEMORY_DEVICE_MAX_LEN_WITH_NULL as usize];
 const _: [(); bitbox_hal::memory::MULTISIG_NAME_MAX_LEN + 1] =
     [(); MEMORY_MULTISIG_NAME_MAX_LEN_WITH_NULL as usize];
+const _: [(); bitbox_hal::memory::ETH_NETWORK_NAME_MAX_LEN + 1] =
+    [(); MEMORY_ETH_NETWORK_NAME_MAX_LEN_WITH_NULL as usize];
+const _: [(); bitbox_hal::memory::ETH_NETWORK_UNIT_MAX_LEN + 1] =
+    [(); MEMORY_ETH_NETWORK_UNIT_MAX_LEN_WITH_NULL as usize];
 
 // Whenever execution reaches somewhere it isn't supposed to rust code will "panic". Our panic
 // handler will print the available information on the screen and over RTT. If we compile with
@@ -5,8 +5,13 @@ use alloc::vec::Vec;
 
 /// `idx` must be smaller than BIP39_WORDLIST_LEN.
 pub fn get_word(idx: u16) -> Result<zeroize::Zeroizing<String>, ()> {
+    get_word_in(bip39::Language::English, idx)
+}
+
+/// Like `get_word()`, but using the wordlist of the given language.
+pub fn get_word_in(language: bip39::Language, idx: u16) -> Result<zeroize::Zeroizing<String>, ()> {
     Ok(zeroize::Zeroizing::new(
-        bip39::Language::English
+        language
             .word_list()
             .get(idx as usize)
             .ok_or(())?
@@ -16,7 +21,15 @@ pub fn get_word(idx: u16) -> Result<zeroize::Zeroizing<String>, ()> {
 
 /// Encode a seed as a BIP39 mnemonic.
 pub fn mnemonic_from_seed(seed: &[u8]) -> Result<zeroize::Zeroizing<String>, ()> {
-    let mnemonic = bip39::Mnemonic::from_entropy(seed).map_err(|_| ())?;
+    mnemonic_from_seed_in(bip39::Language::English, seed)
+}
+
+/// Encode a seed as a BIP39 mnemonic using the wordlist of the given language.
+pub fn mnemonic_from_seed_in(
+    language: bip39::Language,
+    seed: &[u8],
+) -> Result<zeroize::Zeroizing<String>, ()> {
+    let mnemonic = bip39::Mnemonic::from_entropy_in(language, seed).map_err(|_| ())?;
     Ok(zeroize::Zeroizing::new(mnemonic.to_string()))
 }
 
@@ -121,6 +134,30 @@ mod tests {
         assert_eq!(get_word(563).unwrap().as_ref() as &str, "edit");
     }
 
+    #[test]
+    fn test_get_word_in() {
+        assert!(get_word_in(bip39::Language::Italian, 2048).is_err());
+
+        assert_eq!(
+            get_word_in(bip39::Language::English, 563).unwrap().as_str(),
+            "edit"
+        );
+        assert_eq!(
+            get_word_in(bip39::Language::Italian, 0).unwrap().as_str(),
+            "abaco"
+        );
+        assert_eq!(
+            get_word_in(bip39::Language::Italian, 2047)
+                .unwrap()
+                .as_str(),
+            "zuppa"
+        );
+        assert_eq!(
+            get_word_in(bip39::Language::Czech, 563).unwrap().as_str(),
+            "kapota"
+        );
+    }
+
     #[test]
     fn test_mnemonic_from_seed() {
         // 12 words
@@ -148,6 +185,31 @@ mod tests {
         assert!(mnemonic_from_seed(b"foo").is_err());
     }
 
+    #[test]
+    fn test_mnemonic_from_seed_in() {
+        let seed = b"\xae\x6a\x40\x26\x1f\x0a\xcc\x16\x57\x04\x9c\xb2\x1a\xf5\xfb\xf7";
+        assert_eq!(
+            mnemonic_from_seed_in(bip39::Language::English, seed)
+                .unwrap()
+                .as_str(),
+            "purpose faith another dignity proud arctic foster near rare stumble leave urge",
+        );
+        assert_eq!(
+            mnemonic_from_seed_in(bip39::Language::Italian, seed)
+                .unwrap()
+                .as_str(),
+            "randagio finanza amarena disumano querela ampliare gazebo orribile reprimere spatola miele uditivo",
+        );
+        assert_eq!(
+            mnemonic_from_seed_in(bip39::Language::Czech, seed)
+                .unwrap()
+                .as_str(),
+            "rande kotleta blesk investor pysk bokorys lednice patrona rokle tuhnout obnos vyzdobit",
+        );
+
+        assert!(mnemonic_from_seed_in(bip39::Language::Czech, b"foo").is_err());
+    }
+
     #[test]
     fn test_mnemonic_to_seed() {
         assert!(mnemonic_to_seed("invalid").is_err());
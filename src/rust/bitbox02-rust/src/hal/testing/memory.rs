@@ -3,7 +3,9 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 
-use crate::hal::memory::{Error, PasswordStretchAlgo, Platform, SecurechipType};
+use crate::hal::memory::{
+    Error, LightningChannelState, PasswordStretchAlgo, Platform, SecurechipType, SeedType,
+};
 
 pub struct TestingMemory {
     ble_enabled: bool,
@@ -12,8 +14,10 @@ pub struct TestingMemory {
     initialized: bool,
     is_seeded: bool,
     mnemonic_passphrase_enabled: bool,
+    blind_signing_enabled: bool,
     seed_birthdate: u32,
     encrypted_seed_and_hmac: Option<(Vec<u8>, PasswordStretchAlgo)>,
+    seed_type: SeedType,
     device_name: Option<String>,
     unlock_attempts: u8,
     salt_root: [u8; 32],
@@ -22,10 +26,17 @@ pub struct TestingMemory {
     attestation_root_pubkey_identifier: Option<[u8; 32]>,
     attestation_bootloader_hash: [u8; 32],
     multisig_entries: Vec<([u8; 32], String)>,
+    eth_networks: Vec<(u64, String, String)>,
+    lightning_channels: Vec<(u32, u32, LightningChannelState)>,
+    lightning_channels_forgotten: Vec<(u32, u32)>,
 }
 
 // Same as MEMORY_MULTISIG_NUM_ENTRIES in memory.h.
 const MULTISIG_LIMIT: usize = 25;
+// Same as MEMORY_ETH_NETWORK_NUM_ENTRIES in memory.h.
+const ETH_NETWORK_LIMIT: usize = 25;
+// Same as MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES in memory.h.
+const LIGHTNING_CHANNEL_LIMIT: usize = 25;
 
 impl TestingMemory {
     pub fn new() -> Self {
@@ -36,8 +47,10 @@ impl TestingMemory {
             initialized: false,
             is_seeded: false,
             mnemonic_passphrase_enabled: false,
+            blind_signing_enabled: false,
             seed_birthdate: 0,
             encrypted_seed_and_hmac: None,
+            seed_type: SeedType::Bip39,
             device_name: None,
             unlock_attempts: 0,
             salt_root: *b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
@@ -46,6 +59,9 @@ impl TestingMemory {
             attestation_root_pubkey_identifier: None,
             attestation_bootloader_hash: [0; 32],
             multisig_entries: Vec::new(),
+            eth_networks: Vec::new(),
+            lightning_channels: Vec::new(),
+            lightning_channels_forgotten: Vec::new(),
         }
     }
 
@@ -119,6 +135,15 @@ impl crate::hal::Memory for TestingMemory {
         Ok(())
     }
 
+    fn is_blind_signing_enabled(&mut self) -> bool {
+        self.blind_signing_enabled
+    }
+
+    fn set_blind_signing_enabled(&mut self, enabled: bool) -> Result<(), ()> {
+        self.blind_signing_enabled = enabled;
+        Ok(())
+    }
+
     fn set_seed_birthdate(&mut self, timestamp: u32) -> Result<(), ()> {
         self.seed_birthdate = timestamp;
         Ok(())
@@ -151,24 +176,34 @@ impl crate::hal::Memory for TestingMemory {
         &mut self,
         data: &[u8],
         password_stretch_algo: PasswordStretchAlgo,
+        seed_type: SeedType,
     ) -> Result<(), ()> {
         // 96 is the max space allocated in BitBox02's memory for this.
         if data.len() > 96 {
             return Err(());
         }
         self.encrypted_seed_and_hmac = Some((data.to_vec(), password_stretch_algo));
+        self.seed_type = seed_type;
         self.is_seeded = true;
         Ok(())
     }
 
+    fn get_seed_type(&mut self) -> SeedType {
+        self.seed_type
+    }
+
     fn reset_hww(&mut self) -> Result<(), ()> {
         self.initialized = false;
         self.is_seeded = false;
         self.mnemonic_passphrase_enabled = false;
+        self.blind_signing_enabled = false;
         self.seed_birthdate = 0;
         self.encrypted_seed_and_hmac = None;
+        self.seed_type = SeedType::Bip39;
         self.device_name = None;
         self.multisig_entries = Vec::new();
+        self.eth_networks = Vec::new();
+        // The Lightning channel state survives resets.
         Ok(())
     }
 
@@ -259,4 +294,108 @@ impl crate::hal::Memory for TestingMemory {
             .find(|(existing_hash, _)| existing_hash == hash)
             .map(|(_, name)| name.clone())
     }
+
+    fn eth_network_set(&mut self, chain_id: u64, name: &str, unit: &str) -> Result<(), Error> {
+        if chain_id == 0 || chain_id == u64::MAX || name.is_empty() || unit.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        if let Some((_, existing_name, existing_unit)) = self
+            .eth_networks
+            .iter_mut()
+            .find(|(existing_chain_id, _, _)| *existing_chain_id == chain_id)
+        {
+            *existing_name = String::from(name);
+            *existing_unit = String::from(unit);
+            return Ok(());
+        }
+        if self.eth_networks.len() >= ETH_NETWORK_LIMIT {
+            return Err(Error::Full);
+        }
+        self.eth_networks
+            .push((chain_id, String::from(name), String::from(unit)));
+        Ok(())
+    }
+
+    fn eth_network_get(&self, chain_id: u64) -> Option<(String, String)> {
+        self.eth_networks
+            .iter()
+            .find(|(existing_chain_id, _, _)| *existing_chain_id == chain_id)
+            .map(|(_, name, unit)| (name.clone(), unit.clone()))
+    }
+
+    fn lightning_channel_set(
+        &mut self,
+        coin: u32,
+        channel_index: u32,
+        state: &LightningChannelState,
+    ) -> Result<(), Error> {
+        if channel_index == u32::MAX {
+            return Err(Error::InvalidInput);
+        }
+        if let Some((_, _, existing_state)) =
+            self.lightning_channels
+                .iter_mut()
+                .find(|(existing_coin, existing_index, _)| {
+                    *existing_coin == coin && *existing_index == channel_index
+                })
+        {
+            *existing_state = *state;
+            return Ok(());
+        }
+        if self.lightning_channels.len() >= LIGHTNING_CHANNEL_LIMIT {
+            return Err(Error::Full);
+        }
+        self.lightning_channels.push((coin, channel_index, *state));
+        Ok(())
+    }
+
+    fn lightning_channel_get(
+        &self,
+        coin: u32,
+        channel_index: u32,
+    ) -> Option<LightningChannelState> {
+        self.lightning_channels
+            .iter()
+            .find(|(existing_coin, existing_index, _)| {
+                *existing_coin == coin && *existing_index == channel_index
+            })
+            .map(|(_, _, state)| *state)
+    }
+
+    fn lightning_channels_forget(
+        &mut self,
+        coin: u32,
+        below_channel_index: u32,
+    ) -> Result<(), Error> {
+        if coin == u32::MAX || below_channel_index == u32::MAX {
+            return Err(Error::InvalidInput);
+        }
+        let forgotten = |(existing_coin, existing_index, _): &(u32, u32, LightningChannelState)| {
+            *existing_coin == coin && *existing_index < below_channel_index
+        };
+        if self
+            .lightning_channels
+            .iter()
+            .any(|channel| forgotten(channel) && !channel.2.closed)
+        {
+            return Err(Error::InvalidInput);
+        }
+        self.lightning_channels
+            .retain(|channel| !forgotten(channel));
+        if below_channel_index > self.lightning_channels_forgotten_below(coin) {
+            self.lightning_channels_forgotten
+                .retain(|(existing_coin, _)| *existing_coin != coin);
+            self.lightning_channels_forgotten
+                .push((coin, below_channel_index));
+        }
+        Ok(())
+    }
+
+    fn lightning_channels_forgotten_below(&self, coin: u32) -> u32 {
+        self.lightning_channels_forgotten
+            .iter()
+            .find(|(existing_coin, _)| *existing_coin == coin)
+            .map(|(_, below_channel_index)| *below_channel_index)
+            .unwrap_or(0)
+    }
 }
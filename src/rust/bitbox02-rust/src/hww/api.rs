@@ -13,6 +13,12 @@ pub mod bitcoin;
 #[cfg(feature = "app-cardano")]
 mod cardano;
 
+#[cfg(feature = "app-nostr")]
+mod nostr;
+
+#[cfg(feature = "app-solana")]
+mod solana;
+
 mod backup;
 mod bip85;
 mod bluetooth;
@@ -23,10 +29,12 @@ mod reset;
 mod restore;
 mod rootfingerprint;
 mod sdcard;
+mod set_blind_signing_enabled;
 mod set_device_name;
 mod set_mnemonic_passphrase_enabled;
 mod set_password;
 mod show_mnemonic;
+mod show_slip39_shares;
 mod system;
 
 use alloc::vec::Vec;
@@ -124,10 +132,13 @@ fn can_call(hal: &mut impl crate::hal::Hal, request: &Request) -> bool {
             state,
             State::Uninitialized | State::Seeded | State::InitializedAndUnlocked
         ),
-        Request::SetPassword(_) | Request::RestoreBackup(_) | Request::RestoreFromMnemonic(_) => {
+        Request::SetPassword(_)
+        | Request::RestoreBackup(_)
+        | Request::RestoreFromMnemonic(_)
+        | Request::RestoreFromSlip39Shares(_) => {
             matches!(state, State::Uninitialized | State::Seeded)
         }
-        Request::CreateBackup(_) | Request::ShowMnemonic(_) => {
+        Request::CreateBackup(_) | Request::ShowMnemonic(_) | Request::ShowSlip39Shares(_) => {
             matches!(state, State::Seeded | State::InitializedAndUnlocked)
         }
         Request::Fingerprint(_)
@@ -137,9 +148,12 @@ fn can_call(hal: &mut impl crate::hal::Hal, request: &Request) -> bool {
         | Request::BtcSignInit(_)
         | Request::CheckBackup(_)
         | Request::SetMnemonicPassphraseEnabled(_)
+        | Request::SetBlindSigningEnabled(_)
         | Request::Eth(_)
         | Request::Reset(_)
         | Request::Cardano(_)
+        | Request::Solana(_)
+        | Request::Nostr(_)
         | Request::Bip85(_)
         | Request::ChangePassword(_) => {
             matches!(state, State::InitializedAndUnlocked)
@@ -162,6 +176,9 @@ async fn process_api(hal: &mut impl crate::hal::Hal, request: &Request) -> Resul
         Request::SetMnemonicPassphraseEnabled(request) => {
             set_mnemonic_passphrase_enabled::process(hal, request).await
         }
+        Request::SetBlindSigningEnabled(request) => {
+            set_blind_signing_enabled::process(hal, request).await
+        }
         Request::InsertRemoveSdcard(request) => sdcard::process(hal, request).await,
         Request::ListBackups(_) => backup::list(hal).await,
         Request::CheckSdcard(_) => Ok(Response::CheckSdcard(pb::CheckSdCardResponse {
@@ -172,6 +189,10 @@ async fn process_api(hal: &mut impl crate::hal::Hal, request: &Request) -> Resul
         Request::RestoreBackup(request) => restore::from_file(hal, request).await,
         Request::ShowMnemonic(_) => show_mnemonic::process(hal).await,
         Request::RestoreFromMnemonic(request) => restore::from_mnemonic(hal, request).await,
+        Request::ShowSlip39Shares(request) => show_slip39_shares::process(hal, request).await,
+        Request::RestoreFromSlip39Shares(request) => {
+            restore::from_slip39_shares(hal, request).await
+        }
         Request::ElectrumEncryptionKey(request) => electrum::process(hal, request).await,
 
         #[cfg(feature = "app-ethereum")]
@@ -196,6 +217,25 @@ async fn process_api(hal: &mut impl crate::hal::Hal, request: &Request) -> Resul
             .map(|r| Response::Cardano(pb::CardanoResponse { response: Some(r) })),
         #[cfg(not(feature = "app-cardano"))]
         Request::Cardano(_) => Err(Error::Disabled),
+
+        #[cfg(feature = "app-solana")]
+        Request::Solana(pb::SolanaRequest {
+            request: Some(request),
+        }) => solana::process_api(hal, request)
+            .await
+            .map(|r| Response::Solana(pb::SolanaResponse { response: Some(r) })),
+        #[cfg(not(feature = "app-solana"))]
+        Request::Solana(_) => Err(Error::Disabled),
+
+        #[cfg(feature = "app-nostr")]
+        Request::Nostr(pb::NostrRequest {
+            request: Some(request),
+        }) => nostr::process_api(hal, request)
+            .await
+            .map(|r| Response::Nostr(pb::NostrResponse { response: Some(r) })),
+        #[cfg(not(feature = "app-nostr"))]
+        Request::Nostr(_) => Err(Error::Disabled),
+
         Request::Bip85(request) => bip85::process(hal, request).await,
         Request::Bluetooth(pb::BluetoothRequest {
             request: Some(request),
@@ -8,6 +8,7 @@ use alloc::vec::Vec;
 use pb::response::Response;
 
 use crate::backup;
+use crate::hal::memory::SeedType;
 use crate::hal::{Memory, Sd, Ui};
 use crate::workflow::unlock;
 
@@ -61,6 +62,8 @@ pub async fn check(
 /// If the device is initialized, an existing backup is overwritten, but the seed birthdate is
 /// retained from the previous backup. If no backup existed, the seed birthdate is set to 0, meaning
 /// it is unknown.
+///
+/// Backups are restored as BIP-39 seeds, so SLIP-39 seeds can't be backed up this way.
 pub async fn create(
     hal: &mut impl crate::hal::Hal,
     &pb::CreateBackupRequest {
@@ -68,6 +71,9 @@ pub async fn create(
         timezone_offset,
     }: &pb::CreateBackupRequest,
 ) -> Result<Response, Error> {
+    if hal.memory().get_seed_type() != SeedType::Bip39 {
+        return Err(Error::InvalidState);
+    }
     hal.ui()
         .confirm(&ConfirmParams {
             title: "Is today?",
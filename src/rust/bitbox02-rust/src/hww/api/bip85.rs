@@ -25,18 +25,45 @@ pub async fn process(
         Some(pb::bip85_request::App::Ln(request)) => Ok(Response::Bip85(pb::Bip85Response {
             app: Some(pb::bip85_response::App::Ln(process_ln(hal, request).await?)),
         })),
+        Some(pb::bip85_request::App::Hex(())) => {
+            process_hex(hal).await?;
+            Ok(Response::Bip85(pb::Bip85Response {
+                app: Some(pb::bip85_response::App::Hex(())),
+            }))
+        }
+        Some(pb::bip85_request::App::PwdBase64(())) => {
+            process_pwd(hal, PasswordEncoding::Base64).await?;
+            Ok(Response::Bip85(pb::Bip85Response {
+                app: Some(pb::bip85_response::App::PwdBase64(())),
+            }))
+        }
+        Some(pb::bip85_request::App::PwdBase85(())) => {
+            process_pwd(hal, PasswordEncoding::Base85).await?;
+            Ok(Response::Bip85(pb::Bip85Response {
+                app: Some(pb::bip85_response::App::PwdBase85(())),
+            }))
+        }
+        Some(pb::bip85_request::App::Wif(())) => {
+            process_wif(hal).await?;
+            Ok(Response::Bip85(pb::Bip85Response {
+                app: Some(pb::bip85_response::App::Wif(())),
+            }))
+        }
+        Some(pb::bip85_request::App::Xprv(())) => {
+            process_xprv(hal).await?;
+            Ok(Response::Bip85(pb::Bip85Response {
+                app: Some(pb::bip85_response::App::Xprv(())),
+            }))
+        }
     }
 }
 
-/// Derives and displays a BIP-39 seed according to BIP-85:
-/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#bip39.
-async fn process_bip39(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
-    use crate::hal::ui::{CanCancel, TrinaryChoice};
-
+/// Shows the two introductory screens of a BIP-85 app. `body` asks to derive the app's secret.
+async fn confirm_app(hal: &mut impl crate::hal::Hal, body: &str) -> Result<(), Error> {
     hal.ui()
         .confirm(&ConfirmParams {
             title: "BIP-85",
-            body: "Derive BIP-39\nmnemonic?",
+            body,
             accept_is_nextarrow: true,
             ..Default::default()
         })
@@ -51,20 +78,14 @@ async fn process_bip39(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
             ..Default::default()
         })
         .await?;
+    Ok(())
+}
 
-    let num_words: u32 = match hal
-        .ui()
-        .trinary_choice("How many words?", Some("12"), None, Some("24"))
-        .await
-    {
-        TrinaryChoice::Left => 12,
-        TrinaryChoice::Middle => unreachable!(),
-        TrinaryChoice::Right => 24,
-    };
-
-    hal.ui().status(&format!("{} words", num_words), true).await;
+/// Lets the user pick the index. The first few are quick-access. "More" leads to a full number
+/// input keyboard.
+async fn select_index(hal: &mut impl crate::hal::Hal) -> Result<u32, Error> {
+    use crate::hal::ui::CanCancel;
 
-    // Pick index. The first few are quick-access. "More" leads to a full number input keyboard.
     let index: u32 = match hal
         .ui()
         .menu(&["0", "1", "2", "3", "4", "More"], Some("Select index"))
@@ -97,22 +118,138 @@ async fn process_bip39(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
     };
 
     hal.ui().status(&format!("Index: {}", index), true).await;
+    Ok(index)
+}
 
+/// Lets the user enter a length in the given range using the number input keyboard.
+async fn enter_length(
+    hal: &mut impl crate::hal::Hal,
+    title: &str,
+    unit: &str,
+    range: core::ops::RangeInclusive<u32>,
+) -> Result<u32, Error> {
+    let number_string = hal
+        .ui()
+        .enter_string(
+            &crate::hal::ui::EnterStringParams {
+                title,
+                number_input: true,
+                longtouch: true,
+                ..Default::default()
+            },
+            crate::hal::ui::CanCancel::Yes,
+            "",
+        )
+        .await?;
+    match number_string.as_str().parse::<u32>() {
+        Ok(length) if range.contains(&length) => {
+            hal.ui().status(&format!("{} {}", length, unit), true).await;
+            Ok(length)
+        }
+        _ => {
+            hal.ui()
+                .status(
+                    &format!("Must be\n{} to {}", range.start(), range.end()),
+                    false,
+                )
+                .await;
+            Err(Error::InvalidInput)
+        }
+    }
+}
+
+async fn confirm_keypath(hal: &mut impl crate::hal::Hal, keypath: &str) -> Result<(), Error> {
     hal.ui()
         .confirm(&ConfirmParams {
             title: "Keypath",
-            body: &format!("m/83696968'/39'/0'/{}'/{}'", num_words, index),
+            body: keypath,
             scrollable: true,
             longtouch: true,
             ..Default::default()
         })
         .await?;
+    Ok(())
+}
 
-    let mnemonic = keystore::bip85_bip39(hal, num_words, index)?;
+/// Displays the derived secret. It is never sent to the host.
+async fn show_secret(
+    hal: &mut impl crate::hal::Hal,
+    title: &str,
+    secret: &str,
+) -> Result<(), Error> {
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title,
+            body: secret,
+            scrollable: true,
+            accept_only: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui().status("Finished", true).await;
+    Ok(())
+}
+
+/// Lets the user pick the wordlist of the derived mnemonic. Only languages whose wordlists can be
+/// displayed with the device font are offered.
+async fn select_language(hal: &mut impl crate::hal::Hal) -> Result<bip39::Language, Error> {
+    const LANGUAGES: [(&str, bip39::Language); 3] = [
+        ("English", bip39::Language::English),
+        ("Italian", bip39::Language::Italian),
+        ("Czech", bip39::Language::Czech),
+    ];
+    let names: Vec<&str> = LANGUAGES.iter().map(|(name, _)| *name).collect();
+    let choice = hal.ui().menu(&names, Some("Select language")).await?;
+    let (name, language) = LANGUAGES.get(choice as usize).ok_or(Error::Generic)?;
+    hal.ui().status(name, true).await;
+    Ok(*language)
+}
+
+/// Derives and displays a BIP-39 seed according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#bip39.
+async fn process_bip39(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
+    use crate::hal::ui::TrinaryChoice;
+
+    confirm_app(hal, "Derive BIP-39\nmnemonic?").await?;
+
+    let num_words: u32 = match hal
+        .ui()
+        .trinary_choice("How many words?", Some("12"), Some("18"), Some("24"))
+        .await
+    {
+        TrinaryChoice::Left => 12,
+        TrinaryChoice::Middle => 18,
+        TrinaryChoice::Right => 24,
+    };
+
+    hal.ui().status(&format!("{} words", num_words), true).await;
+
+    let language = select_language(hal).await?;
+
+    let index = select_index(hal).await?;
+
+    confirm_keypath(
+        hal,
+        &format!(
+            "m/83696968'/39'/{}'/{}'/{}'",
+            keystore::bip85_language_code(language),
+            num_words,
+            index
+        ),
+    )
+    .await?;
+
+    let mnemonic = keystore::bip85_bip39(hal, language, num_words, index)?;
     let words: Vec<&str> = mnemonic.split(' ').collect();
     {
         let crate::hal::HalSubsystems { ui, random, .. } = hal.as_mut();
-        crate::workflow::mnemonic::show_and_confirm_mnemonic(ui, random, &words).await?;
+        crate::workflow::mnemonic::show_and_confirm_mnemonic(
+            ui,
+            random,
+            language.word_list(),
+            &words,
+        )
+        .await?;
     }
 
     hal.ui().status("Finished", true).await;
@@ -145,3 +282,372 @@ async fn process_ln(
         .map_err(|_| Error::Generic)?
         .to_vec())
 }
+
+/// Derives and displays hex encoded entropy of a user-chosen length according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#hex
+async fn process_hex(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
+    confirm_app(hal, "Derive\nhex entropy?").await?;
+    let num_bytes = enter_length(hal, "Number of bytes", "bytes", 16..=64).await?;
+    let index = select_index(hal).await?;
+    confirm_keypath(
+        hal,
+        &format!("m/83696968'/128169'/{}'/{}'", num_bytes, index),
+    )
+    .await?;
+    let entropy = keystore::bip85_hex(hal, num_bytes, index)?;
+    show_secret(hal, "Hex", &entropy).await
+}
+
+enum PasswordEncoding {
+    Base64,
+    Base85,
+}
+
+/// Derives and displays a password of a user-chosen length according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#pwd-base64
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#pwd-base85
+async fn process_pwd(
+    hal: &mut impl crate::hal::Hal,
+    encoding: PasswordEncoding,
+) -> Result<(), Error> {
+    let (app_number, lengths, confirm_body) = match encoding {
+        PasswordEncoding::Base64 => (707764, 20..=86, "Derive base64\npassword?"),
+        PasswordEncoding::Base85 => (707785, 10..=80, "Derive base85\npassword?"),
+    };
+    confirm_app(hal, confirm_body).await?;
+    let length = enter_length(hal, "Password length", "characters", lengths).await?;
+    let index = select_index(hal).await?;
+    confirm_keypath(
+        hal,
+        &format!("m/83696968'/{}'/{}'/{}'", app_number, length, index),
+    )
+    .await?;
+    let password = match encoding {
+        PasswordEncoding::Base64 => keystore::bip85_pwd_base64(hal, length, index)?,
+        PasswordEncoding::Base85 => keystore::bip85_pwd_base85(hal, length, index)?,
+    };
+    show_secret(hal, "Password", &password).await
+}
+
+/// Derives and displays a WIF private key according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#hd-seed-wif
+async fn process_wif(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
+    confirm_app(hal, "Derive WIF\nprivate key?").await?;
+    let index = select_index(hal).await?;
+    confirm_keypath(hal, &format!("m/83696968'/2'/{}'", index)).await?;
+    let wif = keystore::bip85_wif(hal, index)?;
+    show_secret(hal, "WIF", &wif).await
+}
+
+/// Derives and displays an extended private key according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#xprv
+async fn process_xprv(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
+    confirm_app(hal, "Derive\nxprv?").await?;
+    let index = select_index(hal).await?;
+    confirm_keypath(hal, &format!("m/83696968'/32'/{}'", index)).await?;
+    let xprv = keystore::bip85_xprv(hal, index)?;
+    show_secret(hal, "XPRV", &xprv).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked_using_mnemonic;
+    use alloc::boxed::Box;
+    use util::bb02_async::block_on;
+
+    fn mock_unlocked() {
+        mock_unlocked_using_mnemonic(
+            "virtual weapon code laptop defy cricket vicious target wave leopard garden give",
+            "",
+        );
+    }
+
+    fn request(app: pb::bip85_request::App) -> pb::Bip85Request {
+        pb::Bip85Request { app: Some(app) }
+    }
+
+    #[test]
+    fn test_process_bip39() {
+        use crate::hal::testing::ui::TestingUi;
+        use crate::hal::ui::TrinaryChoice;
+
+        mock_unlocked();
+        let expected_words: Vec<&str> = "utensile tubatura tollerare anca grifone tarpare conciso albo segregato caso alabarda gregge imbocco tortora sgrassato proroga achille innesco"
+            .split(' ')
+            .collect();
+
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.set_trinary_choice(Box::new(
+            |message, label_left, label_middle, label_right| {
+                assert_eq!(message, "How many words?");
+                assert_eq!(label_left, Some("12"));
+                assert_eq!(label_middle, Some("18"));
+                assert_eq!(label_right, Some("24"));
+                TrinaryChoice::Middle
+            },
+        ));
+        mock_hal.ui.set_menu(Box::new(|choices, title| match title {
+            Some("Select language") => {
+                assert_eq!(choices, &["English", "Italian", "Czech"]);
+                Ok(1)
+            }
+            Some("Select index") => Ok(1),
+            _ => panic!("unexpected menu"),
+        }));
+        mock_hal
+            .ui
+            .prepare_show_and_confirm_mnemonic(&mut mock_hal.random, expected_words.len());
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &request(pb::bip85_request::App::Bip39(()))
+            )),
+            Ok(Response::Bip85(pb::Bip85Response {
+                app: Some(pb::bip85_response::App::Bip39(())),
+            })),
+        );
+        assert_eq!(
+            mock_hal.ui.screens[2..6],
+            [
+                Screen::Status {
+                    title: "18 words".into(),
+                    success: true,
+                },
+                Screen::Status {
+                    title: "Italian".into(),
+                    success: true,
+                },
+                Screen::Status {
+                    title: "Index: 1".into(),
+                    success: true,
+                },
+                Screen::Confirm {
+                    title: "Keypath".into(),
+                    body: "m/83696968'/39'/7'/18'/1'".into(),
+                    longtouch: true,
+                },
+            ],
+        );
+        let quiz_screens = &mock_hal.ui.screens[6..mock_hal.ui.screens.len() - 1];
+        TestingUi::assert_show_and_confirm_mnemonic_screens(quiz_screens, &expected_words);
+        // The wrong choices in the quiz come from the same wordlist.
+        match &quiz_screens[3] {
+            Screen::QuizMnemonicWord { choices, .. } => {
+                assert_eq!(choices[0], "abaco");
+            }
+            _ => panic!("unexpected screen"),
+        }
+        assert_eq!(
+            mock_hal.ui.screens.last().unwrap(),
+            &Screen::Status {
+                title: "Finished".into(),
+                success: true,
+            },
+        );
+    }
+
+    #[test]
+    fn test_process_hex() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.set_enter_string(Box::new(|params| {
+            assert_eq!(params.title, "Number of bytes");
+            assert!(params.number_input);
+            Ok("16".into())
+        }));
+        mock_hal.ui.set_menu(Box::new(|_, title| {
+            assert_eq!(title, Some("Select index"));
+            Ok(0)
+        }));
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &request(pb::bip85_request::App::Hex(()))
+            )),
+            Ok(Response::Bip85(pb::Bip85Response {
+                app: Some(pb::bip85_response::App::Hex(())),
+            })),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "BIP-85".into(),
+                    body: "Derive\nhex entropy?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "BIP-85".into(),
+                    body:
+                        "This is an advanced feature. Proceed only if you know what you are doing."
+                            .into(),
+                    longtouch: false,
+                },
+                Screen::Status {
+                    title: "16 bytes".into(),
+                    success: true,
+                },
+                Screen::Status {
+                    title: "Index: 0".into(),
+                    success: true,
+                },
+                Screen::Confirm {
+                    title: "Keypath".into(),
+                    body: "m/83696968'/128169'/16'/0'".into(),
+                    longtouch: true,
+                },
+                Screen::Confirm {
+                    title: "Hex".into(),
+                    body: "3db743509311e54c87ddf1d50ac187b4".into(),
+                    longtouch: false,
+                },
+                Screen::Status {
+                    title: "Finished".into(),
+                    success: true,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_process_pwd() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        mock_hal
+            .ui
+            .set_enter_string(Box::new(|_params| Ok("10".into())));
+        mock_hal.ui.set_menu(Box::new(|_, _| Ok(0)));
+        assert!(
+            block_on(process(
+                &mut mock_hal,
+                &request(pb::bip85_request::App::PwdBase85(()))
+            ))
+            .is_ok()
+        );
+        assert_eq!(
+            mock_hal.ui.screens[0],
+            Screen::Confirm {
+                title: "BIP-85".into(),
+                body: "Derive base85\npassword?".into(),
+                longtouch: false,
+            },
+        );
+        assert_eq!(
+            mock_hal.ui.screens[4],
+            Screen::Confirm {
+                title: "Keypath".into(),
+                body: "m/83696968'/707785'/10'/0'".into(),
+                longtouch: true,
+            },
+        );
+        assert_eq!(
+            mock_hal.ui.screens[5],
+            Screen::Confirm {
+                title: "Password".into(),
+                body: "<i}}BR1O>U".into(),
+                longtouch: false,
+            },
+        );
+
+        // Base64 passwords must be at least 20 characters long.
+        let mut mock_hal = TestingHal::new();
+        mock_hal
+            .ui
+            .set_enter_string(Box::new(|_params| Ok("10".into())));
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &request(pb::bip85_request::App::PwdBase64(()))
+            )),
+            Err(Error::InvalidInput),
+        );
+        assert_eq!(
+            mock_hal.ui.screens[0],
+            Screen::Confirm {
+                title: "BIP-85".into(),
+                body: "Derive base64\npassword?".into(),
+                longtouch: false,
+            },
+        );
+        assert_eq!(
+            mock_hal.ui.screens.last().unwrap(),
+            &Screen::Status {
+                title: "Must be\n20 to 86".into(),
+                success: false,
+            },
+        );
+    }
+
+    #[test]
+    fn test_process_wif_xprv() {
+        mock_unlocked();
+
+        // Index selected using the number input.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.set_menu(Box::new(|_, _| Ok(5)));
+        mock_hal.ui.set_enter_string(Box::new(|params| {
+            assert_eq!(params.title, "Enter index");
+            Ok("1".into())
+        }));
+        assert!(
+            block_on(process(
+                &mut mock_hal,
+                &request(pb::bip85_request::App::Wif(()))
+            ))
+            .is_ok()
+        );
+        assert_eq!(
+            mock_hal.ui.screens[2..5],
+            [
+                Screen::Status {
+                    title: "Index: 1".into(),
+                    success: true,
+                },
+                Screen::Confirm {
+                    title: "Keypath".into(),
+                    body: "m/83696968'/2'/1'".into(),
+                    longtouch: true,
+                },
+                Screen::Confirm {
+                    title: "WIF".into(),
+                    body: "L28A5vg5yc1N2J7AmGochrPFUS86NKLqPBbFF19nd966mj3SsMQD".into(),
+                    longtouch: false,
+                },
+            ],
+        );
+
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.set_menu(Box::new(|_, _| Ok(0)));
+        assert!(
+            block_on(process(
+                &mut mock_hal,
+                &request(pb::bip85_request::App::Xprv(()))
+            ))
+            .is_ok()
+        );
+        assert_eq!(
+            mock_hal.ui.screens[4],
+            Screen::Confirm {
+                title: "XPRV".into(),
+                body: "xprv9s21ZrQH143K2G55HNRqe8NuwBXKSzjYhXsHTE5NER7h2Q5QKEgcMF5jkmwJLdTm3XDW1nBiNr7eDjwWxSutCRNKFzKVmAdwviLkkkRFYGz".into(),
+                longtouch: false,
+            },
+        );
+
+        // User aborts at the keypath confirmation.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.set_menu(Box::new(|_, _| Ok(0)));
+        mock_hal.ui.abort_nth(3);
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &request(pb::bip85_request::App::Xprv(()))
+            )),
+            Err(Error::UserAbort),
+        );
+    }
+}
@@ -8,6 +8,7 @@ mod bip143;
 mod bip341;
 pub mod common;
 pub mod keypath;
+mod lightning;
 mod multisig;
 pub mod params;
 mod payment_request;
@@ -307,6 +308,7 @@ pub async fn process_api(
         }
         Request::SignMessage(request) => signmsg::process(hal, request).await,
         Request::Xpubs(request) => xpubs::process_xpubs(hal, request).await,
+        Request::Lightning(request) => lightning::process(hal, request).await,
         // These are streamed asynchronously using the `next_request()` primitive in
         // bitcoin/signtx.rs and are not handled directly.
         Request::PrevtxInit(_)
@@ -0,0 +1,2572 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validating Lightning channel signer.
+//!
+//! The host runs the Lightning node, while the node key and the channel keys never leave the
+//! device. Channel transactions are signed only if they don't violate the following rules:
+//!
+//! - Counterparty commitments are signed in order. The last one can be signed again (e.g. when
+//!   retransmitting after a reconnect), but only if the transaction and its HTLC transactions are
+//!   exactly the same.
+//! - If our balance in a counterparty commitment is lower than in the previous one, the user has
+//!   to confirm the difference on the device.
+//! - Holder commitments are validated in order, including the signature of the counterparty, and
+//!   only validated holder commitments are signed.
+//! - Holder commitments are revoked in order, and only if a newer holder commitment has been
+//!   validated. A revoked holder commitment is never signed.
+//! - Every commitment transaction must encode its commitment number in the locktime and the
+//!   sequence, and its outputs must be the to_local, to_remote and offered or received HTLC
+//!   outputs derived for the commitment, so that a transaction of one commitment can't be passed
+//!   off as one of another commitment.
+//! - The HTLC transactions spending the HTLC outputs pay to the to_local script of the owner of
+//!   the commitment, and their fee rate can't exceed the one of the commitment transaction, so
+//!   that the amount of an HTLC can't be diverted or given away as fee.
+//! - Our balance is our own output in the counterparty commitment. An HTLC we offer is counted as
+//!   paid when it is added, so the decrease of our balance is confirmed before the payment can be
+//!   claimed.
+//! - Signing a holder commitment force closes the channel: no further counterparty commitments
+//!   are signed and no further holder commitments are validated or revoked.
+//! - Only closed channels can be forgotten to free their state, and the indices of forgotten
+//!   channels can't be set up again.
+//!
+//! Only channels using `option_static_remotekey` without anchor outputs are supported, i.e. our
+//! output in the counterparty commitment pays to the payment basepoint and all transactions are
+//! signed with `SIGHASH_ALL`.
+//!
+//! The state needed to enforce these rules is persisted per coin and channel in a flash chunk
+//! reserved for it. Every signed counterparty commitment, every validated holder commitment and
+//! every revocation updates it, which counts against the endurance of that chunk but not of the
+//! chunks holding other app data. The state survives device resets: otherwise, after a reset and
+//! a restore of the same seed, the host could set up a channel again and get old, revoked
+//! commitments signed. The state is not tied to the seed, as resetting and restoring another seed
+//! in between would otherwise clear it. The channel indices used with a previous seed therefore
+//! stay in use after restoring a different seed.
+//!
+//! See https://github.com/lightning/bolts/blob/master/03-transactions.md
+
+use super::Error;
+use super::bip143;
+use super::common::{FormatUnit, format_amount};
+use super::params::Params;
+use super::pb;
+use super::script::serialize_varint;
+
+use crate::hal::ui::ConfirmParams;
+use crate::hal::{Memory, Ui};
+use crate::keystore;
+use crate::secp256k1::SECP256K1;
+
+use alloc::vec::Vec;
+
+use bitcoin::hashes::Hash;
+use bitcoin::secp256k1::{Message, PublicKey, Scalar, SecretKey, ecdsa::Signature};
+use pb::BtcCoin;
+use pb::btc_lightning_request::Request;
+use pb::btc_lightning_response::Response;
+use sha2::{Digest, Sha256};
+use util::bip32::HARDENED;
+use zeroize::Zeroizing;
+
+const PURPOSE: u32 = 9735 + HARDENED;
+
+/// Commitment numbers are 48 bits.
+const MAX_COMMITMENT_NUMBER: u64 = (1 << 48) - 1;
+
+const SIGHASH_ALL: u32 = 0x01;
+
+/// Weights of transactions without anchor outputs, used to compare their fee rates, see
+/// https://github.com/lightning/bolts/blob/master/03-transactions.md#fee-calculation
+const COMMITMENT_TX_BASE_WEIGHT: u64 = 724;
+const COMMITMENT_TX_WEIGHT_PER_HTLC: u64 = 172;
+const HTLC_TIMEOUT_TX_WEIGHT: u64 = 663;
+const HTLC_SUCCESS_TX_WEIGHT: u64 = 703;
+
+/// Locktimes from this value on are timestamps instead of block heights.
+const LOCKTIME_THRESHOLD: u32 = 500_000_000;
+
+#[derive(Copy, Clone)]
+enum ChannelKey {
+    Funding = 0,
+    Revocation = 1,
+    Payment = 2,
+    DelayedPayment = 3,
+    Htlc = 4,
+    CommitmentSeed = 5,
+}
+
+fn node_keypath(params: &Params) -> [u32; 3] {
+    [PURPOSE, params.bip44_coin, HARDENED]
+}
+
+fn channel_keypath(
+    params: &Params,
+    channel_index: u32,
+    key: ChannelKey,
+) -> Result<[u32; 5], Error> {
+    if channel_index >= HARDENED {
+        return Err(Error::InvalidInput);
+    }
+    Ok([
+        PURPOSE,
+        params.bip44_coin,
+        1 + HARDENED,
+        channel_index + HARDENED,
+        key as u32 + HARDENED,
+    ])
+}
+
+fn secret_key(hal: &mut impl crate::hal::Hal, keypath: &[u32]) -> Result<SecretKey, Error> {
+    let private_key = keystore::secp256k1_get_private_key(hal, keypath)?;
+    SecretKey::from_slice(&private_key).map_err(|_| Error::Generic)
+}
+
+fn channel_secret_key(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    key: ChannelKey,
+) -> Result<SecretKey, Error> {
+    secret_key(hal, &channel_keypath(params, channel_index, key)?)
+}
+
+fn channel_public_key(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    key: ChannelKey,
+) -> Result<PublicKey, Error> {
+    Ok(channel_secret_key(hal, params, channel_index, key)?.public_key(SECP256K1))
+}
+
+/// Channel parameters, see `pb::BtcLightningChannelParameters`.
+struct ChannelParameters {
+    is_outbound: bool,
+    counterparty_payment_basepoint: PublicKey,
+    counterparty_revocation_basepoint: PublicKey,
+    counterparty_delayed_payment_basepoint: PublicKey,
+    counterparty_htlc_basepoint: PublicKey,
+    holder_to_self_delay: u16,
+    counterparty_to_self_delay: u16,
+}
+
+fn parse_parameters(
+    parameters: &pb::BtcLightningChannelParameters,
+) -> Result<ChannelParameters, Error> {
+    let pubkey = |pubkey: &[u8]| PublicKey::from_slice(pubkey).or(Err(Error::InvalidInput));
+    let delay = |delay: u32| match u16::try_from(delay) {
+        Ok(delay) if delay > 0 => Ok(delay),
+        _ => Err(Error::InvalidInput),
+    };
+    Ok(ChannelParameters {
+        is_outbound: parameters.is_outbound,
+        counterparty_payment_basepoint: pubkey(&parameters.counterparty_payment_basepoint)?,
+        counterparty_revocation_basepoint: pubkey(&parameters.counterparty_revocation_basepoint)?,
+        counterparty_delayed_payment_basepoint: pubkey(
+            &parameters.counterparty_delayed_payment_basepoint,
+        )?,
+        counterparty_htlc_basepoint: pubkey(&parameters.counterparty_htlc_basepoint)?,
+        holder_to_self_delay: delay(parameters.holder_to_self_delay)?,
+        counterparty_to_self_delay: delay(parameters.counterparty_to_self_delay)?,
+    })
+}
+
+/// The hash stored in the channel state, committing to the channel parameters.
+fn parameters_hash(parameters: &ChannelParameters) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([parameters.is_outbound as u8]);
+    hasher.update(parameters.counterparty_payment_basepoint.serialize());
+    hasher.update(parameters.counterparty_revocation_basepoint.serialize());
+    hasher.update(
+        parameters
+            .counterparty_delayed_payment_basepoint
+            .serialize(),
+    );
+    hasher.update(parameters.counterparty_htlc_basepoint.serialize());
+    hasher.update(parameters.holder_to_self_delay.to_le_bytes());
+    hasher.update(parameters.counterparty_to_self_delay.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// The lower 48 bits of `SHA256(opener_payment_basepoint || accepter_payment_basepoint)`, with
+/// which the commitment number is obscured in commitment transactions.
+fn obscuring_factor(holder_payment_basepoint: &PublicKey, parameters: &ChannelParameters) -> u64 {
+    let (opener, accepter) = if parameters.is_outbound {
+        (
+            holder_payment_basepoint,
+            &parameters.counterparty_payment_basepoint,
+        )
+    } else {
+        (
+            &parameters.counterparty_payment_basepoint,
+            holder_payment_basepoint,
+        )
+    };
+    let mut hasher = Sha256::new();
+    hasher.update(opener.serialize());
+    hasher.update(accepter.serialize());
+    let hash: [u8; 32] = hasher.finalize().into();
+    let mut factor = [0u8; 8];
+    factor[2..].copy_from_slice(&hash[26..]);
+    u64::from_be_bytes(factor)
+}
+
+fn sha256d(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/// Computes the per-commitment secret of a commitment number, see
+/// https://github.com/lightning/bolts/blob/master/03-transactions.md#per-commitment-secret-requirements
+fn per_commitment_secret(
+    seed: &[u8; 32],
+    commitment_number: u64,
+) -> Result<Zeroizing<[u8; 32]>, Error> {
+    if commitment_number > MAX_COMMITMENT_NUMBER {
+        return Err(Error::InvalidInput);
+    }
+    // Secrets are generated in reverse order of the index.
+    let index = MAX_COMMITMENT_NUMBER - commitment_number;
+    let mut secret = Zeroizing::new(*seed);
+    for bit in (0..48).rev() {
+        if index & (1 << bit) != 0 {
+            secret[bit / 8] ^= 1 << (bit % 8);
+            *secret = Sha256::digest(secret.as_slice()).into();
+        }
+    }
+    Ok(secret)
+}
+
+fn holder_per_commitment_secret(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    commitment_number: u64,
+) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let seed = channel_secret_key(hal, params, channel_index, ChannelKey::CommitmentSeed)?;
+    per_commitment_secret(&seed.secret_bytes(), commitment_number)
+}
+
+fn holder_per_commitment_point(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    commitment_number: u64,
+) -> Result<PublicKey, Error> {
+    let secret = holder_per_commitment_secret(hal, params, channel_index, commitment_number)?;
+    let secret = SecretKey::from_slice(secret.as_slice()).map_err(|_| Error::Generic)?;
+    Ok(secret.public_key(SECP256K1))
+}
+
+/// `SHA256(per_commitment_point || basepoint)`, used to derive the keys of a commitment from a
+/// basepoint.
+fn key_tweak(basepoint: &PublicKey, per_commitment_point: &PublicKey) -> Result<Scalar, Error> {
+    let mut hasher = Sha256::new();
+    hasher.update(per_commitment_point.serialize());
+    hasher.update(basepoint.serialize());
+    Scalar::from_be_bytes(hasher.finalize().into()).map_err(|_| Error::Generic)
+}
+
+/// `pubkey = basepoint + SHA256(per_commitment_point || basepoint) * G`
+fn derive_public_key(
+    basepoint: &PublicKey,
+    per_commitment_point: &PublicKey,
+) -> Result<PublicKey, Error> {
+    basepoint
+        .add_exp_tweak(SECP256K1, &key_tweak(basepoint, per_commitment_point)?)
+        .map_err(|_| Error::Generic)
+}
+
+/// `revocationpubkey = revocation_basepoint * SHA256(revocation_basepoint || per_commitment_point)
+/// + per_commitment_point * SHA256(per_commitment_point || revocation_basepoint)`
+fn revocation_pubkey(
+    revocation_basepoint: &PublicKey,
+    per_commitment_point: &PublicKey,
+) -> Result<PublicKey, Error> {
+    // `key_tweak(a, b)` is `SHA256(b || a)`.
+    let basepoint_part = revocation_basepoint
+        .mul_tweak(
+            SECP256K1,
+            &key_tweak(per_commitment_point, revocation_basepoint)?,
+        )
+        .map_err(|_| Error::Generic)?;
+    let per_commitment_part = per_commitment_point
+        .mul_tweak(
+            SECP256K1,
+            &key_tweak(revocation_basepoint, per_commitment_point)?,
+        )
+        .map_err(|_| Error::Generic)?;
+    basepoint_part
+        .combine(&per_commitment_part)
+        .map_err(|_| Error::Generic)
+}
+
+/// `privkey = basepoint_secret + SHA256(per_commitment_point || basepoint)`
+fn derive_private_key(
+    basepoint_secret: &SecretKey,
+    per_commitment_point: &PublicKey,
+) -> Result<SecretKey, Error> {
+    let tweak = key_tweak(
+        &basepoint_secret.public_key(SECP256K1),
+        per_commitment_point,
+    )?;
+    basepoint_secret
+        .add_tweak(&tweak)
+        .map_err(|_| Error::Generic)
+}
+
+/// The witness script of the funding output: a 2-of-2 multisig with the pubkeys in lexicographic
+/// order.
+fn funding_script(pubkey1: &PublicKey, pubkey2: &PublicKey) -> Vec<u8> {
+    let (pubkey1, pubkey2) = (pubkey1.serialize(), pubkey2.serialize());
+    let (first, second) = if pubkey1 <= pubkey2 {
+        (pubkey1, pubkey2)
+    } else {
+        (pubkey2, pubkey1)
+    };
+    let mut script = Vec::with_capacity(71);
+    // OP_2
+    script.push(0x52);
+    script.push(33);
+    script.extend_from_slice(&first);
+    script.push(33);
+    script.extend_from_slice(&second);
+    // OP_2 OP_CHECKMULTISIG
+    script.extend_from_slice(&[0x52, 0xae]);
+    script
+}
+
+/// The witness script of the to_local output, spendable by the owner of the commitment after
+/// `to_self_delay` blocks or immediately by the counterparty with the revocation key.
+fn to_local_script(
+    revocation_pubkey: &PublicKey,
+    to_self_delay: u16,
+    delayed_pubkey: &PublicKey,
+) -> Vec<u8> {
+    use bitcoin::opcodes::all::{OP_CHECKSIG, OP_CSV, OP_DROP, OP_ELSE, OP_ENDIF, OP_IF};
+    bitcoin::script::Builder::new()
+        .push_opcode(OP_IF)
+        .push_slice(revocation_pubkey.serialize())
+        .push_opcode(OP_ELSE)
+        .push_int(to_self_delay.into())
+        .push_opcode(OP_CSV)
+        .push_opcode(OP_DROP)
+        .push_slice(delayed_pubkey.serialize())
+        .push_opcode(OP_ENDIF)
+        .push_opcode(OP_CHECKSIG)
+        .into_bytes()
+}
+
+/// The witness script of an offered or received HTLC output, see
+/// https://github.com/lightning/bolts/blob/master/03-transactions.md#offered-htlc-outputs and
+/// https://github.com/lightning/bolts/blob/master/03-transactions.md#received-htlc-outputs
+fn htlc_script(
+    keys: &CommitmentKeys,
+    offered: bool,
+    payment_hash: &[u8; 32],
+    cltv_expiry: u32,
+) -> Vec<u8> {
+    use bitcoin::hashes::{hash160, ripemd160};
+    use bitcoin::opcodes::all::{
+        OP_CHECKMULTISIG, OP_CHECKSIG, OP_CLTV, OP_DROP, OP_DUP, OP_ELSE, OP_ENDIF, OP_EQUAL,
+        OP_EQUALVERIFY, OP_HASH160, OP_IF, OP_NOTIF, OP_SIZE, OP_SWAP,
+    };
+    let revocation_hash = hash160::Hash::hash(&keys.revocation_pubkey.serialize());
+    let payment_hash = ripemd160::Hash::hash(payment_hash);
+    let builder = bitcoin::script::Builder::new()
+        .push_opcode(OP_DUP)
+        .push_opcode(OP_HASH160)
+        .push_slice(revocation_hash.to_byte_array())
+        .push_opcode(OP_EQUAL)
+        .push_opcode(OP_IF)
+        .push_opcode(OP_CHECKSIG)
+        .push_opcode(OP_ELSE)
+        .push_slice(keys.remote_htlc_pubkey.serialize())
+        .push_opcode(OP_SWAP)
+        .push_opcode(OP_SIZE)
+        .push_int(32)
+        .push_opcode(OP_EQUAL);
+    let builder = if offered {
+        builder
+            .push_opcode(OP_NOTIF)
+            .push_opcode(OP_DROP)
+            .push_int(2)
+            .push_opcode(OP_SWAP)
+            .push_slice(keys.local_htlc_pubkey.serialize())
+            .push_int(2)
+            .push_opcode(OP_CHECKMULTISIG)
+            .push_opcode(OP_ELSE)
+            .push_opcode(OP_HASH160)
+            .push_slice(payment_hash.to_byte_array())
+            .push_opcode(OP_EQUALVERIFY)
+            .push_opcode(OP_CHECKSIG)
+    } else {
+        builder
+            .push_opcode(OP_IF)
+            .push_opcode(OP_HASH160)
+            .push_slice(payment_hash.to_byte_array())
+            .push_opcode(OP_EQUALVERIFY)
+            .push_int(2)
+            .push_opcode(OP_SWAP)
+            .push_slice(keys.local_htlc_pubkey.serialize())
+            .push_int(2)
+            .push_opcode(OP_CHECKMULTISIG)
+            .push_opcode(OP_ELSE)
+            .push_opcode(OP_DROP)
+            .push_int(cltv_expiry.into())
+            .push_opcode(OP_CLTV)
+            .push_opcode(OP_DROP)
+            .push_opcode(OP_CHECKSIG)
+    };
+    builder
+        .push_opcode(OP_ENDIF)
+        .push_opcode(OP_ENDIF)
+        .into_bytes()
+}
+
+/// The keys of a commitment transaction, derived with the per-commitment point of the owner of
+/// the commitment ("local" in BOLT #3).
+struct CommitmentKeys {
+    revocation_pubkey: PublicKey,
+    to_self_delay: u16,
+    local_delayed_pubkey: PublicKey,
+    local_htlc_pubkey: PublicKey,
+    remote_htlc_pubkey: PublicKey,
+}
+
+impl CommitmentKeys {
+    fn to_local_script(&self) -> Vec<u8> {
+        to_local_script(
+            &self.revocation_pubkey,
+            self.to_self_delay,
+            &self.local_delayed_pubkey,
+        )
+    }
+
+    /// The witness script of the HTLC output spent by `htlc_tx`.
+    fn htlc_script(&self, htlc_tx: &pb::BtcLightningHtlcTx) -> Result<Vec<u8>, Error> {
+        let payment_hash: [u8; 32] = htlc_tx
+            .payment_hash
+            .as_slice()
+            .try_into()
+            .or(Err(Error::InvalidInput))?;
+        if htlc_tx.cltv_expiry >= LOCKTIME_THRESHOLD {
+            return Err(Error::InvalidInput);
+        }
+        Ok(htlc_script(
+            self,
+            htlc_tx.offered,
+            &payment_hash,
+            htlc_tx.cltv_expiry,
+        ))
+    }
+}
+
+fn p2wpkh_pubkey_script(pubkey: &PublicKey) -> Vec<u8> {
+    let mut script = vec![0x00, 20];
+    script.extend_from_slice(bitcoin::hashes::hash160::Hash::hash(&pubkey.serialize()).as_ref());
+    script
+}
+
+fn p2wsh_pubkey_script(witness_script: &[u8]) -> Vec<u8> {
+    let mut script = vec![0x00, 32];
+    script.extend_from_slice(&Sha256::digest(witness_script));
+    script
+}
+
+fn serialize_output(out: &mut Vec<u8>, output: &pb::BtcLightningOutput) {
+    out.extend_from_slice(&output.value.to_le_bytes());
+    out.extend_from_slice(&serialize_varint(output.pubkey_script.len() as u64));
+    out.extend_from_slice(&output.pubkey_script);
+}
+
+fn hash_outputs<'a>(outputs: impl Iterator<Item = &'a pb::BtcLightningOutput>) -> [u8; 32] {
+    let mut serialized = Vec::new();
+    for output in outputs {
+        serialize_output(&mut serialized, output);
+    }
+    sha256d(&serialized)
+}
+
+/// The commitment transaction spends the funding output and encodes the obscured commitment
+/// number in the lower 24 bits of the locktime and the sequence, which must match
+/// `commitment_number`. Its outputs must be the to_local output of the commitment, the to_remote
+/// output (given as pubkey script) or HTLC outputs spent by one of `htlc_txs`, whose fee rate
+/// can't exceed the one of the commitment transaction.
+fn validate_commitment_tx(
+    tx: &pb::BtcLightningCommitmentTx,
+    commitment_number: u64,
+    obscuring_factor: u64,
+    keys: &CommitmentKeys,
+    to_remote_pubkey_script: &[u8],
+    htlc_txs: &[pb::BtcLightningHtlcTx],
+) -> Result<(), Error> {
+    if tx.version != 2
+        || tx.funding_txid.len() != 32
+        || tx.locktime >> 24 != 0x20
+        || tx.sequence >> 24 != 0x80
+        || tx.outputs.is_empty()
+    {
+        return Err(Error::InvalidInput);
+    }
+    let obscured_commitment_number =
+        (u64::from(tx.sequence & 0xffffff) << 24) | u64::from(tx.locktime & 0xffffff);
+    if obscured_commitment_number ^ obscuring_factor != commitment_number {
+        return Err(Error::InvalidInput);
+    }
+    let mut outputs_value: u64 = 0;
+    for output in tx.outputs.iter() {
+        outputs_value = outputs_value
+            .checked_add(output.value)
+            .ok_or(Error::InvalidInput)?;
+    }
+    let fee = tx
+        .funding_value
+        .checked_sub(outputs_value)
+        .ok_or(Error::InvalidInput)?;
+    let weight = COMMITMENT_TX_BASE_WEIGHT + COMMITMENT_TX_WEIGHT_PER_HTLC * htlc_txs.len() as u64;
+    for (index, htlc_tx) in htlc_txs.iter().enumerate() {
+        if htlc_txs[..index]
+            .iter()
+            .any(|other| other.commitment_output_index == htlc_tx.commitment_output_index)
+        {
+            return Err(Error::InvalidInput);
+        }
+        let htlc_output = tx
+            .outputs
+            .get(htlc_tx.commitment_output_index as usize)
+            .ok_or(Error::InvalidInput)?;
+        if htlc_output.pubkey_script != p2wsh_pubkey_script(&keys.htlc_script(htlc_tx)?) {
+            return Err(Error::InvalidInput);
+        }
+        let htlc_fee = htlc_output
+            .value
+            .checked_sub(htlc_tx.output_value)
+            .ok_or(Error::InvalidInput)?;
+        let htlc_weight = if htlc_tx.offered {
+            HTLC_TIMEOUT_TX_WEIGHT
+        } else {
+            HTLC_SUCCESS_TX_WEIGHT
+        };
+        if u128::from(htlc_fee) * u128::from(weight) > u128::from(fee) * u128::from(htlc_weight) {
+            return Err(Error::InvalidInput);
+        }
+    }
+    let to_local_pubkey_script = p2wsh_pubkey_script(&keys.to_local_script());
+    for (index, output) in tx.outputs.iter().enumerate() {
+        let is_htlc_output = htlc_txs
+            .iter()
+            .any(|htlc_tx| htlc_tx.commitment_output_index as usize == index);
+        if output.pubkey_script != to_local_pubkey_script
+            && output.pubkey_script != to_remote_pubkey_script
+            && !is_htlc_output
+        {
+            return Err(Error::InvalidInput);
+        }
+    }
+    Ok(())
+}
+
+fn commitment_txid(tx: &pb::BtcLightningCommitmentTx) -> [u8; 32] {
+    let mut serialized = Vec::new();
+    serialized.extend_from_slice(&tx.version.to_le_bytes());
+    // One input with an empty scriptSig.
+    serialized.push(1);
+    serialized.extend_from_slice(&tx.funding_txid);
+    serialized.extend_from_slice(&tx.funding_output_index.to_le_bytes());
+    serialized.push(0);
+    serialized.extend_from_slice(&tx.sequence.to_le_bytes());
+    serialized.extend_from_slice(&serialize_varint(tx.outputs.len() as u64));
+    for output in tx.outputs.iter() {
+        serialize_output(&mut serialized, output);
+    }
+    serialized.extend_from_slice(&tx.locktime.to_le_bytes());
+    sha256d(&serialized)
+}
+
+/// The hash stored in the channel state, committing to a signed commitment transaction and its
+/// HTLC transactions.
+fn signed_commitment_hash(
+    tx: &pb::BtcLightningCommitmentTx,
+    htlc_txs: &[pb::BtcLightningHtlcTx],
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(commitment_txid(tx));
+    hasher.update(tx.funding_value.to_le_bytes());
+    hasher.update(&tx.counterparty_funding_pubkey);
+    for htlc_tx in htlc_txs {
+        hasher.update(htlc_tx.commitment_output_index.to_le_bytes());
+        hasher.update([htlc_tx.offered as u8]);
+        hasher.update(serialize_varint(htlc_tx.payment_hash.len() as u64));
+        hasher.update(&htlc_tx.payment_hash);
+        hasher.update(htlc_tx.cltv_expiry.to_le_bytes());
+        hasher.update(htlc_tx.output_value.to_le_bytes());
+    }
+    hasher.finalize().into()
+}
+
+fn sign(private_key: &SecretKey, sighash: &[u8; 32]) -> Result<Vec<u8>, Error> {
+    let private_key = Zeroizing::new(private_key.secret_bytes());
+    let sign_result = crate::secp256k1::secp256k1_sign(&private_key, sighash, None)?;
+    Ok(sign_result.signature.to_vec())
+}
+
+/// The sighash of the funding input of a commitment transaction, signed by both parties.
+fn funding_sighash(
+    tx: &pb::BtcLightningCommitmentTx,
+    funding_pubkey: &PublicKey,
+) -> Result<[u8; 32], Error> {
+    let counterparty_funding_pubkey =
+        PublicKey::from_slice(&tx.counterparty_funding_pubkey).or(Err(Error::InvalidInput))?;
+    let funding_script = funding_script(funding_pubkey, &counterparty_funding_pubkey);
+
+    let funding_txid: [u8; 32] = tx.funding_txid.as_slice().try_into().unwrap();
+    let mut outpoint = Vec::with_capacity(36);
+    outpoint.extend_from_slice(&funding_txid);
+    outpoint.extend_from_slice(&tx.funding_output_index.to_le_bytes());
+    Ok(bip143::sighash(&bip143::Args {
+        version: tx.version,
+        hash_prevouts: sha256d(&outpoint),
+        hash_sequence: sha256d(&tx.sequence.to_le_bytes()),
+        outpoint_hash: funding_txid,
+        outpoint_index: tx.funding_output_index,
+        sighash_script: &funding_script,
+        prevout_value: tx.funding_value,
+        sequence: tx.sequence,
+        hash_outputs: hash_outputs(tx.outputs.iter()),
+        locktime: tx.locktime,
+        sighash_flags: SIGHASH_ALL,
+    }))
+}
+
+/// Signs the funding input of a commitment transaction and the given HTLC transactions spending
+/// its HTLC outputs. `per_commitment_point` is the one of the owner of the commitment. The
+/// transactions must have been validated with `validate_commitment_tx()`.
+fn sign_commitment(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    tx: &pb::BtcLightningCommitmentTx,
+    htlc_txs: &[pb::BtcLightningHtlcTx],
+    keys: &CommitmentKeys,
+    per_commitment_point: &PublicKey,
+) -> Result<pb::BtcLightningSignCommitmentResponse, Error> {
+    let funding_key = channel_secret_key(hal, params, channel_index, ChannelKey::Funding)?;
+    let sighash = funding_sighash(tx, &funding_key.public_key(SECP256K1))?;
+    let signature = sign(&funding_key, &sighash)?;
+
+    let mut htlc_signatures = Vec::with_capacity(htlc_txs.len());
+    if !htlc_txs.is_empty() {
+        let txid = commitment_txid(tx);
+        let htlc_key = derive_private_key(
+            &channel_secret_key(hal, params, channel_index, ChannelKey::Htlc)?,
+            per_commitment_point,
+        )?;
+        let to_local_pubkey_script = p2wsh_pubkey_script(&keys.to_local_script());
+        for htlc_tx in htlc_txs {
+            let htlc_output = tx
+                .outputs
+                .get(htlc_tx.commitment_output_index as usize)
+                .ok_or(Error::InvalidInput)?;
+            // The HTLC transactions pay to the to_local script of the owner of the commitment.
+            let output = pb::BtcLightningOutput {
+                value: htlc_tx.output_value,
+                pubkey_script: to_local_pubkey_script.clone(),
+            };
+            // HTLC-timeout transactions can be broadcast once the HTLC has timed out,
+            // HTLC-success transactions right away.
+            let locktime = if htlc_tx.offered {
+                htlc_tx.cltv_expiry
+            } else {
+                0
+            };
+            let mut outpoint = Vec::with_capacity(36);
+            outpoint.extend_from_slice(&txid);
+            outpoint.extend_from_slice(&htlc_tx.commitment_output_index.to_le_bytes());
+            let sighash = bip143::sighash(&bip143::Args {
+                version: 2,
+                hash_prevouts: sha256d(&outpoint),
+                hash_sequence: sha256d(&0u32.to_le_bytes()),
+                outpoint_hash: txid,
+                outpoint_index: htlc_tx.commitment_output_index,
+                sighash_script: &keys.htlc_script(htlc_tx)?,
+                prevout_value: htlc_output.value,
+                sequence: 0,
+                hash_outputs: hash_outputs(core::iter::once(&output)),
+                locktime,
+                sighash_flags: SIGHASH_ALL,
+            });
+            htlc_signatures.push(sign(&htlc_key, &sighash)?);
+        }
+    }
+    Ok(pb::BtcLightningSignCommitmentResponse {
+        signature,
+        htlc_signatures,
+    })
+}
+
+/// The channel state is stored per BIP-44 coin type, as the channel keys are derived per coin.
+/// Returns `None` if the channel has not been set up with its parameters.
+fn get_channel_state(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+) -> Result<Option<crate::hal::memory::LightningChannelState>, Error> {
+    if channel_index >= HARDENED {
+        return Err(Error::InvalidInput);
+    }
+    Ok(hal
+        .memory()
+        .lightning_channel_get(params.bip44_coin - HARDENED, channel_index))
+}
+
+/// Returns the state of a channel that has been set up with the given parameters.
+fn get_channel(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    parameters: Option<&pb::BtcLightningChannelParameters>,
+) -> Result<(ChannelParameters, crate::hal::memory::LightningChannelState), Error> {
+    let parameters = parse_parameters(parameters.ok_or(Error::InvalidInput)?)?;
+    let state = get_channel_state(hal, params, channel_index)?.ok_or(Error::InvalidState)?;
+    if state.parameters_hash != parameters_hash(&parameters) {
+        return Err(Error::InvalidInput);
+    }
+    Ok((parameters, state))
+}
+
+fn set_channel_state(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    state: &crate::hal::memory::LightningChannelState,
+) -> Result<(), Error> {
+    hal.memory()
+        .lightning_channel_set(params.bip44_coin - HARDENED, channel_index, state)?;
+    Ok(())
+}
+
+fn process_channel_setup(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    request: &pb::BtcLightningChannelSetupRequest,
+) -> Result<Response, Error> {
+    // The keys of forgotten channels have been used already and must not be used again.
+    if request.channel_index
+        < hal
+            .memory()
+            .lightning_channels_forgotten_below(params.bip44_coin - HARDENED)
+    {
+        return Err(Error::InvalidState);
+    }
+    if let Some(parameters) = request.parameters.as_ref() {
+        let parameters_hash = parameters_hash(&parse_parameters(parameters)?);
+        match get_channel_state(hal, params, request.channel_index)? {
+            Some(state) if state.parameters_hash == parameters_hash => {}
+            Some(_) => return Err(Error::InvalidState),
+            None => set_channel_state(
+                hal,
+                params,
+                request.channel_index,
+                &crate::hal::memory::LightningChannelState {
+                    parameters_hash,
+                    ..Default::default()
+                },
+            )?,
+        }
+    }
+    let mut basepoint = |key| -> Result<Vec<u8>, Error> {
+        Ok(channel_public_key(hal, params, request.channel_index, key)?
+            .serialize()
+            .to_vec())
+    };
+    Ok(Response::ChannelSetup(
+        pb::BtcLightningChannelSetupResponse {
+            funding_pubkey: basepoint(ChannelKey::Funding)?,
+            revocation_basepoint: basepoint(ChannelKey::Revocation)?,
+            payment_basepoint: basepoint(ChannelKey::Payment)?,
+            delayed_payment_basepoint: basepoint(ChannelKey::DelayedPayment)?,
+            htlc_basepoint: basepoint(ChannelKey::Htlc)?,
+        },
+    ))
+}
+
+fn process_revoke(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    request: &pb::BtcLightningRevokeRequest,
+) -> Result<Response, Error> {
+    let mut state =
+        get_channel_state(hal, params, request.channel_index)?.ok_or(Error::InvalidState)?;
+    // The last validated holder commitment is never revoked, so that there is always a holder
+    // commitment that can be broadcast.
+    if state.closed
+        || request.commitment_number != state.revoked_holder_commitments
+        || request.commitment_number + 1 >= state.next_holder_commitment
+    {
+        return Err(Error::InvalidState);
+    }
+    let secret = holder_per_commitment_secret(
+        hal,
+        params,
+        request.channel_index,
+        request.commitment_number,
+    )?;
+    state.revoked_holder_commitments = request.commitment_number + 1;
+    set_channel_state(hal, params, request.channel_index, &state)?;
+    Ok(Response::Revoke(pb::BtcLightningRevokeResponse {
+        per_commitment_secret: secret.to_vec(),
+    }))
+}
+
+fn process_forget_channels(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    request: &pb::BtcLightningForgetChannelsRequest,
+) -> Result<Response, Error> {
+    if request.below_channel_index > HARDENED {
+        return Err(Error::InvalidInput);
+    }
+    hal.memory()
+        .lightning_channels_forget(params.bip44_coin - HARDENED, request.below_channel_index)
+        .map_err(|err| match err {
+            // One of the channels is not closed.
+            crate::hal::memory::Error::InvalidInput => Error::InvalidState,
+            err => err.into(),
+        })?;
+    Ok(Response::ForgetChannels(
+        pb::BtcLightningForgetChannelsResponse {},
+    ))
+}
+
+async fn process_sign_counterparty_commitment(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    request: &pb::BtcLightningSignCounterpartyCommitmentRequest,
+) -> Result<Response, Error> {
+    let tx = request.tx.as_ref().ok_or(Error::InvalidInput)?;
+    let per_commitment_point =
+        PublicKey::from_slice(&request.per_commitment_point).or(Err(Error::InvalidInput))?;
+    let commitment_number = request.commitment_number;
+    let (parameters, mut state) = get_channel(
+        hal,
+        params,
+        request.channel_index,
+        request.parameters.as_ref(),
+    )?;
+
+    let payment_basepoint =
+        channel_public_key(hal, params, request.channel_index, ChannelKey::Payment)?;
+    let keys = CommitmentKeys {
+        // The outputs of the counterparty are revocable with our revocation basepoint.
+        revocation_pubkey: revocation_pubkey(
+            &channel_public_key(hal, params, request.channel_index, ChannelKey::Revocation)?,
+            &per_commitment_point,
+        )?,
+        to_self_delay: parameters.counterparty_to_self_delay,
+        local_delayed_pubkey: derive_public_key(
+            &parameters.counterparty_delayed_payment_basepoint,
+            &per_commitment_point,
+        )?,
+        local_htlc_pubkey: derive_public_key(
+            &parameters.counterparty_htlc_basepoint,
+            &per_commitment_point,
+        )?,
+        remote_htlc_pubkey: derive_public_key(
+            &channel_public_key(hal, params, request.channel_index, ChannelKey::Htlc)?,
+            &per_commitment_point,
+        )?,
+    };
+    // Our output pays to the payment basepoint directly (option_static_remotekey).
+    let to_self_script = p2wpkh_pubkey_script(&payment_basepoint);
+    validate_commitment_tx(
+        tx,
+        commitment_number,
+        obscuring_factor(&payment_basepoint, &parameters),
+        &keys,
+        &to_self_script,
+        &request.htlc_txs,
+    )?;
+    if state.closed {
+        return Err(Error::InvalidState);
+    }
+
+    let mut to_self_sat: u64 = 0;
+    for output in tx.outputs.iter() {
+        if output.pubkey_script == to_self_script {
+            to_self_sat = to_self_sat
+                .checked_add(output.value)
+                .ok_or(Error::InvalidInput)?;
+        }
+    }
+
+    let commitment_hash = signed_commitment_hash(tx, &request.htlc_txs);
+    if commitment_number + 1 == state.next_counterparty_commitment {
+        // Retransmission of the last signed commitment. Signing a different transaction with the
+        // same commitment number would leave the counterparty with two valid commitments.
+        if commitment_hash != state.counterparty_commitment_hash {
+            return Err(Error::InvalidState);
+        }
+    } else if commitment_number == state.next_counterparty_commitment {
+        if to_self_sat < state.to_self_sat {
+            let amount =
+                format_amount(params, FormatUnit::Default, state.to_self_sat - to_self_sat)?;
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title: "Lightning",
+                    body: &format!("Channel balance\ndecreases by\n{}", amount),
+                    longtouch: true,
+                    ..Default::default()
+                })
+                .await?;
+        }
+    } else {
+        return Err(Error::InvalidState);
+    }
+
+    let response = sign_commitment(
+        hal,
+        params,
+        request.channel_index,
+        tx,
+        &request.htlc_txs,
+        &keys,
+        &per_commitment_point,
+    )?;
+    state.next_counterparty_commitment = commitment_number + 1;
+    state.to_self_sat = to_self_sat;
+    state.counterparty_commitment_hash = commitment_hash;
+    set_channel_state(hal, params, request.channel_index, &state)?;
+    Ok(Response::SignCommitment(response))
+}
+
+/// Validates a holder commitment transaction and returns its keys and per-commitment point.
+fn validate_holder_commitment_tx(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    channel_index: u32,
+    parameters: &ChannelParameters,
+    commitment_number: u64,
+    tx: &pb::BtcLightningCommitmentTx,
+    htlc_txs: &[pb::BtcLightningHtlcTx],
+) -> Result<(CommitmentKeys, PublicKey), Error> {
+    let per_commitment_point =
+        holder_per_commitment_point(hal, params, channel_index, commitment_number)?;
+    let keys = CommitmentKeys {
+        // Our outputs are revocable with the revocation basepoint of the counterparty.
+        revocation_pubkey: revocation_pubkey(
+            &parameters.counterparty_revocation_basepoint,
+            &per_commitment_point,
+        )?,
+        to_self_delay: parameters.holder_to_self_delay,
+        local_delayed_pubkey: derive_public_key(
+            &channel_public_key(hal, params, channel_index, ChannelKey::DelayedPayment)?,
+            &per_commitment_point,
+        )?,
+        local_htlc_pubkey: derive_public_key(
+            &channel_public_key(hal, params, channel_index, ChannelKey::Htlc)?,
+            &per_commitment_point,
+        )?,
+        remote_htlc_pubkey: derive_public_key(
+            &parameters.counterparty_htlc_basepoint,
+            &per_commitment_point,
+        )?,
+    };
+    validate_commitment_tx(
+        tx,
+        commitment_number,
+        obscuring_factor(
+            &channel_public_key(hal, params, channel_index, ChannelKey::Payment)?,
+            parameters,
+        ),
+        &keys,
+        &p2wpkh_pubkey_script(&parameters.counterparty_payment_basepoint),
+        htlc_txs,
+    )?;
+    Ok((keys, per_commitment_point))
+}
+
+fn process_validate_holder_commitment(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    request: &pb::BtcLightningValidateHolderCommitmentRequest,
+) -> Result<Response, Error> {
+    let tx = request.tx.as_ref().ok_or(Error::InvalidInput)?;
+    let (parameters, mut state) = get_channel(
+        hal,
+        params,
+        request.channel_index,
+        request.parameters.as_ref(),
+    )?;
+    validate_holder_commitment_tx(
+        hal,
+        params,
+        request.channel_index,
+        &parameters,
+        request.commitment_number,
+        tx,
+        &request.htlc_txs,
+    )?;
+    let funding_pubkey =
+        channel_public_key(hal, params, request.channel_index, ChannelKey::Funding)?;
+    let counterparty_funding_pubkey =
+        PublicKey::from_slice(&tx.counterparty_funding_pubkey).or(Err(Error::InvalidInput))?;
+    let signature =
+        Signature::from_compact(&request.counterparty_signature).or(Err(Error::InvalidInput))?;
+    SECP256K1
+        .verify_ecdsa(
+            &Message::from_digest(funding_sighash(tx, &funding_pubkey)?),
+            &signature,
+            &counterparty_funding_pubkey,
+        )
+        .or(Err(Error::InvalidInput))?;
+    if state.closed {
+        return Err(Error::InvalidState);
+    }
+
+    if request.commitment_number == state.next_holder_commitment {
+        state.next_holder_commitment = request.commitment_number + 1;
+        set_channel_state(hal, params, request.channel_index, &state)?;
+    } else if request.commitment_number + 1 != state.next_holder_commitment {
+        // Only the next commitment can be validated, or the last one again (e.g. when
+        // retransmitting after a reconnect).
+        return Err(Error::InvalidState);
+    }
+    Ok(Response::ValidateHolderCommitment(
+        pb::BtcLightningValidateHolderCommitmentResponse {},
+    ))
+}
+
+async fn process_sign_holder_commitment(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    request: &pb::BtcLightningSignHolderCommitmentRequest,
+) -> Result<Response, Error> {
+    let tx = request.tx.as_ref().ok_or(Error::InvalidInput)?;
+    let (parameters, mut state) = get_channel(
+        hal,
+        params,
+        request.channel_index,
+        request.parameters.as_ref(),
+    )?;
+    let (keys, per_commitment_point) = validate_holder_commitment_tx(
+        hal,
+        params,
+        request.channel_index,
+        &parameters,
+        request.commitment_number,
+        tx,
+        &request.htlc_txs,
+    )?;
+    if request.commitment_number < state.revoked_holder_commitments {
+        // Broadcasting a revoked commitment would let the counterparty claim all funds.
+        return Err(Error::InvalidState);
+    }
+    if request.commitment_number >= state.next_holder_commitment {
+        // Only commitments with a valid counterparty signature can be broadcast.
+        return Err(Error::InvalidState);
+    }
+
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Lightning",
+            body: &format!("Force close\nchannel {}?", request.channel_index),
+            longtouch: true,
+            ..Default::default()
+        })
+        .await?;
+
+    let response = sign_commitment(
+        hal,
+        params,
+        request.channel_index,
+        tx,
+        &request.htlc_txs,
+        &keys,
+        &per_commitment_point,
+    )?;
+    state.closed = true;
+    set_channel_state(hal, params, request.channel_index, &state)?;
+    Ok(Response::SignCommitment(response))
+}
+
+/// Handle a Lightning signer request.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::BtcLightningRequest,
+) -> Result<pb::btc_response::Response, Error> {
+    let coin = BtcCoin::try_from(request.coin)?;
+    if !matches!(coin, BtcCoin::Btc | BtcCoin::Tbtc) {
+        return Err(Error::InvalidInput);
+    }
+    let params = super::params::get(coin);
+    let response = match request.request.as_ref().ok_or(Error::InvalidInput)? {
+        Request::NodeId(_) => Response::NodeId(pb::BtcLightningNodeIdResponse {
+            node_id: secret_key(hal, &node_keypath(params))?
+                .public_key(SECP256K1)
+                .serialize()
+                .to_vec(),
+        }),
+        Request::Ecdh(request) => {
+            let pubkey = PublicKey::from_slice(&request.pubkey).or(Err(Error::InvalidInput))?;
+            let node_key = secret_key(hal, &node_keypath(params))?;
+            let shared_secret = bitcoin::secp256k1::ecdh::SharedSecret::new(&pubkey, &node_key);
+            Response::Ecdh(pb::BtcLightningEcdhResponse {
+                shared_secret: shared_secret.secret_bytes().to_vec(),
+            })
+        }
+        Request::ChannelSetup(request) => process_channel_setup(hal, params, request)?,
+        Request::PerCommitmentPoint(request) => {
+            Response::PerCommitmentPoint(pb::BtcLightningPerCommitmentPointResponse {
+                per_commitment_point: holder_per_commitment_point(
+                    hal,
+                    params,
+                    request.channel_index,
+                    request.commitment_number,
+                )?
+                .serialize()
+                .to_vec(),
+            })
+        }
+        Request::Revoke(request) => process_revoke(hal, params, request)?,
+        Request::SignCounterpartyCommitment(request) => {
+            process_sign_counterparty_commitment(hal, params, request).await?
+        }
+        Request::ValidateHolderCommitment(request) => {
+            process_validate_holder_commitment(hal, params, request)?
+        }
+        Request::SignHolderCommitment(request) => {
+            process_sign_holder_commitment(hal, params, request).await?
+        }
+        Request::ForgetChannels(request) => process_forget_channels(hal, params, request)?,
+    };
+    Ok(pb::btc_response::Response::Lightning(
+        pb::BtcLightningResponse {
+            response: Some(response),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::memory::LightningChannelState;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked;
+    use hex_lit::hex;
+    use util::bb02_async::block_on;
+
+    const FUNDING_TXID: [u8; 32] =
+        hex!("8984484a580b825b9972d7adb15050b3ab624ccd731946b3eeddb92f4e7ef6be");
+    const FUNDING_VALUE: u64 = 100_000;
+    const COUNTERPARTY_FUNDING_SECRET: [u8; 32] = [0x11; 32];
+    const COUNTERPARTY_PER_COMMITMENT_SECRET: [u8; 32] = [0x22; 32];
+    const COUNTERPARTY_PAYMENT_SECRET: [u8; 32] = [0x55; 32];
+    const COUNTERPARTY_REVOCATION_SECRET: [u8; 32] = [0x66; 32];
+    const COUNTERPARTY_DELAYED_PAYMENT_SECRET: [u8; 32] = [0x77; 32];
+    const COUNTERPARTY_HTLC_SECRET: [u8; 32] = [0x88; 32];
+    const HOLDER_TO_SELF_DELAY: u16 = 144;
+    const COUNTERPARTY_TO_SELF_DELAY: u16 = 720;
+    const HTLC_VALUE: u64 = 10_000;
+
+    fn call(hal: &mut TestingHal, request: Request) -> Result<Response, Error> {
+        call_coin(hal, BtcCoin::Btc, request)
+    }
+
+    fn call_coin(hal: &mut TestingHal, coin: BtcCoin, request: Request) -> Result<Response, Error> {
+        let request = pb::BtcLightningRequest {
+            coin: coin as _,
+            request: Some(request),
+        };
+        match block_on(process(hal, &request)) {
+            Ok(pb::btc_response::Response::Lightning(pb::BtcLightningResponse {
+                response: Some(response),
+            })) => Ok(response),
+            Ok(_) => panic!("unexpected response"),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn pubkey(secret: &[u8; 32]) -> PublicKey {
+        SecretKey::from_slice(secret).unwrap().public_key(SECP256K1)
+    }
+
+    fn parameters() -> pb::BtcLightningChannelParameters {
+        pb::BtcLightningChannelParameters {
+            is_outbound: true,
+            counterparty_payment_basepoint: pubkey(&COUNTERPARTY_PAYMENT_SECRET)
+                .serialize()
+                .to_vec(),
+            counterparty_revocation_basepoint: pubkey(&COUNTERPARTY_REVOCATION_SECRET)
+                .serialize()
+                .to_vec(),
+            counterparty_delayed_payment_basepoint: pubkey(&COUNTERPARTY_DELAYED_PAYMENT_SECRET)
+                .serialize()
+                .to_vec(),
+            counterparty_htlc_basepoint: pubkey(&COUNTERPARTY_HTLC_SECRET).serialize().to_vec(),
+            holder_to_self_delay: HOLDER_TO_SELF_DELAY.into(),
+            counterparty_to_self_delay: COUNTERPARTY_TO_SELF_DELAY.into(),
+        }
+    }
+
+    fn expected_parameters_hash() -> [u8; 32] {
+        parameters_hash(&parse_parameters(&parameters()).unwrap())
+    }
+
+    fn channel_setup_coin(
+        hal: &mut TestingHal,
+        coin: BtcCoin,
+    ) -> pb::BtcLightningChannelSetupResponse {
+        match call_coin(
+            hal,
+            coin,
+            Request::ChannelSetup(pb::BtcLightningChannelSetupRequest {
+                channel_index: 0,
+                parameters: Some(parameters()),
+            }),
+        ) {
+            Ok(Response::ChannelSetup(response)) => response,
+            _ => panic!("unexpected response"),
+        }
+    }
+
+    fn channel_setup(hal: &mut TestingHal) -> pb::BtcLightningChannelSetupResponse {
+        channel_setup_coin(hal, BtcCoin::Btc)
+    }
+
+    fn counterparty_per_commitment_point() -> PublicKey {
+        pubkey(&COUNTERPARTY_PER_COMMITMENT_SECRET)
+    }
+
+    /// The keys of a commitment transaction of the counterparty.
+    fn counterparty_commitment_keys(
+        setup: &pb::BtcLightningChannelSetupResponse,
+        per_commitment_point: &PublicKey,
+    ) -> CommitmentKeys {
+        CommitmentKeys {
+            revocation_pubkey: revocation_pubkey(
+                &PublicKey::from_slice(&setup.revocation_basepoint).unwrap(),
+                per_commitment_point,
+            )
+            .unwrap(),
+            to_self_delay: COUNTERPARTY_TO_SELF_DELAY,
+            local_delayed_pubkey: derive_public_key(
+                &pubkey(&COUNTERPARTY_DELAYED_PAYMENT_SECRET),
+                per_commitment_point,
+            )
+            .unwrap(),
+            local_htlc_pubkey: derive_public_key(
+                &pubkey(&COUNTERPARTY_HTLC_SECRET),
+                per_commitment_point,
+            )
+            .unwrap(),
+            remote_htlc_pubkey: derive_public_key(
+                &PublicKey::from_slice(&setup.htlc_basepoint).unwrap(),
+                per_commitment_point,
+            )
+            .unwrap(),
+        }
+    }
+
+    /// A commitment transaction encoding the given commitment number.
+    fn commitment_tx(
+        setup: &pb::BtcLightningChannelSetupResponse,
+        commitment_number: u64,
+        outputs: Vec<pb::BtcLightningOutput>,
+    ) -> pb::BtcLightningCommitmentTx {
+        let obscured_commitment_number = commitment_number
+            ^ obscuring_factor(
+                &PublicKey::from_slice(&setup.payment_basepoint).unwrap(),
+                &parse_parameters(&parameters()).unwrap(),
+            );
+        pb::BtcLightningCommitmentTx {
+            version: 2,
+            locktime: 0x20000000 | (obscured_commitment_number & 0xffffff) as u32,
+            funding_txid: FUNDING_TXID.to_vec(),
+            funding_output_index: 1,
+            funding_value: FUNDING_VALUE,
+            sequence: 0x80000000 | (obscured_commitment_number >> 24) as u32,
+            outputs,
+            counterparty_funding_pubkey: pubkey(&COUNTERPARTY_FUNDING_SECRET).serialize().to_vec(),
+        }
+    }
+
+    /// A commitment transaction of the counterparty with our output and the counterparty's
+    /// output.
+    fn counterparty_commitment_tx(
+        setup: &pb::BtcLightningChannelSetupResponse,
+        commitment_number: u64,
+        to_self_sat: u64,
+        per_commitment_point: &PublicKey,
+    ) -> pb::BtcLightningCommitmentTx {
+        let to_local_script =
+            counterparty_commitment_keys(setup, per_commitment_point).to_local_script();
+        commitment_tx(
+            setup,
+            commitment_number,
+            vec![
+                pb::BtcLightningOutput {
+                    value: to_self_sat,
+                    pubkey_script: p2wpkh_pubkey_script(
+                        &PublicKey::from_slice(&setup.payment_basepoint).unwrap(),
+                    ),
+                },
+                pb::BtcLightningOutput {
+                    value: FUNDING_VALUE - HTLC_VALUE - to_self_sat - 1000,
+                    pubkey_script: p2wsh_pubkey_script(&to_local_script),
+                },
+            ],
+        )
+    }
+
+    /// A holder commitment transaction with our output and the counterparty's output.
+    fn holder_commitment_tx(
+        setup: &pb::BtcLightningChannelSetupResponse,
+        commitment_number: u64,
+        to_self_sat: u64,
+        per_commitment_point: &PublicKey,
+    ) -> pb::BtcLightningCommitmentTx {
+        let to_local_script = to_local_script(
+            &revocation_pubkey(
+                &pubkey(&COUNTERPARTY_REVOCATION_SECRET),
+                per_commitment_point,
+            )
+            .unwrap(),
+            HOLDER_TO_SELF_DELAY,
+            &derive_public_key(
+                &PublicKey::from_slice(&setup.delayed_payment_basepoint).unwrap(),
+                per_commitment_point,
+            )
+            .unwrap(),
+        );
+        commitment_tx(
+            setup,
+            commitment_number,
+            vec![
+                pb::BtcLightningOutput {
+                    value: to_self_sat,
+                    pubkey_script: p2wsh_pubkey_script(&to_local_script),
+                },
+                pb::BtcLightningOutput {
+                    value: FUNDING_VALUE - HTLC_VALUE - to_self_sat - 1000,
+                    pubkey_script: p2wpkh_pubkey_script(&pubkey(&COUNTERPARTY_PAYMENT_SECRET)),
+                },
+            ],
+        )
+    }
+
+    /// Adds the output of the HTLC spent by `htlc_tx` to a commitment transaction of the
+    /// counterparty as the third output.
+    fn with_htlc_output(
+        mut tx: pb::BtcLightningCommitmentTx,
+        setup: &pb::BtcLightningChannelSetupResponse,
+        per_commitment_point: &PublicKey,
+        htlc_tx: &pb::BtcLightningHtlcTx,
+    ) -> pb::BtcLightningCommitmentTx {
+        let keys = counterparty_commitment_keys(setup, per_commitment_point);
+        tx.outputs.push(pb::BtcLightningOutput {
+            value: HTLC_VALUE,
+            pubkey_script: p2wsh_pubkey_script(&keys.htlc_script(htlc_tx).unwrap()),
+        });
+        tx
+    }
+
+    /// An HTLC-timeout transaction of an HTLC offered by the counterparty.
+    fn htlc_tx() -> pb::BtcLightningHtlcTx {
+        pb::BtcLightningHtlcTx {
+            commitment_output_index: 2,
+            offered: true,
+            payment_hash: [0x99; 32].to_vec(),
+            cltv_expiry: 800_000,
+            output_value: HTLC_VALUE - 500,
+        }
+    }
+
+    fn counterparty_commitment_request(
+        commitment_number: u64,
+        tx: &pb::BtcLightningCommitmentTx,
+        htlc_txs: &[pb::BtcLightningHtlcTx],
+    ) -> pb::BtcLightningSignCounterpartyCommitmentRequest {
+        pb::BtcLightningSignCounterpartyCommitmentRequest {
+            channel_index: 0,
+            commitment_number,
+            per_commitment_point: counterparty_per_commitment_point().serialize().to_vec(),
+            tx: Some(tx.clone()),
+            htlc_txs: htlc_txs.to_vec(),
+            parameters: Some(parameters()),
+        }
+    }
+
+    fn call_sign(
+        hal: &mut TestingHal,
+        request: Request,
+    ) -> Result<pb::BtcLightningSignCommitmentResponse, Error> {
+        match call(hal, request)? {
+            Response::SignCommitment(response) => Ok(response),
+            _ => panic!("unexpected response"),
+        }
+    }
+
+    fn sign_counterparty_commitment(
+        hal: &mut TestingHal,
+        commitment_number: u64,
+        tx: &pb::BtcLightningCommitmentTx,
+        htlc_txs: &[pb::BtcLightningHtlcTx],
+    ) -> Result<pb::BtcLightningSignCommitmentResponse, Error> {
+        call_sign(
+            hal,
+            Request::SignCounterpartyCommitment(counterparty_commitment_request(
+                commitment_number,
+                tx,
+                htlc_txs,
+            )),
+        )
+    }
+
+    fn sign_holder_commitment(
+        hal: &mut TestingHal,
+        commitment_number: u64,
+        tx: &pb::BtcLightningCommitmentTx,
+    ) -> Result<pb::BtcLightningSignCommitmentResponse, Error> {
+        call_sign(
+            hal,
+            Request::SignHolderCommitment(pb::BtcLightningSignHolderCommitmentRequest {
+                channel_index: 0,
+                commitment_number,
+                tx: Some(tx.clone()),
+                htlc_txs: vec![],
+                parameters: Some(parameters()),
+            }),
+        )
+    }
+
+    fn validate_holder_commitment_request(
+        setup: &pb::BtcLightningChannelSetupResponse,
+        commitment_number: u64,
+        tx: &pb::BtcLightningCommitmentTx,
+    ) -> pb::BtcLightningValidateHolderCommitmentRequest {
+        let sighash =
+            funding_sighash(tx, &PublicKey::from_slice(&setup.funding_pubkey).unwrap()).unwrap();
+        let signature = SECP256K1.sign_ecdsa(
+            &Message::from_digest(sighash),
+            &SecretKey::from_slice(&COUNTERPARTY_FUNDING_SECRET).unwrap(),
+        );
+        pb::BtcLightningValidateHolderCommitmentRequest {
+            channel_index: 0,
+            commitment_number,
+            tx: Some(tx.clone()),
+            htlc_txs: vec![],
+            parameters: Some(parameters()),
+            counterparty_signature: signature.serialize_compact().to_vec(),
+        }
+    }
+
+    fn validate_holder_commitment(
+        hal: &mut TestingHal,
+        setup: &pb::BtcLightningChannelSetupResponse,
+        commitment_number: u64,
+        tx: &pb::BtcLightningCommitmentTx,
+    ) -> Result<(), Error> {
+        match call(
+            hal,
+            Request::ValidateHolderCommitment(validate_holder_commitment_request(
+                setup,
+                commitment_number,
+                tx,
+            )),
+        )? {
+            Response::ValidateHolderCommitment(_) => Ok(()),
+            _ => panic!("unexpected response"),
+        }
+    }
+
+    fn revoke(hal: &mut TestingHal, commitment_number: u64) -> Result<Vec<u8>, Error> {
+        match call(
+            hal,
+            Request::Revoke(pb::BtcLightningRevokeRequest {
+                channel_index: 0,
+                commitment_number,
+            }),
+        )? {
+            Response::Revoke(response) => Ok(response.per_commitment_secret),
+            _ => panic!("unexpected response"),
+        }
+    }
+
+    fn to_bitcoin_output(output: &pb::BtcLightningOutput) -> bitcoin::TxOut {
+        bitcoin::TxOut {
+            value: bitcoin::Amount::from_sat(output.value),
+            script_pubkey: bitcoin::ScriptBuf::from_bytes(output.pubkey_script.clone()),
+        }
+    }
+
+    fn verify_signature(sighash: &[u8; 32], signature: &[u8], pubkey: &PublicKey) {
+        SECP256K1
+            .verify_ecdsa(
+                &Message::from_digest(*sighash),
+                &Signature::from_compact(signature).unwrap(),
+                pubkey,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_per_commitment_secret() {
+        // Test vectors from
+        // https://github.com/lightning/bolts/blob/master/03-transactions.md#generation-tests. The
+        // index I of the test vectors corresponds to the commitment number 2^48-1-I.
+        assert_eq!(
+            *per_commitment_secret(&[0; 32], 0).unwrap(),
+            hex!("02a40c85b6f28da08dfdbe0926c53fab2de6d28c10301f8f7c4073d5e42e3148"),
+        );
+        assert_eq!(
+            *per_commitment_secret(&[0xff; 32], 0).unwrap(),
+            hex!("7cc854b54e3e0dcdb010d7a3fee464a9687be6e8db3be6854c475621e007a5dc"),
+        );
+        assert_eq!(
+            *per_commitment_secret(&[0xff; 32], MAX_COMMITMENT_NUMBER - 0xaaaaaaaaaaa).unwrap(),
+            hex!("56f4008fb007ca9acf0e15b054d5c9fd12ee06cea347914ddbaed70d1c13a528"),
+        );
+        assert_eq!(
+            *per_commitment_secret(&[0xff; 32], MAX_COMMITMENT_NUMBER - 0x555555555555).unwrap(),
+            hex!("9015daaeb06dba4ccc05b91b2f73bd54405f2be9f217fbacd3c5ac2e62327d31"),
+        );
+        assert_eq!(
+            *per_commitment_secret(&[0x01; 32], MAX_COMMITMENT_NUMBER - 1).unwrap(),
+            hex!("915c75942a26bb3a433a8ce2cb0427c29ec6c1775cfc78328b57f6ba7bfeaa9c"),
+        );
+        // The last commitment number has index 0, i.e. the secret is the seed.
+        assert_eq!(
+            *per_commitment_secret(&[0x01; 32], MAX_COMMITMENT_NUMBER).unwrap(),
+            [0x01; 32],
+        );
+        assert!(per_commitment_secret(&[0x01; 32], MAX_COMMITMENT_NUMBER + 1).is_err());
+    }
+
+    #[test]
+    fn test_derive_keys() {
+        // Test vectors from
+        // https://github.com/lightning/bolts/blob/master/03-transactions.md#appendix-e-key-derivation-test-vectors
+        let base_secret = SecretKey::from_slice(&hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        ))
+        .unwrap();
+        let base_point = PublicKey::from_slice(&hex!(
+            "036d6caac248af96f6afa7f904f550253a0f3ef3f5aa2fe6838a95b216691468e2"
+        ))
+        .unwrap();
+        assert_eq!(base_secret.public_key(SECP256K1), base_point);
+        let per_commitment_point = PublicKey::from_slice(&hex!(
+            "025f7117a78150fe2ef97db7cfc83bd57b2e2c0d0dd25eaf467a4a1c2a45ce1486"
+        ))
+        .unwrap();
+        let private_key = derive_private_key(&base_secret, &per_commitment_point).unwrap();
+        assert_eq!(
+            private_key.secret_bytes(),
+            hex!("cbced912d3b21bf196a766651e436aff192362621ce317704ea2f75d87e7be0f"),
+        );
+        assert_eq!(
+            private_key.public_key(SECP256K1).serialize(),
+            hex!("0235f2dbfaa89b57ec7b055afe29849ef7ddfeb1cefdb9ebdc43f5494984db29e5"),
+        );
+        assert_eq!(
+            derive_public_key(&base_point, &per_commitment_point).unwrap(),
+            private_key.public_key(SECP256K1),
+        );
+        assert_eq!(
+            revocation_pubkey(&base_point, &per_commitment_point)
+                .unwrap()
+                .serialize(),
+            hex!("02916e326636d19c33f13e8c0c3a03dd157f332f3e99c317c141dd865eb01f8ff0"),
+        );
+    }
+
+    #[test]
+    fn test_commitment_encoding() {
+        // Test vectors from
+        // https://github.com/lightning/bolts/blob/master/03-transactions.md#appendix-c-commitment-and-htlc-transaction-test-vectors
+        let local_payment_basepoint = PublicKey::from_slice(&hex!(
+            "034f355bdcb7cc0af728ef3cceb9615d90684bb5b2ca5f859ab0f0b704075871aa"
+        ))
+        .unwrap();
+        let remote_payment_basepoint = PublicKey::from_slice(&hex!(
+            "032c0b7cf95324a07d05398b240174dc0c2be444d96b159aa6c7f7b1e668680991"
+        ))
+        .unwrap();
+        let mut parameters = ChannelParameters {
+            is_outbound: true,
+            counterparty_payment_basepoint: remote_payment_basepoint,
+            counterparty_revocation_basepoint: remote_payment_basepoint,
+            counterparty_delayed_payment_basepoint: remote_payment_basepoint,
+            counterparty_htlc_basepoint: remote_payment_basepoint,
+            holder_to_self_delay: 144,
+            counterparty_to_self_delay: 144,
+        };
+        // The local node opened the channel.
+        assert_eq!(
+            obscuring_factor(&local_payment_basepoint, &parameters),
+            0x2bb038521914,
+        );
+        parameters.is_outbound = false;
+        parameters.counterparty_payment_basepoint = local_payment_basepoint;
+        assert_eq!(
+            obscuring_factor(&remote_payment_basepoint, &parameters),
+            0x2bb038521914,
+        );
+
+        assert_eq!(
+            to_local_script(
+                &PublicKey::from_slice(&hex!(
+                    "0212a140cd0c6539d07cd08dfe09984dec3251ea808b892efeac3ede9402bf2b19"
+                ))
+                .unwrap(),
+                144,
+                &PublicKey::from_slice(&hex!(
+                    "03fd5960528dc152014952efdb702a88f71e3c1653b2314431701ec77e57fde83c"
+                ))
+                .unwrap(),
+            ),
+            hex!(
+                "63210212a140cd0c6539d07cd08dfe09984dec3251ea808b892efeac3ede9402bf2b1967029000b2752103fd5960528dc152014952efdb702a88f71e3c1653b2314431701ec77e57fde83c68ac"
+            ),
+        );
+
+        let keys = CommitmentKeys {
+            revocation_pubkey: PublicKey::from_slice(&hex!(
+                "0212a140cd0c6539d07cd08dfe09984dec3251ea808b892efeac3ede9402bf2b19"
+            ))
+            .unwrap(),
+            to_self_delay: 144,
+            local_delayed_pubkey: PublicKey::from_slice(&hex!(
+                "03fd5960528dc152014952efdb702a88f71e3c1653b2314431701ec77e57fde83c"
+            ))
+            .unwrap(),
+            local_htlc_pubkey: PublicKey::from_slice(&hex!(
+                "030d417a46946384f88d5f3337267c5e579765875dc4daca813e21734b140639e7"
+            ))
+            .unwrap(),
+            remote_htlc_pubkey: PublicKey::from_slice(&hex!(
+                "0394854aa6eab5b2a8122cc726e9dded053a2184d88256816826d6231c068d4a5b"
+            ))
+            .unwrap(),
+        };
+        // HTLC #0, received.
+        assert_eq!(
+            htlc_script(&keys, false, &Sha256::digest([0x00; 32]).into(), 500),
+            hex!(
+                "76a91414011f7254d96b819c76986c277d115efce6f7b58763ac67210394854aa6eab5b2a8122cc726e9dded053a2184d88256816826d6231c068d4a5b7c8201208763a914b8bcb07f6344b42ab04250c86a6e8b75d3fdbbc688527c21030d417a46946384f88d5f3337267c5e579765875dc4daca813e21734b140639e752ae677502f401b175ac6868"
+            ),
+        );
+        // HTLC #2, offered.
+        assert_eq!(
+            htlc_script(&keys, true, &Sha256::digest([0x02; 32]).into(), 502),
+            hex!(
+                "76a91414011f7254d96b819c76986c277d115efce6f7b58763ac67210394854aa6eab5b2a8122cc726e9dded053a2184d88256816826d6231c068d4a5b7c820120876475527c21030d417a46946384f88d5f3337267c5e579765875dc4daca813e21734b140639e752ae67a914b43e1b38138a41b37f7cd9a1d274bc63e3a9b5d188ac6868"
+            ),
+        );
+    }
+
+    #[test]
+    fn test_node_id_ecdh() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let node_id = match call(
+            &mut mock_hal,
+            Request::NodeId(pb::BtcLightningNodeIdRequest {}),
+        ) {
+            Ok(Response::NodeId(response)) => PublicKey::from_slice(&response.node_id).unwrap(),
+            _ => panic!("unexpected response"),
+        };
+
+        let peer_secret = SecretKey::from_slice(&[0x33; 32]).unwrap();
+        let shared_secret = match call(
+            &mut mock_hal,
+            Request::Ecdh(pb::BtcLightningEcdhRequest {
+                pubkey: peer_secret.public_key(SECP256K1).serialize().to_vec(),
+            }),
+        ) {
+            Ok(Response::Ecdh(response)) => response.shared_secret,
+            _ => panic!("unexpected response"),
+        };
+        assert_eq!(
+            shared_secret,
+            bitcoin::secp256k1::ecdh::SharedSecret::new(&node_id, &peer_secret)
+                .secret_bytes()
+                .to_vec(),
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+
+        assert_eq!(
+            call(
+                &mut mock_hal,
+                Request::Ecdh(pb::BtcLightningEcdhRequest {
+                    pubkey: vec![0; 33]
+                }),
+            ),
+            Err(Error::InvalidInput),
+        );
+    }
+
+    #[test]
+    fn test_channel_setup() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let setup = |mock_hal: &mut TestingHal, parameters| {
+            call(
+                mock_hal,
+                Request::ChannelSetup(pb::BtcLightningChannelSetupRequest {
+                    channel_index: 0,
+                    parameters,
+                }),
+            )
+        };
+
+        // Commitments of a channel without parameters are not signed.
+        let basepoints = match setup(&mut mock_hal, None) {
+            Ok(Response::ChannelSetup(response)) => response,
+            _ => panic!("unexpected response"),
+        };
+        assert!(mock_hal.memory.lightning_channel_get(0, 0).is_none());
+        let point = counterparty_per_commitment_point();
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                0,
+                &counterparty_commitment_tx(&basepoints, 0, 40_000, &point),
+                &[],
+            ),
+            Err(Error::InvalidState),
+        );
+        assert_eq!(revoke(&mut mock_hal, 0), Err(Error::InvalidState));
+
+        // Invalid parameters.
+        let invalid_parameters = [
+            pb::BtcLightningChannelParameters {
+                counterparty_payment_basepoint: vec![],
+                ..parameters()
+            },
+            pb::BtcLightningChannelParameters {
+                counterparty_revocation_basepoint: vec![0; 33],
+                ..parameters()
+            },
+            pb::BtcLightningChannelParameters {
+                holder_to_self_delay: 0,
+                ..parameters()
+            },
+            pb::BtcLightningChannelParameters {
+                counterparty_to_self_delay: 0x10000,
+                ..parameters()
+            },
+        ];
+        for parameters in invalid_parameters {
+            assert_eq!(
+                setup(&mut mock_hal, Some(parameters)),
+                Err(Error::InvalidInput),
+            );
+        }
+        assert!(mock_hal.memory.lightning_channel_get(0, 0).is_none());
+
+        // The parameters are stored once and can't be changed.
+        assert_eq!(channel_setup(&mut mock_hal), basepoints);
+        assert_eq!(
+            mock_hal.memory.lightning_channel_get(0, 0),
+            Some(LightningChannelState {
+                parameters_hash: expected_parameters_hash(),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(channel_setup(&mut mock_hal), basepoints);
+        assert_eq!(
+            setup(
+                &mut mock_hal,
+                Some(pb::BtcLightningChannelParameters {
+                    is_outbound: false,
+                    ..parameters()
+                })
+            ),
+            Err(Error::InvalidState),
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+    }
+
+    #[test]
+    fn test_sign_counterparty_commitment() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let setup = channel_setup(&mut mock_hal);
+        let per_commitment_point = counterparty_per_commitment_point();
+        let htlc_tx = htlc_tx();
+        let tx = with_htlc_output(
+            counterparty_commitment_tx(&setup, 0, 40_000, &per_commitment_point),
+            &setup,
+            &per_commitment_point,
+            &htlc_tx,
+        );
+        let response =
+            sign_counterparty_commitment(&mut mock_hal, 0, &tx, core::slice::from_ref(&htlc_tx))
+                .unwrap();
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Verify the signatures against the sighashes computed by rust-bitcoin.
+        let funding_pubkey = PublicKey::from_slice(&setup.funding_pubkey).unwrap();
+        let bitcoin_tx = bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::from_consensus(tx.locktime),
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint {
+                    txid: bitcoin::Txid::from_byte_array(FUNDING_TXID),
+                    vout: tx.funding_output_index,
+                },
+                script_sig: bitcoin::ScriptBuf::new(),
+                sequence: bitcoin::Sequence(tx.sequence),
+                witness: bitcoin::Witness::new(),
+            }],
+            output: tx.outputs.iter().map(to_bitcoin_output).collect(),
+        };
+        let sighash = bitcoin::sighash::SighashCache::new(&bitcoin_tx)
+            .p2wsh_signature_hash(
+                0,
+                bitcoin::Script::from_bytes(&funding_script(
+                    &funding_pubkey,
+                    &PublicKey::from_slice(&tx.counterparty_funding_pubkey).unwrap(),
+                )),
+                bitcoin::Amount::from_sat(FUNDING_VALUE),
+                bitcoin::EcdsaSighashType::All,
+            )
+            .unwrap();
+        verify_signature(
+            &sighash.to_byte_array(),
+            &response.signature,
+            &funding_pubkey,
+        );
+
+        // The HTLC-timeout transaction of the counterparty pays to its to_local script once the
+        // HTLC has timed out.
+        let keys = counterparty_commitment_keys(&setup, &per_commitment_point);
+        let bitcoin_htlc_tx = bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::from_consensus(htlc_tx.cltv_expiry),
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint {
+                    txid: bitcoin_tx.compute_txid(),
+                    vout: htlc_tx.commitment_output_index,
+                },
+                script_sig: bitcoin::ScriptBuf::new(),
+                sequence: bitcoin::Sequence(0),
+                witness: bitcoin::Witness::new(),
+            }],
+            output: vec![bitcoin::TxOut {
+                value: bitcoin::Amount::from_sat(htlc_tx.output_value),
+                script_pubkey: bitcoin::ScriptBuf::from_bytes(p2wsh_pubkey_script(
+                    &keys.to_local_script(),
+                )),
+            }],
+        };
+        let sighash = bitcoin::sighash::SighashCache::new(&bitcoin_htlc_tx)
+            .p2wsh_signature_hash(
+                0,
+                bitcoin::Script::from_bytes(&keys.htlc_script(&htlc_tx).unwrap()),
+                bitcoin::Amount::from_sat(HTLC_VALUE),
+                bitcoin::EcdsaSighashType::All,
+            )
+            .unwrap();
+        assert_eq!(response.htlc_signatures.len(), 1);
+        verify_signature(
+            &sighash.to_byte_array(),
+            &response.htlc_signatures[0],
+            &keys.remote_htlc_pubkey,
+        );
+
+        assert_eq!(
+            mock_hal.memory.lightning_channel_get(0, 0),
+            Some(LightningChannelState {
+                parameters_hash: expected_parameters_hash(),
+                next_counterparty_commitment: 1,
+                next_holder_commitment: 0,
+                revoked_holder_commitments: 0,
+                to_self_sat: 40_000,
+                counterparty_commitment_hash: signed_commitment_hash(
+                    &tx,
+                    core::slice::from_ref(&htlc_tx)
+                ),
+                closed: false,
+            }),
+        );
+
+        let tx = with_htlc_output(
+            counterparty_commitment_tx(&setup, 1, 40_000, &per_commitment_point),
+            &setup,
+            &per_commitment_point,
+            &htlc_tx,
+        );
+        // The HTLC transaction must spend the HTLC output it describes.
+        let mut invalid_htlc_tx = htlc_tx.clone();
+        invalid_htlc_tx.commitment_output_index = 1;
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &tx, &[invalid_htlc_tx]),
+            Err(Error::InvalidInput),
+        );
+        let mut invalid_htlc_tx = htlc_tx.clone();
+        invalid_htlc_tx.commitment_output_index = 3;
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &tx,
+                &[htlc_tx.clone(), invalid_htlc_tx]
+            ),
+            Err(Error::InvalidInput),
+        );
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &tx,
+                &[htlc_tx.clone(), htlc_tx.clone()]
+            ),
+            Err(Error::InvalidInput),
+        );
+        let mut invalid_htlc_tx = htlc_tx.clone();
+        invalid_htlc_tx.offered = false;
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &tx, &[invalid_htlc_tx]),
+            Err(Error::InvalidInput),
+        );
+        let mut invalid_htlc_tx = htlc_tx.clone();
+        invalid_htlc_tx.payment_hash = [0x98; 32].to_vec();
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &tx, &[invalid_htlc_tx]),
+            Err(Error::InvalidInput),
+        );
+        // The expiry is part of the script of received HTLCs.
+        let received_htlc_tx = pb::BtcLightningHtlcTx {
+            offered: false,
+            ..htlc_tx.clone()
+        };
+        let received_tx = with_htlc_output(
+            counterparty_commitment_tx(&setup, 1, 40_000, &per_commitment_point),
+            &setup,
+            &per_commitment_point,
+            &received_htlc_tx,
+        );
+        let mut invalid_htlc_tx = received_htlc_tx.clone();
+        invalid_htlc_tx.cltv_expiry = 800_001;
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &received_tx,
+                &[invalid_htlc_tx.clone()]
+            ),
+            Err(Error::InvalidInput),
+        );
+        invalid_htlc_tx.cltv_expiry = LOCKTIME_THRESHOLD;
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &received_tx, &[invalid_htlc_tx]),
+            Err(Error::InvalidInput),
+        );
+        // The HTLC transaction can't pay more than the HTLC amount, nor pay a higher fee rate than
+        // the commitment transaction.
+        let mut invalid_htlc_tx = htlc_tx.clone();
+        invalid_htlc_tx.output_value = HTLC_VALUE + 1;
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &tx, &[invalid_htlc_tx]),
+            Err(Error::InvalidInput),
+        );
+        let mut invalid_htlc_tx = htlc_tx.clone();
+        invalid_htlc_tx.output_value = HTLC_VALUE - 800;
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &tx, &[invalid_htlc_tx]),
+            Err(Error::InvalidInput),
+        );
+        // Every HTLC output must be spent by an HTLC transaction.
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &tx, &[]),
+            Err(Error::InvalidInput),
+        );
+        // Outputs other than to_local, to_remote and HTLC outputs are rejected.
+        let mut invalid_tx = tx.clone();
+        invalid_tx.outputs.push(pb::BtcLightningOutput {
+            value: 1000,
+            pubkey_script: p2wsh_pubkey_script(&[0x51]),
+        });
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &invalid_tx,
+                core::slice::from_ref(&htlc_tx)
+            ),
+            Err(Error::InvalidInput),
+        );
+        // Outputs built for a different per-commitment point.
+        let other_point = pubkey(&[0x44; 32]);
+        let other_tx = with_htlc_output(
+            counterparty_commitment_tx(&setup, 1, 40_000, &other_point),
+            &setup,
+            &other_point,
+            &htlc_tx,
+        );
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &other_tx,
+                core::slice::from_ref(&htlc_tx)
+            ),
+            Err(Error::InvalidInput),
+        );
+        let other_tx = with_htlc_output(
+            counterparty_commitment_tx(&setup, 1, 40_000, &other_point),
+            &setup,
+            &per_commitment_point,
+            &htlc_tx,
+        );
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &other_tx,
+                core::slice::from_ref(&htlc_tx)
+            ),
+            Err(Error::InvalidInput),
+        );
+        // The transaction must encode the commitment number.
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 2, &tx, core::slice::from_ref(&htlc_tx)),
+            Err(Error::InvalidInput),
+        );
+
+        // Invalid commitment transactions.
+        let mut invalid_tx = tx.clone();
+        invalid_tx.version = 1;
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &invalid_tx,
+                core::slice::from_ref(&htlc_tx)
+            ),
+            Err(Error::InvalidInput),
+        );
+        let mut invalid_tx = tx.clone();
+        invalid_tx.locktime = 1;
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &invalid_tx,
+                core::slice::from_ref(&htlc_tx)
+            ),
+            Err(Error::InvalidInput),
+        );
+        let mut invalid_tx = tx.clone();
+        invalid_tx.counterparty_funding_pubkey = vec![];
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                1,
+                &invalid_tx,
+                core::slice::from_ref(&htlc_tx)
+            ),
+            Err(Error::InvalidInput),
+        );
+        // The parameters must be the ones stored at channel setup.
+        assert_eq!(
+            call_sign(
+                &mut mock_hal,
+                Request::SignCounterpartyCommitment(
+                    pb::BtcLightningSignCounterpartyCommitmentRequest {
+                        parameters: Some(pb::BtcLightningChannelParameters {
+                            counterparty_to_self_delay: 721,
+                            ..parameters()
+                        }),
+                        ..counterparty_commitment_request(1, &tx, core::slice::from_ref(&htlc_tx))
+                    }
+                ),
+            ),
+            Err(Error::InvalidInput),
+        );
+        assert_eq!(
+            call_sign(
+                &mut mock_hal,
+                Request::SignCounterpartyCommitment(
+                    pb::BtcLightningSignCounterpartyCommitmentRequest {
+                        parameters: None,
+                        ..counterparty_commitment_request(1, &tx, core::slice::from_ref(&htlc_tx))
+                    }
+                ),
+            ),
+            Err(Error::InvalidInput),
+        );
+        // None of the invalid requests updated the state.
+        assert_eq!(
+            mock_hal
+                .memory
+                .lightning_channel_get(0, 0)
+                .unwrap()
+                .next_counterparty_commitment,
+            1,
+        );
+        sign_counterparty_commitment(&mut mock_hal, 1, &tx, core::slice::from_ref(&htlc_tx))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_counterparty_commitment_policy() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let setup = channel_setup(&mut mock_hal);
+        let point = counterparty_per_commitment_point();
+        let tx = |commitment_number, to_self_sat| {
+            counterparty_commitment_tx(&setup, commitment_number, to_self_sat, &point)
+        };
+
+        sign_counterparty_commitment(&mut mock_hal, 0, &tx(0, 40_000), &[]).unwrap();
+        // Our balance increases.
+        sign_counterparty_commitment(&mut mock_hal, 1, &tx(1, 50_000), &[]).unwrap();
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Retransmission of the last commitment, only if it is exactly the same.
+        sign_counterparty_commitment(&mut mock_hal, 1, &tx(1, 50_000), &[]).unwrap();
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &tx(1, 30_000), &[]),
+            Err(Error::InvalidState),
+        );
+        // Same balance, but a different fee.
+        let mut other_tx = tx(1, 50_000);
+        other_tx.outputs[1].value -= 1;
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 1, &other_tx, &[]),
+            Err(Error::InvalidState),
+        );
+        // Older and skipped commitment numbers are rejected.
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 0, &tx(0, 50_000), &[]),
+            Err(Error::InvalidState),
+        );
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 3, &tx(3, 50_000), &[]),
+            Err(Error::InvalidState),
+        );
+
+        // A decreasing balance has to be confirmed.
+        mock_hal.ui.abort_nth(0);
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 2, &tx(2, 30_000), &[]),
+            Err(Error::UserAbort),
+        );
+        assert_eq!(
+            mock_hal
+                .memory
+                .lightning_channel_get(0, 0)
+                .unwrap()
+                .to_self_sat,
+            50_000
+        );
+        let mut mock_hal = TestingHal {
+            memory: mock_hal.memory,
+            ..TestingHal::new()
+        };
+        sign_counterparty_commitment(&mut mock_hal, 2, &tx(2, 30_000), &[]).unwrap();
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![Screen::Confirm {
+                title: "Lightning".into(),
+                body: "Channel balance\ndecreases by\n0.00020000 BTC".into(),
+                longtouch: true,
+            }],
+        );
+        assert_eq!(
+            mock_hal.memory.lightning_channel_get(0, 0),
+            Some(LightningChannelState {
+                parameters_hash: expected_parameters_hash(),
+                next_counterparty_commitment: 3,
+                next_holder_commitment: 0,
+                revoked_holder_commitments: 0,
+                to_self_sat: 30_000,
+                counterparty_commitment_hash: signed_commitment_hash(&tx(2, 30_000), &[]),
+                closed: false,
+            }),
+        );
+    }
+
+    #[test]
+    fn test_channel_state_per_coin() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let setup = channel_setup(&mut mock_hal);
+        let point = counterparty_per_commitment_point();
+        let tx = counterparty_commitment_tx(&setup, 0, 40_000, &point);
+        sign_counterparty_commitment(&mut mock_hal, 0, &tx, &[]).unwrap();
+        let tx = counterparty_commitment_tx(&setup, 1, 40_000, &point);
+        sign_counterparty_commitment(&mut mock_hal, 1, &tx, &[]).unwrap();
+
+        // The testnet channel with the same index has its own state.
+        let testnet_setup = channel_setup_coin(&mut mock_hal, BtcCoin::Tbtc);
+        assert_ne!(testnet_setup, setup);
+        let testnet_tx = counterparty_commitment_tx(&testnet_setup, 0, 40_000, &point);
+        assert!(
+            call_coin(
+                &mut mock_hal,
+                BtcCoin::Tbtc,
+                Request::SignCounterpartyCommitment(counterparty_commitment_request(
+                    0,
+                    &testnet_tx,
+                    &[]
+                )),
+            )
+            .is_ok()
+        );
+        assert_eq!(
+            mock_hal
+                .memory
+                .lightning_channel_get(1, 0)
+                .unwrap()
+                .next_counterparty_commitment,
+            1,
+        );
+        assert_eq!(
+            mock_hal
+                .memory
+                .lightning_channel_get(0, 0)
+                .unwrap()
+                .next_counterparty_commitment,
+            2,
+        );
+        let tx = counterparty_commitment_tx(&setup, 0, 40_000, &point);
+        assert_eq!(
+            sign_counterparty_commitment(&mut mock_hal, 0, &tx, &[]),
+            Err(Error::InvalidState),
+        );
+    }
+
+    #[test]
+    fn test_state_survives_reset() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let setup = channel_setup(&mut mock_hal);
+        let point = counterparty_per_commitment_point();
+        sign_counterparty_commitment(
+            &mut mock_hal,
+            0,
+            &counterparty_commitment_tx(&setup, 0, 40_000, &point),
+            &[],
+        )
+        .unwrap();
+        sign_counterparty_commitment(
+            &mut mock_hal,
+            1,
+            &counterparty_commitment_tx(&setup, 1, 30_000, &point),
+            &[],
+        )
+        .unwrap();
+
+        // Resetting and restoring the same seed does not allow setting up the channel again.
+        mock_hal.memory.reset_hww().unwrap();
+        mock_unlocked();
+        assert_eq!(
+            call(
+                &mut mock_hal,
+                Request::ChannelSetup(pb::BtcLightningChannelSetupRequest {
+                    channel_index: 0,
+                    parameters: Some(pb::BtcLightningChannelParameters {
+                        counterparty_to_self_delay: 721,
+                        ..parameters()
+                    }),
+                }),
+            ),
+            Err(Error::InvalidState),
+        );
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                0,
+                &counterparty_commitment_tx(&setup, 0, 40_000, &point),
+                &[],
+            ),
+            Err(Error::InvalidState),
+        );
+        assert_eq!(
+            mock_hal
+                .memory
+                .lightning_channel_get(0, 0)
+                .unwrap()
+                .next_counterparty_commitment,
+            2,
+        );
+    }
+
+    #[test]
+    fn test_forget_channels() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let forget = |mock_hal: &mut TestingHal, below_channel_index| {
+            call(
+                mock_hal,
+                Request::ForgetChannels(pb::BtcLightningForgetChannelsRequest {
+                    below_channel_index,
+                }),
+            )
+        };
+        let setup = |mock_hal: &mut TestingHal, channel_index| {
+            call(
+                mock_hal,
+                Request::ChannelSetup(pb::BtcLightningChannelSetupRequest {
+                    channel_index,
+                    parameters: Some(parameters()),
+                }),
+            )
+        };
+        setup(&mut mock_hal, 0).unwrap();
+        setup(&mut mock_hal, 1).unwrap();
+
+        // Open channels can't be forgotten.
+        assert_eq!(forget(&mut mock_hal, 1), Err(Error::InvalidState));
+        assert!(mock_hal.memory.lightning_channel_get(0, 0).is_some());
+
+        let mut state = mock_hal.memory.lightning_channel_get(0, 0).unwrap();
+        state.closed = true;
+        mock_hal.memory.lightning_channel_set(0, 0, &state).unwrap();
+        assert_eq!(
+            forget(&mut mock_hal, 1),
+            Ok(Response::ForgetChannels(
+                pb::BtcLightningForgetChannelsResponse {}
+            )),
+        );
+        assert!(mock_hal.memory.lightning_channel_get(0, 0).is_none());
+        assert!(mock_hal.memory.lightning_channel_get(0, 1).is_some());
+
+        // The index of a forgotten channel can't be used again, for any request.
+        assert_eq!(setup(&mut mock_hal, 0), Err(Error::InvalidState));
+        assert_eq!(revoke(&mut mock_hal, 0), Err(Error::InvalidState));
+        setup(&mut mock_hal, 1).unwrap();
+        setup(&mut mock_hal, 2).unwrap();
+
+        // Other coins are not affected.
+        channel_setup_coin(&mut mock_hal, BtcCoin::Tbtc);
+
+        assert_eq!(
+            forget(&mut mock_hal, HARDENED + 1),
+            Err(Error::InvalidInput)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+    }
+
+    #[test]
+    fn test_holder_commitment_policy() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let setup = channel_setup(&mut mock_hal);
+
+        let point = |mock_hal: &mut TestingHal, commitment_number| match call(
+            mock_hal,
+            Request::PerCommitmentPoint(pb::BtcLightningPerCommitmentPointRequest {
+                channel_index: 0,
+                commitment_number,
+            }),
+        ) {
+            Ok(Response::PerCommitmentPoint(response)) => {
+                PublicKey::from_slice(&response.per_commitment_point).unwrap()
+            }
+            _ => panic!("unexpected response"),
+        };
+        let point0 = point(&mut mock_hal, 0);
+        let point1 = point(&mut mock_hal, 1);
+        let point2 = point(&mut mock_hal, 2);
+        let tx0 = holder_commitment_tx(&setup, 0, 40_000, &point0);
+        let tx1 = holder_commitment_tx(&setup, 1, 40_000, &point1);
+        let tx2 = holder_commitment_tx(&setup, 2, 40_000, &point2);
+
+        // The last validated commitment can't be revoked.
+        assert_eq!(revoke(&mut mock_hal, 0), Err(Error::InvalidState));
+        validate_holder_commitment(&mut mock_hal, &setup, 0, &tx0).unwrap();
+        assert_eq!(revoke(&mut mock_hal, 0), Err(Error::InvalidState));
+        validate_holder_commitment(&mut mock_hal, &setup, 1, &tx1).unwrap();
+
+        // The released secret belongs to the per-commitment point.
+        let secret0 = revoke(&mut mock_hal, 0).unwrap();
+        assert_eq!(
+            SecretKey::from_slice(&secret0)
+                .unwrap()
+                .public_key(SECP256K1),
+            point0,
+        );
+        // Revocations happen in order.
+        assert_eq!(revoke(&mut mock_hal, 0), Err(Error::InvalidState));
+        assert_eq!(revoke(&mut mock_hal, 1), Err(Error::InvalidState));
+        validate_holder_commitment(&mut mock_hal, &setup, 2, &tx2).unwrap();
+        assert_eq!(revoke(&mut mock_hal, 2), Err(Error::InvalidState));
+        revoke(&mut mock_hal, 1).unwrap();
+
+        // Validations happen in order. The last one can be validated again.
+        validate_holder_commitment(&mut mock_hal, &setup, 2, &tx2).unwrap();
+        assert_eq!(
+            validate_holder_commitment(&mut mock_hal, &setup, 1, &tx1),
+            Err(Error::InvalidState),
+        );
+        let point4 = point(&mut mock_hal, 4);
+        assert_eq!(
+            validate_holder_commitment(
+                &mut mock_hal,
+                &setup,
+                4,
+                &holder_commitment_tx(&setup, 4, 40_000, &point4)
+            ),
+            Err(Error::InvalidState),
+        );
+        // The counterparty signature must be valid.
+        let point3 = point(&mut mock_hal, 3);
+        let tx3 = holder_commitment_tx(&setup, 3, 40_000, &point3);
+        assert_eq!(
+            call(
+                &mut mock_hal,
+                Request::ValidateHolderCommitment(
+                    pb::BtcLightningValidateHolderCommitmentRequest {
+                        tx: Some(tx3.clone()),
+                        ..validate_holder_commitment_request(&setup, 3, &tx2)
+                    }
+                ),
+            ),
+            Err(Error::InvalidInput),
+        );
+        assert_eq!(
+            call(
+                &mut mock_hal,
+                Request::ValidateHolderCommitment(
+                    pb::BtcLightningValidateHolderCommitmentRequest {
+                        counterparty_signature: vec![],
+                        ..validate_holder_commitment_request(&setup, 3, &tx3)
+                    }
+                ),
+            ),
+            Err(Error::InvalidInput),
+        );
+        // Commitments that have not been validated are not signed.
+        assert_eq!(
+            sign_holder_commitment(&mut mock_hal, 3, &tx3),
+            Err(Error::InvalidState),
+        );
+        assert_eq!(
+            mock_hal
+                .memory
+                .lightning_channel_get(0, 0)
+                .unwrap()
+                .next_holder_commitment,
+            3,
+        );
+
+        // Revoked commitments are never signed.
+        assert_eq!(
+            sign_holder_commitment(&mut mock_hal, 1, &tx1),
+            Err(Error::InvalidState),
+        );
+        // Nor can they be passed off as the current commitment.
+        assert_eq!(
+            sign_holder_commitment(&mut mock_hal, 2, &tx1),
+            Err(Error::InvalidInput),
+        );
+        assert_eq!(
+            sign_holder_commitment(
+                &mut mock_hal,
+                2,
+                &holder_commitment_tx(&setup, 2, 40_000, &point1)
+            ),
+            Err(Error::InvalidInput),
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // The current commitment can be signed, closing the channel.
+        let response = sign_holder_commitment(&mut mock_hal, 2, &tx2).unwrap();
+        assert_eq!(response.signature.len(), 64);
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![Screen::Confirm {
+                title: "Lightning".into(),
+                body: "Force close\nchannel 0?".into(),
+                longtouch: true,
+            }],
+        );
+        assert_eq!(
+            mock_hal.memory.lightning_channel_get(0, 0),
+            Some(LightningChannelState {
+                parameters_hash: expected_parameters_hash(),
+                next_counterparty_commitment: 0,
+                next_holder_commitment: 3,
+                revoked_holder_commitments: 2,
+                to_self_sat: 0,
+                counterparty_commitment_hash: [0; 32],
+                closed: true,
+            }),
+        );
+
+        // After force closing, the signed commitment can't be revoked and no new commitments are
+        // validated or signed.
+        assert_eq!(revoke(&mut mock_hal, 2), Err(Error::InvalidState));
+        assert_eq!(
+            validate_holder_commitment(&mut mock_hal, &setup, 3, &tx3),
+            Err(Error::InvalidState),
+        );
+        assert_eq!(
+            sign_counterparty_commitment(
+                &mut mock_hal,
+                0,
+                &counterparty_commitment_tx(
+                    &setup,
+                    0,
+                    40_000,
+                    &counterparty_per_commitment_point()
+                ),
+                &[]
+            ),
+            Err(Error::InvalidState),
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            call(
+                &mut mock_hal,
+                Request::ChannelSetup(pb::BtcLightningChannelSetupRequest {
+                    channel_index: HARDENED,
+                    parameters: None,
+                }),
+            ),
+            Err(Error::InvalidInput),
+        );
+        assert_eq!(
+            call(
+                &mut mock_hal,
+                Request::PerCommitmentPoint(pb::BtcLightningPerCommitmentPointRequest {
+                    channel_index: 0,
+                    commitment_number: MAX_COMMITMENT_NUMBER + 1,
+                }),
+            ),
+            Err(Error::InvalidInput),
+        );
+        assert_eq!(
+            call(
+                &mut mock_hal,
+                Request::Revoke(pb::BtcLightningRevokeRequest {
+                    channel_index: u32::MAX,
+                    commitment_number: 0,
+                }),
+            ),
+            Err(Error::InvalidInput),
+        );
+        // Litecoin is not supported.
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::BtcLightningRequest {
+                    coin: BtcCoin::Ltc as _,
+                    request: Some(Request::NodeId(pb::BtcLightningNodeIdRequest {})),
+                }
+            )),
+            Err(Error::InvalidInput),
+        );
+    }
+}
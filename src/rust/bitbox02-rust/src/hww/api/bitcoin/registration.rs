@@ -3,9 +3,6 @@
 use super::Error;
 use super::params;
 use super::pb;
-use crate::hal::ui::ConfirmParams;
-
-use alloc::string::String;
 
 use pb::BtcCoin;
 use pb::btc_register_script_config_request::XPubType;
@@ -14,7 +11,6 @@ use pb::btc_script_config::Config;
 
 use super::multisig::SortXpubs;
 
-use crate::hal::ui::CanCancel;
 use crate::hal::{Memory, Ui};
 
 pub fn process_is_script_config_registered(
@@ -58,45 +54,6 @@ pub fn process_is_script_config_registered(
     }
 }
 
-async fn get_name(
-    hal: &mut impl crate::hal::Hal,
-    request: &pb::BtcRegisterScriptConfigRequest,
-) -> Result<String, Error> {
-    let name = if request.name.is_empty() {
-        hal.ui()
-            .confirm(&ConfirmParams {
-                title: "Register",
-                body: "Please name this\naccount",
-                accept_is_nextarrow: true,
-                ..Default::default()
-            })
-            .await?;
-
-        let name = hal
-            .ui()
-            .enter_string(
-                &crate::hal::ui::EnterStringParams {
-                    title: "Enter account name",
-                    longtouch: true,
-                    ..Default::default()
-                },
-                CanCancel::Yes,
-                "",
-            )
-            .await?;
-        // We truncate the user input string to fit into the maximum allowed multisig
-        // account name length. This is not very nice, but it has to do until we have some
-        // sort of indication in the input component.
-        util::strings::truncate_str(name.as_str(), bitbox_hal::memory::MULTISIG_NAME_MAX_LEN).into()
-    } else {
-        request.name.clone()
-    };
-    if !util::name::validate(&name, bitbox_hal::memory::MULTISIG_NAME_MAX_LEN) {
-        return Err(Error::InvalidInput);
-    }
-    Ok(name)
-}
-
 pub async fn process_register_script_config(
     hal: &mut impl crate::hal::Hal,
     request: &pb::BtcRegisterScriptConfigRequest,
@@ -113,7 +70,7 @@ pub async fn process_register_script_config(
         }) => {
             let coin = BtcCoin::try_from(*coin)?;
             let coin_params = params::get(coin);
-            let name = get_name(hal, request).await?;
+            let name = crate::workflow::account_name::get(hal, &request.name).await?;
             super::multisig::validate(hal, multisig, keypath)?;
             let xpub_type = XPubType::try_from(request.xpub_type)?;
             super::multisig::confirm_extended(
@@ -141,7 +98,7 @@ pub async fn process_register_script_config(
         }) => {
             let coin = BtcCoin::try_from(*coin)?;
             let coin_params = params::get(coin);
-            let name = get_name(hal, request).await?;
+            let name = crate::workflow::account_name::get(hal, &request.name).await?;
             let parsed = super::policies::parse(hal, policy, coin)?;
             parsed
                 .confirm(
@@ -5,16 +5,31 @@ compile_error!("Cardano code is being compiled even though the app-cardano featu
 
 mod address;
 pub mod keypath;
+mod native_script;
 mod params;
+mod registration;
+mod sign_message;
 mod sign_transaction;
 mod xpubs;
 
 use super::Error;
 use super::pb;
 
+use pb::CardanoDerivationType;
 use pb::cardano_request::Request;
 use pb::cardano_response::Response;
 
+use crate::keystore::ed25519::Derivation;
+
+/// Returns the root key derivation requested by the host.
+fn get_derivation(derivation_type: i32) -> Result<Derivation, Error> {
+    Ok(match CardanoDerivationType::try_from(derivation_type)? {
+        CardanoDerivationType::CardanoDerivationLedger => Derivation::Ledger,
+        CardanoDerivationType::CardanoDerivationIcarus => Derivation::Icarus,
+        CardanoDerivationType::CardanoDerivationIcarusTrezor => Derivation::IcarusTrezor,
+    })
+}
+
 /// Handle a Cardano protobuf api call.
 pub async fn process_api(
     hal: &mut impl crate::hal::Hal,
@@ -24,5 +39,12 @@ pub async fn process_api(
         Request::Xpubs(request) => xpubs::process(hal, request),
         Request::Address(request) => address::process(hal, request).await,
         Request::SignTransaction(request) => sign_transaction::process(hal, request).await,
+        Request::IsScriptConfigRegistered(request) => {
+            registration::process_is_script_config_registered(hal, request)
+        }
+        Request::RegisterScriptConfig(request) => {
+            registration::process_register_script_config(hal, request).await
+        }
+        Request::SignMessage(request) => sign_message::process(hal, request).await,
     }
 }
@@ -8,6 +8,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use crate::hal::Ui;
+use crate::keystore::ed25519::Derivation;
 
 use pb::CardanoNetwork;
 use pb::cardano_response::Response;
@@ -303,9 +304,10 @@ pub fn decode_payment_address(params: &params::Params, address: &str) -> Result<
 /// Returns the hash of the pubkey at the keypath. Returns an error if the keystore is locked.
 pub fn pubkey_hash_at_keypath(
     hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
     keypath: &[u32],
 ) -> Result<[u8; ADDRESS_HASH_SIZE], ()> {
-    let xpub = crate::keystore::ed25519::get_xpub(hal, keypath)?;
+    let xpub = crate::keystore::ed25519::get_xpub(hal, derivation, keypath)?;
     let pubkey_bytes = xpub.pubkey_bytes();
     let mut hasher = Blake2bVar::new(ADDRESS_HASH_SIZE).unwrap();
     hasher.update(pubkey_bytes);
@@ -318,20 +320,50 @@ pub fn pubkey_hash_at_keypath(
 fn address_header(params: &params::Params, script_config: &Config) -> u8 {
     let address_tag: u8 = match script_config {
         Config::PkhSkh(_) => 0,
+        Config::NativeScripts(pb::cardano_script_config::NativeScripts {
+            stake_script: Some(_),
+            ..
+        }) => 0b0011,
+        Config::PkhPointer(_) => 0b0100,
+        Config::Pkh(_) => 0b0110,
+        Config::NativeScripts(_) => 0b0111,
+        Config::Skh(_) => 0b1110,
     };
 
     (address_tag << 4) | params.network_id
 }
 
-/// Encode the given address using bech32, validating that the keypaths are valid. If
-/// `keypath_prefix` is provided, it is also validated that the address keypaths start with this
-/// prefix.
-pub fn validate_and_encode_payment_address(
+/// Encodes a natural number as used in pointer addresses: big-endian in groups of 7 bits, with the
+/// most significant bit set in all bytes but the last.
+///
+/// See https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0019/CIP-0019.md#pointers
+fn encode_variable_length_natural(out: &mut Vec<u8>, value: u64) {
+    let mut groups = [0u8; 10];
+    let mut len = 0;
+    let mut value = value;
+    loop {
+        groups[len] = (value & 0x7f) as u8;
+        len += 1;
+        value >>= 7;
+        if value == 0 {
+            break;
+        }
+    }
+    for i in (0..len).rev() {
+        out.push(if i > 0 { groups[i] | 0x80 } else { groups[i] });
+    }
+}
+
+/// Returns the raw bytes of the given address, validating that the keypaths are valid. If
+/// `bip44_account` is provided, it is also validated that the address keypaths belong to this
+/// account.
+pub fn validate_and_get_payment_address_bytes(
     hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
     params: &params::Params,
     script_config: &Config,
     bip44_account: Option<u32>,
-) -> Result<String, Error> {
+) -> Result<Vec<u8>, Error> {
     let header = address_header(params, script_config);
 
     match script_config {
@@ -342,23 +374,119 @@ pub fn validate_and_encode_payment_address(
                 bip44_account,
             )?;
 
-            let payment_key_hash = pubkey_hash_at_keypath(hal, &config.keypath_payment)?;
-            let stake_key_hash = pubkey_hash_at_keypath(hal, &config.keypath_stake)?;
+            let payment_key_hash =
+                pubkey_hash_at_keypath(hal, derivation, &config.keypath_payment)?;
+            let stake_key_hash = pubkey_hash_at_keypath(hal, derivation, &config.keypath_stake)?;
 
             let mut bytes: Vec<u8> = Vec::with_capacity(1 + 2 * ADDRESS_HASH_SIZE);
             bytes.push(header);
             bytes.extend_from_slice(&payment_key_hash);
             bytes.extend_from_slice(&stake_key_hash);
+            Ok(bytes)
+        }
+        Config::Pkh(config) => {
+            super::keypath::validate_address_shelley_payment(
+                &config.keypath_payment,
+                bip44_account,
+            )?;
+            let mut bytes: Vec<u8> = Vec::with_capacity(1 + ADDRESS_HASH_SIZE);
+            bytes.push(header);
+            bytes.extend_from_slice(&pubkey_hash_at_keypath(
+                hal,
+                derivation,
+                &config.keypath_payment,
+            )?);
+            Ok(bytes)
+        }
+        Config::PkhPointer(config) => {
+            super::keypath::validate_address_shelley_payment(
+                &config.keypath_payment,
+                bip44_account,
+            )?;
+            let pointer = config.pointer.as_ref().ok_or(Error::InvalidInput)?;
+            let mut bytes: Vec<u8> = Vec::with_capacity(1 + ADDRESS_HASH_SIZE + 3 * 10);
+            bytes.push(header);
+            bytes.extend_from_slice(&pubkey_hash_at_keypath(
+                hal,
+                derivation,
+                &config.keypath_payment,
+            )?);
+            encode_variable_length_natural(&mut bytes, pointer.slot);
+            encode_variable_length_natural(&mut bytes, pointer.tx_index);
+            encode_variable_length_natural(&mut bytes, pointer.cert_index);
+            Ok(bytes)
+        }
+        // Reward addresses can't receive payments.
+        Config::Skh(_) => Err(Error::InvalidInput),
+        Config::NativeScripts(native_scripts) => {
+            super::native_script::validate(native_scripts, bip44_account)?;
+            // Only registered scripts are considered to belong to this wallet.
+            if super::native_script::get_name(hal, derivation, params, native_scripts)?.is_none() {
+                return Err(Error::InvalidInput);
+            }
 
-            Ok(bech32::encode::<bech32::Bech32>(
-                bech32::Hrp::parse_unchecked(params.bech32_hrp_payment),
-                &bytes,
-            )
-            .unwrap())
+            let mut bytes: Vec<u8> = Vec::with_capacity(1 + 2 * ADDRESS_HASH_SIZE);
+            bytes.push(header);
+            bytes.extend_from_slice(&super::native_script::script_hash(
+                hal,
+                derivation,
+                native_scripts
+                    .payment_script
+                    .as_ref()
+                    .ok_or(Error::InvalidInput)?,
+            )?);
+            if let Some(ref stake_script) = native_scripts.stake_script {
+                bytes.extend_from_slice(&super::native_script::script_hash(
+                    hal,
+                    derivation,
+                    stake_script,
+                )?);
+            }
+            Ok(bytes)
         }
     }
 }
 
+/// Encode the given address using bech32, validating that the keypaths are valid. If
+/// `bip44_account` is provided, it is also validated that the address keypaths belong to this
+/// account.
+pub fn validate_and_encode_payment_address(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    params: &params::Params,
+    script_config: &Config,
+    bip44_account: Option<u32>,
+) -> Result<String, Error> {
+    let bytes = validate_and_get_payment_address_bytes(
+        hal,
+        derivation,
+        params,
+        script_config,
+        bip44_account,
+    )?;
+    Ok(bech32::encode::<bech32::Bech32>(
+        bech32::Hrp::parse_unchecked(params.bech32_hrp_payment),
+        &bytes,
+    )
+    .unwrap())
+}
+
+/// Returns the raw bytes of the reward address of the stake key at the given keypath. The keypath
+/// must be validated by the caller.
+///
+/// See https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0019/CIP-0019.md#stake-addresses
+pub fn reward_address_bytes(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    params: &params::Params,
+    keypath_stake: &[u32],
+) -> Result<Vec<u8>, Error> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(1 + ADDRESS_HASH_SIZE);
+    bytes.push((0b1110 << 4) | params.network_id);
+    bytes.extend_from_slice(&pubkey_hash_at_keypath(hal, derivation, keypath_stake)?);
+    Ok(bytes)
+}
+
 pub async fn process(
     hal: &mut impl crate::hal::Hal,
     request: &pb::CardanoAddressRequest,
@@ -373,9 +501,25 @@ pub async fn process(
         .as_ref()
         .ok_or(Error::InvalidInput)?;
 
-    let encoded_address = validate_and_encode_payment_address(hal, params, script_config, None)?;
+    let derivation = super::get_derivation(request.derivation_type)?;
+    let encoded_address = match script_config {
+        Config::Skh(config) => {
+            super::keypath::validate_address_shelley_stake(&config.keypath_stake, None)?;
+            bech32::encode::<bech32::Bech32>(
+                bech32::Hrp::parse_unchecked(params.bech32_hrp_stake),
+                &reward_address_bytes(hal, derivation, params, &config.keypath_stake)?,
+            )
+            .unwrap()
+        }
+        _ => validate_and_encode_payment_address(hal, derivation, params, script_config, None)?,
+    };
 
     if request.display {
+        if let Config::NativeScripts(native_scripts) = script_config {
+            let name = super::native_script::get_name(hal, derivation, params, native_scripts)?
+                .ok_or(Error::InvalidInput)?;
+            super::native_script::confirm(hal, "Receive to", params, &name, native_scripts).await?;
+        }
         hal.ui()
             .confirm(&ConfirmParams {
                 title: params.name,
@@ -474,6 +618,7 @@ mod tests {
                 network: CardanoNetwork::CardanoMainnet as _,
                 display: false,
                 script_config: Some(make_pkh_skh(keypath_payment, keypath_stake)),
+                derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
             },
         ))
     }
@@ -484,6 +629,7 @@ mod tests {
         assert!(
             pubkey_hash_at_keypath(
                 &mut crate::hal::testing::TestingHal::new(),
+                Derivation::Ledger,
                 &[1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0]
             )
             .is_err()
@@ -493,6 +639,7 @@ mod tests {
         assert_eq!(
             pubkey_hash_at_keypath(
                 &mut crate::hal::testing::TestingHal::new(),
+                Derivation::Ledger,
                 &[1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0]),
             Ok(*b"\x5e\xbf\xc2\xcd\xae\xef\x4b\x4f\x1b\xe7\xfc\xc3\x1c\xfe\x94\x5e\xb9\x2d\x28\x67\x43\x49\xbd\x0f\x1a\x4a\x00\x63")
         );
@@ -576,6 +723,7 @@ mod tests {
                         &[1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
                         &[1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0]
                     )),
+                    derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
                 }
             )),
             Ok(Response::Pub(pb::PubResponse {
@@ -592,6 +740,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_process_native_scripts() {
+        const EXPECTED: &str = "addr1xym4647gna2s8phl4vu0mfg5es66ddzh648mz25ql66lwahnswhhhlq09kcgpgrlexdjsvlr5gjtm09fzl365mp9xnzs5k9ue8";
+        const EXPECTED_NO_STAKE: &str =
+            "addr1wym4647gna2s8phl4vu0mfg5es66ddzh648mz25ql66lwasajytsm";
+
+        let params = params::get(CardanoNetwork::CardanoMainnet);
+        let native_scripts = super::super::native_script::testing::make_native_scripts();
+        let mut native_scripts_no_stake = native_scripts.clone();
+        native_scripts_no_stake.stake_script = None;
+        let request = |native_scripts: &pb::cardano_script_config::NativeScripts, display| {
+            pb::CardanoAddressRequest {
+                network: CardanoNetwork::CardanoMainnet as _,
+                display,
+                script_config: Some(pb::CardanoScriptConfig {
+                    config: Some(Config::NativeScripts(native_scripts.clone())),
+                }),
+                derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
+            }
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        // Not registered yet.
+        assert_eq!(
+            block_on(process(&mut mock_hal, &request(&native_scripts, false))),
+            Err(Error::InvalidInput),
+        );
+
+        super::super::native_script::testing::register(
+            &mut mock_hal,
+            params,
+            &native_scripts,
+            "my multisig",
+        );
+        super::super::native_script::testing::register(
+            &mut mock_hal,
+            params,
+            &native_scripts_no_stake,
+            "my multisig without staking",
+        );
+        assert_eq!(
+            block_on(process(&mut mock_hal, &request(&native_scripts, true))),
+            Ok(Response::Pub(pb::PubResponse {
+                r#pub: EXPECTED.into()
+            }))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Receive to".into(),
+                    body: "2-of-3\nCardano multisig".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Receive to".into(),
+                    body: "my multisig".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: EXPECTED.into(),
+                    longtouch: false,
+                },
+            ]
+        );
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &request(&native_scripts_no_stake, false)
+            )),
+            Ok(Response::Pub(pb::PubResponse {
+                r#pub: EXPECTED_NO_STAKE.into()
+            }))
+        );
+    }
+
     #[test]
     fn test_process_table() {
         struct Test<'a> {
@@ -632,4 +858,135 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_encode_variable_length_natural() {
+        let encode = |value| {
+            let mut out = Vec::new();
+            encode_variable_length_natural(&mut out, value);
+            out
+        };
+        assert_eq!(encode(0), b"\x00");
+        assert_eq!(encode(127), b"\x7f");
+        assert_eq!(encode(128), b"\x81\x00");
+        // From the CIP-19 pointer address test vector.
+        assert_eq!(encode(2498243), b"\x81\x98\xbd\x43");
+        assert_eq!(
+            encode(u64::MAX),
+            b"\x81\xff\xff\xff\xff\xff\xff\xff\xff\x7f"
+        );
+    }
+
+    #[test]
+    fn test_process_address_types() {
+        let request = |network: CardanoNetwork, config: Config| pb::CardanoAddressRequest {
+            network: network as _,
+            display: false,
+            script_config: Some(pb::CardanoScriptConfig {
+                config: Some(config),
+            }),
+            derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
+        };
+        let pkh = |keypath_payment: &[u32]| {
+            Config::Pkh(pb::cardano_script_config::Pkh {
+                keypath_payment: keypath_payment.to_vec(),
+            })
+        };
+        let skh = |keypath_stake: &[u32]| {
+            Config::Skh(pb::cardano_script_config::Skh {
+                keypath_stake: keypath_stake.to_vec(),
+            })
+        };
+        let pkh_pointer = |pointer| {
+            Config::PkhPointer(pb::cardano_script_config::PkhPointer {
+                keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                pointer,
+            })
+        };
+        let keypath_payment = &[1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0];
+        let keypath_stake = &[1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0];
+
+        mock_unlocked();
+        let valid = [
+            (
+                request(CardanoNetwork::CardanoMainnet, pkh(keypath_payment)),
+                "addr1v90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqcch3xvkm",
+            ),
+            (
+                request(CardanoNetwork::CardanoTestnet, pkh(keypath_payment)),
+                "addr_test1vp0tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqccvejse7",
+            ),
+            (
+                request(CardanoNetwork::CardanoMainnet, skh(keypath_stake)),
+                "stake1u8pfaek3kmulphxqt82v0dsfs67cjylekwdty5l06x7f5xqnw76fa",
+            ),
+            (
+                request(
+                    CardanoNetwork::CardanoMainnet,
+                    pkh_pointer(Some(pb::cardano_script_config::Pointer {
+                        slot: 2498243,
+                        tx_index: 27,
+                        cert_index: 3,
+                    })),
+                ),
+                "addr1g90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqcupnz75xxcr3ewrss",
+            ),
+        ];
+        for (request, expected) in valid.iter() {
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), request)),
+                Ok(Response::Pub(pb::PubResponse {
+                    r#pub: (*expected).into()
+                }))
+            );
+        }
+
+        // Reward addresses are displayed like payment addresses.
+        let mut mock_hal = TestingHal::new();
+        assert!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::CardanoAddressRequest {
+                    display: true,
+                    ..request(CardanoNetwork::CardanoMainnet, skh(keypath_stake))
+                }
+            ))
+            .is_ok()
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![Screen::Confirm {
+                title: "Cardano".into(),
+                body: "stake1u8pfaek3kmulphxqt82v0dsfs67cjylekwdty5l06x7f5xqnw76fa".into(),
+                longtouch: false,
+            }]
+        );
+
+        let invalid = [
+            // Stake key as payment key.
+            request(CardanoNetwork::CardanoMainnet, pkh(keypath_stake)),
+            // Payment key as stake key.
+            request(CardanoNetwork::CardanoMainnet, skh(keypath_payment)),
+            // Missing pointer.
+            request(CardanoNetwork::CardanoMainnet, pkh_pointer(None)),
+        ];
+        for request in invalid.iter() {
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), request)),
+                Err(Error::InvalidInput)
+            );
+        }
+
+        // Reward addresses are not payment addresses.
+        assert_eq!(
+            validate_and_encode_payment_address(
+                &mut TestingHal::new(),
+                Derivation::Ledger,
+                params::get(CardanoNetwork::CardanoMainnet),
+                &skh(keypath_stake),
+                None,
+            ),
+            Err(Error::InvalidInput)
+        );
+    }
 }
@@ -7,9 +7,17 @@ const BIP44_ACCOUNT_MAX: u32 = HARDENED + 99; // 100 accounts
 const BIP44_ADDRESS_MAX: u32 = 9999; // 10k addresses
 
 const BIP44_PURPOSE_SHELLEY: u32 = 1852 + HARDENED;
+const BIP44_PURPOSE_MULTISIG: u32 = 1854 + HARDENED;
+const BIP44_PAYMENT_ROLE: u32 = 0;
 const BIP44_COIN: u32 = 1815 + HARDENED;
 const BIP44_STAKE_ROLE: u32 = 2;
 const BIP44_STAKE_ADDRESS: u32 = 0;
+const BIP44_DREP_ROLE: u32 = 3;
+const BIP44_DREP_ADDRESS: u32 = 0;
+
+const POLICY_PURPOSE: u32 = 1855 + HARDENED;
+const POLICY_INDEX_MIN: u32 = HARDENED;
+const POLICY_INDEX_MAX: u32 = HARDENED + 99; // 100 policies
 
 pub struct Error;
 
@@ -41,6 +49,17 @@ pub fn validate_account_shelley(keypath: &[u32]) -> Result<(), Error> {
     Err(Error)
 }
 
+/// Validates a keypath to be m/1854'/1815'/account, where account between 0' and 99'.
+///
+/// See: https://github.com/cardano-foundation/CIPs/blob/master/CIP-1854/README.md
+pub fn validate_account_multisig(keypath: &[u32]) -> Result<(), Error> {
+    if let &[BIP44_PURPOSE_MULTISIG, BIP44_COIN, account] = keypath {
+        check_account(account)?;
+        return Ok(());
+    }
+    Err(Error)
+}
+
 /// Validates that the prefix (all but last two elements) of the keypath is a valid shelley account
 /// payment keypath (m/1852'/1815'/account/role/address, where role is 0 or 1 (receive vs change) and address is less than 10000.
 ///
@@ -90,6 +109,69 @@ pub fn validate_address_shelley_stake(
     Err(Error)
 }
 
+/// Validates a DRep keypath to be m/1852'/1815'/account/3/0. Like for the stake key, only the
+/// first address index is allowed.
+///
+/// See: https://github.com/cardano-foundation/CIPs/blob/master/CIP-0105/README.md
+pub fn validate_drep(keypath: &[u32], bip44_account: Option<u32>) -> Result<(), Error> {
+    if let &[
+        BIP44_PURPOSE_SHELLEY,
+        BIP44_COIN,
+        account,
+        BIP44_DREP_ROLE,
+        BIP44_DREP_ADDRESS,
+    ] = keypath
+    {
+        if bip44_account.is_some_and(|a| a != account) {
+            return Err(Error);
+        }
+        check_account(account)?;
+        return Ok(());
+    }
+    Err(Error)
+}
+
+fn validate_multisig(keypath: &[u32], role: u32, bip44_account: Option<u32>) -> Result<(), Error> {
+    if let &[BIP44_PURPOSE_MULTISIG, BIP44_COIN, account, r, address] = keypath
+        && r == role
+    {
+        if bip44_account.is_some_and(|a| a != account) {
+            return Err(Error);
+        }
+        check_account(account)?;
+        check_address(address)?;
+        return Ok(());
+    }
+    Err(Error)
+}
+
+/// Validates a multisig payment keypath to be m/1854'/1815'/account/0/address, where address is
+/// less than 10000.
+///
+/// See: https://github.com/cardano-foundation/CIPs/blob/master/CIP-1854/README.md
+pub fn validate_multisig_payment(keypath: &[u32], bip44_account: Option<u32>) -> Result<(), Error> {
+    validate_multisig(keypath, BIP44_PAYMENT_ROLE, bip44_account)
+}
+
+/// Validates a multisig stake keypath to be m/1854'/1815'/account/2/address, where address is
+/// less than 10000.
+pub fn validate_multisig_stake(keypath: &[u32], bip44_account: Option<u32>) -> Result<(), Error> {
+    validate_multisig(keypath, BIP44_STAKE_ROLE, bip44_account)
+}
+
+/// Validates a minting policy keypath to be m/1855'/1815'/policy', where policy is between 0' and
+/// 99'.
+///
+/// See: https://github.com/cardano-foundation/CIPs/blob/master/CIP-1855/README.md
+pub fn validate_policy(keypath: &[u32]) -> Result<(), Error> {
+    if let &[POLICY_PURPOSE, BIP44_COIN, policy] = keypath
+        && (POLICY_INDEX_MIN..=POLICY_INDEX_MAX).contains(&policy)
+    {
+        return Ok(());
+    }
+    Err(Error)
+}
+
 /// Validate both keypaths and also check that they have the same first three elements, to ensure
 /// they are from the same account.
 pub fn validate_address_shelley(
@@ -302,4 +384,59 @@ mod tests {
             .is_err()
         );
     }
+
+    #[test]
+    fn test_validate_policy() {
+        let purpose = 1855 + HARDENED;
+        let coin = 1815 + HARDENED;
+        assert!(validate_policy(&[purpose, coin, HARDENED]).is_ok());
+        assert!(validate_policy(&[purpose, coin, HARDENED + 99]).is_ok());
+        assert!(validate_policy(&[purpose, coin, HARDENED + 100]).is_err());
+        assert!(validate_policy(&[purpose, coin, 0]).is_err());
+        assert!(validate_policy(&[purpose, coin]).is_err());
+        assert!(validate_policy(&[purpose, coin, HARDENED, 0]).is_err());
+        assert!(validate_policy(&[1852 + HARDENED, coin, HARDENED]).is_err());
+        assert!(validate_policy(&[purpose, 1 + HARDENED, HARDENED]).is_err());
+    }
+
+    #[test]
+    fn test_validate_drep() {
+        let purpose = 1852 + HARDENED;
+        let coin = 1815 + HARDENED;
+        let account = 99 + HARDENED;
+        assert!(validate_drep(&[purpose, coin, account, 3, 0], None).is_ok());
+        assert!(validate_drep(&[purpose, coin, account, 3, 0], Some(account)).is_ok());
+        // force account, mismatch
+        assert!(validate_drep(&[purpose, coin, account, 3, 0], Some(HARDENED)).is_err());
+        // only the first address index
+        assert!(validate_drep(&[purpose, coin, account, 3, 1], None).is_err());
+        // stake role
+        assert!(validate_drep(&[purpose, coin, account, 2, 0], None).is_err());
+        // account too high
+        assert!(validate_drep(&[purpose, coin, 100 + HARDENED, 3, 0], None).is_err());
+        assert!(validate_drep(&[purpose, coin, account, 3], None).is_err());
+    }
+
+    #[test]
+    fn test_validate_multisig() {
+        let purpose = 1854 + HARDENED;
+        let coin = 1815 + HARDENED;
+        let account = 99 + HARDENED;
+        assert!(validate_multisig_payment(&[purpose, coin, account, 0, 9999], None).is_ok());
+        assert!(validate_multisig_payment(&[purpose, coin, account, 0, 0], Some(account)).is_ok());
+        assert!(validate_multisig_stake(&[purpose, coin, account, 2, 1], None).is_ok());
+        // force account, mismatch
+        assert!(
+            validate_multisig_payment(&[purpose, coin, account, 0, 0], Some(HARDENED)).is_err()
+        );
+        // wrong role
+        assert!(validate_multisig_payment(&[purpose, coin, account, 2, 0], None).is_err());
+        assert!(validate_multisig_stake(&[purpose, coin, account, 0, 0], None).is_err());
+        // too high address
+        assert!(validate_multisig_payment(&[purpose, coin, account, 0, 10000], None).is_err());
+        // single-signature purpose
+        assert!(validate_multisig_payment(&[1852 + HARDENED, coin, account, 0, 0], None).is_err());
+        // account too high
+        assert!(validate_multisig_payment(&[purpose, coin, 100 + HARDENED, 0, 0], None).is_err());
+    }
 }
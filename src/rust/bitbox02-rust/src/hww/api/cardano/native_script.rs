@@ -0,0 +1,613 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::address::{ADDRESS_HASH_SIZE, pubkey_hash_at_keypath};
+use super::keypath::{validate_multisig_payment, validate_multisig_stake};
+use super::params;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use pb::CardanoNativeScript;
+use pb::cardano_native_script::{NOfK, PubKey, Script, Scripts};
+use pb::cardano_script_config::NativeScripts;
+
+use crate::hal::{Memory, Ui};
+use crate::keystore::ed25519::Derivation;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use blake2::{Blake2bVar, digest::VariableOutput};
+use minicbor::encode::{Encoder, Write};
+use sha2::{Digest, Sha256};
+
+/// Max nesting level of all/any/n-of-k scripts.
+const MAX_DEPTH: usize = 5;
+/// Max number of keys in a script.
+const MAX_KEYS: usize = 15;
+
+struct Validation {
+    validate_keypath: fn(&[u32], Option<u32>) -> Result<(), super::keypath::Error>,
+    bip44_account: Option<u32>,
+    num_keys: usize,
+    num_own_keys: usize,
+}
+
+impl Validation {
+    fn validate(&mut self, script: &CardanoNativeScript, depth: usize) -> Result<(), Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::InvalidInput);
+        }
+        match script.script.as_ref().ok_or(Error::InvalidInput)? {
+            Script::Pubkey(PubKey { keypath, key_hash }) => {
+                self.num_keys += 1;
+                if self.num_keys > MAX_KEYS {
+                    return Err(Error::InvalidInput);
+                }
+                match (keypath.is_empty(), key_hash.is_empty()) {
+                    (false, true) => {
+                        // All keys of this wallet must belong to the same account.
+                        (self.validate_keypath)(keypath, self.bip44_account)?;
+                        self.bip44_account = Some(keypath[2]);
+                        self.num_own_keys += 1;
+                    }
+                    (true, false) if key_hash.len() == ADDRESS_HASH_SIZE => {}
+                    _ => return Err(Error::InvalidInput),
+                }
+            }
+            Script::All(Scripts { scripts }) | Script::Any(Scripts { scripts }) => {
+                if scripts.is_empty() {
+                    return Err(Error::InvalidInput);
+                }
+                for script in scripts.iter() {
+                    self.validate(script, depth + 1)?;
+                }
+            }
+            Script::NOfK(NOfK { n, scripts }) => {
+                if *n == 0 || *n as usize > scripts.len() {
+                    return Err(Error::InvalidInput);
+                }
+                for script in scripts.iter() {
+                    self.validate(script, depth + 1)?;
+                }
+            }
+            Script::InvalidBefore(_) | Script::InvalidHereafter(_) => {}
+        }
+        Ok(())
+    }
+}
+
+/// Validates the payment and stake scripts. This includes checking that:
+/// - the payment script contains at least one key of this wallet
+/// - the keypaths of this wallet are CIP-1854 payment keys (role 0) in the payment script and stake
+///   keys (role 2) in the stake script, all of the same account
+/// - cosigner key hashes are 28 bytes
+/// - n-of-k scripts satisfy 0 < n <= k, all/any scripts are not empty
+/// - the scripts are not nested too deep and do not have too many keys.
+///
+/// If `bip44_account` is provided, the keys of this wallet must belong to this account.
+pub fn validate(native_scripts: &NativeScripts, bip44_account: Option<u32>) -> Result<(), Error> {
+    let mut validation = Validation {
+        validate_keypath: validate_multisig_payment,
+        bip44_account,
+        num_keys: 0,
+        num_own_keys: 0,
+    };
+    validation.validate(
+        native_scripts
+            .payment_script
+            .as_ref()
+            .ok_or(Error::InvalidInput)?,
+        0,
+    )?;
+    if validation.num_own_keys == 0 {
+        return Err(Error::InvalidInput);
+    }
+    if let Some(ref stake_script) = native_scripts.stake_script {
+        let mut validation = Validation {
+            validate_keypath: validate_multisig_stake,
+            bip44_account: validation.bip44_account,
+            num_keys: 0,
+            num_own_keys: 0,
+        };
+        validation.validate(stake_script, 0)?;
+    }
+    Ok(())
+}
+
+fn encode_scripts<W: Write>(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    encoder: &mut Encoder<W>,
+    scripts: &[CardanoNativeScript],
+) -> Result<(), Error> {
+    encoder.array(scripts.len() as _)?;
+    for script in scripts.iter() {
+        encode(hal, derivation, encoder, script)?;
+    }
+    Ok(())
+}
+
+fn encode<W: Write>(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    encoder: &mut Encoder<W>,
+    script: &CardanoNativeScript,
+) -> Result<(), Error> {
+    match script.script.as_ref().ok_or(Error::InvalidInput)? {
+        Script::Pubkey(PubKey { keypath, key_hash }) => {
+            encoder.array(2)?.u8(0)?;
+            if keypath.is_empty() {
+                encoder.bytes(key_hash)?;
+            } else {
+                encoder.bytes(&pubkey_hash_at_keypath(hal, derivation, keypath)?)?;
+            }
+        }
+        Script::All(Scripts { scripts }) => {
+            encoder.array(2)?.u8(1)?;
+            encode_scripts(hal, derivation, encoder, scripts)?;
+        }
+        Script::Any(Scripts { scripts }) => {
+            encoder.array(2)?.u8(2)?;
+            encode_scripts(hal, derivation, encoder, scripts)?;
+        }
+        Script::NOfK(NOfK { n, scripts }) => {
+            encoder.array(3)?.u8(3)?.u32(*n)?;
+            encode_scripts(hal, derivation, encoder, scripts)?;
+        }
+        Script::InvalidBefore(slot) => {
+            encoder.array(2)?.u8(4)?.u64(*slot)?;
+        }
+        Script::InvalidHereafter(slot) => {
+            encoder.array(2)?.u8(5)?.u64(*slot)?;
+        }
+    }
+    Ok(())
+}
+
+/// Computes the script hash, which is the Blake2b-224 hash of the native script tag (0) followed by
+/// the CBOR encoded script. The script must be validated using `validate()` first.
+pub fn script_hash(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    script: &CardanoNativeScript,
+) -> Result<[u8; ADDRESS_HASH_SIZE], Error> {
+    let mut encoded: Vec<u8> = vec![0];
+    encode(hal, derivation, &mut Encoder::new(&mut encoded), script)?;
+    let mut hasher = Blake2bVar::new(ADDRESS_HASH_SIZE).unwrap();
+    blake2::digest::Update::update(&mut hasher, &encoded);
+    let mut out = [0u8; ADDRESS_HASH_SIZE];
+    hasher.finalize_variable(&mut out).or(Err(Error::Generic))?;
+    Ok(out)
+}
+
+/// Creates a hash of the scripts, used to register them and to identify them later. The scripts
+/// must be validated using `validate()` first.
+pub fn get_hash(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    params: &params::Params,
+    native_scripts: &NativeScripts,
+) -> Result<[u8; 32], Error> {
+    let mut hasher = Sha256::new();
+    {
+        // 1. Type of registration: Cardano native scripts.
+        // It is chosen to never conflict with Bitcoin registration hashes, which start with the
+        // coin (0x00-0x04) or 0xff for policies.
+        hasher.update([0xfe]);
+    }
+    {
+        // 2. network
+        hasher.update([params.network_id]);
+    }
+    {
+        // 3. payment script hash
+        hasher.update(script_hash(
+            hal,
+            derivation,
+            native_scripts
+                .payment_script
+                .as_ref()
+                .ok_or(Error::InvalidInput)?,
+        )?);
+    }
+    {
+        // 4. stake script hash, if present
+        match native_scripts.stake_script {
+            Some(ref stake_script) => {
+                hasher.update([0x01]);
+                hasher.update(script_hash(hal, derivation, stake_script)?);
+            }
+            None => hasher.update([0x00]),
+        }
+    }
+    Ok(hasher.finalize().into())
+}
+
+/// Get the name of the registered scripts. The scripts must be validated using `validate()` first.
+///
+/// Returns the name if they are registered or None otherwise.
+pub fn get_name(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    params: &params::Params,
+    native_scripts: &NativeScripts,
+) -> Result<Option<String>, Error> {
+    let hash = get_hash(hal, derivation, params, native_scripts)?;
+    Ok(hal.memory().multisig_get_by_hash(&hash))
+}
+
+/// Short description of the top-level script, e.g. "2-of-3".
+fn describe(script: &CardanoNativeScript) -> Result<String, Error> {
+    Ok(match script.script.as_ref().ok_or(Error::InvalidInput)? {
+        Script::Pubkey(_) => "1-of-1".into(),
+        Script::All(Scripts { scripts }) => format!("{}-of-{}", scripts.len(), scripts.len()),
+        Script::Any(Scripts { scripts }) => format!("1-of-{}", scripts.len()),
+        Script::NOfK(NOfK { n, scripts }) => format!("{}-of-{}", n, scripts.len()),
+        Script::InvalidBefore(_) | Script::InvalidHereafter(_) => "Timelock".into(),
+    })
+}
+
+/// Formats the script in the notation of the cardano-cli JSON script format, e.g.
+/// `atLeast(2, sig(m/1854'/1815'/0'/0/0), sig(<key hash>), after(1000))`.
+fn format(script: &CardanoNativeScript) -> Result<String, Error> {
+    let format_scripts = |scripts: &[CardanoNativeScript]| -> Result<String, Error> {
+        Ok(scripts
+            .iter()
+            .map(format)
+            .collect::<Result<Vec<String>, Error>>()?
+            .join(", "))
+    };
+    Ok(match script.script.as_ref().ok_or(Error::InvalidInput)? {
+        Script::Pubkey(PubKey { keypath, key_hash }) => {
+            if keypath.is_empty() {
+                format!("sig({})", hex::encode(key_hash))
+            } else {
+                format!("sig({})", util::bip32::to_string(keypath))
+            }
+        }
+        Script::All(Scripts { scripts }) => format!("all({})", format_scripts(scripts)?),
+        Script::Any(Scripts { scripts }) => format!("any({})", format_scripts(scripts)?),
+        Script::NOfK(NOfK { n, scripts }) => {
+            format!("atLeast({}, {})", n, format_scripts(scripts)?)
+        }
+        Script::InvalidBefore(slot) => format!("after({})", slot),
+        Script::InvalidHereafter(slot) => format!("before({})", slot),
+    })
+}
+
+/// Confirms a native script setup with the user during receive.
+/// Verified are:
+/// - network
+/// - multisig type (m-of-n of the payment script)
+/// - name given by the user
+pub async fn confirm(
+    hal: &mut impl crate::hal::Hal,
+    title: &str,
+    params: &params::Params,
+    name: &str,
+    native_scripts: &NativeScripts,
+) -> Result<(), Error> {
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title,
+            body: &format!(
+                "{}\n{} multisig",
+                describe(
+                    native_scripts
+                        .payment_script
+                        .as_ref()
+                        .ok_or(Error::InvalidInput)?
+                )?,
+                params.name
+            ),
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title,
+            body: name,
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
+/// Confirms a native script setup with the user during registration. In addition to what is
+/// verified in `confirm()`, the full payment and stake scripts are shown, including the keys of
+/// all cosigners.
+pub async fn confirm_extended(
+    hal: &mut impl crate::hal::Hal,
+    title: &str,
+    params: &params::Params,
+    name: &str,
+    native_scripts: &NativeScripts,
+) -> Result<(), Error> {
+    confirm(hal, title, params, name, native_scripts).await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Payment script",
+            body: &format(
+                native_scripts
+                    .payment_script
+                    .as_ref()
+                    .ok_or(Error::InvalidInput)?,
+            )?,
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    let stake_script = match native_scripts.stake_script {
+        Some(ref stake_script) => format(stake_script)?,
+        None => "None (no staking rights)".into(),
+    };
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Stake script",
+            body: &stake_script,
+            scrollable: true,
+            longtouch: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod testing {
+    use super::*;
+    use util::bip32::HARDENED;
+
+    fn pubkey_script(keypath: &[u32], key_hash: &[u8]) -> CardanoNativeScript {
+        CardanoNativeScript {
+            script: Some(Script::Pubkey(PubKey {
+                keypath: keypath.to_vec(),
+                key_hash: key_hash.to_vec(),
+            })),
+        }
+    }
+
+    /// 2-of-3 payment script with the key at m/1854'/1815'/0'/0/0 and two cosigners, and a stake
+    /// script with the key at m/1854'/1815'/0'/2/0.
+    pub fn make_native_scripts() -> NativeScripts {
+        NativeScripts {
+            payment_script: Some(CardanoNativeScript {
+                script: Some(Script::NOfK(NOfK {
+                    n: 2,
+                    scripts: vec![
+                        pubkey_script(&[1854 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0], &[]),
+                        pubkey_script(&[], &[0x11; 28]),
+                        pubkey_script(&[], &[0x22; 28]),
+                    ],
+                })),
+            }),
+            stake_script: Some(pubkey_script(
+                &[1854 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                &[],
+            )),
+        }
+    }
+
+    /// Registers the scripts under the given name, bypassing the user confirmations.
+    pub fn register(
+        hal: &mut impl crate::hal::Hal,
+        params: &params::Params,
+        native_scripts: &NativeScripts,
+        name: &str,
+    ) {
+        let hash = get_hash(hal, Derivation::Ledger, params, native_scripts).unwrap();
+        hal.memory().multisig_set_by_hash(&hash, name).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::make_native_scripts;
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::keystore::testing::mock_unlocked;
+    use util::bip32::HARDENED;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(&make_native_scripts(), None).is_ok());
+        assert!(validate(&make_native_scripts(), Some(HARDENED)).is_ok());
+        // Wrong account.
+        assert!(validate(&make_native_scripts(), Some(HARDENED + 1)).is_err());
+
+        // No stake script.
+        let mut scripts = make_native_scripts();
+        scripts.stake_script = None;
+        assert!(validate(&scripts, None).is_ok());
+
+        // Missing payment script.
+        let mut scripts = make_native_scripts();
+        scripts.payment_script = None;
+        assert!(validate(&scripts, None).is_err());
+
+        let set_payment = |script: Script| -> NativeScripts {
+            let mut scripts = make_native_scripts();
+            scripts.payment_script = Some(CardanoNativeScript {
+                script: Some(script),
+            });
+            scripts
+        };
+        let own_key = || CardanoNativeScript {
+            script: Some(Script::Pubkey(PubKey {
+                keypath: vec![1854 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                key_hash: vec![],
+            })),
+        };
+        let cosigner = || CardanoNativeScript {
+            script: Some(Script::Pubkey(PubKey {
+                keypath: vec![],
+                key_hash: vec![0x11; 28],
+            })),
+        };
+
+        // Timelocks are allowed.
+        assert!(
+            validate(
+                &set_payment(Script::All(Scripts {
+                    scripts: vec![
+                        own_key(),
+                        CardanoNativeScript {
+                            script: Some(Script::InvalidHereafter(1000)),
+                        },
+                    ],
+                })),
+                None
+            )
+            .is_ok()
+        );
+
+        let invalid = [
+            // No key of this wallet.
+            Script::Any(Scripts {
+                scripts: vec![cosigner()],
+            }),
+            // Empty all/any.
+            Script::All(Scripts { scripts: vec![] }),
+            Script::Any(Scripts { scripts: vec![] }),
+            // n out of range.
+            Script::NOfK(NOfK {
+                n: 0,
+                scripts: vec![own_key(), cosigner()],
+            }),
+            Script::NOfK(NOfK {
+                n: 3,
+                scripts: vec![own_key(), cosigner()],
+            }),
+            // Wrong key hash size.
+            Script::Any(Scripts {
+                scripts: vec![
+                    own_key(),
+                    CardanoNativeScript {
+                        script: Some(Script::Pubkey(PubKey {
+                            keypath: vec![],
+                            key_hash: vec![0x11; 32],
+                        })),
+                    },
+                ],
+            }),
+            // Both keypath and key hash.
+            Script::Pubkey(PubKey {
+                keypath: vec![1854 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                key_hash: vec![0x11; 28],
+            }),
+            // Stake key in payment script.
+            Script::Pubkey(PubKey {
+                keypath: vec![1854 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                key_hash: vec![],
+            }),
+            // Shelley key instead of multisig key.
+            Script::Pubkey(PubKey {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                key_hash: vec![],
+            }),
+            // Keys of different accounts.
+            Script::All(Scripts {
+                scripts: vec![
+                    own_key(),
+                    CardanoNativeScript {
+                        script: Some(Script::Pubkey(PubKey {
+                            keypath: vec![1854 + HARDENED, 1815 + HARDENED, HARDENED + 1, 0, 0],
+                            key_hash: vec![],
+                        })),
+                    },
+                ],
+            }),
+            // Too many keys.
+            Script::Any(Scripts {
+                scripts: core::iter::once(own_key())
+                    .chain(core::iter::repeat_with(cosigner).take(MAX_KEYS))
+                    .collect(),
+            }),
+        ];
+        for script in invalid {
+            assert_eq!(
+                validate(&set_payment(script), None),
+                Err(Error::InvalidInput)
+            );
+        }
+
+        // Too deep.
+        let mut script = own_key();
+        for _ in 0..=MAX_DEPTH {
+            script = CardanoNativeScript {
+                script: Some(Script::All(Scripts {
+                    scripts: vec![script],
+                })),
+            };
+        }
+        assert_eq!(
+            validate(&set_payment(script.script.unwrap()), None),
+            Err(Error::InvalidInput)
+        );
+
+        // Payment key in stake script.
+        let mut scripts = make_native_scripts();
+        scripts.stake_script = Some(own_key());
+        assert_eq!(validate(&scripts, None), Err(Error::InvalidInput));
+    }
+
+    #[test]
+    fn test_script_hash() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let scripts = make_native_scripts();
+        assert_eq!(
+            hex::encode(
+                script_hash(
+                    &mut mock_hal,
+                    Derivation::Ledger,
+                    scripts.payment_script.as_ref().unwrap()
+                )
+                .unwrap()
+            ),
+            "375d57c89f550386ffab38fda514cc35a6b457d54fb12a80feb5f776",
+        );
+        assert_eq!(
+            hex::encode(
+                script_hash(
+                    &mut mock_hal,
+                    Derivation::Ledger,
+                    scripts.stake_script.as_ref().unwrap()
+                )
+                .unwrap()
+            ),
+            "f383af7bfc0f2db080a07fc99b2833e3a224bdbca917e3aa6c2534c5",
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let scripts = make_native_scripts();
+        let payment_script = scripts.payment_script.as_ref().unwrap();
+        assert_eq!(describe(payment_script).unwrap(), "2-of-3");
+        assert_eq!(
+            format(payment_script).unwrap(),
+            "atLeast(2, sig(m/1854'/1815'/0'/0/0), sig(11111111111111111111111111111111111111111111111111111111), sig(22222222222222222222222222222222222222222222222222222222))",
+        );
+        let timelock = CardanoNativeScript {
+            script: Some(Script::Any(Scripts {
+                scripts: vec![
+                    CardanoNativeScript {
+                        script: Some(Script::InvalidBefore(100)),
+                    },
+                    CardanoNativeScript {
+                        script: Some(Script::InvalidHereafter(200)),
+                    },
+                ],
+            })),
+        };
+        assert_eq!(describe(&timelock).unwrap(), "1-of-2");
+        assert_eq!(format(&timelock).unwrap(), "any(after(100), before(200))");
+    }
+}
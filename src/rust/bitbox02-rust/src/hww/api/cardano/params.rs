@@ -9,6 +9,8 @@ pub struct Params {
     pub unit: &'static str,
     /// https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0005/CIP-0005.md#miscellaneous
     pub bech32_hrp_payment: &'static str,
+    /// Same as above, for reward (stake) addresses.
+    pub bech32_hrp_stake: &'static str,
     /// https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0019/CIP-0019.md#network-tag
     pub network_id: u8,
     /// Protocol magic used in Byron addresses for non-mainnet chains.
@@ -20,6 +22,7 @@ const PARAMS_MAINNET: Params = Params {
     name: "Cardano",
     unit: "ADA",
     bech32_hrp_payment: "addr",
+    bech32_hrp_stake: "stake",
     network_id: 1,
     protocol_magic: None, // it is 764824073, but we don't need the actual value anywhere
 };
@@ -29,6 +32,7 @@ const PARAMS_TESTNET: Params = Params {
     name: "ADA testnet",
     unit: "TADA",
     bech32_hrp_payment: "addr_test",
+    bech32_hrp_stake: "stake_test",
     network_id: 0,
     protocol_magic: Some(1097911063),
 };
@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::params;
+use super::pb;
+
+use pb::CardanoNetwork;
+use pb::cardano_response::Response;
+use pb::cardano_script_config::{Config, NativeScripts};
+
+use crate::hal::{Memory, Ui};
+use crate::keystore::ed25519::Derivation;
+
+fn parse_registration(
+    registration: Option<&pb::CardanoScriptConfigRegistration>,
+) -> Result<(&'static params::Params, Derivation, &NativeScripts), Error> {
+    match registration {
+        Some(pb::CardanoScriptConfigRegistration {
+            network,
+            script_config:
+                Some(pb::CardanoScriptConfig {
+                    config: Some(Config::NativeScripts(native_scripts)),
+                }),
+            derivation_type,
+        }) => {
+            let network = CardanoNetwork::try_from(*network)?;
+            let derivation = super::get_derivation(*derivation_type)?;
+            Ok((params::get(network), derivation, native_scripts))
+        }
+        // Only native scripts can be registered.
+        _ => Err(Error::InvalidInput),
+    }
+}
+
+pub fn process_is_script_config_registered(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::CardanoIsScriptConfigRegisteredRequest,
+) -> Result<Response, Error> {
+    let (params, derivation, native_scripts) = parse_registration(request.registration.as_ref())?;
+    super::native_script::validate(native_scripts, None)?;
+    Ok(Response::IsScriptConfigRegistered(
+        pb::CardanoIsScriptConfigRegisteredResponse {
+            is_registered: super::native_script::get_name(hal, derivation, params, native_scripts)?
+                .is_some(),
+        },
+    ))
+}
+
+pub async fn process_register_script_config(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::CardanoRegisterScriptConfigRequest,
+) -> Result<Response, Error> {
+    let (params, derivation, native_scripts) = parse_registration(request.registration.as_ref())?;
+    super::native_script::validate(native_scripts, None)?;
+    let name = crate::workflow::account_name::get(hal, &request.name).await?;
+    super::native_script::confirm_extended(hal, "Register", params, &name, native_scripts).await?;
+    let hash = super::native_script::get_hash(hal, derivation, params, native_scripts)?;
+    hal.memory().multisig_set_by_hash(&hash, &name)?;
+    hal.ui().status("Multisig account\nregistered", true).await;
+    Ok(Response::Success(pb::CardanoSuccess {}))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::hww::api::cardano::native_script::testing::make_native_scripts;
+    use crate::keystore::testing::mock_unlocked;
+    use util::bb02_async::block_on;
+
+    fn make_registration(network: CardanoNetwork) -> pb::CardanoScriptConfigRegistration {
+        pb::CardanoScriptConfigRegistration {
+            network: network as _,
+            script_config: Some(pb::CardanoScriptConfig {
+                config: Some(Config::NativeScripts(make_native_scripts())),
+            }),
+            derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
+        }
+    }
+
+    fn is_registered(hal: &mut TestingHal, network: CardanoNetwork) -> Result<Response, Error> {
+        process_is_script_config_registered(
+            hal,
+            &pb::CardanoIsScriptConfigRegisteredRequest {
+                registration: Some(make_registration(network)),
+            },
+        )
+    }
+
+    #[test]
+    fn test_register() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            is_registered(&mut mock_hal, CardanoNetwork::CardanoMainnet),
+            Ok(Response::IsScriptConfigRegistered(
+                pb::CardanoIsScriptConfigRegisteredResponse {
+                    is_registered: false
+                }
+            ))
+        );
+
+        assert_eq!(
+            block_on(process_register_script_config(
+                &mut mock_hal,
+                &pb::CardanoRegisterScriptConfigRequest {
+                    registration: Some(make_registration(CardanoNetwork::CardanoMainnet)),
+                    name: "my multisig".into(),
+                }
+            )),
+            Ok(Response::Success(pb::CardanoSuccess {}))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Register".into(),
+                    body: "2-of-3\nCardano multisig".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Register".into(),
+                    body: "my multisig".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Payment script".into(),
+                    body: "atLeast(2, sig(m/1854'/1815'/0'/0/0), sig(11111111111111111111111111111111111111111111111111111111), sig(22222222222222222222222222222222222222222222222222222222))".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Stake script".into(),
+                    body: "sig(m/1854'/1815'/0'/2/0)".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Multisig account\nregistered".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        assert_eq!(
+            is_registered(&mut mock_hal, CardanoNetwork::CardanoMainnet),
+            Ok(Response::IsScriptConfigRegistered(
+                pb::CardanoIsScriptConfigRegisteredResponse {
+                    is_registered: true
+                }
+            ))
+        );
+        // The registration is network specific.
+        assert_eq!(
+            is_registered(&mut mock_hal, CardanoNetwork::CardanoTestnet),
+            Ok(Response::IsScriptConfigRegistered(
+                pb::CardanoIsScriptConfigRegisteredResponse {
+                    is_registered: false
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_register_invalid() {
+        mock_unlocked();
+        // Missing script config.
+        assert_eq!(
+            block_on(process_register_script_config(
+                &mut TestingHal::new(),
+                &pb::CardanoRegisterScriptConfigRequest {
+                    registration: Some(pb::CardanoScriptConfigRegistration {
+                        network: CardanoNetwork::CardanoMainnet as _,
+                        script_config: None,
+                        derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
+                    }),
+                    name: "my multisig".into(),
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+        // Invalid name.
+        assert_eq!(
+            block_on(process_register_script_config(
+                &mut TestingHal::new(),
+                &pb::CardanoRegisterScriptConfigRequest {
+                    registration: Some(make_registration(CardanoNetwork::CardanoMainnet)),
+                    name: "\n".into(),
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+        // User aborts.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.abort_nth(2);
+        assert_eq!(
+            block_on(process_register_script_config(
+                &mut mock_hal,
+                &pb::CardanoRegisterScriptConfigRequest {
+                    registration: Some(make_registration(CardanoNetwork::CardanoMainnet)),
+                    name: "my multisig".into(),
+                }
+            )),
+            Err(Error::UserAbort)
+        );
+        assert_eq!(
+            is_registered(&mut mock_hal, CardanoNetwork::CardanoMainnet),
+            Ok(Response::IsScriptConfigRegistered(
+                pb::CardanoIsScriptConfigRegisteredResponse {
+                    is_registered: false
+                }
+            ))
+        );
+    }
+}
@@ -0,0 +1,341 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::params;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::vec::Vec;
+
+use pb::CardanoNetwork;
+use pb::cardano_response::Response;
+use pb::cardano_script_config::Config;
+
+use crate::hal::Ui;
+use crate::keystore::ed25519;
+use crate::workflow::verify_message;
+
+use bitcoin::bech32;
+use minicbor::encode::Encoder;
+
+const MAX_PAYLOAD_SIZE: usize = 1024;
+
+/// COSE algorithm identifier of EdDSA.
+/// See https://www.iana.org/assignments/cose/cose.xhtml#algorithms
+const COSE_ALG_EDDSA: i8 = -8;
+
+/// Protected headers of the COSE_Sign1 structure. The address header is defined in CIP-8, see
+/// https://cips.cardano.org/cip/CIP-0008
+fn encode_protected_headers(address: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut encoded = Vec::new();
+    Encoder::new(&mut encoded)
+        .map(2)?
+        // alg
+        .u8(1)?
+        .i8(COSE_ALG_EDDSA)?
+        .str("address")?
+        .bytes(address)?;
+    Ok(encoded)
+}
+
+/// The Sig_structure is the data that is signed, see
+/// https://datatracker.ietf.org/doc/html/rfc8152#section-4.4
+fn encode_sig_structure(protected_headers: &[u8], payload: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut encoded = Vec::new();
+    Encoder::new(&mut encoded)
+        .array(4)?
+        .str("Signature1")?
+        .bytes(protected_headers)?
+        // external_aad
+        .bytes(&[])?
+        .bytes(payload)?;
+    Ok(encoded)
+}
+
+fn encode_cose_sign1(
+    protected_headers: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let mut encoded = Vec::new();
+    Encoder::new(&mut encoded)
+        .array(4)?
+        .bytes(protected_headers)?
+        // unprotected headers
+        .map(1)?
+        .str("hashed")?
+        .bool(false)?
+        .bytes(payload)?
+        .bytes(signature)?;
+    Ok(encoded)
+}
+
+/// See https://datatracker.ietf.org/doc/html/rfc8152#section-13.2
+fn encode_cose_key(public_key: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut encoded = Vec::new();
+    Encoder::new(&mut encoded)
+        .map(4)?
+        // kty: OKP
+        .u8(1)?
+        .u8(1)?
+        // alg
+        .u8(3)?
+        .i8(COSE_ALG_EDDSA)?
+        // crv: Ed25519
+        .i8(-1)?
+        .u8(6)?
+        // x: public key
+        .i8(-2)?
+        .bytes(public_key)?;
+    Ok(encoded)
+}
+
+/// Signs a message according to CIP-8 with the payment key of a base address or with a stake key.
+///
+/// The result contains the CBOR encoded COSE_Sign1 structure and the COSE_Key of the signing key.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::CardanoSignMessageRequest,
+) -> Result<Response, Error> {
+    let network = CardanoNetwork::try_from(request.network)?;
+    let params = params::get(network);
+    let derivation = super::get_derivation(request.derivation_type)?;
+    if request.payload.len() > MAX_PAYLOAD_SIZE {
+        return Err(Error::InvalidInput);
+    }
+
+    let (keypath, address_bytes, hrp): (&[u32], Vec<u8>, &str) =
+        match (&request.script_config, request.stake_keypath.is_empty()) {
+            (
+                Some(pb::CardanoScriptConfig {
+                    config: Some(config @ Config::PkhSkh(pkh_skh)),
+                }),
+                true,
+            ) => (
+                &pkh_skh.keypath_payment,
+                super::address::validate_and_get_payment_address_bytes(
+                    hal, derivation, params, config, None,
+                )?,
+                params.bech32_hrp_payment,
+            ),
+            (None, false) => {
+                super::keypath::validate_address_shelley_stake(&request.stake_keypath, None)?;
+                (
+                    &request.stake_keypath,
+                    super::address::reward_address_bytes(
+                        hal,
+                        derivation,
+                        params,
+                        &request.stake_keypath,
+                    )?,
+                    params.bech32_hrp_stake,
+                )
+            }
+            _ => return Err(Error::InvalidInput),
+        };
+    let address =
+        bech32::encode::<bech32::Bech32>(bech32::Hrp::parse_unchecked(hrp), &address_bytes)
+            .unwrap();
+
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Sign message",
+            body: &format!("Coin: {}", params.name),
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Address",
+            body: &address,
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+
+    verify_message::verify(hal, "Sign message", "Sign", &request.payload, true).await?;
+
+    let protected_headers = encode_protected_headers(&address_bytes)?;
+    let sig_structure = encode_sig_structure(&protected_headers, &request.payload)?;
+    let sign_result = ed25519::sign(hal, derivation, keypath, &sig_structure)?;
+
+    Ok(Response::SignMessage(pb::CardanoSignMessageResponse {
+        signature: encode_cose_sign1(&protected_headers, &request.payload, &sign_result.signature)?,
+        key: encode_cose_key(sign_result.public_key.as_bytes())?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked;
+    use alloc::boxed::Box;
+    use hex_lit::hex;
+    use util::bb02_async::block_on;
+    use util::bip32::HARDENED;
+
+    fn make_pkh_skh() -> pb::CardanoScriptConfig {
+        pb::CardanoScriptConfig {
+            config: Some(Config::PkhSkh(pb::cardano_script_config::PkhSkh {
+                keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+            })),
+        }
+    }
+
+    #[test]
+    fn test_sign_payment_address() {
+        const ADDRESS: &str = "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs";
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(
+            &mut mock_hal,
+            &pb::CardanoSignMessageRequest {
+                network: CardanoNetwork::CardanoMainnet as _,
+                script_config: Some(make_pkh_skh()),
+                stake_keypath: vec![],
+                payload: b"hello world".to_vec(),
+                derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
+            },
+        ))
+        .unwrap();
+        assert_eq!(
+            result,
+            Response::SignMessage(pb::CardanoSignMessageResponse {
+                signature: hex!("845846a2012767616464726573735839015ebfc2cdaeef4b4f1be7fcc31cfe945eb92d28674349bd0f1a4a0063c29ee6d1b6f9f0dcc059d4c7b60986bd8913f9b39ab253efd1bc9a18a166686173686564f44b68656c6c6f20776f726c64584054ed09db8a94ab8ea2a8b62193545181e2189b3dc252caed69c973580b098f1bd68b1a054c63e39ef9d61b43622739687cbf5463d134aaae4f02c8de6a28e009").to_vec(),
+                key: hex!("a40101032720062158201f17afffe805297f8ec6544582b7ea91c30dc1f9119c5c2b263e58fa3659317d").to_vec(),
+            })
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Sign message".into(),
+                    body: "Coin: Cardano".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Address".into(),
+                    body: ADDRESS.into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Sign message".into(),
+                    body: "hello world".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sign_stake_address() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(
+            &mut mock_hal,
+            &pb::CardanoSignMessageRequest {
+                network: CardanoNetwork::CardanoTestnet as _,
+                script_config: None,
+                stake_keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                payload: b"\x00\x01\x02".to_vec(),
+                derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
+            },
+        ))
+        .unwrap();
+        assert_eq!(
+            result,
+            Response::SignMessage(pb::CardanoSignMessageResponse {
+                signature: hex!("84582aa201276761646472657373581de0c29ee6d1b6f9f0dcc059d4c7b60986bd8913f9b39ab253efd1bc9a18a166686173686564f4430001025840ca3a1be5f785009a6c8188e33042c6f61da99a5960878de4a456bc2cdb61d9adc83fe9298eac96a60851c90d2c6966b3c957a3d244f9afc4843fcdcf0c633d03").to_vec(),
+                key: hex!("a4010103272006215820b0dc7313cabf4a4b071514f486d0d99775864e7377700fb99398b3f823010660").to_vec(),
+            })
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Sign message".into(),
+                    body: "Coin: ADA testnet".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Address".into(),
+                    body: "stake_test1urpfaek3kmulphxqt82v0dsfs67cjylekwdty5l06x7f5xq5y5cdq".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Sign message\ndata (hex)".into(),
+                    body: "000102".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sign_invalid() {
+        mock_unlocked();
+        let valid = pb::CardanoSignMessageRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            script_config: Some(make_pkh_skh()),
+            stake_keypath: vec![],
+            payload: b"hello world".to_vec(),
+            derivation_type: pb::CardanoDerivationType::CardanoDerivationLedger as _,
+        };
+        let invalid_requests = [
+            // Neither address nor stake key.
+            pb::CardanoSignMessageRequest {
+                script_config: None,
+                ..valid.clone()
+            },
+            // Both address and stake key.
+            pb::CardanoSignMessageRequest {
+                stake_keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                ..valid.clone()
+            },
+            // Script addresses can't sign.
+            pb::CardanoSignMessageRequest {
+                script_config: Some(pb::CardanoScriptConfig {
+                    config: Some(Config::NativeScripts(
+                        super::super::native_script::testing::make_native_scripts(),
+                    )),
+                }),
+                ..valid.clone()
+            },
+            // Invalid stake keypath.
+            pb::CardanoSignMessageRequest {
+                script_config: None,
+                stake_keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                ..valid.clone()
+            },
+            // Payload too large.
+            pb::CardanoSignMessageRequest {
+                payload: vec![b'a'; MAX_PAYLOAD_SIZE + 1],
+                ..valid.clone()
+            },
+            // Empty payload.
+            pb::CardanoSignMessageRequest {
+                payload: vec![],
+                ..valid.clone()
+            },
+        ];
+        for request in invalid_requests.iter() {
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), request)),
+                Err(Error::InvalidInput)
+            );
+        }
+
+        // User aborts.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.abort_nth(2);
+        assert_eq!(
+            block_on(process(&mut mock_hal, &valid)),
+            Err(Error::UserAbort)
+        );
+    }
+}
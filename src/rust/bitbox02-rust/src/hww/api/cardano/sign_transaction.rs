@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::hal::ui::ConfirmParams;
+mod auxiliary_data;
 mod cbor;
 mod certificates;
+mod governance;
+mod plutus;
 
 use super::Error;
 use super::pb;
@@ -49,10 +52,11 @@ fn format_value(params: &params::Params, value: u64) -> String {
 
 fn make_shelley_witness(
     hal: &mut impl crate::hal::Hal,
+    derivation: ed25519::Derivation,
     keypath: &[u32],
     tx_body_hash: &[u8; 32],
 ) -> Result<ShelleyWitness, ()> {
-    let result = ed25519::sign(hal, keypath, tx_body_hash)?;
+    let result = ed25519::sign(hal, derivation, keypath, tx_body_hash)?;
     Ok(ShelleyWitness {
         public_key: result.public_key.as_ref().to_vec(),
         signature: result.signature.to_vec(),
@@ -122,12 +126,40 @@ fn validate_asset_groups(
     Ok(())
 }
 
+/// Validate size limits in the mint field, that no amount is zero and that there are no duplicate
+/// assets.
+fn validate_mint(
+    mint: &[pb::cardano_sign_transaction_request::MintAssetGroup],
+) -> Result<(), Error> {
+    let mut token_keys: Vec<(&[u8], &[u8])> = Vec::new();
+    for asset_group in mint.iter() {
+        if asset_group.policy_id.len() != 28 || asset_group.tokens.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        for token in asset_group.tokens.iter() {
+            if token.asset_name.len() > 32 || token.value == 0 {
+                return Err(Error::InvalidInput);
+            }
+            let token_key = (
+                asset_group.policy_id.as_slice(),
+                token.asset_name.as_slice(),
+            );
+            if token_keys.contains(&token_key) {
+                return Err(Error::InvalidInput);
+            }
+            token_keys.push(token_key);
+        }
+    }
+    Ok(())
+}
+
 async fn _process(
     hal: &mut impl crate::hal::Hal,
     request: &pb::CardanoSignTransactionRequest,
 ) -> Result<Response, Error> {
     let network = CardanoNetwork::try_from(request.network)?;
     let params = params::get(network);
+    let derivation = super::get_derivation(request.derivation_type)?;
     if request.inputs.is_empty() {
         return Err(Error::InvalidInput);
     }
@@ -135,8 +167,13 @@ async fn _process(
     // change.
 
     // Validate that all keypaths (inputs and change outputs, certificates and withdrawals) have the
-    // same account element.
-    let bip44_account: u32 = *request.inputs[0]
+    // same account element. Inputs locked by a script have no keypath.
+    let bip44_account: u32 = *request
+        .inputs
+        .iter()
+        .chain(request.collateral_inputs.iter())
+        .find(|input| !input.keypath.is_empty())
+        .ok_or(Error::InvalidInput)?
         .keypath
         .get(2)
         .ok_or(Error::InvalidInput)?;
@@ -146,7 +183,14 @@ async fn _process(
     let mut signing_keypaths: Vec<&[u32]> = Vec::new();
 
     for input in request.inputs.iter() {
-        super::keypath::validate_address_shelley_payment(&input.keypath, Some(bip44_account))?;
+        if input.keypath.is_empty() && !request.script_data_hash.is_empty() {
+            continue;
+        }
+        // Inputs of multisig addresses are signed with the multisig payment key of this wallet.
+        super::keypath::validate_address_shelley_payment(&input.keypath, Some(bip44_account))
+            .or_else(|_| {
+                super::keypath::validate_multisig_payment(&input.keypath, Some(bip44_account))
+            })?;
         signing_keypaths.push(&input.keypath);
     }
 
@@ -213,6 +257,17 @@ async fn _process(
         signing_keypaths.push(&withdrawal.keypath);
     }
 
+    if let Some(ref voting_procedures) = request.voting_procedures {
+        governance::verify_voting_procedures(
+            hal,
+            params,
+            voting_procedures,
+            bip44_account,
+            &mut signing_keypaths,
+        )
+        .await?;
+    }
+
     let mut total: u64 = 0;
 
     for output in request.outputs.iter() {
@@ -220,13 +275,40 @@ async fn _process(
 
         validate_asset_groups(&output.asset_groups)?;
 
+        let has_datum = !output.datum_hash.is_empty() || !output.inline_datum.is_empty();
+        if (!output.datum_hash.is_empty() && !output.inline_datum.is_empty())
+            || (!output.datum_hash.is_empty() && output.datum_hash.len() != 32)
+            || (has_datum && output.script_config.is_some())
+        {
+            return Err(Error::InvalidInput);
+        }
+
         match output.script_config {
             Some(ref script_config) => match script_config {
                 CardanoScriptConfig {
                     config: Some(config),
                 } => {
+                    // Change is not shown, so it must be fully controlled by the account, including
+                    // the staking rights. Enterprise addresses, including native script addresses
+                    // without a stake script, have no stake part and pointer addresses refer to a
+                    // stake registration the device can't verify. They can be sent to as regular
+                    // outputs, which are shown.
+                    if matches!(
+                        config,
+                        pb::cardano_script_config::Config::Pkh(_)
+                            | pb::cardano_script_config::Config::PkhPointer(_)
+                            | pb::cardano_script_config::Config::NativeScripts(
+                                pb::cardano_script_config::NativeScripts {
+                                    stake_script: None,
+                                    ..
+                                }
+                            )
+                    ) {
+                        return Err(Error::InvalidInput);
+                    }
                     let encoded_address = super::address::validate_and_encode_payment_address(
                         hal,
+                        derivation,
                         params,
                         config,
                         Some(bip44_account),
@@ -261,10 +343,87 @@ async fn _process(
                             .await?;
                     }
                 }
+
+                if has_datum {
+                    let body = if output.datum_hash.is_empty() {
+                        format!("Inline datum\n{} bytes", output.inline_datum.len())
+                    } else {
+                        format!("Datum hash\n{}", hex::encode(&output.datum_hash))
+                    };
+                    hal.ui()
+                        .confirm(&ConfirmParams {
+                            title: "Datum",
+                            body: &body,
+                            accept_is_nextarrow: true,
+                            scrollable: true,
+                            ..Default::default()
+                        })
+                        .await?;
+                }
             }
         }
     }
 
+    plutus::verify(
+        hal,
+        derivation,
+        params,
+        request,
+        bip44_account,
+        &mut signing_keypaths,
+    )
+    .await?;
+
+    validate_mint(&request.mint)?;
+    for asset_group in request.mint.iter() {
+        for token in asset_group.tokens.iter() {
+            let (title, sign) = if token.value > 0 {
+                ("Mint token", "")
+            } else {
+                ("Burn token", "-")
+            };
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title,
+                    body: &format!(
+                        "Amount: {}{}. Asset: {}. Policy: {}",
+                        sign,
+                        util::decimal::format(token.value.unsigned_abs(), 0),
+                        format_asset(&asset_group.policy_id, &token.asset_name),
+                        hex::encode(&asset_group.policy_id),
+                    ),
+                    accept_is_nextarrow: true,
+                    scrollable: true,
+                    ..Default::default()
+                })
+                .await?;
+        }
+    }
+    // Policy keys can only be used to mint or burn tokens.
+    if !request.policy_keypaths.is_empty() && request.mint.is_empty() {
+        return Err(Error::InvalidInput);
+    }
+    for pb::Keypath { keypath } in request.policy_keypaths.iter() {
+        super::keypath::validate_policy(keypath)?;
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: params.name,
+                body: &format!(
+                    "Sign with minting policy key #{}?",
+                    keypath[2] + 1 - HARDENED
+                ),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+        signing_keypaths.push(keypath);
+    }
+
+    if let Some(ref data) = request.auxiliary_data {
+        auxiliary_data::verify(hal, derivation, params, data, bip44_account).await?;
+    }
+
     if total == 0 {
         hal.ui()
             .confirm(&ConfirmParams {
@@ -287,9 +446,22 @@ async fn _process(
 
     hal.ui().status("Transaction\nconfirmed", true).await;
 
+    let auxiliary_data_supplement = match request.auxiliary_data {
+        Some(ref data) => Some(auxiliary_data::finalize(hal, derivation, params, data)?),
+        None => None,
+    };
+
     let tx_body_hash: [u8; 32] = {
         let mut hasher = Blake2bVar::new(32).unwrap();
-        cbor::encode_transaction_body(hal, request, cbor::HashedWriter::new(&mut hasher))?;
+        cbor::encode_transaction_body(
+            hal,
+            derivation,
+            request,
+            auxiliary_data_supplement
+                .as_ref()
+                .map(|supplement| supplement.auxiliary_data_hash.as_slice()),
+            cbor::HashedWriter::new(&mut hasher),
+        )?;
 
         let mut out = [0u8; 32];
         hasher.finalize_variable(&mut out).or(Err(Error::Generic))?;
@@ -301,11 +473,19 @@ async fn _process(
 
     let mut shelley_witnesses: Vec<ShelleyWitness> = Vec::with_capacity(signing_keypaths.len());
     for keypath in signing_keypaths {
-        shelley_witnesses.push(make_shelley_witness(hal, keypath, &tx_body_hash)?);
+        shelley_witnesses.push(make_shelley_witness(
+            hal,
+            derivation,
+            keypath,
+            &tx_body_hash,
+        )?);
     }
 
     Ok(Response::SignTransaction(
-        pb::CardanoSignTransactionResponse { shelley_witnesses },
+        pb::CardanoSignTransactionResponse {
+            shelley_witnesses,
+            auxiliary_data_supplement,
+        },
     ))
 }
 
@@ -395,6 +575,8 @@ mod tests {
                     value: 1000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 pb::cardano_sign_transaction_request::Output {
                     // Byron Yoroi style address
@@ -402,6 +584,8 @@ mod tests {
                     value: 2000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 pb::cardano_sign_transaction_request::Output {
                     // Byron Dadedalus style address
@@ -409,6 +593,8 @@ mod tests {
                     value: 3000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -421,6 +607,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -438,7 +626,8 @@ mod tests {
                 shelley_witnesses: vec![ShelleyWitness {
                     public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
                     signature: b"\xf2\x8c\xf3\xe9\x03\x9f\x09\xcf\x16\x7b\xbd\x60\xff\xc6\xcc\xaf\x39\x44\x19\x39\x0f\x26\x76\x2e\x1f\x45\x05\xd2\x31\x9d\x89\xd8\xaa\x5f\x38\x93\xc0\x0b\xb7\xef\x27\xaf\x15\x5b\xaa\xf0\xad\x16\xd6\x86\x90\x9a\x3a\xc0\x96\x5d\xd1\x76\x72\x23\x38\xa6\xff\x07".to_vec(),
-                }]
+                }],
+                auxiliary_data_supplement: None,
             })
         );
         const RECIPIENT1: &str = "addr1q9qfllpxg2vu4lq6rnpel4pvpp5xnv3kvvgtxk6k6wp4ff89xrhu8jnu3p33vnctc9eklee5dtykzyag5penc6dcmakqsqqgpt";
@@ -505,6 +694,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 191681,
@@ -522,6 +713,7 @@ mod tests {
                         certificate::StakeDelegation{
                             keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
                             pool_keyhash: b"\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab\xab".to_vec(),
+                            deposit: None,
                         }
                     )),
                 },
@@ -549,7 +741,8 @@ mod tests {
                         public_key: b"\xb0\xdc\x73\x13\xca\xbf\x4a\x4b\x07\x15\x14\xf4\x86\xd0\xd9\x97\x75\x86\x4e\x73\x77\x70\x0f\xb9\x93\x98\xb3\xf8\x23\x01\x06\x60".to_vec(),
                         signature: b"\x8d\x13\x38\x70\xd5\xa2\x57\x32\x83\x26\x8b\x78\x0c\xdc\x21\xf5\xce\x33\xda\xfe\xe9\x9a\x76\xf2\x5f\x64\x73\x1b\xac\x07\x86\xe9\xd6\x8c\x8e\xdb\x29\x9b\xc7\x17\xdb\x26\xcf\xb8\x35\x00\x6d\x95\xfc\xbd\x74\x3e\x8b\xcd\x55\xae\x85\x78\x9b\x01\xd2\x70\xee\x0a".to_vec(),
                     },
-                ]
+                ],
+                auxiliary_data_supplement: None,
             })
         );
         assert_eq!(
@@ -611,6 +804,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 191681,
@@ -646,7 +841,8 @@ mod tests {
                         public_key: b"\xb0\xdc\x73\x13\xca\xbf\x4a\x4b\x07\x15\x14\xf4\x86\xd0\xd9\x97\x75\x86\x4e\x73\x77\x70\x0f\xb9\x93\x98\xb3\xf8\x23\x01\x06\x60".to_vec(),
                         signature: b"\xbf\xce\x07\x7a\xbd\xf7\x3b\xba\xc2\xaf\x1b\x09\x16\x2e\x25\x15\x9a\x8b\xb2\xbb\xe6\x2e\x98\xbc\xaf\xea\x73\xe0\x51\xca\x54\xe0\x8b\x49\xa1\x22\xde\xba\x54\xbb\x2c\xed\xeb\x78\xa8\x7c\x09\x1e\x64\x26\x5f\x84\x73\x8b\xd6\xf6\xfa\xd0\xee\x81\x75\x14\x11\x03".to_vec(),
                     },
-                ]
+                ],
+                auxiliary_data_supplement: None,
             })
         );
         assert_eq!(
@@ -698,6 +894,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 191681,
@@ -709,6 +907,7 @@ mod tests {
                             keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
                             r#type: certificate::vote_delegation::CardanoDRepType::AlwaysAbstain.into(),
                             drep_credhash: None,
+                            deposit: None,
                         }
                     )),
                 },
@@ -732,7 +931,8 @@ mod tests {
                         public_key: b"\xb0\xdc\x73\x13\xca\xbf\x4a\x4b\x07\x15\x14\xf4\x86\xd0\xd9\x97\x75\x86\x4e\x73\x77\x70\x0f\xb9\x93\x98\xb3\xf8\x23\x01\x06\x60".to_vec(),
                         signature: b"\x6f\x32\x48\x4a\x17\x99\xf3\xcc\x4f\xd9\xc5\xd8\x5c\x10\xa7\xdb\xb0\x01\xf9\xa3\x37\xb8\x3c\x23\xc6\x6e\x19\xa8\x94\xc9\x17\xbc\x93\xff\x60\xf5\x4a\x48\x17\xfc\xb3\x34\x32\x37\x49\xdf\x86\x5b\xa1\xdd\xe0\x3c\xfd\xd4\x89\xcb\x3e\xdc\xab\xe5\xd9\xcc\xa6\x08".to_vec(),
                     },
-                ]
+                ],
+                auxiliary_data_supplement: None,
             })
         );
         assert_eq!(
@@ -784,6 +984,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 175157,
@@ -813,7 +1015,8 @@ mod tests {
                         public_key: b"\xb0\xdc\x73\x13\xca\xbf\x4a\x4b\x07\x15\x14\xf4\x86\xd0\xd9\x97\x75\x86\x4e\x73\x77\x70\x0f\xb9\x93\x98\xb3\xf8\x23\x01\x06\x60".to_vec(),
                         signature: b"\xc7\xd9\xf4\x88\xab\x46\xc8\x33\x11\xd5\x29\x51\x00\xe8\xef\x6f\x8f\xd7\x8b\xb9\x1f\xb7\xa4\x29\x06\xde\x39\xad\xa0\x6d\x57\x19\xff\x8e\x5a\xef\x3d\xeb\xb3\x9e\x9a\x41\x4c\x96\x0d\x2b\x6d\x8e\x31\xa3\x78\xd3\x97\xaa\x19\xe9\x13\x33\x7d\xc6\xfd\x8b\x0c\x08".to_vec(),
                     },
-                ]
+                ],
+                auxiliary_data_supplement: None,
             })
         );
         assert_eq!(
@@ -858,6 +1061,8 @@ mod tests {
                     value: 1000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -870,6 +1075,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -884,7 +1091,8 @@ mod tests {
                 shelley_witnesses: vec![ShelleyWitness {
                     public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
                     signature: b"\x05\xc0\x20\x83\xd8\x91\x48\xdf\xb5\x55\x87\x46\x6f\x76\xbf\xfa\x4a\x26\x90\x4b\xe2\x0d\x04\x61\x04\x8a\x81\xbc\x01\x64\xf4\x15\xd7\xa4\xae\x4c\x50\xde\x10\x06\x16\xac\x39\xb6\x79\x00\x2b\x7f\xa8\xd6\xa5\x7f\x68\x80\xfa\xd6\x5e\xb4\x37\xc3\xed\x94\xe3\x0f".to_vec(),
-                }]
+                }],
+                auxiliary_data_supplement: None,
             })
         );
     }
@@ -907,6 +1115,8 @@ mod tests {
                     value: 1000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -919,6 +1129,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -937,7 +1149,8 @@ mod tests {
                 shelley_witnesses: vec![ShelleyWitness {
                     public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
                     signature: b"\x5b\xa3\xc8\x1f\x57\xac\x0c\xb2\x49\x36\xc3\xc6\x7c\xb5\x1e\x86\x7f\xda\x7d\x95\xb4\x57\x22\x59\xbe\x9a\x06\xd0\xb1\x0c\xd4\x3b\x2e\x90\xd5\x32\xd0\x6b\x46\xd0\x5b\x23\x85\xe9\x03\x50\xaf\x2d\x9d\xb1\xc3\x9f\x39\xbf\xe3\x6b\x79\x25\x4e\xcb\xd3\x59\x1b\x0e".to_vec(),
-                }]
+                }],
+                auxiliary_data_supplement: None,
             })
         );
         assert_eq!(
@@ -985,6 +1198,8 @@ mod tests {
                     value: 1000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -997,6 +1212,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -1032,6 +1249,8 @@ mod tests {
                     value: 1000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -1044,6 +1263,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -1104,6 +1325,8 @@ mod tests {
                             ],
                         },
                     ],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -1126,6 +1349,8 @@ mod tests {
                             ],
                         },
                     ],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -1142,7 +1367,8 @@ mod tests {
                 shelley_witnesses: vec![ShelleyWitness {
                     public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
                     signature: b"\xfe\xdd\x2d\xdf\x9d\x00\x69\xe9\xb4\xb6\x11\x83\xae\xdd\xb3\xbb\xe7\x02\x19\x0e\xa5\x8d\x4a\x23\x25\xef\xa2\x2b\xf0\xd6\x32\x5a\x82\x89\x10\x53\xa7\x6b\x6a\x2e\xce\x2d\xf2\xd2\x2a\x6b\x65\x78\x07\x42\xa1\x9f\x27\x61\x18\xee\x68\x34\xa0\x05\x2e\xf9\xa4\x08".to_vec(),
-                }]
+                }],
+                auxiliary_data_supplement: None,
             })
         );
         assert_eq!(
@@ -1201,6 +1427,8 @@ mod tests {
                     value: 1000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -1213,6 +1441,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -1230,6 +1460,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sign_enterprise_output() {
+        let change_config = |config| CardanoScriptConfig {
+            config: Some(config),
+        };
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![pb::cardano_sign_transaction_request::Input {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                prev_out_index: 0,
+            }],
+            outputs: vec![
+                pb::cardano_sign_transaction_request::Output {
+                    encoded_address: "addr1q9qfllpxg2vu4lq6rnpel4pvpp5xnv3kvvgtxk6k6wp4ff89xrhu8jnu3p33vnctc9eklee5dtykzyag5penc6dcmakqsqqgpt".into(),
+                    value: 10000000,
+                    ..Default::default()
+                },
+                // Enterprise address of the account, not marked as change.
+                pb::cardano_sign_transaction_request::Output {
+                    encoded_address: "addr1vx23jd40tm6tmeu52shlkx367vhep2vnsj5h7ev7jh4ecpcgpxkdf".into(),
+                    value: 4829501,
+                    ..Default::default()
+                },
+            ],
+            fee: 170499,
+            ttl: 41115811,
+            ..Default::default()
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        assert_eq!(
+            result,
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses: vec![ShelleyWitness {
+                    public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
+                    signature: b"\x1f\x6a\x80\x37\x49\xc7\x74\xa5\x25\x74\xb8\x2e\xb0\x46\x01\xe0\xe1\x04\x9d\xec\xa1\xcb\x78\xc0\x7e\x51\xa6\x20\xec\x06\x23\x6e\x88\xb6\xdb\x32\xcb\xe1\xc8\x6f\xc5\xca\xda\x18\x11\x1a\x23\xd2\x9d\xb8\xf9\xac\x3d\x8b\x83\x38\x0c\xb5\x73\x51\x73\xe6\xe4\x06".to_vec(),
+                }],
+                auxiliary_data_supplement: None,
+            })
+        );
+        // Both outputs are shown.
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "addr1q9qfllpxg2vu4lq6rnpel4pvpp5xnv3kvvgtxk6k6wp4ff89xrhu8jnu3p33vnctc9eklee5dtykzyag5penc6dcmakqsqqgpt".into(),
+                    amount: "10 ADA".into(),
+                },
+                Screen::Recipient {
+                    recipient: "addr1vx23jd40tm6tmeu52shlkx367vhep2vnsj5h7ev7jh4ecpcgpxkdf".into(),
+                    amount: "4.829501 ADA".into(),
+                },
+                Screen::TotalFee {
+                    total: "15 ADA".into(),
+                    fee: "0.170499 ADA".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                }
+            ]
+        );
+
+        let invalid_change_configs = [
+            // Enterprise addresses have no stake part and are not accepted as change.
+            pb::cardano_script_config::Config::Pkh(pb::cardano_script_config::Pkh {
+                keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 1, 0],
+            }),
+            // Pointer addresses refer to a stake registration that can't be verified.
+            pb::cardano_script_config::Config::PkhPointer(pb::cardano_script_config::PkhPointer {
+                keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 1, 0],
+                pointer: Some(pb::cardano_script_config::Pointer {
+                    slot: 2498243,
+                    tx_index: 27,
+                    cert_index: 3,
+                }),
+            }),
+            // Reward addresses can't receive outputs.
+            pb::cardano_script_config::Config::Skh(pb::cardano_script_config::Skh {
+                keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+            }),
+        ];
+        for config in invalid_change_configs {
+            let mut tx = tx.clone();
+            tx.outputs[1].script_config = Some(change_config(config));
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), &tx)),
+                Err(Error::InvalidInput)
+            );
+        }
+    }
+
     #[test]
     fn test_sign_tx_tag_cbor_sets() {
         let tx = pb::CardanoSignTransactionRequest {
@@ -1245,6 +1576,8 @@ mod tests {
                     value: 1000000,
                     script_config: None,
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
                 // change
                 pb::cardano_sign_transaction_request::Output {
@@ -1257,6 +1590,8 @@ mod tests {
                         }))
                     }),
                     asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
                 },
             ],
             fee: 170499,
@@ -1271,7 +1606,8 @@ mod tests {
                 shelley_witnesses: vec![ShelleyWitness {
                     public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
                     signature: b"\xa1\x53\x67\x4e\xa7\x65\xf3\x49\x27\x5d\x3f\xe4\x76\x01\x0a\x17\x5f\xbb\x73\xa1\x81\x21\x04\x71\x8f\xb8\xd0\x6d\xb4\x6a\xf7\x69\x46\x85\x56\x49\x36\x86\x54\xb8\x6b\x41\x9e\x65\x5c\xfe\x6f\xda\x67\xeb\x1f\x6a\xab\x40\xf1\xff\xdf\xcc\x6c\x3e\x93\x39\xa7\x07".to_vec(),
-                }]
+                }],
+                auxiliary_data_supplement: None,
             })
         );
     }
@@ -1381,4 +1717,1266 @@ mod tests {
                 ],
             }]).is_ok());
     }
+
+    #[test]
+    fn test_sign_cip20_message() {
+        use pb::cardano_sign_transaction_request::{AuxiliaryData, auxiliary_data};
+
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![pb::cardano_sign_transaction_request::Input {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                prev_out_index: 0,
+            }],
+            outputs: vec![pb::cardano_sign_transaction_request::Output {
+                encoded_address: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                value: 4829501,
+                script_config: Some(CardanoScriptConfig {
+                    config: Some(pb::cardano_script_config::Config::PkhSkh(
+                        pb::cardano_script_config::PkhSkh {
+                            keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                            keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                        },
+                    )),
+                }),
+                asset_groups: vec![],
+                datum_hash: vec![],
+                inline_datum: vec![],
+            }],
+            fee: 170499,
+            ttl: 41115811,
+            auxiliary_data: Some(AuxiliaryData {
+                data: Some(auxiliary_data::Data::Cip20Message(
+                    auxiliary_data::Cip20Message {
+                        lines: vec!["Invoice 1234".into(), "Thank you!".into()],
+                    },
+                )),
+            }),
+            ..Default::default()
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        assert_eq!(
+            result,
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses: vec![ShelleyWitness {
+                    public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
+                    signature: b"\xe3\x2f\x68\xd8\xa1\xee\x14\x08\x83\xdd\x9b\x12\x7d\xab\xf9\x9c\x9f\x72\x5b\x2e\xce\x72\xe1\xcc\x38\x68\x07\xbb\xbb\x09\x6f\xda\x00\xe4\x7c\xe0\x29\x76\x4f\xc3\xbc\xf0\x25\xee\x5d\x33\x6d\xf8\x71\xc1\x34\x23\x72\xd1\xac\xa1\x94\x7b\xe3\x33\x9f\x05\x04\x0f".to_vec(),
+                }],
+                auxiliary_data_supplement: Some(
+                    pb::cardano_sign_transaction_response::AuxiliaryDataSupplement {
+                        auxiliary_data_hash: b"\xfd\x5a\x5e\x62\x1b\x70\x00\xef\x04\x32\x25\x3d\xd4\x58\xa1\x00\xd4\xbb\xaf\x5a\x76\x18\x5c\x71\x27\xde\xbd\x4f\x4b\x22\x3a\xb0".to_vec(),
+                        cip36_signature: vec![],
+                    }
+                ),
+            })
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Message".into(),
+                    body: "Invoice 1234\nThank you!".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Fee\n0.170499 ADA".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        // Lines longer than 64 bytes are invalid.
+        let tx = pb::CardanoSignTransactionRequest {
+            auxiliary_data: Some(AuxiliaryData {
+                data: Some(auxiliary_data::Data::Cip20Message(
+                    auxiliary_data::Cip20Message {
+                        lines: vec!["a".repeat(65)],
+                    },
+                )),
+            }),
+            ..tx.clone()
+        };
+        assert_eq!(
+            block_on(process(&mut TestingHal::new(), &tx)),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_sign_cip36_registration() {
+        use auxiliary_data::cip36_registration::Delegation;
+        use pb::cardano_sign_transaction_request::{AuxiliaryData, auxiliary_data};
+
+        let registration = auxiliary_data::Cip36Registration {
+            delegations: vec![
+                Delegation {
+                    vote_key: vec![0x11; 32],
+                    weight: 1,
+                },
+                Delegation {
+                    vote_key: vec![0x22; 32],
+                    weight: 3,
+                },
+            ],
+            stake_keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+            payment_address: Some(CardanoScriptConfig {
+                config: Some(pb::cardano_script_config::Config::PkhSkh(
+                    pb::cardano_script_config::PkhSkh {
+                        keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                        keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                    },
+                )),
+            }),
+            nonce: 41115811,
+            voting_purpose: 0,
+        };
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![pb::cardano_sign_transaction_request::Input {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                prev_out_index: 0,
+            }],
+            outputs: vec![pb::cardano_sign_transaction_request::Output {
+                encoded_address: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                value: 4829501,
+                script_config: Some(CardanoScriptConfig {
+                    config: Some(pb::cardano_script_config::Config::PkhSkh(
+                        pb::cardano_script_config::PkhSkh {
+                            keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                            keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                        },
+                    )),
+                }),
+                asset_groups: vec![],
+                datum_hash: vec![],
+                inline_datum: vec![],
+            }],
+            fee: 170499,
+            ttl: 41115811,
+            auxiliary_data: Some(AuxiliaryData {
+                data: Some(auxiliary_data::Data::Cip36Registration(
+                    registration.clone(),
+                )),
+            }),
+            ..Default::default()
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        let supplement = match result {
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses,
+                auxiliary_data_supplement: Some(supplement),
+            }) => {
+                assert_eq!(shelley_witnesses.len(), 1);
+                supplement
+            }
+            _ => panic!("unexpected response"),
+        };
+        // The registration is signed by the stake key.
+        {
+            use ed25519_dalek::Verifier;
+            let stake_pubkey = ed25519_dalek::VerifyingKey::from_bytes(b"\xb0\xdc\x73\x13\xca\xbf\x4a\x4b\x07\x15\x14\xf4\x86\xd0\xd9\x97\x75\x86\x4e\x73\x77\x70\x0f\xb9\x93\x98\xb3\xf8\x23\x01\x06\x60").unwrap();
+            // blake2b-256 of {61284: registration}.
+            let registration_hash = b"\xad\x8c\x18\x56\x63\x4d\x9c\x47\xc1\x87\x5e\x7a\x29\xca\x82\x64\x3a\x3c\x05\x67\xea\x01\x9b\xb7\x47\xe3\xa4\x93\x73\x54\x16\x09";
+            let signature =
+                ed25519_dalek::Signature::from_slice(&supplement.cip36_signature).unwrap();
+            assert!(stake_pubkey.verify(registration_hash, &signature).is_ok());
+        }
+        // blake2b-256 of {61284: registration, 61285: {1: signature}}.
+        assert_eq!(
+            supplement.auxiliary_data_hash,
+            b"\x2a\x7f\x65\x59\x9a\x0c\x17\xc1\x39\x07\xb0\x74\x49\xb7\xd7\x35\x49\xd9\xc8\x46\x06\x4c\x30\xac\x3c\x32\x70\xbd\xfe\xe1\xd5\x69".to_vec()
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Register vote keys for account #1?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Vote key 1 of 2".into(),
+                    body: "cvote_vk1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygsgc6axh\nWeight: 1".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Vote key 2 of 2".into(),
+                    body: "cvote_vk1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3qmvyglm\nWeight: 3".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Voting rewards".into(),
+                    body: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Fee\n0.170499 ADA".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        let invalid_registrations = [
+            // Invalid vote key.
+            auxiliary_data::Cip36Registration {
+                delegations: vec![Delegation {
+                    vote_key: vec![0x11; 31],
+                    weight: 1,
+                }],
+                ..registration.clone()
+            },
+            // No voting power.
+            auxiliary_data::Cip36Registration {
+                delegations: vec![Delegation {
+                    vote_key: vec![0x11; 32],
+                    weight: 0,
+                }],
+                ..registration.clone()
+            },
+            // Stake key of a different account.
+            auxiliary_data::Cip36Registration {
+                stake_keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED + 1, 2, 0],
+                ..registration.clone()
+            },
+            // Reward address of a different account.
+            auxiliary_data::Cip36Registration {
+                payment_address: Some(CardanoScriptConfig {
+                    config: Some(pb::cardano_script_config::Config::PkhSkh(
+                        pb::cardano_script_config::PkhSkh {
+                            keypath_payment: vec![
+                                1852 + HARDENED,
+                                1815 + HARDENED,
+                                HARDENED + 1,
+                                0,
+                                0,
+                            ],
+                            keypath_stake: vec![
+                                1852 + HARDENED,
+                                1815 + HARDENED,
+                                HARDENED + 1,
+                                2,
+                                0,
+                            ],
+                        },
+                    )),
+                }),
+                ..registration.clone()
+            },
+            // No reward address.
+            auxiliary_data::Cip36Registration {
+                payment_address: None,
+                ..registration.clone()
+            },
+        ];
+        for registration in invalid_registrations {
+            let tx = pb::CardanoSignTransactionRequest {
+                auxiliary_data: Some(AuxiliaryData {
+                    data: Some(auxiliary_data::Data::Cip36Registration(registration)),
+                }),
+                ..tx.clone()
+            };
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), &tx)),
+                Err(Error::InvalidInput)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_mint() {
+        use pb::cardano_sign_transaction_request::{MintAssetGroup, mint_asset_group};
+
+        let policy_id = b"\x7e\xae\x28\xaf\x22\x08\xbe\x85\x6f\x7a\x11\x96\x68\xae\x52\xa4\x9b\x73\x72\x5e\x32\x6d\xc1\x65\x79\xdc\xc3\x73".to_vec();
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![pb::cardano_sign_transaction_request::Input {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                prev_out_index: 0,
+            }],
+            outputs: vec![pb::cardano_sign_transaction_request::Output {
+                encoded_address: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                value: 4829501,
+                script_config: Some(CardanoScriptConfig {
+                    config: Some(pb::cardano_script_config::Config::PkhSkh(
+                        pb::cardano_script_config::PkhSkh {
+                            keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                            keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                        },
+                    )),
+                }),
+                asset_groups: vec![pb::cardano_sign_transaction_request::AssetGroup {
+                    policy_id: policy_id.clone(),
+                    tokens: vec![pb::cardano_sign_transaction_request::asset_group::Token {
+                        asset_name: b"PATATE".to_vec(),
+                        value: 100,
+                    }],
+                }],
+                datum_hash: vec![],
+                inline_datum: vec![],
+            }],
+            fee: 170499,
+            ttl: 41115811,
+            mint: vec![MintAssetGroup {
+                policy_id: policy_id.clone(),
+                tokens: vec![
+                    mint_asset_group::Token {
+                        asset_name: b"PATATE".to_vec(),
+                        value: 100,
+                    },
+                    mint_asset_group::Token {
+                        asset_name: b"".to_vec(),
+                        value: -5,
+                    },
+                ],
+            }],
+            policy_keypaths: vec![pb::Keypath {
+                keypath: vec![1855 + HARDENED, 1815 + HARDENED, HARDENED],
+            }],
+            ..Default::default()
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        assert_eq!(
+            result,
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses: vec![
+                    ShelleyWitness {
+                        public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
+                        signature: b"\x9c\xce\xa1\x22\x62\x51\xa0\x77\x37\x12\x0a\x56\xb8\xe5\x4e\xfb\x65\xda\x8e\x8b\x79\x6e\xc2\xbf\x25\xd3\x1b\xa3\x4c\xf1\x59\x90\xf1\x01\x12\xeb\x46\xe4\x31\x99\x57\x05\xa1\x1d\x0e\xaa\x90\xe7\x52\xd0\x7d\xe3\xb5\xa8\x9e\xc5\x96\xd7\x5e\x66\xab\xdc\x4a\x00".to_vec(),
+                    },
+                    // Policy key.
+                    ShelleyWitness {
+                        public_key: b"\xf3\x11\x97\xe1\x44\x6b\x3c\x6c\x35\xb2\x50\xb2\x4f\xe1\x10\x74\xa6\x45\x77\xd0\xb4\xd1\xe6\x98\xb8\x0c\x5e\x40\x1c\xa4\x31\x29".to_vec(),
+                        signature: b"\x7f\x65\x60\xa6\xfc\xcf\xc9\x27\x4e\x56\xcc\x5f\x26\x7a\xda\x14\xd6\xff\xca\x22\x08\x68\xa4\xd4\xb4\xbb\xf4\xdd\x15\xe3\xdb\x1e\xb5\xf2\x86\x0f\x1f\x8b\x92\xce\x1c\x4d\x8d\x52\xbb\xaa\xd3\x5c\xdf\x2d\x14\xac\xd4\xd2\xf8\x9e\xc5\x63\xbf\x43\x0a\x80\x4d\x0e".to_vec(),
+                    },
+                ],
+                auxiliary_data_supplement: None,
+            })
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Mint token".into(),
+                    body: "Amount: 100. Asset: asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92. Policy: 7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Burn token".into(),
+                    body: "Amount: -5. Asset: asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3. Policy: 7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Sign with minting policy key #1?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Fee\n0.170499 ADA".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        let invalid_txs = [
+            // Zero amount.
+            pb::CardanoSignTransactionRequest {
+                mint: vec![MintAssetGroup {
+                    policy_id: policy_id.clone(),
+                    tokens: vec![mint_asset_group::Token {
+                        asset_name: b"PATATE".to_vec(),
+                        value: 0,
+                    }],
+                }],
+                ..tx.clone()
+            },
+            // Duplicate asset.
+            pb::CardanoSignTransactionRequest {
+                mint: vec![
+                    MintAssetGroup {
+                        policy_id: policy_id.clone(),
+                        tokens: vec![mint_asset_group::Token {
+                            asset_name: b"PATATE".to_vec(),
+                            value: 1,
+                        }],
+                    },
+                    MintAssetGroup {
+                        policy_id: policy_id.clone(),
+                        tokens: vec![mint_asset_group::Token {
+                            asset_name: b"PATATE".to_vec(),
+                            value: 1,
+                        }],
+                    },
+                ],
+                ..tx.clone()
+            },
+            // Invalid policy ID.
+            pb::CardanoSignTransactionRequest {
+                mint: vec![MintAssetGroup {
+                    policy_id: vec![0; 27],
+                    tokens: vec![mint_asset_group::Token {
+                        asset_name: b"PATATE".to_vec(),
+                        value: 1,
+                    }],
+                }],
+                ..tx.clone()
+            },
+            // Policy key without mint.
+            pb::CardanoSignTransactionRequest {
+                mint: vec![],
+                ..tx.clone()
+            },
+            // Invalid policy keypath.
+            pb::CardanoSignTransactionRequest {
+                policy_keypaths: vec![pb::Keypath {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED],
+                }],
+                ..tx.clone()
+            },
+        ];
+        for tx in invalid_txs.iter() {
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), tx)),
+                Err(Error::InvalidInput)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_plutus() {
+        use pb::cardano_sign_transaction_request::{Input, Output, RequiredSigner};
+
+        let change_config = CardanoScriptConfig {
+            config: Some(pb::cardano_script_config::Config::PkhSkh(
+                pb::cardano_script_config::PkhSkh {
+                    keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                    keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                },
+            )),
+        };
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![
+                Input {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                    prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                    prev_out_index: 0,
+                },
+                // Locked by a script, spent without a key witness.
+                Input {
+                    keypath: vec![],
+                    prev_out_hash: vec![0xbb; 32],
+                    prev_out_index: 1,
+                },
+            ],
+            outputs: vec![
+                Output {
+                    encoded_address: "addr1zx42424242424242424242424242424242424242424242kznmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmazjd8".into(),
+                    value: 2000000,
+                    script_config: None,
+                    asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: b"\xd8\x79\x80".to_vec(),
+                },
+                Output {
+                    encoded_address: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                    value: 4829501,
+                    script_config: Some(change_config.clone()),
+                    asset_groups: vec![],
+                    datum_hash: vec![],
+                    inline_datum: vec![],
+                },
+            ],
+            fee: 170499,
+            ttl: 41115811,
+            script_data_hash: vec![0xcc; 32],
+            collateral_inputs: vec![Input {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 1],
+                prev_out_hash: vec![0xdd; 32],
+                prev_out_index: 0,
+            }],
+            collateral_return: Some(Output {
+                encoded_address: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                value: 4000000,
+                script_config: Some(change_config.clone()),
+                asset_groups: vec![],
+                datum_hash: vec![],
+                inline_datum: vec![],
+            }),
+            total_collateral: Some(500000),
+            required_signers: vec![
+                RequiredSigner {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                    key_hash: vec![],
+                },
+                RequiredSigner {
+                    keypath: vec![],
+                    key_hash: vec![0xee; 28],
+                },
+            ],
+            reference_inputs: vec![Input {
+                keypath: vec![],
+                prev_out_hash: vec![0xff; 32],
+                prev_out_index: 2,
+            }],
+            ..Default::default()
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        assert_eq!(
+            result,
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses: vec![
+                    ShelleyWitness {
+                        public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
+                        signature: b"\x6a\xe2\x48\x11\x9e\xcd\x66\x51\x41\x38\x36\xc3\x0d\xf2\xca\x0c\x22\xa9\x93\x78\x7d\x93\x2a\x6e\xd5\xb8\x30\xbf\x36\xe1\x74\x3c\xfa\xa2\x6f\x21\xa4\x25\x60\xef\xe9\x1c\x33\xf3\xdf\x1c\x69\x4b\x90\x81\x61\xc0\xf3\x11\x90\x41\xa3\xbd\xac\x8d\x8d\x2e\x19\x03".to_vec(),
+                    },
+                    // Collateral input.
+                    ShelleyWitness {
+                        public_key: b"\x23\x28\xa3\xf6\x91\x24\xe0\xf2\x44\x8b\x74\x4d\xb2\x52\xef\x3f\xbe\xfa\xd5\xe7\x9d\xb4\x27\x69\x0f\x8d\xab\x50\x50\x9e\x5b\x91".to_vec(),
+                        signature: b"\x6c\xb2\x50\xf0\xa1\xf0\xef\x00\x5d\x1c\x58\x68\x3e\x5c\xab\xfd\xc4\x09\x67\x2e\x04\x03\xba\xde\x05\x40\x02\x3b\x7e\x7a\x0a\x36\xbe\x98\x86\x9f\xc8\xc6\xdb\x4f\x26\xba\x44\x89\xb6\x94\x2a\xb9\x3e\xa1\x2a\x0e\xa4\xd3\x35\xc7\x4a\xa3\xeb\xbe\x3e\xcd\x2d\x02".to_vec(),
+                    },
+                ],
+                auxiliary_data_supplement: None,
+            })
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "addr1zx42424242424242424242424242424242424242424242kznmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmazjd8".into(),
+                    amount: "2 ADA".into(),
+                },
+                Screen::Confirm {
+                    title: "Datum".into(),
+                    body: "Inline datum\n3 bytes".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Warning".into(),
+                    body: "Transaction runs\nPlutus scripts.\nOnly proceed if you\ntrust the dapp.".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Collateral".into(),
+                    body: "0.5 ADA is lost if a script fails.".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Required signer".into(),
+                    body: "eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee".into(),
+                    longtouch: false,
+                },
+                Screen::TotalFee {
+                    total: "2.170499 ADA".into(),
+                    fee: "0.170499 ADA".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        let invalid_txs = [
+            // Script input without script data hash.
+            pb::CardanoSignTransactionRequest {
+                script_data_hash: vec![],
+                collateral_inputs: vec![],
+                collateral_return: None,
+                total_collateral: None,
+                ..tx.clone()
+            },
+            // Invalid script data hash.
+            pb::CardanoSignTransactionRequest {
+                script_data_hash: vec![0xcc; 31],
+                ..tx.clone()
+            },
+            // Collateral return without collateral inputs.
+            pb::CardanoSignTransactionRequest {
+                collateral_inputs: vec![],
+                ..tx.clone()
+            },
+            // Collateral input which is not a payment keypath.
+            pb::CardanoSignTransactionRequest {
+                collateral_inputs: vec![Input {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                    prev_out_hash: vec![0xdd; 32],
+                    prev_out_index: 0,
+                }],
+                ..tx.clone()
+            },
+            // Collateral return to a foreign address.
+            pb::CardanoSignTransactionRequest {
+                collateral_return: Some(Output {
+                    script_config: None,
+                    ..tx.collateral_return.clone().unwrap()
+                }),
+                ..tx.clone()
+            },
+            // Reference input with a keypath.
+            pb::CardanoSignTransactionRequest {
+                reference_inputs: vec![Input {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                    prev_out_hash: vec![0xff; 32],
+                    prev_out_index: 2,
+                }],
+                ..tx.clone()
+            },
+            // Required signer with both a keypath and a key hash.
+            pb::CardanoSignTransactionRequest {
+                required_signers: vec![RequiredSigner {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                    key_hash: vec![0xee; 28],
+                }],
+                ..tx.clone()
+            },
+            // Required signer of another account.
+            pb::CardanoSignTransactionRequest {
+                required_signers: vec![RequiredSigner {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED + 1, 0, 0],
+                    key_hash: vec![],
+                }],
+                ..tx.clone()
+            },
+            // Both datum hash and inline datum.
+            pb::CardanoSignTransactionRequest {
+                outputs: vec![Output {
+                    datum_hash: vec![0xaa; 32],
+                    ..tx.outputs[0].clone()
+                }],
+                ..tx.clone()
+            },
+            // Datum on a change output.
+            pb::CardanoSignTransactionRequest {
+                outputs: vec![Output {
+                    datum_hash: vec![0xaa; 32],
+                    ..tx.outputs[1].clone()
+                }],
+                ..tx.clone()
+            },
+        ];
+        for tx in invalid_txs.iter() {
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), tx)),
+                Err(Error::InvalidInput)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_conway_stake_certificates() {
+        use certificate::vote_delegation::CardanoDRepType;
+
+        let stake_keypath = vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0];
+        let pool_keyhash = vec![0xab; 28];
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![pb::cardano_sign_transaction_request::Input {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                prev_out_index: 0,
+            }],
+            outputs: vec![pb::cardano_sign_transaction_request::Output {
+                encoded_address: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                value: 4829501,
+                script_config: Some(CardanoScriptConfig {
+                    config: Some(pb::cardano_script_config::Config::PkhSkh(
+                        pb::cardano_script_config::PkhSkh {
+                            keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                            keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                        },
+                    )),
+                }),
+                asset_groups: vec![],
+                datum_hash: vec![],
+                inline_datum: vec![],
+            }],
+            fee: 170499,
+            ttl: 41115811,
+            // Not a sensible combination, but it covers all certificates with the stake key.
+            certificates: vec![
+                Certificate {
+                    cert: Some(Cert::StakeRegistrationDeposit(certificate::KeypathDeposit {
+                        keypath: stake_keypath.clone(),
+                        deposit: 2000000,
+                    })),
+                },
+                Certificate {
+                    cert: Some(Cert::StakeVoteDelegation(certificate::StakeVoteDelegation {
+                        keypath: stake_keypath.clone(),
+                        pool_keyhash: pool_keyhash.clone(),
+                        drep_type: CardanoDRepType::KeyHash.into(),
+                        drep_credhash: Some(vec![0xcd; 28]),
+                        deposit: None,
+                    })),
+                },
+                Certificate {
+                    cert: Some(Cert::StakeDelegation(certificate::StakeDelegation {
+                        keypath: stake_keypath.clone(),
+                        pool_keyhash: pool_keyhash.clone(),
+                        deposit: Some(2000000),
+                    })),
+                },
+                Certificate {
+                    cert: Some(Cert::VoteDelegation(certificate::VoteDelegation {
+                        keypath: stake_keypath.clone(),
+                        r#type: CardanoDRepType::AlwaysNoConfidence.into(),
+                        drep_credhash: None,
+                        deposit: Some(2000000),
+                    })),
+                },
+                Certificate {
+                    cert: Some(Cert::StakeVoteDelegation(certificate::StakeVoteDelegation {
+                        keypath: stake_keypath.clone(),
+                        pool_keyhash: pool_keyhash.clone(),
+                        drep_type: CardanoDRepType::ScriptHash.into(),
+                        drep_credhash: Some(vec![0xef; 28]),
+                        deposit: Some(2000000),
+                    })),
+                },
+                Certificate {
+                    cert: Some(Cert::StakeDeregistrationDeposit(certificate::KeypathDeposit {
+                        keypath: stake_keypath.clone(),
+                        deposit: 2000000,
+                    })),
+                },
+            ],
+            ..Default::default()
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        assert_eq!(
+            result,
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses: vec![
+                    ShelleyWitness {
+                        public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
+                        signature: b"\x9c\x93\x60\xfe\xfe\xa7\x94\xe9\xdf\x85\x9b\x1b\x1c\x85\x51\xaf\x25\xb2\x6e\x3b\xc0\x67\x6a\xc8\x66\x21\x74\x26\xf7\x14\xb2\x44\x4b\xed\x27\x4d\xd0\x12\xe2\x1c\x9b\x63\x5c\x50\x82\x54\x23\x02\x75\xcb\x11\x6e\xc4\xcf\x80\xf1\x13\x6d\x39\x24\xe3\x76\x6a\x0f".to_vec(),
+                    },
+                    // Stake key.
+                    ShelleyWitness {
+                        public_key: b"\xb0\xdc\x73\x13\xca\xbf\x4a\x4b\x07\x15\x14\xf4\x86\xd0\xd9\x97\x75\x86\x4e\x73\x77\x70\x0f\xb9\x93\x98\xb3\xf8\x23\x01\x06\x60".to_vec(),
+                        signature: b"\x9a\x4a\x99\xee\x7c\x3f\xc8\x4c\x74\xe6\xfe\x5d\x1a\x6a\xf8\x72\x9b\x29\xb4\x52\xad\x59\xd6\x11\xce\x41\xfa\x0d\x3a\xe4\x8a\xbe\x27\x68\xe4\xa5\x82\x86\xa8\x64\x6f\x7c\xc2\xc5\x44\x46\x74\x95\xfd\x81\x10\xf1\x2f\x93\xe8\x85\x40\xa7\x49\x1c\x66\xa6\x21\x0a".to_vec(),
+                    },
+                ],
+                auxiliary_data_supplement: None,
+            })
+        );
+        let register = Screen::Confirm {
+            title: "Cardano".into(),
+            body: "Register staking key for account #1 with a deposit of 2 ADA?".into(),
+            longtouch: false,
+        };
+        let delegate_staking = Screen::Confirm {
+            title: "Cardano".into(),
+            body: "Delegate staking for account #1 to pool abababababababababababababababababababababababababababab?".into(),
+            longtouch: false,
+        };
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                register.clone(),
+                delegate_staking.clone(),
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Delegate voting for account #1 to type Key Hash and drep cdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcd?".into(),
+                    longtouch: false,
+                },
+                register.clone(),
+                delegate_staking.clone(),
+                register.clone(),
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Delegate voting for account #1 to type Always No Confidence?".into(),
+                    longtouch: false,
+                },
+                register.clone(),
+                delegate_staking.clone(),
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Delegate voting for account #1 to type Script Hash and drep efefefefefefefefefefefefefefefefefefefefefefefefefefefef?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Stop stake delegation for account #1 and refund the deposit of 2 ADA?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Fee\n0.170499 ADA".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        let invalid_certificates = [
+            // DRep key used as stake key.
+            Cert::StakeRegistrationDeposit(certificate::KeypathDeposit {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 3, 0],
+                deposit: 2000000,
+            }),
+            // Invalid pool key hash.
+            Cert::StakeVoteDelegation(certificate::StakeVoteDelegation {
+                keypath: stake_keypath.clone(),
+                pool_keyhash: vec![0xab; 27],
+                drep_type: CardanoDRepType::AlwaysAbstain.into(),
+                drep_credhash: None,
+                deposit: None,
+            }),
+            // Missing DRep hash.
+            Cert::StakeVoteDelegation(certificate::StakeVoteDelegation {
+                keypath: stake_keypath.clone(),
+                pool_keyhash: pool_keyhash.clone(),
+                drep_type: CardanoDRepType::KeyHash.into(),
+                drep_credhash: None,
+                deposit: None,
+            }),
+            // Unexpected DRep hash.
+            Cert::VoteDelegation(certificate::VoteDelegation {
+                keypath: stake_keypath.clone(),
+                r#type: CardanoDRepType::AlwaysAbstain.into(),
+                drep_credhash: Some(vec![0xcd; 28]),
+                deposit: Some(2000000),
+            }),
+        ];
+        for cert in invalid_certificates {
+            let tx = pb::CardanoSignTransactionRequest {
+                certificates: vec![Certificate { cert: Some(cert) }],
+                ..tx.clone()
+            };
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), &tx)),
+                Err(Error::InvalidInput)
+            );
+        }
+
+        // A vote delegation to a key hash DRep without a hash is rejected before it is shown.
+        let tx = pb::CardanoSignTransactionRequest {
+            certificates: vec![Certificate {
+                cert: Some(Cert::VoteDelegation(certificate::VoteDelegation {
+                    keypath: stake_keypath.clone(),
+                    r#type: CardanoDRepType::KeyHash.into(),
+                    drep_credhash: None,
+                    deposit: None,
+                })),
+            }],
+            ..tx.clone()
+        };
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(process(&mut mock_hal, &tx)),
+            Err(Error::InvalidInput)
+        );
+        assert!(
+            !mock_hal
+                .ui
+                .screens
+                .iter()
+                .any(|screen| matches!(screen, Screen::Confirm { body, .. } if body.contains("Delegate voting")))
+        );
+    }
+
+    #[test]
+    fn test_sign_drep() {
+        use pb::cardano_sign_transaction_request::{Anchor, VotingProcedures, voting_procedures};
+        use voting_procedures::vote::Choice;
+
+        let drep_keypath = vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 3, 0];
+        let anchor = Anchor {
+            url: "https://example.com/drep.json".into(),
+            data_hash: vec![0x11; 32],
+        };
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![pb::cardano_sign_transaction_request::Input {
+                keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                prev_out_index: 0,
+            }],
+            outputs: vec![pb::cardano_sign_transaction_request::Output {
+                encoded_address: "addr1q90tlskd4mh5kncmul7vx887j30tjtfgvap5n0g0rf9qqc7znmndrdhe7rwvqkw5c7mqnp4a3yflnvu6kff7l5dungvqmvu6hs".into(),
+                value: 4829501,
+                script_config: Some(CardanoScriptConfig {
+                    config: Some(pb::cardano_script_config::Config::PkhSkh(
+                        pb::cardano_script_config::PkhSkh {
+                            keypath_payment: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                            keypath_stake: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                        },
+                    )),
+                }),
+                asset_groups: vec![],
+                datum_hash: vec![],
+                inline_datum: vec![],
+            }],
+            fee: 170499,
+            ttl: 41115811,
+            certificates: vec![
+                Certificate {
+                    cert: Some(Cert::DrepRegistration(certificate::DRepRegistration {
+                        keypath: drep_keypath.clone(),
+                        deposit: 500000000,
+                        anchor: Some(anchor.clone()),
+                    })),
+                },
+                Certificate {
+                    cert: Some(Cert::DrepUpdate(certificate::DRepUpdate {
+                        keypath: drep_keypath.clone(),
+                        anchor: None,
+                    })),
+                },
+                Certificate {
+                    cert: Some(Cert::DrepDeregistration(certificate::KeypathDeposit {
+                        keypath: drep_keypath.clone(),
+                        deposit: 500000000,
+                    })),
+                },
+            ],
+            voting_procedures: Some(VotingProcedures {
+                drep_keypath: drep_keypath.clone(),
+                votes: vec![
+                    voting_procedures::Vote {
+                        gov_action_tx_hash: vec![0x77; 32],
+                        gov_action_index: 0,
+                        choice: Choice::Yes.into(),
+                        anchor: Some(anchor.clone()),
+                    },
+                    voting_procedures::Vote {
+                        gov_action_tx_hash: vec![0x77; 32],
+                        gov_action_index: 1,
+                        choice: Choice::Abstain.into(),
+                        anchor: None,
+                    },
+                ],
+            }),
+            ..Default::default()
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        assert_eq!(
+            result,
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses: vec![
+                    ShelleyWitness {
+                        public_key: b"\x1f\x17\xaf\xff\xe8\x05\x29\x7f\x8e\xc6\x54\x45\x82\xb7\xea\x91\xc3\x0d\xc1\xf9\x11\x9c\x5c\x2b\x26\x3e\x58\xfa\x36\x59\x31\x7d".to_vec(),
+                        signature: b"\x29\x5c\xbd\xe5\x5b\x9a\x91\xdc\x66\xd5\xfa\x84\x05\xfa\x17\x04\xd1\xf0\xe1\xbe\x6a\x2c\xde\x7e\x4b\xc3\x92\x4a\xd0\x00\x14\x7e\x39\xed\x69\x93\x42\x8f\xe4\xdb\xeb\xc2\x32\xd3\xf7\x0d\x32\x75\x51\xaa\x86\xaf\x20\x93\xf2\xfd\x5a\xc0\x71\xfe\x75\xdb\x12\x06".to_vec(),
+                    },
+                    // DRep key.
+                    ShelleyWitness {
+                        public_key: b"\x89\x49\xb9\xad\x10\x9c\x82\xb6\x53\xc5\xd3\xf3\x78\xff\x5d\x6d\x52\x1c\x4e\xf5\xf0\x64\x16\x33\x87\xf8\xd5\xa1\xd3\xa5\x4c\xde".to_vec(),
+                        signature: b"\xc9\xce\x3a\x15\xbd\x75\xd4\x42\xb1\x41\x07\xc7\xe8\x22\xd1\xa8\x6c\xdd\x7e\x92\x21\x3f\x5b\xc7\x51\xff\xf6\xa9\x2a\x57\x3e\x8a\x4d\x37\x19\x48\xbd\xc3\x78\xf2\x40\xf5\xad\xb1\x6d\x06\xd3\xd3\x77\x92\xa7\xab\xdd\x00\xa6\x02\xd1\xad\xc2\x87\x4e\x3b\xb8\x04".to_vec(),
+                    },
+                ],
+                auxiliary_data_supplement: None,
+            })
+        );
+        let anchor_screen = Screen::Confirm {
+            title: "Anchor".into(),
+            body: "https://example.com/drep.json\nHash: 1111111111111111111111111111111111111111111111111111111111111111".into(),
+            longtouch: false,
+        };
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Register DRep for account #1 with a deposit of 500 ADA?".into(),
+                    longtouch: false,
+                },
+                anchor_screen.clone(),
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Update DRep for account #1?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Retire DRep for account #1 and refund the deposit of 500 ADA?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Vote Yes on governance action 7777777777777777777777777777777777777777777777777777777777777777#0 as DRep of account #1?".into(),
+                    longtouch: false,
+                },
+                anchor_screen.clone(),
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Vote Abstain on governance action 7777777777777777777777777777777777777777777777777777777777777777#1 as DRep of account #1?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Fee\n0.170499 ADA".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        let votes = tx.voting_procedures.clone().unwrap().votes;
+        let invalid_txs = [
+            // Stake key used as DRep key.
+            pb::CardanoSignTransactionRequest {
+                certificates: vec![Certificate {
+                    cert: Some(Cert::DrepUpdate(certificate::DRepUpdate {
+                        keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED, 2, 0],
+                        anchor: None,
+                    })),
+                }],
+                ..tx.clone()
+            },
+            // Anchor URL too long.
+            pb::CardanoSignTransactionRequest {
+                certificates: vec![Certificate {
+                    cert: Some(Cert::DrepUpdate(certificate::DRepUpdate {
+                        keypath: drep_keypath.clone(),
+                        anchor: Some(Anchor {
+                            url: "a".repeat(129),
+                            data_hash: vec![0x11; 32],
+                        }),
+                    })),
+                }],
+                ..tx.clone()
+            },
+            // Invalid anchor hash.
+            pb::CardanoSignTransactionRequest {
+                certificates: vec![Certificate {
+                    cert: Some(Cert::DrepUpdate(certificate::DRepUpdate {
+                        keypath: drep_keypath.clone(),
+                        anchor: Some(Anchor {
+                            url: "https://example.com/drep.json".into(),
+                            data_hash: vec![0x11; 31],
+                        }),
+                    })),
+                }],
+                ..tx.clone()
+            },
+            // DRep of another account.
+            pb::CardanoSignTransactionRequest {
+                voting_procedures: Some(VotingProcedures {
+                    drep_keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED + 1, 3, 0],
+                    votes: votes.clone(),
+                }),
+                ..tx.clone()
+            },
+            // No votes.
+            pb::CardanoSignTransactionRequest {
+                voting_procedures: Some(VotingProcedures {
+                    drep_keypath: drep_keypath.clone(),
+                    votes: vec![],
+                }),
+                ..tx.clone()
+            },
+            // Duplicate governance action.
+            pb::CardanoSignTransactionRequest {
+                voting_procedures: Some(VotingProcedures {
+                    drep_keypath: drep_keypath.clone(),
+                    votes: vec![votes[0].clone(), votes[0].clone()],
+                }),
+                ..tx.clone()
+            },
+            // Invalid governance action ID.
+            pb::CardanoSignTransactionRequest {
+                voting_procedures: Some(VotingProcedures {
+                    drep_keypath: drep_keypath.clone(),
+                    votes: vec![voting_procedures::Vote {
+                        gov_action_index: 65536,
+                        ..votes[1].clone()
+                    }],
+                }),
+                ..tx.clone()
+            },
+        ];
+        for tx in invalid_txs.iter() {
+            assert_eq!(
+                block_on(process(&mut TestingHal::new(), tx)),
+                Err(Error::InvalidInput)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_native_script_multisig() {
+        const CHANGE: &str = "addr1xym4647gna2s8phl4vu0mfg5es66ddzh648mz25ql66lwahnswhhhlq09kcgpgrlexdjsvlr5gjtm09fzl365mp9xnzs5k9ue8";
+        let native_scripts = super::super::native_script::testing::make_native_scripts();
+        let tx = pb::CardanoSignTransactionRequest {
+            network: CardanoNetwork::CardanoMainnet as _,
+            inputs: vec![pb::cardano_sign_transaction_request::Input {
+                keypath: vec![1854 + HARDENED, 1815 + HARDENED, HARDENED, 0, 0],
+                prev_out_hash: b"\x59\x86\x4e\xe7\x3c\xa5\xd9\x10\x98\xa3\x2b\x3c\xe9\x81\x1b\xac\x19\x96\xdc\xba\xef\xa6\xb6\x24\x7d\xca\xaf\xb5\x77\x9c\x25\x38".to_vec(),
+                prev_out_index: 0,
+            }],
+            outputs: vec![
+                pb::cardano_sign_transaction_request::Output {
+                    encoded_address: "addr1q9qfllpxg2vu4lq6rnpel4pvpp5xnv3kvvgtxk6k6wp4ff89xrhu8jnu3p33vnctc9eklee5dtykzyag5penc6dcmakqsqqgpt".into(),
+                    value: 1000000,
+                    ..Default::default()
+                },
+                // change to the multisig address
+                pb::cardano_sign_transaction_request::Output {
+                    encoded_address: CHANGE.into(),
+                    value: 4829501,
+                    script_config: Some(CardanoScriptConfig {
+                        config: Some(pb::cardano_script_config::Config::NativeScripts(
+                            native_scripts.clone(),
+                        )),
+                    }),
+                    ..Default::default()
+                },
+            ],
+            fee: 170499,
+            ttl: 41115811,
+            ..Default::default()
+        };
+
+        mock_unlocked();
+
+        // The change address must be registered.
+        assert_eq!(
+            block_on(process(&mut TestingHal::new(), &tx)),
+            Err(Error::InvalidInput)
+        );
+
+        let mut mock_hal = TestingHal::new();
+        super::super::native_script::testing::register(
+            &mut mock_hal,
+            params::get(CardanoNetwork::CardanoMainnet),
+            &native_scripts,
+            "my multisig",
+        );
+        let result = block_on(process(&mut mock_hal, &tx)).unwrap();
+        assert_eq!(
+            result,
+            Response::SignTransaction(pb::CardanoSignTransactionResponse {
+                shelley_witnesses: vec![ShelleyWitness {
+                    public_key: b"\xb4\xac\x46\x0d\x00\x9c\xaa\xc8\xf8\x36\x6d\x11\xce\x96\x93\x17\x23\xac\xac\x2b\xcb\xfe\x68\x15\xcc\x13\xa5\x40\xc3\xe1\xcf\x30".to_vec(),
+                    signature: b"\x14\xb8\xde\x68\x29\x9d\xf9\x03\x84\xae\x46\x63\x72\x30\x82\xbc\xec\xb5\xd5\xc4\x86\xbd\x3b\xb7\xbd\x4f\x4e\x38\x65\x75\x62\xb7\x46\x8a\x24\x3d\x85\x8f\x96\x3f\x8d\x0e\x89\xd0\x83\xbf\x9d\x0e\x7e\xac\xec\xb5\xe5\xff\x82\x21\xff\x27\x2a\xad\xaf\xd5\x7d\x0a".to_vec(),
+                }],
+                auxiliary_data_supplement: None,
+            })
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Cardano".into(),
+                    body: "Can be mined until\nslot 335011 in\nepoch 292".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "addr1q9qfllpxg2vu4lq6rnpel4pvpp5xnv3kvvgtxk6k6wp4ff89xrhu8jnu3p33vnctc9eklee5dtykzyag5penc6dcmakqsqqgpt".into(),
+                    amount: "1 ADA".into(),
+                },
+                Screen::TotalFee {
+                    total: "1.170499 ADA".into(),
+                    fee: "0.170499 ADA".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "High fee".into(),
+                    body: "The fee is 17.0%\nthe send amount.\nProceed?".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                }
+            ]
+        );
+
+        // A native script address without a stake script has no stake part and is not accepted as
+        // change, even if registered.
+        const CHANGE_NO_STAKE: &str = "addr1wym4647gna2s8phl4vu0mfg5es66ddzh648mz25ql66lwasajytsm";
+        let mut native_scripts_no_stake = native_scripts.clone();
+        native_scripts_no_stake.stake_script = None;
+        super::super::native_script::testing::register(
+            &mut mock_hal,
+            params::get(CardanoNetwork::CardanoMainnet),
+            &native_scripts_no_stake,
+            "my multisig no stake",
+        );
+        let mut tx = tx.clone();
+        tx.outputs[1].encoded_address = CHANGE_NO_STAKE.into();
+        tx.outputs[1].script_config = Some(CardanoScriptConfig {
+            config: Some(pb::cardano_script_config::Config::NativeScripts(
+                native_scripts_no_stake,
+            )),
+        });
+        assert_eq!(
+            block_on(process(&mut mock_hal, &tx)),
+            Err(Error::InvalidInput)
+        );
+    }
 }
@@ -0,0 +1,311 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::Error;
+use super::super::keypath::validate_address_shelley_stake;
+use super::super::params;
+use super::super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bitcoin::bech32;
+
+use blake2::{Blake2bVar, digest::VariableOutput};
+use minicbor::encode::{Encoder, Write};
+
+use pb::cardano_sign_transaction_request::AuxiliaryData;
+use pb::cardano_sign_transaction_request::auxiliary_data::{Cip20Message, Cip36Registration, Data};
+use pb::cardano_sign_transaction_response::AuxiliaryDataSupplement;
+
+use super::cbor::HashedWriter;
+
+use crate::hal::Ui;
+use crate::keystore::ed25519;
+use util::bip32::HARDENED;
+
+/// https://github.com/cardano-foundation/CIPs/blob/master/CIP-0020/README.md
+const CIP20_LABEL: u16 = 674;
+/// Metadata strings are limited to 64 bytes.
+const CIP20_MAX_LINE_LEN: usize = 64;
+
+/// https://github.com/cardano-foundation/CIPs/blob/master/CIP-0036/README.md
+const CIP36_REGISTRATION_LABEL: u16 = 61284;
+const CIP36_WITNESS_LABEL: u16 = 61285;
+const CIP36_VOTE_KEY_LEN: usize = 32;
+const CIP36_MAX_DELEGATIONS: usize = 10;
+
+fn blake2b_256(
+    encode: impl FnOnce(&mut Encoder<HashedWriter<Blake2bVar>>) -> Result<(), Error>,
+) -> Result<[u8; 32], Error> {
+    let mut hasher = Blake2bVar::new(32).unwrap();
+    encode(&mut Encoder::new(HashedWriter::new(&mut hasher)))?;
+    let mut out = [0u8; 32];
+    hasher.finalize_variable(&mut out).or(Err(Error::Generic))?;
+    Ok(out)
+}
+
+async fn verify_cip20(hal: &mut impl crate::hal::Hal, message: &Cip20Message) -> Result<(), Error> {
+    if message.lines.is_empty()
+        || message
+            .lines
+            .iter()
+            .any(|line| line.len() > CIP20_MAX_LINE_LEN)
+    {
+        return Err(Error::InvalidInput);
+    }
+    // Lines which can't be displayed are shown in hex.
+    let body: Vec<String> = message
+        .lines
+        .iter()
+        .map(|line| {
+            if util::ascii::is_printable_ascii(line, util::ascii::Charset::All) {
+                line.clone()
+            } else {
+                format!("0x{}", hex::encode(line))
+            }
+        })
+        .collect();
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Message",
+            body: &body.join("\n"),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
+async fn verify_cip36(
+    hal: &mut impl crate::hal::Hal,
+    derivation: ed25519::Derivation,
+    params: &params::Params,
+    registration: &Cip36Registration,
+    bip44_account: u32,
+) -> Result<(), Error> {
+    validate_address_shelley_stake(&registration.stake_keypath, Some(bip44_account))?;
+    if registration.delegations.is_empty() || registration.delegations.len() > CIP36_MAX_DELEGATIONS
+    {
+        return Err(Error::InvalidInput);
+    }
+    for delegation in registration.delegations.iter() {
+        if delegation.vote_key.len() != CIP36_VOTE_KEY_LEN {
+            return Err(Error::InvalidInput);
+        }
+    }
+    if registration
+        .delegations
+        .iter()
+        .all(|delegation| delegation.weight == 0)
+    {
+        return Err(Error::InvalidInput);
+    }
+    // The voting rewards must go to an address of this wallet.
+    let payment_address = super::super::address::validate_and_encode_payment_address(
+        hal,
+        derivation,
+        params,
+        registration
+            .payment_address
+            .as_ref()
+            .and_then(|script_config| script_config.config.as_ref())
+            .ok_or(Error::InvalidInput)?,
+        Some(bip44_account),
+    )?;
+
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: params.name,
+            body: &format!(
+                "Register vote keys for account #{}?",
+                registration.stake_keypath[2] + 1 - HARDENED
+            ),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    let num_delegations = registration.delegations.len();
+    for (i, delegation) in registration.delegations.iter().enumerate() {
+        let vote_key = bech32::encode::<bech32::Bech32>(
+            bech32::Hrp::parse_unchecked("cvote_vk"),
+            &delegation.vote_key,
+        )
+        .unwrap();
+        let title = if num_delegations == 1 {
+            "Vote key".into()
+        } else {
+            format!("Vote key {} of {}", i + 1, num_delegations)
+        };
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: &title,
+                body: &format!("{}\nWeight: {}", vote_key, delegation.weight),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Voting rewards",
+            body: &payment_address,
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    if registration.voting_purpose != 0 {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Voting purpose",
+                body: &format!("{}", registration.voting_purpose),
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+/// Validates the auxiliary data and lets the user verify it.
+pub async fn verify(
+    hal: &mut impl crate::hal::Hal,
+    derivation: ed25519::Derivation,
+    params: &params::Params,
+    auxiliary_data: &AuxiliaryData,
+    bip44_account: u32,
+) -> Result<(), Error> {
+    match auxiliary_data.data.as_ref().ok_or(Error::InvalidInput)? {
+        Data::Hash(hash) => {
+            if hash.len() != 32 {
+                return Err(Error::InvalidInput);
+            }
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title: "Metadata hash",
+                    body: &hex::encode(hash),
+                    scrollable: true,
+                    accept_is_nextarrow: true,
+                    ..Default::default()
+                })
+                .await
+                .map_err(Error::from)
+        }
+        Data::Cip20Message(message) => verify_cip20(hal, message).await,
+        Data::Cip36Registration(registration) => {
+            verify_cip36(hal, derivation, params, registration, bip44_account).await
+        }
+    }
+}
+
+/// Encodes the CIP-36 registration map, i.e. the value at label 61284.
+fn encode_cip36_registration<W: Write>(
+    encoder: &mut Encoder<W>,
+    registration: &Cip36Registration,
+    stake_pubkey: &[u8],
+    payment_address: &[u8],
+) -> Result<(), Error> {
+    encoder
+        .map(5)?
+        .u8(1)?
+        .array(registration.delegations.len() as _)?;
+    for delegation in registration.delegations.iter() {
+        encoder
+            .array(2)?
+            .bytes(&delegation.vote_key)?
+            .u32(delegation.weight)?;
+    }
+    encoder
+        .u8(2)?
+        .bytes(stake_pubkey)?
+        .u8(3)?
+        .bytes(payment_address)?
+        .u8(4)?
+        .u64(registration.nonce)?
+        .u8(5)?
+        .u64(registration.voting_purpose)?;
+    Ok(())
+}
+
+/// Computes the auxiliary data hash to be included in the transaction body. For CIP-36
+/// registrations, the registration is signed with the stake key.
+///
+/// The auxiliary data must be verified using `verify()` before calling this function.
+pub fn finalize(
+    hal: &mut impl crate::hal::Hal,
+    derivation: ed25519::Derivation,
+    params: &params::Params,
+    auxiliary_data: &AuxiliaryData,
+) -> Result<AuxiliaryDataSupplement, Error> {
+    match auxiliary_data.data.as_ref().ok_or(Error::InvalidInput)? {
+        Data::Hash(hash) => Ok(AuxiliaryDataSupplement {
+            auxiliary_data_hash: hash.clone(),
+            cip36_signature: vec![],
+        }),
+        Data::Cip20Message(message) => {
+            let hash = blake2b_256(|encoder| {
+                encoder
+                    .map(1)?
+                    .u16(CIP20_LABEL)?
+                    .map(1)?
+                    .str("msg")?
+                    .array(message.lines.len() as _)?;
+                for line in message.lines.iter() {
+                    encoder.str(line)?;
+                }
+                Ok(())
+            })?;
+            Ok(AuxiliaryDataSupplement {
+                auxiliary_data_hash: hash.to_vec(),
+                cip36_signature: vec![],
+            })
+        }
+        Data::Cip36Registration(registration) => {
+            let stake_pubkey = ed25519::get_xpub(hal, derivation, &registration.stake_keypath)?
+                .pubkey_bytes()
+                .to_vec();
+            let payment_address = super::super::address::validate_and_get_payment_address_bytes(
+                hal,
+                derivation,
+                params,
+                registration
+                    .payment_address
+                    .as_ref()
+                    .and_then(|script_config| script_config.config.as_ref())
+                    .ok_or(Error::InvalidInput)?,
+                None,
+            )?;
+
+            let registration_hash = blake2b_256(|encoder| {
+                encoder.map(1)?.u16(CIP36_REGISTRATION_LABEL)?;
+                encode_cip36_registration(encoder, registration, &stake_pubkey, &payment_address)
+            })?;
+            let signature = ed25519::sign(
+                hal,
+                derivation,
+                &registration.stake_keypath,
+                &registration_hash,
+            )?
+            .signature;
+
+            let hash = blake2b_256(|encoder| {
+                encoder.map(2)?.u16(CIP36_REGISTRATION_LABEL)?;
+                encode_cip36_registration(encoder, registration, &stake_pubkey, &payment_address)?;
+                encoder
+                    .u16(CIP36_WITNESS_LABEL)?
+                    .map(1)?
+                    .u8(1)?
+                    .bytes(&signature)?;
+                Ok(())
+            })?;
+            Ok(AuxiliaryDataSupplement {
+                auxiliary_data_hash: hash.to_vec(),
+                cip36_signature: signature.to_vec(),
+            })
+        }
+    }
+}
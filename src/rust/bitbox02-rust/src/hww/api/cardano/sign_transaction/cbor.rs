@@ -9,9 +9,13 @@ use alloc::vec::Vec;
 use digest::Update;
 use minicbor::encode::{Encoder, Write};
 
-use pb::cardano_sign_transaction_request::{Certificate, Withdrawal, certificate};
+use pb::cardano_sign_transaction_request::certificate::vote_delegation::CardanoDRepType;
+use pb::cardano_sign_transaction_request::{
+    Anchor, Certificate, Input, Output, Withdrawal, certificate,
+};
 
-use super::super::address::{ADDRESS_HASH_SIZE, decode_payment_address, pubkey_hash_at_keypath};
+use super::super::address::{decode_payment_address, pubkey_hash_at_keypath, reward_address_bytes};
+use crate::keystore::ed25519::Derivation;
 
 /// A newtype for hashers to implement the Write trait, enabling serializing cbor directly into the
 /// hasher.
@@ -34,29 +38,58 @@ impl<U: Update> Write for HashedWriter<'_, U> {
 /// See https://github.com/input-output-hk/cardano-ledger-specs/blob/d0aa86ded0b973b09b629e5aa62aa1e71364d088/eras/alonzo/test-suite/cddl-files/alonzo.cddl#L176
 fn encode_stake_credential<W: Write>(
     hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
     encoder: &mut Encoder<W>,
     keypath: &[u32],
 ) -> Result<(), Error> {
-    let pubkey_hash = pubkey_hash_at_keypath(hal, keypath)?;
+    let pubkey_hash = pubkey_hash_at_keypath(hal, derivation, keypath)?;
     encoder.array(2)?.u8(0)?.bytes(&pubkey_hash)?;
     Ok(())
 }
 
-/// Encode a withdrawal/reward address.
-///
-/// See https://github.com/input-output-hk/cardano-ledger-specs/blob/d0aa86ded0b973b09b629e5aa62aa1e71364d088/eras/alonzo/test-suite/cddl-files/alonzo.cddl#L130
-pub fn encode_withdrawal_address(
-    hal: &mut impl crate::hal::Hal,
-    params: &params::Params,
-    keypath: &[u32],
-) -> Result<Vec<u8>, Error> {
-    let pubkey_hash = pubkey_hash_at_keypath(hal, keypath)?;
-    let mut encoded: Vec<u8> = Vec::with_capacity(1 + ADDRESS_HASH_SIZE);
-    let address_tag = 0b1110; // reward address using a stake keyhash.
-    let header = (address_tag << 4) | params.network_id;
-    encoded.push(header);
-    encoded.extend_from_slice(&pubkey_hash);
-    Ok(encoded)
+fn validate_pool_keyhash(pool_keyhash: &[u8]) -> Result<(), Error> {
+    if pool_keyhash.len() != 28 {
+        return Err(Error::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Encode a DRep, see
+/// https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl
+fn encode_drep<W: Write>(
+    encoder: &mut Encoder<W>,
+    r#type: i32,
+    drep_credhash: &Option<Vec<u8>>,
+) -> Result<(), Error> {
+    match (CardanoDRepType::try_from(r#type)?, drep_credhash) {
+        (CardanoDRepType::KeyHash, Some(hash)) if hash.len() == 28 => {
+            encoder.array(2)?.u8(0)?.bytes(hash)?;
+        }
+        (CardanoDRepType::ScriptHash, Some(hash)) if hash.len() == 28 => {
+            encoder.array(2)?.u8(1)?.bytes(hash)?;
+        }
+        (CardanoDRepType::AlwaysAbstain, None) => {
+            encoder.array(1)?.u8(2)?;
+        }
+        (CardanoDRepType::AlwaysNoConfidence, None) => {
+            encoder.array(1)?.u8(3)?;
+        }
+        _ => return Err(Error::InvalidInput),
+    }
+    Ok(())
+}
+
+/// Encode an anchor, or null if there is none.
+fn encode_anchor<W: Write>(encoder: &mut Encoder<W>, anchor: Option<&Anchor>) -> Result<(), Error> {
+    match anchor {
+        Some(Anchor { url, data_hash }) => {
+            encoder.array(2)?.str(url)?.bytes(data_hash)?;
+        }
+        None => {
+            encoder.null()?;
+        }
+    }
+    Ok(())
 }
 
 /// Start encoding a set:
@@ -74,16 +107,87 @@ fn encode_set_header<W: Write>(
     Ok(())
 }
 
+fn encode_inputs<W: Write>(
+    encoder: &mut Encoder<W>,
+    inputs: &[Input],
+    tag258: bool,
+) -> Result<(), Error> {
+    encode_set_header(encoder, inputs.len() as _, tag258)?;
+    for input in inputs.iter() {
+        if input.prev_out_hash.len() != 32 {
+            return Err(Error::InvalidInput);
+        }
+        encoder
+            .array(2)?
+            .bytes(&input.prev_out_hash)?
+            .u32(input.prev_out_index)?;
+    }
+    Ok(())
+}
+
+/// Encodes an output. Outputs with a datum are encoded in the post-Alonzo map format, all other
+/// outputs in the legacy array format.
+///
+/// See https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl#L341
+fn encode_output<W: Write>(
+    encoder: &mut Encoder<W>,
+    params: &params::Params,
+    output: &Output,
+) -> Result<(), Error> {
+    let decoded_address = decode_payment_address(params, &output.encoded_address)?;
+    let has_datum = !output.datum_hash.is_empty() || !output.inline_datum.is_empty();
+    if has_datum {
+        encoder.map(3)?.u8(0)?.bytes(&decoded_address)?.u8(1)?;
+    } else {
+        encoder.array(2)?.bytes(&decoded_address)?;
+    }
+    // The value is either the ADA amount, or [ADA amount, assets].
+    //
+    // See
+    // https://github.com/input-output-hk/cardano-ledger/blob/bd9bdb17e493ec1b3c8f329b25a5907d8b3d1cd1/eras/alonzo/test-suite/cddl-files/alonzo.cddl#L362
+    if output.asset_groups.is_empty() {
+        encoder.u64(output.value)?;
+    } else {
+        encoder
+            .array(2)?
+            .u64(output.value)?
+            .map(output.asset_groups.len() as _)?;
+        for asset_group in output.asset_groups.iter() {
+            encoder
+                .bytes(&asset_group.policy_id)?
+                .map(asset_group.tokens.len() as _)?;
+            for token in asset_group.tokens.iter() {
+                encoder.bytes(&token.asset_name)?.u64(token.value)?;
+            }
+        }
+    }
+    // The datum option is either [0, datum hash] or [1, #6.24(plutus data)].
+    if !output.datum_hash.is_empty() {
+        encoder.u8(2)?.array(2)?.u8(0)?.bytes(&output.datum_hash)?;
+    } else if !output.inline_datum.is_empty() {
+        encoder
+            .u8(2)?
+            .array(2)?
+            .u8(1)?
+            .tag(minicbor::data::Tag::new(24))?
+            .bytes(&output.inline_datum)?;
+    }
+    Ok(())
+}
+
 /// CBOR encoding for Cardano transactions.
 ///
-/// The transaction must be verified/validated before calling this function.
+/// The transaction must be verified/validated before calling this function. If the transaction
+/// has auxiliary data, its hash must be provided in `auxiliary_data_hash`.
 ///
 /// References:
 /// - Transaction body encoding spec: https://github.com/input-output-hk/cardano-ledger-specs/blob/d0aa86ded0b973b09b629e5aa62aa1e71364d088/eras/alonzo/test-suite/cddl-files/alonzo.cddl#L50
 /// - Serialization implementation: https://github.com/input-output-hk/cardano-ledger-specs/blob/c6c4be1562e23a3dd48282387c4e48ff918fbab0/eras/shelley-ma/impl/src/Cardano/Ledger/ShelleyMA/TxBody.hs#L208
 pub fn encode_transaction_body<W: Write>(
     hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
     tx: &pb::CardanoSignTransactionRequest,
+    auxiliary_data_hash: Option<&[u8]>,
     writer: W,
 ) -> Result<(), Error> {
     let params = params::get(pb::CardanoNetwork::try_from(tx.network)?);
@@ -99,48 +203,45 @@ pub fn encode_transaction_body<W: Write>(
     if !tx.withdrawals.is_empty() {
         num_map_entries += 1;
     }
+    if auxiliary_data_hash.is_some() {
+        num_map_entries += 1;
+    }
     if tx.validity_interval_start != 0 {
         num_map_entries += 1;
     }
+    if !tx.mint.is_empty() {
+        num_map_entries += 1;
+    }
+    if !tx.script_data_hash.is_empty() {
+        num_map_entries += 1;
+    }
+    if !tx.collateral_inputs.is_empty() {
+        num_map_entries += 1;
+    }
+    if !tx.required_signers.is_empty() {
+        num_map_entries += 1;
+    }
+    if tx.collateral_return.is_some() {
+        num_map_entries += 1;
+    }
+    if tx.total_collateral.is_some() {
+        num_map_entries += 1;
+    }
+    if !tx.reference_inputs.is_empty() {
+        num_map_entries += 1;
+    }
+    if tx.voting_procedures.is_some() {
+        num_map_entries += 1;
+    }
 
     encoder.map(num_map_entries)?;
     // Map entry 0 is an array of inputs.
     encoder.u8(0)?;
-    encode_set_header(&mut encoder, tx.inputs.len() as _, tx.tag_cbor_sets)?;
-    for input in tx.inputs.iter() {
-        if input.prev_out_hash.len() != 32 {
-            return Err(Error::InvalidInput);
-        }
-        encoder
-            .array(2)?
-            .bytes(&input.prev_out_hash)?
-            .u32(input.prev_out_index)?;
-    }
+    encode_inputs(&mut encoder, &tx.inputs, tx.tag_cbor_sets)?;
     // Map entry 1 is an array of outputs.
     encoder.u8(1)?.array(tx.outputs.len() as _)?;
     for output in tx.outputs.iter() {
-        let decoded_address = decode_payment_address(params, &output.encoded_address)?;
-        encoder.array(2)?.bytes(&decoded_address)?;
-        // Second array entry is either the ADA amount, or [ADA amount, assets].
-        //
-        // See
-        // https://github.com/input-output-hk/cardano-ledger/blob/bd9bdb17e493ec1b3c8f329b25a5907d8b3d1cd1/eras/alonzo/test-suite/cddl-files/alonzo.cddl#L362
-        if output.asset_groups.is_empty() {
-            encoder.u64(output.value)?;
-        } else {
-            encoder
-                .array(2)?
-                .u64(output.value)?
-                .map(output.asset_groups.len() as _)?;
-            for asset_group in output.asset_groups.iter() {
-                encoder
-                    .bytes(&asset_group.policy_id)?
-                    .map(asset_group.tokens.len() as _)?;
-                for token in asset_group.tokens.iter() {
-                    encoder.bytes(&token.asset_name)?.u64(token.value)?;
-                }
-            }
-        }
+        encode_output(&mut encoder, params, output)?;
     }
     // Map entry 2 is the fee.
     encoder.u8(2)?.u64(tx.fee)?;
@@ -156,67 +257,102 @@ pub fn encode_transaction_body<W: Write>(
             match cert.as_ref().ok_or(Error::InvalidInput)? {
                 certificate::Cert::StakeRegistration(pb::Keypath { keypath }) => {
                     encoder.array(2)?.u8(0)?;
-                    encode_stake_credential(hal, &mut encoder, keypath)?;
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
                 }
                 certificate::Cert::StakeDeregistration(pb::Keypath { keypath }) => {
                     encoder.array(2)?.u8(1)?;
-                    encode_stake_credential(hal, &mut encoder, keypath)?;
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
                 }
                 certificate::Cert::StakeDelegation(certificate::StakeDelegation {
                     keypath,
                     pool_keyhash,
+                    deposit,
                 }) => {
-                    if pool_keyhash.len() != 28 {
-                        return Err(Error::InvalidInput);
-                    }
-                    encoder.array(3)?.u8(2)?;
-                    encode_stake_credential(hal, &mut encoder, keypath)?;
+                    validate_pool_keyhash(pool_keyhash)?;
+                    match deposit {
+                        None => encoder.array(3)?.u8(2)?,
+                        Some(_) => encoder.array(4)?.u8(11)?,
+                    };
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
                     encoder.bytes(pool_keyhash)?;
+                    if let Some(deposit) = deposit {
+                        encoder.u64(*deposit)?;
+                    }
                 }
                 certificate::Cert::VoteDelegation(certificate::VoteDelegation {
                     keypath,
                     r#type,
                     drep_credhash,
+                    deposit,
                 }) => {
-                    encoder.array(3)?.u8(9)?;
-                    encode_stake_credential(hal, &mut encoder, keypath)?;
-                    let drep_type =
-                        certificate::vote_delegation::CardanoDRepType::try_from(*r#type)?;
-                    match drep_type {
-                        certificate::vote_delegation::CardanoDRepType::KeyHash => {
-                            encoder.array(2)?.u8(0)?;
-                            match drep_credhash {
-                                Some(hash) if hash.len() == 28 => {
-                                    encoder.bytes(hash)?;
-                                }
-                                Some(_hash) => return Err(Error::InvalidInput),
-                                None => return Err(Error::InvalidInput),
-                            }
-                        }
-                        certificate::vote_delegation::CardanoDRepType::ScriptHash => {
-                            encoder.array(2)?.u8(1)?;
-                            match drep_credhash {
-                                Some(hash) if hash.len() == 28 => {
-                                    encoder.bytes(hash)?;
-                                }
-                                Some(_hash) => return Err(Error::InvalidInput),
-                                None => return Err(Error::InvalidInput),
-                            }
-                        }
-                        certificate::vote_delegation::CardanoDRepType::AlwaysAbstain => {
-                            if !drep_credhash.is_none() {
-                                return Err(Error::InvalidInput);
-                            }
-                            encoder.array(1)?.u8(2)?;
-                        }
-                        certificate::vote_delegation::CardanoDRepType::AlwaysNoConfidence => {
-                            if !drep_credhash.is_none() {
-                                return Err(Error::InvalidInput);
-                            }
-                            encoder.array(1)?.u8(3)?;
-                        }
+                    match deposit {
+                        None => encoder.array(3)?.u8(9)?,
+                        Some(_) => encoder.array(4)?.u8(12)?,
+                    };
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
+                    encode_drep(&mut encoder, *r#type, drep_credhash)?;
+                    if let Some(deposit) = deposit {
+                        encoder.u64(*deposit)?;
                     }
                 }
+                certificate::Cert::StakeRegistrationDeposit(certificate::KeypathDeposit {
+                    keypath,
+                    deposit,
+                }) => {
+                    encoder.array(3)?.u8(7)?;
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
+                    encoder.u64(*deposit)?;
+                }
+                certificate::Cert::StakeDeregistrationDeposit(certificate::KeypathDeposit {
+                    keypath,
+                    deposit,
+                }) => {
+                    encoder.array(3)?.u8(8)?;
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
+                    encoder.u64(*deposit)?;
+                }
+                certificate::Cert::StakeVoteDelegation(certificate::StakeVoteDelegation {
+                    keypath,
+                    pool_keyhash,
+                    drep_type,
+                    drep_credhash,
+                    deposit,
+                }) => {
+                    validate_pool_keyhash(pool_keyhash)?;
+                    match deposit {
+                        None => encoder.array(4)?.u8(10)?,
+                        Some(_) => encoder.array(5)?.u8(13)?,
+                    };
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
+                    encoder.bytes(pool_keyhash)?;
+                    encode_drep(&mut encoder, *drep_type, drep_credhash)?;
+                    if let Some(deposit) = deposit {
+                        encoder.u64(*deposit)?;
+                    }
+                }
+                certificate::Cert::DrepRegistration(certificate::DRepRegistration {
+                    keypath,
+                    deposit,
+                    anchor,
+                }) => {
+                    encoder.array(4)?.u8(16)?;
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
+                    encoder.u64(*deposit)?;
+                    encode_anchor(&mut encoder, anchor.as_ref())?;
+                }
+                certificate::Cert::DrepDeregistration(certificate::KeypathDeposit {
+                    keypath,
+                    deposit,
+                }) => {
+                    encoder.array(3)?.u8(17)?;
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
+                    encoder.u64(*deposit)?;
+                }
+                certificate::Cert::DrepUpdate(certificate::DRepUpdate { keypath, anchor }) => {
+                    encoder.array(3)?.u8(18)?;
+                    encode_stake_credential(hal, derivation, &mut encoder, keypath)?;
+                    encode_anchor(&mut encoder, anchor.as_ref())?;
+                }
             }
         }
     }
@@ -225,14 +361,91 @@ pub fn encode_transaction_body<W: Write>(
     if !tx.withdrawals.is_empty() {
         encoder.u8(5)?.map(tx.withdrawals.len() as _)?;
         for Withdrawal { keypath, value } in tx.withdrawals.iter() {
-            let withdrawal_address = encode_withdrawal_address(hal, params, keypath)?;
+            let withdrawal_address = reward_address_bytes(hal, derivation, params, keypath)?;
             encoder.bytes(&withdrawal_address)?.u64(*value)?;
         }
     }
+    // Optional map entry 7 is the auxiliary data hash.
+    if let Some(hash) = auxiliary_data_hash {
+        encoder.u8(7)?.bytes(hash)?;
+    }
     // Optional map entry 8 is validity_interval_start.
     if tx.validity_interval_start != 0 {
         encoder.u8(8)?.u64(tx.validity_interval_start)?;
     }
+    // Optional map entry 9 are the minted and burned tokens.
+    if !tx.mint.is_empty() {
+        encoder.u8(9)?.map(tx.mint.len() as _)?;
+        for asset_group in tx.mint.iter() {
+            encoder
+                .bytes(&asset_group.policy_id)?
+                .map(asset_group.tokens.len() as _)?;
+            for token in asset_group.tokens.iter() {
+                encoder.bytes(&token.asset_name)?.i64(token.value)?;
+            }
+        }
+    }
+    // Optional map entry 11 is the script data hash.
+    if !tx.script_data_hash.is_empty() {
+        encoder.u8(11)?.bytes(&tx.script_data_hash)?;
+    }
+    // Optional map entry 13 are the collateral inputs.
+    if !tx.collateral_inputs.is_empty() {
+        encoder.u8(13)?;
+        encode_inputs(&mut encoder, &tx.collateral_inputs, tx.tag_cbor_sets)?;
+    }
+    // Optional map entry 14 are the required signers.
+    if !tx.required_signers.is_empty() {
+        encoder.u8(14)?;
+        encode_set_header(
+            &mut encoder,
+            tx.required_signers.len() as _,
+            tx.tag_cbor_sets,
+        )?;
+        for signer in tx.required_signers.iter() {
+            if signer.keypath.is_empty() {
+                encoder.bytes(&signer.key_hash)?;
+            } else {
+                encoder.bytes(&pubkey_hash_at_keypath(hal, derivation, &signer.keypath)?)?;
+            }
+        }
+    }
+    // Optional map entry 16 is the collateral return.
+    if let Some(ref output) = tx.collateral_return {
+        encoder.u8(16)?;
+        encode_output(&mut encoder, params, output)?;
+    }
+    // Optional map entry 17 is the total collateral.
+    if let Some(total_collateral) = tx.total_collateral {
+        encoder.u8(17)?.u64(total_collateral)?;
+    }
+    // Optional map entry 18 are the reference inputs.
+    if !tx.reference_inputs.is_empty() {
+        encoder.u8(18)?;
+        encode_inputs(&mut encoder, &tx.reference_inputs, tx.tag_cbor_sets)?;
+    }
+    // Optional map entry 19 are the votes, keyed by the voter. The only voter is the DRep key of
+    // this wallet.
+    if let Some(ref voting_procedures) = tx.voting_procedures {
+        let drep_keyhash =
+            pubkey_hash_at_keypath(hal, derivation, &voting_procedures.drep_keypath)?;
+        encoder
+            .u8(19)?
+            .map(1)?
+            .array(2)?
+            .u8(2)?
+            .bytes(&drep_keyhash)?
+            .map(voting_procedures.votes.len() as _)?;
+        for vote in voting_procedures.votes.iter() {
+            encoder
+                .array(2)?
+                .bytes(&vote.gov_action_tx_hash)?
+                .u32(vote.gov_action_index)?
+                .array(2)?
+                .i32(vote.choice)?;
+            encode_anchor(&mut encoder, vote.anchor.as_ref())?;
+        }
+    }
     Ok(())
 }
 
@@ -1,21 +1,127 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::super::Error;
-use super::super::keypath::validate_address_shelley_stake;
+use super::super::keypath::{validate_address_shelley_stake, validate_drep};
 use super::super::params;
 use super::super::pb;
 use crate::hal::ui::ConfirmParams;
 
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use pb::cardano_sign_transaction_request::{
     Certificate, certificate,
-    certificate::Cert::{StakeDelegation, StakeDeregistration, StakeRegistration, VoteDelegation},
+    certificate::Cert::{
+        DrepDeregistration, DrepRegistration, DrepUpdate, StakeDelegation, StakeDeregistration,
+        StakeDeregistrationDeposit, StakeRegistration, StakeRegistrationDeposit,
+        StakeVoteDelegation, VoteDelegation,
+    },
+    certificate::vote_delegation::CardanoDRepType,
 };
 
 use crate::hal::Ui;
 use util::bip32::HARDENED;
 
+async fn confirm(
+    hal: &mut impl crate::hal::Hal,
+    params: &params::Params,
+    body: &str,
+) -> Result<(), Error> {
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: params.name,
+            body,
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
+/// Validates the DRep and formats it for the delegation screen. Key and script hashes are 28
+/// bytes, the predefined DReps have no hash.
+fn format_drep(r#type: i32, drep_credhash: &Option<Vec<u8>>) -> Result<String, Error> {
+    let drep_type = CardanoDRepType::try_from(r#type)?;
+    let drep_type_name = match drep_type {
+        CardanoDRepType::KeyHash => "Key Hash",
+        CardanoDRepType::ScriptHash => "Script Hash",
+        CardanoDRepType::AlwaysAbstain => "Always Abstain",
+        CardanoDRepType::AlwaysNoConfidence => "Always No Confidence",
+    };
+    match (drep_type, drep_credhash) {
+        (CardanoDRepType::KeyHash | CardanoDRepType::ScriptHash, Some(hash))
+            if hash.len() == 28 =>
+        {
+            Ok(format!(
+                "type {} and drep {}",
+                drep_type_name,
+                hex::encode(hash)
+            ))
+        }
+        (CardanoDRepType::AlwaysAbstain | CardanoDRepType::AlwaysNoConfidence, None) => {
+            Ok(format!("type {}", drep_type_name))
+        }
+        _ => Err(Error::InvalidInput),
+    }
+}
+
+async fn verify_stake_registration(
+    hal: &mut impl crate::hal::Hal,
+    params: &params::Params,
+    keypath: &[u32],
+    deposit: u64,
+) -> Result<(), Error> {
+    confirm(
+        hal,
+        params,
+        &format!(
+            "Register staking key for account #{} with a deposit of {}?",
+            keypath[2] + 1 - HARDENED,
+            super::format_value(params, deposit),
+        ),
+    )
+    .await
+}
+
+async fn verify_stake_delegation(
+    hal: &mut impl crate::hal::Hal,
+    params: &params::Params,
+    keypath: &[u32],
+    pool_keyhash: &[u8],
+) -> Result<(), Error> {
+    confirm(
+        hal,
+        params,
+        &format!(
+            "Delegate staking for account #{} to pool {}?",
+            keypath[2] + 1 - HARDENED,
+            hex::encode(pool_keyhash),
+        ),
+    )
+    .await
+}
+
+async fn verify_vote_delegation(
+    hal: &mut impl crate::hal::Hal,
+    params: &params::Params,
+    keypath: &[u32],
+    r#type: i32,
+    drep_credhash: &Option<Vec<u8>>,
+) -> Result<(), Error> {
+    let drep = format_drep(r#type, drep_credhash)?;
+    confirm(
+        hal,
+        params,
+        &format!(
+            "Delegate voting for account #{} to {}?",
+            keypath[2] + 1 - HARDENED,
+            drep,
+        ),
+    )
+    .await
+}
+
 pub async fn verify<'a>(
     hal: &mut impl crate::hal::Hal,
     params: &params::Params,
@@ -30,106 +136,135 @@ pub async fn verify<'a>(
                 validate_address_shelley_stake(keypath, Some(bip44_account))?;
                 signing_keypaths.push(keypath);
                 // 2 ADA will be deposited and refunded once delegation stops, independent of the staking rewards.
-                hal.ui()
-                    .confirm(&ConfirmParams {
-                        title: params.name,
-                        body: &format!(
-                            "Register staking key for account #{}?",
-                            keypath[2] + 1 - HARDENED
-                        ),
-                        scrollable: true,
-                        accept_is_nextarrow: true,
-                        ..Default::default()
-                    })
-                    .await?;
+                confirm(
+                    hal,
+                    params,
+                    &format!(
+                        "Register staking key for account #{}?",
+                        keypath[2] + 1 - HARDENED
+                    ),
+                )
+                .await?;
             }
             StakeDeregistration(pb::Keypath { keypath }) => {
                 validate_address_shelley_stake(keypath, Some(bip44_account))?;
                 signing_keypaths.push(keypath);
                 // 2 ADA will be refunded back, independent of the staking rewards.
-                hal.ui()
-                    .confirm(&ConfirmParams {
-                        title: params.name,
-                        body: &format!(
-                            "Stop stake delegation for account #{}?",
-                            keypath[2] + 1 - HARDENED
-                        ),
-                        scrollable: true,
-                        accept_is_nextarrow: true,
-                        ..Default::default()
-                    })
-                    .await?;
+                confirm(
+                    hal,
+                    params,
+                    &format!(
+                        "Stop stake delegation for account #{}?",
+                        keypath[2] + 1 - HARDENED
+                    ),
+                )
+                .await?;
+            }
+            StakeRegistrationDeposit(certificate::KeypathDeposit { keypath, deposit }) => {
+                validate_address_shelley_stake(keypath, Some(bip44_account))?;
+                signing_keypaths.push(keypath);
+                verify_stake_registration(hal, params, keypath, *deposit).await?;
+            }
+            StakeDeregistrationDeposit(certificate::KeypathDeposit { keypath, deposit }) => {
+                validate_address_shelley_stake(keypath, Some(bip44_account))?;
+                signing_keypaths.push(keypath);
+                confirm(
+                    hal,
+                    params,
+                    &format!(
+                        "Stop stake delegation for account #{} and refund the deposit of {}?",
+                        keypath[2] + 1 - HARDENED,
+                        super::format_value(params, *deposit),
+                    ),
+                )
+                .await?;
             }
             StakeDelegation(certificate::StakeDelegation {
                 keypath,
                 pool_keyhash,
+                deposit,
             }) => {
                 validate_address_shelley_stake(keypath, Some(bip44_account))?;
                 signing_keypaths.push(keypath);
-                hal.ui()
-                    .confirm(&ConfirmParams {
-                        title: params.name,
-                        body: &format!(
-                            "Delegate staking for account #{} to pool {}?",
-                            keypath[2] + 1 - HARDENED,
-                            hex::encode(pool_keyhash),
-                        ),
-                        scrollable: true,
-                        accept_is_nextarrow: true,
-                        ..Default::default()
-                    })
-                    .await?;
+                if let Some(deposit) = deposit {
+                    verify_stake_registration(hal, params, keypath, *deposit).await?;
+                }
+                verify_stake_delegation(hal, params, keypath, pool_keyhash).await?;
             }
             VoteDelegation(certificate::VoteDelegation {
                 keypath,
                 r#type,
                 drep_credhash,
+                deposit,
+            }) => {
+                validate_address_shelley_stake(keypath, Some(bip44_account))?;
+                signing_keypaths.push(keypath);
+                if let Some(deposit) = deposit {
+                    verify_stake_registration(hal, params, keypath, *deposit).await?;
+                }
+                verify_vote_delegation(hal, params, keypath, *r#type, drep_credhash).await?;
+            }
+            StakeVoteDelegation(certificate::StakeVoteDelegation {
+                keypath,
+                pool_keyhash,
+                drep_type,
+                drep_credhash,
+                deposit,
             }) => {
                 validate_address_shelley_stake(keypath, Some(bip44_account))?;
                 signing_keypaths.push(keypath);
-                let drep_type_name =
-                    match certificate::vote_delegation::CardanoDRepType::try_from(*r#type)? {
-                        certificate::vote_delegation::CardanoDRepType::KeyHash => "Key Hash",
-                        certificate::vote_delegation::CardanoDRepType::ScriptHash => "Script Hash",
-                        certificate::vote_delegation::CardanoDRepType::AlwaysAbstain => {
-                            "Always Abstain"
-                        }
-                        certificate::vote_delegation::CardanoDRepType::AlwaysNoConfidence => {
-                            "Always No Confidence"
-                        }
-                    };
-                match drep_credhash {
-                    Some(hash) => {
-                        hal.ui()
-                            .confirm(&ConfirmParams {
-                                title: params.name,
-                                body: &format!(
-                                    "Delegate voting for account #{} to type {} and drep {}?",
-                                    keypath[2] + 1 - HARDENED,
-                                    drep_type_name,
-                                    hex::encode(hash),
-                                ),
-                                scrollable: true,
-                                accept_is_nextarrow: true,
-                                ..Default::default()
-                            })
-                            .await?;
-                    }
-                    None => {
-                        hal.ui()
-                            .confirm(&ConfirmParams {
-                                title: params.name,
-                                body: &format!(
-                                    "Delegate voting for account #{} to type {}?",
-                                    keypath[2] + 1 - HARDENED,
-                                    drep_type_name,
-                                ),
-                                scrollable: true,
-                                accept_is_nextarrow: true,
-                                ..Default::default()
-                            })
-                            .await?;
-                    }
+                if let Some(deposit) = deposit {
+                    verify_stake_registration(hal, params, keypath, *deposit).await?;
+                }
+                verify_stake_delegation(hal, params, keypath, pool_keyhash).await?;
+                verify_vote_delegation(hal, params, keypath, *drep_type, drep_credhash).await?;
+            }
+            DrepRegistration(certificate::DRepRegistration {
+                keypath,
+                deposit,
+                anchor,
+            }) => {
+                validate_drep(keypath, Some(bip44_account))?;
+                signing_keypaths.push(keypath);
+                confirm(
+                    hal,
+                    params,
+                    &format!(
+                        "Register DRep for account #{} with a deposit of {}?",
+                        keypath[2] + 1 - HARDENED,
+                        super::format_value(params, *deposit),
+                    ),
+                )
+                .await?;
+                if let Some(anchor) = anchor {
+                    super::governance::verify_anchor(hal, anchor).await?;
+                }
+            }
+            DrepDeregistration(certificate::KeypathDeposit { keypath, deposit }) => {
+                validate_drep(keypath, Some(bip44_account))?;
+                signing_keypaths.push(keypath);
+                confirm(
+                    hal,
+                    params,
+                    &format!(
+                        "Retire DRep for account #{} and refund the deposit of {}?",
+                        keypath[2] + 1 - HARDENED,
+                        super::format_value(params, *deposit),
+                    ),
+                )
+                .await?;
+            }
+            DrepUpdate(certificate::DRepUpdate { keypath, anchor }) => {
+                validate_drep(keypath, Some(bip44_account))?;
+                signing_keypaths.push(keypath);
+                confirm(
+                    hal,
+                    params,
+                    &format!("Update DRep for account #{}?", keypath[2] + 1 - HARDENED),
+                )
+                .await?;
+                if let Some(anchor) = anchor {
+                    super::governance::verify_anchor(hal, anchor).await?;
                 }
             }
         };
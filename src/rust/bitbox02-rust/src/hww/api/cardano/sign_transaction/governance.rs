@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::Error;
+use super::super::keypath::validate_drep;
+use super::super::params;
+use super::super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::vec::Vec;
+
+use pb::cardano_sign_transaction_request::voting_procedures::vote::Choice;
+use pb::cardano_sign_transaction_request::{Anchor, VotingProcedures};
+
+use crate::hal::Ui;
+use util::bip32::HARDENED;
+
+const ANCHOR_URL_MAX_LEN: usize = 128;
+
+/// Validates a governance metadata anchor and lets the user verify it.
+pub async fn verify_anchor(hal: &mut impl crate::hal::Hal, anchor: &Anchor) -> Result<(), Error> {
+    if anchor.url.len() > ANCHOR_URL_MAX_LEN
+        || !util::ascii::is_printable_ascii(&anchor.url, util::ascii::Charset::All)
+        || anchor.data_hash.len() != 32
+    {
+        return Err(Error::InvalidInput);
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Anchor",
+            body: &format!("{}\nHash: {}", anchor.url, hex::encode(&anchor.data_hash)),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
+/// Validates the votes and lets the user verify each of them. The DRep keypath is added to
+/// `signing_keypaths`.
+pub async fn verify_voting_procedures<'a>(
+    hal: &mut impl crate::hal::Hal,
+    params: &params::Params,
+    voting_procedures: &'a VotingProcedures,
+    bip44_account: u32,
+    signing_keypaths: &mut Vec<&'a [u32]>,
+) -> Result<(), Error> {
+    let keypath = &voting_procedures.drep_keypath;
+    validate_drep(keypath, Some(bip44_account))?;
+    if voting_procedures.votes.is_empty() {
+        return Err(Error::InvalidInput);
+    }
+    for (i, vote) in voting_procedures.votes.iter().enumerate() {
+        if vote.gov_action_tx_hash.len() != 32 || vote.gov_action_index > u16::MAX as u32 {
+            return Err(Error::InvalidInput);
+        }
+        // Governance actions are map keys and must be unique.
+        if voting_procedures.votes[..i].iter().any(|other| {
+            other.gov_action_tx_hash == vote.gov_action_tx_hash
+                && other.gov_action_index == vote.gov_action_index
+        }) {
+            return Err(Error::InvalidInput);
+        }
+        let choice = match Choice::try_from(vote.choice)? {
+            Choice::No => "No",
+            Choice::Yes => "Yes",
+            Choice::Abstain => "Abstain",
+        };
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: params.name,
+                body: &format!(
+                    "Vote {} on governance action {}#{} as DRep of account #{}?",
+                    choice,
+                    hex::encode(&vote.gov_action_tx_hash),
+                    vote.gov_action_index,
+                    keypath[2] + 1 - HARDENED,
+                ),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+        if let Some(ref anchor) = vote.anchor {
+            verify_anchor(hal, anchor).await?;
+        }
+    }
+    signing_keypaths.push(keypath);
+    Ok(())
+}
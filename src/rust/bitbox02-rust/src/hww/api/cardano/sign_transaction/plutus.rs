@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::Error;
+use super::super::keypath::{validate_address_shelley_payment, validate_address_shelley_stake};
+use super::super::params;
+use super::super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::vec::Vec;
+
+use crate::hal::Ui;
+use crate::keystore::ed25519::Derivation;
+
+/// Validates the fields used to interact with Plutus scripts and lets the user verify the
+/// collateral and the required signers which are not part of this wallet.
+///
+/// Keypaths of collateral inputs and required signers are added to `signing_keypaths`.
+pub async fn verify<'a>(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    params: &params::Params,
+    request: &'a pb::CardanoSignTransactionRequest,
+    bip44_account: u32,
+    signing_keypaths: &mut Vec<&'a [u32]>,
+) -> Result<(), Error> {
+    let is_plutus = !request.script_data_hash.is_empty();
+    if is_plutus && request.script_data_hash.len() != 32 {
+        return Err(Error::InvalidInput);
+    }
+    // Collateral is only needed to run Plutus scripts.
+    let has_collateral = !request.collateral_inputs.is_empty()
+        || request.collateral_return.is_some()
+        || request.total_collateral.is_some();
+    if has_collateral && (!is_plutus || request.collateral_inputs.is_empty()) {
+        return Err(Error::InvalidInput);
+    }
+    // Reference inputs are only read, not spent.
+    if request
+        .reference_inputs
+        .iter()
+        .any(|input| !input.keypath.is_empty())
+    {
+        return Err(Error::InvalidInput);
+    }
+
+    if is_plutus {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Warning",
+                body: "Transaction runs\nPlutus scripts.\nOnly proceed if you\ntrust the dapp.",
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    for input in request.collateral_inputs.iter() {
+        validate_address_shelley_payment(&input.keypath, Some(bip44_account))?;
+        signing_keypaths.push(&input.keypath);
+    }
+    if let Some(ref output) = request.collateral_return {
+        super::super::address::decode_payment_address(params, &output.encoded_address)?;
+        super::validate_asset_groups(&output.asset_groups)?;
+        if !output.datum_hash.is_empty() || !output.inline_datum.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        // The collateral return must go back to this wallet.
+        let encoded_address = super::super::address::validate_and_encode_payment_address(
+            hal,
+            derivation,
+            params,
+            output
+                .script_config
+                .as_ref()
+                .and_then(|script_config| script_config.config.as_ref())
+                .ok_or(Error::InvalidInput)?,
+            Some(bip44_account),
+        )?;
+        if encoded_address != output.encoded_address {
+            return Err(Error::InvalidInput);
+        }
+    }
+    if has_collateral {
+        let body = match request.total_collateral {
+            Some(total_collateral) => format!(
+                "{} is lost if a script fails.",
+                super::format_value(params, total_collateral)
+            ),
+            None => "The collateral inputs are lost if a script fails.".into(),
+        };
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Collateral",
+                body: &body,
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    for signer in request.required_signers.iter() {
+        match (signer.keypath.is_empty(), signer.key_hash.is_empty()) {
+            (false, true) => {
+                validate_address_shelley_payment(&signer.keypath, Some(bip44_account)).or_else(
+                    |_| validate_address_shelley_stake(&signer.keypath, Some(bip44_account)),
+                )?;
+                signing_keypaths.push(&signer.keypath);
+            }
+            (true, false) => {
+                if signer.key_hash.len() != 28 {
+                    return Err(Error::InvalidInput);
+                }
+                hal.ui()
+                    .confirm(&ConfirmParams {
+                        title: "Required signer",
+                        body: &hex::encode(&signer.key_hash),
+                        scrollable: true,
+                        accept_is_nextarrow: true,
+                        ..Default::default()
+                    })
+                    .await?;
+            }
+            _ => return Err(Error::InvalidInput),
+        }
+    }
+    Ok(())
+}
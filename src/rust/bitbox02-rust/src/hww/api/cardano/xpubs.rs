@@ -7,20 +7,24 @@ use alloc::vec::Vec;
 
 use pb::cardano_response::Response;
 
-use super::keypath::validate_account_shelley;
+use super::keypath::{validate_account_multisig, validate_account_shelley, validate_policy};
 
-/// Return the xpub at the request keypath.
+/// Return the xpub at the request keypath. Keypaths must be Shelley accounts, multisig accounts or
+/// minting policy keys.
 ///
 /// 64 bytes: 32 bytes public key + 32 bytes chain code.
 pub fn process(
     hal: &mut impl crate::hal::Hal,
     request: &pb::CardanoXpubsRequest,
 ) -> Result<Response, Error> {
+    let derivation = super::get_derivation(request.derivation_type)?;
     let mut xpubs: Vec<Vec<u8>> = Vec::with_capacity(request.keypaths.len());
     for pb::Keypath { keypath } in &request.keypaths {
-        validate_account_shelley(keypath)?;
+        validate_account_shelley(keypath)
+            .or_else(|_| validate_account_multisig(keypath))
+            .or_else(|_| validate_policy(keypath))?;
 
-        let xpub = crate::keystore::ed25519::get_xpub(hal, keypath)?;
+        let xpub = crate::keystore::ed25519::get_xpub(hal, derivation, keypath)?;
         let mut xpub_bytes = Vec::with_capacity(64);
         xpub_bytes.extend_from_slice(xpub.pubkey_bytes());
         xpub_bytes.extend_from_slice(xpub.chain_code());
@@ -42,7 +46,10 @@ mod tests {
         assert_eq!(
             process(
                 &mut crate::hal::testing::TestingHal::new(),
-                &pb::CardanoXpubsRequest { keypaths: vec![] }
+                &pb::CardanoXpubsRequest {
+                    keypaths: vec![],
+                    ..Default::default()
+                }
             ),
             Ok(Response::Xpubs(pb::CardanoXpubsResponse { xpubs: vec![] })),
         );
@@ -55,6 +62,7 @@ mod tests {
                     keypaths: vec![pb::Keypath {
                         keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED]
                     }],
+                    ..Default::default()
                 }
             ),
             Err(Error::Generic),
@@ -73,6 +81,7 @@ mod tests {
                             keypath: vec![1852 + HARDENED, 1815 + HARDENED, 1 + HARDENED]
                         }
                     ],
+                    ..Default::default()
                 }
             ),
             Ok(Response::Xpubs(pb::CardanoXpubsResponse {
@@ -93,6 +102,29 @@ mod tests {
             })),
         );
 
+        // Multisig account and minting policy key.
+        match process(
+            &mut crate::hal::testing::TestingHal::new(),
+            &pb::CardanoXpubsRequest {
+                keypaths: vec![
+                    pb::Keypath {
+                        keypath: vec![1854 + HARDENED, 1815 + HARDENED, HARDENED],
+                    },
+                    pb::Keypath {
+                        keypath: vec![1855 + HARDENED, 1815 + HARDENED, HARDENED],
+                    },
+                ],
+                ..Default::default()
+            },
+        ) {
+            Ok(Response::Xpubs(pb::CardanoXpubsResponse { xpubs })) => {
+                assert_eq!(xpubs.len(), 2);
+                assert_eq!(xpubs[0].len(), 64);
+                assert_eq!(xpubs[1].len(), 64);
+            }
+            _ => panic!("unexpected response"),
+        }
+
         // Invalid keypaths
         let invalid_keypaths: &[&[u32]] = &[
             // Invalid purpose
@@ -108,6 +140,12 @@ mod tests {
             &[1852 + HARDENED, 1815 + HARDENED],
             // Wrong number of elements (too long)
             &[1852 + HARDENED, 1815 + HARDENED, HARDENED, 0],
+            // Policy index too high
+            &[1855 + HARDENED, 1815 + HARDENED, 100 + HARDENED],
+            // Unhardened policy index
+            &[1855 + HARDENED, 1815 + HARDENED, 0],
+            // Multisig account too high
+            &[1854 + HARDENED, 1815 + HARDENED, 100 + HARDENED],
         ];
         for invalid_keypath in invalid_keypaths {
             assert_eq!(
@@ -117,10 +155,49 @@ mod tests {
                         keypaths: vec![pb::Keypath {
                             keypath: invalid_keypath.to_vec(),
                         },],
+                        ..Default::default()
                     }
                 ),
                 Err(Error::InvalidInput),
             );
         }
     }
+
+    #[test]
+    fn test_process_derivation_type() {
+        mock_unlocked();
+        let xpub = |derivation_type: i32| match process(
+            &mut crate::hal::testing::TestingHal::new(),
+            &pb::CardanoXpubsRequest {
+                keypaths: vec![pb::Keypath {
+                    keypath: vec![1852 + HARDENED, 1815 + HARDENED, HARDENED],
+                }],
+                derivation_type,
+            },
+        ) {
+            Ok(Response::Xpubs(pb::CardanoXpubsResponse { mut xpubs })) => Ok(xpubs.remove(0)),
+            Err(err) => Err(err),
+            _ => panic!("unexpected response"),
+        };
+        let ledger = xpub(pb::CardanoDerivationType::CardanoDerivationLedger as _).unwrap();
+        let icarus = xpub(pb::CardanoDerivationType::CardanoDerivationIcarus as _).unwrap();
+        let icarus_trezor =
+            xpub(pb::CardanoDerivationType::CardanoDerivationIcarusTrezor as _).unwrap();
+        assert_eq!(
+            icarus,
+            crate::keystore::ed25519::get_xpub(
+                &mut crate::hal::testing::TestingHal::new(),
+                crate::keystore::ed25519::Derivation::Icarus,
+                &[1852 + HARDENED, 1815 + HARDENED, HARDENED],
+            )
+            .map(|xpub| [&xpub.pubkey_bytes()[..], &xpub.chain_code()[..]].concat())
+            .unwrap()
+        );
+        // The test mnemonic has 24 words, so all three derivations yield different keys.
+        assert_ne!(ledger, icarus);
+        assert_ne!(ledger, icarus_trezor);
+        assert_ne!(icarus, icarus_trezor);
+
+        assert_eq!(xpub(3), Err(Error::InvalidInput));
+    }
 }
@@ -62,6 +62,15 @@ impl core::convert::From<crate::hal::ui::UserAbort> for Error {
     }
 }
 
+impl core::convert::From<crate::workflow::account_name::Error> for Error {
+    fn from(error: crate::workflow::account_name::Error) -> Self {
+        match error {
+            crate::workflow::account_name::Error::InvalidInput => Error::InvalidInput,
+            crate::workflow::account_name::Error::UserAbort => Error::UserAbort,
+        }
+    }
+}
+
 impl core::convert::From<crate::workflow::verify_message::Error> for Error {
     fn from(error: crate::workflow::verify_message::Error) -> Self {
         match error {
@@ -5,15 +5,20 @@ compile_error!(
     "Ethereum code is being compiled even though the app-ethereum feature is not enabled"
 );
 
+mod abi;
 mod address;
 mod amount;
 mod keypath;
 mod params;
 mod pubrequest;
+mod pubs;
+mod register_network;
 mod sighash;
 mod sign;
 mod sign_typed_msg;
 mod signmsg;
+mod siwe;
+mod user_operation;
 
 use super::Error;
 use super::pb;
@@ -69,12 +74,18 @@ pub async fn process_api(
 ) -> Result<Response, Error> {
     match request {
         Request::Pub(request) => pubrequest::process(hal, request).await,
+        Request::Pubs(request) => pubs::process(hal, request),
         Request::SignMsg(request) => signmsg::process(hal, request).await,
         Request::Sign(request) => sign::process(hal, &sign::Transaction::Legacy(request)).await,
         Request::SignEip1559(request) => {
             sign::process(hal, &sign::Transaction::Eip1559(request)).await
         }
+        Request::SignEip4844(request) => {
+            sign::process(hal, &sign::Transaction::Eip4844(request)).await
+        }
         Request::SignTypedMsg(request) => sign_typed_msg::process(hal, request).await,
+        Request::RegisterNetwork(request) => register_network::process(hal, request).await,
+        Request::SignUserOperation(request) => user_operation::process(hal, request).await,
         // These are streamed asynchronously using the `next_request()` primitive
         Request::AntikleptoSignature(_)
         | Request::TypedMsgValue(_)
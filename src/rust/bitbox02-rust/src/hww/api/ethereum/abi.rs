@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Decoding of ABI encoded contract calls: https://docs.soliditylang.org/en/latest/abi-spec.html
+
+use hex_lit::hex;
+use num_bigint::BigUint;
+
+/// `transfer(address to, uint256 value)`
+const SELECTOR_TRANSFER: [u8; 4] = hex!("a9059cbb");
+/// `approve(address spender, uint256 value)`
+const SELECTOR_APPROVE: [u8; 4] = hex!("095ea7b3");
+/// `transferFrom(address from, address to, uint256 value)`
+const SELECTOR_TRANSFER_FROM: [u8; 4] = hex!("23b872dd");
+/// `safeTransferFrom(address from, address to, uint256 tokenId)`
+const SELECTOR_SAFE_TRANSFER_FROM: [u8; 4] = hex!("42842e0e");
+/// `safeTransferFrom(address from, address to, uint256 tokenId, bytes data)`
+const SELECTOR_SAFE_TRANSFER_FROM_DATA: [u8; 4] = hex!("b88d4fde");
+/// `safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes data)`
+const SELECTOR_ERC1155_SAFE_TRANSFER_FROM: [u8; 4] = hex!("f242432a");
+
+/// Reader for the ABI encoded arguments of a contract call. All offsets are in bytes.
+pub struct Reader<'a> {
+    // Arguments, without the method selector.
+    args: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Splits `data` into the method selector and a reader for the arguments.
+    pub fn from_call(data: &'a [u8]) -> Option<([u8; 4], Self)> {
+        if data.len() < 4 {
+            return None;
+        }
+        let (selector, args) = data.split_at(4);
+        Some((selector.try_into().unwrap(), Reader { args }))
+    }
+
+    /// Number of bytes of the arguments.
+    pub fn args_len(&self) -> usize {
+        self.args.len()
+    }
+
+    fn word(&self, offset: usize) -> Option<&'a [u8]> {
+        self.args.get(offset..offset.checked_add(32)?)
+    }
+
+    pub fn uint(&self, offset: usize) -> Option<BigUint> {
+        Some(BigUint::from_bytes_be(self.word(offset)?))
+    }
+
+    /// Reads a word that must hold a small number, e.g. an offset or a length.
+    pub fn usize(&self, offset: usize) -> Option<usize> {
+        let word = self.word(offset)?;
+        if word[..28] != [0u8; 28] {
+            return None;
+        }
+        Some(u32::from_be_bytes(word[28..].try_into().unwrap()) as usize)
+    }
+
+    pub fn address(&self, offset: usize) -> Option<[u8; 20]> {
+        let word = self.word(offset)?;
+        if word[..12] != [0u8; 12] {
+            return None;
+        }
+        Some(word[12..].try_into().unwrap())
+    }
+
+    /// Reads `bytes` whose head is at `offset`. The data offset stored in the head is relative to
+    /// `base`, which is the start of the enclosing tuple or array.
+    pub fn bytes(&self, base: usize, offset: usize) -> Option<&'a [u8]> {
+        let start = base.checked_add(self.usize(offset)?)?;
+        let len = self.usize(start)?;
+        self.args.get(start + 32..(start + 32).checked_add(len)?)
+    }
+
+    /// Reads the head of a dynamic array whose head is at `offset`. Returns the position of the
+    /// first element and the number of elements.
+    pub fn array(&self, offset: usize) -> Option<(usize, usize)> {
+        let start = self.usize(offset)?;
+        let len = self.usize(start)?;
+        Some((start + 32, len))
+    }
+}
+
+/// A call of a token contract.
+#[derive(Debug, PartialEq)]
+pub enum TokenCall {
+    /// ERC-20 transfer.
+    Transfer { to: [u8; 20], value: BigUint },
+    /// ERC-20 allowance.
+    Approve { spender: [u8; 20], value: BigUint },
+    /// `transferFrom()`, which has the same signature in ERC-20 and ERC-721. `value` is the amount
+    /// for ERC-20 tokens and the token ID for ERC-721 tokens.
+    TransferFrom {
+        from: [u8; 20],
+        to: [u8; 20],
+        value: BigUint,
+    },
+    /// ERC-721 `safeTransferFrom()`.
+    NftTransfer {
+        from: [u8; 20],
+        to: [u8; 20],
+        token_id: BigUint,
+    },
+    /// ERC-1155 `safeTransferFrom()`.
+    MultiTokenTransfer {
+        from: [u8; 20],
+        to: [u8; 20],
+        id: BigUint,
+        value: BigUint,
+    },
+}
+
+/// Decodes calls of the ERC-20, ERC-721 and ERC-1155 transfer and approval methods.
+pub fn decode_token_call(data: &[u8]) -> Option<TokenCall> {
+    let (selector, reader) = Reader::from_call(data)?;
+    match selector {
+        SELECTOR_TRANSFER if reader.args_len() == 64 => Some(TokenCall::Transfer {
+            to: reader.address(0)?,
+            value: reader.uint(32)?,
+        }),
+        SELECTOR_APPROVE if reader.args_len() == 64 => Some(TokenCall::Approve {
+            spender: reader.address(0)?,
+            value: reader.uint(32)?,
+        }),
+        SELECTOR_TRANSFER_FROM if reader.args_len() == 96 => Some(TokenCall::TransferFrom {
+            from: reader.address(0)?,
+            to: reader.address(32)?,
+            value: reader.uint(64)?,
+        }),
+        SELECTOR_SAFE_TRANSFER_FROM if reader.args_len() == 96 => Some(TokenCall::NftTransfer {
+            from: reader.address(0)?,
+            to: reader.address(32)?,
+            token_id: reader.uint(64)?,
+        }),
+        SELECTOR_SAFE_TRANSFER_FROM_DATA => {
+            reader.bytes(0, 96)?;
+            Some(TokenCall::NftTransfer {
+                from: reader.address(0)?,
+                to: reader.address(32)?,
+                token_id: reader.uint(64)?,
+            })
+        }
+        SELECTOR_ERC1155_SAFE_TRANSFER_FROM => {
+            reader.bytes(0, 128)?;
+            Some(TokenCall::MultiTokenTransfer {
+                from: reader.address(0)?,
+                to: reader.address(32)?,
+                id: reader.uint(64)?,
+                value: reader.uint(96)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reader() {
+        // f(uint256 a, bytes b, address c) with a = 1, b = 0xaabb, c = 0x11...11
+        let args = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000006000000000000000000000000011111111111111111111111111111111111111110000000000000000000000000000000000000000000000000000000000000002aabb000000000000000000000000000000000000000000000000000000000000"
+        );
+        let reader = Reader { args: &args };
+        assert_eq!(reader.uint(0), Some(1u32.into()));
+        assert_eq!(reader.usize(0), Some(1));
+        assert_eq!(reader.bytes(0, 32), Some(&hex!("aabb")[..]));
+        assert_eq!(reader.address(64), Some([0x11; 20]));
+        // Out of bounds.
+        assert_eq!(reader.uint(129), None);
+        // Not an address.
+        assert_eq!(reader.address(128), None);
+        // Offset pointing past the end.
+        assert_eq!(reader.bytes(32, 32), None);
+        assert_eq!(reader.bytes(usize::MAX, 32), None);
+    }
+
+    #[test]
+    fn test_decode_token_call() {
+        assert_eq!(
+            decode_token_call(&hex!(
+                "a9059cbb000000000000000000000000857b3d969eacb775a9f79cabc62ec4bb1d1cd60e00000000000000000000000000000000000000000000000000000000000f4240"
+            )),
+            Some(TokenCall::Transfer {
+                to: hex!("857b3d969eacb775a9f79cabc62ec4bb1d1cd60e"),
+                value: 1000000u32.into(),
+            })
+        );
+        assert_eq!(
+            decode_token_call(&hex!(
+                "095ea7b3000000000000000000000000857b3d969eacb775a9f79cabc62ec4bb1d1cd60effffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+            )),
+            Some(TokenCall::Approve {
+                spender: hex!("857b3d969eacb775a9f79cabc62ec4bb1d1cd60e"),
+                value: (BigUint::from(1u8) << 256) - 1u8,
+            })
+        );
+        assert_eq!(
+            decode_token_call(&hex!(
+                "42842e0e00000000000000000000000011111111111111111111111111111111111111110000000000000000000000002222222222222222222222222222222222222222000000000000000000000000000000000000000000000000000000000000007b"
+            )),
+            Some(TokenCall::NftTransfer {
+                from: [0x11; 20],
+                to: [0x22; 20],
+                token_id: 123u32.into(),
+            })
+        );
+        assert_eq!(
+            decode_token_call(&hex!(
+                "f242432a00000000000000000000000011111111111111111111111111111111111111110000000000000000000000002222222222222222222222222222222222222222000000000000000000000000000000000000000000000000000000000000007b000000000000000000000000000000000000000000000000000000000000000500000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000000"
+            )),
+            Some(TokenCall::MultiTokenTransfer {
+                from: [0x11; 20],
+                to: [0x22; 20],
+                id: 123u32.into(),
+                value: 5u32.into(),
+            })
+        );
+
+        // Trailing data.
+        assert_eq!(
+            decode_token_call(&hex!(
+                "a9059cbb000000000000000000000000857b3d969eacb775a9f79cabc62ec4bb1d1cd60e00000000000000000000000000000000000000000000000000000000000f424000"
+            )),
+            None
+        );
+        // Unknown method.
+        assert_eq!(decode_token_call(&hex!("deadbeef")), None);
+        assert_eq!(decode_token_call(&[]), None);
+    }
+}
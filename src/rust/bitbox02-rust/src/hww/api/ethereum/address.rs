@@ -38,14 +38,36 @@ pub fn from_pubkey_hash(recipient: &[u8; 20], address_case: pb::EthAddressCase)
     }
 }
 
+/// Returns the 20 byte address of a 65 byte pubkey, i.e. the last 20 bytes of its keccak256 hash.
+pub fn pubkey_hash(pubkey_uncompressed: &[u8; 65]) -> [u8; 20] {
+    let hash = sha3::Keccak256::digest(&pubkey_uncompressed[1..]);
+    hash[hash.len() - 20..].try_into().unwrap()
+}
+
 /// Generates a checksummed ethereum hex address from a 65 byte pubkey.
 /// `recipient` - 20 byte tail (last 20 bytes of the pubkeyhash).
 pub fn from_pubkey(pubkey_uncompressed: &[u8; 65]) -> String {
-    let hash = sha3::Keccak256::digest(&pubkey_uncompressed[1..]);
-    from_pubkey_hash(
-        hash[hash.len() - 20..].try_into().unwrap(),
-        pb::EthAddressCase::Mixed,
-    )
+    from_pubkey_hash(&pubkey_hash(pubkey_uncompressed), pb::EthAddressCase::Mixed)
+}
+
+/// Computes the address of a contract created by `sender` in a transaction with the given nonce:
+/// the last 20 bytes of `keccak256(rlp([sender, nonce]))`.
+///
+/// `nonce` is big endian without leading zeros, at most 16 bytes.
+pub fn contract_address(sender: &[u8; 20], nonce: &[u8]) -> [u8; 20] {
+    let mut hasher = sha3::Keccak256::new();
+    // Both elements are short, so the list length fits into the list header byte.
+    let nonce_header: &[u8] = match nonce {
+        [byte] if *byte < 0x80 => &[],
+        _ => &[0x80 + nonce.len() as u8],
+    };
+    hasher.update([0xc0 + (1 + sender.len() + nonce_header.len() + nonce.len()) as u8]);
+    hasher.update([0x80 + sender.len() as u8]);
+    hasher.update(sender);
+    hasher.update(nonce_header);
+    hasher.update(nonce);
+    let hash = hasher.finalize();
+    hash[hash.len() - 20..].try_into().unwrap()
 }
 
 #[cfg(test)]
@@ -133,4 +155,21 @@ mod tests {
             assert_eq!(from_pubkey(pubkey), expected_address);
         }
     }
+
+    #[test]
+    fn test_contract_address() {
+        let sender =
+            b"\x6a\xc7\xea\x33\xf8\x83\x1e\xa9\xdc\xc5\x33\x93\xaa\xa8\x8b\x25\xa7\x85\xdb\xf0";
+        let tests: &[(&[u8], &str)] = &[
+            (b"", "cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+            (b"\x01", "343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+            (b"\x02", "f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+            (b"\x03", "fffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+            (b"\x80", "08e190dcb7b73f5fcdabb43e102215c83659a76d"),
+            (b"\x1f\xdc", "29ec5fe64d9e0d869c251853af67780f45977185"),
+        ];
+        for (nonce, expected) in tests {
+            assert_eq!(hex::encode(contract_address(sender, nonce)), *expected);
+        }
+    }
 }
@@ -5,11 +5,13 @@ use super::pb;
 use crate::hal::ui::ConfirmParams;
 use pb::EthCoin;
 
-use crate::hal::Ui;
+use crate::hal::{Memory, Ui};
+
+use alloc::borrow::Cow;
 
 use util::bip32::HARDENED;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct Params {
     /// Used until v9.10.0 and kept for backwards compatibility. From v9.10.0, `chain_id` is used to
     /// identify the network.
@@ -17,8 +19,8 @@ pub struct Params {
     pub bip44_coin: u32,
     /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md#list-of-chain-ids
     pub chain_id: u64,
-    pub name: &'static str,
-    pub unit: &'static str,
+    pub name: Cow<'static, str>,
+    pub unit: Cow<'static, str>,
 }
 
 // If there should ever be two networks with the same chain ID, the `get()` function should prompt
@@ -28,83 +30,83 @@ const PARAMS: &[Params] = &[
         coin: Some(EthCoin::Eth),
         bip44_coin: 60 + HARDENED,
         chain_id: 1,
-        name: "Ethereum",
-        unit: "ETH",
+        name: Cow::Borrowed("Ethereum"),
+        unit: Cow::Borrowed("ETH"),
     },
     Params {
         coin: None,
         bip44_coin: 1 + HARDENED,
         chain_id: 11155111,
-        name: "Sepolia",
-        unit: "SEPETH",
+        name: Cow::Borrowed("Sepolia"),
+        unit: Cow::Borrowed("SEPETH"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 56,
-        name: "Binance Smart Chain",
-        unit: "BNB",
+        name: Cow::Borrowed("Binance Smart Chain"),
+        unit: Cow::Borrowed("BNB"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 10,
-        name: "Optimism",
-        unit: "ETH",
+        name: Cow::Borrowed("Optimism"),
+        unit: Cow::Borrowed("ETH"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 137,
-        name: "Polygon",
-        unit: "MATIC",
+        name: Cow::Borrowed("Polygon"),
+        unit: Cow::Borrowed("MATIC"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 250,
-        name: "Fanton Opera",
-        unit: "FTM",
+        name: Cow::Borrowed("Fanton Opera"),
+        unit: Cow::Borrowed("FTM"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 42161,
-        name: "Arbitrum One",
-        unit: "ETH",
+        name: Cow::Borrowed("Arbitrum One"),
+        unit: Cow::Borrowed("ETH"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 8453,
-        name: "Base",
-        unit: "ETH",
+        name: Cow::Borrowed("Base"),
+        unit: Cow::Borrowed("ETH"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 100,
-        name: "Gnosis Chain",
-        unit: "xDAI",
+        name: Cow::Borrowed("Gnosis Chain"),
+        unit: Cow::Borrowed("xDAI"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 999,
-        name: "HyperEVM",
-        unit: "HYPE",
+        name: Cow::Borrowed("HyperEVM"),
+        unit: Cow::Borrowed("HYPE"),
     },
     Params {
         coin: None,
         bip44_coin: 60 + HARDENED,
         chain_id: 146,
-        name: "Sonic",
-        unit: "S",
+        name: Cow::Borrowed("Sonic"),
+        unit: Cow::Borrowed("S"),
     },
 ];
 
 /// Get the chain parameters by `coin` or `chain_id`. If `chain_id` is non-zero, `coin` is ignored.
-fn get(coin: Option<EthCoin>, chain_id: u64) -> Option<&'static Params> {
+pub fn get(coin: Option<EthCoin>, chain_id: u64) -> Option<&'static Params> {
     PARAMS.iter().find(|p| {
         if chain_id > 0 {
             p.chain_id == chain_id
@@ -116,13 +118,42 @@ fn get(coin: Option<EthCoin>, chain_id: u64) -> Option<&'static Params> {
     })
 }
 
-/// Check if the chain_id corresponds to a known network (to show an additional confirmations for).
-pub fn is_known_network(coin: Option<EthCoin>, chain_id: u64) -> bool {
-    get(coin, chain_id).is_some()
+/// Returns true if `name` is the name of a built-in network, ignoring case.
+pub fn is_builtin_name(name: &str) -> bool {
+    PARAMS.iter().any(|p| p.name.eq_ignore_ascii_case(name))
+}
+
+/// Get the chain parameters of a network registered by the user using `ETHRegisterNetworkRequest`.
+///
+/// The name is followed by the chain ID, so that a registered network can't pass for a built-in
+/// one, e.g. by using the same unit.
+pub fn get_registered(hal: &mut impl crate::hal::Hal, chain_id: u64) -> Option<Params> {
+    if chain_id == 0 {
+        return None;
+    }
+    let (name, unit) = hal.memory().eth_network_get(chain_id)?;
+    Some(Params {
+        coin: None,
+        bip44_coin: 60 + HARDENED,
+        chain_id,
+        name: Cow::Owned(format!("{} ({})", name, chain_id)),
+        unit: Cow::Owned(unit),
+    })
+}
+
+/// Check if the chain_id corresponds to a known or registered network (to show an additional
+/// confirmations for).
+pub fn is_known_network(
+    hal: &mut impl crate::hal::Hal,
+    coin: Option<EthCoin>,
+    chain_id: u64,
+) -> bool {
+    get(coin, chain_id).is_some() || get_registered(hal, chain_id).is_some()
 }
 
 /// Get the chain parameters by `coin` or `chain_id`. If `chain_id` is non-zero, `coin` is
-/// ignored. If `coin` is None. `chain_id` alone is used.
+/// ignored. If `coin` is None. `chain_id` alone is used. Networks registered by the user are
+/// considered after the built-in ones.
 ///
 /// If no params could be found and `chain_id` is non-zero, the user is asked to confirm the chain
 /// ID, and params with this chain ID and "UNKNOWN" name is returned. The main reason for this is
@@ -132,8 +163,11 @@ pub async fn get_and_warn_unknown(
     coin: Option<EthCoin>,
     chain_id: u64,
 ) -> Result<Params, Error> {
-    match get(coin, chain_id) {
-        Some(params) => Ok(*params),
+    if let Some(params) = get(coin, chain_id) {
+        return Ok(params.clone());
+    }
+    match get_registered(hal, chain_id) {
+        Some(params) => Ok(params),
         None => {
             if chain_id == 0 {
                 Err(Error::InvalidInput)
@@ -158,8 +192,8 @@ pub async fn get_and_warn_unknown(
                     coin: None,
                     bip44_coin: 60 + HARDENED,
                     chain_id,
-                    name: "UNKNOWN",
-                    unit: "",
+                    name: Cow::Borrowed("UNKNOWN"),
+                    unit: Cow::Borrowed(""),
                 })
             }
         }
@@ -170,6 +204,11 @@ pub async fn get_and_warn_unknown(
 mod tests {
     use super::*;
 
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use alloc::boxed::Box;
+    use util::bb02_async::block_on;
+
     #[test]
     pub fn test_get() {
         assert_eq!(get(Some(EthCoin::Eth), 0).unwrap().name, "Ethereum");
@@ -188,4 +227,49 @@ mod tests {
         assert!(get(None, 2).is_none());
         assert!(get(None, 0).is_none());
     }
+
+    #[test]
+    pub fn test_get_and_warn_unknown() {
+        // Built-in network.
+        let mut mock_hal = TestingHal::new();
+        let params = block_on(get_and_warn_unknown(&mut mock_hal, None, 10)).unwrap();
+        assert_eq!(params.name, "Optimism");
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Unknown network.
+        let mut mock_hal = TestingHal::new();
+        let params = block_on(get_and_warn_unknown(&mut mock_hal, None, 59144)).unwrap();
+        assert_eq!(params.name, "UNKNOWN");
+        assert_eq!(params.chain_id, 59144);
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Warning".into(),
+                    body: "Unknown network\nwith chain ID:\n59144".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Warning".into(),
+                    body: "Only proceed if\nyou recognize\nthis chain ID.".into(),
+                    longtouch: false,
+                },
+            ]
+        );
+
+        // Registered network.
+        let mut mock_hal = TestingHal::new();
+        mock_hal
+            .memory
+            .eth_network_set(59144, "Linea", "ETH")
+            .unwrap();
+        let params = block_on(get_and_warn_unknown(&mut mock_hal, None, 59144)).unwrap();
+        assert_eq!(params.name, "Linea (59144)");
+        assert_eq!(params.unit, "ETH");
+        assert_eq!(params.bip44_coin, 60 + HARDENED);
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Chain ID 0 is invalid.
+        assert!(block_on(get_and_warn_unknown(&mut TestingHal::new(), None, 0)).is_err());
+    }
 }
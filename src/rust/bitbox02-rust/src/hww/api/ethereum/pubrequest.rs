@@ -41,7 +41,7 @@ async fn process_address(
     if request.display {
         let title = match erc20_params {
             Some(erc20_params) => format!("{}\n{}", params.name, erc20_params.unit),
-            None => params.name.into(),
+            None => params.name.clone().into(),
         };
         super::keypath::warn_unusual_keypath(hal, &params, &title, &request.keypath).await?;
         hal.ui()
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::pb;
+
+use pb::eth_pubs_request::OutputType;
+use pb::eth_response::Response;
+
+use crate::bip32;
+use crate::xpubcache::{Bip32XpubCache, Compute};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use util::bip32::HARDENED;
+
+/// Max number of addresses or xpubs that can be requested at once.
+const MAX_PUBS: usize = 20;
+
+/// Retrieves up to 20 addresses or xpubs at once. They are not displayed on the device.
+///
+/// The parents of the requested keys are cached, so that e.g. consecutive addresses of an account
+/// only require one derivation from the root each.
+pub fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::EthPubsRequest,
+) -> Result<Response, Error> {
+    let output_type = OutputType::try_from(request.output_type)?;
+    if request.keypaths.len() > MAX_PUBS {
+        return Err(Error::InvalidInput);
+    }
+    let keypaths: Vec<&[u32]> = request
+        .keypaths
+        .iter()
+        .map(|k| k.keypath.as_slice())
+        .collect();
+
+    let valid_keypath = match output_type {
+        OutputType::Address => super::keypath::is_valid_keypath_address,
+        OutputType::Xpub => super::keypath::is_valid_keypath_xpub,
+    };
+    if !keypaths.iter().all(|keypath| valid_keypath(keypath)) {
+        return Err(Error::InvalidInput);
+    }
+
+    let mut xpub_cache = Bip32XpubCache::new(Compute::Twice);
+    let mut cached_parents: Vec<&[u32]> = Vec::new();
+    for keypath in keypaths.iter() {
+        // Only children with an unhardened last element can be derived from the parent xpub.
+        if let [parent @ .., last] = keypath
+            && *last < HARDENED
+            && !cached_parents.contains(&parent)
+        {
+            xpub_cache.add_keypath(parent);
+            cached_parents.push(parent);
+        }
+    }
+
+    let pubs: Vec<String> = keypaths
+        .iter()
+        .map(|keypath| {
+            let xpub = xpub_cache
+                .get_xpub(hal, keypath)
+                .or(Err(Error::InvalidInput))?;
+            match output_type {
+                OutputType::Address => {
+                    Ok(super::address::from_pubkey(&xpub.pubkey_uncompressed()?))
+                }
+                OutputType::Xpub => Ok(xpub.serialize_str(bip32::XPubType::Xpub)?),
+            }
+        })
+        .collect::<Result<_, Error>>()?;
+    Ok(Response::Pubs(pb::PubsResponse { pubs }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::testing::TestingHal;
+    use crate::keystore::testing::mock_unlocked;
+
+    fn make_request(output_type: OutputType, keypaths: &[&[u32]]) -> pb::EthPubsRequest {
+        pb::EthPubsRequest {
+            output_type: output_type as _,
+            keypaths: keypaths
+                .iter()
+                .map(|keypath| pb::Keypath {
+                    keypath: keypath.to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    pub fn test_process_addresses() {
+        mock_unlocked();
+
+        // A single address derived from the root.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.securechip.event_counter_reset();
+        assert_eq!(
+            process(
+                &mut mock_hal,
+                &make_request(
+                    OutputType::Address,
+                    &[&[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0]]
+                )
+            ),
+            Ok(Response::Pubs(pb::PubsResponse {
+                pubs: vec!["0x773A77b9D32589be03f9132AF759e294f7851be9".into()],
+            }))
+        );
+        let single_counter = mock_hal.securechip.get_event_counter();
+
+        // Addresses of the same account share the cached parent xpub, so they don't need more
+        // derivations from the root than a single address.
+        let keypaths: Vec<Vec<u32>> = (0..20)
+            .map(|i| vec![44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, i])
+            .collect();
+        let keypaths: Vec<&[u32]> = keypaths.iter().map(|k| k.as_slice()).collect();
+        let mut mock_hal = TestingHal::new();
+        mock_hal.securechip.event_counter_reset();
+        match process(&mut mock_hal, &make_request(OutputType::Address, &keypaths)) {
+            Ok(Response::Pubs(pb::PubsResponse { pubs })) => {
+                assert_eq!(pubs.len(), 20);
+                assert_eq!(pubs[0], "0x773A77b9D32589be03f9132AF759e294f7851be9");
+            }
+            _ => panic!("unexpected response"),
+        }
+        assert_eq!(mock_hal.securechip.get_event_counter(), single_counter);
+        // No screens.
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Over the limit.
+        let keypaths: Vec<Vec<u32>> = (0..21)
+            .map(|i| vec![44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, i])
+            .collect();
+        let keypaths: Vec<&[u32]> = keypaths.iter().map(|k| k.as_slice()).collect();
+        assert_eq!(
+            process(
+                &mut TestingHal::new(),
+                &make_request(OutputType::Address, &keypaths)
+            ),
+            Err(Error::InvalidInput)
+        );
+
+        // An xpub keypath is not a valid address keypath.
+        assert_eq!(
+            process(
+                &mut TestingHal::new(),
+                &make_request(
+                    OutputType::Address,
+                    &[
+                        &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0],
+                        &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0],
+                    ]
+                )
+            ),
+            Err(Error::InvalidInput)
+        );
+
+        // Keystore locked.
+        crate::keystore::lock();
+        assert_eq!(
+            process(
+                &mut TestingHal::new(),
+                &make_request(
+                    OutputType::Address,
+                    &[&[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0]]
+                )
+            ),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    pub fn test_process_xpubs() {
+        mock_unlocked();
+
+        let account_xpub = crate::keystore::get_xpub_twice(
+            &mut TestingHal::new(),
+            &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED],
+        )
+        .unwrap()
+        .serialize_str(bip32::XPubType::Xpub)
+        .unwrap();
+        assert_eq!(
+            process(
+                &mut TestingHal::new(),
+                &make_request(
+                    OutputType::Xpub,
+                    &[
+                        &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0],
+                        &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED],
+                    ]
+                )
+            ),
+            Ok(Response::Pubs(pb::PubsResponse {
+                pubs: vec![
+                    "xpub6FNKHYBc1HTwuwZcj4dz7xiG1kN7Hs3v7efYmgtzu1Gv6wJXxaCnFdQDRodbQpJKwdeVBf1RRNHARa6FsUMTCuRe2gKR7xCkSDdnppUp9oW".into(),
+                    account_xpub,
+                ],
+            }))
+        );
+
+        // An address keypath is not a valid xpub keypath.
+        assert_eq!(
+            process(
+                &mut TestingHal::new(),
+                &make_request(
+                    OutputType::Xpub,
+                    &[&[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0]]
+                )
+            ),
+            Err(Error::InvalidInput)
+        );
+    }
+}
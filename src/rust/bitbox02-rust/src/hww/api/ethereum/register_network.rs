@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::params;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use pb::eth_response::Response;
+
+use crate::hal::{Memory, Ui};
+
+use bitbox_hal::memory::{ETH_NETWORK_NAME_MAX_LEN, ETH_NETWORK_UNIT_MAX_LEN};
+
+/// Registers a network not built into the firmware, after confirmation by the user.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::EthRegisterNetworkRequest,
+) -> Result<Response, Error> {
+    let pb::EthRegisterNetworkRequest {
+        chain_id,
+        ref name,
+        ref unit,
+    } = *request;
+    if chain_id == 0 || chain_id == u64::MAX {
+        return Err(Error::InvalidInput);
+    }
+    // Built-in networks cannot be renamed.
+    if params::get(None, chain_id).is_some() {
+        return Err(Error::InvalidInput);
+    }
+    if !util::name::validate(name, ETH_NETWORK_NAME_MAX_LEN)
+        || !util::name::validate(unit, ETH_NETWORK_UNIT_MAX_LEN)
+        || unit.contains(' ')
+        // A registered network must not be confused with a built-in one.
+        || params::is_builtin_name(name)
+    {
+        return Err(Error::InvalidInput);
+    }
+
+    let title = "Add network";
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title,
+            body: &format!("Chain ID:\n{}", chain_id),
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    // Registering a chain ID again replaces the existing entry.
+    if let Some((old_name, _)) = hal.memory().eth_network_get(chain_id) {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title,
+                body: &format!("Replace {}?", old_name),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title,
+            body: &format!("Name:\n{}", name),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title,
+            body: &format!("Unit:\n{}", unit),
+            longtouch: true,
+            ..Default::default()
+        })
+        .await?;
+
+    hal.memory().eth_network_set(chain_id, name, unit)?;
+    hal.ui().status("Network\nadded", true).await;
+    Ok(Response::Success(pb::EthSuccess {}))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use alloc::boxed::Box;
+    use util::bb02_async::block_on;
+
+    fn make_request(chain_id: u64, name: &str, unit: &str) -> pb::EthRegisterNetworkRequest {
+        pb::EthRegisterNetworkRequest {
+            chain_id,
+            name: name.into(),
+            unit: unit.into(),
+        }
+    }
+
+    #[test]
+    fn test_process() {
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(process(&mut mock_hal, &make_request(59144, "Linea", "ETH"))),
+            Ok(Response::Success(pb::EthSuccess {}))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Add network".into(),
+                    body: "Chain ID:\n59144".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Add network".into(),
+                    body: "Name:\nLinea".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Add network".into(),
+                    body: "Unit:\nETH".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Network\nadded".into(),
+                    success: true,
+                },
+            ]
+        );
+        assert_eq!(
+            mock_hal.memory.eth_network_get(59144),
+            Some(("Linea".into(), "ETH".into()))
+        );
+
+        let params = params::get_registered(&mut mock_hal, 59144).unwrap();
+        assert_eq!(params.name, "Linea (59144)");
+        assert_eq!(params.unit, "ETH");
+        assert!(params::is_known_network(&mut mock_hal, None, 59144));
+    }
+
+    #[test]
+    fn test_process_user_abort() {
+        for abort_nth in 0..3 {
+            let mut mock_hal = TestingHal::new();
+            mock_hal.ui.abort_nth(abort_nth);
+            assert_eq!(
+                block_on(process(&mut mock_hal, &make_request(59144, "Linea", "ETH"))),
+                Err(Error::UserAbort)
+            );
+            assert!(mock_hal.memory.eth_network_get(59144).is_none());
+        }
+    }
+
+    #[test]
+    fn test_process_replace() {
+        let mut mock_hal = TestingHal::new();
+        mock_hal
+            .memory
+            .eth_network_set(59144, "Linea", "ETH")
+            .unwrap();
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &make_request(59144, "Linea Mainnet", "LETH")
+            )),
+            Ok(Response::Success(pb::EthSuccess {}))
+        );
+        assert_eq!(
+            mock_hal.ui.screens[..3],
+            [
+                Screen::Confirm {
+                    title: "Add network".into(),
+                    body: "Chain ID:\n59144".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Add network".into(),
+                    body: "Replace Linea?".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Add network".into(),
+                    body: "Name:\nLinea Mainnet".into(),
+                    longtouch: false,
+                },
+            ]
+        );
+        assert_eq!(
+            mock_hal.memory.eth_network_get(59144),
+            Some(("Linea Mainnet".into(), "LETH".into()))
+        );
+
+        // Aborting the replacement keeps the existing entry.
+        let mut mock_hal = TestingHal::new();
+        mock_hal
+            .memory
+            .eth_network_set(59144, "Linea", "ETH")
+            .unwrap();
+        mock_hal.ui.abort_nth(1);
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &make_request(59144, "Linea Mainnet", "LETH")
+            )),
+            Err(Error::UserAbort)
+        );
+        assert_eq!(
+            mock_hal.memory.eth_network_get(59144),
+            Some(("Linea".into(), "ETH".into()))
+        );
+    }
+
+    #[test]
+    fn test_process_invalid_input() {
+        let invalid_requests = [
+            // Invalid chain IDs.
+            make_request(0, "Zero", "ZERO"),
+            make_request(u64::MAX, "Max", "MAX"),
+            // Built-in network.
+            make_request(1, "Not Ethereum", "ETH"),
+            // Invalid names.
+            make_request(59144, "", "ETH"),
+            make_request(59144, " Linea", "ETH"),
+            make_request(59144, "Linea\n", "ETH"),
+            make_request(59144, "Linea Linea Linea Linea Linea Linea", "ETH"),
+            // Names of built-in networks.
+            make_request(59144, "Ethereum", "ETH"),
+            make_request(59144, "arbitrum one", "ETH"),
+            // Invalid units.
+            make_request(59144, "Linea", ""),
+            make_request(59144, "Linea", "E TH"),
+            make_request(59144, "Linea", "ETHETHETHETH"),
+        ];
+        for request in invalid_requests {
+            let mut mock_hal = TestingHal::new();
+            assert_eq!(
+                block_on(process(&mut mock_hal, &request)),
+                Err(Error::InvalidInput)
+            );
+            assert!(mock_hal.ui.screens.is_empty());
+        }
+    }
+}
@@ -132,6 +132,50 @@ impl DataProducer for ChunkingProducer<'_> {
     }
 }
 
+/// Passes through the chunks of another producer while accumulating their keccak256 hash. This
+/// allows hashing the data by itself in the same pass as the sighash, so the host can't provide
+/// different data for the two.
+pub struct HashingProducer<'a> {
+    inner: &'a mut dyn DataProducer,
+    hasher: Keccak256,
+}
+
+impl<'a> HashingProducer<'a> {
+    pub fn new(inner: &'a mut dyn DataProducer) -> Self {
+        Self {
+            inner,
+            hasher: Keccak256::new(),
+        }
+    }
+
+    /// Returns the hash of all data produced so far.
+    pub fn finalize(self) -> [u8; 32] {
+        self.hasher.finalize().into()
+    }
+}
+
+impl DataProducer for HashingProducer<'_> {
+    fn len(&self) -> u32 {
+        self.inner.len()
+    }
+
+    fn first_byte(&self) -> u8 {
+        self.inner.first_byte()
+    }
+
+    fn next<'a>(
+        &'a mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Vec<u8>>, Error>> + 'a>> {
+        Box::pin(async move {
+            let chunk = self.inner.next().await?;
+            if let Some(chunk) = &chunk {
+                self.hasher.update(chunk);
+            }
+            Ok(chunk)
+        })
+    }
+}
+
 pub struct ParamsLegacy<'a> {
     pub nonce: &'a [u8],
     pub gas_price: &'a [u8],
@@ -153,6 +197,19 @@ pub struct ParamsEIP1559<'a> {
     pub data: &'a mut dyn DataProducer,
 }
 
+pub struct ParamsEIP4844<'a> {
+    pub chain_id: u64,
+    pub nonce: &'a [u8],
+    pub max_priority_fee_per_gas: &'a [u8],
+    pub max_fee_per_gas: &'a [u8],
+    pub gas_limit: &'a [u8],
+    pub recipient: &'a [u8],
+    pub value: &'a [u8],
+    pub data: &'a mut dyn DataProducer,
+    pub max_fee_per_blob_gas: &'a [u8],
+    pub blob_versioned_hashes: &'a [Vec<u8>],
+}
+
 trait Write {
     // Writes the given data to the writer.
     fn write(&mut self, data: &[u8]);
@@ -267,6 +324,34 @@ async fn hash_params_eip1559<W: Write>(
     Ok(())
 }
 
+async fn hash_params_eip4844<W: Write>(
+    writer: &mut W,
+    params: &mut ParamsEIP4844<'_>,
+) -> Result<(), Error> {
+    hash_u64(writer, params.chain_id);
+    hash_element(writer, params.nonce);
+    hash_element(writer, params.max_priority_fee_per_gas);
+    hash_element(writer, params.max_fee_per_gas);
+    hash_element(writer, params.gas_limit);
+    hash_element(writer, params.recipient);
+    hash_element(writer, params.value);
+    hash_producer(writer, &mut *params.data).await?;
+    hash_header(writer, RLP_SMALL_TAG, RLP_LARGE_TAG, 0); // access list not currently supported and hashed as empty list
+    hash_element(writer, params.max_fee_per_blob_gas);
+    {
+        // List of the blob versioned hashes.
+        let mut counter = Counter(0);
+        for hash in params.blob_versioned_hashes {
+            hash_element(&mut counter, hash);
+        }
+        hash_header(writer, RLP_SMALL_TAG, RLP_LARGE_TAG, counter.0 as u16);
+        for hash in params.blob_versioned_hashes {
+            hash_element(writer, hash);
+        }
+    }
+    Ok(())
+}
+
 /// Computes the sighash of an Ethereum transaction, using the chain_id as described in EIP155.
 /// `params` are the transaction data. nonce, gas_price, gas_limit, and value are big endian and are
 /// not allowed to have leading zeros (unchecked).
@@ -315,6 +400,28 @@ pub async fn compute_eip1559(params: &mut ParamsEIP1559<'_>) -> Result<[u8; 32],
     Ok(hasher.0.finalize().into())
 }
 
+pub async fn compute_eip4844(params: &mut ParamsEIP4844<'_>) -> Result<[u8; 32], Error> {
+    // https://eips.ethereum.org/EIPS/eip-4844
+    // We hash [chain_id, nonce, max_priority_fee_per_gas, max_fee_per_gas, gas limit, recipient, value, data, access list,
+    // max_fee_per_blob_gas, blob_versioned_hashes] RLP encoded. Prefixed with 0x03 for the blob transaction type.
+
+    // 1) calculate length
+    let mut counter = Counter(0);
+    hash_params_eip4844(&mut counter, params).await?;
+
+    if counter.0 > 0xffff {
+        // Don't support bigger than this for now.
+        return Err(Error::InvalidInput);
+    }
+
+    // 2) hash len and encoded tx elements
+    let mut hasher = Hasher(Keccak256::new());
+    hasher.write(&[0x03]); // prefix the rlp encoding with transaction type before hashing
+    hash_header(&mut hasher, RLP_SMALL_TAG, RLP_LARGE_TAG, counter.0 as u16);
+    hash_params_eip4844(&mut hasher, params).await?;
+    Ok(hasher.0.finalize().into())
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -436,6 +543,53 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_compute_eip4844() {
+        let recipient = hex::decode("04f264cf34440313b4a0192a352814fbe927b885").unwrap();
+        let hash1 = [[0x01].as_slice(), &[0xaa; 31]].concat();
+        let hash2 = [[0x01].as_slice(), &[0xbb; 31]].concat();
+
+        let mut producer = ChunkingProducer::from_data(&[]);
+        let mut params = ParamsEIP4844 {
+            chain_id: 1,
+            nonce: &decode_hex("1fdc"),
+            max_priority_fee_per_gas: &decode_hex("3b9aca00"),
+            max_fee_per_gas: &decode_hex("0165a0bc00"),
+            gas_limit: &decode_hex("5208"),
+            recipient: &recipient,
+            value: &decode_hex("075cf1259e9c4000"),
+            data: &mut producer,
+            max_fee_per_blob_gas: &decode_hex("3b9aca00"),
+            blob_versioned_hashes: core::slice::from_ref(&hash1),
+        };
+        assert_eq!(
+            hex::encode(block_on(compute_eip4844(&mut params)).unwrap()),
+            "7925405e2b07f839fa4a382d91866a79af965ca934d6c85f5ec0cf5b2eea1238",
+        );
+
+        // Streamed data and multiple blobs.
+        let data = vec![0xaa; 100];
+        setup_chunk_responder(data.clone());
+        let mut producer = ChunkingProducer::from_host(data.len() as u32);
+        let mut params = ParamsEIP4844 {
+            chain_id: 11155111,
+            nonce: b"",
+            max_priority_fee_per_gas: b"",
+            max_fee_per_gas: &decode_hex("0165a0bc00"),
+            gas_limit: &decode_hex("5208"),
+            recipient: &recipient,
+            value: b"",
+            data: &mut producer,
+            max_fee_per_blob_gas: &decode_hex("01"),
+            blob_versioned_hashes: &[hash1, hash2],
+        };
+        assert_eq!(
+            hex::encode(block_on(compute_eip4844(&mut params)).unwrap()),
+            "ec220363603c25e26727b8fe9b0e2d6829c2f021462aa6182fce21852240e626",
+        );
+        clear_chunk_responder();
+    }
+
     #[test]
     fn test_compute_legacy() {
         let json_data = include_str!("testdata/legacy_tests.json");
@@ -21,9 +21,15 @@ use num_bigint::BigUint;
 // 1 ETH = 1e18 wei.
 const WEI_DECIMALS: usize = 18;
 
+// EIP-4844 blob parameters.
+const GAS_PER_BLOB: u32 = 1 << 17;
+const MAX_BLOBS_PER_TX: usize = 6;
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
 pub enum Transaction<'a> {
     Legacy(&'a pb::EthSignRequest),
     Eip1559(&'a pb::EthSignEip1559Request),
+    Eip4844(&'a pb::EthSignEip4844Request),
 }
 
 impl Transaction<'_> {
@@ -31,54 +37,62 @@ impl Transaction<'_> {
         match self {
             Transaction::Legacy(legacy) => &legacy.nonce,
             Transaction::Eip1559(eip1559) => &eip1559.nonce,
+            Transaction::Eip4844(eip4844) => &eip4844.nonce,
         }
     }
     fn gas_limit(&self) -> &[u8] {
         match self {
             Transaction::Legacy(legacy) => &legacy.gas_limit,
             Transaction::Eip1559(eip1559) => &eip1559.gas_limit,
+            Transaction::Eip4844(eip4844) => &eip4844.gas_limit,
         }
     }
     fn recipient(&self) -> &[u8] {
         match self {
             Transaction::Legacy(legacy) => &legacy.recipient,
             Transaction::Eip1559(eip1559) => &eip1559.recipient,
+            Transaction::Eip4844(eip4844) => &eip4844.recipient,
         }
     }
     fn value(&self) -> &[u8] {
         match self {
             Transaction::Legacy(legacy) => &legacy.value,
             Transaction::Eip1559(eip1559) => &eip1559.value,
+            Transaction::Eip4844(eip4844) => &eip4844.value,
         }
     }
     fn data(&self) -> &[u8] {
         match self {
             Transaction::Legacy(legacy) => &legacy.data,
             Transaction::Eip1559(eip1559) => &eip1559.data,
+            Transaction::Eip4844(eip4844) => &eip4844.data,
         }
     }
     fn chain_id(&self) -> u64 {
         match self {
             Transaction::Legacy(legacy) => legacy.chain_id,
             Transaction::Eip1559(eip1559) => eip1559.chain_id,
+            Transaction::Eip4844(eip4844) => eip4844.chain_id,
         }
     }
     fn keypath(&self) -> &[u32] {
         match self {
             Transaction::Legacy(legacy) => &legacy.keypath,
             Transaction::Eip1559(eip1559) => &eip1559.keypath,
+            Transaction::Eip4844(eip4844) => &eip4844.keypath,
         }
     }
     fn host_nonce_commitment(&self) -> Option<&pb::AntiKleptoHostNonceCommitment> {
         match self {
             Transaction::Legacy(legacy) => legacy.host_nonce_commitment.as_ref(),
             Transaction::Eip1559(eip1559) => eip1559.host_nonce_commitment.as_ref(),
+            Transaction::Eip4844(eip4844) => eip4844.host_nonce_commitment.as_ref(),
         }
     }
     fn coin(&self) -> Result<Option<pb::EthCoin>, Error> {
         match self {
             Transaction::Legacy(legacy) => Ok(Some(pb::EthCoin::try_from(legacy.coin)?)),
-            Transaction::Eip1559(_) => Ok(None),
+            Transaction::Eip1559(_) | Transaction::Eip4844(_) => Ok(None),
         }
     }
     fn case(&self) -> Result<pb::EthAddressCase, Error> {
@@ -87,12 +101,16 @@ impl Transaction<'_> {
             Transaction::Eip1559(eip1559) => {
                 Ok(pb::EthAddressCase::try_from(eip1559.address_case)?)
             }
+            Transaction::Eip4844(eip4844) => {
+                Ok(pb::EthAddressCase::try_from(eip4844.address_case)?)
+            }
         }
     }
     fn data_length(&self) -> u32 {
         match self {
             Transaction::Legacy(legacy) => legacy.data_length,
             Transaction::Eip1559(eip1559) => eip1559.data_length,
+            Transaction::Eip4844(eip4844) => eip4844.data_length,
         }
     }
 }
@@ -141,13 +159,14 @@ fn parse_erc20(request: &Transaction<'_>) -> Option<([u8; 20], BigUint)> {
 // That is because:
 // 1) actual gas used will often be lower than gas limit (in the case of contract interactions, not simple ETH transfers)
 // 2) in the case of 1559 base fee at execution time might also be lower so that `base fee + priority fee < max fee per gas`
+// For 4844 transactions, the blob fee is added: `blob fee = number of blobs * gas per blob * max fee per blob gas`
 fn parse_fee<'a>(request: &Transaction<'_>, params: &'a Params) -> Amount<'a> {
     let gas_limit = BigUint::from_bytes_be(request.gas_limit());
     match request {
         Transaction::Legacy(legacy) => {
             let gas_price = BigUint::from_bytes_be(&legacy.gas_price);
             Amount {
-                unit: params.unit,
+                unit: &params.unit,
                 decimals: WEI_DECIMALS,
                 value: gas_price.mul(gas_limit),
             }
@@ -155,27 +174,38 @@ fn parse_fee<'a>(request: &Transaction<'_>, params: &'a Params) -> Amount<'a> {
         Transaction::Eip1559(eip1559) => {
             let max_fee_per_gas = BigUint::from_bytes_be(&eip1559.max_fee_per_gas);
             Amount {
-                unit: params.unit,
+                unit: &params.unit,
                 decimals: WEI_DECIMALS,
                 value: max_fee_per_gas.mul(gas_limit),
             }
         }
+        Transaction::Eip4844(eip4844) => {
+            let max_fee_per_gas = BigUint::from_bytes_be(&eip4844.max_fee_per_gas);
+            let blob_gas = BigUint::from(GAS_PER_BLOB).mul(eip4844.blob_versioned_hashes.len());
+            let max_fee_per_blob_gas = BigUint::from_bytes_be(&eip4844.max_fee_per_blob_gas);
+            Amount {
+                unit: &params.unit,
+                decimals: WEI_DECIMALS,
+                value: max_fee_per_gas
+                    .mul(gas_limit)
+                    .add(blob_gas.mul(max_fee_per_blob_gas)),
+            }
+        }
     }
 }
 
-async fn hash_legacy(chain_id: u64, request: &pb::EthSignRequest) -> Result<[u8; 32], Error> {
-    let mut producer = if request.data_length > 0 {
-        super::sighash::ChunkingProducer::from_host(request.data_length)
-    } else {
-        super::sighash::ChunkingProducer::from_data(&request.data)
-    };
+async fn hash_legacy(
+    chain_id: u64,
+    request: &pb::EthSignRequest,
+    data: &mut dyn super::sighash::DataProducer,
+) -> Result<[u8; 32], Error> {
     let mut params = super::sighash::ParamsLegacy {
         nonce: &request.nonce,
         gas_price: &request.gas_price,
         gas_limit: &request.gas_limit,
         recipient: &request.recipient,
         value: &request.value,
-        data: &mut producer,
+        data,
         chain_id,
     };
     super::sighash::compute_legacy(&mut params)
@@ -183,12 +213,10 @@ async fn hash_legacy(chain_id: u64, request: &pb::EthSignRequest) -> Result<[u8;
         .map_err(|_| Error::InvalidInput)
 }
 
-async fn hash_eip1559(request: &pb::EthSignEip1559Request) -> Result<[u8; 32], Error> {
-    let mut producer = if request.data_length > 0 {
-        super::sighash::ChunkingProducer::from_host(request.data_length)
-    } else {
-        super::sighash::ChunkingProducer::from_data(&request.data)
-    };
+async fn hash_eip1559(
+    request: &pb::EthSignEip1559Request,
+    data: &mut dyn super::sighash::DataProducer,
+) -> Result<[u8; 32], Error> {
     let mut params = super::sighash::ParamsEIP1559 {
         chain_id: request.chain_id,
         nonce: &request.nonce,
@@ -197,13 +225,56 @@ async fn hash_eip1559(request: &pb::EthSignEip1559Request) -> Result<[u8; 32], E
         gas_limit: &request.gas_limit,
         recipient: &request.recipient,
         value: &request.value,
-        data: &mut producer,
+        data,
     };
     super::sighash::compute_eip1559(&mut params)
         .await
         .map_err(|_| Error::InvalidInput)
 }
 
+async fn hash_eip4844(
+    request: &pb::EthSignEip4844Request,
+    data: &mut dyn super::sighash::DataProducer,
+) -> Result<[u8; 32], Error> {
+    let mut params = super::sighash::ParamsEIP4844 {
+        chain_id: request.chain_id,
+        nonce: &request.nonce,
+        max_priority_fee_per_gas: &request.max_priority_fee_per_gas,
+        max_fee_per_gas: &request.max_fee_per_gas,
+        gas_limit: &request.gas_limit,
+        recipient: &request.recipient,
+        value: &request.value,
+        data,
+        max_fee_per_blob_gas: &request.max_fee_per_blob_gas,
+        blob_versioned_hashes: &request.blob_versioned_hashes,
+    };
+    super::sighash::compute_eip4844(&mut params)
+        .await
+        .map_err(|_| Error::InvalidInput)
+}
+
+/// Produces the data field, streaming it from the host if it is too large to be sent inline.
+fn data_producer<'a>(request: &'a Transaction<'a>) -> super::sighash::ChunkingProducer<'a> {
+    if request.data_length() > 0 {
+        super::sighash::ChunkingProducer::from_host(request.data_length())
+    } else {
+        super::sighash::ChunkingProducer::from_data(request.data())
+    }
+}
+
+/// Computes the sighash of the transaction, getting the data field from `data`.
+async fn compute_sighash(
+    chain_id: u64,
+    request: &Transaction<'_>,
+    data: &mut dyn super::sighash::DataProducer,
+) -> Result<[u8; 32], Error> {
+    match request {
+        Transaction::Legacy(legacy) => hash_legacy(chain_id, legacy, data).await,
+        Transaction::Eip1559(eip1559) => hash_eip1559(eip1559, data).await,
+        Transaction::Eip4844(eip4844) => hash_eip4844(eip4844, data).await,
+    }
+}
+
 /// Verifies an ERC20 transfer.
 ///
 /// If the ERC20 contract is known (stored in our list of supported ERC20 tokens), the token name,
@@ -312,7 +383,7 @@ async fn verify_standard_transaction(
 
     let address = super::address::from_pubkey_hash(&recipient, request.case()?);
     let amount = Amount {
-        unit: params.unit,
+        unit: &params.unit,
         decimals: WEI_DECIMALS,
         value: BigUint::from_bytes_be(request.value()),
     };
@@ -322,7 +393,7 @@ async fn verify_standard_transaction(
 
     let fee = parse_fee(request, params);
     let total = Amount {
-        unit: params.unit,
+        unit: &params.unit,
         decimals: WEI_DECIMALS,
         value: (&amount.value).add(&fee.value),
     };
@@ -332,6 +403,95 @@ async fn verify_standard_transaction(
     Ok(())
 }
 
+/// Verifies a contract creation transaction, which has an empty recipient and the init code of
+/// the contract in the data field.
+///
+/// The size and hash of the init code, the address of the new contract, the value sent to it, the
+/// total and the fee are confirmed.
+///
+/// The init code is hashed in the same pass as the sighash, which is returned, so the confirmed
+/// hash is guaranteed to belong to the signed init code even if it is streamed from the host.
+async fn verify_contract_creation(
+    hal: &mut impl crate::hal::Hal,
+    request: &Transaction<'_>,
+    params: &Params,
+) -> Result<[u8; 32], Error> {
+    let init_code_len = if request.data_length() > 0 {
+        request.data_length() as usize
+    } else {
+        request.data().len()
+    };
+    let mut producer = data_producer(request);
+    let mut hashing_producer = super::sighash::HashingProducer::new(&mut producer);
+    let sighash = compute_sighash(params.chain_id, request, &mut hashing_producer).await?;
+    let init_code_hash = hashing_producer.finalize();
+
+    // The contract address is derived from the sender address and the nonce.
+    let sender = super::address::pubkey_hash(
+        &keystore::get_xpub_twice(hal, request.keypath())
+            .or(Err(Error::InvalidInput))?
+            .pubkey_uncompressed()?,
+    );
+    let contract_address = super::address::from_pubkey_hash(
+        &super::address::contract_address(&sender, request.nonce()),
+        request.case()?,
+    );
+
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Deploy\ncontract",
+            body: &format!("Init code:\n{} bytes", init_code_len),
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Init code hash",
+            body: &format!("0x{}", hex::encode(init_code_hash)),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Contract address",
+            body: &contract_address,
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+
+    let amount = Amount {
+        unit: &params.unit,
+        decimals: WEI_DECIMALS,
+        value: BigUint::from_bytes_be(request.value()),
+    };
+    if !request.value().is_empty() {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Amount",
+                body: &amount.format(),
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    let fee = parse_fee(request, params);
+    let total = Amount {
+        unit: &params.unit,
+        decimals: WEI_DECIMALS,
+        value: (&amount.value).add(&fee.value),
+    };
+    let percentage = calculate_percentage(&fee.value, &amount.value);
+    transaction::verify_total_fee_maybe_warn(hal, &total.format(), &fee.format(), percentage)
+        .await?;
+    Ok(sighash)
+}
+
 pub async fn _process(
     hal: &mut impl crate::hal::Hal,
     request: &Transaction<'_>,
@@ -342,10 +502,10 @@ pub async fn _process(
     if !super::keypath::is_valid_keypath_address(request.keypath()) {
         return Err(Error::InvalidInput);
     }
-    super::keypath::warn_unusual_keypath(hal, &params, params.name, request.keypath()).await?;
+    super::keypath::warn_unusual_keypath(hal, &params, &params.name, request.keypath()).await?;
 
     // Show chain confirmation only for known networks
-    if super::params::is_known_network(request.coin()?, request.chain_id()) {
+    if super::params::is_known_network(hal, request.coin()?, request.chain_id()) {
         hal.ui()
             .confirm(&ConfirmParams {
                 body: &format!("Sign transaction on\n\n{}", params.name),
@@ -404,24 +564,62 @@ pub async fn _process(
                 return Err(Error::InvalidInput);
             }
         }
+        Transaction::Eip4844(eip4844) => {
+            if let [0, ..] = &eip4844.max_priority_fee_per_gas[..] {
+                return Err(Error::InvalidInput);
+            }
+            if let [0, ..] = &eip4844.max_fee_per_gas[..] {
+                return Err(Error::InvalidInput);
+            }
+            if let [0, ..] = &eip4844.max_fee_per_blob_gas[..] {
+                return Err(Error::InvalidInput);
+            }
+            if eip4844.max_priority_fee_per_gas.len() > 16
+                || eip4844.max_fee_per_gas.len() > 16
+                || eip4844.max_fee_per_blob_gas.len() > 16
+            {
+                return Err(Error::InvalidInput);
+            }
+            // A blob transaction carries at least one blob.
+            let num_blobs = eip4844.blob_versioned_hashes.len();
+            if num_blobs == 0 || num_blobs > MAX_BLOBS_PER_TX {
+                return Err(Error::InvalidInput);
+            }
+            if !eip4844
+                .blob_versioned_hashes
+                .iter()
+                .all(|hash| hash.len() == 32 && hash[0] == VERSIONED_HASH_VERSION_KZG)
+            {
+                return Err(Error::InvalidInput);
+            }
+        }
     }
 
-    let recipient = parse_recipient(request.recipient())?;
-    if recipient == [0; 20] {
-        // Reserved for contract creation.
+    let contract_creation_sighash = if request.recipient().is_empty() {
+        // Contract creation. Blob transactions can't create contracts, and there has to be init
+        // code to deploy.
+        if matches!(request, Transaction::Eip4844(_))
+            || (request.data().is_empty() && request.data_length() == 0)
+        {
+            return Err(Error::InvalidInput);
+        }
+        Some(verify_contract_creation(hal, request, &params).await?)
+    } else if parse_recipient(request.recipient())? == [0; 20] {
+        // The zero address is not a valid recipient. Contracts are created with an empty
+        // recipient.
         return Err(Error::InvalidInput);
-    }
-
-    if let Some((erc20_recipient, erc20_value)) = parse_erc20(request) {
+    } else if let Some((erc20_recipient, erc20_value)) = parse_erc20(request) {
         verify_erc20_transaction(hal, request, &params, erc20_recipient, erc20_value).await?;
+        None
     } else {
         verify_standard_transaction(hal, request, &params).await?;
-    }
+        None
+    };
     hal.ui().status("Transaction\nconfirmed", true).await;
 
-    let hash: [u8; 32] = match request {
-        Transaction::Legacy(legacy) => hash_legacy(params.chain_id, legacy).await?,
-        Transaction::Eip1559(eip1559) => hash_eip1559(eip1559).await?,
+    let hash: [u8; 32] = match contract_creation_sighash {
+        Some(hash) => hash,
+        None => compute_sighash(params.chain_id, request, &mut data_producer(request)).await?,
     };
 
     let host_nonce = match request.host_nonce_commitment() {
@@ -480,6 +678,8 @@ mod tests {
     use crate::hal::testing::ui::Screen;
     use crate::keystore::testing::mock_unlocked;
     use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
     use util::bb02_async::block_on;
     use util::bip32::HARDENED;
 
@@ -1318,6 +1518,321 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn test_process_eip4844() {
+        let valid_request = pb::EthSignEip4844Request {
+            keypath: vec![44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0],
+            nonce: hex!("1fdc").to_vec(),
+            max_priority_fee_per_gas: hex!("3b9aca00").to_vec(),
+            max_fee_per_gas: hex!("0165a0bc00").to_vec(),
+            gas_limit: hex!("5208").to_vec(),
+            recipient: hex!("04f264cf34440313b4a0192a352814fbe927b885").to_vec(),
+            value: hex!("075cf1259e9c4000").to_vec(),
+            data: b"".to_vec(),
+            host_nonce_commitment: None,
+            chain_id: 1,
+            address_case: pb::EthAddressCase::Mixed as _,
+            data_length: 0,
+            max_fee_per_blob_gas: hex!("3b9aca00").to_vec(),
+            blob_versioned_hashes: vec![[[0x01].as_slice(), &[0xaa; 31]].concat()],
+        };
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        match block_on(process(
+            &mut mock_hal,
+            &Transaction::Eip4844(&valid_request),
+        )) {
+            Ok(Response::Sign(pb::EthSignResponse { signature })) => {
+                assert_eq!(signature.len(), 65)
+            }
+            _ => panic!("unexpected response"),
+        }
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "".into(),
+                    body: "Sign transaction on\n\nEthereum".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "0x04F264Cf34440313B4A0192A352814FBe927b885".into(),
+                    amount: "0.530564 ETH".into(),
+                },
+                // fee = 21000 * 6 gwei + 131072 * 1 gwei
+                Screen::TotalFee {
+                    total: "0.530821072 ETH".into(),
+                    fee: "0.000257072 ETH".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        // The worst-case blob fee grows with the number of blobs.
+        let mut request = valid_request.clone();
+        request.blob_versioned_hashes = vec![[[0x01].as_slice(), &[0xaa; 31]].concat(); 6];
+        let mut mock_hal = TestingHal::new();
+        assert!(block_on(process(&mut mock_hal, &Transaction::Eip4844(&request))).is_ok());
+        assert_eq!(
+            mock_hal.ui.screens[2],
+            Screen::TotalFee {
+                total: "0.531476432 ETH".into(),
+                fee: "0.000912432 ETH".into(),
+                longtouch: true,
+            }
+        );
+
+        let invalid_requests = [
+            // No blobs.
+            pb::EthSignEip4844Request {
+                blob_versioned_hashes: vec![],
+                ..valid_request.clone()
+            },
+            // Too many blobs.
+            pb::EthSignEip4844Request {
+                blob_versioned_hashes: vec![[[0x01].as_slice(), &[0xaa; 31]].concat(); 7],
+                ..valid_request.clone()
+            },
+            // Wrong versioned hash version.
+            pb::EthSignEip4844Request {
+                blob_versioned_hashes: vec![[[0x02].as_slice(), &[0xaa; 31]].concat()],
+                ..valid_request.clone()
+            },
+            // Wrong versioned hash length.
+            pb::EthSignEip4844Request {
+                blob_versioned_hashes: vec![[[0x01].as_slice(), &[0xaa; 30]].concat()],
+                ..valid_request.clone()
+            },
+            // max_fee_per_blob_gas with leading zero byte.
+            pb::EthSignEip4844Request {
+                max_fee_per_blob_gas: hex!("003b9aca00").to_vec(),
+                ..valid_request.clone()
+            },
+            // max_fee_per_blob_gas too large.
+            pb::EthSignEip4844Request {
+                max_fee_per_blob_gas: vec![1; 17],
+                ..valid_request.clone()
+            },
+        ];
+        for request in invalid_requests.iter() {
+            assert_eq!(
+                block_on(process(
+                    &mut TestingHal::new(),
+                    &Transaction::Eip4844(request)
+                )),
+                Err(Error::InvalidInput)
+            );
+        }
+    }
+
+    /// Contract creation: empty recipient, the init code is in the data field.
+    #[test]
+    pub fn test_process_contract_creation() {
+        const KEYPATH: &[u32] = &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0];
+        const INIT_CODE: &[u8] = &hex!("6080604052348015600f57600080fd5b50");
+
+        let expected_screens = vec![
+            Screen::Confirm {
+                title: "".into(),
+                body: "Sign transaction on\n\nEthereum".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Deploy\ncontract".into(),
+                body: "Init code:\n17 bytes".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Init code hash".into(),
+                body: "0x9782e38b2927e497dbec51c468bc9da14d403478b2bb602f2236aa3d61a26e68".into(),
+                longtouch: false,
+            },
+            // Derived from the sender 0x773A77b9D32589be03f9132AF759e294f7851be9 and the nonce.
+            Screen::Confirm {
+                title: "Contract address".into(),
+                body: "0xC0c96788d8d01BE6eFC4981218742913de761B88".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Amount".into(),
+                body: "0.530564 ETH".into(),
+                longtouch: false,
+            },
+            Screen::TotalFee {
+                total: "0.531164 ETH".into(),
+                fee: "0.0006 ETH".into(),
+                longtouch: true,
+            },
+            Screen::Status {
+                title: "Transaction\nconfirmed".into(),
+                success: true,
+            },
+        ];
+
+        mock_unlocked();
+        let legacy_request = pb::EthSignRequest {
+            coin: pb::EthCoin::Eth as _,
+            keypath: KEYPATH.to_vec(),
+            nonce: hex!("1fdc").to_vec(),
+            gas_price: hex!("0165a0bc00").to_vec(),
+            gas_limit: hex!("0186a0").to_vec(),
+            recipient: b"".to_vec(),
+            value: hex!("075cf1259e9c4000").to_vec(),
+            data: INIT_CODE.to_vec(),
+            host_nonce_commitment: None,
+            chain_id: 1,
+            address_case: pb::EthAddressCase::Mixed as _,
+            data_length: 0,
+        };
+        let mut mock_hal = TestingHal::new();
+        match block_on(process(
+            &mut mock_hal,
+            &Transaction::Legacy(&legacy_request),
+        )) {
+            Ok(Response::Sign(pb::EthSignResponse { signature })) => {
+                assert_eq!(signature.len(), 65)
+            }
+            _ => panic!("unexpected response"),
+        }
+        assert_eq!(mock_hal.ui.screens, expected_screens);
+
+        let eip1559_request = pb::EthSignEip1559Request {
+            keypath: KEYPATH.to_vec(),
+            nonce: hex!("1fdc").to_vec(),
+            max_priority_fee_per_gas: b"".to_vec(),
+            max_fee_per_gas: hex!("0165a0bc00").to_vec(),
+            gas_limit: hex!("0186a0").to_vec(),
+            recipient: b"".to_vec(),
+            value: hex!("075cf1259e9c4000").to_vec(),
+            data: INIT_CODE.to_vec(),
+            host_nonce_commitment: None,
+            chain_id: 1,
+            address_case: pb::EthAddressCase::Mixed as _,
+            data_length: 0,
+        };
+        let mut mock_hal = TestingHal::new();
+        match block_on(process(
+            &mut mock_hal,
+            &Transaction::Eip1559(&eip1559_request),
+        )) {
+            Ok(Response::Sign(pb::EthSignResponse { signature })) => {
+                assert_eq!(signature.len(), 65)
+            }
+            _ => panic!("unexpected response"),
+        }
+        assert_eq!(mock_hal.ui.screens, expected_screens);
+
+        // Streamed init code is requested only once. A host that would answer a second request
+        // with different init code can't make the device sign code other than the confirmed one.
+        let inline_signature = match block_on(process(
+            &mut TestingHal::new(),
+            &Transaction::Legacy(&legacy_request),
+        )) {
+            Ok(Response::Sign(pb::EthSignResponse { signature })) => signature,
+            _ => panic!("unexpected response"),
+        };
+        let num_requests = Rc::new(Cell::new(0));
+        {
+            let num_requests = num_requests.clone();
+            *crate::hww::MOCK_NEXT_REQUEST.0.borrow_mut() = Some(Box::new(move |response| {
+                let chunk = match num_requests.get() {
+                    0 => INIT_CODE.to_vec(),
+                    _ => vec![0xfe; INIT_CODE.len()],
+                };
+                num_requests.set(num_requests.get() + 1);
+                match response {
+                    crate::pb::response::Response::Eth(pb::EthResponse {
+                        response: Some(pb::eth_response::Response::DataRequestChunk(_)),
+                    }) => Ok(crate::pb::request::Request::Eth(pb::EthRequest {
+                        request: Some(pb::eth_request::Request::DataResponseChunk(
+                            pb::EthSignDataResponseChunkRequest { chunk },
+                        )),
+                    })),
+                    _ => panic!("unexpected response"),
+                }
+            }));
+        }
+        let mut mock_hal = TestingHal::new();
+        match block_on(process(
+            &mut mock_hal,
+            &Transaction::Legacy(&pb::EthSignRequest {
+                data: vec![],
+                data_length: INIT_CODE.len() as _,
+                ..legacy_request.clone()
+            }),
+        )) {
+            Ok(Response::Sign(pb::EthSignResponse { signature })) => {
+                assert_eq!(signature, inline_signature)
+            }
+            _ => panic!("unexpected response"),
+        }
+        clear_chunk_responder();
+        assert_eq!(num_requests.get(), 1);
+        assert_eq!(mock_hal.ui.screens, expected_screens);
+
+        // No value: the amount screen is skipped.
+        let mut mock_hal = TestingHal::new();
+        assert!(
+            block_on(process(
+                &mut mock_hal,
+                &Transaction::Legacy(&pb::EthSignRequest {
+                    value: b"".to_vec(),
+                    ..legacy_request.clone()
+                })
+            ))
+            .is_ok()
+        );
+        assert_eq!(
+            mock_hal.ui.screens[4],
+            Screen::TotalFee {
+                total: "0.0006 ETH".into(),
+                fee: "0.0006 ETH".into(),
+                longtouch: true,
+            }
+        );
+
+        // No init code.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &Transaction::Legacy(&pb::EthSignRequest {
+                    data: b"".to_vec(),
+                    ..legacy_request.clone()
+                })
+            )),
+            Err(Error::InvalidInput)
+        );
+
+        // Blob transactions can't create contracts.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &Transaction::Eip4844(&pb::EthSignEip4844Request {
+                    keypath: KEYPATH.to_vec(),
+                    nonce: hex!("1fdc").to_vec(),
+                    max_priority_fee_per_gas: b"".to_vec(),
+                    max_fee_per_gas: hex!("0165a0bc00").to_vec(),
+                    gas_limit: hex!("0186a0").to_vec(),
+                    recipient: b"".to_vec(),
+                    value: b"".to_vec(),
+                    data: INIT_CODE.to_vec(),
+                    host_nonce_commitment: None,
+                    chain_id: 1,
+                    address_case: pb::EthAddressCase::Mixed as _,
+                    data_length: 0,
+                    max_fee_per_blob_gas: hex!("3b9aca00").to_vec(),
+                    blob_versioned_hashes: vec![[[0x01].as_slice(), &[0xaa; 31]].concat()],
+                })
+            )),
+            Err(Error::InvalidInput)
+        );
+    }
+
     /// Unknown chain ID (network params not hardcoded in in the firmware).
     #[test]
     pub fn test_process_unknown_network() {
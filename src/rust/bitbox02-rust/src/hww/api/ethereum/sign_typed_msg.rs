@@ -7,11 +7,13 @@
 //! https://github.com/MetaMask/eth-sig-util/blob/v4.0.1/src/sign-typed-data.ts
 //! using SignTypedDataVersion.V4.
 
+mod presets;
+
 use super::Error;
 use super::pb;
 
-use crate::hal::Ui;
 use crate::hal::ui::ConfirmParams;
+use crate::hal::{Memory, Ui};
 use crate::keystore;
 
 use pb::eth_request::Request;
@@ -256,6 +258,24 @@ fn encode_value(typ: &MemberType, value: Vec<u8>) -> Result<(Vec<u8>, String), E
     Ok(result)
 }
 
+/// Raw values sent by the host while hashing, keyed by root object and member path. Array sizes that
+/// are sent by the host are included at the path of the array.
+#[derive(Default)]
+struct CollectedValues(Vec<(RootObject, Vec<u32>, Vec<u8>)>);
+
+impl CollectedValues {
+    fn push(&mut self, root_object: RootObject, path: &[u32], value: &[u8]) {
+        self.0.push((root_object, path.to_vec(), value.to_vec()));
+    }
+
+    fn get(&self, root_object: RootObject, path: &[u32]) -> Option<&[u8]> {
+        self.0
+            .iter()
+            .find(|(r, p, _)| *r == root_object && p == path)
+            .map(|(_, _, value)| value.as_slice())
+    }
+}
+
 fn confirm_title(root_object: RootObject) -> &'static str {
     match root_object {
         RootObject::Unknown => "Unknown",
@@ -274,6 +294,7 @@ async fn encode_member<U: sha3::digest::Update>(
     path: &[u32],
     formatted_path: &[String],
     title_suffix: Option<String>,
+    collect: Option<&mut CollectedValues>,
 ) -> Result<(), Error> {
     if member_type.r#type == DataType::Struct as i32 {
         let value_encoded = Box::pin(hash_struct(
@@ -284,6 +305,7 @@ async fn encode_member<U: sha3::digest::Update>(
             path,
             formatted_path,
             title_suffix,
+            collect,
         ))
         .await?;
         hasher.update(&value_encoded);
@@ -296,11 +318,19 @@ async fn encode_member<U: sha3::digest::Update>(
             path,
             formatted_path,
             title_suffix,
+            collect,
         ))
         .await?;
         hasher.update(&encoded_value);
     } else {
         let value = get_value_from_host(root_object, path).await?;
+        if let Some(collect) = collect {
+            // The collected values are summarized by the caller instead of being shown one by one.
+            collect.push(root_object, path, &value);
+            let (value_encoded, _) = encode_value(member_type, value)?;
+            hasher.update(&value_encoded);
+            return Ok(());
+        }
         let (value_encoded, value_formatted) = encode_value(member_type, value)?;
         let lines: Vec<&str> = value_formatted.split('\n').collect();
         for (i, &line) in lines.iter().enumerate() {
@@ -332,6 +362,7 @@ async fn encode_member<U: sha3::digest::Update>(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn hash_array(
     hal: &mut impl crate::hal::Hal,
     types: &[StructType],
@@ -340,37 +371,43 @@ async fn hash_array(
     path: &[u32],
     formatted_path: &[String],
     title_suffix: Option<String>,
+    mut collect: Option<&mut CollectedValues>,
 ) -> Result<Vec<u8>, Error> {
     let array_size = if member_type.size > 0 {
         member_type.size
     } else {
         let array_size_encoded = get_value_from_host(root_object, path).await?;
+        if let Some(collect) = collect.as_deref_mut() {
+            collect.push(root_object, path, &array_size_encoded);
+        }
         u32::from_be_bytes(array_size_encoded.try_into().or(Err(Error::InvalidInput))?)
     };
 
     let array_type = member_type.array_type.as_ref().ok_or(Error::InvalidInput)?;
 
-    hal.ui()
-        .confirm(&ConfirmParams {
-            title: &format!(
-                "{}{}",
-                confirm_title(root_object),
-                title_suffix.as_deref().unwrap_or("")
-            ),
-            body: &format!(
-                "{}: {}",
-                formatted_path.join("."),
-                if array_size == 0 {
-                    "(empty list)".into()
-                } else {
-                    format!("list with {} elements", array_size)
-                }
-            ),
-            scrollable: true,
-            accept_is_nextarrow: true,
-            ..Default::default()
-        })
-        .await?;
+    if collect.is_none() {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: &format!(
+                    "{}{}",
+                    confirm_title(root_object),
+                    title_suffix.as_deref().unwrap_or("")
+                ),
+                body: &format!(
+                    "{}: {}",
+                    formatted_path.join("."),
+                    if array_size == 0 {
+                        "(empty list)".into()
+                    } else {
+                        format!("list with {} elements", array_size)
+                    }
+                ),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
 
     let mut hasher = sha3::Keccak256::new();
     let mut child_path = path.to_vec();
@@ -391,12 +428,14 @@ async fn hash_array(
             &child_path,
             &child_formatted_path,
             title_suffix.clone(),
+            collect.as_deref_mut(),
         )
         .await?;
     }
     Ok(hasher.finalize().to_vec())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn hash_struct(
     hal: &mut impl crate::hal::Hal,
     types: &[StructType],
@@ -405,6 +444,7 @@ async fn hash_struct(
     path: &[u32],
     formatted_path: &[String],
     title_suffix: Option<String>,
+    mut collect: Option<&mut CollectedValues>,
 ) -> Result<Vec<u8>, Error> {
     let mut hasher = sha3::Keccak256::new();
     hasher.update(&type_hash(types, struct_name)?);
@@ -434,6 +474,7 @@ async fn hash_struct(
             } else {
                 Some(format!(" ({}/{})", index + 1, typ.members.len()))
             },
+            collect.as_deref_mut(),
         )
         .await?;
     }
@@ -468,10 +509,13 @@ async fn validate_chain_id(request: &pb::EthSignTypedMessageRequest) -> Result<(
     Ok(())
 }
 
+/// Computes the EIP-712 sighash. If `collect` is `None`, all values are shown to the user while
+/// hashing. Otherwise, nothing is shown and the values are collected instead.
 async fn eip712_sighash(
     hal: &mut impl crate::hal::Hal,
     types: &[StructType],
     primary_type: &str,
+    mut collect: Option<&mut CollectedValues>,
 ) -> Result<[u8; 32], Error> {
     let mut hasher = sha3::Keccak256::new();
     hasher.update([0x19u8, 0x01]);
@@ -483,6 +527,7 @@ async fn eip712_sighash(
         &[],
         &[],
         None,
+        collect.as_deref_mut(),
     )
     .await?;
     hasher.update(&domain_separator);
@@ -498,6 +543,7 @@ async fn eip712_sighash(
             &[],
             &[],
             None,
+            collect,
         )
         .await?;
         hasher.update(&message_struct_hash);
@@ -505,6 +551,68 @@ async fn eip712_sighash(
     Ok(hasher.finalize().into())
 }
 
+/// Returns the precomputed domain separator and message hash if the host requested blind signing,
+/// i.e. if it provided them instead of the types and values of the message.
+fn get_blind_hashes(
+    request: &pb::EthSignTypedMessageRequest,
+) -> Result<Option<([u8; 32], [u8; 32])>, Error> {
+    if request.domain_separator.is_empty() && request.message_hash.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some((
+        request
+            .domain_separator
+            .as_slice()
+            .try_into()
+            .or(Err(Error::InvalidInput))?,
+        request
+            .message_hash
+            .as_slice()
+            .try_into()
+            .or(Err(Error::InvalidInput))?,
+    )))
+}
+
+/// Shows the precomputed hashes of a blind signing request and returns the EIP-712 sighash.
+async fn blind_sighash(
+    hal: &mut impl crate::hal::Hal,
+    domain_separator: &[u8; 32],
+    message_hash: &[u8; 32],
+) -> Result<[u8; 32], Error> {
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Warning",
+            body: "Blind signing!\nThe message contents\ncannot be verified.",
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Domain hash",
+            body: &format!("0x{}", hex::encode(domain_separator)),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Message hash",
+            body: &format!("0x{}", hex::encode(message_hash)),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+
+    let mut hasher = sha3::Keccak256::new();
+    hasher.update([0x19u8, 0x01]);
+    hasher.update(domain_separator);
+    hasher.update(message_hash);
+    Ok(hasher.finalize().into())
+}
+
 /// Process a EIP-712 sign typed message request.
 ///
 /// https://eips.ethereum.org/EIPS/eip-712
@@ -515,7 +623,12 @@ pub async fn process(
     hal: &mut impl crate::hal::Hal,
     request: &pb::EthSignTypedMessageRequest,
 ) -> Result<Response, Error> {
-    validate_chain_id(request).await?;
+    let blind_hashes = get_blind_hashes(request)?;
+    match blind_hashes {
+        Some(_) if !hal.memory().is_blind_signing_enabled() => return Err(Error::Disabled),
+        Some(_) => {}
+        None => validate_chain_id(request).await?,
+    }
 
     // Base component on the screen stack during signing, which is shown while the device is waiting
     // for the next signing api call. Without this, the 'See the BitBoxApp' waiting screen would
@@ -523,9 +636,9 @@ pub async fn process(
     let mut empty_component = bitbox02::ui::empty_create();
     empty_component.screen_stack_push();
 
-    // Verify address. We don't need the actual result, but we have to propagate validation or user
-    // abort errors.
-    super::pubrequest::process(
+    // Verify address. The address is also needed to tell which side of a summarized message
+    // belongs to the user.
+    let address = match super::pubrequest::process(
         hal,
         &pb::EthPubRequest {
             output_type: pb::eth_pub_request::OutputType::Address as _,
@@ -536,9 +649,22 @@ pub async fn process(
             chain_id: request.chain_id,
         },
     )
-    .await?;
+    .await?
+    {
+        Response::Pub(pb::PubResponse { r#pub }) => r#pub,
+        _ => return Err(Error::Generic),
+    };
 
-    let sighash: [u8; 32] = eip712_sighash(hal, &request.types, &request.primary_type).await?;
+    let sighash: [u8; 32] = match blind_hashes {
+        Some((domain_separator, message_hash)) => {
+            blind_sighash(hal, &domain_separator, &message_hash).await?
+        }
+        // Well-known messages are summarized. All others are shown member by member.
+        None => match presets::process(hal, request, &address).await? {
+            Some(sighash) => sighash,
+            None => eip712_sighash(hal, &request.types, &request.primary_type, None).await?,
+        },
+    };
 
     hal.ui()
         .confirm(&ConfirmParams {
@@ -590,6 +716,7 @@ mod tests {
     use crate::hal::testing::TestingHal;
     use crate::hal::testing::ui::Screen;
     use crate::keystore::testing::mock_unlocked;
+    use hex_lit::hex;
     use util::bb02_async::block_on;
     use util::bip32::HARDENED;
 
@@ -985,6 +1112,7 @@ mod tests {
             &[],
             &[],
             None,
+            None,
         ))
         .unwrap();
         assert_eq!(
@@ -1506,6 +1634,7 @@ mod tests {
             &mut mock_hal,
             &typed_msg.types,
             typed_msg.primary_type,
+            None,
         ))
         .unwrap();
         assert_eq!(
@@ -1593,6 +1722,7 @@ mod tests {
             &mut TestingHal::new(),
             &typed_msg.types,
             typed_msg.primary_type,
+            None,
         ))
         .unwrap();
         assert_eq!(
@@ -1600,4 +1730,784 @@ mod tests {
             *b"\xaa\x83\xc7\x03\x05\xec\x6c\x13\x1e\x7a\x88\xf2\x58\xc4\x08\x13\x44\x7b\xec\x8b\x9b\xce\xf9\x4e\x54\x79\x60\x3d\x99\x59\xda\x07",
         );
     }
+
+    /// Sets up the host to respond with the values of the given typed message.
+    fn mock_host_responses(typed_msg: TypedMessage<'static>) {
+        *crate::hww::MOCK_NEXT_REQUEST.0.borrow_mut() = Some(Box::new(move |response| {
+            Ok(typed_msg.handle_host_response(&response).unwrap())
+        }));
+    }
+
+    const ADDRESS: &str = "0x773A77b9D32589be03f9132AF759e294f7851be9";
+    const USDC_ADDRESS: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+    const DAI_ADDRESS: &str = "0x6B175474E89094C44Da98b954EedeAC495271d0F";
+    const SPENDER_ADDRESS: &str = "0x04F264Cf34440313B4A0192A352814FBe927b885";
+
+    fn make_permit_message(
+        verifying_contract: &'static str,
+        owner: &'static str,
+    ) -> TypedMessage<'static> {
+        TypedMessage {
+            types: vec![
+                StructType {
+                    name: "EIP712Domain".into(),
+                    members: vec![
+                        mk_member("name", mk_type(DataType::String)),
+                        mk_member("version", mk_type(DataType::String)),
+                        mk_member("chainId", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("verifyingContract", mk_type(DataType::Address)),
+                    ],
+                },
+                StructType {
+                    name: "Permit".into(),
+                    members: vec![
+                        mk_member("owner", mk_type(DataType::Address)),
+                        mk_member("spender", mk_type(DataType::Address)),
+                        mk_member("value", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("nonce", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("deadline", mk_sized_type(DataType::Uint, 32)),
+                    ],
+                },
+            ],
+            primary_type: "Permit",
+            domain: Object::Struct(vec![
+                Object::String("USD Coin"),
+                Object::String("2"),
+                Object::BigUint(BigUint::from(1u32)),
+                Object::String(verifying_contract),
+            ]),
+            message: Object::Struct(vec![
+                Object::String(owner),
+                Object::String(SPENDER_ADDRESS),
+                Object::BigUint(BigUint::from(1000000u32)),
+                Object::BigUint(BigUint::from(0u32)),
+                Object::BigUint(BigUint::from(1601281809u32)),
+            ]),
+        }
+    }
+
+    fn make_request(typed_msg: &TypedMessage<'_>) -> pb::EthSignTypedMessageRequest {
+        pb::EthSignTypedMessageRequest {
+            chain_id: 1,
+            keypath: vec![44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0],
+            types: typed_msg.types.clone(),
+            primary_type: typed_msg.primary_type.into(),
+            host_nonce_commitment: None,
+            domain_separator: vec![],
+            message_hash: vec![],
+        }
+    }
+
+    /// Computes the sighash of the message without any summary.
+    fn full_sighash(typed_msg: TypedMessage<'static>) -> [u8; 32] {
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        block_on(eip712_sighash(
+            &mut TestingHal::new(),
+            &request.types,
+            &request.primary_type,
+            None,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_process_preset_permit() {
+        let typed_msg = make_permit_message(USDC_ADDRESS, ADDRESS);
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let response = block_on(process(&mut mock_hal, &request)).unwrap();
+        match response {
+            Response::Sign(pb::EthSignResponse { signature }) => assert_eq!(signature.len(), 65),
+            _ => panic!("unexpected response"),
+        }
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Ethereum".into(),
+                    body: ADDRESS.into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Permit".into(),
+                    body: "Allow spender\nto spend\n1 USDC".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Spender".into(),
+                    body: SPENDER_ADDRESS.into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Valid until".into(),
+                    body: "Mon 2020-09-28\n08:30 UTC".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "".into(),
+                    body: "Sign data?".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preset_permit_sighash() {
+        let expected_sighash = full_sighash(make_permit_message(USDC_ADDRESS, ADDRESS));
+
+        let typed_msg = make_permit_message(USDC_ADDRESS, ADDRESS);
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(Some(expected_sighash))
+        );
+    }
+
+    #[test]
+    fn test_preset_permit_fallback() {
+        // Unknown token.
+        let typed_msg = make_permit_message("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC", ADDRESS);
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(None)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Owner is not the signer.
+        let typed_msg = make_permit_message(USDC_ADDRESS, SPENDER_ADDRESS);
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(None)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Additional member in the message type.
+        let mut typed_msg = make_permit_message(USDC_ADDRESS, ADDRESS);
+        typed_msg.types[1]
+            .members
+            .push(mk_member("extra", mk_type(DataType::String)));
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(None)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+    }
+
+    #[test]
+    fn test_preset_domain_from_sighash_pass() {
+        // The domain values are fetched from the host only once, in the same pass that computes
+        // the sighash.
+        let expected_sighash = full_sighash(make_permit_message(USDC_ADDRESS, ADDRESS));
+        let typed_msg = make_permit_message(USDC_ADDRESS, ADDRESS);
+        let request = make_request(&typed_msg);
+        let domain_requests = alloc::rc::Rc::new(core::cell::RefCell::new(Vec::new()));
+        let domain_requests_mock = domain_requests.clone();
+        *crate::hww::MOCK_NEXT_REQUEST.0.borrow_mut() = Some(Box::new(move |response| {
+            if let pb::response::Response::Eth(pb::EthResponse {
+                response: Some(Response::TypedMsgValue(value_response)),
+            }) = &response
+            {
+                if value_response.root_object == RootObject::Domain as i32 {
+                    domain_requests_mock
+                        .borrow_mut()
+                        .push(value_response.path.clone());
+                }
+            }
+            Ok(typed_msg.handle_host_response(&response).unwrap())
+        }));
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(Some(expected_sighash))
+        );
+        assert_eq!(
+            *domain_requests.borrow(),
+            vec![vec![0], vec![1], vec![2], vec![3]]
+        );
+
+        // The chain ID in the domain does not match the request.
+        let typed_msg = make_permit_message(USDC_ADDRESS, ADDRESS);
+        let mut request = make_request(&typed_msg);
+        request.chain_id = 5;
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(None)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+    }
+
+    fn make_permit2_batch_message() -> TypedMessage<'static> {
+        TypedMessage {
+            types: vec![
+                StructType {
+                    name: "EIP712Domain".into(),
+                    members: vec![
+                        mk_member("name", mk_type(DataType::String)),
+                        mk_member("chainId", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("verifyingContract", mk_type(DataType::Address)),
+                    ],
+                },
+                StructType {
+                    name: "PermitDetails".into(),
+                    members: vec![
+                        mk_member("token", mk_type(DataType::Address)),
+                        mk_member("amount", mk_sized_type(DataType::Uint, 20)),
+                        mk_member("expiration", mk_sized_type(DataType::Uint, 6)),
+                        mk_member("nonce", mk_sized_type(DataType::Uint, 6)),
+                    ],
+                },
+                StructType {
+                    name: "PermitBatch".into(),
+                    members: vec![
+                        mk_member("details", mk_arr_type(mk_struct_type("PermitDetails"))),
+                        mk_member("spender", mk_type(DataType::Address)),
+                        mk_member("sigDeadline", mk_sized_type(DataType::Uint, 32)),
+                    ],
+                },
+            ],
+            primary_type: "PermitBatch",
+            domain: Object::Struct(vec![
+                Object::String("Permit2"),
+                Object::BigUint(BigUint::from(1u32)),
+                Object::String("0x000000000022D473030F116dDEE9F6B43aC78BA3"),
+            ]),
+            message: Object::Struct(vec![
+                Object::List(vec![
+                    Object::Struct(vec![
+                        Object::String(USDC_ADDRESS),
+                        Object::BigUint(BigUint::from_bytes_be(&[0xff; 20])),
+                        Object::BigUint(BigUint::from(1601281809u32)),
+                        Object::BigUint(BigUint::from(0u32)),
+                    ]),
+                    Object::Struct(vec![
+                        Object::String(DAI_ADDRESS),
+                        Object::BigUint(BigUint::from(5500000000000000000u64)),
+                        Object::BigUint(BigUint::from(1601281809u32)),
+                        Object::BigUint(BigUint::from(1u32)),
+                    ]),
+                ]),
+                Object::String(SPENDER_ADDRESS),
+                Object::BigUint(BigUint::from_bytes_be(&[0xff; 32])),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_preset_permit2_batch() {
+        let expected_sighash = full_sighash(make_permit2_batch_message());
+
+        let typed_msg = make_permit2_batch_message();
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(Some(expected_sighash))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            [
+                ("Permit2 (1/2)", "Allow spender\nto spend\nUnlimited USDC"),
+                ("Expires (1/2)", "Mon 2020-09-28\n08:30 UTC"),
+                ("Permit2 (2/2)", "Allow spender\nto spend\n5.5 DAI"),
+                ("Expires (2/2)", "Mon 2020-09-28\n08:30 UTC"),
+                ("Spender", SPENDER_ADDRESS),
+                ("Valid until", "No deadline"),
+            ]
+            .iter()
+            .map(|&(title, body)| Screen::Confirm {
+                title: title.into(),
+                body: body.into(),
+                longtouch: false,
+            })
+            .collect::<Vec<_>>()
+        );
+    }
+
+    fn make_seaport_order_message(
+        offerer: &'static str,
+        verifying_contract: &'static str,
+    ) -> TypedMessage<'static> {
+        const NFT_ADDRESS: &str = "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D";
+        const FEE_ADDRESS: &str = "0x0000a26b00c1F0DF003000390027140000fAa719";
+        let mk_consideration = |amount: u64, recipient: &'static str| {
+            Object::Struct(vec![
+                Object::BigUint(BigUint::from(0u32)),
+                Object::String("0x0000000000000000000000000000000000000000"),
+                Object::BigUint(BigUint::from(0u32)),
+                Object::BigUint(BigUint::from(amount)),
+                Object::BigUint(BigUint::from(amount)),
+                Object::String(recipient),
+            ])
+        };
+        TypedMessage {
+            types: vec![
+                StructType {
+                    name: "EIP712Domain".into(),
+                    members: vec![
+                        mk_member("name", mk_type(DataType::String)),
+                        mk_member("version", mk_type(DataType::String)),
+                        mk_member("chainId", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("verifyingContract", mk_type(DataType::Address)),
+                    ],
+                },
+                StructType {
+                    name: "OrderComponents".into(),
+                    members: vec![
+                        mk_member("offerer", mk_type(DataType::Address)),
+                        mk_member("zone", mk_type(DataType::Address)),
+                        mk_member("offer", mk_arr_type(mk_struct_type("OfferItem"))),
+                        mk_member(
+                            "consideration",
+                            mk_arr_type(mk_struct_type("ConsiderationItem")),
+                        ),
+                        mk_member("orderType", mk_sized_type(DataType::Uint, 1)),
+                        mk_member("startTime", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("endTime", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("zoneHash", mk_sized_type(DataType::Bytes, 32)),
+                        mk_member("salt", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("conduitKey", mk_sized_type(DataType::Bytes, 32)),
+                        mk_member("counter", mk_sized_type(DataType::Uint, 32)),
+                    ],
+                },
+                StructType {
+                    name: "OfferItem".into(),
+                    members: vec![
+                        mk_member("itemType", mk_sized_type(DataType::Uint, 1)),
+                        mk_member("token", mk_type(DataType::Address)),
+                        mk_member("identifierOrCriteria", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("startAmount", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("endAmount", mk_sized_type(DataType::Uint, 32)),
+                    ],
+                },
+                StructType {
+                    name: "ConsiderationItem".into(),
+                    members: vec![
+                        mk_member("itemType", mk_sized_type(DataType::Uint, 1)),
+                        mk_member("token", mk_type(DataType::Address)),
+                        mk_member("identifierOrCriteria", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("startAmount", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("endAmount", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("recipient", mk_type(DataType::Address)),
+                    ],
+                },
+            ],
+            primary_type: "OrderComponents",
+            domain: Object::Struct(vec![
+                Object::String("Seaport"),
+                Object::String("1.6"),
+                Object::BigUint(BigUint::from(1u32)),
+                Object::String(verifying_contract),
+            ]),
+            message: Object::Struct(vec![
+                Object::String(offerer),
+                Object::String("0x0000000000000000000000000000000000000000"),
+                Object::List(vec![Object::Struct(vec![
+                    Object::BigUint(BigUint::from(2u32)),
+                    Object::String(NFT_ADDRESS),
+                    Object::BigUint(BigUint::from(1234u32)),
+                    Object::BigUint(BigUint::from(1u32)),
+                    Object::BigUint(BigUint::from(1u32)),
+                ])]),
+                Object::List(vec![
+                    mk_consideration(1000000000000000000, ADDRESS),
+                    mk_consideration(25000000000000000, FEE_ADDRESS),
+                ]),
+                Object::BigUint(BigUint::from(0u32)),
+                Object::BigUint(BigUint::from(1601200000u32)),
+                Object::BigUint(BigUint::from(1601281809u32)),
+                Object::Bytes(&[0; 32]),
+                Object::BigUint(BigUint::from(42u32)),
+                Object::Bytes(&[0; 32]),
+                Object::BigUint(BigUint::from(0u32)),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_preset_seaport_order() {
+        const SEAPORT_ADDRESS: &str = "0x0000000000000068F116a894984e2DCa6BB7B8C4";
+        let expected_sighash = full_sighash(make_seaport_order_message(ADDRESS, SEAPORT_ADDRESS));
+
+        let typed_msg = make_seaport_order_message(ADDRESS, SEAPORT_ADDRESS);
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(Some(expected_sighash))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            [
+                (
+                    "You offer (1/1)",
+                    "NFT #1234\n0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D"
+                ),
+                ("You receive (1/1)", "1 ETH"),
+                (
+                    "Fee (1/1)",
+                    "0.025 ETH\nto 0x0000a26b00c1F0DF003000390027140000fAa719"
+                ),
+                ("Valid until", "Mon 2020-09-28\n08:30 UTC"),
+            ]
+            .iter()
+            .map(|&(title, body)| Screen::Confirm {
+                title: title.into(),
+                body: body.into(),
+                longtouch: false,
+            })
+            .collect::<Vec<_>>()
+        );
+
+        // Unknown Seaport deployment.
+        let typed_msg =
+            make_seaport_order_message(ADDRESS, "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC");
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(None)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Offerer is not the signer.
+        let typed_msg = make_seaport_order_message(SPENDER_ADDRESS, SEAPORT_ADDRESS);
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(None)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+    }
+
+    fn make_safe_tx_message(
+        to: &'static str,
+        value: u64,
+        data: &'static [u8],
+        operation: u8,
+        gas_price: u32,
+    ) -> TypedMessage<'static> {
+        TypedMessage {
+            types: vec![
+                StructType {
+                    name: "EIP712Domain".into(),
+                    members: vec![
+                        mk_member("chainId", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("verifyingContract", mk_type(DataType::Address)),
+                    ],
+                },
+                StructType {
+                    name: "SafeTx".into(),
+                    members: vec![
+                        mk_member("to", mk_type(DataType::Address)),
+                        mk_member("value", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("data", mk_type(DataType::Bytes)),
+                        mk_member("operation", mk_sized_type(DataType::Uint, 1)),
+                        mk_member("safeTxGas", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("baseGas", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("gasPrice", mk_sized_type(DataType::Uint, 32)),
+                        mk_member("gasToken", mk_type(DataType::Address)),
+                        mk_member("refundReceiver", mk_type(DataType::Address)),
+                        mk_member("nonce", mk_sized_type(DataType::Uint, 32)),
+                    ],
+                },
+            ],
+            primary_type: "SafeTx",
+            domain: Object::Struct(vec![
+                Object::BigUint(BigUint::from(1u32)),
+                Object::String(SAFE_ADDRESS),
+            ]),
+            message: Object::Struct(vec![
+                Object::String(to),
+                Object::BigUint(BigUint::from(value)),
+                Object::Bytes(data),
+                Object::BigUint(BigUint::from(operation)),
+                Object::BigUint(BigUint::from(0u32)),
+                Object::BigUint(BigUint::from(0u32)),
+                Object::BigUint(BigUint::from(gas_price)),
+                Object::String("0x0000000000000000000000000000000000000000"),
+                Object::String("0x0000000000000000000000000000000000000000"),
+                Object::BigUint(BigUint::from(7u32)),
+            ]),
+        }
+    }
+
+    const SAFE_ADDRESS: &str = "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC";
+
+    /// Checks that the SafeTx is summarized with the given screens and hashed correctly.
+    fn assert_safe_tx_summary(
+        make_msg: impl Fn() -> TypedMessage<'static>,
+        expected_screens: &[(&str, &str)],
+    ) {
+        let expected_sighash = full_sighash(make_msg());
+        let typed_msg = make_msg();
+        let request = make_request(&typed_msg);
+        mock_host_responses(typed_msg);
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+            Ok(Some(expected_sighash))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            expected_screens
+                .iter()
+                .map(|&(title, body)| Screen::Confirm {
+                    title: title.into(),
+                    body: body.into(),
+                    longtouch: false,
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_preset_safe_tx() {
+        // USDC transfer.
+        const TRANSFER_DATA: &[u8] = &hex!(
+            "a9059cbb00000000000000000000000004f264cf34440313b4a0192a352814fbe927b88500000000000000000000000000000000000000000000000000000000000f4240"
+        );
+        assert_safe_tx_summary(
+            || make_safe_tx_message(USDC_ADDRESS, 0, TRANSFER_DATA, 0, 0),
+            &[
+                ("Safe", SAFE_ADDRESS),
+                (
+                    "Send",
+                    "1 USDC\nto 0x04F264Cf34440313B4A0192A352814FBe927b885",
+                ),
+                ("Safe nonce", "7"),
+            ],
+        );
+
+        // Plain ETH transfer.
+        assert_safe_tx_summary(
+            || make_safe_tx_message(SPENDER_ADDRESS, 1000000000000000000, b"", 0, 0),
+            &[
+                ("Safe", SAFE_ADDRESS),
+                (
+                    "Send",
+                    "1 ETH\nto 0x04F264Cf34440313B4A0192A352814FBe927b885",
+                ),
+                ("Safe nonce", "7"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_preset_safe_tx_multi_send() {
+        // multiSend() of 1 ETH and 1 USDC sent to SPENDER_ADDRESS.
+        const MULTI_SEND_DATA: &[u8] = &hex!(
+            "8d80ff0a000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000ee0004f264cf34440313b4a0192a352814fbe927b8850000000000000000000000000000000000000000000000000de0b6b3a7640000000000000000000000000000000000000000000000000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb4800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000044a9059cbb00000000000000000000000004f264cf34440313b4a0192a352814fbe927b88500000000000000000000000000000000000000000000000000000000000f4240000000000000000000000000000000000000"
+        );
+        const MULTI_SEND_CALL_ONLY: &str = "0x9641d764fc13c8B624c04430C7356C1C7C8102e2";
+        assert_safe_tx_summary(
+            || make_safe_tx_message(MULTI_SEND_CALL_ONLY, 0, MULTI_SEND_DATA, 1, 0),
+            &[
+                ("Safe", SAFE_ADDRESS),
+                (
+                    "Send (1/2)",
+                    "1 ETH\nto 0x04F264Cf34440313B4A0192A352814FBe927b885",
+                ),
+                (
+                    "Send (2/2)",
+                    "1 USDC\nto 0x04F264Cf34440313B4A0192A352814FBe927b885",
+                ),
+                ("Safe nonce", "7"),
+            ],
+        );
+
+        // The same batch sent to an unknown contract is not unpacked, and the delegatecall is
+        // flagged.
+        const OTHER_CONTRACT: &str = "0x1111111111111111111111111111111111111111";
+        let data_hex = format!("0x{}", hex::encode(MULTI_SEND_DATA));
+        assert_safe_tx_summary(
+            || make_safe_tx_message(OTHER_CONTRACT, 0, MULTI_SEND_DATA, 1, 0),
+            &[
+                ("Safe", SAFE_ADDRESS),
+                (
+                    "Warning",
+                    "Delegatecall to\n0x1111111111111111111111111111111111111111\nThis contract can\ntake full control\nof the Safe!",
+                ),
+                ("Contract call", OTHER_CONTRACT),
+                ("Data", &data_hex),
+                ("Safe nonce", "7"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_preset_safe_tx_contract_call() {
+        assert_safe_tx_summary(
+            || make_safe_tx_message(SPENDER_ADDRESS, 50000000000000000, &hex!("deadbeef"), 0, 0),
+            &[
+                ("Safe", SAFE_ADDRESS),
+                ("Contract call", SPENDER_ADDRESS),
+                ("Value", "0.05 ETH"),
+                ("Data", "0xdeadbeef"),
+                ("Safe nonce", "7"),
+            ],
+        );
+
+        // `transferFrom()` of an unknown token could be an ERC-20 transfer, so it is not shown as
+        // an NFT transfer.
+        const TRANSFER_FROM_DATA: &[u8] = &hex!(
+            "23b872dd000000000000000000000000cccccccccccccccccccccccccccccccccccccccc00000000000000000000000004f264cf34440313b4a0192a352814fbe927b88500000000000000000000000000000000000000000000000000000000000f4240"
+        );
+        const UNKNOWN_TOKEN: &str = "0x1111111111111111111111111111111111111111";
+        let data_hex = format!("0x{}", hex::encode(TRANSFER_FROM_DATA));
+        assert_safe_tx_summary(
+            || make_safe_tx_message(UNKNOWN_TOKEN, 0, TRANSFER_FROM_DATA, 0, 0),
+            &[
+                ("Safe", SAFE_ADDRESS),
+                ("Contract call", UNKNOWN_TOKEN),
+                ("Data", &data_hex),
+                ("Safe nonce", "7"),
+            ],
+        );
+
+        // Invalid operation, or a gas refund paid by the Safe: shown member by member.
+        for typed_msg in [
+            make_safe_tx_message(SPENDER_ADDRESS, 0, b"", 2, 0),
+            make_safe_tx_message(SPENDER_ADDRESS, 0, b"", 0, 1),
+        ] {
+            let request = make_request(&typed_msg);
+            mock_host_responses(typed_msg);
+            let mut mock_hal = TestingHal::new();
+            assert_eq!(
+                block_on(presets::process(&mut mock_hal, &request, ADDRESS)),
+                Ok(None)
+            );
+            assert!(mock_hal.ui.screens.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_process_blind() {
+        // Mail example from the EIP-712 spec.
+        const DOMAIN_SEPARATOR: [u8; 32] =
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f");
+        const MESSAGE_HASH: [u8; 32] =
+            hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e");
+        const SIGHASH: [u8; 32] =
+            hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+
+        let request = pb::EthSignTypedMessageRequest {
+            chain_id: 1,
+            keypath: vec![44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0],
+            types: vec![],
+            primary_type: "".into(),
+            host_nonce_commitment: None,
+            domain_separator: DOMAIN_SEPARATOR.to_vec(),
+            message_hash: MESSAGE_HASH.to_vec(),
+        };
+
+        // Blind signing not enabled.
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(process(&mut mock_hal, &request)),
+            Err(Error::Disabled)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // All good.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.memory.set_blind_signing_enabled(true).unwrap();
+        let expected = crate::secp256k1::secp256k1_sign(
+            keystore::secp256k1_get_private_key(&mut mock_hal, &request.keypath)
+                .unwrap()
+                .as_slice()
+                .try_into()
+                .unwrap(),
+            &SIGHASH,
+            None,
+        )
+        .unwrap();
+        let mut expected_signature = expected.signature.to_vec();
+        expected_signature.push(expected.recid);
+        assert_eq!(
+            block_on(process(&mut mock_hal, &request)),
+            Ok(Response::Sign(pb::EthSignResponse {
+                signature: expected_signature
+            }))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Ethereum".into(),
+                    body: "0x773A77b9D32589be03f9132AF759e294f7851be9".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Warning".into(),
+                    body: "Blind signing!\nThe message contents\ncannot be verified.".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Domain hash".into(),
+                    body: "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+                        .into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Message hash".into(),
+                    body: "0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+                        .into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "".into(),
+                    body: "Sign data?".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+
+        // Invalid hash lengths.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.memory.set_blind_signing_enabled(true).unwrap();
+        let mut invalid_request = request.clone();
+        invalid_request.message_hash = vec![];
+        assert_eq!(
+            block_on(process(&mut mock_hal, &invalid_request)),
+            Err(Error::InvalidInput)
+        );
+        let mut invalid_request = request.clone();
+        invalid_request.domain_separator = vec![0; 31];
+        assert_eq!(
+            block_on(process(&mut mock_hal, &invalid_request)),
+            Err(Error::InvalidInput)
+        );
+
+        // User aborted the warning.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.memory.set_blind_signing_enabled(true).unwrap();
+        mock_hal.ui.abort_nth(1);
+        assert_eq!(
+            block_on(process(&mut mock_hal, &request)),
+            Err(Error::UserAbort)
+        );
+    }
 }
@@ -0,0 +1,782 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Condensed summaries of well-known EIP-712 messages.
+//!
+//! A message is summarized only if its primary type matches one of the known structures exactly
+//! and the domain binds it to a known contract on the requested chain. The summary is built from
+//! the same values that are hashed for the signature. If any value can't be represented in the
+//! summary (e.g. an unknown token), `None` is returned and the message is shown member by member.
+
+use super::super::Error;
+use super::super::abi::{Reader, TokenCall, decode_token_call};
+use super::super::address;
+use super::super::amount::Amount;
+use super::super::params;
+use super::super::pb;
+use super::{CollectedValues, DOMAIN_TYPE_NAME, eip712_sighash, encode_type, get_type};
+
+use crate::hal::Ui;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+
+use pb::eth_sign_typed_message_request::DataType;
+use pb::eth_typed_message_value_response::RootObject;
+
+/// Uniswap Permit2 contract, deployed at the same address on all chains.
+const PERMIT2_ADDRESS: [u8; 20] = hex_lit::hex!("000000000022d473030f116ddee9f6b43ac78ba3");
+
+/// Seaport v1.5 and v1.6 contracts, deployed at the same addresses on all chains.
+const SEAPORT_ADDRESSES: &[[u8; 20]] = &[
+    hex_lit::hex!("00000000000000adc04c56bf30ac9d3c0aaf14dc"),
+    hex_lit::hex!("0000000000000068f116a894984e2dca6bb7b8c4"),
+];
+
+/// Safe MultiSend and MultiSendCallOnly contracts of v1.3.0 (canonical and EIP-155 deployments)
+/// and v1.4.1, deployed at the same addresses on all chains. They are called with `DELEGATECALL`
+/// to batch multiple calls into one Safe transaction.
+const SAFE_MULTI_SEND_ADDRESSES: &[[u8; 20]] = &[
+    hex_lit::hex!("a238cbeb142c10ef7ad8442c6d1f9e89e07e7761"),
+    hex_lit::hex!("40a2accbd92bca938b02010e17a5b8929b49130d"),
+    hex_lit::hex!("998739bfdaadde7c933b942a68053933098f9eda"),
+    hex_lit::hex!("a1dabef33b3b82c7814b6d82a79e50f4ac44102b"),
+    hex_lit::hex!("38869bf66a61cf6bdb996a6ae40d5853fd43b526"),
+    hex_lit::hex!("9641d764fc13c8b624c04430c7356c1c7c8102e2"),
+];
+
+/// `multiSend(bytes transactions)`
+const SELECTOR_MULTI_SEND: [u8; 4] = hex_lit::hex!("8d80ff0a");
+
+#[derive(Copy, Clone)]
+enum Preset {
+    /// EIP-2612 permit of an ERC-20 token, verified by the token contract itself.
+    Permit,
+    /// Permit2 allowance for one token.
+    Permit2Single,
+    /// Permit2 allowances for multiple tokens.
+    Permit2Batch,
+    /// Seaport order, without bulk order signatures.
+    SeaportOrder,
+    /// Transaction of a Safe multisig account (v1.3.0 and later), signed by one of its owners.
+    SafeTx,
+}
+
+/// Primary type name and its full `encodeType()` for each preset. The message has to match the
+/// type exactly, so that the member paths used below are fixed and no member is left out.
+const PRESETS: &[(Preset, &str, &str)] = &[
+    (
+        Preset::Permit,
+        "Permit",
+        "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)",
+    ),
+    (
+        Preset::Permit2Single,
+        "PermitSingle",
+        "PermitSingle(PermitDetails details,address spender,uint256 sigDeadline)PermitDetails(address token,uint160 amount,uint48 expiration,uint48 nonce)",
+    ),
+    (
+        Preset::Permit2Batch,
+        "PermitBatch",
+        "PermitBatch(PermitDetails[] details,address spender,uint256 sigDeadline)PermitDetails(address token,uint160 amount,uint48 expiration,uint48 nonce)",
+    ),
+    (
+        Preset::SeaportOrder,
+        "OrderComponents",
+        "OrderComponents(address offerer,address zone,OfferItem[] offer,ConsiderationItem[] consideration,uint8 orderType,uint256 startTime,uint256 endTime,bytes32 zoneHash,uint256 salt,bytes32 conduitKey,uint256 counter)ConsiderationItem(uint8 itemType,address token,uint256 identifierOrCriteria,uint256 startAmount,uint256 endAmount,address recipient)OfferItem(uint8 itemType,address token,uint256 identifierOrCriteria,uint256 startAmount,uint256 endAmount)",
+    ),
+    (
+        Preset::SafeTx,
+        "SafeTx",
+        "SafeTx(address to,uint256 value,bytes data,uint8 operation,uint256 safeTxGas,uint256 baseGas,uint256 gasPrice,address gasToken,address refundReceiver,uint256 nonce)",
+    ),
+];
+
+/// Screens to confirm, as (title, body) pairs.
+type Summary = Vec<(String, String)>;
+
+fn identify(request: &pb::EthSignTypedMessageRequest) -> Option<Preset> {
+    PRESETS
+        .iter()
+        .find(|&&(_, primary_type, encoded_type)| {
+            request.primary_type == primary_type
+                && encode_type(&request.types, primary_type).ok().as_deref() == Some(encoded_type)
+        })
+        .map(|&(preset, _, _)| preset)
+}
+
+fn is_known_contract(preset: Preset, chain_id: u64, contract: &[u8; 20]) -> bool {
+    match preset {
+        Preset::Permit => erc20_params::get(chain_id, *contract).is_some(),
+        Preset::Permit2Single | Preset::Permit2Batch => *contract == PERMIT2_ADDRESS,
+        Preset::SeaportOrder => SEAPORT_ADDRESSES.contains(contract),
+        // The verifying contract is the Safe account itself, which is shown in the summary. Like
+        // all domain values, it is taken from the values that are hashed.
+        Preset::SafeTx => true,
+    }
+}
+
+/// Parses an address as sent by the host, e.g. "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".
+fn parse_address(value: &[u8]) -> Option<[u8; 20]> {
+    match value {
+        [b'0', b'x' | b'X', rest @ ..] => hex::decode(rest).ok()?.try_into().ok(),
+        _ => None,
+    }
+}
+
+fn format_address(address: &[u8; 20]) -> String {
+    address::from_pubkey_hash(address, pb::EthAddressCase::Mixed)
+}
+
+fn child_path(path: &[u32], index: u32) -> Vec<u32> {
+    let mut path = path.to_vec();
+    path.push(index);
+    path
+}
+
+fn get_address(values: &CollectedValues, path: &[u32]) -> Option<[u8; 20]> {
+    parse_address(values.get(RootObject::Message, path)?)
+}
+
+fn get_uint(values: &CollectedValues, path: &[u32]) -> Option<BigUint> {
+    Some(BigUint::from_bytes_be(
+        values.get(RootObject::Message, path)?,
+    ))
+}
+
+fn get_array_size(values: &CollectedValues, path: &[u32]) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        values.get(RootObject::Message, path)?.try_into().ok()?,
+    ))
+}
+
+fn uint_max(bits: usize) -> BigUint {
+    (BigUint::from(1u8) << bits) - 1u8
+}
+
+/// Formats a token amount. The maximum value of the amount type is commonly used for unlimited
+/// allowances and is shown as such.
+fn format_token_amount(token: &erc20_params::Params, value: BigUint, bits: usize) -> String {
+    if value == uint_max(bits) {
+        format!("Unlimited {}", token.unit)
+    } else {
+        Amount {
+            unit: token.unit,
+            decimals: token.decimals as _,
+            value,
+        }
+        .format()
+    }
+}
+
+/// Formats a unix timestamp in UTC. Timestamps beyond the 32 bit range are used to express that
+/// there is no deadline.
+fn format_deadline(timestamp: &BigUint) -> Option<String> {
+    match u32::try_from(timestamp) {
+        Ok(timestamp) => Some(format!(
+            "{} UTC",
+            util::datetime::format_datetime(timestamp, 0, false).ok()?
+        )),
+        Err(_) => Some("No deadline".into()),
+    }
+}
+
+fn summarize_permit(
+    chain_id: u64,
+    contract: &[u8; 20],
+    signer: &[u8; 20],
+    values: &CollectedValues,
+) -> Option<Summary> {
+    let token = erc20_params::get(chain_id, *contract)?;
+    if get_address(values, &[0])? != *signer {
+        return None;
+    }
+    let spender = get_address(values, &[1])?;
+    let amount = format_token_amount(&token, get_uint(values, &[2])?, 256);
+    let deadline = format_deadline(&get_uint(values, &[4])?)?;
+    Some(vec![
+        (
+            "Permit".into(),
+            format!("Allow spender\nto spend\n{}", amount),
+        ),
+        ("Spender".into(), format_address(&spender)),
+        ("Valid until".into(), deadline),
+    ])
+}
+
+/// Summarizes `PermitDetails` at `path`, returning the amount and the expiration of the allowance.
+fn summarize_permit2_details(
+    chain_id: u64,
+    values: &CollectedValues,
+    path: &[u32],
+) -> Option<(String, String)> {
+    let token = erc20_params::get(chain_id, get_address(values, &child_path(path, 0))?)?;
+    let amount = format_token_amount(&token, get_uint(values, &child_path(path, 1))?, 160);
+    let expiration = get_uint(values, &child_path(path, 2))?;
+    // An expiration of zero means that the allowance expires in the same block, which we don't
+    // bother to explain in a summary.
+    if expiration == BigUint::ZERO {
+        return None;
+    }
+    Some((amount, format_deadline(&expiration)?))
+}
+
+fn summarize_permit2(preset: Preset, chain_id: u64, values: &CollectedValues) -> Option<Summary> {
+    let mut summary = Vec::new();
+    match preset {
+        Preset::Permit2Single => {
+            let (amount, expiration) = summarize_permit2_details(chain_id, values, &[0])?;
+            summary.push((
+                "Permit2".into(),
+                format!("Allow spender\nto spend\n{}", amount),
+            ));
+            summary.push(("Expires".into(), expiration));
+        }
+        Preset::Permit2Batch => {
+            let num_details = get_array_size(values, &[0])?;
+            if num_details == 0 {
+                return None;
+            }
+            for index in 0..num_details {
+                let (amount, expiration) =
+                    summarize_permit2_details(chain_id, values, &[0, index])?;
+                summary.push((
+                    format!("Permit2 ({}/{})", index + 1, num_details),
+                    format!("Allow spender\nto spend\n{}", amount),
+                ));
+                summary.push((
+                    format!("Expires ({}/{})", index + 1, num_details),
+                    expiration,
+                ));
+            }
+        }
+        _ => return None,
+    }
+    let spender = get_address(values, &[1])?;
+    summary.push(("Spender".into(), format_address(&spender)));
+    summary.push((
+        "Valid until".into(),
+        format_deadline(&get_uint(values, &[2])?)?,
+    ));
+    Some(summary)
+}
+
+/// Formats an `OfferItem` or `ConsiderationItem` at `path`. Criteria-based items and items with an
+/// amount that changes over the duration of the order are not summarized.
+fn format_seaport_item(chain_id: u64, values: &CollectedValues, path: &[u32]) -> Option<String> {
+    let item_type = get_uint(values, &child_path(path, 0))?;
+    let token = get_address(values, &child_path(path, 1))?;
+    let identifier = get_uint(values, &child_path(path, 2))?;
+    let amount = get_uint(values, &child_path(path, 3))?;
+    if amount != get_uint(values, &child_path(path, 4))? {
+        return None;
+    }
+    match u8::try_from(&item_type).ok()? {
+        // Native currency
+        0 => {
+            if token != [0; 20] {
+                return None;
+            }
+            let params = params::get(None, chain_id)?;
+            Some(
+                Amount {
+                    unit: &params.unit,
+                    decimals: 18,
+                    value: amount,
+                }
+                .format(),
+            )
+        }
+        // ERC-20
+        1 => {
+            let token = erc20_params::get(chain_id, token)?;
+            Some(
+                Amount {
+                    unit: token.unit,
+                    decimals: token.decimals as _,
+                    value: amount,
+                }
+                .format(),
+            )
+        }
+        // ERC-721
+        2 => {
+            if amount != BigUint::from(1u8) {
+                return None;
+            }
+            Some(format!("NFT #{}\n{}", identifier, format_address(&token)))
+        }
+        // ERC-1155
+        3 => Some(format!(
+            "{} x NFT #{}\n{}",
+            amount,
+            identifier,
+            format_address(&token)
+        )),
+        _ => None,
+    }
+}
+
+fn summarize_seaport_order(
+    chain_id: u64,
+    signer: &[u8; 20],
+    values: &CollectedValues,
+) -> Option<Summary> {
+    if get_address(values, &[0])? != *signer {
+        return None;
+    }
+    let mut summary = Vec::new();
+
+    let num_offer = get_array_size(values, &[2])?;
+    for index in 0..num_offer {
+        summary.push((
+            format!("You offer ({}/{})", index + 1, num_offer),
+            format_seaport_item(chain_id, values, &[2, index])?,
+        ));
+    }
+    if num_offer == 0 {
+        summary.push(("You offer".into(), "Nothing".into()));
+    }
+
+    // Consideration items paid to the offerer are what the user receives. All others are paid to
+    // third parties, e.g. marketplace fees or creator royalties.
+    let mut received = Vec::new();
+    let mut fees = Vec::new();
+    for index in 0..get_array_size(values, &[3])? {
+        let path = [3, index];
+        let item = format_seaport_item(chain_id, values, &path)?;
+        let recipient = get_address(values, &child_path(&path, 5))?;
+        if recipient == *signer {
+            received.push(item);
+        } else {
+            fees.push(format!("{}\nto {}", item, format_address(&recipient)));
+        }
+    }
+    if received.is_empty() {
+        // Signing an order that pays the user nothing gives away the offered items for free.
+        summary.push(("You receive".into(), "Nothing".into()));
+    }
+    for (index, item) in received.iter().enumerate() {
+        summary.push((
+            format!("You receive ({}/{})", index + 1, received.len()),
+            item.clone(),
+        ));
+    }
+    for (index, fee) in fees.iter().enumerate() {
+        summary.push((format!("Fee ({}/{})", index + 1, fees.len()), fee.clone()));
+    }
+    summary.push((
+        "Valid until".into(),
+        format_deadline(&get_uint(values, &[6])?)?,
+    ));
+    Some(summary)
+}
+
+/// A call made by a Safe account.
+struct SafeCall<'a> {
+    to: [u8; 20],
+    value: BigUint,
+    data: &'a [u8],
+    /// 0 for `CALL`, 1 for `DELEGATECALL`.
+    operation: u8,
+}
+
+/// Unpacks the calls batched by `multiSend(bytes transactions)`, where each call is encoded as
+/// `operation (1 byte) || to (20 bytes) || value (32 bytes) || data length (32 bytes) || data`.
+fn decode_multi_send(data: &[u8]) -> Option<Vec<SafeCall<'_>>> {
+    let (selector, reader) = Reader::from_call(data)?;
+    if selector != SELECTOR_MULTI_SEND {
+        return None;
+    }
+    let mut transactions = reader.bytes(0, 0)?;
+    let mut calls = Vec::new();
+    while !transactions.is_empty() {
+        if transactions.len() < 85 {
+            return None;
+        }
+        let (head, rest) = transactions.split_at(85);
+        // The data length is a 32 byte big endian word.
+        let data_len = &head[53..85];
+        if data_len[..24] != [0u8; 24] {
+            return None;
+        }
+        let data_len = usize::try_from(u64::from_be_bytes(data_len[24..].try_into().unwrap()))
+            .ok()
+            .filter(|&len| len <= rest.len())?;
+        calls.push(SafeCall {
+            operation: head[0],
+            to: head[1..21].try_into().unwrap(),
+            value: BigUint::from_bytes_be(&head[21..53]),
+            data: &rest[..data_len],
+        });
+        transactions = &rest[data_len..];
+    }
+    Some(calls)
+}
+
+/// Summarizes a single call of a Safe account. Token transfers and approvals of known tokens are
+/// decoded. Other contract calls show the raw call data.
+fn summarize_safe_call(
+    chain_id: u64,
+    safe: &[u8; 20],
+    call: &SafeCall<'_>,
+    suffix: &str,
+) -> Option<Summary> {
+    let params = params::get(None, chain_id)?;
+    let format_value = |value: &BigUint| {
+        Amount {
+            unit: &params.unit,
+            decimals: 18,
+            value: value.clone(),
+        }
+        .format()
+    };
+    let mut summary: Summary = Vec::new();
+    match call.operation {
+        0 => {}
+        1 => summary.push((
+            format!("Warning{}", suffix),
+            format!(
+                "Delegatecall to\n{}\nThis contract can\ntake full control\nof the Safe!",
+                format_address(&call.to)
+            ),
+        )),
+        _ => return None,
+    }
+
+    if call.data.is_empty() {
+        summary.push((
+            format!("Send{}", suffix),
+            format!(
+                "{}\nto {}",
+                format_value(&call.value),
+                format_address(&call.to)
+            ),
+        ));
+        return Some(summary);
+    }
+
+    let token_call = if call.value == BigUint::ZERO {
+        decode_token_call(call.data)
+    } else {
+        None
+    };
+    let erc20 = erc20_params::get(chain_id, call.to);
+    let format_erc20 = |token: &erc20_params::Params, value: BigUint| {
+        Amount {
+            unit: token.unit,
+            decimals: token.decimals as _,
+            value,
+        }
+        .format()
+    };
+    let from_other = |from: &[u8; 20]| (from != safe).then(|| format_address(from));
+    let decoded: Option<(String, Option<String>)> = match (token_call, &erc20) {
+        (Some(TokenCall::Transfer { to, value }), Some(token)) => Some((
+            format!("{}\nto {}", format_erc20(token, value), format_address(&to)),
+            None,
+        )),
+        (Some(TokenCall::TransferFrom { from, to, value }), Some(token)) => Some((
+            format!("{}\nto {}", format_erc20(token, value), format_address(&to)),
+            from_other(&from),
+        )),
+        // `transferFrom()` of an unknown token may be an ERC-20 transfer of any amount, so it is
+        // shown as a raw contract call. Only `safeTransferFrom()` is shown as an NFT transfer.
+        (
+            Some(TokenCall::NftTransfer {
+                from,
+                to,
+                token_id: value,
+            }),
+            None,
+        ) => Some((
+            format!(
+                "NFT #{}\n{}\nto {}",
+                value,
+                format_address(&call.to),
+                format_address(&to)
+            ),
+            from_other(&from),
+        )),
+        (
+            Some(TokenCall::MultiTokenTransfer {
+                from,
+                to,
+                id,
+                value,
+            }),
+            None,
+        ) => Some((
+            format!(
+                "{} x NFT #{}\n{}\nto {}",
+                value,
+                id,
+                format_address(&call.to),
+                format_address(&to)
+            ),
+            from_other(&from),
+        )),
+        (Some(TokenCall::Approve { spender, value }), Some(token)) => {
+            summary.push((
+                format!("Approve{}", suffix),
+                format!(
+                    "Allow spender\nto spend\n{}",
+                    format_token_amount(token, value, 256)
+                ),
+            ));
+            summary.push((format!("Spender{}", suffix), format_address(&spender)));
+            return Some(summary);
+        }
+        _ => None,
+    };
+    match decoded {
+        Some((body, from)) => {
+            summary.push((format!("Send{}", suffix), body));
+            if let Some(from) = from {
+                summary.push((format!("From{}", suffix), from));
+            }
+        }
+        None => {
+            summary.push((format!("Contract call{}", suffix), format_address(&call.to)));
+            if call.value != BigUint::ZERO {
+                summary.push((format!("Value{}", suffix), format_value(&call.value)));
+            }
+            summary.push((
+                format!("Data{}", suffix),
+                format!("0x{}", hex::encode(call.data)),
+            ));
+        }
+    }
+    Some(summary)
+}
+
+fn summarize_safe_tx(chain_id: u64, safe: &[u8; 20], values: &CollectedValues) -> Option<Summary> {
+    let call = SafeCall {
+        to: get_address(values, &[0])?,
+        value: get_uint(values, &[1])?,
+        data: values.get(RootObject::Message, &[2])?,
+        operation: u8::try_from(&get_uint(values, &[3])?).ok()?,
+    };
+    // With a non-zero gas price, the Safe refunds `(gasUsed + baseGas) * gasPrice` in `gasToken`
+    // to `refundReceiver`. `gasUsed` is only known on execution, so no reliable maximum can be
+    // shown. Such transactions are shown member by member instead.
+    if get_uint(values, &[6])? != BigUint::ZERO {
+        return None;
+    }
+    let nonce = get_uint(values, &[9])?;
+
+    let mut summary: Summary = vec![("Safe".into(), format_address(safe))];
+
+    // Batches are unpacked if they are executed by a known MultiSend contract.
+    let batch = if call.operation == 1
+        && call.value == BigUint::ZERO
+        && SAFE_MULTI_SEND_ADDRESSES.contains(&call.to)
+    {
+        decode_multi_send(call.data).filter(|calls| !calls.is_empty())
+    } else {
+        None
+    };
+    match batch {
+        Some(calls) => {
+            for (index, call) in calls.iter().enumerate() {
+                let suffix = format!(" ({}/{})", index + 1, calls.len());
+                summary.extend(summarize_safe_call(chain_id, safe, call, &suffix)?);
+            }
+        }
+        None => summary.extend(summarize_safe_call(chain_id, safe, &call, "")?),
+    }
+
+    summary.push(("Safe nonce".into(), format!("{}", nonce)));
+    Some(summary)
+}
+
+/// If the message is a known preset, it is hashed without showing its members, and a summary of
+/// it is shown instead. `address` is the address of the signing key.
+///
+/// Returns the sighash if the summary was confirmed, or `None` if the message needs to be shown
+/// member by member.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::EthSignTypedMessageRequest,
+    address: &str,
+) -> Result<Option<[u8; 32]>, Error> {
+    let preset = match identify(request) {
+        Some(preset) => preset,
+        None => return Ok(None),
+    };
+    let signer = parse_address(address.as_bytes()).ok_or(Error::Generic)?;
+
+    // The signature has to be bound to the chain and to the contract we recognize.
+    let domain_type = get_type(&request.types, DOMAIN_TYPE_NAME).ok_or(Error::InvalidInput)?;
+    let domain_member_index = |name: &str, data_type: DataType| {
+        domain_type.members.iter().position(|member| {
+            member.name == name
+                && member.r#type.as_ref().map(|typ| typ.r#type) == Some(data_type as _)
+        })
+    };
+    let (chain_id_index, contract_index) = match (
+        domain_member_index("chainId", DataType::Uint),
+        domain_member_index("verifyingContract", DataType::Address),
+    ) {
+        (Some(chain_id_index), Some(contract_index)) => (chain_id_index, contract_index),
+        _ => return Ok(None),
+    };
+
+    let mut values = CollectedValues::default();
+    let sighash = eip712_sighash(
+        hal,
+        &request.types,
+        &request.primary_type,
+        Some(&mut values),
+    )
+    .await?;
+
+    // The domain values are taken from the hashing pass above, so that the host can't show us a
+    // different chain or contract than the one that is signed.
+    let chain_id = values
+        .get(RootObject::Domain, &[chain_id_index as _])
+        .and_then(|value| u64::try_from(BigUint::from_bytes_be(value)).ok());
+    if chain_id != Some(request.chain_id) {
+        return Ok(None);
+    }
+    let contract = match values
+        .get(RootObject::Domain, &[contract_index as _])
+        .and_then(parse_address)
+    {
+        Some(contract) if is_known_contract(preset, request.chain_id, &contract) => contract,
+        _ => return Ok(None),
+    };
+
+    let summary = match preset {
+        Preset::Permit => summarize_permit(request.chain_id, &contract, &signer, &values),
+        Preset::Permit2Single | Preset::Permit2Batch => {
+            summarize_permit2(preset, request.chain_id, &values)
+        }
+        Preset::SeaportOrder => summarize_seaport_order(request.chain_id, &signer, &values),
+        Preset::SafeTx => summarize_safe_tx(request.chain_id, &contract, &values),
+    };
+    let summary = match summary {
+        Some(summary) => summary,
+        None => return Ok(None),
+    };
+    for (title, body) in summary.iter() {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title,
+                body,
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    Ok(Some(sighash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_address() {
+        assert_eq!(
+            parse_address(b"0x000000000022D473030F116dDEE9F6B43aC78BA3"),
+            Some(PERMIT2_ADDRESS)
+        );
+        assert_eq!(
+            parse_address(b"0X000000000022d473030f116ddee9f6b43ac78ba3"),
+            Some(PERMIT2_ADDRESS)
+        );
+        assert_eq!(
+            parse_address(b"000000000022D473030F116dDEE9F6B43aC78BA3"),
+            None
+        );
+        assert_eq!(parse_address(b"0x01"), None);
+        assert_eq!(parse_address(b"0xzz"), None);
+    }
+
+    #[test]
+    fn test_decode_multi_send() {
+        let mut transactions = Vec::new();
+        // CALL of 1 wei to 0x11..11 without data.
+        transactions.push(0u8);
+        transactions.extend([0x11; 20]);
+        transactions.extend([0u8; 31]);
+        transactions.push(1);
+        transactions.extend([0u8; 32]);
+        // DELEGATECALL to 0x22..22 with 2 bytes of data.
+        transactions.push(1u8);
+        transactions.extend([0x22; 20]);
+        transactions.extend([0u8; 32]);
+        transactions.extend([0u8; 31]);
+        transactions.push(2);
+        transactions.extend([0xaa, 0xbb]);
+
+        let mut data = SELECTOR_MULTI_SEND.to_vec();
+        data.extend([0u8; 31]);
+        data.push(32);
+        data.extend([0u8; 31]);
+        data.push(transactions.len() as u8);
+        data.extend(&transactions);
+
+        let calls = decode_multi_send(&data).unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(
+            (
+                calls[0].operation,
+                calls[0].to,
+                &calls[0].value,
+                calls[0].data
+            ),
+            (0, [0x11; 20], &BigUint::from(1u8), &[][..])
+        );
+        assert_eq!(
+            (
+                calls[1].operation,
+                calls[1].to,
+                &calls[1].value,
+                calls[1].data
+            ),
+            (1, [0x22; 20], &BigUint::ZERO, &[0xaa, 0xbb][..])
+        );
+
+        // Truncated data of the last call.
+        let mut truncated = data.clone();
+        truncated.pop();
+        let len_index = 4 + 63;
+        truncated[len_index] -= 1;
+        assert!(decode_multi_send(&truncated).is_none());
+        // Truncated header of the last call.
+        let mut truncated = SELECTOR_MULTI_SEND.to_vec();
+        truncated.extend([0u8; 31]);
+        truncated.push(32);
+        truncated.extend([0u8; 31]);
+        truncated.push(85 + 10);
+        truncated.extend(&transactions[..85 + 10]);
+        assert!(decode_multi_send(&truncated).is_none());
+        // Data length that does not fit.
+        let mut too_long = data.clone();
+        too_long[4 + 64 + 53] = 1;
+        assert!(decode_multi_send(&too_long).is_none());
+        // Not a multiSend() call.
+        assert!(decode_multi_send(&transactions).is_none());
+    }
+
+    #[test]
+    fn test_format_deadline() {
+        assert_eq!(
+            format_deadline(&BigUint::from(1601281809u32)).as_deref(),
+            Some("Mon 2020-09-28\n08:30 UTC")
+        );
+        assert_eq!(
+            format_deadline(&BigUint::from(u32::MAX as u64 + 1)).as_deref(),
+            Some("No deadline")
+        );
+        assert_eq!(
+            format_deadline(&uint_max(256)).as_deref(),
+            Some("No deadline")
+        );
+    }
+}
@@ -2,7 +2,9 @@
 
 use super::Error;
 use super::pb;
+use crate::hal::ui::ConfirmParams;
 
+use crate::hal::Ui;
 use crate::keystore;
 
 use crate::workflow::verify_message;
@@ -14,6 +16,128 @@ use core::convert::TryInto;
 
 use sha3::digest::Digest;
 
+/// Shows a summary of a Sign-In with Ethereum message. The user is warned if the message is for a
+/// different account or network than the one used for signing.
+async fn verify_siwe(
+    hal: &mut impl crate::hal::Hal,
+    siwe: &super::siwe::Message<'_>,
+    address: &str,
+    chain_id: u64,
+) -> Result<(), Error> {
+    if !siwe.address.eq_ignore_ascii_case(address) {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Warning",
+                body: &format!("Sign-in is for\na different account:\n{}", siwe.address),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    if siwe.chain_id != chain_id {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Warning",
+                body: &format!(
+                    "Sign-in is for\na different network:\nchain ID {}",
+                    siwe.chain_id
+                ),
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Sign-In",
+            body: &format!("{}\nwants you to sign in", siwe.domain),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    if let Some(statement) = siwe.statement {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Statement",
+                body: statement,
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "URI",
+            body: siwe.uri,
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    for (i, resource) in siwe.resources.iter().enumerate() {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: &format!("Resource {}/{}", i + 1, siwe.resources.len()),
+                body: resource,
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Issued at",
+            body: siwe.issued_at,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    if let Some(expiration_time) = siwe.expiration_time {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Expires",
+                body: expiration_time,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    if let Some(not_before) = siwe.not_before {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Not before",
+                body: not_before,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    if let Some(request_id) = siwe.request_id {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Request ID",
+                body: request_id,
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Sign-In",
+            body: "Sign in?",
+            longtouch: true,
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
 /// Process a sign message request.
 ///
 /// The result contains a 65 byte signature. The first 64 bytes are the secp256k1 signature in
@@ -35,11 +159,34 @@ pub async fn process(
         chain_id: request.chain_id,
     };
 
-    // Verify address. We don't need the actual result, but we have to propagate validation or user
-    // abort errors.
-    super::pubrequest::process(hal, &pub_request).await?;
+    // Verify address. The address is also needed to check Sign-In with Ethereum messages.
+    let address = match super::pubrequest::process(hal, &pub_request).await? {
+        Response::Pub(pb::PubResponse { r#pub }) => r#pub,
+        _ => return Err(Error::Generic),
+    };
 
-    verify_message::verify(hal, "Sign message", "Sign", &request.msg, true).await?;
+    // Only printable ASCII messages are summarized as Sign-In with Ethereum messages, like the
+    // generic message display. Anything else is shown as hex.
+    let siwe = if util::ascii::is_printable_ascii(&request.msg, util::ascii::Charset::AllNewline) {
+        core::str::from_utf8(&request.msg)
+            .ok()
+            .and_then(super::siwe::parse)
+    } else {
+        None
+    };
+    match siwe {
+        Some(siwe) => {
+            let chain_id = match super::params::get(
+                Some(pb::EthCoin::try_from(request.coin)?),
+                request.chain_id,
+            ) {
+                Some(params) => params.chain_id,
+                None => request.chain_id,
+            };
+            verify_siwe(hal, &siwe, &address, chain_id).await?
+        }
+        None => verify_message::verify(hal, "Sign message", "Sign", &request.msg, true).await?,
+    }
 
     // Construct message to be signed. There is no standard for this. We match what MyEtherWallet,
     // Trezor, etc. do, e.g.:
@@ -235,6 +382,175 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_process_siwe() {
+        let siwe_msg = |address: &str, chain_id: u64| {
+            format!(
+                "example.com wants you to sign in with your Ethereum account:\n\
+                 {}\n\
+                 \n\
+                 Sign in to Example.\n\
+                 \n\
+                 URI: https://example.com/login\n\
+                 Version: 1\n\
+                 Chain ID: {}\n\
+                 Nonce: abcdefgh12345678\n\
+                 Issued At: 2021-09-30T16:25:24Z\n\
+                 Expiration Time: 2021-10-01T16:25:24Z\n\
+                 Not Before: 2021-09-30T17:00:00Z\n\
+                 Request ID: request-42",
+                address, chain_id
+            )
+        };
+        let summary_screens = vec![
+            Screen::Confirm {
+                title: "Sign-In".into(),
+                body: "example.com\nwants you to sign in".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Statement".into(),
+                body: "Sign in to Example.".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "URI".into(),
+                body: "https://example.com/login".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Issued at".into(),
+                body: "2021-09-30T16:25:24Z".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Expires".into(),
+                body: "2021-10-01T16:25:24Z".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Not before".into(),
+                body: "2021-09-30T17:00:00Z".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Request ID".into(),
+                body: "request-42".into(),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Sign-In".into(),
+                body: "Sign in?".into(),
+                longtouch: true,
+            },
+        ];
+        let address_screen = Screen::Confirm {
+            title: "Ethereum".into(),
+            body: EXPECTED_ADDRESS.into(),
+            longtouch: false,
+        };
+
+        // Matching address (in any case) and chain ID.
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let msg = siwe_msg(&EXPECTED_ADDRESS.to_lowercase(), 1);
+        assert!(matches!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::EthSignMessageRequest {
+                    coin: pb::EthCoin::Eth as _,
+                    keypath: KEYPATH.to_vec(),
+                    msg: msg.as_bytes().to_vec(),
+                    host_nonce_commitment: None,
+                    chain_id: 0,
+                }
+            )),
+            Ok(Response::Sign(_))
+        ));
+        let mut expected_screens = vec![address_screen.clone()];
+        expected_screens.extend(summary_screens.clone());
+        assert_eq!(mock_hal.ui.screens, expected_screens);
+
+        // Different account and network.
+        const OTHER_ADDRESS: &str = "0x857B3D969eAcB775a9f79cabc62Ec4bB1D1cd60e";
+        let mut mock_hal = TestingHal::new();
+        let msg = siwe_msg(OTHER_ADDRESS, 10);
+        assert!(matches!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::EthSignMessageRequest {
+                    coin: pb::EthCoin::Eth as _,
+                    keypath: KEYPATH.to_vec(),
+                    msg: msg.as_bytes().to_vec(),
+                    host_nonce_commitment: None,
+                    chain_id: 1,
+                }
+            )),
+            Ok(Response::Sign(_))
+        ));
+        let mut expected_screens = vec![
+            address_screen.clone(),
+            Screen::Confirm {
+                title: "Warning".into(),
+                body: format!("Sign-in is for\na different account:\n{}", OTHER_ADDRESS),
+                longtouch: false,
+            },
+            Screen::Confirm {
+                title: "Warning".into(),
+                body: "Sign-in is for\na different network:\nchain ID 10".into(),
+                longtouch: false,
+            },
+        ];
+        expected_screens.extend(summary_screens.clone());
+        assert_eq!(mock_hal.ui.screens, expected_screens);
+
+        // User aborts the mismatch warning.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.abort_nth(1);
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::EthSignMessageRequest {
+                    coin: pb::EthCoin::Eth as _,
+                    keypath: KEYPATH.to_vec(),
+                    msg: msg.as_bytes().to_vec(),
+                    host_nonce_commitment: None,
+                    chain_id: 1,
+                }
+            )),
+            Err(Error::UserAbort)
+        );
+
+        // Non-ASCII characters (here a Cyrillic "а" in the domain) are not summarized, the message
+        // is shown as hex instead.
+        let mut mock_hal = TestingHal::new();
+        let msg = siwe_msg(EXPECTED_ADDRESS, 1).replacen("example.com", "ex\u{430}mple.com", 1);
+        assert!(matches!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::EthSignMessageRequest {
+                    coin: pb::EthCoin::Eth as _,
+                    keypath: KEYPATH.to_vec(),
+                    msg: msg.as_bytes().to_vec(),
+                    host_nonce_commitment: None,
+                    chain_id: 0,
+                }
+            )),
+            Ok(Response::Sign(_))
+        ));
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                address_screen.clone(),
+                Screen::Confirm {
+                    title: "Sign message\ndata (hex)".into(),
+                    body: hex::encode(msg.as_bytes()),
+                    longtouch: true,
+                },
+            ]
+        );
+    }
+
     #[test]
     pub fn test_process_failures() {
         const KEYPATH: &[u32] = &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0];
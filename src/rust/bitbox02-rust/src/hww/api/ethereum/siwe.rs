@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parsing of EIP-4361 Sign-In with Ethereum (SIWE) messages:
+//! https://eips.ethereum.org/EIPS/eip-4361
+
+use alloc::vec::Vec;
+
+const PREAMBLE_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+
+/// A parsed SIWE message. All fields borrow from the original message.
+#[derive(Debug, PartialEq)]
+pub struct Message<'a> {
+    /// RFC 3986 authority requesting the signing, optionally prefixed by the scheme, e.g.
+    /// `https://example.com`.
+    pub domain: &'a str,
+    /// EIP-55 mixed-case checksum address, as written in the message.
+    pub address: &'a str,
+    pub statement: Option<&'a str>,
+    pub uri: &'a str,
+    pub version: &'a str,
+    pub chain_id: u64,
+    pub nonce: &'a str,
+    pub issued_at: &'a str,
+    pub expiration_time: Option<&'a str>,
+    pub not_before: Option<&'a str>,
+    pub request_id: Option<&'a str>,
+    pub resources: Vec<&'a str>,
+}
+
+fn is_valid_address(address: &str) -> bool {
+    match address.strip_prefix("0x") {
+        Some(hex) => hex.len() == 40 && hex.bytes().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+    }
+}
+
+fn is_valid_nonce(nonce: &str) -> bool {
+    nonce.len() >= 8 && nonce.bytes().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_valid_chain_id(chain_id: &str) -> bool {
+    !chain_id.is_empty()
+        && chain_id.bytes().all(|c| c.is_ascii_digit())
+        && !chain_id.starts_with('0')
+}
+
+/// Returns the value of the line if it is `"<tag>: <value>"` with a non-empty value.
+fn tagged<'a>(line: Option<&'a str>, tag: &str) -> Option<&'a str> {
+    let value = line?.strip_prefix(tag)?.strip_prefix(": ")?;
+    if value.is_empty() { None } else { Some(value) }
+}
+
+/// Parses `msg` as a SIWE message. Returns `None` if the message does not follow the EIP-4361
+/// grammar, in which case it should be treated as a regular message.
+///
+/// The blank line before the `URI` field that the grammar mandates when there is no statement is
+/// optional, as some libraries omit it.
+pub fn parse(msg: &str) -> Option<Message<'_>> {
+    let mut lines = msg.split('\n').peekable();
+
+    let domain = lines.next()?.strip_suffix(PREAMBLE_SUFFIX)?;
+    if domain.is_empty() || domain.contains(char::is_whitespace) {
+        return None;
+    }
+    let address = lines.next()?;
+    if !is_valid_address(address) {
+        return None;
+    }
+    if !lines.next()?.is_empty() {
+        return None;
+    }
+    let statement = match lines.peek() {
+        Some(&"") => {
+            lines.next();
+            None
+        }
+        Some(line) if line.starts_with("URI: ") => None,
+        Some(_) => {
+            let statement = lines.next()?;
+            if !lines.next()?.is_empty() {
+                return None;
+            }
+            Some(statement)
+        }
+        None => return None,
+    };
+
+    let uri = tagged(lines.next(), "URI")?;
+    let version = tagged(lines.next(), "Version")?;
+    if version != "1" {
+        return None;
+    }
+    let chain_id = tagged(lines.next(), "Chain ID")?;
+    if !is_valid_chain_id(chain_id) {
+        return None;
+    }
+    let chain_id: u64 = chain_id.parse().ok()?;
+    let nonce = tagged(lines.next(), "Nonce")?;
+    if !is_valid_nonce(nonce) {
+        return None;
+    }
+    let issued_at = tagged(lines.next(), "Issued At")?;
+
+    let expiration_time = tagged(lines.peek().copied(), "Expiration Time");
+    if expiration_time.is_some() {
+        lines.next();
+    }
+    let not_before = tagged(lines.peek().copied(), "Not Before");
+    if not_before.is_some() {
+        lines.next();
+    }
+    let request_id = tagged(lines.peek().copied(), "Request ID");
+    if request_id.is_some() {
+        lines.next();
+    }
+    let mut resources = Vec::new();
+    if lines.peek() == Some(&"Resources:") {
+        lines.next();
+        while let Some(resource) = lines.peek().and_then(|line| line.strip_prefix("- ")) {
+            if resource.is_empty() {
+                return None;
+            }
+            resources.push(resource);
+            lines.next();
+        }
+    }
+    // Nothing may follow.
+    if lines.next().is_some() {
+        return None;
+    }
+
+    Some(Message {
+        domain,
+        address,
+        statement,
+        uri,
+        version,
+        chain_id,
+        nonce,
+        issued_at,
+        expiration_time,
+        not_before,
+        request_id,
+        resources,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0x773A77b9D32589be03f9132AF759e294f7851be9";
+
+    #[test]
+    fn test_parse_full() {
+        let msg = "https://example.com wants you to sign in with your Ethereum account:\n\
+                   0x773A77b9D32589be03f9132AF759e294f7851be9\n\
+                   \n\
+                   I accept the ExampleOrg Terms of Service: https://example.com/tos\n\
+                   \n\
+                   URI: https://example.com/login\n\
+                   Version: 1\n\
+                   Chain ID: 1\n\
+                   Nonce: 32891756\n\
+                   Issued At: 2021-09-30T16:25:24Z\n\
+                   Expiration Time: 2021-10-01T16:25:24Z\n\
+                   Not Before: 2021-09-30T16:25:24Z\n\
+                   Request ID: abc-123\n\
+                   Resources:\n\
+                   - ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/\n\
+                   - https://example.com/my-web2-claim.json";
+        assert_eq!(
+            parse(msg),
+            Some(Message {
+                domain: "https://example.com",
+                address: ADDRESS,
+                statement: Some(
+                    "I accept the ExampleOrg Terms of Service: https://example.com/tos"
+                ),
+                uri: "https://example.com/login",
+                version: "1",
+                chain_id: 1,
+                nonce: "32891756",
+                issued_at: "2021-09-30T16:25:24Z",
+                expiration_time: Some("2021-10-01T16:25:24Z"),
+                not_before: Some("2021-09-30T16:25:24Z"),
+                request_id: Some("abc-123"),
+                resources: vec![
+                    "ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/",
+                    "https://example.com/my-web2-claim.json",
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_minimal() {
+        let expected = Message {
+            domain: "example.com",
+            address: ADDRESS,
+            statement: None,
+            uri: "https://example.com",
+            version: "1",
+            chain_id: 137,
+            nonce: "abcdefgh12345678",
+            issued_at: "2021-09-30T16:25:24Z",
+            expiration_time: None,
+            not_before: None,
+            request_id: None,
+            resources: vec![],
+        };
+        let body = "URI: https://example.com\n\
+                    Version: 1\n\
+                    Chain ID: 137\n\
+                    Nonce: abcdefgh12345678\n\
+                    Issued At: 2021-09-30T16:25:24Z";
+        let header = "example.com wants you to sign in with your Ethereum account:\n\
+                      0x773A77b9D32589be03f9132AF759e294f7851be9\n";
+        // As per the grammar, with two blank lines.
+        assert_eq!(
+            parse(&format!("{}\n\n{}", header, body)).as_ref(),
+            Some(&expected)
+        );
+        // With only one blank line.
+        assert_eq!(
+            parse(&format!("{}\n{}", header, body)).as_ref(),
+            Some(&expected)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let valid = "example.com wants you to sign in with your Ethereum account:\n\
+                     0x773A77b9D32589be03f9132AF759e294f7851be9\n\
+                     \n\
+                     URI: https://example.com\n\
+                     Version: 1\n\
+                     Chain ID: 1\n\
+                     Nonce: abcdefgh12345678\n\
+                     Issued At: 2021-09-30T16:25:24Z";
+        assert!(parse(valid).is_some());
+
+        let cases: &[(&str, &str)] = &[
+            // Not a SIWE message.
+            (valid, "hello world"),
+            // Wrong preamble.
+            (" wants you to sign in", " wants you to log in"),
+            // Invalid address.
+            (
+                "0x773A77b9D32589be03f9132AF759e294f7851be9",
+                "0x773A77b9D32589be03f9132AF759e294f7851be",
+            ),
+            (
+                "0x773A77b9D32589be03f9132AF759e294f7851be9",
+                "773A77b9D32589be03f9132AF759e294f7851be9ab",
+            ),
+            // Unsupported version.
+            ("Version: 1", "Version: 2"),
+            // Invalid chain IDs.
+            ("Chain ID: 1", "Chain ID: 0x1"),
+            ("Chain ID: 1", "Chain ID: 01"),
+            ("Chain ID: 1", "Chain ID: 99999999999999999999999"),
+            // Nonce too short or not alphanumeric.
+            ("Nonce: abcdefgh12345678", "Nonce: abc"),
+            ("Nonce: abcdefgh12345678", "Nonce: abcdefgh-12345678"),
+            // Missing field.
+            ("Nonce: abcdefgh12345678\n", ""),
+            // Fields out of order.
+            (
+                "Chain ID: 1\nNonce: abcdefgh12345678",
+                "Nonce: abcdefgh12345678\nChain ID: 1",
+            ),
+            // Trailing garbage.
+            ("2021-09-30T16:25:24Z", "2021-09-30T16:25:24Z\nfoo"),
+        ];
+        for &(from, to) in cases {
+            let msg = valid.replace(from, to);
+            assert!(parse(&msg).is_none(), "{}", msg);
+        }
+    }
+}
@@ -0,0 +1,898 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::abi::{Reader, TokenCall, decode_token_call};
+use super::amount::{Amount, calculate_percentage};
+use super::params::Params;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use crate::keystore;
+
+use crate::hal::Ui;
+use crate::workflow::transaction;
+
+use alloc::vec::Vec;
+use hex_lit::hex;
+use pb::eth_response::Response;
+use pb::eth_sign_user_operation_request::EntryPointVersion;
+
+use core::ops::{Add, Mul};
+use num_bigint::BigUint;
+use sha3::digest::Digest;
+
+// 1 ETH = 1e18 wei.
+const WEI_DECIMALS: usize = 18;
+
+const MAX_CALL_DATA_LENGTH: usize = 6144;
+const MAX_CALLS: usize = 10;
+
+/// Canonical EntryPoint deployments.
+const ENTRY_POINT_V0_6: [u8; 20] = hex!("5ff137d4b0fdcd49dca30c7cf57e578a026d2789");
+const ENTRY_POINT_V0_7: [u8; 20] = hex!("0000000071727de22e5e9d8baf0edac6f37da032");
+
+/// `execute(address dest, uint256 value, bytes func)`
+const SELECTOR_EXECUTE: [u8; 4] = hex!("b61d27f6");
+/// `executeBatch(address[] dest, bytes[] func)`
+const SELECTOR_EXECUTE_BATCH: [u8; 4] = hex!("18dfb3c7");
+/// `executeBatch(address[] dest, uint256[] value, bytes[] func)`
+const SELECTOR_EXECUTE_BATCH_VALUES: [u8; 4] = hex!("47e1da2a");
+
+/// A call made by the smart account, decoded from the `callData` of the user operation.
+#[derive(Debug, PartialEq)]
+struct Call<'a> {
+    to: [u8; 20],
+    value: BigUint,
+    data: &'a [u8],
+}
+
+/// Decodes the calls of the common `execute` and `executeBatch` smart account methods. Returns
+/// `None` if `call_data` does not invoke one of them.
+fn decode_call_data(call_data: &[u8]) -> Option<Vec<Call<'_>>> {
+    let (selector, reader) = Reader::from_call(call_data)?;
+    match selector {
+        SELECTOR_EXECUTE => Some(vec![Call {
+            to: reader.address(0)?,
+            value: reader.uint(32)?,
+            data: reader.bytes(0, 64)?,
+        }]),
+        SELECTOR_EXECUTE_BATCH => {
+            let (dests, n) = reader.array(0)?;
+            let (funcs, n_funcs) = reader.array(32)?;
+            if n > MAX_CALLS || n_funcs != n {
+                return None;
+            }
+            (0..n)
+                .map(|i| {
+                    Some(Call {
+                        to: reader.address(dests + 32 * i)?,
+                        value: BigUint::default(),
+                        data: reader.bytes(funcs, funcs + 32 * i)?,
+                    })
+                })
+                .collect()
+        }
+        SELECTOR_EXECUTE_BATCH_VALUES => {
+            let (dests, n) = reader.array(0)?;
+            let (values, n_values) = reader.array(32)?;
+            let (funcs, n_funcs) = reader.array(64)?;
+            if n > MAX_CALLS || n_values != n || n_funcs != n {
+                return None;
+            }
+            (0..n)
+                .map(|i| {
+                    Some(Call {
+                        to: reader.address(dests + 32 * i)?,
+                        value: reader.uint(values + 32 * i)?,
+                        data: reader.bytes(funcs, funcs + 32 * i)?,
+                    })
+                })
+                .collect()
+        }
+        _ => None,
+    }
+}
+
+/// Parses an ERC-20 transfer made by the smart account. Returns the token recipient and value.
+fn parse_erc20_transfer(call: &Call<'_>) -> Option<([u8; 20], BigUint)> {
+    if call.value != BigUint::default() {
+        return None;
+    }
+    match decode_token_call(call.data)? {
+        TokenCall::Transfer { to, value } => Some((to, value)),
+        _ => None,
+    }
+}
+
+struct Paymaster {
+    address: [u8; 20],
+    /// Sum of the paymaster verification and postOp gas limits. Only set for v0.7, where they are
+    /// separate from the account's verification gas limit.
+    gas_limit: BigUint,
+}
+
+/// Parses the paymaster address and, for v0.7, its gas limits from `paymaster_and_data`.
+fn parse_paymaster(
+    paymaster_and_data: &[u8],
+    version: EntryPointVersion,
+) -> Result<Option<Paymaster>, Error> {
+    if paymaster_and_data.is_empty() {
+        return Ok(None);
+    }
+    let min_len = match version {
+        EntryPointVersion::V06 => 20,
+        EntryPointVersion::V07 => 52,
+    };
+    if paymaster_and_data.len() < min_len {
+        return Err(Error::InvalidInput);
+    }
+    let gas_limit = match version {
+        EntryPointVersion::V06 => BigUint::default(),
+        EntryPointVersion::V07 => BigUint::from_bytes_be(&paymaster_and_data[20..36])
+            .add(BigUint::from_bytes_be(&paymaster_and_data[36..52])),
+    };
+    Ok(Some(Paymaster {
+        address: paymaster_and_data[..20].try_into().unwrap(),
+        gas_limit,
+    }))
+}
+
+/// The maximum amount that the account or paymaster has to prefund to the EntryPoint, following
+/// the `requiredPrefund` computation of the respective EntryPoint version. The actual cost is
+/// usually lower.
+fn max_gas_cost(
+    request: &pb::EthSignUserOperationRequest,
+    version: EntryPointVersion,
+    paymaster: Option<&Paymaster>,
+) -> BigUint {
+    let call_gas_limit = BigUint::from_bytes_be(&request.call_gas_limit);
+    let verification_gas_limit = BigUint::from_bytes_be(&request.verification_gas_limit);
+    let pre_verification_gas = BigUint::from_bytes_be(&request.pre_verification_gas);
+    let max_fee_per_gas = BigUint::from_bytes_be(&request.max_fee_per_gas);
+    let gas = call_gas_limit + pre_verification_gas;
+    let gas = match (version, paymaster) {
+        (_, None) => gas + verification_gas_limit,
+        // The verification gas limit also covers the paymaster validation and postOp calls.
+        (EntryPointVersion::V06, Some(_)) => gas + verification_gas_limit.mul(3u32),
+        (EntryPointVersion::V07, Some(paymaster)) => {
+            gas + verification_gas_limit + &paymaster.gas_limit
+        }
+    };
+    gas.mul(max_fee_per_gas)
+}
+
+/// Left pads a big endian number to 32 bytes.
+fn pad32(value: &[u8]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    result[32 - value.len()..].copy_from_slice(value);
+    result
+}
+
+/// Left pads a big endian number to 16 bytes.
+fn pad16(value: &[u8]) -> [u8; 16] {
+    let mut result = [0u8; 16];
+    result[16 - value.len()..].copy_from_slice(value);
+    result
+}
+
+/// Computes the `userOpHash` as defined by the EntryPoint contract:
+/// `keccak256(abi.encode(keccak256(pack(userOp)), entryPoint, chainId))`.
+fn compute_user_op_hash(
+    request: &pb::EthSignUserOperationRequest,
+    version: EntryPointVersion,
+) -> [u8; 32] {
+    let keccak = |data: &[u8]| -> [u8; 32] { sha3::Keccak256::digest(data).into() };
+
+    let mut packed = sha3::Keccak256::new();
+    packed.update(pad32(&request.sender));
+    packed.update(pad32(&request.nonce));
+    packed.update(keccak(&request.init_code));
+    packed.update(keccak(&request.call_data));
+    match version {
+        EntryPointVersion::V06 => {
+            packed.update(pad32(&request.call_gas_limit));
+            packed.update(pad32(&request.verification_gas_limit));
+            packed.update(pad32(&request.pre_verification_gas));
+            packed.update(pad32(&request.max_fee_per_gas));
+            packed.update(pad32(&request.max_priority_fee_per_gas));
+        }
+        EntryPointVersion::V07 => {
+            // accountGasLimits
+            packed.update(pad16(&request.verification_gas_limit));
+            packed.update(pad16(&request.call_gas_limit));
+            packed.update(pad32(&request.pre_verification_gas));
+            // gasFees
+            packed.update(pad16(&request.max_priority_fee_per_gas));
+            packed.update(pad16(&request.max_fee_per_gas));
+        }
+    }
+    packed.update(keccak(&request.paymaster_and_data));
+
+    let mut hasher = sha3::Keccak256::new();
+    hasher.update(packed.finalize());
+    hasher.update(pad32(&request.entry_point));
+    hasher.update(pad32(&request.chain_id.to_be_bytes()));
+    hasher.finalize().into()
+}
+
+/// Shows the calls made by the smart account. Plain value transfers and ERC-20 transfers are
+/// shown as recipient and amount. Other contract calls show the raw call data.
+///
+/// Returns the sum of the values sent in the native currency.
+async fn verify_calls(
+    hal: &mut impl crate::hal::Hal,
+    params: &Params,
+    calls: &[Call<'_>],
+) -> Result<BigUint, Error> {
+    let mut total = BigUint::default();
+    for (i, call) in calls.iter().enumerate() {
+        let address = super::address::from_pubkey_hash(&call.to, pb::EthAddressCase::Mixed);
+        if calls.len() > 1 {
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title: "Smart account",
+                    body: &format!("Call {} of {}", i + 1, calls.len()),
+                    accept_is_nextarrow: true,
+                    ..Default::default()
+                })
+                .await?;
+        }
+        if let Some((token_recipient, token_value)) = parse_erc20_transfer(call) {
+            let token_recipient =
+                super::address::from_pubkey_hash(&token_recipient, pb::EthAddressCase::Mixed);
+            let formatted_value = match erc20_params::get(params.chain_id, call.to) {
+                Some(erc20_params) => Amount {
+                    unit: erc20_params.unit,
+                    decimals: erc20_params.decimals as _,
+                    value: token_value,
+                }
+                .format(),
+                None => "Unknown token".into(),
+            };
+            hal.ui()
+                .verify_recipient(&token_recipient, &formatted_value)
+                .await?;
+            continue;
+        }
+        if !call.data.is_empty() {
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title: "Unknown\ncontract",
+                    body: "Only proceed if you\nunderstand exactly\nwhat the data means.",
+                    accept_is_nextarrow: true,
+                    ..Default::default()
+                })
+                .await?;
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title: "Call data",
+                    body: &hex::encode(call.data),
+                    scrollable: true,
+                    display_size: call.data.len(),
+                    accept_is_nextarrow: true,
+                    ..Default::default()
+                })
+                .await?;
+        }
+        let amount = Amount {
+            unit: &params.unit,
+            decimals: WEI_DECIMALS,
+            value: call.value.clone(),
+        };
+        hal.ui()
+            .verify_recipient(&address, &amount.format())
+            .await?;
+        total += &call.value;
+    }
+    Ok(total)
+}
+
+/// Shows the raw call data of a user operation whose calls could not be decoded.
+async fn verify_raw_call_data(
+    hal: &mut impl crate::hal::Hal,
+    call_data: &[u8],
+) -> Result<(), Error> {
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Unknown\ncontract",
+            body: "You will be shown\nthe raw\ncall data.",
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Unknown\ncontract",
+            body: "Only proceed if you\nunderstand exactly\nwhat the data means.",
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Call data",
+            body: &hex::encode(call_data),
+            scrollable: true,
+            display_size: call_data.len(),
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    Ok(())
+}
+
+fn validate(request: &pb::EthSignUserOperationRequest) -> Result<(), Error> {
+    if request.entry_point.len() != 20
+        || request.sender.len() != 20
+        || request.nonce.len() > 32
+        || request.call_gas_limit.len() > 16
+        || request.verification_gas_limit.len() > 16
+        || request.pre_verification_gas.len() > 16
+        || request.max_fee_per_gas.len() > 16
+        || request.max_priority_fee_per_gas.len() > 16
+        || request.call_data.len() > MAX_CALL_DATA_LENGTH
+        || request.init_code.len() > MAX_CALL_DATA_LENGTH
+        || request.paymaster_and_data.len() > MAX_CALL_DATA_LENGTH
+    {
+        return Err(Error::InvalidInput);
+    }
+    // No zero prefix in the big endian numbers.
+    for number in [
+        &request.nonce,
+        &request.call_gas_limit,
+        &request.verification_gas_limit,
+        &request.pre_verification_gas,
+        &request.max_fee_per_gas,
+        &request.max_priority_fee_per_gas,
+    ] {
+        if let [0, ..] = number[..] {
+            return Err(Error::InvalidInput);
+        }
+    }
+    // The init code starts with the factory address.
+    if !request.init_code.is_empty() && request.init_code.len() < 20 {
+        return Err(Error::InvalidInput);
+    }
+    Ok(())
+}
+
+async fn _process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::EthSignUserOperationRequest,
+) -> Result<Response, Error> {
+    let version = EntryPointVersion::try_from(request.entry_point_version)?;
+    // Validate the whole request before showing anything.
+    if !super::keypath::is_valid_keypath_address(&request.keypath) {
+        return Err(Error::InvalidInput);
+    }
+    validate(request)?;
+    let paymaster = parse_paymaster(&request.paymaster_and_data, version)?;
+
+    let params = super::params::get_and_warn_unknown(hal, None, request.chain_id).await?;
+    super::keypath::warn_unusual_keypath(hal, &params, &params.name, &request.keypath).await?;
+
+    if super::params::is_known_network(hal, None, request.chain_id) {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                body: &format!("Sign user operation\non\n\n{}", params.name),
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    let expected_entry_point = match version {
+        EntryPointVersion::V06 => ENTRY_POINT_V0_6,
+        EntryPointVersion::V07 => ENTRY_POINT_V0_7,
+    };
+    if request.entry_point.as_slice() != expected_entry_point {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Unknown\nEntryPoint",
+                body: &super::address::from_pubkey_hash(
+                    request.entry_point.as_slice().try_into().unwrap(),
+                    pb::EthAddressCase::Mixed,
+                ),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Smart account",
+            body: &super::address::from_pubkey_hash(
+                request.sender.as_slice().try_into().unwrap(),
+                pb::EthAddressCase::Mixed,
+            ),
+            scrollable: true,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+    if !request.init_code.is_empty() {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Smart account",
+                body: "The account will be\ncreated by this\noperation.",
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    let value = match decode_call_data(&request.call_data) {
+        Some(calls) if !calls.is_empty() => verify_calls(hal, &params, &calls).await?,
+        _ => {
+            if !request.call_data.is_empty() {
+                verify_raw_call_data(hal, &request.call_data).await?;
+            }
+            BigUint::default()
+        }
+    };
+
+    if let Some(paymaster) = &paymaster {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Paymaster",
+                body: &format!(
+                    "Fees may be paid by\n{}",
+                    super::address::from_pubkey_hash(&paymaster.address, pb::EthAddressCase::Mixed)
+                ),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    let fee = Amount {
+        unit: &params.unit,
+        decimals: WEI_DECIMALS,
+        value: max_gas_cost(request, version, paymaster.as_ref()),
+    };
+    let total = Amount {
+        unit: &params.unit,
+        decimals: WEI_DECIMALS,
+        value: (&value).add(&fee.value),
+    };
+    let percentage = calculate_percentage(&fee.value, &value);
+    transaction::verify_total_fee_maybe_warn(hal, &total.format(), &fee.format(), percentage)
+        .await?;
+    hal.ui().status("Transaction\nconfirmed", true).await;
+
+    // Smart accounts validate the signature over the EIP-191 message hash of the userOpHash.
+    let mut msg: Vec<u8> = Vec::new();
+    msg.extend(b"\x19Ethereum Signed Message:\n32");
+    msg.extend(compute_user_op_hash(request, version));
+    let sighash: [u8; 32] = sha3::Keccak256::digest(&msg).into();
+
+    let host_nonce = match request.host_nonce_commitment {
+        // Engage in the anti-klepto protocol if the host sends a host nonce commitment.
+        Some(pb::AntiKleptoHostNonceCommitment { ref commitment }) => {
+            let signer_commitment = crate::secp256k1::secp256k1_nonce_commit(
+                keystore::secp256k1_get_private_key(hal, &request.keypath)?
+                    .as_slice()
+                    .try_into()
+                    .unwrap(),
+                &sighash,
+                commitment
+                    .as_slice()
+                    .try_into()
+                    .or(Err(Error::InvalidInput))?,
+            )?;
+
+            // Send signer commitment to host and wait for the host nonce from the host.
+            super::antiklepto_get_host_nonce(signer_commitment).await?
+        }
+
+        // Return signature directly without the anti-klepto protocol, for backwards compatibility.
+        None => [0; 32],
+    };
+    let sign_result = crate::secp256k1::secp256k1_sign(
+        keystore::secp256k1_get_private_key(hal, &request.keypath)?
+            .as_slice()
+            .try_into()
+            .unwrap(),
+        &sighash,
+        Some(&host_nonce),
+    )?;
+    let mut signature: Vec<u8> = sign_result.signature.to_vec();
+    signature.push(sign_result.recid);
+
+    Ok(Response::Sign(pb::EthSignResponse { signature }))
+}
+
+/// Verify and sign an ERC-4337 user operation.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::EthSignUserOperationRequest,
+) -> Result<Response, Error> {
+    let result = _process(hal, request).await;
+    if let Err(Error::UserAbort) = result {
+        hal.ui().status("Transaction\ncanceled", false).await;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked;
+    use crate::secp256k1::SECP256K1;
+    use alloc::boxed::Box;
+    use bitcoin::secp256k1;
+    use util::bb02_async::block_on;
+    use util::bip32::HARDENED;
+
+    const KEYPATH: &[u32] = &[44 + HARDENED, 60 + HARDENED, 0 + HARDENED, 0, 0];
+
+    // execute(0x04f264cf34440313b4a0192a352814fbe927b885, 0.530564 ETH, "")
+    const CALL_DATA_EXECUTE: &[u8] = &hex!(
+        "b61d27f600000000000000000000000004f264cf34440313b4a0192a352814fbe927b885000000000000000000000000000000000000000000000000075cf1259e9c400000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000000"
+    );
+
+    // executeBatch with a transfer of 0.530564 ETH to 0x04f264cf34440313b4a0192a352814fbe927b885
+    // and a transfer of 1 USDT to 0x857b3d969eacb775a9f79cabc62ec4bb1d1cd60e.
+    const CALL_DATA_EXECUTE_BATCH: &[u8] = &hex!(
+        "47e1da2a000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000120000000000000000000000000000000000000000000000000000000000000000200000000000000000000000004f264cf34440313b4a0192a352814fbe927b885000000000000000000000000dac17f958d2ee523a2206206994597c13d831ec70000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000075cf1259e9c4000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000044a9059cbb000000000000000000000000857b3d969eacb775a9f79cabc62ec4bb1d1cd60e00000000000000000000000000000000000000000000000000000000000f424000000000000000000000000000000000000000000000000000000000"
+    );
+
+    fn make_request(version: EntryPointVersion) -> pb::EthSignUserOperationRequest {
+        pb::EthSignUserOperationRequest {
+            chain_id: 1,
+            keypath: KEYPATH.to_vec(),
+            entry_point_version: version as _,
+            entry_point: match version {
+                EntryPointVersion::V06 => ENTRY_POINT_V0_6.to_vec(),
+                EntryPointVersion::V07 => ENTRY_POINT_V0_7.to_vec(),
+            },
+            sender: hex!("1111111111111111111111111111111111111111").to_vec(),
+            nonce: hex!("01").to_vec(),
+            init_code: vec![],
+            call_data: CALL_DATA_EXECUTE.to_vec(),
+            call_gas_limit: hex!("0186a0").to_vec(),
+            verification_gas_limit: hex!("030d40").to_vec(),
+            pre_verification_gas: hex!("c350").to_vec(),
+            max_fee_per_gas: hex!("04a817c800").to_vec(),
+            max_priority_fee_per_gas: hex!("3b9aca00").to_vec(),
+            paymaster_and_data: vec![],
+            host_nonce_commitment: None,
+        }
+    }
+
+    #[test]
+    fn test_compute_user_op_hash() {
+        assert_eq!(
+            compute_user_op_hash(
+                &make_request(EntryPointVersion::V06),
+                EntryPointVersion::V06
+            ),
+            hex!("947b5145af9c0620a61b3edc0bed0cde0af474cc1a61feedd19506ac610c2d3f"),
+        );
+        assert_eq!(
+            compute_user_op_hash(
+                &make_request(EntryPointVersion::V07),
+                EntryPointVersion::V07
+            ),
+            hex!("53659627176cec86515a65aff0326f754ae94aa7c81284fc9f74746e19ab83b7"),
+        );
+    }
+
+    #[test]
+    fn test_decode_call_data() {
+        assert_eq!(
+            decode_call_data(CALL_DATA_EXECUTE),
+            Some(vec![Call {
+                to: hex!("04f264cf34440313b4a0192a352814fbe927b885"),
+                value: 0x075cf1259e9c4000u64.into(),
+                data: &[],
+            }])
+        );
+        let calls = decode_call_data(CALL_DATA_EXECUTE_BATCH).unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(
+            calls[0],
+            Call {
+                to: hex!("04f264cf34440313b4a0192a352814fbe927b885"),
+                value: 0x075cf1259e9c4000u64.into(),
+                data: &[],
+            }
+        );
+        assert_eq!(
+            calls[1].to,
+            hex!("dac17f958d2ee523a2206206994597c13d831ec7")
+        );
+        assert_eq!(
+            parse_erc20_transfer(&calls[1]),
+            Some((
+                hex!("857b3d969eacb775a9f79cabc62ec4bb1d1cd60e"),
+                1000000u32.into()
+            ))
+        );
+
+        // Unknown method.
+        assert!(decode_call_data(&hex!("a9059cbb")).is_none());
+        assert!(decode_call_data(&[]).is_none());
+        // Truncated arguments.
+        assert!(decode_call_data(&CALL_DATA_EXECUTE[..100]).is_none());
+        assert!(decode_call_data(&CALL_DATA_EXECUTE_BATCH[..300]).is_none());
+        // Offset out of bounds.
+        let mut call_data = CALL_DATA_EXECUTE.to_vec();
+        call_data[4 + 64 + 31] = 0xff;
+        assert!(decode_call_data(&call_data).is_none());
+    }
+
+    #[test]
+    fn test_max_gas_cost() {
+        let request = make_request(EntryPointVersion::V07);
+        // (100000 + 200000 + 50000) * 20 gwei
+        assert_eq!(
+            max_gas_cost(&request, EntryPointVersion::V06, None),
+            7000000000000000u64.into()
+        );
+        assert_eq!(
+            max_gas_cost(&request, EntryPointVersion::V07, None),
+            7000000000000000u64.into()
+        );
+        let paymaster = Paymaster {
+            address: [0x22; 20],
+            gas_limit: 30000u32.into(),
+        };
+        // (100000 + 3 * 200000 + 50000) * 20 gwei
+        assert_eq!(
+            max_gas_cost(&request, EntryPointVersion::V06, Some(&paymaster)),
+            15000000000000000u64.into()
+        );
+        // (100000 + 200000 + 50000 + 30000) * 20 gwei
+        assert_eq!(
+            max_gas_cost(&request, EntryPointVersion::V07, Some(&paymaster)),
+            7600000000000000u64.into()
+        );
+    }
+
+    #[test]
+    fn test_parse_paymaster() {
+        assert!(
+            parse_paymaster(&[], EntryPointVersion::V07)
+                .unwrap()
+                .is_none()
+        );
+        let mut data = vec![0x22; 20];
+        data.extend(10000u128.to_be_bytes());
+        data.extend(20000u128.to_be_bytes());
+        data.extend(b"paymaster data");
+        let paymaster = parse_paymaster(&data, EntryPointVersion::V07)
+            .unwrap()
+            .unwrap();
+        assert_eq!(paymaster.address, [0x22; 20]);
+        assert_eq!(paymaster.gas_limit, 30000u32.into());
+        let paymaster = parse_paymaster(&data, EntryPointVersion::V06)
+            .unwrap()
+            .unwrap();
+        assert_eq!(paymaster.gas_limit, BigUint::default());
+
+        assert!(parse_paymaster(&data[..19], EntryPointVersion::V06).is_err());
+        assert!(parse_paymaster(&data[..51], EntryPointVersion::V07).is_err());
+    }
+
+    /// Checks that `signature` is a signature of the EIP-191 hash of `user_op_hash`.
+    fn verify_signature(hal: &mut TestingHal, signature: &[u8], user_op_hash: &[u8; 32]) {
+        let mut msg = b"\x19Ethereum Signed Message:\n32".to_vec();
+        msg.extend(user_op_hash);
+        let sighash: [u8; 32] = sha3::Keccak256::digest(&msg).into();
+        let privkey = keystore::secp256k1_get_private_key(hal, KEYPATH).unwrap();
+        let pubkey = secp256k1::SecretKey::from_slice(&privkey)
+            .unwrap()
+            .public_key(SECP256K1);
+        assert_eq!(signature.len(), 65);
+        SECP256K1
+            .verify_ecdsa(
+                &secp256k1::Message::from_digest(sighash),
+                &secp256k1::ecdsa::Signature::from_compact(&signature[..64]).unwrap(),
+                &pubkey,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_process_execute() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let signature = match block_on(process(
+            &mut mock_hal,
+            &make_request(EntryPointVersion::V07),
+        )) {
+            Ok(Response::Sign(pb::EthSignResponse { signature })) => signature,
+            _ => panic!("unexpected response"),
+        };
+        verify_signature(
+            &mut mock_hal,
+            &signature,
+            &hex!("53659627176cec86515a65aff0326f754ae94aa7c81284fc9f74746e19ab83b7"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "".into(),
+                    body: "Sign user operation\non\n\nEthereum".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Smart account".into(),
+                    body: "0x1111111111111111111111111111111111111111".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "0x04F264Cf34440313B4A0192A352814FBe927b885".into(),
+                    amount: "0.530564 ETH".into(),
+                },
+                Screen::TotalFee {
+                    total: "0.537564 ETH".into(),
+                    fee: "0.007 ETH".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_execute_batch() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let mut request = make_request(EntryPointVersion::V06);
+        request.call_data = CALL_DATA_EXECUTE_BATCH.to_vec();
+        request.init_code = hex!("3333333333333333333333333333333333333333abcd").to_vec();
+        request.paymaster_and_data = hex!("2222222222222222222222222222222222222222").to_vec();
+        assert!(matches!(
+            block_on(process(&mut mock_hal, &request)),
+            Ok(Response::Sign(_))
+        ));
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "".into(),
+                    body: "Sign user operation\non\n\nEthereum".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Smart account".into(),
+                    body: "0x1111111111111111111111111111111111111111".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Smart account".into(),
+                    body: "The account will be\ncreated by this\noperation.".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Smart account".into(),
+                    body: "Call 1 of 2".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "0x04F264Cf34440313B4A0192A352814FBe927b885".into(),
+                    amount: "0.530564 ETH".into(),
+                },
+                Screen::Confirm {
+                    title: "Smart account".into(),
+                    body: "Call 2 of 2".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "0x857B3D969eAcB775a9f79cabc62Ec4bB1D1cd60e".into(),
+                    amount: "1 USDT".into(),
+                },
+                Screen::Confirm {
+                    title: "Paymaster".into(),
+                    body: "Fees may be paid by\n0x2222222222222222222222222222222222222222".into(),
+                    longtouch: false,
+                },
+                Screen::TotalFee {
+                    total: "0.545564 ETH".into(),
+                    fee: "0.015 ETH".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_unknown_call_data() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        let mut request = make_request(EntryPointVersion::V07);
+        request.call_data = hex!("deadbeef").to_vec();
+        request.entry_point = [0x44; 20].to_vec();
+        assert!(matches!(
+            block_on(process(&mut mock_hal, &request)),
+            Ok(Response::Sign(_))
+        ));
+        assert_eq!(
+            mock_hal.ui.screens[1],
+            Screen::Confirm {
+                title: "Unknown\nEntryPoint".into(),
+                body: "0x4444444444444444444444444444444444444444".into(),
+                longtouch: false,
+            }
+        );
+        assert_eq!(
+            mock_hal.ui.screens[5],
+            Screen::Confirm {
+                title: "Call data".into(),
+                body: "deadbeef".into(),
+                longtouch: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_user_abort() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.abort_nth(2);
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &make_request(EntryPointVersion::V07)
+            )),
+            Err(Error::UserAbort)
+        );
+        assert_eq!(
+            mock_hal.ui.screens.last(),
+            Some(&Screen::Status {
+                title: "Transaction\ncanceled".into(),
+                success: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_process_invalid_input() {
+        mock_unlocked();
+        let invalid: &[fn(&mut pb::EthSignUserOperationRequest)] = &[
+            |r| r.entry_point_version = 2,
+            |r| r.keypath = vec![44 + HARDENED, 0 + HARDENED, 0 + HARDENED, 0, 0],
+            |r| r.entry_point = vec![0; 19],
+            |r| r.sender = vec![],
+            |r| r.nonce = vec![0, 1],
+            |r| r.nonce = vec![1; 33],
+            |r| r.call_gas_limit = vec![1; 17],
+            |r| r.max_fee_per_gas = vec![0, 1],
+            |r| r.init_code = vec![1; 19],
+            |r| r.paymaster_and_data = vec![1; 20],
+            |r| r.call_data = vec![1; MAX_CALL_DATA_LENGTH + 1],
+        ];
+        for modify in invalid {
+            // On an unknown network with an unusual keypath, nothing is shown before the request
+            // is rejected.
+            for chain_id in [1, 123456] {
+                let mut request = make_request(EntryPointVersion::V07);
+                request.chain_id = chain_id;
+                request.keypath[1] = 1 + HARDENED;
+                modify(&mut request);
+                let mut mock_hal = TestingHal::new();
+                assert_eq!(
+                    block_on(process(&mut mock_hal, &request)),
+                    Err(Error::InvalidInput)
+                );
+                assert!(mock_hal.ui.screens.is_empty());
+            }
+        }
+    }
+}
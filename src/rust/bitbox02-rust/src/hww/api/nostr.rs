@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(not(feature = "app-nostr"))]
+compile_error!("Nostr code is being compiled even though the app-nostr feature is not enabled");
+
+mod encryption;
+mod keypath;
+mod nip44;
+mod pubrequest;
+mod sign_event;
+
+use super::Error;
+use super::pb;
+
+use pb::nostr_request::Request;
+use pb::nostr_response::Response;
+
+/// Name shown to the user.
+const NAME: &str = "Nostr";
+
+/// Handle a Nostr protobuf api call.
+pub async fn process_api(
+    hal: &mut impl crate::hal::Hal,
+    request: &Request,
+) -> Result<Response, Error> {
+    match request {
+        Request::Pub(request) => pubrequest::process(hal, request).await,
+        Request::SignEvent(request) => sign_event::process(hal, request).await,
+        Request::Encrypt(request) => encryption::process_encrypt(hal, request).await,
+        Request::Decrypt(request) => encryption::process_decrypt(hal, request).await,
+    }
+}
@@ -0,0 +1,431 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use pb::NostrEncryptionScheme;
+use pb::nostr_response::Response;
+
+use super::nip44;
+use super::pubrequest::{get_pubkey, npub};
+use crate::hal::{Random, Ui};
+use crate::keystore;
+
+use bitcoin::secp256k1::{Parity, PublicKey, SecretKey, XOnlyPublicKey, ecdh};
+use sha2::{Digest, Sha256};
+use util::cell::SyncCell;
+use zeroize::Zeroizing;
+
+/// Number of confirmed counterparties that are remembered. When full, the oldest one is replaced.
+const MAX_APPROVED_COUNTERPARTIES: usize = 16;
+
+#[derive(Clone, Copy)]
+struct ApprovedCounterparties {
+    /// SHA256 of our public key followed by the counterparty's public key.
+    entries: [Option<[u8; 32]>; MAX_APPROVED_COUNTERPARTIES],
+    next: usize,
+}
+
+// Counterparties the user allowed encrypting and decrypting messages with. They are kept in RAM
+// only, so the user confirms again after replugging the device.
+static APPROVED_COUNTERPARTIES: SyncCell<ApprovedCounterparties> =
+    SyncCell::new(ApprovedCounterparties {
+        entries: [None; MAX_APPROVED_COUNTERPARTIES],
+        next: 0,
+    });
+
+/// Asks the user to confirm the counterparty, unless they already did for this identity.
+async fn confirm_counterparty(
+    hal: &mut impl crate::hal::Hal,
+    our_pubkey: &[u8; 32],
+    counterparty: &[u8; 32],
+) -> Result<(), Error> {
+    let id: [u8; 32] = Sha256::new()
+        .chain_update(our_pubkey)
+        .chain_update(counterparty)
+        .finalize()
+        .into();
+    let mut approved = APPROVED_COUNTERPARTIES.read();
+    if approved.entries.contains(&Some(id)) {
+        return Ok(());
+    }
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Messages with",
+            body: &npub(counterparty),
+            scrollable: true,
+            longtouch: true,
+            ..Default::default()
+        })
+        .await?;
+    approved.entries[approved.next] = Some(id);
+    approved.next = (approved.next + 1) % MAX_APPROVED_COUNTERPARTIES;
+    APPROVED_COUNTERPARTIES.write(approved);
+    Ok(())
+}
+
+/// Returns the x coordinate of the ECDH shared point of the key at the keypath and the
+/// counterparty's public key, lifted to the point with even y.
+fn ecdh_shared_x(
+    hal: &mut impl crate::hal::Hal,
+    keypath: &[u32],
+    counterparty: &XOnlyPublicKey,
+) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let secret_key = SecretKey::from_slice(&keystore::secp256k1_get_private_key(hal, keypath)?)
+        .or(Err(Error::Generic))?;
+    let point = Zeroizing::new(ecdh::shared_secret_point(
+        &PublicKey::from_x_only_public_key(*counterparty, Parity::Even),
+        &secret_key,
+    ));
+    Ok(Zeroizing::new(point[..32].try_into().unwrap()))
+}
+
+/// Validates the request, confirms the counterparty with the user and returns the ECDH shared
+/// secret.
+async fn prepare(
+    hal: &mut impl crate::hal::Hal,
+    keypath: &[u32],
+    counterparty: &[u8],
+) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let our_pubkey = get_pubkey(hal, keypath)?;
+    let counterparty: &[u8; 32] = counterparty.try_into().or(Err(Error::InvalidInput))?;
+    let counterparty_key = XOnlyPublicKey::from_slice(counterparty).or(Err(Error::InvalidInput))?;
+    confirm_counterparty(hal, &our_pubkey, counterparty).await?;
+    ecdh_shared_x(hal, keypath, &counterparty_key)
+}
+
+/// Encrypts a message to the counterparty with NIP-44 or NIP-04.
+pub async fn process_encrypt(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::NostrEncryptRequest,
+) -> Result<Response, Error> {
+    let scheme = NostrEncryptionScheme::try_from(request.scheme)?;
+    let shared_x = prepare(hal, &request.keypath, &request.counterparty).await?;
+    let payload = match scheme {
+        NostrEncryptionScheme::NostrEncryptionNip44 => {
+            let nonce = hal.random().random_32_bytes();
+            nip44::encrypt(
+                &nip44::conversation_key(&shared_x),
+                &nonce,
+                &request.plaintext,
+            )
+            .or(Err(Error::InvalidInput))?
+        }
+        NostrEncryptionScheme::NostrEncryptionNip04 => {
+            let iv: [u8; 16] = hal.random().random_32_bytes()[..16].try_into().unwrap();
+            bitbox_aes::encrypt(&iv, &shared_x, &request.plaintext)
+        }
+    };
+    Ok(Response::Encrypt(pb::NostrEncryptResponse { payload }))
+}
+
+/// Decrypts a message exchanged with the counterparty with NIP-44 or NIP-04.
+pub async fn process_decrypt(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::NostrDecryptRequest,
+) -> Result<Response, Error> {
+    let scheme = NostrEncryptionScheme::try_from(request.scheme)?;
+    let shared_x = prepare(hal, &request.keypath, &request.counterparty).await?;
+    let plaintext = match scheme {
+        NostrEncryptionScheme::NostrEncryptionNip44 => {
+            nip44::decrypt(&nip44::conversation_key(&shared_x), &request.payload)
+        }
+        NostrEncryptionScheme::NostrEncryptionNip04 => {
+            bitbox_aes::decrypt(&shared_x, &request.payload)
+        }
+    }
+    .or(Err(Error::InvalidInput))?;
+    Ok(Response::Decrypt(pb::NostrDecryptResponse {
+        plaintext: plaintext.to_vec(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked_using_mnemonic;
+    use hex_lit::hex;
+    use util::bb02_async::block_on;
+    use util::bip32::HARDENED;
+
+    const KEYPATH: &[u32] = &[44 + HARDENED, 1237 + HARDENED, HARDENED, 0, 0];
+    // Public key of the private key 2.
+    const COUNTERPARTY: [u8; 32] =
+        hex!("c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5");
+    const COUNTERPARTY_NPUB: &str =
+        "npub1ccz8l9zpa47k6vz9gphftsrumpw80rjt3nhnefat4symjhrsnmjs38mnyd";
+
+    fn setup() {
+        mock_unlocked_using_mnemonic(
+            "leader monkey parrot ring guide accident before fence cannon height naive bean",
+            "",
+        );
+        APPROVED_COUNTERPARTIES.write(ApprovedCounterparties {
+            entries: [None; MAX_APPROVED_COUNTERPARTIES],
+            next: 0,
+        });
+    }
+
+    fn encrypt(
+        mock_hal: &mut TestingHal,
+        scheme: NostrEncryptionScheme,
+        counterparty: &[u8],
+        plaintext: &[u8],
+    ) -> Result<Response, Error> {
+        block_on(process_encrypt(
+            mock_hal,
+            &pb::NostrEncryptRequest {
+                keypath: KEYPATH.to_vec(),
+                scheme: scheme as _,
+                counterparty: counterparty.to_vec(),
+                plaintext: plaintext.to_vec(),
+            },
+        ))
+    }
+
+    fn decrypt(
+        mock_hal: &mut TestingHal,
+        scheme: NostrEncryptionScheme,
+        payload: &[u8],
+    ) -> Result<Response, Error> {
+        block_on(process_decrypt(
+            mock_hal,
+            &pb::NostrDecryptRequest {
+                keypath: KEYPATH.to_vec(),
+                scheme: scheme as _,
+                counterparty: COUNTERPARTY.to_vec(),
+                payload: payload.to_vec(),
+            },
+        ))
+    }
+
+    fn encrypted(payload: &[u8]) -> Result<Response, Error> {
+        Ok(Response::Encrypt(pb::NostrEncryptResponse {
+            payload: payload.to_vec(),
+        }))
+    }
+
+    fn decrypted(plaintext: &[u8]) -> Result<Response, Error> {
+        Ok(Response::Decrypt(pb::NostrDecryptResponse {
+            plaintext: plaintext.to_vec(),
+        }))
+    }
+
+    #[test]
+    fn test_nip44() {
+        setup();
+        let mut mock_hal = TestingHal::new();
+        mock_hal.random.mock_next([0x11; 32]);
+        assert_eq!(
+            encrypt(
+                &mut mock_hal,
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &COUNTERPARTY,
+                b"Hello, Nostr!"
+            ),
+            encrypted(&hex!(
+                "021111111111111111111111111111111111111111111111111111111111111111f7cb9ca3f9e9c418f9a648edfeebf17fbd7304f48713449a1a532ce279c8d63fa1343b06b5d65b4efaea5b6e50c9fdcdb74078b4feebc5eaf44e8f31445f791e8e64"
+            ))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![Screen::Confirm {
+                title: "Messages with".into(),
+                body: COUNTERPARTY_NPUB.into(),
+                longtouch: true,
+            }]
+        );
+
+        // Message from the counterparty. The counterparty was already confirmed.
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            decrypt(
+                &mut mock_hal,
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &hex!(
+                    "0233333333333333333333333333333333333333333333333333333333333333334655c14ccf41159ffd4d1b607a65b4349a9d686bb9fef63c5207337283beaaf87f4599c35fd6b17a7833e1f402162d0771850b5fde9f459a84a87a37dc8638f15e21"
+                )
+            ),
+            decrypted(b"gm")
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+
+        // Invalid payload.
+        assert_eq!(
+            decrypt(
+                &mut TestingHal::new(),
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &[2; 99]
+            ),
+            Err(Error::InvalidInput)
+        );
+        // Empty plaintext.
+        assert_eq!(
+            encrypt(
+                &mut TestingHal::new(),
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &COUNTERPARTY,
+                b""
+            ),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_nip04() {
+        setup();
+        let mut mock_hal = TestingHal::new();
+        mock_hal.random.mock_next([0x22; 32]);
+        assert_eq!(
+            encrypt(
+                &mut mock_hal,
+                NostrEncryptionScheme::NostrEncryptionNip04,
+                &COUNTERPARTY,
+                b"Hello, Nostr!"
+            ),
+            encrypted(&hex!(
+                "22222222222222222222222222222222d07e60ce66aaf43d371dff62fea32e7c"
+            ))
+        );
+        assert_eq!(mock_hal.ui.screens.len(), 1);
+
+        assert_eq!(
+            decrypt(
+                &mut TestingHal::new(),
+                NostrEncryptionScheme::NostrEncryptionNip04,
+                &hex!("44444444444444444444444444444444d123bcbf31b1d3807d09e597ab6795f6")
+            ),
+            decrypted(b"gm")
+        );
+        // Invalid padding.
+        assert_eq!(
+            decrypt(
+                &mut TestingHal::new(),
+                NostrEncryptionScheme::NostrEncryptionNip04,
+                &hex!("44444444444444444444444444444444d123bcbf31b1d3807d09e597ab6795f7")
+            ),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_confirm_counterparty() {
+        setup();
+
+        // User rejects the counterparty.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.abort_nth(0);
+        assert_eq!(
+            encrypt(
+                &mut mock_hal,
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &COUNTERPARTY,
+                b"gm"
+            ),
+            Err(Error::UserAbort)
+        );
+        // Asked again.
+        let mut mock_hal = TestingHal::new();
+        encrypt(
+            &mut mock_hal,
+            NostrEncryptionScheme::NostrEncryptionNip44,
+            &COUNTERPARTY,
+            b"gm",
+        )
+        .unwrap();
+        assert_eq!(mock_hal.ui.screens.len(), 1);
+
+        // Approvals are per identity.
+        let mut mock_hal = TestingHal::new();
+        block_on(process_encrypt(
+            &mut mock_hal,
+            &pb::NostrEncryptRequest {
+                keypath: vec![44 + HARDENED, 1237 + HARDENED, 1 + HARDENED, 0, 0],
+                scheme: NostrEncryptionScheme::NostrEncryptionNip44 as _,
+                counterparty: COUNTERPARTY.to_vec(),
+                plaintext: b"gm".to_vec(),
+            },
+        ))
+        .unwrap();
+        assert_eq!(mock_hal.ui.screens.len(), 1);
+
+        // The oldest approval is forgotten when the list is full.
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        for i in 1..MAX_APPROVED_COUNTERPARTIES {
+            let secret_key = SecretKey::from_slice(&[i as u8 + 2; 32]).unwrap();
+            let counterparty = secret_key.x_only_public_key(&secp).0.serialize();
+            let mut mock_hal = TestingHal::new();
+            encrypt(
+                &mut mock_hal,
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &counterparty,
+                b"gm",
+            )
+            .unwrap();
+            assert_eq!(mock_hal.ui.screens.len(), 1);
+        }
+        let mut mock_hal = TestingHal::new();
+        encrypt(
+            &mut mock_hal,
+            NostrEncryptionScheme::NostrEncryptionNip44,
+            &COUNTERPARTY,
+            b"gm",
+        )
+        .unwrap();
+        assert_eq!(mock_hal.ui.screens.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        setup();
+        // Not a valid x coordinate.
+        assert_eq!(
+            encrypt(
+                &mut TestingHal::new(),
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &[0xff; 32],
+                b"gm"
+            ),
+            Err(Error::InvalidInput)
+        );
+        // Wrong size.
+        assert_eq!(
+            encrypt(
+                &mut TestingHal::new(),
+                NostrEncryptionScheme::NostrEncryptionNip44,
+                &COUNTERPARTY[..31],
+                b"gm"
+            ),
+            Err(Error::InvalidInput)
+        );
+        // Unknown scheme.
+        assert_eq!(
+            block_on(process_encrypt(
+                &mut TestingHal::new(),
+                &pb::NostrEncryptRequest {
+                    keypath: KEYPATH.to_vec(),
+                    scheme: 2,
+                    counterparty: COUNTERPARTY.to_vec(),
+                    plaintext: b"gm".to_vec(),
+                },
+            )),
+            Err(Error::InvalidInput)
+        );
+        // Invalid keypath.
+        assert_eq!(
+            block_on(process_decrypt(
+                &mut TestingHal::new(),
+                &pb::NostrDecryptRequest {
+                    keypath: vec![44 + HARDENED, 1237 + HARDENED, HARDENED, 0],
+                    scheme: 0,
+                    counterparty: COUNTERPARTY.to_vec(),
+                    payload: vec![],
+                },
+            )),
+            Err(Error::InvalidInput)
+        );
+    }
+}
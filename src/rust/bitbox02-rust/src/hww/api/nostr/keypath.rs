@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use util::bip32::HARDENED;
+
+const PURPOSE: u32 = 44 + HARDENED;
+const COIN: u32 = 1237 + HARDENED;
+const ACCOUNT_MAX: u32 = 99; // 100 accounts
+
+/// Returns true if the keypath is m/44'/1237'/account'/0/0 as specified in NIP-06, where account
+/// is between 0' and 99'.
+pub fn is_valid_keypath(keypath: &[u32]) -> bool {
+    matches!(
+        keypath,
+        &[PURPOSE, COIN, account, 0, 0] if (HARDENED..=HARDENED + ACCOUNT_MAX).contains(&account)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_keypath() {
+        assert!(is_valid_keypath(&[
+            44 + HARDENED,
+            1237 + HARDENED,
+            HARDENED,
+            0,
+            0
+        ]));
+        assert!(is_valid_keypath(&[
+            44 + HARDENED,
+            1237 + HARDENED,
+            99 + HARDENED,
+            0,
+            0
+        ]));
+
+        // Account too high.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            1237 + HARDENED,
+            100 + HARDENED,
+            0,
+            0
+        ]));
+        // Unhardened account.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            1237 + HARDENED,
+            0,
+            0,
+            0
+        ]));
+        // Wrong change or address index.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            1237 + HARDENED,
+            HARDENED,
+            1,
+            0
+        ]));
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            1237 + HARDENED,
+            HARDENED,
+            0,
+            1
+        ]));
+        // Wrong coin.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            HARDENED,
+            HARDENED,
+            0,
+            0
+        ]));
+        // Too short.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            1237 + HARDENED,
+            HARDENED,
+            0
+        ]));
+    }
+}
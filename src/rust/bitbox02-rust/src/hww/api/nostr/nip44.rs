@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! NIP-44 version 2 payload encryption.
+//!
+//! See https://github.com/nostr-protocol/nips/blob/master/44.md
+
+use alloc::vec::Vec;
+
+use chacha20::ChaCha20;
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+const VERSION: u8 = 2;
+const NONCE_SIZE: usize = 32;
+const MAC_SIZE: usize = 32;
+const MIN_PLAINTEXT_SIZE: usize = 1;
+const MAX_PLAINTEXT_SIZE: usize = 65535;
+
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    for part in parts {
+        mac.update(part);
+    }
+    mac
+}
+
+/// Returns the conversation key, which is the HKDF-extract of the x coordinate of the ECDH shared
+/// point.
+pub fn conversation_key(shared_x: &[u8; 32]) -> Zeroizing<[u8; 32]> {
+    Zeroizing::new(
+        hmac_sha256(b"nip44-v2", &[shared_x])
+            .finalize()
+            .into_bytes()
+            .into(),
+    )
+}
+
+struct MessageKeys {
+    chacha_key: Zeroizing<[u8; 32]>,
+    chacha_nonce: [u8; 12],
+    hmac_key: Zeroizing<[u8; 32]>,
+}
+
+/// HKDF-expand of the conversation key with the nonce as info, to 76 bytes.
+fn message_keys(conversation_key: &[u8; 32], nonce: &[u8; NONCE_SIZE]) -> MessageKeys {
+    let mut okm = Zeroizing::new([0u8; 96]);
+    let mut previous: &[u8] = &[];
+    for (i, chunk) in okm.chunks_mut(32).enumerate() {
+        let block = hmac_sha256(conversation_key, &[previous, nonce, &[i as u8 + 1]])
+            .finalize()
+            .into_bytes();
+        chunk.copy_from_slice(&block);
+        previous = chunk;
+    }
+    MessageKeys {
+        chacha_key: Zeroizing::new(okm[..32].try_into().unwrap()),
+        chacha_nonce: okm[32..44].try_into().unwrap(),
+        hmac_key: Zeroizing::new(okm[44..76].try_into().unwrap()),
+    }
+}
+
+/// Length of the plaintext after padding, excluding the two length prefix bytes.
+fn padded_len(unpadded_len: usize) -> usize {
+    if unpadded_len <= 32 {
+        return 32;
+    }
+    let next_power = 1 << (usize::BITS - (unpadded_len - 1).leading_zeros());
+    let chunk = if next_power <= 256 {
+        32
+    } else {
+        next_power / 8
+    };
+    chunk * ((unpadded_len - 1) / chunk + 1)
+}
+
+/// Encrypts the plaintext. The result is the raw payload, which is base64 encoded by the host:
+/// `version || nonce || ciphertext || mac`.
+pub fn encrypt(
+    conversation_key: &[u8; 32],
+    nonce: &[u8; NONCE_SIZE],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, ()> {
+    if !(MIN_PLAINTEXT_SIZE..=MAX_PLAINTEXT_SIZE).contains(&plaintext.len()) {
+        return Err(());
+    }
+    let keys = message_keys(conversation_key, nonce);
+
+    let mut payload =
+        Vec::with_capacity(1 + NONCE_SIZE + 2 + padded_len(plaintext.len()) + MAC_SIZE);
+    payload.push(VERSION);
+    payload.extend_from_slice(nonce);
+    payload.extend_from_slice(&(plaintext.len() as u16).to_be_bytes());
+    payload.extend_from_slice(plaintext);
+    payload.resize(1 + NONCE_SIZE + 2 + padded_len(plaintext.len()), 0);
+    ChaCha20::new(&(*keys.chacha_key).into(), &keys.chacha_nonce.into())
+        .apply_keystream(&mut payload[1 + NONCE_SIZE..]);
+
+    let mac = hmac_sha256(keys.hmac_key.as_slice(), &[&payload[1..]])
+        .finalize()
+        .into_bytes();
+    payload.extend_from_slice(&mac);
+    Ok(payload)
+}
+
+/// Decrypts a raw (base64 decoded) payload. Fails if the MAC or the padding is invalid.
+pub fn decrypt(conversation_key: &[u8; 32], payload: &[u8]) -> Result<Zeroizing<Vec<u8>>, ()> {
+    // The smallest payload is a padded plaintext of 32 bytes.
+    if payload.len() < 1 + NONCE_SIZE + 2 + 32 + MAC_SIZE || payload[0] != VERSION {
+        return Err(());
+    }
+    let (payload, mac) = payload.split_at(payload.len() - MAC_SIZE);
+    let nonce: &[u8; NONCE_SIZE] = payload[1..1 + NONCE_SIZE].try_into().unwrap();
+    let ciphertext = &payload[1 + NONCE_SIZE..];
+    let keys = message_keys(conversation_key, nonce);
+    hmac_sha256(keys.hmac_key.as_slice(), &[nonce, ciphertext])
+        .verify_slice(mac)
+        .or(Err(()))?;
+
+    let mut padded = Zeroizing::new(ciphertext.to_vec());
+    ChaCha20::new(&(*keys.chacha_key).into(), &keys.chacha_nonce.into())
+        .apply_keystream(&mut padded);
+    let unpadded_len = u16::from_be_bytes([padded[0], padded[1]]) as usize;
+    if unpadded_len < MIN_PLAINTEXT_SIZE || padded.len() != 2 + padded_len(unpadded_len) {
+        return Err(());
+    }
+    Ok(Zeroizing::new(padded[2..2 + unpadded_len].to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_lit::hex;
+
+    #[test]
+    fn test_padded_len() {
+        // Test vectors from https://github.com/paulmillr/nip44/blob/main/nip44.vectors.json
+        let vectors: &[(usize, usize)] = &[
+            (16, 32),
+            (32, 32),
+            (33, 64),
+            (37, 64),
+            (45, 64),
+            (49, 64),
+            (64, 64),
+            (65, 96),
+            (100, 128),
+            (111, 128),
+            (200, 224),
+            (250, 256),
+            (320, 320),
+            (383, 384),
+            (384, 384),
+            (400, 448),
+            (500, 512),
+            (512, 512),
+            (515, 640),
+            (700, 768),
+            (800, 896),
+            (900, 1024),
+            (1020, 1024),
+            (65536, 65536),
+        ];
+        for &(unpadded, padded) in vectors {
+            assert_eq!(padded_len(unpadded), padded);
+        }
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        // Test vector from https://github.com/paulmillr/nip44/blob/main/nip44.vectors.json, with
+        // the private keys 1 and 2. The shared x coordinate is that of 2*G.
+        let conversation_key = conversation_key(&hex!(
+            "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"
+        ));
+        assert_eq!(
+            *conversation_key,
+            hex!("c41c775356fd92eadc63ff5a0dc1da211b268cbea22316767095b2871ea1412d")
+        );
+        let nonce = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+        // Base64: AgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABee0G5VSK0/9YypIObAtDKfYEAjD35uVkHyB0F4DwrcNaCXlCWZKaArsGrY6M9wnuTMxWfp1RTN9Xga8no+kF5Vsb
+        let payload = hex!(
+            "02000000000000000000000000000000000000000000000000000000000000000179ed06e5548ad3ff58ca920e6c0b4329f6040230f7e6e5641f20741780f0adc35a09794259929a02bb06ad8e8cf709ee4ccc567e9d514cdf5781af27a3e905e55b1b"
+        );
+        assert_eq!(
+            encrypt(&conversation_key, &nonce, b"a").unwrap(),
+            payload.to_vec()
+        );
+        assert_eq!(
+            decrypt(&conversation_key, &payload).unwrap().as_slice(),
+            b"a"
+        );
+
+        // Roundtrip of the largest plaintext.
+        let plaintext = vec![0x61; MAX_PLAINTEXT_SIZE];
+        let encrypted = encrypt(&conversation_key, &nonce, &plaintext).unwrap();
+        assert_eq!(encrypted.len(), 1 + 32 + 2 + 65536 + 32);
+        assert_eq!(
+            decrypt(&conversation_key, &encrypted).unwrap().as_slice(),
+            plaintext.as_slice()
+        );
+
+        // Invalid plaintext sizes.
+        assert!(encrypt(&conversation_key, &nonce, b"").is_err());
+        assert!(encrypt(&conversation_key, &nonce, &vec![0; MAX_PLAINTEXT_SIZE + 1]).is_err());
+
+        // Invalid MAC.
+        let mut invalid = payload;
+        *invalid.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&conversation_key, &invalid).is_err());
+        // Modified ciphertext.
+        let mut invalid = payload;
+        invalid[40] ^= 1;
+        assert!(decrypt(&conversation_key, &invalid).is_err());
+        // Unknown version.
+        let mut invalid = payload;
+        invalid[0] = 1;
+        assert!(decrypt(&conversation_key, &invalid).is_err());
+        // Truncated.
+        assert!(decrypt(&conversation_key, &payload[..payload.len() - 1]).is_err());
+        // Wrong key.
+        assert!(decrypt(&[0; 32], &payload).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::string::String;
+
+use pb::nostr_response::Response;
+
+use crate::hal::Ui;
+use crate::keystore;
+
+use bitcoin::bech32;
+
+/// Returns the x-only public key at the keypath, which identifies the user in Nostr events.
+pub fn get_pubkey(hal: &mut impl crate::hal::Hal, keypath: &[u32]) -> Result<[u8; 32], Error> {
+    if !super::keypath::is_valid_keypath(keypath) {
+        return Err(Error::InvalidInput);
+    }
+    let xpub = keystore::get_xpub_once(hal, keypath)?;
+    Ok(xpub.public_key()[1..].try_into().unwrap())
+}
+
+/// Encodes the public key as an npub string as specified in NIP-19.
+pub fn npub(pubkey: &[u8; 32]) -> String {
+    bech32::encode::<bech32::Bech32>(bech32::Hrp::parse_unchecked("npub"), pubkey).unwrap()
+}
+
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::NostrPubRequest,
+) -> Result<Response, Error> {
+    let npub = npub(&get_pubkey(hal, &request.keypath)?);
+    if request.display {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: super::NAME,
+                body: &npub,
+                scrollable: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    Ok(Response::Pub(pb::PubResponse { r#pub: npub }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked_using_mnemonic;
+    use hex_lit::hex;
+    use util::bb02_async::block_on;
+    use util::bip32::HARDENED;
+
+    #[test]
+    fn test_get_pubkey() {
+        // Test vectors from NIP-06.
+        mock_unlocked_using_mnemonic(
+            "leader monkey parrot ring guide accident before fence cannon height naive bean",
+            "",
+        );
+        let pubkey = get_pubkey(
+            &mut TestingHal::new(),
+            &[44 + HARDENED, 1237 + HARDENED, HARDENED, 0, 0],
+        )
+        .unwrap();
+        assert_eq!(
+            pubkey,
+            hex!("17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917")
+        );
+        assert_eq!(
+            npub(&pubkey).as_str(),
+            "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu"
+        );
+
+        mock_unlocked_using_mnemonic(
+            "what bleak badge arrange retreat wolf trade produce cricket blur garlic valid proud rude strong choose busy staff weather area salt hollow arm fade",
+            "",
+        );
+        assert_eq!(
+            get_pubkey(
+                &mut TestingHal::new(),
+                &[44 + HARDENED, 1237 + HARDENED, HARDENED, 0, 0]
+            ),
+            Ok(hex!(
+                "d41b22899549e1f3d335a31002cfd382174006e166d3e658e3a5eecdb6463573"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_process() {
+        mock_unlocked_using_mnemonic(
+            "leader monkey parrot ring guide accident before fence cannon height naive bean",
+            "",
+        );
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::NostrPubRequest {
+                    keypath: vec![44 + HARDENED, 1237 + HARDENED, HARDENED, 0, 0],
+                    display: true,
+                }
+            )),
+            Ok(Response::Pub(pb::PubResponse {
+                r#pub: "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu".into()
+            }))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![Screen::Confirm {
+                title: "Nostr".into(),
+                body: "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu".into(),
+                longtouch: false,
+            }]
+        );
+
+        // Invalid keypath.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::NostrPubRequest {
+                    keypath: vec![44 + HARDENED, 1237 + HARDENED, HARDENED, 0, 1],
+                    display: false,
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+
+        // Locked.
+        crate::keystore::lock();
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::NostrPubRequest {
+                    keypath: vec![44 + HARDENED, 1237 + HARDENED, HARDENED, 0, 0],
+                    display: false,
+                }
+            )),
+            Err(Error::Generic)
+        );
+    }
+}
@@ -0,0 +1,646 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use pb::nostr_response::Response;
+
+use crate::hal::Ui;
+use crate::keystore;
+use crate::workflow::verify_message;
+
+use sha2::{Digest, Sha256};
+
+/// Content longer than this can't be reasonably verified on the device.
+const MAX_CONTENT_SIZE: usize = 9999;
+
+/// How events of a supported kind are shown before signing.
+struct KindInfo {
+    name: &'static str,
+    /// Tags that are shown, by tag name, with the title of their screen. The value of the tag
+    /// (its second element) is shown as the body.
+    shown_tags: &'static [(&'static str, &'static str)],
+    /// The content is a ciphertext and is not shown.
+    encrypted: bool,
+}
+
+/// Returns how events of the given kind are shown, or None if the kind is not supported. Events
+/// of other kinds are not signed, as their tags could have a meaning that is not shown.
+/// See https://github.com/nostr-protocol/nips#event-kinds
+fn kind_info(kind: u32) -> Option<KindInfo> {
+    let (name, shown_tags, encrypted): (_, &[(&str, &str)], _) = match kind {
+        0 => ("Profile metadata", &[], false),
+        1 => ("Text note", &[], false),
+        // The followed accounts are counted instead of shown, see `process()`.
+        3 => ("Follow list", &[], false),
+        4 => ("Direct message", &[("p", "Recipient")], true),
+        5 => (
+            "Deletion request",
+            &[("e", "Delete event"), ("a", "Delete event")],
+            false,
+        ),
+        6 => ("Repost", &[("e", "Repost of")], false),
+        7 => ("Reaction", &[("e", "Reaction to")], false),
+        16 => ("Generic repost", &[("e", "Repost of")], false),
+        1111 => ("Comment", &[], false),
+        9734 => (
+            "Zap request",
+            &[("p", "Zap recipient"), ("amount", "Amount (msat)")],
+            false,
+        ),
+        10002 => ("Relay list", &[("r", "Relay")], false),
+        22242 => (
+            "Relay login",
+            &[("relay", "Relay"), ("challenge", "Challenge")],
+            false,
+        ),
+        27235 => ("HTTP login", &[("u", "URL"), ("method", "Method")], false),
+        30023 => ("Long-form article", &[], false),
+        _ => return None,
+    };
+    Some(KindInfo {
+        name,
+        shown_tags,
+        encrypted,
+    })
+}
+
+/// Appends `s` as a JSON string, escaped as specified in NIP-01. All other characters are included
+/// verbatim.
+fn write_json_string(out: &mut Vec<u8>, s: &str) {
+    out.push(b'"');
+    for c in s.chars() {
+        match c {
+            '\n' => out.extend_from_slice(b"\\n"),
+            '"' => out.extend_from_slice(b"\\\""),
+            '\\' => out.extend_from_slice(b"\\\\"),
+            '\r' => out.extend_from_slice(b"\\r"),
+            '\t' => out.extend_from_slice(b"\\t"),
+            '\x08' => out.extend_from_slice(b"\\b"),
+            '\x0c' => out.extend_from_slice(b"\\f"),
+            c => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+    out.push(b'"');
+}
+
+/// Serializes the event for computing its id:
+/// `[0,<pubkey, as a lowercase hex string>,<created_at>,<kind>,<tags>,<content>]`
+fn serialize_event(pubkey: &[u8; 32], request: &pb::NostrSignEventRequest) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(b"[0,");
+    write_json_string(&mut out, &hex::encode(pubkey));
+    out.extend_from_slice(format!(",{},{},[", request.created_at, request.kind).as_bytes());
+    for (i, tag) in request.tags.iter().enumerate() {
+        if i > 0 {
+            out.push(b',');
+        }
+        out.push(b'[');
+        for (j, value) in tag.values.iter().enumerate() {
+            if j > 0 {
+                out.push(b',');
+            }
+            write_json_string(&mut out, value);
+        }
+        out.push(b']');
+    }
+    out.extend_from_slice(b"],");
+    write_json_string(&mut out, &request.content);
+    out.push(b']');
+    out
+}
+
+/// Process a NIP-01 event signing request.
+///
+/// The response contains the event id and the BIP-340 Schnorr signature of it.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::NostrSignEventRequest,
+) -> Result<Response, Error> {
+    if request.content.len() > MAX_CONTENT_SIZE {
+        return Err(Error::InvalidInput);
+    }
+    let pubkey = super::pubrequest::get_pubkey(hal, &request.keypath)?;
+    let id: [u8; 32] = Sha256::digest(serialize_event(&pubkey, request)).into();
+
+    let kind = kind_info(request.kind).ok_or(Error::InvalidInput)?;
+
+    // (title, body) of the screens showing the tags.
+    let mut tag_screens: Vec<(&str, String)> = Vec::new();
+    if request.kind == 3 {
+        let count = request
+            .tags
+            .iter()
+            .filter(|tag| tag.values.first().map(String::as_str) == Some("p"))
+            .count();
+        tag_screens.push(("Follows", format!("{} accounts", count)));
+    }
+    for tag in request.tags.iter() {
+        let title = match tag.values.first().and_then(|name| {
+            kind.shown_tags
+                .iter()
+                .find(|(shown_name, _)| shown_name == name)
+        }) {
+            Some((_, title)) => title,
+            None => continue,
+        };
+        match tag.values.get(1) {
+            Some(value)
+                if !value.is_empty()
+                    && util::ascii::is_printable_ascii(value, util::ascii::Charset::All) =>
+            {
+                tag_screens.push((title, value.clone()))
+            }
+            _ => return Err(Error::InvalidInput),
+        }
+    }
+
+    let show_content = !request.content.is_empty() && !kind.encrypted;
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: super::NAME,
+            body: &format!("Sign event\n{}", kind.name),
+            accept_is_nextarrow: true,
+            longtouch: !show_content && tag_screens.is_empty(),
+            ..Default::default()
+        })
+        .await?;
+    for (i, (title, body)) in tag_screens.iter().enumerate() {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title,
+                body,
+                scrollable: true,
+                accept_is_nextarrow: true,
+                longtouch: !show_content && i == tag_screens.len() - 1,
+                ..Default::default()
+            })
+            .await?;
+    }
+    if show_content {
+        verify_message::verify(hal, "Content", "Content", request.content.as_bytes(), true).await?;
+    }
+
+    let signature = keystore::secp256k1_schnorr_sign(hal, &request.keypath, &id, None)?;
+    Ok(Response::SignEvent(pb::NostrSignEventResponse {
+        id: id.to_vec(),
+        signature: signature.to_vec(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked_using_mnemonic;
+    use bitcoin::secp256k1;
+    use hex_lit::hex;
+    use util::bb02_async::block_on;
+    use util::bip32::HARDENED;
+
+    const KEYPATH: &[u32] = &[44 + HARDENED, 1237 + HARDENED, HARDENED, 0, 0];
+    // Public key at `KEYPATH` of the NIP-06 test mnemonic.
+    const PUBKEY: [u8; 32] =
+        hex!("17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917");
+
+    fn mock_unlocked() {
+        mock_unlocked_using_mnemonic(
+            "leader monkey parrot ring guide accident before fence cannon height naive bean",
+            "",
+        );
+    }
+
+    /// Signs the event and checks that the response contains the expected id and a valid
+    /// signature of it.
+    fn sign_and_verify(
+        mock_hal: &mut TestingHal,
+        request: &pb::NostrSignEventRequest,
+        expected_id: &[u8; 32],
+    ) {
+        let response = match block_on(process(mock_hal, request)) {
+            Ok(Response::SignEvent(response)) => response,
+            _ => panic!("unexpected response"),
+        };
+        assert_eq!(response.id.as_slice(), expected_id);
+        let secp = secp256k1::Secp256k1::verification_only();
+        secp.verify_schnorr(
+            &secp256k1::schnorr::Signature::from_slice(&response.signature).unwrap(),
+            &secp256k1::Message::from_digest(*expected_id),
+            &secp256k1::XOnlyPublicKey::from_slice(&PUBKEY).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_serialize_event() {
+        let request = pb::NostrSignEventRequest {
+            keypath: KEYPATH.to_vec(),
+            created_at: 1,
+            kind: 7,
+            tags: vec![
+                pb::NostrTag {
+                    values: vec!["t".into()],
+                },
+                pb::NostrTag { values: vec![] },
+            ],
+            content: "a\tb\r\x08\x0c\x01é\n\"\\".into(),
+        };
+        assert_eq!(
+            serialize_event(&PUBKEY, &request),
+            "[0,\"17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917\",1,7,[[\"t\"],[]],\"a\\tb\\r\\b\\f\x01é\\n\\\"\\\\\"]".as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_process_text_note() {
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        sign_and_verify(
+            &mut mock_hal,
+            &pb::NostrSignEventRequest {
+                keypath: KEYPATH.to_vec(),
+                created_at: 1700000000,
+                kind: 1,
+                tags: vec![
+                    pb::NostrTag {
+                        values: vec![
+                            "e".into(),
+                            "5c83da77af1dec6d7289834998ad7aafbd9e2191396d75ec3cc27f5a77226f36"
+                                .into(),
+                            "wss://relay.example.com".into(),
+                            "reply".into(),
+                        ],
+                    },
+                    pb::NostrTag {
+                        values: vec![
+                            "p".into(),
+                            "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917"
+                                .into(),
+                        ],
+                    },
+                ],
+                content: "Hello\n\"Nostr\" \\o/".into(),
+            },
+            &hex!("23025de49442971baff3c30752d0f3f19f05878b5bfeff4d96ba2b75c9a4dfbc"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Nostr".into(),
+                    body: "Sign event\nText note".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Content 1/2".into(),
+                    body: "Hello".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Content 2/2".into(),
+                    body: "\"Nostr\" \\o/".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+
+        // Non-ASCII content is shown as hex.
+        let mut mock_hal = TestingHal::new();
+        sign_and_verify(
+            &mut mock_hal,
+            &pb::NostrSignEventRequest {
+                keypath: KEYPATH.to_vec(),
+                created_at: 1700000000,
+                kind: 1,
+                tags: vec![],
+                content: "gm 🌅".into(),
+            },
+            &hex!("afba8c5925fe199a8f6bc7a65148ebc5a5d1c1a34af2f7befb60779bef4914de"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens[1],
+            Screen::Confirm {
+                title: "Content\ndata (hex)".into(),
+                body: "676d20f09f8c85".into(),
+                longtouch: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_hidden_content() {
+        mock_unlocked();
+
+        // The ciphertext of direct messages is not shown.
+        let mut mock_hal = TestingHal::new();
+        sign_and_verify(
+            &mut mock_hal,
+            &pb::NostrSignEventRequest {
+                keypath: KEYPATH.to_vec(),
+                created_at: 1700000000,
+                kind: 4,
+                tags: vec![pb::NostrTag {
+                    values: vec![
+                        "p".into(),
+                        "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917".into(),
+                    ],
+                }],
+                content: "ciphertext?iv=abc".into(),
+            },
+            &hex!("f4f03a82bf4947efaee1bf70abe753736980f009a9b8f2dddef95914bb0efcae"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Nostr".into(),
+                    body: "Sign event\nDirect message".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Recipient".into(),
+                    body: "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_tags() {
+        mock_unlocked();
+
+        // Relay login: the relay and challenge are shown.
+        let mut mock_hal = TestingHal::new();
+        sign_and_verify(
+            &mut mock_hal,
+            &pb::NostrSignEventRequest {
+                keypath: KEYPATH.to_vec(),
+                created_at: 1700000000,
+                kind: 22242,
+                tags: vec![
+                    pb::NostrTag {
+                        values: vec!["relay".into(), "wss://relay.example.com".into()],
+                    },
+                    pb::NostrTag {
+                        values: vec!["challenge".into(), "challengestring".into()],
+                    },
+                ],
+                content: "".into(),
+            },
+            &hex!("bf9825d99ba89dda5ac8f64ec58d70375e5fc647867ddc869dd498402951ba21"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Nostr".into(),
+                    body: "Sign event\nRelay login".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Relay".into(),
+                    body: "wss://relay.example.com".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Challenge".into(),
+                    body: "challengestring".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+
+        // Deletion request: the deleted event is shown before the content.
+        let mut mock_hal = TestingHal::new();
+        sign_and_verify(
+            &mut mock_hal,
+            &pb::NostrSignEventRequest {
+                keypath: KEYPATH.to_vec(),
+                created_at: 1700000000,
+                kind: 5,
+                tags: vec![
+                    pb::NostrTag {
+                        values: vec![
+                            "e".into(),
+                            "5c83da77af1dec6d7289834998ad7aafbd9e2191396d75ec3cc27f5a77226f36"
+                                .into(),
+                        ],
+                    },
+                    pb::NostrTag {
+                        values: vec!["k".into(), "1".into()],
+                    },
+                ],
+                content: "posted by mistake".into(),
+            },
+            &hex!("8e8640c1fd2019fd6e5158e304f8c6a177a899d9aedbdcae53d63fb9d4a27e5d"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Nostr".into(),
+                    body: "Sign event\nDeletion request".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Delete event".into(),
+                    body: "5c83da77af1dec6d7289834998ad7aafbd9e2191396d75ec3cc27f5a77226f36".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Content".into(),
+                    body: "posted by mistake".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+
+        // Follow list: the followed accounts are counted.
+        let mut mock_hal = TestingHal::new();
+        sign_and_verify(
+            &mut mock_hal,
+            &pb::NostrSignEventRequest {
+                keypath: KEYPATH.to_vec(),
+                created_at: 1700000000,
+                kind: 3,
+                tags: vec![
+                    pb::NostrTag {
+                        values: vec![
+                            "p".into(),
+                            "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917"
+                                .into(),
+                        ],
+                    },
+                    pb::NostrTag {
+                        values: vec![
+                            "p".into(),
+                            "5c83da77af1dec6d7289834998ad7aafbd9e2191396d75ec3cc27f5a77226f36"
+                                .into(),
+                        ],
+                    },
+                    pb::NostrTag {
+                        values: vec!["t".into(), "bitcoin".into()],
+                    },
+                ],
+                content: "".into(),
+            },
+            &hex!("a2109755af2ad97e18f009df6f5c92d3b809fae8a45f58925c8fc5f2f49cc7d1"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Nostr".into(),
+                    body: "Sign event\nFollow list".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Follows".into(),
+                    body: "2 accounts".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+
+        // Zap request: the recipient and amount are shown.
+        let mut mock_hal = TestingHal::new();
+        sign_and_verify(
+            &mut mock_hal,
+            &pb::NostrSignEventRequest {
+                keypath: KEYPATH.to_vec(),
+                created_at: 1700000000,
+                kind: 9734,
+                tags: vec![
+                    pb::NostrTag {
+                        values: vec!["relays".into(), "wss://relay.example.com".into()],
+                    },
+                    pb::NostrTag {
+                        values: vec!["amount".into(), "21000".into()],
+                    },
+                    pb::NostrTag {
+                        values: vec![
+                            "p".into(),
+                            "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917"
+                                .into(),
+                        ],
+                    },
+                ],
+                content: "Zap!".into(),
+            },
+            &hex!("62de5477680dfed8b054e4fdec657f0511061929fb79a80dbc7d1ba47a038bf0"),
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Nostr".into(),
+                    body: "Sign event\nZap request".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Amount (msat)".into(),
+                    body: "21000".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Zap recipient".into(),
+                    body: "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Content".into(),
+                    body: "Zap!".into(),
+                    longtouch: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_invalid() {
+        mock_unlocked();
+        // Invalid keypath.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::NostrSignEventRequest {
+                    keypath: vec![44 + HARDENED, 1237 + HARDENED, HARDENED, 0],
+                    created_at: 1700000000,
+                    kind: 1,
+                    tags: vec![],
+                    content: "gm".into(),
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+        // Unsupported kind.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::NostrSignEventRequest {
+                    keypath: KEYPATH.to_vec(),
+                    created_at: 1700000000,
+                    kind: 12345,
+                    tags: vec![],
+                    content: "".into(),
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+        // Shown tag without a value, or with a value that can't be shown.
+        for values in [
+            vec!["relay".into()],
+            vec!["relay".into(), "wss://rélay".into()],
+        ] {
+            assert_eq!(
+                block_on(process(
+                    &mut TestingHal::new(),
+                    &pb::NostrSignEventRequest {
+                        keypath: KEYPATH.to_vec(),
+                        created_at: 1700000000,
+                        kind: 22242,
+                        tags: vec![pb::NostrTag { values }],
+                        content: "".into(),
+                    }
+                )),
+                Err(Error::InvalidInput)
+            );
+        }
+        // Content too long.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::NostrSignEventRequest {
+                    keypath: KEYPATH.to_vec(),
+                    created_at: 1700000000,
+                    kind: 1,
+                    tags: vec![],
+                    content: "a".repeat(MAX_CONTENT_SIZE + 1),
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+        // User aborts.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.abort_nth(0);
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::NostrSignEventRequest {
+                    keypath: KEYPATH.to_vec(),
+                    created_at: 1700000000,
+                    kind: 1,
+                    tags: vec![],
+                    content: "gm".into(),
+                }
+            )),
+            Err(Error::UserAbort)
+        );
+    }
+}
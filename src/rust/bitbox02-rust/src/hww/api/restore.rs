@@ -1,13 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::Error;
-use crate::hal::ui::ConfirmParams;
+use crate::hal::ui::{CanCancel, ConfirmParams, TrinaryChoice};
 use crate::pb;
+use crate::slip39;
 
 use pb::response::Response;
 
 #[cfg(feature = "app-u2f")]
 use crate::hal::SecureChip;
+use crate::hal::memory::SeedType;
 use crate::hal::{Memory, Ui};
 use crate::workflow::{password, unlock};
 
@@ -83,14 +85,13 @@ pub async fn from_file(
     Ok(Response::Success(pb::Success {}))
 }
 
-pub async fn from_mnemonic(
+/// Asks the user to confirm the current date and time, which initializes the U2F counter.
+#[cfg_attr(not(feature = "app-u2f"), allow(unused_variables))]
+async fn confirm_datetime(
     hal: &mut impl crate::hal::Hal,
-    #[cfg_attr(not(feature = "app-u2f"), allow(unused_variables))]
-    &pb::RestoreFromMnemonicRequest {
-        timestamp,
-        timezone_offset,
-    }: &pb::RestoreFromMnemonicRequest,
-) -> Result<Response, Error> {
+    timestamp: u32,
+    timezone_offset: i32,
+) -> Result<(), Error> {
     #[cfg(feature = "app-u2f")]
     {
         let datetime_string = util::datetime::format_datetime(timestamp, timezone_offset, false)
@@ -104,17 +105,17 @@ pub async fn from_mnemonic(
             })
             .await?;
     }
+    Ok(())
+}
 
-    let mnemonic = crate::workflow::mnemonic::get(hal.ui()).await?;
-    let seed = match crate::bip39::mnemonic_to_seed(&mnemonic) {
-        Ok(seed) => seed,
-        Err(()) => {
-            hal.ui().status("Recovery words\ninvalid", false).await;
-            return Err(Error::Generic);
-        }
-    };
-    hal.ui().status("Recovery words\nvalid", true).await;
-
+/// Asks the user for a new device password, stores the restored seed and unlocks the keystore.
+#[cfg_attr(not(feature = "app-u2f"), allow(unused_variables))]
+async fn store_seed(
+    hal: &mut impl crate::hal::Hal,
+    seed: &[u8],
+    seed_type: SeedType,
+    timestamp: u32,
+) -> Result<Response, Error> {
     // If entering password fails (repeat password does not match the first), we don't want to abort
     // the process immediately. We break out only if the user confirms.
     let password = loop {
@@ -133,7 +134,13 @@ pub async fn from_mnemonic(
         }
     };
 
-    if let Err(err) = crate::keystore::encrypt_and_store_seed(hal, &seed, &password) {
+    let result = match seed_type {
+        SeedType::Bip39 => crate::keystore::encrypt_and_store_seed(hal, seed, &password),
+        SeedType::Slip39 => {
+            crate::keystore::encrypt_and_store_slip39_master_secret(hal, seed, &password)
+        }
+    };
+    if let Err(err) = result {
         hal.ui()
             .status(&format!("Could not\nrestore backup\n{:?}", err), false)
             .await;
@@ -149,19 +156,126 @@ pub async fn from_mnemonic(
 
     hal.memory().set_initialized().or(Err(Error::Memory))?;
 
-    unlock::unlock_bip39(hal, &seed).await;
+    unlock::unlock_bip39(hal, seed).await;
     Ok(Response::Success(pb::Success {}))
 }
 
+pub async fn from_mnemonic(
+    hal: &mut impl crate::hal::Hal,
+    &pb::RestoreFromMnemonicRequest {
+        timestamp,
+        timezone_offset,
+    }: &pb::RestoreFromMnemonicRequest,
+) -> Result<Response, Error> {
+    confirm_datetime(hal, timestamp, timezone_offset).await?;
+
+    let mnemonic = crate::workflow::mnemonic::get(hal.ui()).await?;
+    let seed = match crate::bip39::mnemonic_to_seed(&mnemonic) {
+        Ok(seed) => seed,
+        Err(()) => {
+            hal.ui().status("Recovery words\ninvalid", false).await;
+            return Err(Error::Generic);
+        }
+    };
+    hal.ui().status("Recovery words\nvalid", true).await;
+
+    store_seed(hal, &seed, SeedType::Bip39, timestamp).await
+}
+
+/// Restores the seed from SLIP-39 shares. The user enters shares until enough groups have enough
+/// shares, followed by the passphrase the shares were created with (empty if none). The recovered
+/// master secret is the BIP-32 seed, so no mnemonic passphrase is asked for when unlocking.
+pub async fn from_slip39_shares(
+    hal: &mut impl crate::hal::Hal,
+    &pb::RestoreFromSlip39SharesRequest {
+        timestamp,
+        timezone_offset,
+    }: &pb::RestoreFromSlip39SharesRequest,
+) -> Result<Response, Error> {
+    confirm_datetime(hal, timestamp, timezone_offset).await?;
+
+    // All shares of a set have the same length, which depends on the seed size (16, 24 or 32
+    // bytes).
+    let num_words: usize = match hal
+        .ui()
+        .trinary_choice("How many words?", Some("20"), Some("27"), Some("33"))
+        .await
+    {
+        TrinaryChoice::Left => 20,
+        TrinaryChoice::Middle => 27,
+        TrinaryChoice::Right => 33,
+    };
+
+    let mut share_set = slip39::ShareSet::new();
+    let mut share_number: usize = 1;
+    while !share_set.is_complete() {
+        hal.ui()
+            .status(&format!("Enter share {}", share_number), true)
+            .await;
+        let mnemonic = crate::workflow::slip39::get_share(hal.ui(), num_words).await?;
+        let result = slip39::Share::from_mnemonic(&mnemonic).and_then(|share| {
+            let (group_index, group_count, member_threshold) =
+                (share.group_index, share.group_count, share.member_threshold);
+            share_set
+                .add(share)
+                .map(|()| (group_index, group_count, member_threshold))
+        });
+        match result {
+            Ok((group_index, group_count, member_threshold)) => {
+                share_number += 1;
+                let progress = format!(
+                    "{} of {} shares",
+                    share_set.group_share_count(group_index),
+                    member_threshold
+                );
+                let body = if group_count > 1 {
+                    format!("Group {}:\n{}", group_index + 1, progress)
+                } else {
+                    progress
+                };
+                hal.ui().status(&body, true).await;
+            }
+            Err(err) => {
+                let body = match err {
+                    slip39::Error::ShareMismatch => "Share does not\nmatch the others",
+                    slip39::Error::DuplicateShare => "Share already\nentered",
+                    slip39::Error::GroupComplete => "Group already\ncomplete",
+                    _ => "Invalid share",
+                };
+                hal.ui().status(body, false).await;
+            }
+        }
+    }
+
+    let passphrase = password::enter(
+        hal,
+        "Optional passphrase",
+        password::PasswordType::Bip39Passphrase,
+        CanCancel::Yes,
+    )
+    .await?;
+    let seed = match share_set.combine(&passphrase) {
+        Ok(seed) => seed,
+        Err(_) => {
+            hal.ui().status("Shares invalid", false).await;
+            return Err(Error::Generic);
+        }
+    };
+    hal.ui().status("Shares valid", true).await;
+
+    store_seed(hal, &seed, SeedType::Slip39, timestamp).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::{Screen, TestingHal};
     use bitbox02::memory;
     use util::bb02_async::block_on;
 
     use alloc::boxed::Box;
+    use alloc::collections::VecDeque;
     use alloc::vec::Vec;
 
     #[test]
@@ -212,4 +326,87 @@ mod tests {
         drop(mock_hal); // to remove mutable borrow of password_entries
         assert_eq!(password_entries, 2);
     }
+
+    #[test]
+    fn test_from_slip39_shares() {
+        crate::keystore::lock();
+        // SLIP-39 test vector: 2 of 3 shares, passphrase "TREZOR".
+        let share1 = "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed";
+        let share2 = "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking";
+        // The first share is entered twice.
+        let mut inputs: VecDeque<&str> = share1
+            .split(' ')
+            .chain(share1.split(' '))
+            .chain(share2.split(' '))
+            .chain(["TREZOR", "password", "password"])
+            .collect();
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.set_trinary_choice(Box::new(
+            |message, label_left, label_middle, label_right| {
+                assert_eq!(message, "How many words?");
+                assert_eq!(
+                    (label_left, label_middle, label_right),
+                    (Some("20"), Some("27"), Some("33"))
+                );
+                TrinaryChoice::Left
+            },
+        ));
+        mock_hal.ui.set_enter_string(Box::new(move |params| {
+            let title_ok = match inputs.len() {
+                3 => params.title == "Optional passphrase",
+                2 => params.title == "Set password",
+                1 => params.title == "Repeat password",
+                _ => params.slip39_wordlist && params.title.ends_with(" of 20"),
+            };
+            assert!(title_ok, "unexpected title {}", params.title);
+            Ok(inputs.pop_front().unwrap().into())
+        }));
+
+        assert_eq!(
+            block_on(from_slip39_shares(
+                &mut mock_hal,
+                &pb::RestoreFromSlip39SharesRequest {
+                    timestamp: 0,
+                    timezone_offset: 0,
+                }
+            )),
+            Ok(Response::Success(pb::Success {}))
+        );
+
+        let statuses: Vec<&str> = mock_hal
+            .ui
+            .screens
+            .iter()
+            .filter_map(|screen| match screen {
+                Screen::Status { title, .. } => Some(title.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                "Enter share 1",
+                "1 of 2 shares",
+                "Enter share 2",
+                "Share already\nentered",
+                "Enter share 2",
+                "2 of 2 shares",
+                "Shares valid",
+                "Success",
+            ]
+        );
+
+        assert!(!crate::keystore::is_locked());
+        assert!(mock_hal.memory.is_initialized());
+        assert_eq!(mock_hal.memory.get_seed_type(), SeedType::Slip39);
+        // The master secret of the test vector is the BIP-32 seed.
+        assert_eq!(
+            hex::encode(crate::keystore::copy_seed(&mut mock_hal).unwrap()),
+            "b43ceb7e57a0ea8766221624d01b0864"
+        );
+        assert_eq!(
+            hex::encode(crate::keystore::copy_bip39_seed(&mut mock_hal).unwrap()),
+            "b43ceb7e57a0ea8766221624d01b0864"
+        );
+    }
 }
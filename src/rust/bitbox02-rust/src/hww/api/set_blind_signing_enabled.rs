@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use crate::hal::ui::ConfirmParams;
+use crate::pb;
+
+use pb::response::Response;
+
+use crate::hal::{Memory, Ui};
+
+/// Handle the SetBlindSigningEnabled API call. The setting applies to all apps that support blind
+/// signing, which is why the user is told which ones are affected.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    &pb::SetBlindSigningEnabledRequest { enabled }: &pb::SetBlindSigningEnabledRequest,
+) -> Result<Response, Error> {
+    if enabled {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Warning",
+                body: "Blind signed data\ncannot be verified\non the device.",
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "All apps",
+                body: "Applies to Ethereum\ntyped messages and\nSolana transactions.",
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    let params = ConfirmParams {
+        title: if enabled { "Enable" } else { "Disable" },
+        body: "Blind\nsigning",
+        longtouch: true,
+        ..Default::default()
+    };
+
+    hal.ui().confirm(&params).await?;
+
+    if hal.memory().set_blind_signing_enabled(enabled).is_err() {
+        return Err(Error::Memory);
+    }
+
+    Ok(Response::Success(pb::Success {}))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use alloc::boxed::Box;
+    use util::bb02_async::block_on;
+
+    #[test]
+    pub fn test_blind_signing_enabled() {
+        // All good.
+
+        // Enable:
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::SetBlindSigningEnabledRequest { enabled: true }
+            )),
+            Ok(Response::Success(pb::Success {}))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Warning".into(),
+                    body: "Blind signed data\ncannot be verified\non the device.".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "All apps".into(),
+                    body: "Applies to Ethereum\ntyped messages and\nSolana transactions.".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Enable".into(),
+                    body: "Blind\nsigning".into(),
+                    longtouch: true,
+                },
+            ],
+        );
+
+        assert!(mock_hal.memory.is_blind_signing_enabled());
+        // Disable:
+        mock_hal.ui.screens.clear();
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::SetBlindSigningEnabledRequest { enabled: false }
+            )),
+            Ok(Response::Success(pb::Success {}))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![Screen::Confirm {
+                title: "Disable".into(),
+                body: "Blind\nsigning".into(),
+                longtouch: true,
+            }],
+        );
+        assert!(!mock_hal.memory.is_blind_signing_enabled());
+
+        // User aborted the warnings or the confirmation.
+        for abort_nth in 0..3 {
+            let mut mock_hal = TestingHal::new();
+            mock_hal.ui.abort_nth(abort_nth);
+            assert_eq!(
+                block_on(process(
+                    &mut mock_hal,
+                    &pb::SetBlindSigningEnabledRequest { enabled: true }
+                )),
+                Err(Error::UserAbort)
+            );
+            assert!(!mock_hal.memory.is_blind_signing_enabled());
+        }
+    }
+}
@@ -6,12 +6,17 @@ use crate::pb;
 
 use pb::response::Response;
 
+use crate::hal::memory::SeedType;
 use crate::hal::{Memory, Ui};
 
 pub async fn process(
     hal: &mut impl crate::hal::Hal,
     &pb::SetMnemonicPassphraseEnabledRequest { enabled }: &pb::SetMnemonicPassphraseEnabledRequest,
 ) -> Result<Response, Error> {
+    // The passphrase of a SLIP-39 seed is entered when restoring from the shares.
+    if enabled && hal.memory().get_seed_type() != SeedType::Bip39 {
+        return Err(Error::InvalidState);
+    }
     let params = ConfirmParams {
         title: if enabled { "Enable" } else { "Disable" },
         body: "Optional\npassphrase",
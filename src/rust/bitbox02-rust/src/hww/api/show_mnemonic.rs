@@ -8,6 +8,7 @@ use crate::pb;
 
 use pb::response::Response;
 
+use crate::hal::memory::SeedType;
 use crate::hal::{Memory, Ui};
 use crate::workflow::unlock;
 
@@ -15,7 +16,12 @@ use crate::workflow::unlock;
 /// 12/18/24 BIP39 English words. Afterwards, for each word, the user
 /// is asked to pick the right word among 5 words, to check if they
 /// wrote it down correctly.
+///
+/// A SLIP-39 seed has no BIP-39 mnemonic, see ShowSlip39Shares.
 pub async fn process(hal: &mut impl crate::hal::Hal) -> Result<Response, Error> {
+    if hal.memory().get_seed_type() != SeedType::Bip39 {
+        return Err(Error::InvalidState);
+    }
     let mnemonic_sentence = {
         let seed = if hal.memory().is_initialized() {
             unlock::unlock_keystore(hal, "Unlock device", CanCancel::Yes).await?
@@ -48,7 +54,13 @@ pub async fn process(hal: &mut impl crate::hal::Hal) -> Result<Response, Error>
 
     {
         let crate::hal::HalSubsystems { ui, random, .. } = hal.as_mut();
-        crate::workflow::mnemonic::show_and_confirm_mnemonic(ui, random, &words).await?;
+        crate::workflow::mnemonic::show_and_confirm_mnemonic(
+            ui,
+            random,
+            bip39::Language::English.word_list(),
+            &words,
+        )
+        .await?;
     }
 
     hal.memory().set_initialized().or(Err(Error::Memory))?;
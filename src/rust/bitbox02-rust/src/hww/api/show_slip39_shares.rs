@@ -0,0 +1,330 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::hal::ui::{CanCancel, ConfirmParams};
+use alloc::vec::Vec;
+
+use super::Error;
+use crate::pb;
+use crate::slip39;
+
+use pb::response::Response;
+
+use crate::hal::memory::SeedType;
+use crate::hal::{Memory, Ui};
+use crate::workflow::{password, unlock};
+
+/// Handle the ShowSlip39Shares API call. This splits the seed into SLIP-39 shares according to the
+/// requested groups and thresholds, optionally protected by a passphrase, and shows each share
+/// like the recovery words in ShowMnemonic.
+///
+/// The shares encode the BIP-32 seed. A newly created seed is turned into a SLIP-39 seed once its
+/// shares are shown, see `keystore::set_slip39_seed()`. The BIP-32 seed of an initialized BIP-39
+/// wallet is derived from the mnemonic and can't be backed up this way.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::ShowSlip39SharesRequest,
+) -> Result<Response, Error> {
+    let group_threshold: u8 = request
+        .group_threshold
+        .try_into()
+        .or(Err(Error::InvalidInput))?;
+    let groups: Vec<slip39::GroupParams> = request
+        .groups
+        .iter()
+        .map(|group| {
+            Ok(slip39::GroupParams {
+                member_threshold: group
+                    .member_threshold
+                    .try_into()
+                    .or(Err(Error::InvalidInput))?,
+                member_count: group.member_count.try_into().or(Err(Error::InvalidInput))?,
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+    slip39::check_params(group_threshold, &groups).or(Err(Error::InvalidInput))?;
+
+    let seed_type = hal.memory().get_seed_type();
+    if seed_type == SeedType::Bip39 && hal.memory().is_initialized() {
+        return Err(Error::InvalidState);
+    }
+
+    let seed = if hal.memory().is_initialized() {
+        unlock::unlock_keystore(hal, "Unlock device", CanCancel::Yes).await?
+    } else {
+        crate::keystore::copy_seed(hal)?
+    };
+
+    let body = if groups.len() == 1 {
+        format!(
+            "Create {} shares,\n{} needed to restore",
+            groups[0].member_count, groups[0].member_threshold
+        )
+    } else {
+        format!(
+            "Create {} groups,\n{} needed to restore",
+            groups.len(),
+            group_threshold
+        )
+    };
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "SLIP-39",
+            body: &body,
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+
+    let passphrase = loop {
+        let passphrase = password::enter(
+            hal,
+            "Optional passphrase",
+            password::PasswordType::Bip39Passphrase,
+            CanCancel::Yes,
+        )
+        .await?;
+        if let Ok(()) = unlock::confirm_mnemonic_passphrase(hal, &passphrase).await {
+            break passphrase;
+        }
+        hal.ui().status("Please try again", false).await;
+    };
+
+    hal.ui()
+        .confirm(&ConfirmParams {
+            title: "Warning",
+            body: "DO NOT share your\nrecovery shares with\nanyone!",
+            accept_is_nextarrow: true,
+            ..Default::default()
+        })
+        .await?;
+
+    let shares = slip39::split(hal.random(), &seed, &passphrase, group_threshold, &groups)
+        .or(Err(Error::Generic))?;
+
+    for (group_index, (group, group_shares)) in groups.iter().zip(shares.iter()).enumerate() {
+        if groups.len() > 1 {
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title: &format!("Group {}", group_index + 1),
+                    body: &format!(
+                        "{} of {} shares\nneeded",
+                        group.member_threshold, group.member_count
+                    ),
+                    accept_is_nextarrow: true,
+                    ..Default::default()
+                })
+                .await?;
+        }
+        for (share_index, share) in group_shares.iter().enumerate() {
+            hal.ui()
+                .confirm(&ConfirmParams {
+                    title: &format!("Share {}/{}", share_index + 1, group_shares.len()),
+                    body: "Please write down\nthe following words",
+                    accept_is_nextarrow: true,
+                    ..Default::default()
+                })
+                .await?;
+
+            let words: Vec<&str> = share.split(' ').collect();
+            let crate::hal::HalSubsystems { ui, random, .. } = hal.as_mut();
+            crate::workflow::mnemonic::show_and_confirm_mnemonic(
+                ui,
+                random,
+                &slip39::WORDLIST,
+                &words,
+            )
+            .await?;
+        }
+    }
+
+    if seed_type == SeedType::Bip39 {
+        crate::keystore::set_slip39_seed(hal).or(Err(Error::Memory))?;
+        // Unlock again to use the seed as the BIP-32 seed from now on.
+        unlock::unlock_bip39(hal, &seed).await;
+    }
+    hal.memory().set_initialized().or(Err(Error::Memory))?;
+
+    hal.ui().status("Backup created", true).await;
+    Ok(Response::Success(pb::Success {}))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::boxed::Box;
+    use alloc::string::String;
+
+    use crate::hal::testing::ui::Screen;
+    use crate::hal::testing::{TestingHal, TestingRandom, TestingUi};
+    use bitbox02::testing::mock_memory;
+    use hex_lit::hex;
+    use util::bb02_async::block_on;
+
+    const SEED: [u8; 16] = hex!("b43ceb7e57a0ea8766221624d01b0864");
+
+    /// Mocks the randomness consumed by `slip39::split()` for one group with a member threshold
+    /// of 2 and a 16 byte seed: the identifier and the random part of the digest share.
+    fn mock_split_random(random: &mut TestingRandom) {
+        random.mock_next([0x12; 32]);
+        random.mock_next([0xab; 32]);
+    }
+
+    #[test]
+    fn test_process() {
+        mock_memory();
+        let mut mock_hal = TestingHal::new();
+        crate::keystore::encrypt_and_store_seed(&mut mock_hal, &SEED, "password").unwrap();
+        assert!(!mock_hal.memory.is_initialized());
+
+        let groups = [slip39::GroupParams {
+            member_threshold: 2,
+            member_count: 3,
+        }];
+        let expected_shares = {
+            let mut random = TestingRandom::new();
+            mock_split_random(&mut random);
+            slip39::split(&mut random, &SEED, "passphrase", 1, &groups).unwrap()
+        };
+
+        mock_split_random(&mut mock_hal.random);
+        // For each word of each share, put the correct word at position 2 and fill the remaining
+        // quiz choices with other words.
+        for word in expected_shares[0].iter().flat_map(|share| share.split(' ')) {
+            TestingUi::mock_next_u16(&mut mock_hal.random, 2);
+            for idx in (0u16..)
+                .filter(|&idx| slip39::WORDLIST[idx as usize] != word)
+                .take(4)
+            {
+                TestingUi::mock_next_u16(&mut mock_hal.random, idx);
+            }
+            mock_hal.ui.push_quiz_choice(2);
+        }
+        mock_hal.ui.set_enter_string(Box::new(|params| {
+            assert_eq!(params.title, "Optional passphrase");
+            Ok("passphrase".into())
+        }));
+
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::ShowSlip39SharesRequest {
+                    group_threshold: 1,
+                    groups: vec![pb::show_slip39_shares_request::Group {
+                        member_threshold: 2,
+                        member_count: 3,
+                    }],
+                }
+            )),
+            Ok(Response::Success(pb::Success {}))
+        );
+        assert!(mock_hal.memory.is_initialized());
+        assert_eq!(mock_hal.memory.get_seed_type(), SeedType::Slip39);
+        assert_eq!(
+            crate::keystore::copy_bip39_seed(&mut mock_hal)
+                .unwrap()
+                .as_slice(),
+            &SEED
+        );
+
+        assert_eq!(
+            mock_hal.ui.screens[0],
+            Screen::Confirm {
+                title: "SLIP-39".into(),
+                body: "Create 3 shares,\n2 needed to restore".into(),
+                longtouch: false,
+            }
+        );
+        let shown_shares: Vec<String> = mock_hal
+            .ui
+            .screens
+            .iter()
+            .filter_map(|screen| match screen {
+                Screen::ShowMnemonic { words } => Some(words.join(" ")),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            shown_shares,
+            expected_shares[0]
+                .iter()
+                .map(|share| share.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            mock_hal.ui.screens.last(),
+            Some(&Screen::Status {
+                title: "Backup created".into(),
+                success: true,
+            })
+        );
+
+        // Any two shares recover the seed with the passphrase.
+        let mut share_set = slip39::ShareSet::new();
+        for share in &shown_shares[1..] {
+            share_set
+                .add(slip39::Share::from_mnemonic(share).unwrap())
+                .unwrap();
+        }
+        assert_eq!(share_set.combine("passphrase").unwrap().as_slice(), &SEED);
+    }
+
+    #[test]
+    fn test_process_invalid_input() {
+        mock_memory();
+        let mut mock_hal = TestingHal::new();
+        crate::keystore::encrypt_and_store_seed(&mut mock_hal, &SEED, "password").unwrap();
+
+        // Group threshold exceeds the number of groups.
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::ShowSlip39SharesRequest {
+                    group_threshold: 2,
+                    groups: vec![pb::show_slip39_shares_request::Group {
+                        member_threshold: 2,
+                        member_count: 3,
+                    }],
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+        // Member count does not fit.
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::ShowSlip39SharesRequest {
+                    group_threshold: 1,
+                    groups: vec![pb::show_slip39_shares_request::Group {
+                        member_threshold: 2,
+                        member_count: 258,
+                    }],
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+    }
+    #[test]
+    fn test_process_initialized_bip39() {
+        mock_memory();
+        let mut mock_hal = TestingHal::new();
+        crate::keystore::encrypt_and_store_seed(&mut mock_hal, &SEED, "password").unwrap();
+        mock_hal.memory.set_initialized().unwrap();
+
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::ShowSlip39SharesRequest {
+                    group_threshold: 1,
+                    groups: vec![pb::show_slip39_shares_request::Group {
+                        member_threshold: 2,
+                        member_count: 3,
+                    }],
+                }
+            )),
+            Err(Error::InvalidState)
+        );
+        assert!(mock_hal.ui.screens.is_empty());
+    }
+}
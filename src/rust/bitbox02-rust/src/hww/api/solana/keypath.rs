@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use util::bip32::HARDENED;
+
+const PURPOSE: u32 = 44 + HARDENED;
+const COIN: u32 = 501 + HARDENED;
+const ACCOUNT_MAX: u32 = 99; // 100 accounts
+const CHANGE: u32 = HARDENED;
+
+/// Returns true if the keypath is m/44'/501'/account'/0', where account is between 0' and 99'.
+/// This is the keypath used by most Solana wallets, e.g. Phantom and Solflare.
+pub fn is_valid_keypath(keypath: &[u32]) -> bool {
+    matches!(
+        keypath,
+        &[PURPOSE, COIN, account, CHANGE] if (HARDENED..=HARDENED + ACCOUNT_MAX).contains(&account)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_keypath() {
+        assert!(is_valid_keypath(&[
+            44 + HARDENED,
+            501 + HARDENED,
+            HARDENED,
+            HARDENED
+        ]));
+        assert!(is_valid_keypath(&[
+            44 + HARDENED,
+            501 + HARDENED,
+            99 + HARDENED,
+            HARDENED
+        ]));
+
+        // Account too high.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            501 + HARDENED,
+            100 + HARDENED,
+            HARDENED
+        ]));
+        // Unhardened account.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            501 + HARDENED,
+            0,
+            HARDENED
+        ]));
+        // Unhardened change.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            501 + HARDENED,
+            HARDENED,
+            0
+        ]));
+        // Wrong coin.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            60 + HARDENED,
+            HARDENED,
+            HARDENED
+        ]));
+        // Too short.
+        assert!(!is_valid_keypath(&[
+            44 + HARDENED,
+            501 + HARDENED,
+            HARDENED
+        ]));
+    }
+}
@@ -0,0 +1,273 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parsing of Solana transaction messages, legacy and versioned (v0).
+//!
+//! See https://solana.com/docs/core/transactions#message and
+//! https://docs.anza.xyz/proposals/versioned-transactions
+
+use alloc::vec::Vec;
+
+pub type Pubkey = [u8; 32];
+
+pub struct Instruction<'a> {
+    /// Index into `Message::account_keys`.
+    pub program_id_index: u8,
+    /// Indices into the accounts of the message, see `Message::account()`.
+    pub accounts: &'a [u8],
+    pub data: &'a [u8],
+}
+
+pub struct Message<'a> {
+    pub num_required_signatures: u8,
+    /// Static account keys. The first `num_required_signatures` keys are the signers, the first
+    /// one paying the fee.
+    pub account_keys: Vec<&'a Pubkey>,
+    pub instructions: Vec<Instruction<'a>>,
+}
+
+impl<'a> Message<'a> {
+    /// Returns the account at the index as used in instructions. Returns None for accounts loaded
+    /// from address lookup tables (v0 messages only), which come after the static account keys and
+    /// can't be resolved on the device.
+    pub fn account(&self, index: u8) -> Option<&'a Pubkey> {
+        self.account_keys.get(index as usize).copied()
+    }
+
+    pub fn program_id(&self, instruction: &Instruction) -> &'a Pubkey {
+        // Validated when parsing.
+        self.account_keys[instruction.program_id_index as usize]
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ()> {
+        if self.buf.len() < len {
+            return Err(());
+        }
+        let (result, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(result)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ()> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_pubkey(&mut self) -> Result<&'a Pubkey, ()> {
+        Ok(self.read_bytes(32)?.try_into().unwrap())
+    }
+
+    /// Reads a "compact-u16", a little-endian base-128 varint of at most three bytes.
+    /// See https://solana.com/docs/core/transactions#compact-array-format
+    fn read_compact_u16(&mut self) -> Result<u16, ()> {
+        let mut result: u32 = 0;
+        for i in 0..3 {
+            let byte = self.read_u8()?;
+            // The third byte only has two significant bits.
+            if i == 2 && byte > 0b11 {
+                return Err(());
+            }
+            result |= ((byte & 0x7f) as u32) << (7 * i);
+            if byte & 0x80 == 0 {
+                // Reject non-canonical encodings with trailing zero bytes.
+                if i > 0 && byte == 0 {
+                    return Err(());
+                }
+                return Ok(result as u16);
+            }
+        }
+        Err(())
+    }
+
+    /// Reads a compact-u16 length prefixed byte array.
+    fn read_compact_bytes(&mut self) -> Result<&'a [u8], ()> {
+        let len = self.read_compact_u16()?;
+        self.read_bytes(len as usize)
+    }
+}
+
+/// Parses a serialized transaction message. Only the parts needed for signing and display are
+/// kept.
+pub fn parse(message: &[u8]) -> Result<Message<'_>, ()> {
+    let mut reader = Reader { buf: message };
+
+    let first_byte = reader.read_u8()?;
+    let (versioned, num_required_signatures) = if first_byte & 0x80 != 0 {
+        // Only version 0 exists so far.
+        if first_byte & 0x7f != 0 {
+            return Err(());
+        }
+        (true, reader.read_u8()?)
+    } else {
+        (false, first_byte)
+    };
+    let num_readonly_signed = reader.read_u8()?;
+    let num_readonly_unsigned = reader.read_u8()?;
+
+    let num_account_keys = reader.read_compact_u16()?;
+    let account_keys = (0..num_account_keys)
+        .map(|_| reader.read_pubkey())
+        .collect::<Result<Vec<_>, ()>>()?;
+    if num_required_signatures == 0
+        || num_required_signatures as usize > account_keys.len()
+        // The fee payer must be writable.
+        || num_readonly_signed >= num_required_signatures
+        || num_readonly_unsigned as usize > account_keys.len() - num_required_signatures as usize
+    {
+        return Err(());
+    }
+
+    // Recent blockhash.
+    reader.read_pubkey()?;
+
+    let num_instructions = reader.read_compact_u16()?;
+    let mut instructions = Vec::with_capacity(num_instructions as usize);
+    for _ in 0..num_instructions {
+        let program_id_index = reader.read_u8()?;
+        // Programs can't be loaded from lookup tables.
+        if program_id_index as usize >= account_keys.len() {
+            return Err(());
+        }
+        instructions.push(Instruction {
+            program_id_index,
+            accounts: reader.read_compact_bytes()?,
+            data: reader.read_compact_bytes()?,
+        });
+    }
+
+    let mut num_lookup_accounts: usize = 0;
+    if versioned {
+        let num_lookups = reader.read_compact_u16()?;
+        for _ in 0..num_lookups {
+            // Address of the lookup table.
+            reader.read_pubkey()?;
+            let writable_indexes = reader.read_compact_bytes()?;
+            let readonly_indexes = reader.read_compact_bytes()?;
+            num_lookup_accounts += writable_indexes.len() + readonly_indexes.len();
+        }
+    }
+
+    if !reader.buf.is_empty() {
+        return Err(());
+    }
+
+    // Account indices are u8, so there can be at most 256 accounts.
+    let num_accounts = account_keys.len() + num_lookup_accounts;
+    if num_accounts > 256
+        || instructions
+            .iter()
+            .flat_map(|instruction| instruction.accounts.iter())
+            .any(|&index| index as usize >= num_accounts)
+    {
+        return Err(());
+    }
+
+    Ok(Message {
+        num_required_signatures,
+        account_keys,
+        instructions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_lit::hex;
+
+    #[test]
+    fn test_read_compact_u16() {
+        let read = |buf: &[u8]| {
+            let mut reader = Reader { buf };
+            let result = reader.read_compact_u16();
+            if !reader.buf.is_empty() {
+                panic!("not all bytes consumed");
+            }
+            result
+        };
+        assert_eq!(read(&[0x00]), Ok(0));
+        assert_eq!(read(&[0x7f]), Ok(0x7f));
+        assert_eq!(read(&[0x80, 0x01]), Ok(0x80));
+        assert_eq!(read(&[0xff, 0x7f]), Ok(0x3fff));
+        assert_eq!(read(&[0x80, 0x80, 0x01]), Ok(0x4000));
+        assert_eq!(read(&[0xff, 0xff, 0x03]), Ok(0xffff));
+        // Overflow.
+        assert_eq!(read(&[0x80, 0x80, 0x04]), Err(()));
+        // Non-canonical.
+        assert_eq!(read(&[0x80, 0x00]), Err(()));
+        // Truncated.
+        assert_eq!(read(&[0x80]), Err(()));
+    }
+
+    #[test]
+    fn test_parse_legacy() {
+        // System transfer of 1 SOL: one signer, the recipient and the system program.
+        let message = hex!(
+            "010001030f36299f0556228d22d1dcbd1ce5a223763f0d7b57c07d62dc0b458e19122be812121212121212121212121212121212121212121212121212121212121212120000000000000000000000000000000000000000000000000000000000000000010101010101010101010101010101010101010101010101010101010101010101020200010c0200000000ca9a3b00000000"
+        );
+        let parsed = parse(&message).unwrap();
+        assert_eq!(parsed.num_required_signatures, 1);
+        assert_eq!(parsed.account_keys.len(), 3);
+        assert_eq!(parsed.instructions.len(), 1);
+        let instruction = &parsed.instructions[0];
+        assert_eq!(parsed.program_id(instruction), &[0u8; 32]);
+        assert_eq!(instruction.accounts, &[0, 1]);
+        assert_eq!(instruction.data, &hex!("0200000000ca9a3b00000000")[..]);
+        assert_eq!(parsed.account(1), Some(parsed.account_keys[1]));
+
+        // Trailing bytes.
+        assert!(parse(&[&message[..], &[0][..]].concat()).is_err());
+        // Truncated.
+        assert!(parse(&message[..message.len() - 1]).is_err());
+        // Program index out of range.
+        let mut invalid = message;
+        invalid[message.len() - 17] = 3;
+        assert!(parse(&invalid).is_err());
+        // Account index out of range.
+        let mut invalid = message;
+        invalid[message.len() - 14] = 3;
+        assert!(parse(&invalid).is_err());
+        // No signers.
+        let mut invalid = message;
+        invalid[0] = 0;
+        assert!(parse(&invalid).is_err());
+        // Read-only fee payer.
+        let mut invalid = message;
+        invalid[1] = 1;
+        assert!(parse(&invalid).is_err());
+        // Unsupported version.
+        assert!(parse(&[&[0x81][..], &message[..]].concat()).is_err());
+    }
+
+    #[test]
+    fn test_parse_v0() {
+        // Same as the legacy transfer above, but as a v0 message with an address lookup table
+        // providing one writable and two read-only accounts.
+        let legacy = hex!(
+            "010001030f36299f0556228d22d1dcbd1ce5a223763f0d7b57c07d62dc0b458e19122be812121212121212121212121212121212121212121212121212121212121212120000000000000000000000000000000000000000000000000000000000000000010101010101010101010101010101010101010101010101010101010101010101020200010c0200000000ca9a3b00000000"
+        );
+        let mut message = vec![0x80];
+        message.extend_from_slice(&legacy);
+        message.push(1);
+        message.extend_from_slice(&[0x33; 32]);
+        message.extend_from_slice(&[1, 5, 2, 6, 7]);
+        let parsed = parse(&message).unwrap();
+        assert_eq!(parsed.account_keys.len(), 3);
+        // Loaded accounts can't be resolved.
+        assert_eq!(parsed.account(3), None);
+
+        // The recipient is the last of the loaded accounts.
+        let recipient_index = message.len() - 14 - 38;
+        message[recipient_index] = 5;
+        assert_eq!(parse(&message).unwrap().account(5), None);
+        // Account index out of range.
+        message[recipient_index] = 6;
+        assert!(parse(&message).is_err());
+
+        // Missing lookup tables.
+        assert!(parse(&[&[0x80][..], &legacy[..]].concat()).is_err());
+    }
+}
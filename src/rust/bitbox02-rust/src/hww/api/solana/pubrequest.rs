@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::string::String;
+
+use pb::solana_response::Response;
+
+use crate::hal::Ui;
+use crate::keystore::ed25519::slip10;
+
+/// Returns the base58 encoded address of the account at the keypath, which is its public key.
+pub fn get_address(hal: &mut impl crate::hal::Hal, keypath: &[u32]) -> Result<String, Error> {
+    if !super::keypath::is_valid_keypath(keypath) {
+        return Err(Error::InvalidInput);
+    }
+    Ok(bitcoin::base58::encode(&slip10::get_pubkey(hal, keypath)?))
+}
+
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::SolanaPubRequest,
+) -> Result<Response, Error> {
+    let address = get_address(hal, &request.keypath)?;
+    if request.display {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: super::NAME,
+                body: &address,
+                scrollable: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    Ok(Response::Pub(pb::PubResponse { r#pub: address }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::{mock_unlocked, mock_unlocked_using_mnemonic};
+    use util::bb02_async::block_on;
+    use util::bip32::HARDENED;
+
+    #[test]
+    fn test_process() {
+        mock_unlocked_using_mnemonic(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        );
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::SolanaPubRequest {
+                    keypath: vec![44 + HARDENED, 501 + HARDENED, HARDENED, HARDENED],
+                    display: false,
+                }
+            )),
+            Ok(Response::Pub(pb::PubResponse {
+                r#pub: "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk".into()
+            }))
+        );
+
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            block_on(process(
+                &mut mock_hal,
+                &pb::SolanaPubRequest {
+                    keypath: vec![44 + HARDENED, 501 + HARDENED, 1 + HARDENED, HARDENED],
+                    display: true,
+                }
+            )),
+            Ok(Response::Pub(pb::PubResponse {
+                r#pub: "7FZrYthLQVNgJm5rssxAtRP2adrmAeSe4uDm77G6gen".into()
+            }))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![Screen::Confirm {
+                title: "Solana".into(),
+                body: "7FZrYthLQVNgJm5rssxAtRP2adrmAeSe4uDm77G6gen".into(),
+                longtouch: false,
+            }]
+        );
+
+        // Invalid keypath.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::SolanaPubRequest {
+                    keypath: vec![44 + HARDENED, 501 + HARDENED, HARDENED, 0],
+                    display: false,
+                }
+            )),
+            Err(Error::InvalidInput)
+        );
+
+        // Locked.
+        crate::keystore::lock();
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::SolanaPubRequest {
+                    keypath: vec![44 + HARDENED, 501 + HARDENED, HARDENED, HARDENED],
+                    display: false,
+                }
+            )),
+            Err(Error::Generic)
+        );
+    }
+}
@@ -0,0 +1,663 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::Error;
+use super::pb;
+use crate::hal::ui::ConfirmParams;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use pb::solana_response::Response;
+
+use super::message::{self, Instruction, Message, Pubkey};
+use crate::hal::{Memory, Ui};
+use crate::keystore::ed25519::slip10;
+use crate::workflow::transaction;
+
+use hex_lit::hex;
+
+/// Maximum size of a serialized transaction, including the signatures.
+/// See https://solana.com/docs/core/transactions#transaction-size
+const MAX_MESSAGE_SIZE: usize = 1232;
+
+const SYSTEM_PROGRAM_ID: Pubkey = [0; 32];
+/// TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA
+const TOKEN_PROGRAM_ID: Pubkey =
+    hex!("06ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a9");
+/// TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    hex!("06ddf6e1ee758fde18425dbce46ccddab61afc4d83b90d27febdf928d8a18bfc");
+/// ComputeBudget111111111111111111111111111111
+const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    hex!("0306466fe5211732ffecadba72c39be7bc8ce5bbc5f7126b2c439b3a40000000");
+
+const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+/// Compute unit limit per instruction if the transaction does not set one.
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+/// Compute budget instructions. They don't move funds and are not shown, but the compute unit
+/// price and limit determine the priority fee.
+#[derive(Default)]
+struct ComputeBudget {
+    heap_frame: Option<u32>,
+    unit_limit: Option<u32>,
+    /// In micro-lamports per compute unit.
+    unit_price: Option<u64>,
+    loaded_accounts_data_size_limit: Option<u32>,
+}
+
+impl ComputeBudget {
+    /// Adds a compute budget instruction. Each kind can appear at most once per transaction.
+    fn add(&mut self, data: &[u8]) -> Result<(), Error> {
+        fn set<T>(field: &mut Option<T>, value: T) -> Result<(), Error> {
+            if field.replace(value).is_some() {
+                return Err(Error::InvalidInput);
+            }
+            Ok(())
+        }
+        match data {
+            [1, rest @ ..] => set(&mut self.heap_frame, parse_u32(rest)?),
+            [2, rest @ ..] => set(&mut self.unit_limit, parse_u32(rest)?),
+            [3, rest @ ..] => set(&mut self.unit_price, parse_u64(rest)?),
+            [4, rest @ ..] => set(&mut self.loaded_accounts_data_size_limit, parse_u32(rest)?),
+            _ => Err(Error::InvalidInput),
+        }
+    }
+
+    /// Priority fee in lamports, rounded up.
+    /// See https://solana.com/docs/core/fees#prioritization-fees
+    fn priority_fee(&self, num_instructions: usize) -> Result<u64, Error> {
+        let unit_limit = match self.unit_limit {
+            Some(limit) => limit as u64,
+            None => DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT.saturating_mul(num_instructions as u64),
+        }
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+        let micro_lamports = self.unit_price.unwrap_or(0) as u128 * unit_limit as u128;
+        u64::try_from(micro_lamports.div_ceil(1_000_000)).or(Err(Error::InvalidInput))
+    }
+}
+
+enum Action<'a> {
+    SolTransfer {
+        from: &'a Pubkey,
+        to: &'a Pubkey,
+        lamports: u64,
+    },
+    TokenTransfer {
+        /// Owner of the source token account.
+        owner: &'a Pubkey,
+        /// Destination token account.
+        destination: &'a Pubkey,
+        amount: u64,
+        /// Mint and decimals, only known for `TransferChecked`.
+        mint: Option<(&'a Pubkey, u8)>,
+    },
+    Unknown {
+        program_id: &'a Pubkey,
+    },
+}
+
+fn parse_u32(data: &[u8]) -> Result<u32, Error> {
+    Ok(u32::from_le_bytes(
+        data.try_into().or(Err(Error::InvalidInput))?,
+    ))
+}
+
+fn parse_u64(data: &[u8]) -> Result<u64, Error> {
+    Ok(u64::from_le_bytes(
+        data.try_into().or(Err(Error::InvalidInput))?,
+    ))
+}
+
+/// Returns the account at position `position` of the instruction. Accounts that are shown to the
+/// user must be static account keys, as accounts loaded from lookup tables can't be resolved.
+fn instruction_account<'a>(
+    message: &Message<'a>,
+    instruction: &Instruction,
+    position: usize,
+) -> Result<&'a Pubkey, Error> {
+    let index = *instruction
+        .accounts
+        .get(position)
+        .ok_or(Error::InvalidInput)?;
+    message.account(index).ok_or(Error::InvalidInput)
+}
+
+/// Decodes a non-compute-budget instruction. Returns `Action::Unknown` for instructions that can't
+/// be shown.
+fn decode_instruction<'a>(
+    message: &Message<'a>,
+    instruction: &Instruction,
+) -> Result<Action<'a>, Error> {
+    let program_id = message.program_id(instruction);
+    let account = |position| instruction_account(message, instruction, position);
+    if program_id == &SYSTEM_PROGRAM_ID {
+        // The system program uses a u32 discriminant, 2 is `Transfer`.
+        if let [2, 0, 0, 0, rest @ ..] = instruction.data {
+            return Ok(Action::SolTransfer {
+                from: account(0)?,
+                to: account(1)?,
+                lamports: parse_u64(rest)?,
+            });
+        }
+    } else if program_id == &TOKEN_PROGRAM_ID || program_id == &TOKEN_2022_PROGRAM_ID {
+        match instruction.data {
+            // Transfer; accounts: source, destination, owner.
+            [3, rest @ ..] => {
+                return Ok(Action::TokenTransfer {
+                    owner: account(2)?,
+                    destination: account(1)?,
+                    amount: parse_u64(rest)?,
+                    mint: None,
+                });
+            }
+            // TransferChecked; accounts: source, mint, destination, owner.
+            [12, rest @ ..] => {
+                let (&decimals, amount) = rest.split_last().ok_or(Error::InvalidInput)?;
+                return Ok(Action::TokenTransfer {
+                    owner: account(3)?,
+                    destination: account(2)?,
+                    amount: parse_u64(amount)?,
+                    mint: Some((account(1)?, decimals)),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(Action::Unknown { program_id })
+}
+
+fn format_lamports(lamports: u64) -> String {
+    format!("{} SOL", util::decimal::format(lamports, 9))
+}
+
+/// Shows the sender if it is not the account being signed with, which happens e.g. if the account
+/// is only the fee payer or a co-signer.
+async fn verify_from(
+    hal: &mut impl crate::hal::Hal,
+    from: &Pubkey,
+    our_pubkey: &Pubkey,
+) -> Result<(), Error> {
+    if from != our_pubkey {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "From",
+                body: &bitcoin::base58::encode(from),
+                scrollable: true,
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+async fn _process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::SolanaSignTransactionRequest,
+) -> Result<Response, Error> {
+    if !super::keypath::is_valid_keypath(&request.keypath) {
+        return Err(Error::InvalidInput);
+    }
+    if request.message.len() > MAX_MESSAGE_SIZE {
+        return Err(Error::InvalidInput);
+    }
+    let message = message::parse(&request.message).or(Err(Error::InvalidInput))?;
+    let our_pubkey = slip10::get_pubkey(hal, &request.keypath)?;
+
+    let signers = &message.account_keys[..message.num_required_signatures as usize];
+    if !signers.contains(&&our_pubkey) {
+        return Err(Error::InvalidInput);
+    }
+
+    // Decode all instructions before showing anything.
+    let mut compute_budget = ComputeBudget::default();
+    let mut actions: Vec<Action> = Vec::with_capacity(message.instructions.len());
+    for instruction in message.instructions.iter() {
+        if message.program_id(instruction) == &COMPUTE_BUDGET_PROGRAM_ID {
+            compute_budget.add(instruction.data)?;
+        } else {
+            actions.push(decode_instruction(&message, instruction)?);
+        }
+    }
+    if actions
+        .iter()
+        .any(|action| matches!(action, Action::Unknown { .. }))
+        && !hal.memory().is_blind_signing_enabled()
+    {
+        return Err(Error::Disabled);
+    }
+
+    // The fee is paid by the first signer.
+    let fee: u64 = if message.account_keys[0] == &our_pubkey {
+        (LAMPORTS_PER_SIGNATURE * message.num_required_signatures as u64)
+            .checked_add(compute_budget.priority_fee(actions.len())?)
+            .ok_or(Error::InvalidInput)?
+    } else {
+        0
+    };
+
+    // SOL sent from the account being signed with.
+    let mut total: u64 = 0;
+    for action in actions.iter() {
+        match action {
+            Action::SolTransfer { from, to, lamports } => {
+                verify_from(hal, from, &our_pubkey).await?;
+                hal.ui()
+                    .verify_recipient(&bitcoin::base58::encode(*to), &format_lamports(*lamports))
+                    .await?;
+                if *from == &our_pubkey {
+                    total = total.checked_add(*lamports).ok_or(Error::InvalidInput)?;
+                }
+            }
+            Action::TokenTransfer {
+                owner,
+                destination,
+                amount,
+                mint,
+            } => {
+                verify_from(hal, owner, &our_pubkey).await?;
+                let amount = match mint {
+                    Some((mint, decimals)) => {
+                        hal.ui()
+                            .confirm(&ConfirmParams {
+                                title: "Token",
+                                body: &bitcoin::base58::encode(*mint),
+                                scrollable: true,
+                                accept_is_nextarrow: true,
+                                ..Default::default()
+                            })
+                            .await?;
+                        format!(
+                            "{} tokens",
+                            util::decimal::format(*amount, *decimals as usize)
+                        )
+                    }
+                    None => format!("{} base units\nof unknown token", amount),
+                };
+                hal.ui()
+                    .verify_recipient(&bitcoin::base58::encode(*destination), &amount)
+                    .await?;
+            }
+            Action::Unknown { program_id } => {
+                hal.ui()
+                    .confirm(&ConfirmParams {
+                        title: "Warning",
+                        body: "Unknown instruction!\nIts effects\ncannot be verified.",
+                        accept_is_nextarrow: true,
+                        ..Default::default()
+                    })
+                    .await?;
+                hal.ui()
+                    .confirm(&ConfirmParams {
+                        title: "Program",
+                        body: &bitcoin::base58::encode(*program_id),
+                        scrollable: true,
+                        accept_is_nextarrow: true,
+                        ..Default::default()
+                    })
+                    .await?;
+            }
+        }
+    }
+
+    if total == 0 {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: super::NAME,
+                body: &format!("Fee\n{}", format_lamports(fee)),
+                longtouch: true,
+                ..Default::default()
+            })
+            .await?;
+    } else {
+        let fee_percentage: f64 = 100. * (fee as f64) / (total as f64);
+        transaction::verify_total_fee_maybe_warn(
+            hal,
+            &format_lamports(total.checked_add(fee).ok_or(Error::InvalidInput)?),
+            &format_lamports(fee),
+            Some(fee_percentage),
+        )
+        .await?;
+    }
+
+    hal.ui().status("Transaction\nconfirmed", true).await;
+
+    let signature = slip10::sign(hal, &request.keypath, &request.message)?;
+    Ok(Response::SignTransaction(
+        pb::SolanaSignTransactionResponse {
+            signature: signature.to_vec(),
+        },
+    ))
+}
+
+/// Verify and sign a Solana transaction message.
+pub async fn process(
+    hal: &mut impl crate::hal::Hal,
+    request: &pb::SolanaSignTransactionRequest,
+) -> Result<Response, Error> {
+    let result = _process(hal, request).await;
+    if let Err(Error::UserAbort) = result {
+        hal.ui().status("Transaction\ncanceled", false).await;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hal::testing::TestingHal;
+    use crate::hal::testing::ui::Screen;
+    use crate::keystore::testing::mock_unlocked;
+    use util::bb02_async::block_on;
+    use util::bip32::HARDENED;
+
+    const KEYPATH: &[u32] = &[44 + HARDENED, 501 + HARDENED, HARDENED, HARDENED];
+    const RECIPIENT: &str = "2DYKaRPBeNM5WdW8rNsYEktjPrnd89Mm4Lzp3qonSzoj";
+
+    fn sign(mock_hal: &mut TestingHal, message: &[u8]) -> Result<Response, Error> {
+        block_on(process(
+            mock_hal,
+            &pb::SolanaSignTransactionRequest {
+                keypath: KEYPATH.to_vec(),
+                message: message.to_vec(),
+            },
+        ))
+    }
+
+    fn signature(signature: [u8; 64]) -> Result<Response, Error> {
+        Ok(Response::SignTransaction(
+            pb::SolanaSignTransactionResponse {
+                signature: signature.to_vec(),
+            },
+        ))
+    }
+
+    #[test]
+    fn test_sol_transfer() {
+        // Transfer of 1.5 SOL with SetComputeUnitLimit(100000) and SetComputeUnitPrice(1000000).
+        let message = hex!(
+            "010002040f36299f0556228d22d1dcbd1ce5a223763f0d7b57c07d62dc0b458e19122be8121212121212121212121212121212121212121212121212121212121212121200000000000000000000000000000000000000000000000000000000000000000306466fe5211732ffecadba72c39be7bc8ce5bbc5f7126b2c439b3a4000000001010101010101010101010101010101010101010101010101010101010101010303000502a08601000300090340420f0000000000020200010c02000000002f685900000000"
+        );
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            sign(&mut mock_hal, &message),
+            signature(hex!(
+                "8a47472cd498b6507b862c83b8a771376fcd56e8dde69de35ec30759c32dfa23fc56b146649dcee4c4ff92a13dae3ee255bdf499665de33305cb7e48717f2f04"
+            ))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Recipient {
+                    recipient: RECIPIENT.into(),
+                    amount: "1.5 SOL".into(),
+                },
+                Screen::TotalFee {
+                    total: "1.500105 SOL".into(),
+                    fee: "0.000105 SOL".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        // User aborts.
+        let mut mock_hal = TestingHal::new();
+        mock_hal.ui.abort_nth(0);
+        assert_eq!(sign(&mut mock_hal, &message), Err(Error::UserAbort));
+        assert_eq!(
+            mock_hal.ui.screens.last(),
+            Some(&Screen::Status {
+                title: "Transaction\ncanceled".into(),
+                success: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_token_transfer_checked() {
+        // TransferChecked of 1.5 tokens with 6 decimals.
+        let message = hex!(
+            "010002050f36299f0556228d22d1dcbd1ce5a223763f0d7b57c07d62dc0b458e19122be821212121212121212121212121212121212121212121212121212121212121212222222222222222222222222222222222222222222222222222222222222222232323232323232323232323232323232323232323232323232323232323232306ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a90101010101010101010101010101010101010101010101010101010101010101010404010302000a0c60e316000000000006"
+        );
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            sign(&mut mock_hal, &message),
+            signature(hex!(
+                "01c301e528450fa213f426369c302acb66cb342b251b1831b5714cf24a8fbc169055ac13d1b618b7237efb1be29d4211536e9634fa3278d9e8bf30ed7606c50f"
+            ))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Token".into(),
+                    body: "3NAM1YJMhSPvtAkmGTRABe1hYZN3aE2hZHKy3JZy9fHk".into(),
+                    longtouch: false,
+                },
+                Screen::Recipient {
+                    recipient: "3JF3sEqM796hk5WFqA6EtmEwJQ9quALszsfJyvXNQKy3".into(),
+                    amount: "1.5 tokens".into(),
+                },
+                Screen::Confirm {
+                    title: "Solana".into(),
+                    body: "Fee\n0.000005 SOL".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_v0() {
+        // Transfer of 1000 lamports, with an address lookup table providing an account that is
+        // passed to the instruction but not shown.
+        let message = hex!(
+            "80010001030f36299f0556228d22d1dcbd1ce5a223763f0d7b57c07d62dc0b458e19122be81212121212121212121212121212121212121212121212121212121212121212000000000000000000000000000000000000000000000000000000000000000001010101010101010101010101010101010101010101010101010101010101010102030001030c02000000e803000000000000013333333333333333333333333333333333333333333333333333333333333333000104"
+        );
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            sign(&mut mock_hal, &message),
+            signature(hex!(
+                "792425931ccb36a52c7775357e2cc8718d5a0748b6bbef30ade9482635406fa9439a54f1ce309d99075aa6ccc190a8b2a9c82f0a1a1e785b8bbd30ace05c5203"
+            ))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Recipient {
+                    recipient: RECIPIENT.into(),
+                    amount: "0.000001 SOL".into(),
+                },
+                Screen::TotalFee {
+                    total: "0.000006 SOL".into(),
+                    fee: "0.000005 SOL".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "High fee".into(),
+                    body: "The fee is 500.0%\nthe send amount.\nProceed?".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        // The recipient is loaded from the lookup table and can't be shown.
+        let mut invalid = message;
+        invalid[message.len() - 51] = 3;
+        assert_eq!(
+            sign(&mut TestingHal::new(), &invalid),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_cosigner() {
+        // Transfer of 1 SOL from our account, with the fee paid by another signer.
+        let message = hex!(
+            "0200010455555555555555555555555555555555555555555555555555555555555555550f36299f0556228d22d1dcbd1ce5a223763f0d7b57c07d62dc0b458e19122be812121212121212121212121212121212121212121212121212121212121212120000000000000000000000000000000000000000000000000000000000000000010101010101010101010101010101010101010101010101010101010101010101030201020c0200000000ca9a3b00000000"
+        );
+        mock_unlocked();
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(
+            sign(&mut mock_hal, &message),
+            signature(hex!(
+                "dc1255a12d1365f9fa60c7899371f4b8541bff993d984bebd1496a0c3df0699e66814a0b0b708625c8a4294569e7e1dba8d8a45a837ab925d44ea908b4970a00"
+            ))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Recipient {
+                    recipient: RECIPIENT.into(),
+                    amount: "1 SOL".into(),
+                },
+                Screen::TotalFee {
+                    total: "1 SOL".into(),
+                    fee: "0 SOL".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+
+        // Our account is not a signer.
+        let mut invalid = message;
+        invalid[0] = 1;
+        invalid[2] = 2;
+        assert_eq!(
+            sign(&mut TestingHal::new(), &invalid),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_unknown_instruction() {
+        let message = hex!(
+            "010001020f36299f0556228d22d1dcbd1ce5a223763f0d7b57c07d62dc0b458e19122be84444444444444444444444444444444444444444444444444444444444444444010101010101010101010101010101010101010101010101010101010101010101010100020102"
+        );
+        mock_unlocked();
+
+        // Blind signing disabled.
+        let mut mock_hal = TestingHal::new();
+        assert_eq!(sign(&mut mock_hal, &message), Err(Error::Disabled));
+        assert!(mock_hal.ui.screens.is_empty());
+
+        let mut mock_hal = TestingHal::new();
+        mock_hal.memory.set_blind_signing_enabled(true).unwrap();
+        assert_eq!(
+            sign(&mut mock_hal, &message),
+            signature(hex!(
+                "b545f2d0d8196aad8aa0ee16c7671d41739ef5eabb809d189695120792fc53a24c5e6ad14e1f080f42543092a3861d106325c5dfcbb7ad5f14ad3321c902230a"
+            ))
+        );
+        assert_eq!(
+            mock_hal.ui.screens,
+            vec![
+                Screen::Confirm {
+                    title: "Warning".into(),
+                    body: "Unknown instruction!\nIts effects\ncannot be verified.".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Program".into(),
+                    body: "5bV6jUfhDHCQVA1WfKBUnXUsboJgoKgkzkKcxr3joew5".into(),
+                    longtouch: false,
+                },
+                Screen::Confirm {
+                    title: "Solana".into(),
+                    body: "Fee\n0.000005 SOL".into(),
+                    longtouch: true,
+                },
+                Screen::Status {
+                    title: "Transaction\nconfirmed".into(),
+                    success: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_budget() {
+        let mut budget = ComputeBudget::default();
+        // No price set.
+        assert_eq!(budget.priority_fee(1), Ok(0));
+        budget.add(&hex!("03010000000000000000")[..]).unwrap_err();
+        budget.add(&hex!("030100000000000000")).unwrap();
+        // One micro-lamport per unit with the default limit of 200000 per instruction.
+        assert_eq!(budget.priority_fee(1), Ok(1));
+        assert_eq!(budget.priority_fee(3), Ok(1));
+        // Duplicate.
+        assert_eq!(
+            budget.add(&hex!("030100000000000000")),
+            Err(Error::InvalidInput)
+        );
+        budget.add(&hex!("0201000000")).unwrap();
+        // Rounded up.
+        assert_eq!(budget.priority_fee(1), Ok(1));
+
+        let mut budget = ComputeBudget::default();
+        budget.add(&hex!("03ffffffffffffffff")).unwrap();
+        budget.add(&hex!("0240420f00")).unwrap();
+        assert_eq!(budget.priority_fee(1), Ok(u64::MAX));
+        // Limit is capped at 1400000.
+        let mut budget = ComputeBudget::default();
+        budget.add(&hex!("03ffffffffffffffff")).unwrap();
+        assert_eq!(budget.priority_fee(10), Err(Error::InvalidInput));
+
+        // Unknown or malformed.
+        assert_eq!(
+            ComputeBudget::default().add(&hex!("00")),
+            Err(Error::InvalidInput)
+        );
+        assert_eq!(
+            ComputeBudget::default().add(&hex!("02010000")),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        mock_unlocked();
+        // Invalid keypath.
+        assert_eq!(
+            block_on(process(
+                &mut TestingHal::new(),
+                &pb::SolanaSignTransactionRequest {
+                    keypath: vec![44 + HARDENED, 501 + HARDENED, HARDENED],
+                    message: vec![],
+                },
+            )),
+            Err(Error::InvalidInput)
+        );
+        // Malformed message.
+        assert_eq!(
+            sign(&mut TestingHal::new(), &[1, 0, 0]),
+            Err(Error::InvalidInput)
+        );
+        // Too large.
+        assert_eq!(
+            sign(&mut TestingHal::new(), &[0; MAX_MESSAGE_SIZE + 1]),
+            Err(Error::InvalidInput)
+        );
+    }
+}
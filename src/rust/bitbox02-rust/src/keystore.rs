@@ -19,6 +19,11 @@ use bitcoin::hashes::{Hash, HashEngine, Hmac, HmacEngine, sha256, sha512};
 /// aes256cbc-hmac cipher adds 16 bytes IV, 16 bytes padding, 32 bytes hmac.
 const ENCRYPTION_OVERHEAD: usize = 64;
 
+/// The biggest retained buffer we will store is the bip39 passphrase, which is limited to 149
+/// bytes by the input component. The other retained buffers are at most 96 bytes (ed25519 root
+/// keys).
+const RETAINED_BUFFER_MAX_SIZE: usize = 160;
+
 // Unlocking the keystore takes longer than the default 500 ms watchdog. Bump the watchdog timeout
 // to roughly seven seconds so we don't assume communication was lost mid-unlock.
 const LONG_TIMEOUT: i16 = -70;
@@ -112,16 +117,14 @@ impl core::convert::From<securechip::Error> for Error {
 
 #[derive(Copy, Clone)]
 struct ReadOnlyBuffer {
-    // 64 is the biggest retained buffer (bip39 seed) we will store, and 64 is added for the
-    // aes256cbc-hmac overhead.
-    data: [u8; 64 + ENCRYPTION_OVERHEAD],
+    data: [u8; RETAINED_BUFFER_MAX_SIZE + ENCRYPTION_OVERHEAD],
     len: usize,
 }
 
 impl ReadOnlyBuffer {
     fn from_slice(data: &[u8]) -> Self {
         let mut result = ReadOnlyBuffer {
-            data: [0; 64 + ENCRYPTION_OVERHEAD],
+            data: [0; RETAINED_BUFFER_MAX_SIZE + ENCRYPTION_OVERHEAD],
             len: data.len(),
         };
         result.data[..data.len()].copy_from_slice(data);
@@ -190,6 +193,15 @@ static RETAINED_SEED: SyncCell<Option<RetainedEncryptedBuffer>> = SyncCell::new(
 static RETAINED_SEED_HASH: SyncCell<Option<[u8; 32]>> = SyncCell::new(None);
 // Stores the encrypted BIP-39 seed after bip39-unlock.
 static RETAINED_BIP39_SEED: SyncCell<Option<RetainedEncryptedBuffer>> = SyncCell::new(None);
+// Stores the BIP-39 passphrase after bip39-unlock, encrypted with a key derived from the BIP-39
+// seed. It is needed for derivations which do not start from the BIP-39 seed, such as the Icarus
+// derivation for Cardano. Unlike the other retained buffers, it does not use the securechip, so
+// that retaining it does not add securechip operations to every unlock.
+static RETAINED_MNEMONIC_PASSPHRASE: SyncCell<Option<ReadOnlyBuffer>> = SyncCell::new(None);
+// Caches the encrypted ed25519 Icarus root keys, which are expensive to derive. They are derived
+// on first use. See `ed25519::Derivation`.
+static RETAINED_ICARUS_SEED: SyncCell<Option<RetainedEncryptedBuffer>> = SyncCell::new(None);
+static RETAINED_ICARUS_TREZOR_SEED: SyncCell<Option<RetainedEncryptedBuffer>> = SyncCell::new(None);
 
 static ROOT_FINGERPRINT: SyncCell<Option<[u8; 4]>> = SyncCell::new(None);
 
@@ -199,6 +211,9 @@ pub fn lock() {
     RETAINED_SEED.write(None);
     RETAINED_SEED_HASH.write(None);
     RETAINED_BIP39_SEED.write(None);
+    RETAINED_MNEMONIC_PASSPHRASE.write(None);
+    RETAINED_ICARUS_SEED.write(None);
+    RETAINED_ICARUS_TREZOR_SEED.write(None);
 }
 
 /// Returns false if the keystore is unlocked (unlock() followed by unlock_bip39()), true otherwise.
@@ -260,6 +275,35 @@ fn retain_bip39_seed(hal: &mut impl KeystoreHal, bip39_seed: &[u8]) -> Result<()
     Ok(())
 }
 
+/// Returns the key and IV used to encrypt the retained bip39 passphrase. As the key is unique per
+/// bip39 seed and only ever encrypts one passphrase, the IV can be derived deterministically too.
+fn mnemonic_passphrase_encryption_key(
+    bip39_seed: &[u8],
+) -> (zeroize::Zeroizing<[u8; 32]>, [u8; 16]) {
+    let mut engine = HmacEngine::<sha512::Hash>::new(b"keystore_retained_mnemonic_passphrase");
+    engine.input(bip39_seed);
+    let hmac = zeroize::Zeroizing::new(Hmac::from_engine(engine).to_byte_array());
+    (
+        zeroize::Zeroizing::new(hmac[..32].try_into().unwrap()),
+        hmac[32..48].try_into().unwrap(),
+    )
+}
+
+fn retain_mnemonic_passphrase(bip39_seed: &[u8], mnemonic_passphrase: &[u8]) {
+    // The Icarus root keys depend on the passphrase and are derived again on next use.
+    RETAINED_ICARUS_SEED.write(None);
+    RETAINED_ICARUS_TREZOR_SEED.write(None);
+    if mnemonic_passphrase.len() >= RETAINED_BUFFER_MAX_SIZE {
+        // Can't happen with passphrases entered on the device. Derivations which need the
+        // passphrase are unavailable in this case.
+        RETAINED_MNEMONIC_PASSPHRASE.write(None);
+        return;
+    }
+    let (key, iv) = mnemonic_passphrase_encryption_key(bip39_seed);
+    let encrypted = bitbox_aes::encrypt_with_hmac(&iv, key.as_slice(), mnemonic_passphrase);
+    RETAINED_MNEMONIC_PASSPHRASE.write(Some(ReadOnlyBuffer::from_slice(&encrypted)));
+}
+
 /// Returns the stretching algo that will be used when setting new passwords.
 pub fn default_password_stretch_algo(
     hal: &mut impl crate::hal::Hal,
@@ -278,6 +322,7 @@ pub fn default_password_stretch_algo(
 fn encrypt_and_store_seed_internal(
     hal: &mut impl crate::hal::Hal,
     seed: &[u8],
+    seed_type: memory::SeedType,
     password: &str,
 ) -> Result<(), Error> {
     // Check that the seed is valid
@@ -305,7 +350,7 @@ fn encrypt_and_store_seed_internal(
     }
 
     hal.memory()
-        .set_encrypted_seed_and_hmac(&encrypted, password_stretch_algo)
+        .set_encrypted_seed_and_hmac(&encrypted, password_stretch_algo, seed_type)
         .map_err(|_| Error::Memory)?;
 
     if !verify_seed(hal, &secret, seed, password_stretch_algo) {
@@ -326,7 +371,40 @@ pub fn encrypt_and_store_seed(
     if hal.memory().is_initialized() {
         return Err(Error::Memory);
     }
-    encrypt_and_store_seed_internal(hal, seed, password)
+    encrypt_and_store_seed_internal(hal, seed, memory::SeedType::Bip39, password)
+}
+
+/// Like `encrypt_and_store_seed()`, but for a SLIP-39 master secret, which is used directly as the
+/// BIP-32 seed instead of being BIP-39 entropy.
+pub fn encrypt_and_store_slip39_master_secret(
+    hal: &mut impl crate::hal::Hal,
+    master_secret: &[u8],
+    password: &str,
+) -> Result<(), Error> {
+    if hal.memory().is_initialized() {
+        return Err(Error::Memory);
+    }
+    encrypt_and_store_seed_internal(hal, master_secret, memory::SeedType::Slip39, password)
+}
+
+/// Turns a newly created seed into a SLIP-39 master secret, so that it is used directly as the
+/// BIP-32 seed. This changes the wallet, so it is only possible before the device is initialized,
+/// i.e. before the seed has been backed up. The keystore must be unlocked again afterwards with
+/// `unlock_bip39()`.
+pub fn set_slip39_seed(hal: &mut impl crate::hal::Hal) -> Result<(), Error> {
+    if !hal.memory().is_seeded() {
+        return Err(Error::Unseeded);
+    }
+    if hal.memory().is_initialized() {
+        return Err(Error::Memory);
+    }
+    let (encrypted, password_stretch_algo) = hal
+        .memory()
+        .get_encrypted_seed_and_hmac()
+        .map_err(|_| Error::Memory)?;
+    hal.memory()
+        .set_encrypted_seed_and_hmac(&encrypted, password_stretch_algo, memory::SeedType::Slip39)
+        .map_err(|_| Error::Memory)
 }
 
 /// Re-encrypts the seed with a (new) password
@@ -344,12 +422,16 @@ pub fn re_encrypt_seed(
     // 2. encrypt_and_store_seed_internal calls lock() which clears BIP39 seed and root fingerprint
     // 3. We want to avoid forcing the user to re-enter their BIP39 passphrase
     let bip39_seed = copy_bip39_seed(hal).map_err(|_| Error::InvalidState)?;
+    let mnemonic_passphrase = RETAINED_MNEMONIC_PASSPHRASE.read();
     let root_fingerprint = ROOT_FINGERPRINT.read().ok_or(Error::InvalidState)?;
 
-    encrypt_and_store_seed_internal(hal, seed, new_password)?;
+    let seed_type = hal.memory().get_seed_type();
+    encrypt_and_store_seed_internal(hal, seed, seed_type, new_password)?;
 
-    // Re-retain the bip39 seed and root fingerprint
+    // Re-retain the bip39 seed, the bip39 passphrase and root fingerprint. The passphrase is
+    // encrypted with a key derived from the unchanged bip39 seed, so it can be restored as is.
     retain_bip39_seed(&mut KeystoreHalImpl::from_hal(hal), bip39_seed.as_slice())?;
+    RETAINED_MNEMONIC_PASSPHRASE.write(mnemonic_passphrase);
     ROOT_FINGERPRINT.write(Some(root_fingerprint));
 
     Ok(())
@@ -369,7 +451,8 @@ fn migrate_password_algo_and_retain_seed(
         .get_encrypted_seed_and_hmac()
         .map_err(|_| Error::Memory)?;
     if stored_algo != default_algo {
-        encrypt_and_store_seed_internal(hal, seed, password)
+        let seed_type = hal.memory().get_seed_type();
+        encrypt_and_store_seed_internal(hal, seed, seed_type, password)
     } else {
         retain_seed(&mut KeystoreHalImpl::from_hal(hal), seed)
     }
@@ -464,6 +547,10 @@ pub fn get_remaining_unlock_attempts(hal: &mut impl crate::hal::Hal) -> u8 {
 /// of `keystore_copy_seed()`).
 /// `mnemonic_passphrase` is the bip39 passphrase used in the derivation. Use the empty string if no
 /// passphrase is needed or provided.
+///
+/// If the seed is a SLIP-39 master secret, it is the BIP-32 seed itself and the passphrase must be
+/// empty, as the SLIP-39 passphrase is applied when the master secret is recovered from its
+/// shares.
 pub async fn unlock_bip39(
     hal: &mut impl KeystoreHal,
     seed: &[u8],
@@ -472,17 +559,37 @@ pub async fn unlock_bip39(
 ) -> Result<(), Error> {
     check_retained_seed(hal, seed).map_err(|_| Error::CannotUnlockBIP39)?;
 
-    let (bip39_seed, root_fingerprint) =
-        crate::bip39::derive_seed(seed, mnemonic_passphrase, &yield_now).await;
+    let (bip39_seed, root_fingerprint) = match hal.memory().get_seed_type() {
+        memory::SeedType::Bip39 => {
+            let (bip39_seed, root_fingerprint) =
+                crate::bip39::derive_seed(seed, mnemonic_passphrase, &yield_now).await;
 
-    let (bip39_seed_2, root_fingerprint_2) =
-        crate::bip39::derive_seed(seed, mnemonic_passphrase, &yield_now).await;
+            let (bip39_seed_2, root_fingerprint_2) =
+                crate::bip39::derive_seed(seed, mnemonic_passphrase, &yield_now).await;
 
-    if bip39_seed != bip39_seed_2 || root_fingerprint != root_fingerprint_2 {
-        return Err(Error::Memory);
-    }
+            if bip39_seed != bip39_seed_2 || root_fingerprint != root_fingerprint_2 {
+                return Err(Error::Memory);
+            }
+            (
+                zeroize::Zeroizing::new(bip39_seed.to_vec()),
+                root_fingerprint,
+            )
+        }
+        memory::SeedType::Slip39 => {
+            if !mnemonic_passphrase.is_empty() {
+                return Err(Error::CannotUnlockBIP39);
+            }
+            let root_fingerprint =
+                bitcoin::bip32::Xpriv::new_master(bitcoin::NetworkKind::Main, seed)
+                    .map_err(|_| Error::CannotUnlockBIP39)?
+                    .fingerprint(SECP256K1)
+                    .to_bytes();
+            (zeroize::Zeroizing::new(seed.to_vec()), root_fingerprint)
+        }
+    };
 
     retain_bip39_seed(hal, bip39_seed.as_slice())?;
+    retain_mnemonic_passphrase(bip39_seed.as_slice(), mnemonic_passphrase.as_bytes());
 
     // Store root fingerprint.
     ROOT_FINGERPRINT.write(Some(root_fingerprint));
@@ -507,6 +614,16 @@ pub fn copy_bip39_seed(hal: &mut impl crate::hal::Hal) -> Result<zeroize::Zeroiz
         .map_err(|_| ())
 }
 
+/// Returns a copy of the retained bip39 passphrase. Errors if the keystore is locked.
+fn copy_mnemonic_passphrase(
+    hal: &mut impl crate::hal::Hal,
+) -> Result<zeroize::Zeroizing<Vec<u8>>, ()> {
+    let encrypted = RETAINED_MNEMONIC_PASSPHRASE.read().ok_or(())?;
+    let bip39_seed = copy_bip39_seed(hal)?;
+    let (key, _) = mnemonic_passphrase_encryption_key(&bip39_seed);
+    bitbox_aes::decrypt_with_hmac(key.as_slice(), encrypted.as_slice())
+}
+
 /// Generates the seed, mixes it with host_entropy, and stores it encrypted with the
 /// password. The size of the host entropy determines the size of the seed. Can be either 16 or 32
 /// bytes, resulting in 12 or 24 BIP39 recovery words.
@@ -544,10 +661,13 @@ pub fn create_and_store_seed(
     encrypt_and_store_seed(hal, seed, password)
 }
 
-/// Returns the keystore's seed encoded as a BIP-39 mnemonic.
+/// Returns the keystore's seed encoded as a BIP-39 mnemonic. Fails for SLIP-39 seeds.
 pub fn get_bip39_mnemonic(
     hal: &mut impl crate::hal::Hal,
 ) -> Result<zeroize::Zeroizing<String>, ()> {
+    if hal.memory().get_seed_type() != memory::SeedType::Bip39 {
+        return Err(());
+    }
     crate::bip39::mnemonic_from_seed(&copy_seed(hal)?)
 }
 
@@ -708,12 +828,24 @@ fn bip85_entropy(
     ))
 }
 
+/// Returns the BIP-85 language code of a BIP39 wordlist, which is part of the keypath:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#bip39
+pub fn bip85_language_code(language: bip39::Language) -> u32 {
+    match language {
+        bip39::Language::English => 0,
+        bip39::Language::Italian => 7,
+        bip39::Language::Czech => 8,
+    }
+}
+
 /// Computes a BIP39 mnemonic according to BIP-85:
 /// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#bip39
+/// The mnemonic uses the wordlist of `language`.
 /// `words` must be 12, 18 or 24.
 /// `index` must be smaller than `bip32::HARDENED`.
 pub fn bip85_bip39(
     hal: &mut impl crate::hal::Hal,
+    language: bip39::Language,
     words: u32,
     index: u32,
 ) -> Result<zeroize::Zeroizing<String>, ()> {
@@ -731,13 +863,13 @@ pub fn bip85_bip39(
     let keypath = [
         83696968 + HARDENED,
         39 + HARDENED,
-        0 + HARDENED,
+        bip85_language_code(language) + HARDENED,
         words + HARDENED,
         index + HARDENED,
     ];
 
     let entropy = bip85_entropy(hal, &keypath)?;
-    crate::bip39::mnemonic_from_seed(&entropy[..seed_size])
+    crate::bip39::mnemonic_from_seed_in(language, &entropy[..seed_size])
 }
 
 /// Computes a 16 byte deterministic seed specifically for Lightning hot wallets according to BIP-85.
@@ -765,6 +897,169 @@ pub fn bip85_ln(
     Ok(entropy)
 }
 
+/// Computes a compressed mainnet WIF private key according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#hd-seed-wif
+/// `index` must be smaller than `bip32::HARDENED`.
+pub fn bip85_wif(
+    hal: &mut impl crate::hal::Hal,
+    index: u32,
+) -> Result<zeroize::Zeroizing<String>, ()> {
+    if index >= HARDENED {
+        return Err(());
+    }
+    let keypath = [83696968 + HARDENED, 2 + HARDENED, index + HARDENED];
+
+    let entropy = bip85_entropy(hal, &keypath)?;
+    let private_key = bitcoin::secp256k1::SecretKey::from_slice(&entropy[..32]).map_err(|_| ())?;
+    Ok(zeroize::Zeroizing::new(
+        bitcoin::PrivateKey::new(private_key, bitcoin::NetworkKind::Main).to_wif(),
+    ))
+}
+
+/// Computes a mainnet xprv according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#xprv
+/// The first 32 bytes of the entropy are the chain code, the second 32 bytes the private key.
+/// `index` must be smaller than `bip32::HARDENED`.
+pub fn bip85_xprv(
+    hal: &mut impl crate::hal::Hal,
+    index: u32,
+) -> Result<zeroize::Zeroizing<String>, ()> {
+    if index >= HARDENED {
+        return Err(());
+    }
+    let keypath = [83696968 + HARDENED, 32 + HARDENED, index + HARDENED];
+
+    let entropy = bip85_entropy(hal, &keypath)?;
+    let chain_code: [u8; 32] = entropy[..32].try_into().unwrap();
+    let xprv = bitcoin::bip32::Xpriv {
+        network: bitcoin::NetworkKind::Main,
+        depth: 0,
+        parent_fingerprint: Default::default(),
+        child_number: bitcoin::bip32::ChildNumber::from_normal_idx(0).unwrap(),
+        private_key: bitcoin::secp256k1::SecretKey::from_slice(&entropy[32..64]).map_err(|_| ())?,
+        chain_code: chain_code.into(),
+    };
+    Ok(zeroize::Zeroizing::new(format!("{}", xprv)))
+}
+
+/// Computes hex encoded entropy according to BIP-85:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#hex
+/// `num_bytes` must be between 16 and 64.
+/// `index` must be smaller than `bip32::HARDENED`.
+pub fn bip85_hex(
+    hal: &mut impl crate::hal::Hal,
+    num_bytes: u32,
+    index: u32,
+) -> Result<zeroize::Zeroizing<String>, ()> {
+    if index >= HARDENED || !(16..=64).contains(&num_bytes) {
+        return Err(());
+    }
+    let keypath = [
+        83696968 + HARDENED,
+        128169 + HARDENED,
+        num_bytes + HARDENED,
+        index + HARDENED,
+    ];
+
+    let entropy = bip85_entropy(hal, &keypath)?;
+    Ok(zeroize::Zeroizing::new(hex::encode(
+        &entropy[..num_bytes as usize],
+    )))
+}
+
+/// Computes a password according to BIP-85, which is the base64 encoding of the entropy, truncated
+/// to `length` characters:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#pwd-base64
+/// `length` must be between 20 and 86.
+/// `index` must be smaller than `bip32::HARDENED`.
+pub fn bip85_pwd_base64(
+    hal: &mut impl crate::hal::Hal,
+    length: u32,
+    index: u32,
+) -> Result<zeroize::Zeroizing<String>, ()> {
+    if index >= HARDENED || !(20..=86).contains(&length) {
+        return Err(());
+    }
+    let keypath = [
+        83696968 + HARDENED,
+        707764 + HARDENED,
+        length + HARDENED,
+        index + HARDENED,
+    ];
+
+    let entropy = bip85_entropy(hal, &keypath)?;
+    let mut password = base64_encode(&entropy);
+    password.truncate(length as usize);
+    Ok(password)
+}
+
+/// Computes a password according to BIP-85, which is the base85 encoding of the entropy, truncated
+/// to `length` characters:
+/// https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki#pwd-base85
+/// `length` must be between 10 and 80.
+/// `index` must be smaller than `bip32::HARDENED`.
+pub fn bip85_pwd_base85(
+    hal: &mut impl crate::hal::Hal,
+    length: u32,
+    index: u32,
+) -> Result<zeroize::Zeroizing<String>, ()> {
+    if index >= HARDENED || !(10..=80).contains(&length) {
+        return Err(());
+    }
+    let keypath = [
+        83696968 + HARDENED,
+        707785 + HARDENED,
+        length + HARDENED,
+        index + HARDENED,
+    ];
+
+    let entropy = bip85_entropy(hal, &keypath)?;
+    let mut password = base85_encode(&entropy);
+    password.truncate(length as usize);
+    Ok(password)
+}
+
+/// Standard base64 encoding with padding (RFC 4648).
+fn base64_encode(data: &[u8]) -> zeroize::Zeroizing<String> {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut result = zeroize::Zeroizing::new(String::with_capacity(data.len().div_ceil(3) * 4));
+    for chunk in data.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let value = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(ALPHABET[(value >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
+
+/// Base85 encoding with the RFC 1924 alphabet, as Python's `base64.b85encode()`.
+fn base85_encode(data: &[u8]) -> zeroize::Zeroizing<String> {
+    const ALPHABET: &[u8; 85] =
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+    let mut result = zeroize::Zeroizing::new(String::with_capacity(data.len().div_ceil(4) * 5));
+    for chunk in data.chunks(4) {
+        let mut group = [0u8; 4];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(group);
+        let mut encoded = [0u8; 5];
+        for c in encoded.iter_mut().rev() {
+            *c = ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+        // A partial group of n bytes is encoded in n + 1 characters.
+        for &c in &encoded[..chunk.len() + 1] {
+            result.push(c as char);
+        }
+    }
+    result
+}
+
 /// Sign message with private key using the given private key.
 ///
 /// Sign a message using the private key at the keypath, which is optionally tweaked with the given
@@ -1471,7 +1766,11 @@ mod tests {
 
             mock_hal
                 .memory
-                .set_encrypted_seed_and_hmac(&encrypted, memory::PasswordStretchAlgo::V0)
+                .set_encrypted_seed_and_hmac(
+                    &encrypted,
+                    memory::PasswordStretchAlgo::V0,
+                    memory::SeedType::Bip39,
+                )
                 .unwrap();
         }
 
@@ -1580,6 +1879,76 @@ mod tests {
         assert_eq!(decrypted.as_slice(), expected_bip39_seed.as_slice());
     }
 
+    #[test]
+    fn test_unlock_bip39_slip39() {
+        mock_memory();
+        lock();
+        let mut mock_hal = TestingHal::new();
+
+        // Master secret of the SLIP-39 test vectors.
+        let master_secret = hex!("b43ceb7e57a0ea8766221624d01b0864");
+
+        assert!(
+            encrypt_and_store_slip39_master_secret(&mut mock_hal, &master_secret, "password")
+                .is_ok()
+        );
+        assert_eq!(mock_hal.memory.get_seed_type(), memory::SeedType::Slip39);
+        // The passphrase is applied when combining the shares.
+        assert!(matches!(
+            block_on(unlock_bip39(
+                &mut KeystoreHalImpl::from_hal(&mut mock_hal),
+                &master_secret,
+                "foo",
+                async || {},
+            )),
+            Err(Error::CannotUnlockBIP39)
+        ));
+        assert!(
+            block_on(unlock_bip39(
+                &mut KeystoreHalImpl::from_hal(&mut mock_hal),
+                &master_secret,
+                "",
+                async || {},
+            ))
+            .is_ok()
+        );
+        // The master secret is the BIP-32 seed.
+        assert_eq!(
+            copy_bip39_seed(&mut mock_hal).unwrap().as_slice(),
+            master_secret.as_slice()
+        );
+        assert_eq!(root_fingerprint(), Ok(vec![0xe0, 0x8c, 0xa0, 0x0a]));
+        assert!(get_bip39_mnemonic(&mut mock_hal).is_err());
+    }
+
+    #[test]
+    fn test_set_slip39_seed() {
+        mock_memory();
+        lock();
+        let mut mock_hal = TestingHal::new();
+        assert!(matches!(
+            set_slip39_seed(&mut mock_hal),
+            Err(Error::Unseeded)
+        ));
+
+        let seed = hex!("b43ceb7e57a0ea8766221624d01b0864");
+        assert!(encrypt_and_store_seed(&mut mock_hal, &seed, "password").is_ok());
+        assert_eq!(mock_hal.memory.get_seed_type(), memory::SeedType::Bip39);
+        assert!(set_slip39_seed(&mut mock_hal).is_ok());
+        assert_eq!(mock_hal.memory.get_seed_type(), memory::SeedType::Slip39);
+        // The seed itself is unchanged.
+        lock();
+        assert_eq!(
+            block_on(unlock(&mut mock_hal, "password"))
+                .unwrap()
+                .as_slice(),
+            seed.as_slice()
+        );
+
+        mock_hal.memory.set_initialized().unwrap();
+        assert!(matches!(set_slip39_seed(&mut mock_hal), Err(Error::Memory)));
+    }
+
     #[test]
     fn test_secp256k1_get_private_key() {
         lock();
@@ -1818,7 +2187,7 @@ mod tests {
     #[test]
     fn test_bip85_bip39() {
         lock();
-        assert!(bip85_bip39(&mut TestingHal::new(), 12, 0).is_err());
+        assert!(bip85_bip39(&mut TestingHal::new(), bip39::Language::English, 12, 0).is_err());
 
         // Test fixtures generated using:
         // `docker build -t bip85 .`
@@ -1835,32 +2204,79 @@ mod tests {
         );
 
         assert_eq!(
-            bip85_bip39(&mut TestingHal::new(), 12, 0).unwrap().as_ref() as &str,
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::English, 12, 0)
+                .unwrap()
+                .as_ref() as &str,
             "slender whip place siren tissue chaos ankle door only assume tent shallow",
         );
         assert_eq!(
-            bip85_bip39(&mut TestingHal::new(), 12, 1).unwrap().as_ref() as &str,
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::English, 12, 1)
+                .unwrap()
+                .as_ref() as &str,
             "income soft level reunion height pony crane use unfold win keen satisfy",
         );
         assert_eq!(
-            bip85_bip39(&mut TestingHal::new(), 12, HARDENED - 1)
-                .unwrap()
-                .as_ref() as &str,
+            bip85_bip39(
+                &mut TestingHal::new(),
+                bip39::Language::English,
+                12,
+                HARDENED - 1
+            )
+            .unwrap()
+            .as_ref() as &str,
             "carry build nerve market domain energy mistake script puzzle replace mixture idea",
         );
         assert_eq!(
-            bip85_bip39(&mut TestingHal::new(), 18, 0).unwrap().as_ref() as &str,
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::English, 18, 0)
+                .unwrap()
+                .as_ref() as &str,
             "enact peasant tragic habit expand jar senior melody coin acid logic upper soccer later earn napkin planet stereo",
         );
         assert_eq!(
-            bip85_bip39(&mut TestingHal::new(), 24, 0).unwrap().as_ref() as &str,
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::English, 24, 0)
+                .unwrap()
+                .as_ref() as &str,
             "cabbage wink october add anchor mean tray surprise gasp tomorrow garbage habit beyond merge where arrive beef gentle animal office drop panel chest size",
         );
 
+        // Other languages, with the language code in the keypath.
+        assert_eq!(
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::Italian, 12, 0)
+                .unwrap()
+                .as_str(),
+            "gallina pesista svista casuale erede sfratto pargolo avere mannaro africano voragine tesi",
+        );
+        assert_eq!(
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::Italian, 18, 1)
+                .unwrap()
+                .as_str(),
+            "utensile tubatura tollerare anca grifone tarpare conciso albo segregato caso alabarda gregge imbocco tortora sgrassato proroga achille innesco",
+        );
+        assert_eq!(
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::Czech, 12, 1)
+                .unwrap()
+                .as_str(),
+            "jalovec fond souboj poznatek horda zdobit kanoe pestrost obzor putyka fixace ubrus",
+        );
+        assert_eq!(
+            bip85_bip39(&mut TestingHal::new(), bip39::Language::Czech, 24, 0)
+                .unwrap()
+                .as_str(),
+            "viset vagon laso inzerce kotel klenba letmo valoun rypadlo cokoliv krok zafoukat tlupa znak povidla ocitnout humr cenzor odmlka majetek penze posadit pokles zlato",
+        );
+
         // Invalid number of words.
-        assert!(bip85_bip39(&mut TestingHal::new(), 10, 0).is_err());
+        assert!(bip85_bip39(&mut TestingHal::new(), bip39::Language::English, 10, 0).is_err());
         // Index too high.
-        assert!(bip85_bip39(&mut TestingHal::new(), 12, HARDENED).is_err());
+        assert!(
+            bip85_bip39(
+                &mut TestingHal::new(),
+                bip39::Language::English,
+                12,
+                HARDENED
+            )
+            .is_err()
+        );
     }
 
     #[test]
@@ -1892,6 +2308,114 @@ mod tests {
         assert!(bip85_ln(&mut TestingHal::new(), HARDENED).is_err());
     }
 
+    #[test]
+    fn test_bip85_wif_xprv() {
+        lock();
+        assert!(bip85_wif(&mut TestingHal::new(), 0).is_err());
+        assert!(bip85_xprv(&mut TestingHal::new(), 0).is_err());
+
+        // Test fixtures generated with a Python implementation checked against the test vectors
+        // of BIP-85.
+        mock_unlocked_using_mnemonic(
+            "virtual weapon code laptop defy cricket vicious target wave leopard garden give",
+            "",
+        );
+
+        assert_eq!(
+            bip85_wif(&mut TestingHal::new(), 0).unwrap().as_str(),
+            "L4GM2EdYgvFrbMQ5QmpCX7Q8EGT6pB2knmBVutdxKs8mTrwBDPHW",
+        );
+        assert_eq!(
+            bip85_wif(&mut TestingHal::new(), 1).unwrap().as_str(),
+            "L28A5vg5yc1N2J7AmGochrPFUS86NKLqPBbFF19nd966mj3SsMQD",
+        );
+        assert_eq!(
+            bip85_xprv(&mut TestingHal::new(), 0).unwrap().as_str(),
+            "xprv9s21ZrQH143K2G55HNRqe8NuwBXKSzjYhXsHTE5NER7h2Q5QKEgcMF5jkmwJLdTm3XDW1nBiNr7eDjwWxSutCRNKFzKVmAdwviLkkkRFYGz",
+        );
+        assert_eq!(
+            bip85_xprv(&mut TestingHal::new(), HARDENED - 1)
+                .unwrap()
+                .as_str(),
+            "xprv9s21ZrQH143K2uzTzZtQ6cTTW4Gr4nB6UQM4nrcjbBE4PBz4psraMDzBy69QNiju7xE53CsB3VpMvJkPbLV1DtprtCbXRyCg8iwmSiU75Mj",
+        );
+
+        // Index too high.
+        assert!(bip85_wif(&mut TestingHal::new(), HARDENED).is_err());
+        assert!(bip85_xprv(&mut TestingHal::new(), HARDENED).is_err());
+    }
+
+    #[test]
+    fn test_bip85_hex_pwd() {
+        lock();
+        assert!(bip85_hex(&mut TestingHal::new(), 16, 0).is_err());
+
+        // Test fixtures generated with a Python implementation checked against the test vectors
+        // of BIP-85.
+        mock_unlocked_using_mnemonic(
+            "virtual weapon code laptop defy cricket vicious target wave leopard garden give",
+            "",
+        );
+
+        assert_eq!(
+            bip85_hex(&mut TestingHal::new(), 16, 0).unwrap().as_str(),
+            "3db743509311e54c87ddf1d50ac187b4",
+        );
+        assert_eq!(
+            bip85_hex(&mut TestingHal::new(), 64, 1).unwrap().as_str(),
+            "c7a75d2b87e7746e9a27a0b0d6b140d4169db4348beaf93683b322e619aac62ca27fc35c28fdd2dc56cf2911a4c0b62ae58fdd3856d529d169416a0f55b69507",
+        );
+        assert_eq!(
+            bip85_pwd_base64(&mut TestingHal::new(), 20, 0)
+                .unwrap()
+                .as_str(),
+            "T7e/cJBzKvZSKFhdTjUF",
+        );
+        assert_eq!(
+            bip85_pwd_base64(&mut TestingHal::new(), 86, 0)
+                .unwrap()
+                .as_str(),
+            "EFq97evmN9bPJmDtI59drcZ1SkGXgxYCxsNrzf1cNwVn5MmrIy+y2GrIphdH4k2WeY+h+c9x6zkYVbNHUincFg",
+        );
+        assert_eq!(
+            bip85_pwd_base85(&mut TestingHal::new(), 10, 0)
+                .unwrap()
+                .as_str(),
+            "<i}}BR1O>U",
+        );
+        assert_eq!(
+            bip85_pwd_base85(&mut TestingHal::new(), 80, 0)
+                .unwrap()
+                .as_str(),
+            "cvh~pIWG|Fg^qFApba!CMd`J(>T{gDm#h#VZ*Fj1mAOTm6VJoYxs$UQ3?sD~LWME~J#@~Ej_k2CTvZdG",
+        );
+
+        // Invalid lengths.
+        assert!(bip85_hex(&mut TestingHal::new(), 15, 0).is_err());
+        assert!(bip85_hex(&mut TestingHal::new(), 65, 0).is_err());
+        assert!(bip85_pwd_base64(&mut TestingHal::new(), 19, 0).is_err());
+        assert!(bip85_pwd_base64(&mut TestingHal::new(), 87, 0).is_err());
+        assert!(bip85_pwd_base85(&mut TestingHal::new(), 9, 0).is_err());
+        assert!(bip85_pwd_base85(&mut TestingHal::new(), 81, 0).is_err());
+        // Index too high.
+        assert!(bip85_hex(&mut TestingHal::new(), 16, HARDENED).is_err());
+        assert!(bip85_pwd_base64(&mut TestingHal::new(), 20, HARDENED).is_err());
+        assert!(bip85_pwd_base85(&mut TestingHal::new(), 10, HARDENED).is_err());
+    }
+
+    #[test]
+    fn test_base64_base85_encode() {
+        assert_eq!(base64_encode(b"").as_str(), "");
+        assert_eq!(base64_encode(b"f").as_str(), "Zg==");
+        assert_eq!(base64_encode(b"fo").as_str(), "Zm8=");
+        assert_eq!(base64_encode(b"foobar").as_str(), "Zm9vYmFy");
+        // Test vectors from Python's `base64.b85encode()`.
+        assert_eq!(base85_encode(b"").as_str(), "");
+        assert_eq!(base85_encode(b"f").as_str(), "W&");
+        assert_eq!(base85_encode(b"foobar").as_str(), "W^Zp|VR8");
+        assert_eq!(base85_encode(&[0xff; 4]).as_str(), "|NsC0");
+    }
+
     #[test]
     fn test_fixtures() {
         struct Test {
@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod slip10;
+
 use alloc::vec::Vec;
 
+use crate::hal::Memory;
+use crate::hal::memory::SeedType;
 use crate::hash::Sha512;
 use bip32_ed25519::{ED25519_EXPANDED_SECRET_KEY_SIZE, Xprv, Xpub};
 
@@ -13,12 +17,24 @@ fn hmac_sha512(key: &[u8], msg: &[u8]) -> [u8; 64] {
     Hmac::from_engine(engine).to_byte_array()
 }
 
-/// Get the seed to be used for ed25519 applications such as Cardano. The output is the root key to
-/// BIP32-ED25519.
+/// Derivation of the BIP32-ED25519 root key from the BIP-39 mnemonic.
+/// See https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0003/CIP-0003.md
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Derivation {
+    /// Derivation from the BIP-39 seed, compatible with Ledger.
+    Ledger,
+    /// Derivation from the BIP-39 entropy, used by most software wallets such as Yoroi and Eternl.
+    Icarus,
+    /// Like `Icarus`, but for 24 word mnemonics, the entropy includes the BIP-39 checksum byte.
+    /// This is compatible with Trezor.
+    IcarusTrezor,
+}
+
+/// Derives the root key from the BIP-39 seed.
 /// This implements a derivation compatible with Ledger according to
 /// https://github.com/LedgerHQ/orakolo/blob/0b2d5e669ec61df9a824df9fa1a363060116b490/src/python/orakolo/HDEd25519.py.
 /// Returns 96 bytes. It will contain a 64 byte expanded ed25519 private key followed by a 32 byte chain code.
-fn get_seed(hal: &mut impl crate::hal::Hal) -> Result<zeroize::Zeroizing<Vec<u8>>, ()> {
+fn get_seed_ledger(hal: &mut impl crate::hal::Hal) -> Result<zeroize::Zeroizing<Vec<u8>>, ()> {
     let bip39_seed = crate::keystore::copy_bip39_seed(hal)?;
     let mut seed_out = zeroize::Zeroizing::new(vec![0u8; 96]);
     let first64: &mut [u8] = &mut seed_out.as_mut_slice()[..64];
@@ -46,8 +62,107 @@ fn get_seed(hal: &mut impl crate::hal::Hal) -> Result<zeroize::Zeroizing<Vec<u8>
     Ok(seed_out)
 }
 
-fn get_xprv(hal: &mut impl crate::hal::Hal, keypath: &[u32]) -> Result<Xprv<Sha512>, ()> {
-    let root = get_seed(hal)?;
+/// PBKDF2 with HMAC-SHA512, see https://datatracker.ietf.org/doc/html/rfc8018#section-5.2.
+fn pbkdf2_hmac_sha512(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    let engine = HmacEngine::<sha512::Hash>::new(password);
+    for (i, chunk) in out.chunks_mut(64).enumerate() {
+        let mut block_engine = engine.clone();
+        block_engine.input(salt);
+        block_engine.input(&(i as u32 + 1).to_be_bytes());
+        let mut u = zeroize::Zeroizing::new(Hmac::from_engine(block_engine).to_byte_array());
+        let mut t = u.clone();
+        for _ in 1..iterations {
+            let mut iteration_engine = engine.clone();
+            iteration_engine.input(u.as_slice());
+            *u = Hmac::from_engine(iteration_engine).to_byte_array();
+            t.iter_mut().zip(u.iter()).for_each(|(t, u)| *t ^= u);
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
+
+/// Derives the Icarus root key from the BIP-39 entropy and passphrase according to
+/// https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0003/Icarus.md.
+/// Returns 96 bytes in the same format as `get_seed_ledger()`.
+fn derive_icarus_seed(entropy: &[u8], mnemonic_passphrase: &[u8]) -> zeroize::Zeroizing<Vec<u8>> {
+    let mut seed_out = zeroize::Zeroizing::new(vec![0u8; 96]);
+    pbkdf2_hmac_sha512(mnemonic_passphrase, entropy, 4096, &mut seed_out);
+    seed_out[0] &= 0b1111_1000;
+    seed_out[31] &= 0b0001_1111;
+    seed_out[31] |= 0b0100_0000;
+    seed_out
+}
+
+/// Returns the Icarus root key. It is derived on first use and retained until the keystore is
+/// locked, as the derivation is slow.
+fn get_seed_icarus(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+) -> Result<zeroize::Zeroizing<Vec<u8>>, ()> {
+    let (retained, purpose) = match derivation {
+        Derivation::Icarus => (
+            &super::RETAINED_ICARUS_SEED,
+            "keystore_retained_icarus_seed_access",
+        ),
+        Derivation::IcarusTrezor => (
+            &super::RETAINED_ICARUS_TREZOR_SEED,
+            "keystore_retained_icarus_trezor_seed_access",
+        ),
+        Derivation::Ledger => return Err(()),
+    };
+    // Check that the keystore is unlocked, as the cache is only cleared when locking.
+    if super::is_locked() {
+        return Err(());
+    }
+    if let Some(seed) = retained.read() {
+        return seed
+            .decrypt(&mut super::KeystoreHalImpl::from_hal(hal))
+            .map_err(|_| ());
+    }
+
+    let mut entropy = super::copy_seed(hal)?;
+    // Trezor includes the checksum in the entropy for 24 word mnemonics. The checksum of 32 bytes
+    // of entropy is the first byte of its SHA256 hash.
+    if derivation == Derivation::IcarusTrezor && entropy.len() == 32 {
+        let checksum = sha256::Hash::hash(&entropy).to_byte_array()[0];
+        entropy.push(checksum);
+    }
+    let mnemonic_passphrase = super::copy_mnemonic_passphrase(hal)?;
+    let seed = derive_icarus_seed(&entropy, &mnemonic_passphrase);
+    retained.write(Some(
+        super::RetainedEncryptedBuffer::from_buffer(
+            &mut super::KeystoreHalImpl::from_hal(hal),
+            &seed,
+            purpose,
+        )
+        .map_err(|_| ())?,
+    ));
+    Ok(seed)
+}
+
+/// Get the seed to be used for ed25519 applications such as Cardano, using the given derivation.
+/// Returns 96 bytes. It will contain a 64 byte expanded ed25519 private key followed by a 32 byte
+/// chain code.
+fn get_seed(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+) -> Result<zeroize::Zeroizing<Vec<u8>>, ()> {
+    // All derivations are defined on BIP-39 mnemonics.
+    if hal.memory().get_seed_type() != SeedType::Bip39 {
+        return Err(());
+    }
+    match derivation {
+        Derivation::Ledger => get_seed_ledger(hal),
+        Derivation::Icarus | Derivation::IcarusTrezor => get_seed_icarus(hal, derivation),
+    }
+}
+
+fn get_xprv(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    keypath: &[u32],
+) -> Result<Xprv<Sha512>, ()> {
+    let root = get_seed(hal, derivation)?;
     Ok(Xprv::<Sha512>::from_normalize(
         &root[..ED25519_EXPANDED_SECRET_KEY_SIZE],
         &root[ED25519_EXPANDED_SECRET_KEY_SIZE..],
@@ -55,8 +170,12 @@ fn get_xprv(hal: &mut impl crate::hal::Hal, keypath: &[u32]) -> Result<Xprv<Sha5
     .derive_path(keypath))
 }
 
-pub fn get_xpub(hal: &mut impl crate::hal::Hal, keypath: &[u32]) -> Result<Xpub<Sha512>, ()> {
-    Ok(get_xprv(hal, keypath)?.public())
+pub fn get_xpub(
+    hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
+    keypath: &[u32],
+) -> Result<Xpub<Sha512>, ()> {
+    Ok(get_xprv(hal, derivation, keypath)?.public())
 }
 
 pub struct SignResult {
@@ -64,12 +183,15 @@ pub struct SignResult {
     pub public_key: ed25519_dalek::VerifyingKey,
 }
 
+/// Signs the message with the key at the keypath. The message is usually a 32 byte hash, but can
+/// be longer, e.g. for CIP-8 message signing.
 pub fn sign(
     hal: &mut impl crate::hal::Hal,
+    derivation: Derivation,
     keypath: &[u32],
-    msg: &[u8; 32],
+    msg: &[u8],
 ) -> Result<SignResult, ()> {
-    let xprv = get_xprv(hal, keypath)?;
+    let xprv = get_xprv(hal, derivation, keypath)?;
     let secret_key =
         ed25519_dalek::hazmat::ExpandedSecretKey::from_bytes(&xprv.expanded_secret_key());
     let public_key = ed25519_dalek::VerifyingKey::from(&secret_key);
@@ -118,7 +240,7 @@ mod tests {
             "",
         );
         assert_eq!(
-            get_seed(&mut mock_hal).unwrap().as_slice(),
+            get_seed(&mut mock_hal, Derivation::Ledger).unwrap().as_slice(),
             b"\xa0\x8c\xf8\x5b\x56\x4e\xcf\x3b\x94\x7d\x8d\x43\x21\xfb\x96\xd7\x0e\xe7\xbb\x76\x08\x77\xe3\x71\x89\x9b\x14\xe2\xcc\xf8\x86\x58\x10\x4b\x88\x46\x82\xb5\x7e\xfd\x97\xde\xcb\xb3\x18\xa4\x5c\x05\xa5\x27\xb9\xcc\x5c\x2f\x64\xf7\x35\x29\x35\xa0\x49\xce\xea\x60\x68\x0d\x52\x30\x81\x94\xcc\xef\x2a\x18\xe6\x81\x2b\x45\x2a\x58\x15\xfb\xd7\xf5\xba\xbc\x08\x38\x56\x91\x9a\xaf\x66\x8f\xe7\xe4",
         );
 
@@ -128,7 +250,7 @@ mod tests {
             "",
         );
         assert_eq!(
-            get_seed(&mut mock_hal).unwrap().as_slice(),
+            get_seed(&mut mock_hal, Derivation::Ledger).unwrap().as_slice(),
             b"\x58\x7c\x67\x74\x35\x7e\xcb\xf8\x40\xd4\xdb\x64\x04\xff\x7a\xf0\x16\xda\xce\x04\x00\x76\x97\x51\xad\x2a\xbf\xc7\x7b\x9a\x38\x44\xcc\x71\x70\x25\x20\xef\x1a\x4d\x1b\x68\xb9\x11\x87\x78\x7a\x9b\x8f\xaa\xb0\xa9\xbb\x6b\x16\x0d\xe5\x41\xb6\xee\x62\x46\x99\x01\xfc\x0b\xed\xa0\x97\x5f\xe4\x76\x3b\xea\xbd\x83\xb7\x05\x1a\x5f\xd5\xcb\xce\x5b\x88\xe8\x2c\x4b\xba\xca\x26\x50\x14\xe5\x24\xbd",
         );
 
@@ -137,26 +259,101 @@ mod tests {
             "foo",
         );
         assert_eq!(
-            get_seed(&mut mock_hal).unwrap().as_slice(),
+            get_seed(&mut mock_hal, Derivation::Ledger).unwrap().as_slice(),
             b"\xf0\x53\xa1\xe7\x52\xde\x5c\x26\x19\x7b\x60\xf0\x32\xa4\x80\x9f\x08\xbb\x3e\x5d\x90\x48\x4f\xe4\x20\x24\xbe\x31\xef\xcb\xa7\x57\x8d\x91\x4d\x3f\xf9\x92\xe2\x16\x52\xfe\xe6\xa4\xd9\x9f\x60\x91\x00\x69\x38\xfa\xc2\xc0\xc0\xf9\xd2\xde\x0b\xa6\x4b\x75\x4e\x92\xa4\xf3\x72\x3f\x23\x47\x20\x77\xaa\x4c\xd4\xdd\x8a\x8a\x17\x5d\xba\x07\xea\x18\x52\xda\xd1\xcf\x26\x8c\x61\xa2\x67\x9c\x38\x90",
         );
     }
 
+    #[test]
+    fn test_get_seed_icarus() {
+        // The 15 word test vectors are taken from:
+        // https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0003/Icarus.md#test-vectors
+        //
+        // CIP-3 has no 24 word vectors. Those below were computed with a Python implementation of
+        // the CIP-3 algorithm (PBKDF2-HMAC-SHA512 over the entropy), once with and once without
+        // the checksum byte in the entropy.
+
+        let mut mock_hal = crate::hal::testing::TestingHal::new();
+
+        crate::keystore::lock();
+        assert!(get_seed(&mut mock_hal, Derivation::Icarus).is_err());
+
+        mock_unlocked_using_mnemonic(
+            "eight country switch draw meat scout mystery blade tip drift useless good keep usage title",
+            "",
+        );
+        let expected = b"\xc0\x65\xaf\xd2\x83\x2c\xd8\xb0\x87\xc4\xd9\xab\x70\x11\xf4\x81\xee\x1e\x07\x21\xe7\x8e\xa5\xdd\x60\x9f\x3a\xb3\xf1\x56\xd2\x45\xd1\x76\xbd\x8f\xd4\xec\x60\xb4\x73\x1c\x39\x18\xa2\xa7\x2a\x02\x26\xc0\xcd\x11\x9e\xc3\x5b\x47\xe4\xd5\x58\x84\x66\x7f\x55\x2a\x23\xf7\xfd\xcd\x4a\x10\xc6\xcd\x2c\x73\x93\xac\x61\xd8\x77\x87\x3e\x24\x8f\x41\x76\x34\xaa\x3d\x81\x2a\xf3\x27\xff\xe9\xd6\x20";
+        assert_eq!(
+            get_seed(&mut mock_hal, Derivation::Icarus)
+                .unwrap()
+                .as_slice(),
+            expected,
+        );
+        // Retained after the first derivation.
+        assert_eq!(
+            get_seed(&mut mock_hal, Derivation::Icarus)
+                .unwrap()
+                .as_slice(),
+            expected,
+        );
+        // Icarus-Trezor only differs for 24 words.
+        assert_eq!(
+            get_seed(&mut mock_hal, Derivation::IcarusTrezor)
+                .unwrap()
+                .as_slice(),
+            expected,
+        );
+
+        // Locking clears the retained root key.
+        crate::keystore::lock();
+        assert!(get_seed(&mut mock_hal, Derivation::Icarus).is_err());
+
+        mock_unlocked_using_mnemonic(
+            "eight country switch draw meat scout mystery blade tip drift useless good keep usage title",
+            "foo",
+        );
+        assert_eq!(
+            get_seed(&mut mock_hal, Derivation::Icarus).unwrap().as_slice(),
+            b"\x70\x53\x10\x39\x90\x40\x19\x35\x1e\x1a\xfb\x36\x1c\xd1\xb3\x12\xa4\xd0\x56\x5d\x4f\xf9\xf8\x06\x2d\x38\xac\xf4\xb1\x5c\xce\x41\xd7\xb5\x73\x8d\x9c\x89\x3f\xee\xa5\x55\x12\xa3\x00\x4a\xcb\x0d\x22\x2c\x35\xd3\xe3\xd5\xcd\xe9\x43\xa1\x5a\x98\x24\xcb\xac\x59\x44\x3c\xf6\x7e\x58\x96\x14\x07\x6b\xa0\x1e\x35\x4b\x1a\x43\x2e\x0e\x6d\xb3\xb5\x9e\x37\xfc\x56\xb5\xfb\x02\x22\x97\x0a\x01\x0e",
+        );
+
+        // 24 words: Icarus-Trezor includes the checksum in the entropy.
+        mock_unlocked_using_mnemonic(
+            "recall grace sport punch exhibit mad harbor stand obey short width stem awkward used stairs wool ugly trap season stove worth toward congress jaguar",
+            "",
+        );
+        assert_eq!(
+            get_seed(&mut mock_hal, Derivation::Icarus).unwrap().as_slice(),
+            b"\xb8\xbd\x2c\x2b\x2f\xb4\x6a\x10\x8b\x2e\xc0\xd1\xbd\xc0\x24\x01\x2d\xa2\xd6\x87\x60\x9b\x00\x80\x93\xdb\x95\x70\xcf\x0b\x83\x44\xfb\x7b\xf9\x18\x68\x3e\xb5\x29\x19\x91\x3e\x75\xbe\x75\xb8\x36\x8d\xe1\xaa\x16\xd9\x03\x24\xf6\xcd\x0f\xa6\x84\xb0\x93\xa2\x93\x0f\x16\x54\xc8\xb9\x11\x68\xa4\x4f\x9f\xc6\xbb\xeb\x02\x51\x4d\xb6\x28\xd1\x28\x08\x22\x6f\x3f\x34\x36\x5a\xf3\x79\xa4\x6d\x96",
+        );
+        assert_eq!(
+            get_seed(&mut mock_hal, Derivation::IcarusTrezor)
+                .unwrap()
+                .as_slice(),
+            b"\x50\xc6\xd1\x86\x94\x5d\x3f\x7a\xfd\x3f\xab\xd1\xbc\x29\x3d\x2e\xf4\x95\x47\xf1\x37\x11\xc4\xe6\x1d\x1b\x17\xba\x03\xb3\x49\x5c\xb9\x8d\xf4\xa9\x70\x0e\x2c\x1d\x12\xe4\xa6\x48\x9a\xba\x9a\x8c\x33\x60\x0c\x35\x17\xa8\x79\x0b\x4e\xdb\x6d\xe6\x74\x07\x11\xfb\x80\x9e\x33\xd8\x0d\x45\x3c\x87\x4b\x5d\x7e\xa4\x05\x00\x83\x08\xc7\x03\xca\x61\x24\x08\xc3\x16\x52\x2b\x6e\x0a\x2a\x13\xea\xe8",
+        );
+    }
+
     #[test]
     fn test_get_xpub() {
         crate::keystore::lock();
 
         let mut mock_hal = crate::hal::testing::TestingHal::new();
 
-        assert!(get_xpub(&mut mock_hal, &[]).is_err());
+        assert!(get_xpub(&mut mock_hal, Derivation::Ledger, &[]).is_err());
 
         mock_unlocked();
 
-        let xpub = get_xpub(&mut mock_hal, &[]).unwrap();
+        let xpub = get_xpub(&mut mock_hal, Derivation::Ledger, &[]).unwrap();
         assert_eq!(xpub.pubkey_bytes(), b"\x1c\xc2\xc8\x0d\x6f\xb0\x3e\xc0\x9e\x8a\x26\x8b\xaa\x45\xd4\xca\x2a\xfe\x5c\x5a\xc4\xdb\x3e\xe2\x9c\x7a\xd2\x37\x55\xab\xdc\x14");
         assert_eq!(xpub.chain_code(), b"\xf0\xa5\x91\x06\x42\xd0\x77\x98\x17\x40\x2e\x5e\x7a\x75\x54\x95\xe7\x44\xf5\x5c\xf1\x1e\x49\xee\xfd\x22\xa4\x60\xe9\xb2\xf7\x53");
 
-        let xpub = get_xpub(&mut mock_hal, &[10 + HARDENED_OFFSET, 10]).unwrap();
+        let xpub = get_xpub(
+            &mut mock_hal,
+            Derivation::Ledger,
+            &[10 + HARDENED_OFFSET, 10],
+        )
+        .unwrap();
         assert_eq!(xpub.pubkey_bytes(), b"\xab\x58\xbd\x94\x7e\x2b\xf6\x64\xa7\xc0\x66\xde\x2e\xf0\x24\x0e\xfc\x24\xf3\x6e\xfd\x50\x2d\xf8\x83\x93\xe1\x96\xaf\x3c\x91\x8e");
         assert_eq!(xpub.chain_code(), b"\xf2\x00\x13\x38\x58\x02\xa6\xf9\xc0\x5e\xe7\xb0\x36\x16\xad\xf6\x9f\x5f\x9e\xc4\x32\x53\xa5\xd0\x8b\xe9\x65\x79\x81\x90\x83\xbb");
     }
@@ -167,13 +364,18 @@ mod tests {
 
         let mut mock_hal = crate::hal::testing::TestingHal::new();
 
-        assert!(get_xprv(&mut mock_hal, &[]).is_err());
+        assert!(get_xprv(&mut mock_hal, Derivation::Ledger, &[]).is_err());
 
         mock_unlocked();
-        let xprv = get_xprv(&mut mock_hal, &[]).unwrap();
+        let xprv = get_xprv(&mut mock_hal, Derivation::Ledger, &[]).unwrap();
         assert_eq!(xprv.expanded_secret_key().as_slice(), b"\xf8\xcb\x28\x85\x37\x60\x2b\x90\xd1\x29\x75\x4b\xdd\x0e\x4b\xed\xf9\xe2\x92\x3a\x04\xb6\x86\x7e\xdb\xeb\xc7\x93\xa7\x17\x6f\x5d\xca\xc5\xc9\x5d\x5f\xd2\x3a\x8e\x01\x6c\x95\x57\x69\x0e\xad\x1f\x00\x2b\x0f\x35\xd7\x06\xff\x8e\x59\x84\x1c\x09\xe0\xb6\xbb\x23");
 
-        let xprv = get_xprv(&mut mock_hal, &[10 + HARDENED_OFFSET, 10]).unwrap();
+        let xprv = get_xprv(
+            &mut mock_hal,
+            Derivation::Ledger,
+            &[10 + HARDENED_OFFSET, 10],
+        )
+        .unwrap();
         assert_eq!(xprv.expanded_secret_key().as_slice(), b"\x00\x28\x46\xb1\xeb\x06\x66\xff\x4e\xf1\x66\xde\x37\x80\xdf\xe1\x95\xed\x6f\xfd\xce\x41\x18\x09\x9d\x9d\x80\x85\xaa\x17\x6f\x5d\x1f\xcf\xf9\x55\x2e\xe4\xc0\xcb\x03\xaa\x42\x1a\xe8\x2f\x98\xa0\x0a\xfc\x65\xb6\x84\x66\x31\xaa\x41\x8e\x6d\x5a\x62\x6e\x75\xf4");
     }
 
@@ -184,6 +386,7 @@ mod tests {
         assert!(
             sign(
                 &mut crate::hal::testing::TestingHal::new(),
+                Derivation::Ledger,
                 &[10 + HARDENED_OFFSET, 10],
                 msg
             )
@@ -193,6 +396,7 @@ mod tests {
         mock_unlocked();
         let sig = sign(
             &mut crate::hal::testing::TestingHal::new(),
+            Derivation::Ledger,
             &[10 + HARDENED_OFFSET, 10],
             msg,
         )
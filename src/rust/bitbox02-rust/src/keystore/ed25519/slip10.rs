@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! SLIP-10 ed25519 key derivation, as used by Solana. Unlike BIP32-Ed25519 (see the parent
+//! module), only hardened derivation is possible and there are no extended public keys.
+//!
+//! See https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+
+use crate::hash::Sha512;
+
+use bitcoin::hashes::{Hash, HashEngine, Hmac, HmacEngine, sha512};
+
+use util::bip32::HARDENED;
+
+/// Derives the private key at the keypath from the BIP-39 seed. All keypath elements must be
+/// hardened.
+fn derive(bip39_seed: &[u8], keypath: &[u32]) -> Result<zeroize::Zeroizing<[u8; 64]>, ()> {
+    let mut engine = HmacEngine::<sha512::Hash>::new(b"ed25519 seed");
+    engine.input(bip39_seed);
+    // First 32 bytes: private key, last 32 bytes: chain code.
+    let mut key = zeroize::Zeroizing::new(Hmac::from_engine(engine).to_byte_array());
+    for &index in keypath {
+        if index < HARDENED {
+            return Err(());
+        }
+        let mut engine = HmacEngine::<sha512::Hash>::new(&key[32..]);
+        engine.input(&[0]);
+        engine.input(&key[..32]);
+        engine.input(&index.to_be_bytes());
+        *key = Hmac::from_engine(engine).to_byte_array();
+    }
+    Ok(key)
+}
+
+fn get_secret_key(
+    hal: &mut impl crate::hal::Hal,
+    keypath: &[u32],
+) -> Result<ed25519_dalek::hazmat::ExpandedSecretKey, ()> {
+    let bip39_seed = crate::keystore::copy_bip39_seed(hal)?;
+    let key = derive(&bip39_seed, keypath)?;
+    let hash = zeroize::Zeroizing::new(sha512::Hash::hash(&key[..32]).to_byte_array());
+    Ok(ed25519_dalek::hazmat::ExpandedSecretKey::from_bytes(&hash))
+}
+
+/// Returns the ed25519 public key at the keypath.
+pub fn get_pubkey(hal: &mut impl crate::hal::Hal, keypath: &[u32]) -> Result<[u8; 32], ()> {
+    let secret_key = get_secret_key(hal, keypath)?;
+    Ok(ed25519_dalek::VerifyingKey::from(&secret_key).to_bytes())
+}
+
+/// Signs the message with the key at the keypath, returning the 64 byte signature.
+pub fn sign(hal: &mut impl crate::hal::Hal, keypath: &[u32], msg: &[u8]) -> Result<[u8; 64], ()> {
+    let secret_key = get_secret_key(hal, keypath)?;
+    let public_key = ed25519_dalek::VerifyingKey::from(&secret_key);
+    Ok(ed25519_dalek::hazmat::raw_sign::<Sha512>(&secret_key, msg, &public_key).to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::keystore::testing::mock_unlocked_using_mnemonic;
+    use hex_lit::hex;
+
+    #[test]
+    fn test_derive() {
+        // Test vector 1 from https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+        let seed = hex!("000102030405060708090a0b0c0d0e0f");
+        let key = derive(&seed, &[]).unwrap();
+        assert_eq!(
+            key[..32],
+            hex!("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7")
+        );
+        assert_eq!(
+            key[32..],
+            hex!("90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb")
+        );
+        let key = derive(&seed, &[HARDENED]).unwrap();
+        assert_eq!(
+            key[..32],
+            hex!("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3")
+        );
+        assert_eq!(
+            key[32..],
+            hex!("8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69")
+        );
+
+        // Unhardened derivation is not possible.
+        assert!(derive(&seed, &[HARDENED, 1]).is_err());
+    }
+
+    #[test]
+    fn test_get_pubkey() {
+        let mut mock_hal = crate::hal::testing::TestingHal::new();
+
+        crate::keystore::lock();
+        assert!(get_pubkey(&mut mock_hal, &[44 + HARDENED, 501 + HARDENED]).is_err());
+
+        mock_unlocked_using_mnemonic(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        );
+        // Solana address HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk.
+        assert_eq!(
+            get_pubkey(
+                &mut mock_hal,
+                &[44 + HARDENED, 501 + HARDENED, HARDENED, HARDENED]
+            ),
+            Ok(hex!(
+                "f036276246a75b9de3349ed42b15e232f6518fc20f5fcd4f1d64e81f9bd258f7"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        let mut mock_hal = crate::hal::testing::TestingHal::new();
+        mock_unlocked_using_mnemonic(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        );
+        assert_eq!(
+            sign(
+                &mut mock_hal,
+                &[44 + HARDENED, 501 + HARDENED, HARDENED, HARDENED],
+                b"message"
+            ),
+            Ok(hex!(
+                "a95a567d07d9e29fce2f9f89078a081693ceb48026f0037f13a55fed6af613740da4b2be08c116802dbbfea20c0938fd3a927da70e5dd5e723188a70ad668101"
+            ))
+        );
+    }
+}
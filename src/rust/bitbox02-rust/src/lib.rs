@@ -32,6 +32,7 @@ pub mod main_loop;
 pub mod reset;
 pub mod salt;
 pub mod secp256k1;
+pub mod slip39;
 #[cfg(feature = "app-u2f")]
 mod u2f;
 mod version;
@@ -216,6 +216,16 @@ pub struct SetDeviceNameRequest {
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
 }
+/// Enables or disables blind signing. This is a global setting shared by all apps. It allows signing
+/// data that cannot be fully verified on the device: Ethereum typed messages given only their
+/// precomputed domain separator and message hash (see `ETHSignTypedMessageRequest`), and Solana
+/// transactions containing instructions that cannot be decoded (see `SolanaSignTransactionRequest`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetBlindSigningEnabledRequest {
+    #[prost(bool, tag = "1")]
+    pub enabled: bool,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetPasswordRequest {
@@ -966,10 +976,311 @@ pub struct BtcSignMessageResponse {
     #[prost(bytes = "vec", tag = "1")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
 }
+/// Lightning channel signer. Commitment numbers count up from 0, as in BOLT #3.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BtcLightningNodeIdRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningNodeIdResponse {
+    /// 33 bytes compressed public key.
+    #[prost(bytes = "vec", tag = "1")]
+    pub node_id: ::prost::alloc::vec::Vec<u8>,
+}
+/// BOLT #8 ECDH with the node key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningEcdhRequest {
+    /// 33 bytes compressed public key.
+    #[prost(bytes = "vec", tag = "1")]
+    pub pubkey: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningEcdhResponse {
+    /// SHA256 of the compressed shared point.
+    #[prost(bytes = "vec", tag = "1")]
+    pub shared_secret: ::prost::alloc::vec::Vec<u8>,
+}
+/// Parameters of a channel negotiated in `open_channel` and `accept_channel`, see BOLT #2.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningChannelParameters {
+    /// True if we opened (funded) the channel.
+    #[prost(bool, tag = "1")]
+    pub is_outbound: bool,
+    /// 33 bytes compressed public keys of the counterparty.
+    #[prost(bytes = "vec", tag = "2")]
+    pub counterparty_payment_basepoint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub counterparty_revocation_basepoint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub counterparty_delayed_payment_basepoint: ::prost::alloc::vec::Vec<u8>,
+    /// `to_self_delay` of our to_local output, requested by the counterparty.
+    #[prost(uint32, tag = "5")]
+    pub holder_to_self_delay: u32,
+    /// `to_self_delay` of the counterparty's to_local output, requested by us.
+    #[prost(uint32, tag = "6")]
+    pub counterparty_to_self_delay: u32,
+    /// 33 bytes compressed public key of the counterparty.
+    #[prost(bytes = "vec", tag = "7")]
+    pub counterparty_htlc_basepoint: ::prost::alloc::vec::Vec<u8>,
+}
+/// Returns the basepoints of the channel. If `parameters` is set, they are stored for the channel,
+/// which is required before any of its commitments can be signed. The parameters of a channel
+/// can't be changed once stored, and have to be passed again with every commitment.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningChannelSetupRequest {
+    #[prost(uint32, tag = "1")]
+    pub channel_index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub parameters: ::core::option::Option<BtcLightningChannelParameters>,
+}
+/// All fields are 33 bytes compressed public keys.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningChannelSetupResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub funding_pubkey: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub revocation_basepoint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub payment_basepoint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub delayed_payment_basepoint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub htlc_basepoint: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BtcLightningPerCommitmentPointRequest {
+    #[prost(uint32, tag = "1")]
+    pub channel_index: u32,
+    #[prost(uint64, tag = "2")]
+    pub commitment_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningPerCommitmentPointResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub per_commitment_point: ::prost::alloc::vec::Vec<u8>,
+}
+/// Releases the per-commitment secret of a holder commitment, revoking it. Only holder commitments
+/// older than the last validated one can be revoked.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BtcLightningRevokeRequest {
+    #[prost(uint32, tag = "1")]
+    pub channel_index: u32,
+    #[prost(uint64, tag = "2")]
+    pub commitment_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningRevokeResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub per_commitment_secret: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningOutput {
+    #[prost(uint64, tag = "1")]
+    pub value: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub pubkey_script: ::prost::alloc::vec::Vec<u8>,
+}
+/// Unsigned commitment transaction, spending the funding output. Its outputs can only be the
+/// to_local and to_remote outputs of the commitment and HTLC outputs, see BOLT #3. The transactions
+/// spending all HTLC outputs have to be passed along with the commitment.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningCommitmentTx {
+    #[prost(uint32, tag = "1")]
+    pub version: u32,
+    #[prost(uint32, tag = "2")]
+    pub locktime: u32,
+    /// In the byte order of the serialized transaction.
+    #[prost(bytes = "vec", tag = "3")]
+    pub funding_txid: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "4")]
+    pub funding_output_index: u32,
+    #[prost(uint64, tag = "5")]
+    pub funding_value: u64,
+    #[prost(uint32, tag = "6")]
+    pub sequence: u32,
+    #[prost(message, repeated, tag = "7")]
+    pub outputs: ::prost::alloc::vec::Vec<BtcLightningOutput>,
+    /// 33 bytes compressed public key.
+    #[prost(bytes = "vec", tag = "8")]
+    pub counterparty_funding_pubkey: ::prost::alloc::vec::Vec<u8>,
+}
+/// Unsigned HTLC-timeout or HTLC-success transaction, spending an HTLC output of the commitment
+/// transaction. The script of the HTLC output, the locktime and the output script of the HTLC
+/// transaction are derived from these fields and the keys of the commitment, see BOLT #3.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningHtlcTx {
+    #[prost(uint32, tag = "1")]
+    pub commitment_output_index: u32,
+    /// True if the HTLC is offered by the owner of the commitment, i.e. this is its HTLC-timeout
+    /// transaction. Otherwise, it is the HTLC-success transaction of an HTLC received by the owner.
+    #[prost(bool, tag = "2")]
+    pub offered: bool,
+    /// 32 bytes SHA256 hash of the payment preimage.
+    #[prost(bytes = "vec", tag = "3")]
+    pub payment_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Block height at which the HTLC times out.
+    #[prost(uint32, tag = "4")]
+    pub cltv_expiry: u32,
+    /// Value of the output of the HTLC transaction, i.e. the value of the HTLC output minus the fee.
+    #[prost(uint64, tag = "5")]
+    pub output_value: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningSignCounterpartyCommitmentRequest {
+    #[prost(uint32, tag = "1")]
+    pub channel_index: u32,
+    #[prost(uint64, tag = "2")]
+    pub commitment_number: u64,
+    /// Per-commitment point of the counterparty for this commitment.
+    #[prost(bytes = "vec", tag = "3")]
+    pub per_commitment_point: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub tx: ::core::option::Option<BtcLightningCommitmentTx>,
+    #[prost(message, repeated, tag = "5")]
+    pub htlc_txs: ::prost::alloc::vec::Vec<BtcLightningHtlcTx>,
+    #[prost(message, optional, tag = "6")]
+    pub parameters: ::core::option::Option<BtcLightningChannelParameters>,
+}
+/// Validates a new holder commitment received from the counterparty, including the counterparty's
+/// signature. Holder commitments are validated in order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningValidateHolderCommitmentRequest {
+    #[prost(uint32, tag = "1")]
+    pub channel_index: u32,
+    #[prost(uint64, tag = "2")]
+    pub commitment_number: u64,
+    #[prost(message, optional, tag = "3")]
+    pub tx: ::core::option::Option<BtcLightningCommitmentTx>,
+    #[prost(message, repeated, tag = "4")]
+    pub htlc_txs: ::prost::alloc::vec::Vec<BtcLightningHtlcTx>,
+    #[prost(message, optional, tag = "5")]
+    pub parameters: ::core::option::Option<BtcLightningChannelParameters>,
+    /// 64 bytes (32 bytes big endian R, 32 bytes big endian S) signature of the counterparty.
+    #[prost(bytes = "vec", tag = "6")]
+    pub counterparty_signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BtcLightningValidateHolderCommitmentResponse {}
+/// Signs a validated holder commitment for broadcasting it, force closing the channel.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningSignHolderCommitmentRequest {
+    #[prost(uint32, tag = "1")]
+    pub channel_index: u32,
+    #[prost(uint64, tag = "2")]
+    pub commitment_number: u64,
+    #[prost(message, optional, tag = "3")]
+    pub tx: ::core::option::Option<BtcLightningCommitmentTx>,
+    #[prost(message, repeated, tag = "4")]
+    pub htlc_txs: ::prost::alloc::vec::Vec<BtcLightningHtlcTx>,
+    #[prost(message, optional, tag = "5")]
+    pub parameters: ::core::option::Option<BtcLightningChannelParameters>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningSignCommitmentResponse {
+    /// 64 bytes (32 bytes big endian R, 32 bytes big endian S).
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    /// One signature per HTLC transaction, in the same order.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub htlc_signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// Frees the slots of all closed channels with an index below `below_channel_index`. Their indices
+/// can't be set up again. Only forget channels whose closing transaction is deeply confirmed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BtcLightningForgetChannelsRequest {
+    #[prost(uint32, tag = "1")]
+    pub below_channel_index: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BtcLightningForgetChannelsResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningRequest {
+    #[prost(enumeration = "BtcCoin", tag = "1")]
+    pub coin: i32,
+    #[prost(
+        oneof = "btc_lightning_request::Request",
+        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10"
+    )]
+    pub request: ::core::option::Option<btc_lightning_request::Request>,
+}
+/// Nested message and enum types in `BTCLightningRequest`.
+pub mod btc_lightning_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Request {
+        #[prost(message, tag = "2")]
+        NodeId(super::BtcLightningNodeIdRequest),
+        #[prost(message, tag = "3")]
+        Ecdh(super::BtcLightningEcdhRequest),
+        #[prost(message, tag = "4")]
+        ChannelSetup(super::BtcLightningChannelSetupRequest),
+        #[prost(message, tag = "5")]
+        PerCommitmentPoint(super::BtcLightningPerCommitmentPointRequest),
+        #[prost(message, tag = "6")]
+        Revoke(super::BtcLightningRevokeRequest),
+        #[prost(message, tag = "7")]
+        SignCounterpartyCommitment(super::BtcLightningSignCounterpartyCommitmentRequest),
+        #[prost(message, tag = "8")]
+        SignHolderCommitment(super::BtcLightningSignHolderCommitmentRequest),
+        #[prost(message, tag = "9")]
+        ValidateHolderCommitment(super::BtcLightningValidateHolderCommitmentRequest),
+        #[prost(message, tag = "10")]
+        ForgetChannels(super::BtcLightningForgetChannelsRequest),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BtcLightningResponse {
+    #[prost(oneof = "btc_lightning_response::Response", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub response: ::core::option::Option<btc_lightning_response::Response>,
+}
+/// Nested message and enum types in `BTCLightningResponse`.
+pub mod btc_lightning_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        NodeId(super::BtcLightningNodeIdResponse),
+        #[prost(message, tag = "2")]
+        Ecdh(super::BtcLightningEcdhResponse),
+        #[prost(message, tag = "3")]
+        ChannelSetup(super::BtcLightningChannelSetupResponse),
+        #[prost(message, tag = "4")]
+        PerCommitmentPoint(super::BtcLightningPerCommitmentPointResponse),
+        #[prost(message, tag = "5")]
+        Revoke(super::BtcLightningRevokeResponse),
+        #[prost(message, tag = "6")]
+        SignCommitment(super::BtcLightningSignCommitmentResponse),
+        #[prost(message, tag = "7")]
+        ValidateHolderCommitment(super::BtcLightningValidateHolderCommitmentResponse),
+        #[prost(message, tag = "8")]
+        ForgetChannels(super::BtcLightningForgetChannelsResponse),
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BtcRequest {
-    #[prost(oneof = "btc_request::Request", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
+    #[prost(oneof = "btc_request::Request", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
     pub request: ::core::option::Option<btc_request::Request>,
 }
 /// Nested message and enum types in `BTCRequest`.
@@ -995,12 +1306,14 @@ pub mod btc_request {
         PaymentRequest(super::BtcPaymentRequestRequest),
         #[prost(message, tag = "9")]
         Xpubs(super::BtcXpubsRequest),
+        #[prost(message, tag = "10")]
+        Lightning(super::BtcLightningRequest),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BtcResponse {
-    #[prost(oneof = "btc_response::Response", tags = "1, 2, 3, 4, 5, 6")]
+    #[prost(oneof = "btc_response::Response", tags = "1, 2, 3, 4, 5, 6, 7")]
     pub response: ::core::option::Option<btc_response::Response>,
 }
 /// Nested message and enum types in `BTCResponse`.
@@ -1020,6 +1333,8 @@ pub mod btc_response {
         AntikleptoSignerCommitment(super::AntiKleptoSignerCommitment),
         #[prost(message, tag = "6")]
         Pubs(super::PubsResponse),
+        #[prost(message, tag = "7")]
+        Lightning(super::BtcLightningResponse),
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -1104,6 +1419,8 @@ impl BtcOutputType {
 pub struct CardanoXpubsRequest {
     #[prost(message, repeated, tag = "1")]
     pub keypaths: ::prost::alloc::vec::Vec<Keypath>,
+    #[prost(enumeration = "CardanoDerivationType", tag = "2")]
+    pub derivation_type: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1111,6 +1428,62 @@ pub struct CardanoXpubsResponse {
     #[prost(bytes = "vec", repeated, tag = "1")]
     pub xpubs: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+/// Native script, used for multisig wallets according to CIP-1854.
+/// See <https://github.com/cardano-foundation/CIPs/blob/master/CIP-1854/README.md> and
+/// <https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/allegra/impl/cddl-files/allegra.cddl>
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardanoNativeScript {
+    #[prost(oneof = "cardano_native_script::Script", tags = "1, 2, 3, 4, 5, 6")]
+    pub script: ::core::option::Option<cardano_native_script::Script>,
+}
+/// Nested message and enum types in `CardanoNativeScript`.
+pub mod cardano_native_script {
+    /// Exactly one of the fields must be set.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PubKey {
+        /// Key of this wallet, m/1854'/1815'/account'/role/index.
+        #[prost(uint32, repeated, tag = "1")]
+        pub keypath: ::prost::alloc::vec::Vec<u32>,
+        /// Key hash of a cosigner.
+        ///
+        /// 28 bytes
+        #[prost(bytes = "vec", tag = "2")]
+        pub key_hash: ::prost::alloc::vec::Vec<u8>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Scripts {
+        #[prost(message, repeated, tag = "1")]
+        pub scripts: ::prost::alloc::vec::Vec<super::CardanoNativeScript>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NOfK {
+        #[prost(uint32, tag = "1")]
+        pub n: u32,
+        #[prost(message, repeated, tag = "2")]
+        pub scripts: ::prost::alloc::vec::Vec<super::CardanoNativeScript>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Script {
+        #[prost(message, tag = "1")]
+        Pubkey(PubKey),
+        #[prost(message, tag = "2")]
+        All(Scripts),
+        #[prost(message, tag = "3")]
+        Any(Scripts),
+        #[prost(message, tag = "4")]
+        NOfK(NOfK),
+        /// Slot numbers.
+        #[prost(uint64, tag = "5")]
+        InvalidBefore(u64),
+        #[prost(uint64, tag = "6")]
+        InvalidHereafter(u64),
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CardanoScriptConfig {
@@ -1118,7 +1491,7 @@ pub struct CardanoScriptConfig {
     /// <https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0019/CIP-0019.md>
     /// See also:
     /// <https://github.com/input-output-hk/cardano-ledger-specs/blob/d0aa86ded0b973b09b629e5aa62aa1e71364d088/eras/alonzo/test-suite/cddl-files/alonzo.cddl#L137>
-    #[prost(oneof = "cardano_script_config::Config", tags = "1")]
+    #[prost(oneof = "cardano_script_config::Config", tags = "1, 2, 3, 4, 5")]
     pub config: ::core::option::Option<cardano_script_config::Config>,
 }
 /// Nested message and enum types in `CardanoScriptConfig`.
@@ -1131,6 +1504,47 @@ pub mod cardano_script_config {
         #[prost(uint32, repeated, tag = "2")]
         pub keypath_stake: ::prost::alloc::vec::Vec<u32>,
     }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Pkh {
+        #[prost(uint32, repeated, tag = "1")]
+        pub keypath_payment: ::prost::alloc::vec::Vec<u32>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Skh {
+        #[prost(uint32, repeated, tag = "1")]
+        pub keypath_stake: ::prost::alloc::vec::Vec<u32>,
+    }
+    /// Refers to the stake registration certificate at the given position in the chain.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Pointer {
+        #[prost(uint64, tag = "1")]
+        pub slot: u64,
+        #[prost(uint64, tag = "2")]
+        pub tx_index: u64,
+        #[prost(uint64, tag = "3")]
+        pub cert_index: u64,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PkhPointer {
+        #[prost(uint32, repeated, tag = "1")]
+        pub keypath_payment: ::prost::alloc::vec::Vec<u32>,
+        #[prost(message, optional, tag = "2")]
+        pub pointer: ::core::option::Option<Pointer>,
+    }
+    /// Payment script using role 0 keys and stake script using role 2 keys. If there is no stake
+    /// script, it is an enterprise address without staking rights.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NativeScripts {
+        #[prost(message, optional, tag = "1")]
+        pub payment_script: ::core::option::Option<super::CardanoNativeScript>,
+        #[prost(message, optional, tag = "2")]
+        pub stake_script: ::core::option::Option<super::CardanoNativeScript>,
+    }
     /// Entries correspond to address types as described in:
     /// <https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0019/CIP-0019.md>
     /// See also:
@@ -1141,10 +1555,58 @@ pub mod cardano_script_config {
         /// Shelley PaymentKeyHash & StakeKeyHash
         #[prost(message, tag = "1")]
         PkhSkh(PkhSkh),
+        /// Shelley ScriptHash & ScriptHash, or ScriptHash only. Must be registered using
+        /// `CardanoRegisterScriptConfigRequest`.
+        #[prost(message, tag = "2")]
+        NativeScripts(NativeScripts),
+        /// Shelley PaymentKeyHash only (enterprise address)
+        #[prost(message, tag = "3")]
+        Pkh(Pkh),
+        /// Shelley StakeKeyHash (reward address). Not a payment address, so it can't be used as a
+        /// transaction output.
+        #[prost(message, tag = "4")]
+        Skh(Skh),
+        /// Shelley PaymentKeyHash & Pointer
+        #[prost(message, tag = "5")]
+        PkhPointer(PkhPointer),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardanoScriptConfigRegistration {
+    #[prost(enumeration = "CardanoNetwork", tag = "1")]
+    pub network: i32,
+    #[prost(message, optional, tag = "2")]
+    pub script_config: ::core::option::Option<CardanoScriptConfig>,
+    #[prost(enumeration = "CardanoDerivationType", tag = "3")]
+    pub derivation_type: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardanoIsScriptConfigRegisteredRequest {
+    #[prost(message, optional, tag = "1")]
+    pub registration: ::core::option::Option<CardanoScriptConfigRegistration>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CardanoIsScriptConfigRegisteredResponse {
+    #[prost(bool, tag = "1")]
+    pub is_registered: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardanoRegisterScriptConfigRequest {
+    #[prost(message, optional, tag = "1")]
+    pub registration: ::core::option::Option<CardanoScriptConfigRegistration>,
+    /// If empty, the name is entered on the device instead.
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CardanoSuccess {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CardanoAddressRequest {
     #[prost(enumeration = "CardanoNetwork", tag = "1")]
     pub network: i32,
@@ -1152,6 +1614,8 @@ pub struct CardanoAddressRequest {
     pub display: bool,
     #[prost(message, optional, tag = "3")]
     pub script_config: ::core::option::Option<CardanoScriptConfig>,
+    #[prost(enumeration = "CardanoDerivationType", tag = "4")]
+    pub derivation_type: i32,
 }
 /// Max allowed transaction size is 16384 bytes according to
 /// <https://github.com/cardano-foundation/CIPs/blob/master/CIP-0009/CIP-0009.md.> Unlike with BTC, we
@@ -1188,12 +1652,54 @@ pub struct CardanoSignTransactionRequest {
     /// See <https://github.com/IntersectMBO/cardano-ledger/blob/6e2d37cc0f47bd02e89b4ce9f78b59c35c958e96/eras/conway/impl/cddl-files/extra.cddl#L5>
     #[prost(bool, tag = "10")]
     pub tag_cbor_sets: bool,
+    #[prost(message, optional, tag = "11")]
+    pub auxiliary_data: ::core::option::Option<
+        cardano_sign_transaction_request::AuxiliaryData,
+    >,
+    #[prost(message, repeated, tag = "12")]
+    pub mint: ::prost::alloc::vec::Vec<cardano_sign_transaction_request::MintAssetGroup>,
+    /// Minting policy keys (m/1855'/1815'/policy') to sign the transaction with. Only allowed if
+    /// `mint` is not empty.
+    #[prost(message, repeated, tag = "13")]
+    pub policy_keypaths: ::prost::alloc::vec::Vec<Keypath>,
+    /// Fields to interact with Plutus scripts. Collateral inputs and the collateral return must
+    /// belong to this wallet.
+    ///
+    /// 32 bytes
+    #[prost(bytes = "vec", tag = "14")]
+    pub script_data_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, repeated, tag = "15")]
+    pub collateral_inputs: ::prost::alloc::vec::Vec<
+        cardano_sign_transaction_request::Input,
+    >,
+    #[prost(message, optional, tag = "16")]
+    pub collateral_return: ::core::option::Option<
+        cardano_sign_transaction_request::Output,
+    >,
+    #[prost(uint64, optional, tag = "17")]
+    pub total_collateral: ::core::option::Option<u64>,
+    #[prost(message, repeated, tag = "18")]
+    pub required_signers: ::prost::alloc::vec::Vec<
+        cardano_sign_transaction_request::RequiredSigner,
+    >,
+    #[prost(message, repeated, tag = "19")]
+    pub reference_inputs: ::prost::alloc::vec::Vec<
+        cardano_sign_transaction_request::Input,
+    >,
+    #[prost(message, optional, tag = "20")]
+    pub voting_procedures: ::core::option::Option<
+        cardano_sign_transaction_request::VotingProcedures,
+    >,
+    #[prost(enumeration = "CardanoDerivationType", tag = "21")]
+    pub derivation_type: i32,
 }
 /// Nested message and enum types in `CardanoSignTransactionRequest`.
 pub mod cardano_sign_transaction_request {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Input {
+        /// Empty for inputs not spent by this wallet, i.e. reference inputs and inputs locked by a
+        /// script (only allowed if `script_data_hash` is set).
         #[prost(uint32, repeated, tag = "1")]
         pub keypath: ::prost::alloc::vec::Vec<u32>,
         #[prost(bytes = "vec", tag = "2")]
@@ -1222,24 +1728,71 @@ pub mod cardano_sign_transaction_request {
             pub value: u64,
         }
     }
+    /// Native tokens minted or burned under one policy.
+    /// See <https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl#L501>
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct Output {
-        #[prost(string, tag = "1")]
-        pub encoded_address: ::prost::alloc::string::String,
-        #[prost(uint64, tag = "2")]
-        pub value: u64,
-        /// Optional. If provided, this is validated as a change output.
-        #[prost(message, optional, tag = "3")]
-        pub script_config: ::core::option::Option<super::CardanoScriptConfig>,
-        #[prost(message, repeated, tag = "4")]
-        pub asset_groups: ::prost::alloc::vec::Vec<AssetGroup>,
-    }
-    /// See <https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl#L273>
+    pub struct MintAssetGroup {
+        #[prost(bytes = "vec", tag = "1")]
+        pub policy_id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(message, repeated, tag = "2")]
+        pub tokens: ::prost::alloc::vec::Vec<mint_asset_group::Token>,
+    }
+    /// Nested message and enum types in `MintAssetGroup`.
+    pub mod mint_asset_group {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Token {
+            #[prost(bytes = "vec", tag = "1")]
+            pub asset_name: ::prost::alloc::vec::Vec<u8>,
+            /// Positive to mint, negative to burn. Must not be zero.
+            #[prost(sint64, tag = "2")]
+            pub value: i64,
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Output {
+        #[prost(string, tag = "1")]
+        pub encoded_address: ::prost::alloc::string::String,
+        #[prost(uint64, tag = "2")]
+        pub value: u64,
+        /// Optional. If provided, this is validated as a change output. Enterprise and pointer
+        /// addresses are not accepted as change.
+        #[prost(message, optional, tag = "3")]
+        pub script_config: ::core::option::Option<super::CardanoScriptConfig>,
+        #[prost(message, repeated, tag = "4")]
+        pub asset_groups: ::prost::alloc::vec::Vec<AssetGroup>,
+        /// At most one of `datum_hash` and `inline_datum` can be set. If one is set, the output is
+        /// serialized in the post-Alonzo map format, otherwise in the legacy array format.
+        ///
+        /// 32 bytes
+        #[prost(bytes = "vec", tag = "5")]
+        pub datum_hash: ::prost::alloc::vec::Vec<u8>,
+        /// CBOR encoded Plutus data.
+        #[prost(bytes = "vec", tag = "6")]
+        pub inline_datum: ::prost::alloc::vec::Vec<u8>,
+    }
+    /// Governance metadata anchor.
+    /// See <https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl>
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Anchor {
+        /// At most 128 bytes.
+        #[prost(string, tag = "1")]
+        pub url: ::prost::alloc::string::String,
+        /// 32 bytes
+        #[prost(bytes = "vec", tag = "2")]
+        pub data_hash: ::prost::alloc::vec::Vec<u8>,
+    }
+    /// See <https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl#L273>
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Certificate {
-        #[prost(oneof = "certificate::Cert", tags = "1, 2, 3, 10")]
+        #[prost(
+            oneof = "certificate::Cert",
+            tags = "1, 2, 3, 10, 11, 12, 13, 14, 15, 16"
+        )]
         pub cert: ::core::option::Option<certificate::Cert>,
     }
     /// Nested message and enum types in `Certificate`.
@@ -1251,6 +1804,9 @@ pub mod cardano_sign_transaction_request {
             pub keypath: ::prost::alloc::vec::Vec<u32>,
             #[prost(bytes = "vec", tag = "2")]
             pub pool_keyhash: ::prost::alloc::vec::Vec<u8>,
+            /// If set, the stake key is registered in the same certificate (stake_reg_deleg_cert).
+            #[prost(uint64, optional, tag = "3")]
+            pub deposit: ::core::option::Option<u64>,
         }
         #[allow(clippy::derive_partial_eq_without_eq)]
         #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1262,6 +1818,9 @@ pub mod cardano_sign_transaction_request {
             pub r#type: i32,
             #[prost(bytes = "vec", optional, tag = "3")]
             pub drep_credhash: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+            /// If set, the stake key is registered in the same certificate (vote_reg_deleg_cert).
+            #[prost(uint64, optional, tag = "4")]
+            pub deposit: ::core::option::Option<u64>,
         }
         /// Nested message and enum types in `VoteDelegation`.
         pub mod vote_delegation {
@@ -1308,6 +1867,50 @@ pub mod cardano_sign_transaction_request {
                 }
             }
         }
+        /// Delegation of both staking and voting (stake_vote_deleg_cert).
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct StakeVoteDelegation {
+            #[prost(uint32, repeated, tag = "1")]
+            pub keypath: ::prost::alloc::vec::Vec<u32>,
+            #[prost(bytes = "vec", tag = "2")]
+            pub pool_keyhash: ::prost::alloc::vec::Vec<u8>,
+            #[prost(enumeration = "vote_delegation::CardanoDRepType", tag = "3")]
+            pub drep_type: i32,
+            #[prost(bytes = "vec", optional, tag = "4")]
+            pub drep_credhash: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+            /// If set, the stake key is registered in the same certificate (stake_vote_reg_deleg_cert).
+            #[prost(uint64, optional, tag = "5")]
+            pub deposit: ::core::option::Option<u64>,
+        }
+        /// Stake key (reg_cert, unreg_cert) or DRep key (unreg_drep_cert) with an explicit deposit.
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct KeypathDeposit {
+            #[prost(uint32, repeated, tag = "1")]
+            pub keypath: ::prost::alloc::vec::Vec<u32>,
+            #[prost(uint64, tag = "2")]
+            pub deposit: u64,
+        }
+        /// keypath refers to the DRep key m/1852'/1815'/account'/3/0.
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DRepRegistration {
+            #[prost(uint32, repeated, tag = "1")]
+            pub keypath: ::prost::alloc::vec::Vec<u32>,
+            #[prost(uint64, tag = "2")]
+            pub deposit: u64,
+            #[prost(message, optional, tag = "3")]
+            pub anchor: ::core::option::Option<super::Anchor>,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct DRepUpdate {
+            #[prost(uint32, repeated, tag = "1")]
+            pub keypath: ::prost::alloc::vec::Vec<u32>,
+            #[prost(message, optional, tag = "2")]
+            pub anchor: ::core::option::Option<super::Anchor>,
+        }
         #[allow(clippy::derive_partial_eq_without_eq)]
         #[derive(Clone, PartialEq, ::prost::Oneof)]
         pub enum Cert {
@@ -1319,6 +1922,89 @@ pub mod cardano_sign_transaction_request {
             StakeDelegation(StakeDelegation),
             #[prost(message, tag = "10")]
             VoteDelegation(VoteDelegation),
+            #[prost(message, tag = "11")]
+            StakeRegistrationDeposit(KeypathDeposit),
+            #[prost(message, tag = "12")]
+            StakeDeregistrationDeposit(KeypathDeposit),
+            #[prost(message, tag = "13")]
+            StakeVoteDelegation(StakeVoteDelegation),
+            #[prost(message, tag = "14")]
+            DrepRegistration(DRepRegistration),
+            #[prost(message, tag = "15")]
+            DrepDeregistration(KeypathDeposit),
+            #[prost(message, tag = "16")]
+            DrepUpdate(DRepUpdate),
+        }
+    }
+    /// Votes on governance actions cast with the DRep key of this wallet.
+    /// See <https://github.com/IntersectMBO/cardano-ledger/blob/cardano-ledger-conway-1.12.0.0/eras/conway/impl/cddl-files/conway.cddl>
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct VotingProcedures {
+        /// DRep key m/1852'/1815'/account'/3/0.
+        #[prost(uint32, repeated, tag = "1")]
+        pub drep_keypath: ::prost::alloc::vec::Vec<u32>,
+        #[prost(message, repeated, tag = "2")]
+        pub votes: ::prost::alloc::vec::Vec<voting_procedures::Vote>,
+    }
+    /// Nested message and enum types in `VotingProcedures`.
+    pub mod voting_procedures {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Vote {
+            /// Governance action ID.
+            ///
+            /// 32 bytes
+            #[prost(bytes = "vec", tag = "1")]
+            pub gov_action_tx_hash: ::prost::alloc::vec::Vec<u8>,
+            #[prost(uint32, tag = "2")]
+            pub gov_action_index: u32,
+            #[prost(enumeration = "vote::Choice", tag = "3")]
+            pub choice: i32,
+            #[prost(message, optional, tag = "4")]
+            pub anchor: ::core::option::Option<super::Anchor>,
+        }
+        /// Nested message and enum types in `Vote`.
+        pub mod vote {
+            #[derive(
+                Clone,
+                Copy,
+                Debug,
+                PartialEq,
+                Eq,
+                Hash,
+                PartialOrd,
+                Ord,
+                ::prost::Enumeration
+            )]
+            #[repr(i32)]
+            pub enum Choice {
+                No = 0,
+                Yes = 1,
+                Abstain = 2,
+            }
+            impl Choice {
+                /// String value of the enum field names used in the ProtoBuf definition.
+                ///
+                /// The values are not transformed in any way and thus are considered stable
+                /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+                pub fn as_str_name(&self) -> &'static str {
+                    match self {
+                        Choice::No => "NO",
+                        Choice::Yes => "YES",
+                        Choice::Abstain => "ABSTAIN",
+                    }
+                }
+                /// Creates an enum from field names used in the ProtoBuf definition.
+                pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+                    match value {
+                        "NO" => Some(Self::No),
+                        "YES" => Some(Self::Yes),
+                        "ABSTAIN" => Some(Self::Abstain),
+                        _ => None,
+                    }
+                }
+            }
         }
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1329,6 +2015,84 @@ pub mod cardano_sign_transaction_request {
         #[prost(uint64, tag = "2")]
         pub value: u64,
     }
+    /// Auxiliary data (transaction metadata). The device computes the auxiliary data hash included in
+    /// the transaction body (map entry 7) and returns it, see `AuxiliaryDataSupplement`.
+    ///
+    /// For `cip20_message` and `cip36_registration`, the host must serialize the auxiliary data
+    /// exactly as the device does: a metadata map (no auxiliary scripts) in canonical CBOR.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AuxiliaryData {
+        #[prost(oneof = "auxiliary_data::Data", tags = "1, 2, 3")]
+        pub data: ::core::option::Option<auxiliary_data::Data>,
+    }
+    /// Nested message and enum types in `AuxiliaryData`.
+    pub mod auxiliary_data {
+        /// CIP-20 transaction message, serialized as {674: {"msg": \[lines...\]}}.
+        /// See <https://github.com/cardano-foundation/CIPs/blob/master/CIP-0020/README.md>
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Cip20Message {
+            /// Each line is at most 64 bytes.
+            #[prost(string, repeated, tag = "1")]
+            pub lines: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+        }
+        /// CIP-36 vote key registration, serialized as {61284: registration, 61285: {1: signature}}.
+        /// The device signs the registration with the stake key.
+        /// See <https://github.com/cardano-foundation/CIPs/blob/master/CIP-0036/README.md>
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Cip36Registration {
+            #[prost(message, repeated, tag = "1")]
+            pub delegations: ::prost::alloc::vec::Vec<cip36_registration::Delegation>,
+            #[prost(uint32, repeated, tag = "2")]
+            pub stake_keypath: ::prost::alloc::vec::Vec<u32>,
+            /// Address receiving the voting rewards. Must be an address of this wallet.
+            #[prost(message, optional, tag = "3")]
+            pub payment_address: ::core::option::Option<
+                super::super::CardanoScriptConfig,
+            >,
+            #[prost(uint64, tag = "4")]
+            pub nonce: u64,
+            /// 0 is Catalyst.
+            #[prost(uint64, tag = "5")]
+            pub voting_purpose: u64,
+        }
+        /// Nested message and enum types in `CIP36Registration`.
+        pub mod cip36_registration {
+            #[allow(clippy::derive_partial_eq_without_eq)]
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Delegation {
+                /// 32 bytes
+                #[prost(bytes = "vec", tag = "1")]
+                pub vote_key: ::prost::alloc::vec::Vec<u8>,
+                #[prost(uint32, tag = "2")]
+                pub weight: u32,
+            }
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Data {
+            /// Hash of arbitrary auxiliary data serialized by the host. It is shown to the user as is.
+            #[prost(bytes, tag = "1")]
+            Hash(::prost::alloc::vec::Vec<u8>),
+            #[prost(message, tag = "2")]
+            Cip20Message(Cip20Message),
+            #[prost(message, tag = "3")]
+            Cip36Registration(Cip36Registration),
+        }
+    }
+    /// Signer required by a Plutus script. Exactly one of the fields must be set. If `keypath` is
+    /// set, the transaction is signed with the key at the keypath.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RequiredSigner {
+        #[prost(uint32, repeated, tag = "1")]
+        pub keypath: ::prost::alloc::vec::Vec<u32>,
+        /// 28 bytes
+        #[prost(bytes = "vec", tag = "2")]
+        pub key_hash: ::prost::alloc::vec::Vec<u8>,
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1337,6 +2101,11 @@ pub struct CardanoSignTransactionResponse {
     pub shelley_witnesses: ::prost::alloc::vec::Vec<
         cardano_sign_transaction_response::ShelleyWitness,
     >,
+    /// Only present if `auxiliary_data` was provided in the request.
+    #[prost(message, optional, tag = "2")]
+    pub auxiliary_data_supplement: ::core::option::Option<
+        cardano_sign_transaction_response::AuxiliaryDataSupplement,
+    >,
 }
 /// Nested message and enum types in `CardanoSignTransactionResponse`.
 pub mod cardano_sign_transaction_response {
@@ -1348,11 +2117,51 @@ pub mod cardano_sign_transaction_response {
         #[prost(bytes = "vec", tag = "2")]
         pub signature: ::prost::alloc::vec::Vec<u8>,
     }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AuxiliaryDataSupplement {
+        /// Blake2b-256 hash of the serialized auxiliary data.
+        #[prost(bytes = "vec", tag = "1")]
+        pub auxiliary_data_hash: ::prost::alloc::vec::Vec<u8>,
+        /// Signature of the CIP-36 registration, only present for `cip36_registration`.
+        #[prost(bytes = "vec", tag = "2")]
+        pub cip36_signature: ::prost::alloc::vec::Vec<u8>,
+    }
+}
+/// Signs a message according to CIP-8, as used by the CIP-30 `signData` dapp connector endpoint.
+/// Exactly one of `script_config` and `stake_keypath` must be set.
+/// See <https://cips.cardano.org/cip/CIP-0008> and <https://cips.cardano.org/cip/CIP-0030.>
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardanoSignMessageRequest {
+    #[prost(enumeration = "CardanoNetwork", tag = "1")]
+    pub network: i32,
+    /// Base address (`pkh_skh` only). The message is signed with its payment key.
+    #[prost(message, optional, tag = "2")]
+    pub script_config: ::core::option::Option<CardanoScriptConfig>,
+    /// Stake key (m/1852'/1815'/account'/2/0). The message is signed with this key, and its reward
+    /// address is used.
+    #[prost(uint32, repeated, tag = "3")]
+    pub stake_keypath: ::prost::alloc::vec::Vec<u32>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub payload: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration = "CardanoDerivationType", tag = "5")]
+    pub derivation_type: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardanoSignMessageResponse {
+    /// CBOR encoded COSE_Sign1 structure.
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    /// CBOR encoded COSE_Key of the public key.
+    #[prost(bytes = "vec", tag = "2")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CardanoRequest {
-    #[prost(oneof = "cardano_request::Request", tags = "1, 2, 3")]
+    #[prost(oneof = "cardano_request::Request", tags = "1, 2, 3, 4, 5, 6")]
     pub request: ::core::option::Option<cardano_request::Request>,
 }
 /// Nested message and enum types in `CardanoRequest`.
@@ -1366,12 +2175,18 @@ pub mod cardano_request {
         Address(super::CardanoAddressRequest),
         #[prost(message, tag = "3")]
         SignTransaction(super::CardanoSignTransactionRequest),
+        #[prost(message, tag = "4")]
+        IsScriptConfigRegistered(super::CardanoIsScriptConfigRegisteredRequest),
+        #[prost(message, tag = "5")]
+        RegisterScriptConfig(super::CardanoRegisterScriptConfigRequest),
+        #[prost(message, tag = "6")]
+        SignMessage(super::CardanoSignMessageRequest),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CardanoResponse {
-    #[prost(oneof = "cardano_response::Response", tags = "1, 2, 3")]
+    #[prost(oneof = "cardano_response::Response", tags = "1, 2, 3, 4, 5, 6")]
     pub response: ::core::option::Option<cardano_response::Response>,
 }
 /// Nested message and enum types in `CardanoResponse`.
@@ -1385,6 +2200,50 @@ pub mod cardano_response {
         Pub(super::PubResponse),
         #[prost(message, tag = "3")]
         SignTransaction(super::CardanoSignTransactionResponse),
+        #[prost(message, tag = "4")]
+        IsScriptConfigRegistered(super::CardanoIsScriptConfigRegisteredResponse),
+        #[prost(message, tag = "5")]
+        Success(super::CardanoSuccess),
+        #[prost(message, tag = "6")]
+        SignMessage(super::CardanoSignMessageResponse),
+    }
+}
+/// Derivation of the root key from the BIP-39 mnemonic, see
+/// <https://github.com/cardano-foundation/CIPs/blob/6c249ef48f8f5b32efc0ec768fadf4321f3173f2/CIP-0003/CIP-0003.md>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CardanoDerivationType {
+    /// Compatible with Ledger.
+    CardanoDerivationLedger = 0,
+    /// Compatible with most software wallets, e.g. Yoroi and Eternl.
+    CardanoDerivationIcarus = 1,
+    /// Same as Icarus, except for 24 word mnemonics. Compatible with Trezor.
+    CardanoDerivationIcarusTrezor = 2,
+}
+impl CardanoDerivationType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CardanoDerivationType::CardanoDerivationLedger => "CARDANO_DERIVATION_LEDGER",
+            CardanoDerivationType::CardanoDerivationIcarus => "CARDANO_DERIVATION_ICARUS",
+            CardanoDerivationType::CardanoDerivationIcarusTrezor => {
+                "CARDANO_DERIVATION_ICARUS_TREZOR"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CARDANO_DERIVATION_LEDGER" => Some(Self::CardanoDerivationLedger),
+            "CARDANO_DERIVATION_ICARUS" => Some(Self::CardanoDerivationIcarus),
+            "CARDANO_DERIVATION_ICARUS_TREZOR" => {
+                Some(Self::CardanoDerivationIcarusTrezor)
+            }
+            _ => None,
+        }
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -1470,6 +2329,54 @@ pub mod eth_pub_request {
         }
     }
 }
+/// Retrieves many addresses or xpubs at once, without displaying them.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthPubsRequest {
+    #[prost(enumeration = "eth_pubs_request::OutputType", tag = "1")]
+    pub output_type: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub keypaths: ::prost::alloc::vec::Vec<Keypath>,
+}
+/// Nested message and enum types in `ETHPubsRequest`.
+pub mod eth_pubs_request {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum OutputType {
+        Address = 0,
+        Xpub = 1,
+    }
+    impl OutputType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                OutputType::Address => "ADDRESS",
+                OutputType::Xpub => "XPUB",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "ADDRESS" => Some(Self::Address),
+                "XPUB" => Some(Self::Xpub),
+                _ => None,
+            }
+        }
+    }
+}
 /// TX payload for "legacy" (EIP-155) transactions: <https://eips.ethereum.org/EIPS/eip-155>
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1543,6 +2450,49 @@ pub struct EthSignEip1559Request {
     #[prost(uint32, tag = "12")]
     pub data_length: u32,
 }
+/// TX payload for an EIP-4844 (type 3) blob transaction: <https://eips.ethereum.org/EIPS/eip-4844>
+/// The blobs themselves are not part of the signed transaction, only their versioned hashes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthSignEip4844Request {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    #[prost(uint32, repeated, tag = "2")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "3")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "4")]
+    pub max_priority_fee_per_gas: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "5")]
+    pub max_fee_per_gas: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "6")]
+    pub gas_limit: ::prost::alloc::vec::Vec<u8>,
+    /// 20 byte recipient
+    #[prost(bytes = "vec", tag = "7")]
+    pub recipient: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 32 bytes
+    #[prost(bytes = "vec", tag = "8")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "9")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "10")]
+    pub host_nonce_commitment: ::core::option::Option<AntiKleptoHostNonceCommitment>,
+    #[prost(enumeration = "EthAddressCase", tag = "11")]
+    pub address_case: i32,
+    /// For streaming: if non-zero, data field should be empty and data will be requested in chunks
+    #[prost(uint32, tag = "12")]
+    pub data_length: u32,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "13")]
+    pub max_fee_per_blob_gas: ::prost::alloc::vec::Vec<u8>,
+    /// 32 byte versioned hashes of the blobs, 1 to 6 entries.
+    #[prost(bytes = "vec", repeated, tag = "14")]
+    pub blob_versioned_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct EthSignDataRequestChunkResponse {
@@ -1593,6 +2543,14 @@ pub struct EthSignTypedMessageRequest {
     pub primary_type: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "5")]
     pub host_nonce_commitment: ::core::option::Option<AntiKleptoHostNonceCommitment>,
+    /// Blind signing: if both are set, `types` and `primary_type` are ignored and the message is
+    /// signed as keccak256("\x19\x01" || domain_separator || message_hash) without streaming its
+    /// values. Both must be 32 bytes. Only allowed if blind signing was enabled on the device
+    /// using `SetBlindSigningEnabledRequest`.
+    #[prost(bytes = "vec", tag = "6")]
+    pub domain_separator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub message_hash: ::prost::alloc::vec::Vec<u8>,
 }
 /// Nested message and enum types in `ETHSignTypedMessageRequest`.
 pub mod eth_sign_typed_message_request {
@@ -1740,10 +2698,124 @@ pub struct EthTypedMessageValueRequest {
     #[prost(bytes = "vec", tag = "1")]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+/// Registers a network that is not built into the firmware. After registration, the network is
+/// shown by its name, followed by its chain ID, and its unit instead of a warning about an unknown
+/// chain ID.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthRegisterNetworkRequest {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    /// Printable ASCII, at most 30 bytes. Must not be the name of a built-in network.
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Native currency unit, e.g. "ETH". Printable ASCII without spaces, at most 10 bytes.
+    #[prost(string, tag = "3")]
+    pub unit: ::prost::alloc::string::String,
+}
+/// ERC-4337 user operation of a smart account: <https://eips.ethereum.org/EIPS/eip-4337>
+///
+/// The fields are given unpacked for both EntryPoint versions. For v0.7, the device packs them into
+/// a PackedUserOperation: `init_code` is `factory || factoryData` and `paymaster_and_data` is
+/// `paymaster || paymasterVerificationGasLimit (16 bytes) || paymasterPostOpGasLimit (16 bytes) ||
+/// paymasterData`.
+///
+/// The signature is over the EIP-191 message hash of the 32 byte userOpHash, as expected by
+/// smart accounts that use `toEthSignedMessageHash(userOpHash)` to validate signatures.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthSignUserOperationRequest {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    #[prost(uint32, repeated, tag = "2")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    #[prost(
+        enumeration = "eth_sign_user_operation_request::EntryPointVersion",
+        tag = "3"
+    )]
+    pub entry_point_version: i32,
+    /// 20 bytes
+    #[prost(bytes = "vec", tag = "4")]
+    pub entry_point: ::prost::alloc::vec::Vec<u8>,
+    /// 20 bytes
+    #[prost(bytes = "vec", tag = "5")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 32 bytes
+    #[prost(bytes = "vec", tag = "6")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub init_code: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub call_data: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "9")]
+    pub call_gas_limit: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "10")]
+    pub verification_gas_limit: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "11")]
+    pub pre_verification_gas: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "12")]
+    pub max_fee_per_gas: ::prost::alloc::vec::Vec<u8>,
+    /// smallest big endian serialization, max. 16 bytes
+    #[prost(bytes = "vec", tag = "13")]
+    pub max_priority_fee_per_gas: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "14")]
+    pub paymaster_and_data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "15")]
+    pub host_nonce_commitment: ::core::option::Option<AntiKleptoHostNonceCommitment>,
+}
+/// Nested message and enum types in `ETHSignUserOperationRequest`.
+pub mod eth_sign_user_operation_request {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum EntryPointVersion {
+        V06 = 0,
+        V07 = 1,
+    }
+    impl EntryPointVersion {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                EntryPointVersion::V06 => "V0_6",
+                EntryPointVersion::V07 => "V0_7",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "V0_6" => Some(Self::V06),
+                "V0_7" => Some(Self::V07),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EthSuccess {}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EthRequest {
-    #[prost(oneof = "eth_request::Request", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    #[prost(
+        oneof = "eth_request::Request",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12"
+    )]
     pub request: ::core::option::Option<eth_request::Request>,
 }
 /// Nested message and enum types in `ETHRequest`.
@@ -1767,12 +2839,20 @@ pub mod eth_request {
         SignEip1559(super::EthSignEip1559Request),
         #[prost(message, tag = "8")]
         DataResponseChunk(super::EthSignDataResponseChunkRequest),
+        #[prost(message, tag = "9")]
+        RegisterNetwork(super::EthRegisterNetworkRequest),
+        #[prost(message, tag = "10")]
+        SignUserOperation(super::EthSignUserOperationRequest),
+        #[prost(message, tag = "11")]
+        SignEip4844(super::EthSignEip4844Request),
+        #[prost(message, tag = "12")]
+        Pubs(super::EthPubsRequest),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EthResponse {
-    #[prost(oneof = "eth_response::Response", tags = "1, 2, 3, 4, 5")]
+    #[prost(oneof = "eth_response::Response", tags = "1, 2, 3, 4, 5, 6, 7")]
     pub response: ::core::option::Option<eth_response::Response>,
 }
 /// Nested message and enum types in `ETHResponse`.
@@ -1790,6 +2870,10 @@ pub mod eth_response {
         TypedMsgValue(super::EthTypedMessageValueResponse),
         #[prost(message, tag = "5")]
         DataRequestChunk(super::EthSignDataRequestChunkResponse),
+        #[prost(message, tag = "6")]
+        Success(super::EthSuccess),
+        #[prost(message, tag = "7")]
+        Pubs(super::PubsResponse),
     }
 }
 /// Kept for backwards compatibility. Use chain_id instead, introduced in v9.10.0.
@@ -1868,7 +2952,7 @@ pub struct ElectrumEncryptionKeyResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Bip85Request {
-    #[prost(oneof = "bip85_request::App", tags = "1, 2")]
+    #[prost(oneof = "bip85_request::App", tags = "1, 2, 3, 4, 5, 6, 7")]
     pub app: ::core::option::Option<bip85_request::App>,
 }
 /// Nested message and enum types in `BIP85Request`.
@@ -1886,12 +2970,22 @@ pub mod bip85_request {
         Bip39(()),
         #[prost(message, tag = "2")]
         Ln(AppLn),
+        #[prost(message, tag = "3")]
+        Hex(()),
+        #[prost(message, tag = "4")]
+        PwdBase64(()),
+        #[prost(message, tag = "5")]
+        PwdBase85(()),
+        #[prost(message, tag = "6")]
+        Wif(()),
+        #[prost(message, tag = "7")]
+        Xprv(()),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bip85Response {
-    #[prost(oneof = "bip85_response::App", tags = "1, 2")]
+    #[prost(oneof = "bip85_response::App", tags = "1, 2, 3, 4, 5, 6, 7")]
     pub app: ::core::option::Option<bip85_response::App>,
 }
 /// Nested message and enum types in `BIP85Response`.
@@ -1903,6 +2997,16 @@ pub mod bip85_response {
         Bip39(()),
         #[prost(bytes, tag = "2")]
         Ln(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag = "3")]
+        Hex(()),
+        #[prost(message, tag = "4")]
+        PwdBase64(()),
+        #[prost(message, tag = "5")]
+        PwdBase85(()),
+        #[prost(message, tag = "6")]
+        Wif(()),
+        #[prost(message, tag = "7")]
+        Xprv(()),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1916,12 +3020,265 @@ pub struct RestoreFromMnemonicRequest {
     #[prost(int32, tag = "2")]
     pub timezone_offset: i32,
 }
+/// Splits the seed into SLIP-39 shares and displays them on the device. A share set is recovered if
+/// `group_threshold` groups are complete, i.e. have `member_threshold` shares each. The shares encode
+/// the BIP-32 seed, so this fails with an invalid state error for an initialized BIP-39 wallet. A
+/// newly created seed is used as the BIP-32 seed from then on.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShowSlip39SharesRequest {
+    #[prost(uint32, tag = "1")]
+    pub group_threshold: u32,
+    #[prost(message, repeated, tag = "2")]
+    pub groups: ::prost::alloc::vec::Vec<show_slip39_shares_request::Group>,
+}
+/// Nested message and enum types in `ShowSlip39SharesRequest`.
+pub mod show_slip39_shares_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Group {
+        #[prost(uint32, tag = "1")]
+        pub member_threshold: u32,
+        #[prost(uint32, tag = "2")]
+        pub member_count: u32,
+    }
+}
+/// Restores from SLIP-39 shares. The master secret is used as the BIP-32 seed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RestoreFromSlip39SharesRequest {
+    #[prost(uint32, tag = "1")]
+    pub timestamp: u32,
+    #[prost(int32, tag = "2")]
+    pub timezone_offset: i32,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SetMnemonicPassphraseEnabledRequest {
     #[prost(bool, tag = "1")]
     pub enabled: bool,
 }
+/// Keypath must be m/44'/1237'/account'/0/0, see NIP-06. The response contains the npub-encoded
+/// public key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrPubRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    #[prost(bool, tag = "2")]
+    pub display: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrTag {
+    #[prost(string, repeated, tag = "1")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Signs a NIP-01 event. The public key of the event is the one at the keypath. The device
+/// computes the event id from the serialized event and signs it. Only common event kinds are
+/// supported, and their relevant tags are shown to the user.
+/// See <https://github.com/nostr-protocol/nips/blob/master/01.md>
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrSignEventRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    /// Unix timestamp in seconds.
+    #[prost(uint64, tag = "2")]
+    pub created_at: u64,
+    #[prost(uint32, tag = "3")]
+    pub kind: u32,
+    #[prost(message, repeated, tag = "4")]
+    pub tags: ::prost::alloc::vec::Vec<NostrTag>,
+    #[prost(string, tag = "5")]
+    pub content: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrSignEventResponse {
+    /// 32 bytes event id.
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// 64 bytes BIP-340 Schnorr signature over the event id.
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// Encrypts a message to the counterparty, using ECDH between the key at the keypath and the
+/// counterparty's public key. The user confirms each counterparty once until the device is
+/// unplugged.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrEncryptRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    #[prost(enumeration = "NostrEncryptionScheme", tag = "2")]
+    pub scheme: i32,
+    /// 32 bytes x-only public key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub counterparty: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub plaintext: ::prost::alloc::vec::Vec<u8>,
+}
+/// The payload is not base64 encoded. For NIP-44, it is the version byte, nonce, ciphertext and
+/// MAC. For NIP-04, it is the 16 byte IV followed by the ciphertext.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrEncryptResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub payload: ::prost::alloc::vec::Vec<u8>,
+}
+/// Decrypts a message from or to the counterparty. See `NostrEncryptRequest`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrDecryptRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    #[prost(enumeration = "NostrEncryptionScheme", tag = "2")]
+    pub scheme: i32,
+    /// 32 bytes x-only public key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub counterparty: ::prost::alloc::vec::Vec<u8>,
+    /// See `NostrEncryptResponse`.
+    #[prost(bytes = "vec", tag = "4")]
+    pub payload: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrDecryptResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub plaintext: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrRequest {
+    #[prost(oneof = "nostr_request::Request", tags = "1, 2, 3, 4")]
+    pub request: ::core::option::Option<nostr_request::Request>,
+}
+/// Nested message and enum types in `NostrRequest`.
+pub mod nostr_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Request {
+        #[prost(message, tag = "1")]
+        Pub(super::NostrPubRequest),
+        #[prost(message, tag = "2")]
+        SignEvent(super::NostrSignEventRequest),
+        #[prost(message, tag = "3")]
+        Encrypt(super::NostrEncryptRequest),
+        #[prost(message, tag = "4")]
+        Decrypt(super::NostrDecryptRequest),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NostrResponse {
+    #[prost(oneof = "nostr_response::Response", tags = "1, 2, 3, 4")]
+    pub response: ::core::option::Option<nostr_response::Response>,
+}
+/// Nested message and enum types in `NostrResponse`.
+pub mod nostr_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Pub(super::PubResponse),
+        #[prost(message, tag = "2")]
+        SignEvent(super::NostrSignEventResponse),
+        #[prost(message, tag = "3")]
+        Encrypt(super::NostrEncryptResponse),
+        #[prost(message, tag = "4")]
+        Decrypt(super::NostrDecryptResponse),
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NostrEncryptionScheme {
+    /// See <https://github.com/nostr-protocol/nips/blob/master/44.md>
+    NostrEncryptionNip44 = 0,
+    /// Deprecated, but still widely used for direct messages.
+    /// See <https://github.com/nostr-protocol/nips/blob/master/04.md>
+    NostrEncryptionNip04 = 1,
+}
+impl NostrEncryptionScheme {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NostrEncryptionScheme::NostrEncryptionNip44 => "NOSTR_ENCRYPTION_NIP44",
+            NostrEncryptionScheme::NostrEncryptionNip04 => "NOSTR_ENCRYPTION_NIP04",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NOSTR_ENCRYPTION_NIP44" => Some(Self::NostrEncryptionNip44),
+            "NOSTR_ENCRYPTION_NIP04" => Some(Self::NostrEncryptionNip04),
+            _ => None,
+        }
+    }
+}
+/// Keypath must be m/44'/501'/account'/0'.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaPubRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    #[prost(bool, tag = "2")]
+    pub display: bool,
+}
+/// Signs a serialized Solana transaction message, legacy or versioned (v0). See
+/// <https://solana.com/docs/core/transactions#message>
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaSignTransactionRequest {
+    #[prost(uint32, repeated, tag = "1")]
+    pub keypath: ::prost::alloc::vec::Vec<u32>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub message: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaSignTransactionResponse {
+    /// 64 bytes ed25519 signature.
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaRequest {
+    #[prost(oneof = "solana_request::Request", tags = "1, 2")]
+    pub request: ::core::option::Option<solana_request::Request>,
+}
+/// Nested message and enum types in `SolanaRequest`.
+pub mod solana_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Request {
+        #[prost(message, tag = "1")]
+        Pub(super::SolanaPubRequest),
+        #[prost(message, tag = "2")]
+        SignTransaction(super::SolanaSignTransactionRequest),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaResponse {
+    #[prost(oneof = "solana_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<solana_response::Response>,
+}
+/// Nested message and enum types in `SolanaResponse`.
+pub mod solana_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Pub(super::PubResponse),
+        #[prost(message, tag = "2")]
+        SignTransaction(super::SolanaSignTransactionResponse),
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RebootRequest {
@@ -2006,7 +3363,7 @@ pub struct Success {}
 pub struct Request {
     #[prost(
         oneof = "request::Request",
-        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 24, 25, 26, 27, 28, 29, 30"
+        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35"
     )]
     pub request: ::core::option::Option<request::Request>,
 }
@@ -2073,6 +3430,16 @@ pub mod request {
         Bluetooth(super::BluetoothRequest),
         #[prost(message, tag = "30")]
         ChangePassword(super::ChangePasswordRequest),
+        #[prost(message, tag = "31")]
+        SetBlindSigningEnabled(super::SetBlindSigningEnabledRequest),
+        #[prost(message, tag = "32")]
+        Solana(super::SolanaRequest),
+        #[prost(message, tag = "33")]
+        Nostr(super::NostrRequest),
+        #[prost(message, tag = "34")]
+        ShowSlip39Shares(super::ShowSlip39SharesRequest),
+        #[prost(message, tag = "35")]
+        RestoreFromSlip39Shares(super::RestoreFromSlip39SharesRequest),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -2080,7 +3447,7 @@ pub mod request {
 pub struct Response {
     #[prost(
         oneof = "response::Response",
-        tags = "1, 2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17"
+        tags = "1, 2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19"
     )]
     pub response: ::core::option::Option<response::Response>,
 }
@@ -2122,5 +3489,9 @@ pub mod response {
         Bip85(super::Bip85Response),
         #[prost(message, tag = "17")]
         Bluetooth(super::BluetoothResponse),
+        #[prost(message, tag = "18")]
+        Solana(super::SolanaResponse),
+        #[prost(message, tag = "19")]
+        Nostr(super::NostrResponse),
     }
 }
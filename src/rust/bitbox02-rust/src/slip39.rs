@@ -0,0 +1,982 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! SLIP-39 Shamir's Secret-Sharing for Mnemonic Codes:
+//! https://github.com/satoshilabs/slips/blob/master/slip-0039.md
+//!
+//! As specified, the master secret is used directly as the BIP-32 seed. It is stored in the keystore
+//! as a SLIP-39 seed, see `keystore::encrypt_and_store_slip39_master_secret()`.
+
+mod wordlist;
+
+pub use wordlist::WORDLIST;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use bitcoin::hashes::{Hash, HashEngine, Hmac, HmacEngine, sha256};
+
+/// Number of bits encoded by one word.
+const RADIX_BITS: u32 = 10;
+/// Identifier, extendable flag, iteration exponent, group and member parameters.
+const HEADER_LENGTH_WORDS: usize = 4;
+const CHECKSUM_LENGTH_WORDS: usize = 3;
+/// The master secret must have at least 128 bits.
+const MIN_SECRET_LENGTH: usize = 16;
+/// A mnemonic encoding the smallest possible master secret.
+const MIN_MNEMONIC_LENGTH_WORDS: usize = HEADER_LENGTH_WORDS + CHECKSUM_LENGTH_WORDS + 13;
+/// Maximum number of groups, and of members per group.
+const MAX_SHARE_COUNT: u8 = 16;
+
+const BASE_ITERATION_COUNT: u32 = 10000;
+const ROUND_COUNT: u8 = 4;
+/// The PBKDF2 iteration count when creating shares is `BASE_ITERATION_COUNT << ITERATION_EXPONENT`.
+const ITERATION_EXPONENT: u8 = 1;
+
+/// x-coordinates at which the secret and its digest are stored in the polynomial.
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+const DIGEST_LENGTH: usize = 4;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// Invalid group/member thresholds or counts.
+    InvalidParams,
+    /// The master secret must have an even length of at least 16 bytes.
+    InvalidSecretLength,
+    /// The passphrase must consist of printable ASCII characters.
+    InvalidPassphrase,
+    /// Unknown word, invalid length, padding or checksum.
+    InvalidShare,
+    /// The share does not belong to the same set as the other shares.
+    ShareMismatch,
+    /// The share was already entered.
+    DuplicateShare,
+    /// The group of the share already has enough shares.
+    GroupComplete,
+    /// The group and member thresholds are not met.
+    NotEnoughShares,
+    /// The recovered secret does not match its digest.
+    InvalidDigest,
+}
+
+/// `idx` must be smaller than 1024.
+pub fn get_word(idx: u16) -> Result<zeroize::Zeroizing<String>, ()> {
+    Ok(zeroize::Zeroizing::new(
+        WORDLIST.get(idx as usize).ok_or(())?.to_string(),
+    ))
+}
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+fn rs1024_polymod(values: impl Iterator<Item = u16>) -> u32 {
+    const GEN: [u32; 10] = [
+        0xe0e040, 0x1c1c080, 0x3838100, 0x7070200, 0xe0e0009, 0x1c0c2412, 0x38086c24, 0x3090fc48,
+        0x21b1f890, 0x3f3f120,
+    ];
+    let mut chk: u32 = 1;
+    for value in values {
+        let b = chk >> 20;
+        chk = ((chk & 0xfffff) << 10) ^ value as u32;
+        for (i, generator) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_create_checksum(extendable: bool, data: &[u16]) -> [u16; CHECKSUM_LENGTH_WORDS] {
+    let values = customization_string(extendable)
+        .iter()
+        .map(|&c| c as u16)
+        .chain(data.iter().copied())
+        .chain([0; CHECKSUM_LENGTH_WORDS]);
+    let polymod = rs1024_polymod(values) ^ 1;
+    [2, 1, 0].map(|i| ((polymod >> (RADIX_BITS * i)) & 1023) as u16)
+}
+
+fn rs1024_verify_checksum(extendable: bool, data: &[u16]) -> bool {
+    let values = customization_string(extendable)
+        .iter()
+        .map(|&c| c as u16)
+        .chain(data.iter().copied());
+    rs1024_polymod(values) == 1
+}
+
+/// Converts the share value to words, padding it with leading zero bits to a multiple of 10 bits.
+fn value_to_words(value: &[u8]) -> Vec<u16> {
+    let word_count = (value.len() * 8).div_ceil(RADIX_BITS as usize);
+    let mut words = Vec::with_capacity(word_count);
+    let mut acc: u32 = 0;
+    let mut bits: u32 = (word_count * RADIX_BITS as usize - value.len() * 8) as u32;
+    for &byte in value {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        while bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            words.push(((acc >> bits) & 1023) as u16);
+        }
+        acc &= (1 << bits) - 1;
+    }
+    words
+}
+
+/// Inverse of `value_to_words()`. The padding must be at most 8 bits and all zero.
+fn words_to_value(words: &[u16]) -> Result<zeroize::Zeroizing<Vec<u8>>, Error> {
+    let padding = (words.len() * RADIX_BITS as usize % 16) as u32;
+    if padding > 8 {
+        return Err(Error::InvalidShare);
+    }
+    let mut value = zeroize::Zeroizing::new(Vec::with_capacity(words.len() * 10 / 8));
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut skip = padding;
+    for &word in words {
+        acc = (acc << RADIX_BITS) | word as u32;
+        bits += RADIX_BITS;
+        if skip > 0 {
+            if acc >> (bits - skip) != 0 {
+                return Err(Error::InvalidShare);
+            }
+            bits -= skip;
+            acc &= (1 << bits) - 1;
+            skip = 0;
+        }
+        while bits >= 8 {
+            bits -= 8;
+            value.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Ok(value)
+}
+
+/// A single share, encoded as a mnemonic.
+pub struct Share {
+    /// Random identifier, the same for all shares of a set.
+    pub identifier: u16,
+    pub extendable: bool,
+    pub iteration_exponent: u8,
+    pub group_index: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+    pub member_index: u8,
+    pub member_threshold: u8,
+    pub value: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// Decodes a space separated list of SLIP-39 words, verifying the checksum.
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Share, Error> {
+        let indices: zeroize::Zeroizing<Vec<u16>> = zeroize::Zeroizing::new(
+            mnemonic
+                .split(' ')
+                .map(|word| WORDLIST.binary_search(&word).map(|idx| idx as u16))
+                .collect::<Result<Vec<u16>, _>>()
+                .map_err(|_| Error::InvalidShare)?,
+        );
+        if indices.len() < MIN_MNEMONIC_LENGTH_WORDS {
+            return Err(Error::InvalidShare);
+        }
+        let header: u64 = indices[..HEADER_LENGTH_WORDS]
+            .iter()
+            .fold(0, |acc, &idx| (acc << RADIX_BITS) | idx as u64);
+        let extendable = (header >> 24) & 1 == 1;
+        if !rs1024_verify_checksum(extendable, &indices) {
+            return Err(Error::InvalidShare);
+        }
+        let share = Share {
+            identifier: (header >> 25) as u16,
+            extendable,
+            iteration_exponent: ((header >> 20) & 0xf) as u8,
+            group_index: ((header >> 16) & 0xf) as u8,
+            group_threshold: ((header >> 12) & 0xf) as u8 + 1,
+            group_count: ((header >> 8) & 0xf) as u8 + 1,
+            member_index: ((header >> 4) & 0xf) as u8,
+            member_threshold: (header & 0xf) as u8 + 1,
+            value: words_to_value(
+                &indices[HEADER_LENGTH_WORDS..indices.len() - CHECKSUM_LENGTH_WORDS],
+            )?,
+        };
+        if share.group_threshold > share.group_count || share.group_index >= share.group_count {
+            return Err(Error::InvalidShare);
+        }
+        Ok(share)
+    }
+
+    /// Encodes the share as a space separated list of SLIP-39 words.
+    pub fn to_mnemonic(&self) -> zeroize::Zeroizing<String> {
+        let header: u64 = ((self.identifier as u64) << 25)
+            | ((self.extendable as u64) << 24)
+            | ((self.iteration_exponent as u64) << 20)
+            | ((self.group_index as u64) << 16)
+            | (((self.group_threshold - 1) as u64) << 12)
+            | (((self.group_count - 1) as u64) << 8)
+            | ((self.member_index as u64) << 4)
+            | ((self.member_threshold - 1) as u64);
+        let mut indices: zeroize::Zeroizing<Vec<u16>> = zeroize::Zeroizing::new(
+            (0..HEADER_LENGTH_WORDS)
+                .rev()
+                .map(|i| ((header >> (RADIX_BITS as usize * i)) & 1023) as u16)
+                .collect(),
+        );
+        indices.extend(value_to_words(&self.value));
+        let checksum = rs1024_create_checksum(self.extendable, &indices);
+        indices.extend(checksum);
+        let words: Vec<&str> = indices.iter().map(|&idx| WORDLIST[idx as usize]).collect();
+        zeroize::Zeroizing::new(words.join(" "))
+    }
+
+    /// True if the share was created together with `other`, with the same master secret.
+    fn is_same_set(&self, other: &Share) -> bool {
+        self.identifier == other.identifier
+            && self.extendable == other.extendable
+            && self.iteration_exponent == other.iteration_exponent
+            && self.group_threshold == other.group_threshold
+            && self.group_count == other.group_count
+            && self.value.len() == other.value.len()
+    }
+}
+
+/// Exponent and logarithm tables of GF(256) with the Rijndael polynomial x^8 + x^4 + x^3 + x + 1.
+const fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+        // Multiply by the generator x + 1.
+        poly ^= poly << 1;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+        i += 1;
+    }
+    (exp, log)
+}
+
+const GF256: ([u8; 255], [u8; 256]) = gf256_tables();
+
+/// Evaluates the polynomial defined by `shares` (x-coordinate, value) at `x` using Lagrange
+/// interpolation. The x-coordinates must be unique.
+fn interpolate(shares: &[(u8, &[u8])], x: u8) -> zeroize::Zeroizing<Vec<u8>> {
+    let (exp, log) = &GF256;
+    if let Some((_, value)) = shares.iter().find(|(share_x, _)| *share_x == x) {
+        return zeroize::Zeroizing::new(value.to_vec());
+    }
+    let log_product: i32 = shares
+        .iter()
+        .map(|(share_x, _)| log[(share_x ^ x) as usize] as i32)
+        .sum();
+    let mut result = zeroize::Zeroizing::new(vec![0u8; shares[0].1.len()]);
+    for (share_x, value) in shares {
+        let log_denominator: i32 = shares
+            .iter()
+            .filter(|(other_x, _)| other_x != share_x)
+            .map(|(other_x, _)| log[(share_x ^ other_x) as usize] as i32)
+            .sum();
+        let log_basis =
+            (log_product - log[(share_x ^ x) as usize] as i32 - log_denominator).rem_euclid(255);
+        for (r, &v) in result.iter_mut().zip(value.iter()) {
+            if v != 0 {
+                *r ^= exp[((log[v as usize] as i32 + log_basis) % 255) as usize];
+            }
+        }
+    }
+    result
+}
+
+fn hmac_sha256(key: &[u8], msg: &[u8]) -> [u8; 32] {
+    let mut engine = HmacEngine::<sha256::Hash>::new(key);
+    engine.input(msg);
+    Hmac::from_engine(engine).to_byte_array()
+}
+
+fn random_bytes(random: &mut impl crate::hal::Random, len: usize) -> zeroize::Zeroizing<Vec<u8>> {
+    let mut result = zeroize::Zeroizing::new(Vec::with_capacity(len));
+    while result.len() < len {
+        let chunk = random.random_32_bytes();
+        let take = core::cmp::min(32, len - result.len());
+        result.extend_from_slice(&chunk[..take]);
+    }
+    result
+}
+
+/// Splits `secret` into `count` shares, `threshold` of which are needed to recover it. Share `i`
+/// is the value at x-coordinate `i`.
+fn split_secret(
+    random: &mut impl crate::hal::Random,
+    threshold: u8,
+    count: u8,
+    secret: &[u8],
+) -> Vec<zeroize::Zeroizing<Vec<u8>>> {
+    if threshold == 1 {
+        return (0..count)
+            .map(|_| zeroize::Zeroizing::new(secret.to_vec()))
+            .collect();
+    }
+    let random_share_count = threshold - 2;
+    let mut shares: Vec<zeroize::Zeroizing<Vec<u8>>> = (0..random_share_count)
+        .map(|_| random_bytes(random, secret.len()))
+        .collect();
+    let random_part = random_bytes(random, secret.len() - DIGEST_LENGTH);
+    let mut digest =
+        zeroize::Zeroizing::new(hmac_sha256(&random_part, secret)[..DIGEST_LENGTH].to_vec());
+    digest.extend_from_slice(&random_part);
+
+    let interpolated: Vec<zeroize::Zeroizing<Vec<u8>>> = {
+        let base_shares: Vec<(u8, &[u8])> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, share)| (i as u8, share.as_slice()))
+            .chain([(DIGEST_INDEX, digest.as_slice()), (SECRET_INDEX, secret)])
+            .collect();
+        (random_share_count..count)
+            .map(|x| interpolate(&base_shares, x))
+            .collect()
+    };
+    shares.extend(interpolated);
+    shares
+}
+
+/// Recovers the secret from `threshold` shares and verifies its digest.
+fn recover_secret(
+    threshold: u8,
+    shares: &[(u8, &[u8])],
+) -> Result<zeroize::Zeroizing<Vec<u8>>, Error> {
+    if threshold == 1 {
+        return Ok(zeroize::Zeroizing::new(shares[0].1.to_vec()));
+    }
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest = interpolate(shares, DIGEST_INDEX);
+    if hmac_sha256(&digest[DIGEST_LENGTH..], &secret)[..DIGEST_LENGTH] != digest[..DIGEST_LENGTH] {
+        return Err(Error::InvalidDigest);
+    }
+    Ok(secret)
+}
+
+/// PBKDF2 with HMAC-SHA256, see https://datatracker.ietf.org/doc/html/rfc8018#section-5.2.
+fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    let engine = HmacEngine::<sha256::Hash>::new(password);
+    for (i, chunk) in out.chunks_mut(32).enumerate() {
+        let mut block_engine = engine.clone();
+        block_engine.input(salt);
+        block_engine.input(&(i as u32 + 1).to_be_bytes());
+        let mut u = zeroize::Zeroizing::new(Hmac::from_engine(block_engine).to_byte_array());
+        let mut t = u.clone();
+        for _ in 1..iterations {
+            let mut iteration_engine = engine.clone();
+            iteration_engine.input(u.as_slice());
+            *u = Hmac::from_engine(iteration_engine).to_byte_array();
+            t.iter_mut().zip(u.iter()).for_each(|(t, u)| *t ^= u);
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
+
+/// Encrypts (or decrypts, if `encrypt` is false) the master secret with the passphrase using the
+/// four round Feistel network of SLIP-39.
+fn crypt(
+    input: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    encrypt: bool,
+) -> zeroize::Zeroizing<Vec<u8>> {
+    let half = input.len() / 2;
+    let mut left = zeroize::Zeroizing::new(input[..half].to_vec());
+    let mut right = zeroize::Zeroizing::new(input[half..].to_vec());
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+    let mut salt_prefix: Vec<u8> = Vec::new();
+    if !extendable {
+        salt_prefix.extend_from_slice(b"shamir");
+        salt_prefix.extend_from_slice(&identifier.to_be_bytes());
+    }
+    for i in 0..ROUND_COUNT {
+        let round = if encrypt { i } else { ROUND_COUNT - 1 - i };
+        let mut password = zeroize::Zeroizing::new(vec![round]);
+        password.extend_from_slice(passphrase);
+        let mut salt = zeroize::Zeroizing::new(salt_prefix.clone());
+        salt.extend_from_slice(&right);
+        let mut f = zeroize::Zeroizing::new(vec![0u8; half]);
+        pbkdf2_hmac_sha256(&password, &salt, iterations, &mut f);
+        let new_right: zeroize::Zeroizing<Vec<u8>> =
+            zeroize::Zeroizing::new(left.iter().zip(f.iter()).map(|(l, f)| l ^ f).collect());
+        left = right;
+        right = new_right;
+    }
+    let mut result = right;
+    result.extend_from_slice(&left);
+    result
+}
+
+fn check_passphrase(passphrase: &str) -> Result<(), Error> {
+    if passphrase.bytes().all(|b| (32..=126).contains(&b)) {
+        Ok(())
+    } else {
+        Err(Error::InvalidPassphrase)
+    }
+}
+
+/// Parameters of one group of shares.
+pub struct GroupParams {
+    /// Number of shares of this group needed to recover the group secret.
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+/// Checks the group threshold and the group parameters.
+pub fn check_params(group_threshold: u8, groups: &[GroupParams]) -> Result<(), Error> {
+    if groups.is_empty()
+        || groups.len() > MAX_SHARE_COUNT as usize
+        || group_threshold == 0
+        || group_threshold as usize > groups.len()
+    {
+        return Err(Error::InvalidParams);
+    }
+    for group in groups {
+        if group.member_threshold == 0
+            || group.member_threshold > group.member_count
+            || group.member_count > MAX_SHARE_COUNT
+            // A group with several shares, each of which recovers the group, is not allowed.
+            || (group.member_threshold == 1 && group.member_count > 1)
+        {
+            return Err(Error::InvalidParams);
+        }
+    }
+    Ok(())
+}
+
+/// Splits the master secret into mnemonic shares. The result contains the mnemonics of each
+/// group. The master secret is encrypted with `passphrase` first, which is needed again when
+/// recovering.
+pub fn split(
+    random: &mut impl crate::hal::Random,
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[GroupParams],
+) -> Result<Vec<Vec<zeroize::Zeroizing<String>>>, Error> {
+    if master_secret.len() < MIN_SECRET_LENGTH || !master_secret.len().is_multiple_of(2) {
+        return Err(Error::InvalidSecretLength);
+    }
+    check_passphrase(passphrase)?;
+    check_params(group_threshold, groups)?;
+
+    let identifier = {
+        let random = random.random_32_bytes();
+        u16::from_be_bytes([random[0], random[1]]) & 0x7fff
+    };
+    let encrypted_master_secret = crypt(
+        master_secret,
+        passphrase.as_bytes(),
+        ITERATION_EXPONENT,
+        identifier,
+        true,
+        true,
+    );
+    let group_secrets = split_secret(
+        random,
+        group_threshold,
+        groups.len() as u8,
+        &encrypted_master_secret,
+    );
+    Ok(groups
+        .iter()
+        .zip(group_secrets.iter())
+        .enumerate()
+        .map(|(group_index, (group, group_secret))| {
+            split_secret(
+                random,
+                group.member_threshold,
+                group.member_count,
+                group_secret,
+            )
+            .into_iter()
+            .enumerate()
+            .map(|(member_index, value)| {
+                Share {
+                    identifier,
+                    extendable: true,
+                    iteration_exponent: ITERATION_EXPONENT,
+                    group_index: group_index as u8,
+                    group_threshold,
+                    group_count: groups.len() as u8,
+                    member_index: member_index as u8,
+                    member_threshold: group.member_threshold,
+                    value,
+                }
+                .to_mnemonic()
+            })
+            .collect()
+        })
+        .collect())
+}
+
+/// Collects the shares entered during recovery until the group and member thresholds are met.
+#[derive(Default)]
+pub struct ShareSet {
+    shares: Vec<Share>,
+}
+
+impl ShareSet {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Number of shares entered so far in the given group.
+    pub fn group_share_count(&self, group_index: u8) -> usize {
+        self.shares
+            .iter()
+            .filter(|share| share.group_index == group_index)
+            .count()
+    }
+
+    fn is_group_complete(&self, group_index: u8, member_threshold: u8) -> bool {
+        self.group_share_count(group_index) >= member_threshold as usize
+    }
+
+    /// Adds a share. It must belong to the same set as the previously added shares, and its group
+    /// must not be complete yet.
+    pub fn add(&mut self, share: Share) -> Result<(), Error> {
+        if let Some(first) = self.shares.first() {
+            if !first.is_same_set(&share) {
+                return Err(Error::ShareMismatch);
+            }
+        }
+        for other in self
+            .shares
+            .iter()
+            .filter(|other| other.group_index == share.group_index)
+        {
+            if other.member_threshold != share.member_threshold {
+                return Err(Error::ShareMismatch);
+            }
+            if other.member_index == share.member_index {
+                return Err(Error::DuplicateShare);
+            }
+        }
+        if self.is_group_complete(share.group_index, share.member_threshold) {
+            return Err(Error::GroupComplete);
+        }
+        self.shares.push(share);
+        Ok(())
+    }
+
+    /// Group indices of the groups which have enough shares.
+    fn complete_groups(&self) -> Vec<u8> {
+        let mut groups: Vec<u8> = self
+            .shares
+            .iter()
+            .filter(|share| self.is_group_complete(share.group_index, share.member_threshold))
+            .map(|share| share.group_index)
+            .collect();
+        groups.sort_unstable();
+        groups.dedup();
+        groups
+    }
+
+    /// True if enough groups are complete to recover the master secret.
+    pub fn is_complete(&self) -> bool {
+        match self.shares.first() {
+            None => false,
+            Some(first) => self.complete_groups().len() >= first.group_threshold as usize,
+        }
+    }
+
+    /// Recovers the master secret, decrypting it with `passphrase`. A wrong passphrase results in
+    /// a different master secret, not in an error.
+    pub fn combine(&self, passphrase: &str) -> Result<zeroize::Zeroizing<Vec<u8>>, Error> {
+        check_passphrase(passphrase)?;
+        if !self.is_complete() {
+            return Err(Error::NotEnoughShares);
+        }
+        let first = &self.shares[0];
+        let group_secrets: Vec<(u8, zeroize::Zeroizing<Vec<u8>>)> = self
+            .complete_groups()
+            .into_iter()
+            .take(first.group_threshold as usize)
+            .map(|group_index| {
+                let member_shares: Vec<(u8, &[u8])> = self
+                    .shares
+                    .iter()
+                    .filter(|share| share.group_index == group_index)
+                    .map(|share| (share.member_index, share.value.as_slice()))
+                    .collect();
+                let member_threshold = self
+                    .shares
+                    .iter()
+                    .find(|share| share.group_index == group_index)
+                    .unwrap()
+                    .member_threshold;
+                Ok((
+                    group_index,
+                    recover_secret(member_threshold, &member_shares)?,
+                ))
+            })
+            .collect::<Result<_, Error>>()?;
+        let group_shares: Vec<(u8, &[u8])> = group_secrets
+            .iter()
+            .map(|(group_index, secret)| (*group_index, secret.as_slice()))
+            .collect();
+        let encrypted_master_secret = recover_secret(first.group_threshold, &group_shares)?;
+        Ok(crypt(
+            &encrypted_master_secret,
+            passphrase.as_bytes(),
+            first.iteration_exponent,
+            first.identifier,
+            first.extendable,
+            false,
+        ))
+    }
+}
+
+// C API
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rust_get_slip39_word(idx: u16, mut out: util::bytes::BytesMut) -> bool {
+    let word = match get_word(idx) {
+        Err(()) => return false,
+        Ok(w) => w,
+    };
+    let bytes = word.as_bytes();
+    let out = out.as_mut();
+    if out.len() < bytes.len() + 1 {
+        return false;
+    }
+    out[..bytes.len()].clone_from_slice(bytes);
+    out[bytes.len()] = 0;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::testing::TestingRandom;
+    use hex_lit::hex;
+
+    // Test vectors from https://github.com/trezor/python-shamir-mnemonic/blob/master/vectors.json.
+    // All use the passphrase "TREZOR".
+
+    const VECTOR_1OF1_128: &str = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+
+    const VECTOR_2OF3_128: [&str; 2] = [
+        "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+        "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+    ];
+
+    const VECTOR_1OF1_256: &str = "theory painting academic academic armed sweater year military elder discuss acne wildlife boring employer fused large satoshi bundle carbon diagnose anatomy hamster leaves tracks paces beyond phantom capital marvel lips brave detect luck";
+
+    const VECTOR_2OF3_256: [&str; 2] = [
+        "humidity disease academic always aluminum jewelry energy woman receiver strategy amuse duckling lying evidence network walnut tactics forget hairy rebound impulse brother survive clothes stadium mailman rival ocean reward venture always armed unwrap",
+        "humidity disease academic agency actress jacket gross physics cylinder solution fake mortgage benefit public busy prepare sharp friar change work slow purchase ruler again tricycle involve viral wireless mixture anatomy desert cargo upgrade",
+    ];
+
+    fn combine(mnemonics: &[&str], passphrase: &str) -> Result<zeroize::Zeroizing<Vec<u8>>, Error> {
+        let mut share_set = ShareSet::new();
+        for mnemonic in mnemonics {
+            share_set.add(Share::from_mnemonic(mnemonic)?)?;
+        }
+        share_set.combine(passphrase)
+    }
+
+    #[test]
+    fn test_wordlist() {
+        assert_eq!(WORDLIST.len(), 1024);
+        assert!(WORDLIST.windows(2).all(|w| w[0] < w[1]));
+        // Each word is uniquely identified by its first four letters.
+        assert!(WORDLIST.windows(2).all(|w| w[0][..4] != w[1][..4]));
+        assert!(WORDLIST.iter().all(|w| (4..=8).contains(&w.len())));
+
+        assert!(get_word(1024).is_err());
+        assert_eq!(get_word(0).unwrap().as_str(), "academic");
+        assert_eq!(get_word(1023).unwrap().as_str(), "zero");
+    }
+
+    #[test]
+    fn test_rust_get_slip39_word() {
+        let mut word = [1u8; 10];
+        assert!(!rust_get_slip39_word(1024, unsafe {
+            util::bytes::rust_util_bytes_mut(word.as_mut_ptr(), word.len())
+        }));
+        // "academic" plus null terminator does not fit.
+        assert!(!rust_get_slip39_word(0, unsafe {
+            util::bytes::rust_util_bytes_mut(word.as_mut_ptr(), 8)
+        }));
+        assert!(rust_get_slip39_word(0, unsafe {
+            util::bytes::rust_util_bytes_mut(word.as_mut_ptr(), 9)
+        }));
+        assert_eq!(
+            util::strings::str_from_null_terminated(&word).unwrap(),
+            "academic"
+        );
+    }
+
+    #[test]
+    fn test_combine_vectors() {
+        assert_eq!(
+            combine(&[VECTOR_1OF1_128], "TREZOR").unwrap().as_slice(),
+            &hex!("bb54aac4b89dc868ba37d9cc21b2cece"),
+        );
+        assert_eq!(
+            combine(&VECTOR_2OF3_128, "TREZOR").unwrap().as_slice(),
+            &hex!("b43ceb7e57a0ea8766221624d01b0864"),
+        );
+        assert_eq!(
+            combine(&[VECTOR_1OF1_256], "TREZOR").unwrap().as_slice(),
+            &hex!("989baf9dcaad5b10ca33dfd8cc75e42477025dce88ae83e75a230086a0e00e92"),
+        );
+        assert_eq!(
+            combine(&VECTOR_2OF3_256, "TREZOR").unwrap().as_slice(),
+            &hex!("c938b319067687e990e05e0da0ecce1278f75ff58d9853f19dcaeed5de104aae"),
+        );
+
+        // A different passphrase results in a different master secret.
+        assert_ne!(
+            combine(&[VECTOR_1OF1_128], "").unwrap().as_slice(),
+            &hex!("bb54aac4b89dc868ba37d9cc21b2cece"),
+        );
+    }
+
+    #[test]
+    fn test_from_mnemonic() {
+        let share = Share::from_mnemonic(VECTOR_2OF3_128[0]).unwrap();
+        assert!(!share.extendable);
+        assert_eq!(share.iteration_exponent, 2);
+        assert_eq!(share.group_index, 0);
+        assert_eq!(share.group_threshold, 1);
+        assert_eq!(share.group_count, 1);
+        assert_eq!(share.member_index, 2);
+        assert_eq!(share.member_threshold, 2);
+        assert_eq!(share.value.len(), 16);
+        assert_eq!(share.to_mnemonic().as_str(), VECTOR_2OF3_128[0]);
+
+        let share = Share::from_mnemonic(VECTOR_1OF1_256).unwrap();
+        assert_eq!(share.value.len(), 32);
+        assert_eq!(share.to_mnemonic().as_str(), VECTOR_1OF1_256);
+
+        // Invalid checksum.
+        assert!(matches!(
+            Share::from_mnemonic(
+                "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney"
+            ),
+            Err(Error::InvalidShare)
+        ));
+        // Unknown word.
+        assert!(matches!(
+            Share::from_mnemonic(
+                "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision abandon"
+            ),
+            Err(Error::InvalidShare)
+        ));
+        // Too short.
+        assert!(matches!(
+            Share::from_mnemonic("duckling enlarge academic academic agency result length"),
+            Err(Error::InvalidShare)
+        ));
+        assert!(matches!(Share::from_mnemonic(""), Err(Error::InvalidShare)));
+    }
+
+    #[test]
+    fn test_share_set() {
+        // Not enough shares.
+        let mut share_set = ShareSet::new();
+        share_set
+            .add(Share::from_mnemonic(VECTOR_2OF3_128[0]).unwrap())
+            .unwrap();
+        assert!(!share_set.is_complete());
+        assert_eq!(share_set.group_share_count(0), 1);
+        assert_eq!(share_set.combine("TREZOR"), Err(Error::NotEnoughShares));
+
+        // Same share twice.
+        assert_eq!(
+            share_set.add(Share::from_mnemonic(VECTOR_2OF3_128[0]).unwrap()),
+            Err(Error::DuplicateShare)
+        );
+        // Share of a different set.
+        assert_eq!(
+            share_set.add(Share::from_mnemonic(VECTOR_2OF3_256[0]).unwrap()),
+            Err(Error::ShareMismatch)
+        );
+
+        share_set
+            .add(Share::from_mnemonic(VECTOR_2OF3_128[1]).unwrap())
+            .unwrap();
+        assert!(share_set.is_complete());
+
+        // Non-printable passphrase.
+        assert_eq!(share_set.combine("\n"), Err(Error::InvalidPassphrase));
+    }
+
+    #[test]
+    fn test_split() {
+        let mut random = TestingRandom::new();
+        let secret = hex!("b43ceb7e57a0ea8766221624d01b0864");
+        let shares = split(
+            &mut random,
+            &secret,
+            "",
+            1,
+            &[GroupParams {
+                member_threshold: 2,
+                member_count: 3,
+            }],
+        )
+        .unwrap();
+        assert_eq!(shares.len(), 1);
+        let shares: Vec<&str> = shares[0].iter().map(|share| share.as_str()).collect();
+        assert_eq!(
+            shares,
+            vec![
+                "guest dream academic acid arcade therapy improve lips corner space paid square plunge pancake emission eclipse mountain plastic testify coal",
+                "guest dream academic agency chew regular busy headset herald parcel dream metric ambition goat sidewalk grumpy intend buyer aviation shadow",
+                "guest dream academic always boring fragment security western general describe sweater true pink drove freshman rival black genre starting depict",
+            ]
+        );
+        for pair in [[0, 1], [1, 2], [0, 2]] {
+            assert_eq!(
+                combine(&[shares[pair[0]], shares[pair[1]]], "")
+                    .unwrap()
+                    .as_slice(),
+                &secret
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_combine() {
+        let mut random = TestingRandom::new();
+        let secret = hex!("c938b319067687e990e05e0da0ecce1278f75ff58d9853f19dcaeed5de104aae");
+        let groups = [
+            GroupParams {
+                member_threshold: 2,
+                member_count: 3,
+            },
+            GroupParams {
+                member_threshold: 1,
+                member_count: 1,
+            },
+            GroupParams {
+                member_threshold: 3,
+                member_count: 5,
+            },
+        ];
+        let shares = split(&mut random, &secret, "passphrase", 2, &groups).unwrap();
+        assert_eq!(
+            shares.iter().map(|group| group.len()).collect::<Vec<_>>(),
+            vec![3, 1, 5]
+        );
+        for share in shares.iter().flatten() {
+            assert_eq!(share.split(' ').count(), 33);
+        }
+
+        // Groups 0 and 2.
+        let mnemonics = [
+            shares[0][2].as_str(),
+            shares[2][4].as_str(),
+            shares[0][0].as_str(),
+            shares[2][0].as_str(),
+            shares[2][2].as_str(),
+        ];
+        assert_eq!(
+            combine(&mnemonics, "passphrase").unwrap().as_slice(),
+            &secret
+        );
+        assert_ne!(combine(&mnemonics, "").unwrap().as_slice(), &secret);
+
+        // Groups 1 and 2.
+        let mnemonics = [
+            shares[1][0].as_str(),
+            shares[2][1].as_str(),
+            shares[2][3].as_str(),
+            shares[2][4].as_str(),
+        ];
+        assert_eq!(
+            combine(&mnemonics, "passphrase").unwrap().as_slice(),
+            &secret
+        );
+
+        // Only one group is complete.
+        let mnemonics = [
+            shares[1][0].as_str(),
+            shares[2][1].as_str(),
+            shares[2][3].as_str(),
+        ];
+        assert_eq!(
+            combine(&mnemonics, "passphrase"),
+            Err(Error::NotEnoughShares)
+        );
+
+        // A complete group does not accept more shares.
+        let mnemonics = [
+            shares[0][0].as_str(),
+            shares[0][1].as_str(),
+            shares[0][2].as_str(),
+        ];
+        assert_eq!(combine(&mnemonics, "passphrase"), Err(Error::GroupComplete));
+    }
+
+    #[test]
+    fn test_split_invalid() {
+        let mut random = TestingRandom::new();
+        let secret = [0u8; 16];
+        let group = |member_threshold, member_count| GroupParams {
+            member_threshold,
+            member_count,
+        };
+        assert!(split(&mut random, &secret, "", 1, &[group(1, 1)]).is_ok());
+
+        assert_eq!(
+            split(&mut random, &secret[..15], "", 1, &[group(1, 1)]),
+            Err(Error::InvalidSecretLength)
+        );
+        assert_eq!(
+            split(&mut random, &[0u8; 17], "", 1, &[group(1, 1)]),
+            Err(Error::InvalidSecretLength)
+        );
+        assert_eq!(
+            split(&mut random, &secret, "\u{e4}", 1, &[group(1, 1)]),
+            Err(Error::InvalidPassphrase)
+        );
+        assert_eq!(
+            split(&mut random, &secret, "", 1, &[]),
+            Err(Error::InvalidParams)
+        );
+        assert_eq!(
+            split(&mut random, &secret, "", 0, &[group(1, 1)]),
+            Err(Error::InvalidParams)
+        );
+        assert_eq!(
+            split(&mut random, &secret, "", 2, &[group(1, 1)]),
+            Err(Error::InvalidParams)
+        );
+        assert_eq!(
+            split(&mut random, &secret, "", 1, &[group(3, 2)]),
+            Err(Error::InvalidParams)
+        );
+        assert_eq!(
+            split(&mut random, &secret, "", 1, &[group(1, 2)]),
+            Err(Error::InvalidParams)
+        );
+        assert_eq!(
+            split(&mut random, &secret, "", 1, &[group(2, 17)]),
+            Err(Error::InvalidParams)
+        );
+        let groups: Vec<GroupParams> = (0..17).map(|_| group(1, 1)).collect();
+        assert_eq!(
+            split(&mut random, &secret, "", 1, &groups),
+            Err(Error::InvalidParams)
+        );
+    }
+}
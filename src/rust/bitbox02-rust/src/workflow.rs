@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod account_name;
 #[cfg_attr(
     all(feature = "c-unit-testing", not(feature = "testing")),
     path = "workflow/mnemonic_c_unit_tests.rs"
@@ -7,6 +8,7 @@
 pub mod mnemonic;
 pub mod pairing;
 pub mod password;
+pub mod slip39;
 pub mod transaction;
 pub mod unlock;
 pub mod verify_message;
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::hal::Ui;
+use crate::hal::ui::{CanCancel, ConfirmParams, EnterStringParams, UserAbort};
+
+use alloc::string::String;
+
+use bitbox_hal::memory::MULTISIG_NAME_MAX_LEN;
+
+pub enum Error {
+    InvalidInput,
+    UserAbort,
+}
+
+impl core::convert::From<UserAbort> for Error {
+    fn from(_error: UserAbort) -> Self {
+        Error::UserAbort
+    }
+}
+
+/// Returns the given account name, or lets the user enter one on the device if it is empty. The
+/// name is validated to be usable as the name of a registered account.
+///
+/// Registered Bitcoin multisig accounts, Bitcoin policies and Cardano native script accounts are
+/// all stored in the same multisig registration slots (see `Memory::multisig_set_by_hash()`), so
+/// they share one budget of at most 25 registered accounts.
+pub async fn get(hal: &mut impl crate::hal::Hal, name: &str) -> Result<String, Error> {
+    let name: String = if name.is_empty() {
+        hal.ui()
+            .confirm(&ConfirmParams {
+                title: "Register",
+                body: "Please name this\naccount",
+                accept_is_nextarrow: true,
+                ..Default::default()
+            })
+            .await?;
+
+        let name = hal
+            .ui()
+            .enter_string(
+                &EnterStringParams {
+                    title: "Enter account name",
+                    longtouch: true,
+                    ..Default::default()
+                },
+                CanCancel::Yes,
+                "",
+            )
+            .await?;
+        // We truncate the user input string to fit into the maximum allowed multisig
+        // account name length. This is not very nice, but it has to do until we have some
+        // sort of indication in the input component.
+        util::strings::truncate_str(name.as_str(), MULTISIG_NAME_MAX_LEN).into()
+    } else {
+        name.into()
+    };
+    if !util::name::validate(&name, MULTISIG_NAME_MAX_LEN) {
+        return Err(Error::InvalidInput);
+    }
+    Ok(name)
+}
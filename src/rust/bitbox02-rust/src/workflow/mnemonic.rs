@@ -16,12 +16,13 @@ fn as_str_vec(v: &[zeroize::Zeroizing<String>]) -> Vec<&str> {
     v.iter().map(|s| s.as_str()).collect()
 }
 
-/// Return 5 words from the BIP39 wordlist, 4 of which are random, and
+/// Return 5 words from `wordlist`, 4 of which are random, and
 /// one of them is provided `word`. Returns the position of `word` in
 /// the list of words, and the lis of words.  This is used to test if
 /// the user wrote down the seed words properly.
 fn create_random_unique_words(
     hal_random: &mut impl crate::hal::Random,
+    wordlist: &[&str],
     word: &str,
     length: u8,
 ) -> (u8, Vec<zeroize::Zeroizing<String>>) {
@@ -43,16 +44,16 @@ fn create_random_unique_words(
             // A random word everywhere else.
             // Loop until we get a unique word, we don't want repeated words in the list.
             loop {
-                let idx = rand16(hal_random) % BIP39_WORDLIST_LEN;
+                let idx = rand16(hal_random) % wordlist.len() as u16;
                 if picked_indices.contains(&idx) {
                     continue;
                 };
-                let random_word = crate::bip39::get_word(idx).unwrap();
-                if random_word.as_str() == word {
+                let random_word = wordlist[idx as usize];
+                if random_word == word {
                     continue;
                 }
                 picked_indices.push(idx);
-                return random_word;
+                return zeroize::Zeroizing::new(random_word.into());
             }
         })
         .collect();
@@ -60,9 +61,12 @@ fn create_random_unique_words(
     (index_word, result)
 }
 
+/// Shows the mnemonic and quizzes the user on each word. The wrong choices in the quiz are picked
+/// from `wordlist`, which must be the wordlist of `words`.
 pub async fn show_and_confirm_mnemonic(
     hal_ui: &mut impl crate::hal::Ui,
     hal_random: &mut impl crate::hal::Random,
+    wordlist: &[&str],
     words: &[&str],
 ) -> Result<(), UserAbort> {
     hal_ui
@@ -92,7 +96,8 @@ pub async fn show_and_confirm_mnemonic(
     // Part 2) Confirm words
     for (word_idx, word) in words.iter().enumerate() {
         let title = format!("{:02}", word_idx + 1);
-        let (correct_idx, choices) = create_random_unique_words(hal_random, word, NUM_RANDOM_WORDS);
+        let (correct_idx, choices) =
+            create_random_unique_words(hal_random, wordlist, word, NUM_RANDOM_WORDS);
         let mut choices: Vec<&str> = choices.iter().map(|c| c.as_ref()).collect();
         choices.push("Back to\nrecovery words");
         let back_idx = (choices.len() - 1) as u8;
@@ -402,8 +407,12 @@ mod tests {
         let mut random = TestingRandom::new();
         // Place the target at index 2 in a 5-entry list.
         TestingUi::prepare_mnemonic_quiz_word_random(&mut random);
-        let (correct_idx, choices) =
-            create_random_unique_words(&mut random, "zoo", NUM_RANDOM_WORDS);
+        let (correct_idx, choices) = create_random_unique_words(
+            &mut random,
+            bip39::Language::English.word_list(),
+            "zoo",
+            NUM_RANDOM_WORDS,
+        );
         assert_eq!(correct_idx, 2);
         assert_eq!(
             as_str_vec(&choices),
@@ -414,6 +423,20 @@ mod tests {
         unique.sort_unstable();
         unique.dedup();
         assert_eq!(unique.len(), choices.len());
+
+        // The other choices are picked from the given wordlist.
+        TestingUi::prepare_mnemonic_quiz_word_random(&mut random);
+        let (correct_idx, choices) = create_random_unique_words(
+            &mut random,
+            bip39::Language::Italian.word_list(),
+            "zuppa",
+            NUM_RANDOM_WORDS,
+        );
+        assert_eq!(correct_idx, 2);
+        assert_eq!(
+            as_str_vec(&choices),
+            vec!["abaco", "abbaglio", "zuppa", "abbinato", "abete"]
+        );
     }
 
     #[test]
@@ -425,7 +448,12 @@ mod tests {
         let mut random = TestingRandom::new();
         ui.prepare_show_and_confirm_mnemonic(&mut random, words.len());
 
-        let result = block_on(show_and_confirm_mnemonic(&mut ui, &mut random, &words));
+        let result = block_on(show_and_confirm_mnemonic(
+            &mut ui,
+            &mut random,
+            bip39::Language::English.word_list(),
+            &words,
+        ));
         assert!(result.is_ok());
         TestingUi::assert_show_and_confirm_mnemonic_screens(&ui.screens, &words);
     }
@@ -8,6 +8,7 @@ use alloc::string::ToString;
 pub async fn show_and_confirm_mnemonic(
     _ui: &mut impl crate::hal::Ui,
     _random: &mut impl crate::hal::Random,
+    _wordlist: &[&str],
     words: &[&str],
 ) -> Result<(), UserAbort> {
     for word in words.iter() {
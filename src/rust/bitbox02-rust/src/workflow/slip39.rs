@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::hal::ui::{CanCancel, ConfirmParams, EnterStringParams, UserAbort};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Retrieve one SLIP-39 share of `num_words` words from the user. Each word is entered with the
+/// keyboard restricted to the SLIP-39 wordlist. The checksum is verified by the caller when
+/// decoding the share.
+pub async fn get_share(
+    hal_ui: &mut impl crate::hal::Ui,
+    num_words: usize,
+) -> Result<zeroize::Zeroizing<String>, UserAbort> {
+    let slip39_wordlist: Vec<u16> = (0..crate::slip39::WORDLIST.len() as u16).collect();
+
+    let mut word_idx: usize = 0;
+    let mut entered_words = vec![zeroize::Zeroizing::new(String::new()); num_words];
+    while word_idx < num_words {
+        let title = format!("{} of {}", word_idx + 1, num_words);
+        match hal_ui
+            .enter_string(
+                &EnterStringParams {
+                    title: &title,
+                    wordlist: Some(&slip39_wordlist),
+                    slip39_wordlist: true,
+                    ..Default::default()
+                },
+                CanCancel::Yes,
+                entered_words[word_idx].as_str(),
+            )
+            .await
+        {
+            Ok(word) => {
+                entered_words[word_idx] = word;
+                word_idx += 1;
+            }
+            Err(UserAbort) => {
+                // In all but the first word, the user can go back to edit the previous word.
+                if word_idx > 0 {
+                    match hal_ui
+                        .menu(&["Edit previous word", "Cancel restore"], Some("Choose"))
+                        .await
+                    {
+                        Err(UserAbort) => continue,
+                        Ok(0) => {
+                            word_idx -= 1;
+                            continue;
+                        }
+                        Ok(1) => {}
+                        _ => panic!("only two choices"),
+                    }
+                }
+                let params = ConfirmParams {
+                    title: "Restore",
+                    body: "Do you really\nwant to cancel?",
+                    ..Default::default()
+                };
+                if let Err(UserAbort) = hal_ui.confirm(&params).await {
+                    continue;
+                }
+                return Err(UserAbort);
+            }
+        }
+    }
+    let words: Vec<&str> = entered_words.iter().map(|word| word.as_str()).collect();
+    Ok(zeroize::Zeroizing::new(words.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::hal::testing::TestingUi;
+    use alloc::boxed::Box;
+    use alloc::collections::VecDeque;
+    use util::bb02_async::block_on;
+
+    #[test]
+    fn test_get_share() {
+        let mut ui = TestingUi::new();
+        let mut words: VecDeque<&str> = ["academic", "cancel", "acid", "acne"].into();
+        ui.set_enter_string(Box::new(move |params| {
+            assert!(params.slip39_wordlist);
+            assert_eq!(params.wordlist.unwrap().len(), 1024);
+            match words.pop_front().unwrap() {
+                // Go back to edit the previous word.
+                "cancel" => Err(UserAbort),
+                word => Ok(word.into()),
+            }
+        }));
+        ui.set_menu(Box::new(|_, title| {
+            assert_eq!(title, Some("Choose"));
+            Ok(0)
+        }));
+        match block_on(get_share(&mut ui, 2)) {
+            Ok(share) => assert_eq!(share.as_str(), "acid acne"),
+            Err(UserAbort) => panic!("unexpected abort"),
+        }
+
+        // Cancel at the first word.
+        let mut ui = TestingUi::new();
+        ui.set_enter_string(Box::new(|_| Err(UserAbort)));
+        assert!(block_on(get_share(&mut ui, 20)).is_err());
+    }
+}
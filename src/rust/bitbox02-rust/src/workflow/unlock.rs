@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::general::abort;
+use crate::hal::memory::SeedType;
 use crate::hal::ui::{CanCancel, ConfirmParams};
 use crate::hal::{Memory, Ui};
 use crate::workflow::password;
@@ -9,7 +10,7 @@ use alloc::vec::Vec;
 
 /// Confirm the entered mnemonic passphrase with the user. Returns true if the user confirmed it,
 /// false if the user rejected it.
-async fn confirm_mnemonic_passphrase(
+pub async fn confirm_mnemonic_passphrase(
     hal: &mut impl crate::hal::Hal,
     passphrase: &str,
 ) -> Result<(), crate::hal::ui::UserAbort> {
@@ -130,13 +131,16 @@ pub async fn unlock_keystore(
 }
 
 /// Performs the BIP39 keystore unlock, including unlock animation. If the optional passphrase
-/// feature is enabled, the user will be asked for the passphrase.
+/// feature is enabled, the user will be asked for the passphrase. SLIP-39 seeds have no passphrase
+/// at this point, see `keystore::unlock_bip39()`.
 pub async fn unlock_bip39(hal: &mut impl crate::hal::Hal, seed: &[u8]) {
     // Empty passphrase by default.
     let mut mnemonic_passphrase = zeroize::Zeroizing::new("".into());
 
     // If setting activated, get the passphrase from the user.
-    if hal.memory().is_mnemonic_passphrase_enabled() {
+    if hal.memory().is_mnemonic_passphrase_enabled()
+        && hal.memory().get_seed_type() == SeedType::Bip39
+    {
         // Loop until the user confirms.
         loop {
             mnemonic_passphrase = password::enter(
@@ -19,6 +19,8 @@ const ALLOWLIST_VARS: &[&str] = &[
     "MAX_LABEL_SIZE",
     "MAX_PK_SCRIPT_SIZE",
     "MAX_VARINT_SIZE",
+    "MEMORY_ETH_NETWORK_NUM_ENTRIES",
+    "MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES",
     "MEMORY_MULTISIG_NUM_ENTRIES",
     "MEMORY_PLATFORM_BITBOX02_PLUS",
     "MEMORY_PLATFORM_BITBOX02",
@@ -93,6 +95,8 @@ const ALLOWLIST_FNS: &[&str] = &[
     "memory_bootloader_hash",
     "memory_bootloader_set_flags",
     "memory_check_noise_remote_static_pubkey",
+    "memory_eth_network_get",
+    "memory_eth_network_set",
     "memory_get_attestation_bootloader_hash",
     "memory_get_attestation_pubkey_and_certificate",
     "memory_get_authorization_key",
@@ -107,9 +111,15 @@ const ALLOWLIST_FNS: &[&str] = &[
     "memory_get_salt_root",
     "memory_get_securechip_type",
     "memory_get_seed_birthdate",
+    "memory_is_blind_signing_enabled",
     "memory_is_initialized",
     "memory_is_mnemonic_passphrase_enabled",
     "memory_is_seeded",
+    "memory_is_slip39_seed",
+    "memory_lightning_channel_get",
+    "memory_lightning_channel_set",
+    "memory_lightning_channels_forget",
+    "memory_lightning_channels_forgotten_below",
     "memory_multisig_get_by_hash",
     "memory_multisig_set_by_hash",
     "memory_reset_hww",
@@ -117,6 +127,7 @@ const ALLOWLIST_FNS: &[&str] = &[
     "memory_set_attestation_bootloader_hash",
     "memory_set_attestation_certificate",
     "memory_set_attestation_device_pubkey",
+    "memory_set_blind_signing_enabled",
     "memory_set_device_name",
     "memory_set_encrypted_seed_and_hmac",
     "memory_set_initialized",
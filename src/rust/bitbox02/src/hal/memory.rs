@@ -4,7 +4,9 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use bitbox_hal::Memory;
-use bitbox_hal::memory::{Error, PasswordStretchAlgo, Platform, SecurechipType};
+use bitbox_hal::memory::{
+    Error, LightningChannelState, PasswordStretchAlgo, Platform, SecurechipType, SeedType,
+};
 
 pub struct BitBox02Memory;
 
@@ -91,6 +93,14 @@ impl Memory for BitBox02Memory {
         crate::memory::set_mnemonic_passphrase_enabled(enabled)
     }
 
+    fn is_blind_signing_enabled(&mut self) -> bool {
+        crate::memory::is_blind_signing_enabled()
+    }
+
+    fn set_blind_signing_enabled(&mut self, enabled: bool) -> Result<(), ()> {
+        crate::memory::set_blind_signing_enabled(enabled)
+    }
+
     fn set_seed_birthdate(&mut self, timestamp: u32) -> Result<(), ()> {
         crate::memory::set_seed_birthdate(timestamp)
     }
@@ -120,13 +130,23 @@ impl Memory for BitBox02Memory {
         &mut self,
         data: &[u8],
         password_stretch_algo: PasswordStretchAlgo,
+        seed_type: SeedType,
     ) -> Result<(), ()> {
         crate::memory::set_encrypted_seed_and_hmac(
             data,
             to_bitbox02_password_stretch_algo(password_stretch_algo),
+            seed_type == SeedType::Slip39,
         )
     }
 
+    fn get_seed_type(&mut self) -> SeedType {
+        if crate::memory::is_slip39_seed() {
+            SeedType::Slip39
+        } else {
+            SeedType::Bip39
+        }
+    }
+
     fn reset_hww(&mut self) -> Result<(), ()> {
         crate::memory::reset_hww()
     }
@@ -171,6 +191,43 @@ impl Memory for BitBox02Memory {
     fn multisig_get_by_hash(&self, hash: &[u8; 32]) -> Option<String> {
         crate::memory::multisig_get_by_hash(hash)
     }
+
+    fn eth_network_set(&mut self, chain_id: u64, name: &str, unit: &str) -> Result<(), Error> {
+        crate::memory::eth_network_set(chain_id, name, unit).map_err(to_hal_error)
+    }
+
+    fn eth_network_get(&self, chain_id: u64) -> Option<(String, String)> {
+        crate::memory::eth_network_get(chain_id)
+    }
+
+    fn lightning_channel_set(
+        &mut self,
+        coin: u32,
+        channel_index: u32,
+        state: &LightningChannelState,
+    ) -> Result<(), Error> {
+        crate::memory::lightning_channel_set(coin, channel_index, state).map_err(to_hal_error)
+    }
+
+    fn lightning_channel_get(
+        &self,
+        coin: u32,
+        channel_index: u32,
+    ) -> Option<LightningChannelState> {
+        crate::memory::lightning_channel_get(coin, channel_index)
+    }
+
+    fn lightning_channels_forget(
+        &mut self,
+        coin: u32,
+        below_channel_index: u32,
+    ) -> Result<(), Error> {
+        crate::memory::lightning_channels_forget(coin, below_channel_index).map_err(to_hal_error)
+    }
+
+    fn lightning_channels_forgotten_below(&self, coin: u32) -> u32 {
+        crate::memory::lightning_channels_forgotten_below(coin)
+    }
 }
 
 #[cfg(test)]
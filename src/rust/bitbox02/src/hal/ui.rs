@@ -48,6 +48,7 @@ fn to_bitbox02_trinary_input_string_params<'a>(
     crate::ui::TrinaryInputStringParams {
         title: params.title,
         wordlist: params.wordlist,
+        slip39_wordlist: params.slip39_wordlist,
         number_input: params.number_input,
         hide: params.hide,
         special_chars: params.special_chars,
@@ -255,6 +256,7 @@ mod tests {
         let input_without_wordlist = EnterStringParams {
             title: "Enter",
             wordlist: None,
+            slip39_wordlist: false,
             number_input: true,
             hide: true,
             special_chars: true,
@@ -266,6 +268,7 @@ mod tests {
             to_bitbox02_trinary_input_string_params(&input_without_wordlist);
         assert_eq!(output_without_wordlist.title, "Enter");
         assert!(output_without_wordlist.wordlist.is_none());
+        assert!(!output_without_wordlist.slip39_wordlist);
         assert!(output_without_wordlist.number_input);
         assert!(output_without_wordlist.hide);
         assert!(output_without_wordlist.special_chars);
@@ -277,6 +280,7 @@ mod tests {
         let input_with_wordlist = EnterStringParams {
             title: "Seed",
             wordlist: Some(&wordlist),
+            slip39_wordlist: true,
             number_input: false,
             hide: false,
             special_chars: false,
@@ -287,6 +291,7 @@ mod tests {
         let output_with_wordlist = to_bitbox02_trinary_input_string_params(&input_with_wordlist);
         assert_eq!(output_with_wordlist.title, "Seed");
         assert_eq!(output_with_wordlist.wordlist.unwrap(), wordlist.as_slice());
+        assert!(output_with_wordlist.slip39_wordlist);
         assert!(!output_with_wordlist.number_input);
         assert!(!output_with_wordlist.hide);
         assert!(!output_with_wordlist.special_chars);
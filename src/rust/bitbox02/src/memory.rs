@@ -3,7 +3,10 @@
 extern crate alloc;
 use alloc::string::String;
 use alloc::vec::Vec;
-use bitbox_hal::memory::{DEVICE_NAME_MAX_LEN, MULTISIG_NAME_MAX_LEN};
+use bitbox_hal::memory::{
+    DEVICE_NAME_MAX_LEN, ETH_NETWORK_NAME_MAX_LEN, ETH_NETWORK_UNIT_MAX_LEN, LightningChannelState,
+    MULTISIG_NAME_MAX_LEN,
+};
 
 pub use bitbox02_sys::memory_ble_metadata_t as BleMetadata;
 
@@ -48,10 +51,18 @@ pub fn is_seeded() -> bool {
     unsafe { bitbox02_sys::memory_is_seeded() }
 }
 
+pub fn is_slip39_seed() -> bool {
+    unsafe { bitbox02_sys::memory_is_slip39_seed() }
+}
+
 pub fn is_mnemonic_passphrase_enabled() -> bool {
     unsafe { bitbox02_sys::memory_is_mnemonic_passphrase_enabled() }
 }
 
+pub fn is_blind_signing_enabled() -> bool {
+    unsafe { bitbox02_sys::memory_is_blind_signing_enabled() }
+}
+
 pub fn get_attestation_bootloader_hash() -> [u8; 32] {
     let mut hash = [0u8; 32];
     unsafe {
@@ -122,6 +133,13 @@ pub fn set_mnemonic_passphrase_enabled(enabled: bool) -> Result<(), ()> {
     }
 }
 
+pub fn set_blind_signing_enabled(enabled: bool) -> Result<(), ()> {
+    match unsafe { bitbox02_sys::memory_set_blind_signing_enabled(enabled) } {
+        true => Ok(()),
+        false => Err(()),
+    }
+}
+
 pub fn set_seed_birthdate(timestamp: u32) -> Result<(), ()> {
     match unsafe { bitbox02_sys::memory_set_seed_birthdate(timestamp) } {
         true => Ok(()),
@@ -140,6 +158,7 @@ pub fn get_seed_birthdate() -> u32 {
 pub fn set_encrypted_seed_and_hmac(
     data: &[u8],
     password_stretch_algo: PasswordStretchAlgo,
+    slip39_seed: bool,
 ) -> Result<(), ()> {
     if data.len() > u8::MAX as usize {
         return Err(());
@@ -149,6 +168,7 @@ pub fn set_encrypted_seed_and_hmac(
             data.as_ptr(),
             data.len() as u8,
             password_stretch_algo,
+            slip39_seed,
         )
     } {
         true => Ok(()),
@@ -211,6 +231,101 @@ pub fn multisig_get_by_hash(hash: &[u8]) -> Option<String> {
     }
 }
 
+pub fn eth_network_set(chain_id: u64, name: &str, unit: &str) -> Result<(), MemoryError> {
+    match unsafe {
+        bitbox02_sys::memory_eth_network_set(
+            chain_id,
+            util::strings::str_to_cstr_vec(name)
+                .or(Err(MemoryError::MEMORY_ERR_INVALID_INPUT))?
+                .as_ptr()
+                .cast(),
+            util::strings::str_to_cstr_vec(unit)
+                .or(Err(MemoryError::MEMORY_ERR_INVALID_INPUT))?
+                .as_ptr()
+                .cast(),
+        )
+    } {
+        MemoryError::MEMORY_OK => Ok(()),
+        err => Err(err),
+    }
+}
+
+pub fn eth_network_get(chain_id: u64) -> Option<(String, String)> {
+    let mut name = [0u8; ETH_NETWORK_NAME_MAX_LEN + 1];
+    let mut unit = [0u8; ETH_NETWORK_UNIT_MAX_LEN + 1];
+    match unsafe {
+        bitbox02_sys::memory_eth_network_get(
+            chain_id,
+            name.as_mut_ptr().cast(),
+            unit.as_mut_ptr().cast(),
+        )
+    } {
+        true => Some((
+            util::strings::str_from_null_terminated(&name[..])
+                .unwrap()
+                .into(),
+            util::strings::str_from_null_terminated(&unit[..])
+                .unwrap()
+                .into(),
+        )),
+        false => None,
+    }
+}
+
+pub fn lightning_channel_set(
+    coin: u32,
+    channel_index: u32,
+    state: &LightningChannelState,
+) -> Result<(), MemoryError> {
+    match unsafe {
+        bitbox02_sys::memory_lightning_channel_set(
+            coin,
+            channel_index,
+            state.parameters_hash.as_ptr(),
+            state.next_counterparty_commitment,
+            state.next_holder_commitment,
+            state.revoked_holder_commitments,
+            state.to_self_sat,
+            state.counterparty_commitment_hash.as_ptr(),
+            state.closed,
+        )
+    } {
+        MemoryError::MEMORY_OK => Ok(()),
+        err => Err(err),
+    }
+}
+
+pub fn lightning_channel_get(coin: u32, channel_index: u32) -> Option<LightningChannelState> {
+    let mut state = LightningChannelState::default();
+    match unsafe {
+        bitbox02_sys::memory_lightning_channel_get(
+            coin,
+            channel_index,
+            state.parameters_hash.as_mut_ptr(),
+            &mut state.next_counterparty_commitment,
+            &mut state.next_holder_commitment,
+            &mut state.revoked_holder_commitments,
+            &mut state.to_self_sat,
+            state.counterparty_commitment_hash.as_mut_ptr(),
+            &mut state.closed,
+        )
+    } {
+        true => Some(state),
+        false => None,
+    }
+}
+
+pub fn lightning_channels_forget(coin: u32, below_channel_index: u32) -> Result<(), MemoryError> {
+    match unsafe { bitbox02_sys::memory_lightning_channels_forget(coin, below_channel_index) } {
+        MemoryError::MEMORY_OK => Ok(()),
+        err => Err(err),
+    }
+}
+
+pub fn lightning_channels_forgotten_below(coin: u32) -> u32 {
+    unsafe { bitbox02_sys::memory_lightning_channels_forgotten_below(coin) }
+}
+
 #[derive(Clone, Copy)]
 pub enum Platform {
     BitBox02,
@@ -466,6 +581,7 @@ mod tests {
         set_encrypted_seed_and_hmac(
             &seed_data,
             PasswordStretchAlgo::MEMORY_PASSWORD_STRETCH_ALGO_V0,
+            false,
         )
         .unwrap();
         assert!(is_seeded());
@@ -488,6 +604,21 @@ mod tests {
         assert!(!is_mnemonic_passphrase_enabled());
     }
 
+    #[test]
+    fn test_blind_signing_enabled_roundtrip() {
+        mock_memory();
+
+        assert!(!is_blind_signing_enabled());
+
+        set_blind_signing_enabled(true).unwrap();
+        assert!(is_blind_signing_enabled());
+        // Independent of the other flags in the same bitmask.
+        assert!(!is_mnemonic_passphrase_enabled());
+
+        set_blind_signing_enabled(false).unwrap();
+        assert!(!is_blind_signing_enabled());
+    }
+
     #[test]
     fn test_seed_birthdate_roundtrip() {
         mock_memory();
@@ -695,6 +826,198 @@ mod tests {
         assert_eq!(err, MemoryError::MEMORY_ERR_FULL);
     }
 
+    #[test]
+    fn test_memory_eth_network() {
+        mock_memory();
+
+        assert!(eth_network_get(1234).is_none());
+
+        // set
+        assert!(eth_network_set(1234, "My network", "MYN").is_ok());
+        assert!(eth_network_set(5678, "Other network", "OTH").is_ok());
+        // overwrite with the same is possible
+        assert!(eth_network_set(5678, "Other network", "OTH").is_ok());
+
+        // get
+        assert_eq!(
+            eth_network_get(1234),
+            Some(("My network".into(), "MYN".into()))
+        );
+        assert_eq!(
+            eth_network_get(5678),
+            Some(("Other network".into(), "OTH".into()))
+        );
+        assert!(eth_network_get(1).is_none());
+
+        // rename
+        assert!(eth_network_set(1234, "Renamed", "REN").is_ok());
+        assert_eq!(
+            eth_network_get(1234),
+            Some(("Renamed".into(), "REN".into()))
+        );
+
+        // invalid input
+        assert_eq!(
+            eth_network_set(0, "foo", "FOO").unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+        assert_eq!(
+            eth_network_set(u64::MAX, "foo", "FOO").unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+        assert_eq!(
+            eth_network_set(1234, "", "FOO").unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+        assert_eq!(
+            eth_network_set(1234, "foo", "").unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+        assert!(eth_network_get(u64::MAX).is_none());
+    }
+
+    #[test]
+    fn test_memory_eth_network_full() {
+        mock_memory();
+
+        let limit = bitbox02_sys::MEMORY_ETH_NETWORK_NUM_ENTRIES as u64;
+        for chain_id in 1..=limit {
+            assert!(eth_network_set(chain_id, &format!("name{chain_id}"), "UNIT").is_ok());
+        }
+        let err = eth_network_set(limit + 1, "too many", "UNIT").unwrap_err();
+        assert_eq!(err, MemoryError::MEMORY_ERR_FULL);
+        // Existing networks can still be updated.
+        assert!(eth_network_set(1, "renamed", "UNIT").is_ok());
+    }
+
+    #[test]
+    fn test_memory_lightning_channel() {
+        mock_memory();
+
+        assert!(lightning_channel_get(0, 0).is_none());
+
+        let state = LightningChannelState {
+            parameters_hash: [0x11; 32],
+            next_counterparty_commitment: 3,
+            next_holder_commitment: 4,
+            revoked_holder_commitments: 2,
+            to_self_sat: 100_000,
+            counterparty_commitment_hash: [0x22; 32],
+            closed: false,
+        };
+        assert!(lightning_channel_set(0, 0, &state).is_ok());
+        assert!(lightning_channel_set(0, 7, &LightningChannelState::default()).is_ok());
+        // setting the same state again is possible
+        assert!(lightning_channel_set(0, 0, &state).is_ok());
+
+        assert_eq!(lightning_channel_get(0, 0), Some(state));
+        assert_eq!(
+            lightning_channel_get(0, 7),
+            Some(LightningChannelState::default())
+        );
+        assert!(lightning_channel_get(0, 1).is_none());
+        // Channels of different coins are stored separately.
+        assert!(lightning_channel_get(1, 0).is_none());
+        assert!(lightning_channel_set(1, 0, &LightningChannelState::default()).is_ok());
+        assert_eq!(
+            lightning_channel_get(1, 0),
+            Some(LightningChannelState::default())
+        );
+        assert_eq!(lightning_channel_get(0, 0), Some(state));
+
+        // update
+        let updated = LightningChannelState {
+            parameters_hash: [0x11; 32],
+            next_counterparty_commitment: 4,
+            next_holder_commitment: 5,
+            revoked_holder_commitments: 3,
+            to_self_sat: 90_000,
+            counterparty_commitment_hash: [0x33; 32],
+            closed: true,
+        };
+        assert!(lightning_channel_set(0, 0, &updated).is_ok());
+        assert_eq!(lightning_channel_get(0, 0), Some(updated));
+
+        // invalid input
+        assert_eq!(
+            lightning_channel_set(0, u32::MAX, &state).unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+        assert!(lightning_channel_get(0, u32::MAX).is_none());
+
+        // full
+        let limit = bitbox02_sys::MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES;
+        for channel_index in 8..limit + 5 {
+            assert!(lightning_channel_set(0, channel_index, &state).is_ok());
+        }
+        assert_eq!(
+            lightning_channel_set(0, limit + 5, &state).unwrap_err(),
+            MemoryError::MEMORY_ERR_FULL
+        );
+        // Existing channels can still be updated.
+        assert!(lightning_channel_set(0, 7, &state).is_ok());
+
+        // The state survives a reset.
+        reset_hww().unwrap();
+        assert_eq!(lightning_channel_get(0, 0), Some(updated));
+        assert_eq!(lightning_channel_get(0, 7), Some(state));
+    }
+
+    #[test]
+    fn test_memory_lightning_channels_forget() {
+        mock_memory();
+
+        let open = LightningChannelState::default();
+        let closed = LightningChannelState {
+            closed: true,
+            ..Default::default()
+        };
+        assert!(lightning_channel_set(0, 0, &closed).is_ok());
+        assert!(lightning_channel_set(0, 1, &open).is_ok());
+        assert!(lightning_channel_set(0, 2, &closed).is_ok());
+        assert!(lightning_channel_set(1, 0, &open).is_ok());
+        assert_eq!(lightning_channels_forgotten_below(0), 0);
+
+        // Open channels can't be forgotten.
+        assert_eq!(
+            lightning_channels_forget(0, 2).unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+        assert_eq!(lightning_channel_get(0, 0), Some(closed));
+        assert_eq!(lightning_channels_forgotten_below(0), 0);
+
+        assert!(lightning_channels_forget(0, 1).is_ok());
+        assert!(lightning_channel_get(0, 0).is_none());
+        assert_eq!(lightning_channel_get(0, 1), Some(open));
+        assert_eq!(lightning_channel_get(1, 0), Some(open));
+        assert_eq!(lightning_channels_forgotten_below(0), 1);
+        assert_eq!(lightning_channels_forgotten_below(1), 0);
+
+        // Forgetting fewer channels doesn't lower the index.
+        assert!(lightning_channels_forget(0, 0).is_ok());
+        assert_eq!(lightning_channels_forgotten_below(0), 1);
+
+        // The slots are freed.
+        let limit = bitbox02_sys::MEMORY_LIGHTNING_CHANNEL_NUM_ENTRIES;
+        for channel_index in 10..limit + 8 {
+            assert!(lightning_channel_set(0, channel_index, &open).is_ok());
+        }
+        assert_eq!(
+            lightning_channel_set(0, limit + 8, &open).unwrap_err(),
+            MemoryError::MEMORY_ERR_FULL
+        );
+
+        // invalid input
+        assert_eq!(
+            lightning_channels_forget(u32::MAX, 1).unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+        assert_eq!(
+            lightning_channels_forget(0, u32::MAX).unwrap_err(),
+            MemoryError::MEMORY_ERR_INVALID_INPUT
+        );
+    }
+
     #[test]
     fn test_encrypted_seed_and_hmac_roundtrip() {
         for algo in [
@@ -705,18 +1028,26 @@ mod tests {
 
             assert!(!is_seeded());
             let seed_data: Vec<u8> = (0..96).map(|i| i as u8).collect();
-            set_encrypted_seed_and_hmac(&seed_data, algo).unwrap();
+            set_encrypted_seed_and_hmac(&seed_data, algo, false).unwrap();
             assert!(is_seeded());
+            assert!(!is_slip39_seed());
 
             let (stored, stored_algo) = get_encrypted_seed_and_hmac().unwrap();
             assert_eq!(stored, seed_data);
             assert_eq!(stored_algo, algo);
 
             let oversized = vec![0u8; 97];
-            assert!(set_encrypted_seed_and_hmac(&oversized, algo).is_err());
+            assert!(set_encrypted_seed_and_hmac(&oversized, algo, true).is_err());
             let (stored, stored_algo) = get_encrypted_seed_and_hmac().unwrap();
             assert_eq!(stored, seed_data);
             assert_eq!(stored_algo, algo);
+            assert!(!is_slip39_seed());
+
+            // The seed type is stored with the seed.
+            set_encrypted_seed_and_hmac(&seed_data, algo, true).unwrap();
+            assert!(is_slip39_seed());
+            set_encrypted_seed_and_hmac(&seed_data, algo, false).unwrap();
+            assert!(!is_slip39_seed());
         }
     }
 
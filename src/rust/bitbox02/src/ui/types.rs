@@ -63,8 +63,10 @@ pub struct ConfirmParams<'a> {
 pub struct TrinaryInputStringParams<'a> {
     /// The confirmation title of the screen. Max 200 chars, otherwise **panic**.
     pub title: &'a str,
-    /// Currently specialized to the BIP39 wordlist: a list of BIP39 word indices. Can be extended if needed.
+    /// A list of word indices into the BIP39 English wordlist, or into the SLIP-39 wordlist if
+    /// `slip39_wordlist` is true.
     pub wordlist: Option<&'a [u16]>,
+    pub slip39_wordlist: bool,
     pub number_input: bool,
     pub hide: bool,
     pub special_chars: bool,
@@ -113,6 +113,7 @@ pub async fn trinary_input_string(
             None => 0,
             Some(wordlist) => wordlist.len() as _,
         },
+        slip39_wordlist: params.slip39_wordlist,
         number_input: params.number_input,
         hide: params.hide,
         special_chars: params.special_chars,
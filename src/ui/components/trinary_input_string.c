@@ -49,17 +49,13 @@ static char _special_chars[] = " !\"#$%&'()*+,-./:;<=>?^[\\]@_{|}";
 
 static const UG_FONT* _font = &font_password_11X12;
 
-static void _get_bip39_word_stack(uint16_t idx, char* word_out, size_t word_out_size)
-{
-    if (!rust_get_bip39_word(idx, rust_util_bytes_mut((uint8_t*)word_out, word_out_size))) {
-        Abort("_get_bip39_word_stack");
-    }
-}
-
 typedef struct {
     // Can be NULL.
     const uint16_t* wordlist;
     size_t wordlist_size;
+    // Only applies if wordlist != NULL: the indices refer to the SLIP-39 instead of the BIP39
+    // wordlist.
+    bool slip39_wordlist;
     bool number_input;
     // Only applies if wordlist != NULL: determines if a word from the wordlist was entered.
     bool can_confirm;
@@ -103,6 +99,20 @@ typedef struct {
     component_t* keyboard_switch_component;
 } data_t;
 
+static void _get_word_stack(const data_t* data, uint16_t idx, char* word_out, size_t word_out_size)
+{
+    BytesMut out = rust_util_bytes_mut((uint8_t*)word_out, word_out_size);
+    bool ok;
+    if (data->slip39_wordlist) {
+        ok = rust_get_slip39_word(idx, out);
+    } else {
+        ok = rust_get_bip39_word(idx, out);
+    }
+    if (!ok) {
+        Abort("_get_word_stack");
+    }
+}
+
 static void _cleanup(component_t* component)
 {
     data_t* data = (data_t*)component->data;
@@ -284,7 +294,7 @@ static void _set_alphabet(component_t* trinary_input_string, bool maybe_autocomp
         char charset[27] = {0};
         for (size_t word_idx = 0; word_idx < data->wordlist_size; word_idx++) {
             char word[10];
-            _get_bip39_word_stack(data->wordlist[word_idx], word, sizeof(word));
+            _get_word_stack(data, data->wordlist[word_idx], word, sizeof(word));
 
             if (STREQ(word, data->string)) {
                 data->can_confirm = true;
@@ -310,7 +320,7 @@ static void _set_alphabet(component_t* trinary_input_string, bool maybe_autocomp
 
         if (maybe_autocomplete && !found_word_not_unique && found_word_idx != data->wordlist_size) {
             char word[10];
-            _get_bip39_word_stack(data->wordlist[found_word_idx], word, sizeof(word));
+            _get_word_stack(data, data->wordlist[found_word_idx], word, sizeof(word));
 
             data->string_index = snprintf(data->string, sizeof(data->string), "%s", word);
             // We autocompleted, so we don't offer any more letters to choose. The charset above
@@ -455,6 +465,7 @@ component_t* trinary_input_string_create(
     data->cancel_user_data = cancel_user_data;
     data->wordlist = params->wordlist;
     data->wordlist_size = params->wordlist_size;
+    data->slip39_wordlist = params->slip39_wordlist;
     data->number_input = params->number_input;
     data->hide = params->hide;
     data->longtouch = params->longtouch;
@@ -516,10 +527,10 @@ void trinary_input_string_set_input(component_t* trinary_input_string, const cha
         return;
     }
     for (size_t i = 0; i < data->wordlist_size; i++) {
-        char bip39_word[10];
-        _get_bip39_word_stack(data->wordlist[i], bip39_word, sizeof(bip39_word));
+        char wordlist_word[10];
+        _get_word_stack(data, data->wordlist[i], wordlist_word, sizeof(wordlist_word));
 
-        if (STREQ(bip39_word, word)) {
+        if (STREQ(wordlist_word, word)) {
             data->string_index = snprintf(data->string, sizeof(data->string), "%s", word);
             _set_alphabet(trinary_input_string, false);
             return;
@@ -12,9 +12,11 @@
 
 typedef struct {
     const char* title;
-    // Restrict and autocomplete to this list of BIP39 words. The elements are indices into the
-    // BIP39 English wordlist. Set to NULL to allow arbitrary input.
+    // Restrict and autocomplete to this list of words. The elements are indices into the BIP39
+    // English wordlist, or into the SLIP-39 wordlist if `slip39_wordlist` is true. Set to NULL to
+    // allow arbitrary input.
     uint16_t const* wordlist;
+    bool slip39_wordlist;
     // If true, the user can enter numbers only.
     bool number_input;
     // Set to 0 if wordlist is NULL.